@@ -444,6 +444,14 @@ pub fn build(b: *std.Build) void {
     );
     upstream_surface_step.dependOn(&upstream_surface_cmd.step);
 
+    // stwo-interop-rs library surface snapshot (semver guard for downstream embedders).
+    const interop_public_api_cmd = b.addSystemCommand(&.{ "python3", "scripts/check_interop_public_api.py" });
+    const interop_public_api_step = b.step(
+        "interop-public-api",
+        "Check the stwo-interop-rs public library API against public_api.txt",
+    );
+    interop_public_api_step.dependOn(&interop_public_api_cmd.step);
+
     // Capture current roadmap baseline snapshot for section-15 closure tracking.
     const roadmap_baseline_cmd = b.addSystemCommand(&.{ "python3", "scripts/roadmap_baseline.py" });
     const roadmap_baseline_step = b.step(
@@ -0,0 +1,125 @@
+#!/usr/bin/env python3
+"""Sampling-profiler overhead gate for the Rust interop bench mode.
+
+Builds stwo-interop-rs with `--features profiling`, runs one small bench with
+`--profile-out`, and fails if the profiled/unprofiled average time ratio of
+either phase exceeds the bound the binary reports (`overhead_bound`), or if
+any per-iteration folded-stack file is missing. Files may be empty when an
+iteration finishes before the first sample.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import subprocess
+import sys
+from pathlib import Path
+
+
+ROOT = Path(__file__).resolve().parent.parent
+RUST_MANIFEST = ROOT / "tools" / "stwo-interop-rs" / "Cargo.toml"
+RUST_BIN = ROOT / "tools" / "stwo-interop-rs" / "target" / "release" / "stwo-interop-rs"
+PROFILE_DIR_DEFAULT = ROOT / "vectors" / ".bench_profiles"
+ARTIFACT = ROOT / "vectors" / ".bench_profiles.artifact.json"
+
+RUST_TOOLCHAIN_DEFAULT = "nightly-2025-07-14"
+
+SMALL_WORKLOAD_ARGS = [
+    "--example",
+    "wide_fibonacci",
+    "--wf-log-n-rows",
+    "10",
+    "--wf-sequence-len",
+    "16",
+    "--pow-bits",
+    "0",
+    "--fri-log-blowup",
+    "1",
+    "--fri-log-last-layer",
+    "0",
+    "--fri-n-queries",
+    "3",
+]
+
+
+def run(cmd: list[str]) -> subprocess.CompletedProcess[str]:
+    return subprocess.run(cmd, cwd=ROOT, text=True, capture_output=True, check=False)
+
+
+def parse_args() -> argparse.Namespace:
+    parser = argparse.ArgumentParser(description="Bench profiling overhead gate")
+    parser.add_argument("--rust-toolchain", default=RUST_TOOLCHAIN_DEFAULT)
+    parser.add_argument("--profile-dir", type=Path, default=PROFILE_DIR_DEFAULT)
+    parser.add_argument("--warmups", type=int, default=1)
+    parser.add_argument("--repeats", type=int, default=5)
+    return parser.parse_args()
+
+
+def main() -> int:
+    args = parse_args()
+
+    build = run(
+        [
+            "cargo",
+            f"+{args.rust_toolchain}",
+            "build",
+            "--release",
+            "--features",
+            "profiling",
+            "--manifest-path",
+            str(RUST_MANIFEST),
+        ]
+    )
+    if build.returncode != 0:
+        print(build.stderr, file=sys.stderr)
+        return 1
+
+    bench = run(
+        [
+            str(RUST_BIN),
+            "--mode",
+            "bench",
+            "--artifact",
+            str(ARTIFACT),
+            "--bench-warmups",
+            str(args.warmups),
+            "--bench-repeats",
+            str(args.repeats),
+            "--profile-out",
+            str(args.profile_dir),
+            *SMALL_WORKLOAD_ARGS,
+        ]
+    )
+    if bench.returncode != 0:
+        print(bench.stderr, file=sys.stderr)
+        return 1
+    report = json.loads(bench.stdout.strip().splitlines()[-1])
+    profiling = report.get("profiling")
+    if not profiling:
+        print("bench report has no profiling section", file=sys.stderr)
+        return 1
+
+    failures = []
+    bound = float(profiling["overhead_bound"])
+    for phase in ("prove", "verify"):
+        ratio = float(profiling[f"{phase}_overhead_ratio"])
+        print(f"{phase}: profiled/unprofiled = {ratio:.3f} (bound {bound:.2f})")
+        if ratio > bound:
+            failures.append(f"{phase} profiling overhead {ratio:.3f} exceeds {bound:.2f}")
+
+    expected_files = 2 * (args.warmups + args.repeats)
+    files = [Path(path) for path in profiling["files"]]
+    if len(files) != expected_files:
+        failures.append(f"expected {expected_files} profile files, got {len(files)}")
+    for path in files:
+        if not path.is_file():
+            failures.append(f"missing profile file {path}")
+
+    for failure in failures:
+        print(failure, file=sys.stderr)
+    return 1 if failures else 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
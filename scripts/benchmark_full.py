@@ -400,6 +400,10 @@ def bench_runtime(
     ] + [str(arg) for arg in workload["args"]]
     if runtime == "zig":
         cmd.extend(["--bench-proof-codec", zig_bench_proof_codec])
+    # The Rust bench writes its authoritative report to --bench-out; Zig only prints it.
+    bench_out = ARTIFACT_DIR / f"{runtime}_{family}.bench.json"
+    if runtime == "rust":
+        cmd.extend(["--bench-out", str(bench_out)])
 
     runtime_env: dict[str, str] | None = None
     if runtime == "zig":
@@ -419,7 +423,10 @@ def bench_runtime(
             f"command: {' '.join(cmd)}\n"
             f"stderr:\n{proc.stderr}"
         )
-    payload = parse_json_stdout(proc.stdout)
+    if runtime == "rust":
+        payload = json.loads(bench_out.read_text(encoding="utf-8"))
+    else:
+        payload = parse_json_stdout(proc.stdout)
     if not isinstance(payload, dict):
         raise RuntimeError(f"{runtime} bench payload for family '{family}' is not an object")
     payload["peak_rss_kb"] = peak_rss_kb
@@ -0,0 +1,172 @@
+#!/usr/bin/env python3
+"""Flag unintentional changes to the stwo-interop-rs library surface.
+
+Extracts every public item of `tools/stwo-interop-rs/src/lib.rs` (consts, type aliases,
+re-exports, functions, inherent methods, struct fields and enum variants, plus
+`#[non_exhaustive]` markers) into one normalized line each and compares the result with the
+checked-in `public_api.txt`. Items marked `#[doc(hidden)]` are outside the supported surface and
+are skipped. Run with `--update` after an intended surface change and review the diff for semver
+impact.
+"""
+
+from __future__ import annotations
+
+import argparse
+import difflib
+import re
+import sys
+from pathlib import Path
+
+
+ROOT = Path(__file__).resolve().parent.parent
+LIB_PATH = ROOT / "tools" / "stwo-interop-rs" / "src" / "lib.rs"
+SNAPSHOT_PATH = ROOT / "tools" / "stwo-interop-rs" / "public_api.txt"
+
+ITEM_RE = re.compile(r"^pub (const|type|use|fn|struct|enum|mod) ")
+IMPL_RE = re.compile(r"^impl ([A-Za-z0-9_]+) \{$")
+
+
+def normalize(text: str) -> str:
+    text = re.sub(r"\s+", " ", text).strip()
+    text = re.sub(r"\( ", "(", text)
+    text = re.sub(r",? \)", ")", text)
+    text = re.sub(r",? \}", " }", text)
+    return text
+
+
+def strip_line_comment(line: str) -> str:
+    stripped = line.strip()
+    if stripped.startswith("//") or stripped.startswith("#["):
+        return ""
+    return line
+
+
+def split_top_level(body: str) -> list[str]:
+    parts: list[str] = []
+    depth = 0
+    current = ""
+    for index, ch in enumerate(body):
+        if ch in "{([<":
+            depth += 1
+        elif ch in "})]" or (ch == ">" and body[index - 1 : index] != "-"):
+            depth -= 1
+        if ch == "," and depth == 0:
+            parts.append(current)
+            current = ""
+        else:
+            current += ch
+    parts.append(current)
+    return [normalize(part) for part in parts if part.strip()]
+
+
+def collect_until(lines: list[str], start: int, terminator) -> tuple[list[str], int]:
+    """Returns lines[start..=end] where `end` is the first line satisfying `terminator`."""
+    end = start
+    while not terminator(lines[end]):
+        end += 1
+    return lines[start : end + 1], end
+
+
+def signature(lines: list[str]) -> str:
+    text = normalize(" ".join(lines))
+    return re.sub(r"\s*(\{.*|;)$", "", text)
+
+
+def extract_public_api(source: str) -> list[str]:
+    lines = source.splitlines()
+    entries: list[str] = []
+    attributes: list[str] = []
+    i = 0
+    while i < len(lines):
+        line = lines[i]
+        if line.startswith("#["):
+            attributes.append(line.strip())
+            i += 1
+            continue
+        if line.startswith("///") or line.startswith("//!"):
+            i += 1
+            continue
+        hidden = "#[doc(hidden)]" in attributes
+        non_exhaustive = "#[non_exhaustive]" in attributes
+        attributes = []
+
+        impl_match = IMPL_RE.match(line)
+        if impl_match:
+            body, end = collect_until(lines, i, lambda l: l == "}")
+            owner = impl_match.group(1)
+            j = 0
+            while j < len(body):
+                if body[j].startswith("    pub fn "):
+                    sig, k = collect_until(body, j, lambda l: l.rstrip().endswith("{"))
+                    entries.append(signature(sig).replace("pub fn ", f"pub fn {owner}::", 1))
+                    j = k
+                j += 1
+            i = end + 1
+            continue
+
+        match = ITEM_RE.match(line)
+        if not match or hidden:
+            i += 1
+            continue
+        kind = match.group(1)
+        if kind in ("const", "type", "use"):
+            item, end = collect_until(lines, i, lambda l: l.rstrip().endswith(";"))
+            text = signature(item)
+            if kind == "const":
+                text = re.sub(r"\s*=.*$", "", text)
+            entries.append(text)
+        elif kind in ("fn", "mod"):
+            item, end = collect_until(lines, i, lambda l: l.rstrip().endswith(("{", ";")))
+            entries.append(signature(item))
+        else:
+            name = re.match(r"pub (?:struct|enum) ([A-Za-z0-9_]+)", line).group(1)
+            marker = " #[non_exhaustive]" if non_exhaustive else ""
+            entries.append(f"pub {kind} {name}{marker}")
+            if line.rstrip().endswith((";", "{}")):
+                end = i
+            else:
+                item, end = collect_until(lines, i, lambda l: l == "}")
+                body = "\n".join(strip_line_comment(l) for l in item[1:-1])
+                for member in split_top_level(body):
+                    if kind == "struct" and member.startswith("pub "):
+                        entries.append(f"pub {name}::{member[len('pub '):]}")
+                    elif kind == "enum":
+                        entries.append(f"pub {name}::{member}")
+        i = end + 1
+    return entries
+
+
+def render(entries: list[str]) -> str:
+    return "".join(f"{entry}\n" for entry in entries)
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description="Check the stwo-interop-rs public API snapshot")
+    parser.add_argument("--update", action="store_true", help="rewrite public_api.txt")
+    args = parser.parse_args()
+
+    current = render(extract_public_api(LIB_PATH.read_text(encoding="utf-8")))
+    if args.update:
+        SNAPSHOT_PATH.write_text(current, encoding="utf-8")
+        return 0
+
+    expected = SNAPSHOT_PATH.read_text(encoding="utf-8") if SNAPSHOT_PATH.exists() else ""
+    if current == expected:
+        return 0
+    sys.stderr.write(
+        "stwo-interop-rs public API changed; if intended, run "
+        "`python3 scripts/check_interop_public_api.py --update` and review the semver impact:\n"
+    )
+    sys.stderr.writelines(
+        difflib.unified_diff(
+            expected.splitlines(keepends=True),
+            current.splitlines(keepends=True),
+            fromfile="public_api.txt",
+            tofile="src/lib.rs",
+        )
+    )
+    return 1
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""Find where two stwo-interop-rs `--dump-transcript` files diverge.
+
+Each dump holds one JSON object per line, `{"index", "step", "digest"}`, with the channel digest
+after every channel interaction of an example driver. Dump the prover side with
+`--mode generate --dump-transcript` and the verifier side with `--mode verify --dump-transcript`
+on the same artifact; the first step whose name or digest differs is where the two transcripts
+part ways. Exits with 0 when the dumps agree and 1 otherwise.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from pathlib import Path
+from typing import Any
+
+
+def load_transcript(path: Path) -> list[dict[str, Any]]:
+    steps = []
+    for line_number, line in enumerate(path.read_text(encoding="utf-8").splitlines(), start=1):
+        if not line.strip():
+            continue
+        try:
+            steps.append(json.loads(line))
+        except json.JSONDecodeError as exc:
+            raise ValueError(f"{path}:{line_number}: invalid transcript line: {exc}") from exc
+    return steps
+
+
+def first_mismatch(
+    left: list[dict[str, Any]], right: list[dict[str, Any]]
+) -> dict[str, Any] | None:
+    """Returns the first differing step of two transcripts, or None when they agree.
+
+    A transcript that stops early mismatches at the first step only the other one records.
+    """
+    for index in range(max(len(left), len(right))):
+        left_step = left[index] if index < len(left) else None
+        right_step = right[index] if index < len(right) else None
+        if left_step is None or right_step is None:
+            return {"index": index, "left": left_step, "right": right_step}
+        if (left_step.get("step"), left_step.get("digest")) != (
+            right_step.get("step"),
+            right_step.get("digest"),
+        ):
+            return {"index": index, "left": left_step, "right": right_step}
+    return None
+
+
+def describe(step: dict[str, Any] | None) -> str:
+    if step is None:
+        return "<missing>"
+    return f"{step.get('step')} {step.get('digest')}"
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("left", type=Path, help="first transcript dump, e.g. the generate side")
+    parser.add_argument("right", type=Path, help="second transcript dump, e.g. the verify side")
+    args = parser.parse_args()
+
+    left = load_transcript(args.left)
+    right = load_transcript(args.right)
+    mismatch = first_mismatch(left, right)
+    if mismatch is None:
+        print(f"transcripts match ({len(left)} steps)")
+        return 0
+    print(f"first mismatch at step {mismatch['index']}:")
+    print(f"  {args.left}: {describe(mismatch['left'])}")
+    print(f"  {args.right}: {describe(mismatch['right'])}")
+    return 1
+
+
+if __name__ == "__main__":
+    sys.exit(main())
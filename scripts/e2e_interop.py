@@ -2,7 +2,8 @@
 """Cross-language interoperability gate for proof exchange artifacts.
 
 This gate enforces true bidirectional exchange for the `blake`, `plonk`,
-`poseidon`, `xor`, `state_machine`, and `wide_fibonacci` example wrappers:
+`poseidon`, `xor`, `state_machine`, `state_machine_pair`, and `wide_fibonacci`
+example wrappers:
 1. Rust-generated proof artifact verifies in Zig.
 2. Zig-generated proof artifact verifies in Rust.
 3. Tampered artifacts are rejected in both directions.
@@ -29,13 +30,77 @@ RUST_MANIFEST = ROOT / "tools" / "stwo-interop-rs" / "Cargo.toml"
 RUST_TOOLCHAIN_DEFAULT = "nightly-2025-07-14"
 UPSTREAM_COMMIT = "a8fcf4bdde3778ae72f1e6cfe61a38e2911648d2"
 SCHEMA_VERSION = 1
+# Artifact schema each generator writes: Rust writes v2, Zig still writes v1 (no generator_version).
+ARTIFACT_SCHEMA_VERSIONS = {"rust": 2, "zig": 1}
 EXCHANGE_MODE = "proof_exchange_json_wire_v1"
-SUPPORTED_EXAMPLES = ("blake", "plonk", "poseidon", "xor", "state_machine", "wide_fibonacci")
+SUPPORTED_EXAMPLES = (
+    "blake",
+    "plonk",
+    "poseidon",
+    "xor",
+    "state_machine",
+    "state_machine_pair",
+    "wide_fibonacci",
+)
 M31_MODULUS = 2147483647
 REJECTION_CLASS_VERIFIER = "verifier_semantic"
 REJECTION_CLASS_PARSER = "parser"
 REJECTION_CLASS_METADATA = "metadata_policy"
 REJECTION_CLASS_OTHER = "other"
+# Rejection class of each `kind` the Rust verifier reports on failure (its `InteropError`). The
+# zero-bit nonce policy is where `pow_failed` comes from in this gate, so it stays a policy class.
+REJECTION_CLASS_BY_KIND = {
+    "schema_mismatch": REJECTION_CLASS_METADATA,
+    "checksum_mismatch": REJECTION_CLASS_PARSER,
+    "decode_error": REJECTION_CLASS_PARSER,
+    "statement_invalid": REJECTION_CLASS_VERIFIER,
+    "proof_shape_invalid": REJECTION_CLASS_VERIFIER,
+    "merkle_root_mismatch": REJECTION_CLASS_VERIFIER,
+    "fri_verification_failed": REJECTION_CLASS_VERIFIER,
+    "pow_failed": REJECTION_CLASS_METADATA,
+    "oods_mismatch": REJECTION_CLASS_VERIFIER,
+    "other": REJECTION_CLASS_OTHER,
+}
+# A nonce no prover emits at pow_bits = 0 (both grind from 0). The Rust verifier rejects it by
+# policy; the Zig verifier rejects it because the nonce is mixed into the query-sampling channel.
+POW_GARBAGE_NONCE = 0xDEADBEEFCAFEF00D
+TAMPER_KIND_POW_NONCE_GARBAGE_AT_ZERO_BITS = "pow_nonce_garbage_at_zero_bits"
+# Rust-side decode checks against the declared `proof_bytes_len` (Zig artifacts omit the field, so
+# `zig_to_rust_verify` already covers the legacy path).
+TAMPER_KIND_PROOF_BYTES_LEN_MISMATCH = "proof_bytes_len_mismatch"
+TAMPER_KIND_PROOF_BYTES_HEX_ODD_LENGTH = "proof_bytes_hex_odd_length"
+# Fields of the Rust artifact's `component_metadata`, each tampered on its own; verify must reject
+# the mismatch before running the verifier.
+COMPONENT_METADATA_FIELDS = (
+    "n_constraints",
+    "max_constraint_log_degree_bound",
+    "preprocessed_column_indices",
+    "mask_shape",
+)
+# `LimitKind` values of the capabilities `limits` table.
+LIMIT_KINDS = ("protocol_bound", "implementation_cap", "resource_guard")
+# Statement-binding matrix: extra Rust artifacts with non-default statements, cross-verified
+# together with the Zig artifact (default statement) of the same example.
+CROSS_VERIFY_STATEMENT_ARGS = {
+    "state_machine": [["--sm-initial-0", "10"], ["--sm-initial-1", "4"]],
+    "wide_fibonacci": [["--wf-sequence-len", "17"], ["--wf-log-n-rows", "6"]],
+}
+# `expected_verify_result` values: "ok" or the Rust verify stage a negative fixture must fail at.
+EXPECTED_VERIFY_OK = "ok"
+VERIFY_STAGE_COMPONENT_METADATA = "component_metadata"
+VERIFY_STAGE_METADATA = "metadata"
+VERIFY_STAGE_POW_POLICY = "pow_policy"
+VERIFY_STAGE_PROOF_DECODE = "proof_decode"
+VERIFY_STAGE_VERIFY = "verify"
+# Stages of the Rust generate-mode `--timings-out` summary.
+GENERATE_TIMING_STAGES = (
+    "trace_generation",
+    "commit",
+    "prove",
+    "wire_encode",
+    "hex_encode",
+    "write",
+)
 
 
 def rel(path: Path) -> str:
@@ -82,6 +147,9 @@ def run_step(
         "stderr_tail": trim_tail(proc.stderr),
     }
     if expect_failure:
+        kind = verify_failure_kind(step["stdout_tail"])
+        if kind is not None:
+            step["rejection_kind"] = kind
         step["rejection_class"] = classify_rejection(step["stdout_tail"], step["stderr_tail"])
     steps.append(step)
     if succeeded:
@@ -97,10 +165,62 @@ def run_step(
     )
 
 
+def check_expected_verify_result(step: dict[str, Any]) -> str:
+    """Compares a Rust verify step's actual outcome with the fixture's expected_verify_result.
+
+    Passes only on an exact match: a negative fixture must fail, and at the expected stage.
+    Returns the matched result; raises with an "unexpectedly verified" or "classification
+    drift" message otherwise.
+    """
+    name = step["name"]
+    report = None
+    for line in reversed(step["stdout_tail"].strip().splitlines()):
+        try:
+            report = json.loads(line)
+            break
+        except json.JSONDecodeError:
+            continue
+    if not isinstance(report, dict) or "expected_verify_result" not in report:
+        raise RuntimeError(f"{name}: verify report carries no expected_verify_result")
+
+    expected = report["expected_verify_result"]
+    actual = report.get("actual_verify_result")
+    if step["return_code"] == 0 and actual != EXPECTED_VERIFY_OK:
+        raise RuntimeError(f"{name}: exit code 0 but reported actual_verify_result {actual!r}")
+    if actual == expected:
+        return actual
+    if actual == EXPECTED_VERIFY_OK:
+        raise RuntimeError(f"{name}: negative fixture unexpectedly verified (expected failure at {expected})")
+    if expected == EXPECTED_VERIFY_OK:
+        raise RuntimeError(f"{name}: expected the fixture to verify, failed at {actual}")
+    raise RuntimeError(
+        f"{name}: classification drift: expected failure at {expected}, failed at {actual}"
+    )
+
+
+def verify_failure_kind(stdout_tail: str) -> Optional[str]:
+    """Returns the `kind` of the Rust verifier's `{"status": "fail"}` report, if stdout has one."""
+    for line in reversed(stdout_tail.strip().splitlines()):
+        try:
+            report = json.loads(line)
+        except json.JSONDecodeError:
+            continue
+        if isinstance(report, dict) and report.get("status") == "fail":
+            kind = report.get("kind")
+            return kind if isinstance(kind, str) else None
+    return None
+
+
 def classify_rejection(stdout_tail: str, stderr_tail: str) -> str:
+    # The Rust verifier reports its failure kind; the markers below classify the Zig verifier.
+    kind = verify_failure_kind(stdout_tail)
+    if kind is not None:
+        return REJECTION_CLASS_BY_KIND.get(kind, REJECTION_CLASS_OTHER)
+
     combined = f"{stdout_tail}\n{stderr_tail}".lower()
 
     parser_markers = (
+        "proof_bytes_len_mismatch",
         "syntaxerror",
         "unexpectedtoken",
         "expected value at line",
@@ -115,6 +235,10 @@ def classify_rejection(stdout_tail: str, stderr_tail: str) -> str:
         "unsupportedgenerator",
         "unsupported generator",
         "unknown artifact generator",
+        "pow nonce policy",
+        "component_metadata_mismatch",
+        "preprocessed_columns_mismatch",
+        "preprocessedcolumnsmismatch",
     )
     if any(marker in combined for marker in metadata_markers):
         return REJECTION_CLASS_METADATA
@@ -150,9 +274,10 @@ def assert_artifact_metadata(artifact_path: Path, *, expected_generator: str, ex
     generator = data.get("generator")
     artifact_example = data.get("example")
 
-    if schema_version != SCHEMA_VERSION:
+    expected_schema_version = ARTIFACT_SCHEMA_VERSIONS[expected_generator]
+    if schema_version != expected_schema_version:
         raise RuntimeError(
-            f"{rel(artifact_path)} schema_version mismatch: expected {SCHEMA_VERSION}, got {schema_version}"
+            f"{rel(artifact_path)} schema_version mismatch: expected {expected_schema_version}, got {schema_version}"
         )
     if exchange_mode != EXCHANGE_MODE:
         raise RuntimeError(
@@ -172,6 +297,21 @@ def assert_artifact_metadata(artifact_path: Path, *, expected_generator: str, ex
         )
 
 
+def assert_generate_timings(timings_path: Path, *, example: str) -> None:
+    data = json.loads(timings_path.read_text(encoding="utf-8"))
+    if data.get("example") != example:
+        raise RuntimeError(
+            f"{rel(timings_path)} example mismatch: expected {example}, got {data.get('example')}"
+        )
+    stages = data.get("stages", {})
+    for stage in GENERATE_TIMING_STAGES:
+        seconds = stages.get(stage)
+        if not isinstance(seconds, (int, float)):
+            raise RuntimeError(f"{rel(timings_path)} missing timing stage {stage}")
+        if seconds < 0:
+            raise RuntimeError(f"{rel(timings_path)} negative timing for stage {stage}: {seconds}")
+
+
 def tamper_proof_bytes_hex(src: Path, dst: Path) -> None:
     artifact = json.loads(src.read_text(encoding="utf-8"))
     proof_hex = artifact.get("proof_bytes_hex")
@@ -195,6 +335,9 @@ def tamper_proof_bytes_hex(src: Path, dst: Path) -> None:
         sort_keys=True,
     ).encode("utf-8")
     artifact["proof_bytes_hex"] = mutated_proof_bytes.hex()
+    # The mutation must reach the verifier, so drop the checksum it would otherwise trip.
+    artifact.pop("proof_bytes_blake3", None)
+    artifact["expected_verify_result"] = VERIFY_STAGE_VERIFY
 
     dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
 
@@ -222,7 +365,7 @@ def tamper_statement(src: Path, dst: Path, *, example: str) -> None:
         if not isinstance(stmt, dict):
             raise RuntimeError(f"{rel(src)} missing xor_statement")
         stmt["offset"] = int(stmt.get("offset", 0)) + 1
-    elif example == "state_machine":
+    elif example in ("state_machine", "state_machine_pair"):
         stmt = artifact.get("state_machine_statement")
         if not isinstance(stmt, dict):
             raise RuntimeError(f"{rel(src)} missing state_machine_statement")
@@ -239,6 +382,71 @@ def tamper_statement(src: Path, dst: Path, *, example: str) -> None:
         stmt["sequence_len"] = int(stmt.get("sequence_len", 0)) + 1
     else:
         raise RuntimeError(f"unsupported example for statement tamper: {example}")
+    artifact["expected_verify_result"] = VERIFY_STAGE_VERIFY
+
+    dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
+
+
+def tamper_pow_nonce_at_zero_bits(src: Path, dst: Path) -> None:
+    artifact = json.loads(src.read_text(encoding="utf-8"))
+    pow_bits = int(artifact.get("pcs_config", {}).get("pow_bits", -1))
+    if pow_bits != 0:
+        raise RuntimeError(f"{rel(src)} expected pow_bits = 0 for nonce tamper, got {pow_bits}")
+
+    proof_bytes = bytes.fromhex(artifact["proof_bytes_hex"])
+    proof_wire = json.loads(proof_bytes.decode("utf-8"))
+    proof_wire["proof_of_work"] = POW_GARBAGE_NONCE
+    mutated_proof_bytes = json.dumps(
+        proof_wire,
+        separators=(",", ":"),
+        sort_keys=True,
+    ).encode("utf-8")
+    artifact["proof_bytes_hex"] = mutated_proof_bytes.hex()
+    artifact.pop("proof_bytes_blake3", None)
+    artifact["expected_verify_result"] = VERIFY_STAGE_POW_POLICY
+
+    dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
+
+
+def tamper_proof_bytes_len(src: Path, dst: Path, *, kind: str) -> None:
+    artifact = json.loads(src.read_text(encoding="utf-8"))
+    proof_hex = artifact.get("proof_bytes_hex")
+    declared_len = artifact.get("proof_bytes_len")
+    if not isinstance(proof_hex, str) or len(proof_hex) == 0:
+        raise RuntimeError(f"{rel(src)} missing proof_bytes_hex")
+    if not isinstance(declared_len, int):
+        raise RuntimeError(f"{rel(src)} missing proof_bytes_len")
+
+    if kind == TAMPER_KIND_PROOF_BYTES_LEN_MISMATCH:
+        artifact["proof_bytes_len"] = declared_len + 1
+    elif kind == TAMPER_KIND_PROOF_BYTES_HEX_ODD_LENGTH:
+        artifact["proof_bytes_hex"] = proof_hex[:-1]
+    else:
+        raise RuntimeError(f"unsupported proof_bytes_len tamper kind: {kind}")
+    artifact["expected_verify_result"] = VERIFY_STAGE_PROOF_DECODE
+
+    dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
+
+
+def tamper_component_metadata(src: Path, dst: Path, *, field: str) -> None:
+    artifact = json.loads(src.read_text(encoding="utf-8"))
+    metadata = artifact.get("component_metadata")
+    if metadata is None and artifact.get("components"):
+        # Multi-component artifacts record metadata per component; tamper the first.
+        metadata = artifact["components"][0].get("metadata")
+    if not isinstance(metadata, dict):
+        raise RuntimeError(f"{rel(src)} missing component_metadata")
+
+    if field in ("n_constraints", "max_constraint_log_degree_bound"):
+        metadata[field] += 1
+    elif field == "preprocessed_column_indices":
+        indices = metadata[field]
+        metadata[field] = indices[:-1] if indices else [0]
+    elif field == "mask_shape":
+        metadata[field] = [*metadata[field], [1]]
+    else:
+        raise RuntimeError(f"unsupported component_metadata field: {field}")
+    artifact["expected_verify_result"] = VERIFY_STAGE_COMPONENT_METADATA
 
     dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
 
@@ -255,9 +463,95 @@ def tamper_metadata(
         artifact["upstream_commit"] = upstream_commit
     if generator is not None:
         artifact["generator"] = generator
+    artifact["expected_verify_result"] = VERIFY_STAGE_METADATA
     dst.write_text(json.dumps(artifact, indent=2, sort_keys=True) + "\n", encoding="utf-8")
 
 
+def assert_pow_zero_bits_convention(rust_toolchain: str, steps: list[dict[str, Any]]) -> None:
+    step = run_step(
+        name="rust_interop_capabilities",
+        cmd=[
+            "cargo",
+            f"+{rust_toolchain}",
+            "run",
+            "--quiet",
+            "--manifest-path",
+            str(RUST_MANIFEST),
+            "--",
+            "--mode",
+            "capabilities",
+        ],
+        steps=steps,
+    )
+    capabilities = json.loads(step["stdout_tail"])
+    convention = capabilities.get("pow_zero_bits_convention", {})
+    if convention.get("pow_check") != "skipped" or convention.get("required_nonce") != 0:
+        raise RuntimeError(f"unexpected pow_bits = 0 convention: {convention}")
+    problems = capability_limit_problems(capabilities)
+    if problems:
+        raise RuntimeError("invalid capabilities limits: " + "; ".join(problems))
+
+
+def capability_limit_problems(capabilities: dict[str, Any]) -> list[str]:
+    """Every example must publish its parameter bounds, each with a known kind and min <= max."""
+    limits = capabilities.get("limits")
+    if not isinstance(limits, list):
+        return ["missing limits"]
+    problems = []
+    for index, limit in enumerate(limits):
+        kind = limit.get("kind")
+        if kind not in LIMIT_KINDS:
+            problems.append(f"limits[{index}]: unknown kind {kind!r}")
+        if not limit.get("min", 0) <= limit.get("max", -1):
+            problems.append(f"limits[{index}]: min exceeds max")
+        if not limit.get("reason"):
+            problems.append(f"limits[{index}]: missing reason")
+    covered = {limit.get("example") for limit in limits}
+    for example in capabilities.get("examples", []):
+        if example not in covered:
+            problems.append(f"{example}: no limits listed")
+    return problems
+
+
+def assert_repro_bundle_replays(
+    *,
+    name: str,
+    bundle_dir: Path,
+    replay_bundle_dir: Path,
+    rust_toolchain: str,
+    steps: list[dict[str, Any]],
+) -> dict[str, Any]:
+    manifest = json.loads((bundle_dir / "manifest.json").read_text(encoding="utf-8"))
+    for entry in manifest.get("files", []):
+        if not (bundle_dir / entry["name"]).is_file():
+            raise RuntimeError(f"{name}: repro bundle is missing {entry['name']}")
+
+    shutil.rmtree(replay_bundle_dir, ignore_errors=True)
+    step = run_step(
+        name=name,
+        cmd=[
+            "env",
+            f"RUSTUP_TOOLCHAIN={rust_toolchain}",
+            "sh",
+            str(bundle_dir / "replay.sh"),
+            "--repro-bundle",
+            str(replay_bundle_dir),
+        ],
+        steps=steps,
+        expect_failure=True,
+        required_rejection_class=REJECTION_CLASS_VERIFIER,
+    )
+
+    original = json.loads((bundle_dir / "error.json").read_text(encoding="utf-8"))
+    replayed = json.loads((replay_bundle_dir / "error.json").read_text(encoding="utf-8"))
+    for key in ("failed_stage", "error"):
+        if original.get(key) != replayed.get(key):
+            raise RuntimeError(
+                f"{name}: replay {key} {replayed.get(key)!r} differs from bundle {original.get(key)!r}"
+            )
+    return step
+
+
 def run_example_case(
     *,
     example: str,
@@ -266,6 +560,7 @@ def run_example_case(
     all_steps: list[dict[str, Any]],
 ) -> dict[str, Any]:
     rust_artifact = artifact_dir / f"{example}_rust_to_zig.json"
+    rust_generate_timings = artifact_dir / f"{example}_rust_generate_timings.json"
     zig_artifact = artifact_dir / f"{example}_zig_to_rust.json"
     rust_statement_tampered = artifact_dir / f"{example}_rust_to_zig_statement_tampered.json"
     rust_tampered = artifact_dir / f"{example}_rust_to_zig_tampered.json"
@@ -275,6 +570,18 @@ def run_example_case(
     zig_tampered = artifact_dir / f"{example}_zig_to_rust_tampered.json"
     zig_commit_tampered = artifact_dir / f"{example}_zig_to_rust_commit_tampered.json"
     zig_generator_tampered = artifact_dir / f"{example}_zig_to_rust_generator_tampered.json"
+    rust_pow_nonce_tampered = artifact_dir / f"{example}_rust_to_zig_pow_nonce_tampered.json"
+    zig_pow_nonce_tampered = artifact_dir / f"{example}_zig_to_rust_pow_nonce_tampered.json"
+    rust_len_tampered = {
+        kind: artifact_dir / f"{example}_rust_{kind}.json"
+        for kind in (TAMPER_KIND_PROOF_BYTES_LEN_MISMATCH, TAMPER_KIND_PROOF_BYTES_HEX_ODD_LENGTH)
+    }
+    rust_component_metadata_tampered = {
+        field: artifact_dir / f"{example}_rust_component_metadata_{field}_tampered.json"
+        for field in COMPONENT_METADATA_FIELDS
+    }
+    zig_tampered_bundle = artifact_dir / f"{example}_zig_to_rust_tampered_repro"
+    zig_tampered_replay_bundle = artifact_dir / f"{example}_zig_to_rust_tampered_repro_replay"
 
     start_index = len(all_steps)
 
@@ -293,10 +600,13 @@ def run_example_case(
             example,
             "--artifact",
             str(rust_artifact),
+            "--timings-out",
+            str(rust_generate_timings),
         ],
         steps=all_steps,
     )
     assert_artifact_metadata(rust_artifact, expected_generator="rust", example=example)
+    assert_generate_timings(rust_generate_timings, example=example)
 
     run_step(
         name=f"{example}_rust_to_zig_verify",
@@ -386,6 +696,71 @@ def run_example_case(
         expect_failure=True,
         required_rejection_class=REJECTION_CLASS_METADATA,
     )
+    tamper_pow_nonce_at_zero_bits(rust_artifact, rust_pow_nonce_tampered)
+    rust_to_zig_pow_nonce_tamper_step = run_step(
+        name=f"{example}_rust_to_zig_{TAMPER_KIND_POW_NONCE_GARBAGE_AT_ZERO_BITS}_reject",
+        cmd=[
+            "zig",
+            "run",
+            "src/interop_cli.zig",
+            "--",
+            "--mode",
+            "verify",
+            "--artifact",
+            str(rust_pow_nonce_tampered),
+        ],
+        steps=all_steps,
+        expect_failure=True,
+        required_rejection_class=REJECTION_CLASS_VERIFIER,
+    )
+
+    rust_len_tamper_steps = {}
+    for kind, tampered in rust_len_tampered.items():
+        tamper_proof_bytes_len(rust_artifact, tampered, kind=kind)
+        step = run_step(
+            name=f"{example}_rust_{kind}_reject",
+            cmd=[
+                "cargo",
+                f"+{rust_toolchain}",
+                "run",
+                "--manifest-path",
+                str(RUST_MANIFEST),
+                "--",
+                "--mode",
+                "verify",
+                "--artifact",
+                str(tampered),
+            ],
+            steps=all_steps,
+            expect_failure=True,
+            required_rejection_class=REJECTION_CLASS_PARSER,
+        )
+        check_expected_verify_result(step)
+        rust_len_tamper_steps[kind] = step
+
+    rust_component_metadata_tamper_steps = {}
+    for field, tampered in rust_component_metadata_tampered.items():
+        tamper_component_metadata(rust_artifact, tampered, field=field)
+        step = run_step(
+            name=f"{example}_rust_component_metadata_{field}_tamper_reject",
+            cmd=[
+                "cargo",
+                f"+{rust_toolchain}",
+                "run",
+                "--manifest-path",
+                str(RUST_MANIFEST),
+                "--",
+                "--mode",
+                "verify",
+                "--artifact",
+                str(tampered),
+            ],
+            steps=all_steps,
+            expect_failure=True,
+            required_rejection_class=REJECTION_CLASS_METADATA,
+        )
+        check_expected_verify_result(step)
+        rust_component_metadata_tamper_steps[field] = step
 
     run_step(
         name=f"{example}_zig_generate",
@@ -441,8 +816,10 @@ def run_example_case(
         expect_failure=True,
         required_rejection_class=REJECTION_CLASS_VERIFIER,
     )
+    check_expected_verify_result(zig_to_rust_statement_tamper_step)
 
     tamper_proof_bytes_hex(zig_artifact, zig_tampered)
+    shutil.rmtree(zig_tampered_bundle, ignore_errors=True)
     zig_to_rust_tamper_step = run_step(
         name=f"{example}_zig_to_rust_tamper_reject",
         cmd=[
@@ -456,11 +833,21 @@ def run_example_case(
             "verify",
             "--artifact",
             str(zig_tampered),
+            "--repro-bundle",
+            str(zig_tampered_bundle),
         ],
         steps=all_steps,
         expect_failure=True,
         required_rejection_class=REJECTION_CLASS_VERIFIER,
     )
+    check_expected_verify_result(zig_to_rust_tamper_step)
+    zig_to_rust_tamper_replay_step = assert_repro_bundle_replays(
+        name=f"{example}_zig_to_rust_tamper_repro_replay_reject",
+        bundle_dir=zig_tampered_bundle,
+        replay_bundle_dir=zig_tampered_replay_bundle,
+        rust_toolchain=rust_toolchain,
+        steps=all_steps,
+    )
     tamper_metadata(
         zig_artifact,
         zig_commit_tampered,
@@ -484,6 +871,7 @@ def run_example_case(
         expect_failure=True,
         required_rejection_class=REJECTION_CLASS_METADATA,
     )
+    check_expected_verify_result(zig_to_rust_commit_tamper_step)
     tamper_metadata(zig_artifact, zig_generator_tampered, generator="invalid-generator")
     zig_to_rust_generator_tamper_step = run_step(
         name=f"{example}_zig_to_rust_generator_tamper_reject",
@@ -503,6 +891,27 @@ def run_example_case(
         expect_failure=True,
         required_rejection_class=REJECTION_CLASS_METADATA,
     )
+    check_expected_verify_result(zig_to_rust_generator_tamper_step)
+    tamper_pow_nonce_at_zero_bits(zig_artifact, zig_pow_nonce_tampered)
+    zig_to_rust_pow_nonce_tamper_step = run_step(
+        name=f"{example}_zig_to_rust_{TAMPER_KIND_POW_NONCE_GARBAGE_AT_ZERO_BITS}_reject",
+        cmd=[
+            "cargo",
+            f"+{rust_toolchain}",
+            "run",
+            "--manifest-path",
+            str(RUST_MANIFEST),
+            "--",
+            "--mode",
+            "verify",
+            "--artifact",
+            str(zig_pow_nonce_tampered),
+        ],
+        steps=all_steps,
+        expect_failure=True,
+        required_rejection_class=REJECTION_CLASS_METADATA,
+    )
+    check_expected_verify_result(zig_to_rust_pow_nonce_tamper_step)
 
     return {
         "example": example,
@@ -513,26 +922,119 @@ def run_example_case(
             "rust_to_zig_tampered": rel(rust_tampered),
             "rust_to_zig_commit_tampered": rel(rust_commit_tampered),
             "rust_to_zig_generator_tampered": rel(rust_generator_tampered),
+            "rust_to_zig_pow_nonce_tampered": rel(rust_pow_nonce_tampered),
             "zig_to_rust": rel(zig_artifact),
             "zig_to_rust_statement_tampered": rel(zig_statement_tampered),
             "zig_to_rust_tampered": rel(zig_tampered),
+            "zig_to_rust_tampered_repro_bundle": rel(zig_tampered_bundle),
             "zig_to_rust_commit_tampered": rel(zig_commit_tampered),
             "zig_to_rust_generator_tampered": rel(zig_generator_tampered),
+            "zig_to_rust_pow_nonce_tampered": rel(zig_pow_nonce_tampered),
+            **{f"rust_{kind}": rel(tampered) for kind, tampered in rust_len_tampered.items()},
+            **{
+                f"rust_component_metadata_{field}_tampered": rel(tampered)
+                for field, tampered in rust_component_metadata_tampered.items()
+            },
         },
         "tamper_rejections": {
             "rust_to_zig_statement_tamper": rust_to_zig_statement_tamper_step.get("rejection_class"),
             "rust_to_zig_proof_tamper": rust_to_zig_tamper_step.get("rejection_class"),
             "rust_to_zig_commit_tamper": rust_to_zig_commit_tamper_step.get("rejection_class"),
             "rust_to_zig_generator_tamper": rust_to_zig_generator_tamper_step.get("rejection_class"),
+            f"rust_to_zig_{TAMPER_KIND_POW_NONCE_GARBAGE_AT_ZERO_BITS}": rust_to_zig_pow_nonce_tamper_step.get(
+                "rejection_class"
+            ),
             "zig_to_rust_statement_tamper": zig_to_rust_statement_tamper_step.get("rejection_class"),
             "zig_to_rust_proof_tamper": zig_to_rust_tamper_step.get("rejection_class"),
+            "zig_to_rust_proof_tamper_repro_replay": zig_to_rust_tamper_replay_step.get("rejection_class"),
             "zig_to_rust_commit_tamper": zig_to_rust_commit_tamper_step.get("rejection_class"),
             "zig_to_rust_generator_tamper": zig_to_rust_generator_tamper_step.get("rejection_class"),
+            f"zig_to_rust_{TAMPER_KIND_POW_NONCE_GARBAGE_AT_ZERO_BITS}": zig_to_rust_pow_nonce_tamper_step.get(
+                "rejection_class"
+            ),
+            **{f"rust_{kind}": step.get("rejection_class") for kind, step in rust_len_tamper_steps.items()},
+            **{
+                f"rust_component_metadata_{field}_tamper": step.get("rejection_class")
+                for field, step in rust_component_metadata_tamper_steps.items()
+            },
         },
         "steps": [step["name"] for step in all_steps[start_index:]],
     }
 
 
+def run_cross_verify_case(
+    *,
+    example: str,
+    artifact_dir: Path,
+    rust_toolchain: str,
+    all_steps: list[dict[str, Any]],
+) -> dict[str, Any]:
+    cross_dir = artifact_dir / f"{example}_cross_verify"
+    shutil.rmtree(cross_dir, ignore_errors=True)
+    cross_dir.mkdir(parents=True)
+    shutil.copyfile(artifact_dir / f"{example}_zig_to_rust.json", cross_dir / "zig_default.json")
+
+    for index, statement_args in enumerate(CROSS_VERIFY_STATEMENT_ARGS[example]):
+        run_step(
+            name=f"{example}_cross_verify_generate_{index}",
+            cmd=[
+                "cargo",
+                f"+{rust_toolchain}",
+                "run",
+                "--manifest-path",
+                str(RUST_MANIFEST),
+                "--",
+                "--mode",
+                "generate",
+                "--example",
+                example,
+                "--artifact",
+                str(cross_dir / f"rust_statement_{index}.json"),
+                *statement_args,
+            ],
+            steps=all_steps,
+        )
+
+    step = run_step(
+        name=f"{example}_cross_verify",
+        cmd=[
+            "cargo",
+            f"+{rust_toolchain}",
+            "run",
+            "--manifest-path",
+            str(RUST_MANIFEST),
+            "--",
+            "--mode",
+            "cross-verify",
+            "--artifacts",
+            str(cross_dir),
+        ],
+        steps=all_steps,
+    )
+    report = json.loads(step["stdout_tail"].strip().splitlines()[-1])
+    groups = report["groups"]
+    n_artifacts = len(CROSS_VERIFY_STATEMENT_ARGS[example]) + 1
+    if len(groups) != 1 or len(groups[0]["artifacts"]) != n_artifacts:
+        raise RuntimeError(
+            f"{example}_cross_verify: expected one group of {n_artifacts} artifacts, got {groups}"
+        )
+    group = groups[0]
+    return {
+        "example": example,
+        "artifacts_dir": rel(cross_dir),
+        "artifacts": group["artifacts"],
+        "results": group["results"],
+        "off_diagonal_failure_stages": sorted(
+            {
+                result
+                for result_row, same_row in zip(group["results"], group["same_statement"])
+                for result, same in zip(result_row, same_row)
+                if not same
+            }
+        ),
+    }
+
+
 def write_report(report_out: Path, report: dict[str, Any]) -> None:
     report_out.parent.mkdir(parents=True, exist_ok=True)
     report_out.write_text(json.dumps(report, indent=2, sort_keys=True) + "\n", encoding="utf-8")
@@ -571,7 +1073,7 @@ def compute_summary(
         rejection_class = str(step.get("rejection_class", REJECTION_CLASS_OTHER))
         tamper_rejection_counts[rejection_class] = tamper_rejection_counts.get(rejection_class, 0) + 1
 
-    tamper_cases_total = len(examples) * 8
+    tamper_cases_total = len(examples) * 13
     tamper_cases_executed = len(tamper_steps)
     tamper_cases_passed = len([step for step in tamper_steps if step.get("status") == "ok"])
     tamper_cases_failed = tamper_cases_executed - tamper_cases_passed
@@ -633,6 +1135,7 @@ def main() -> int:
 
     steps: list[dict[str, Any]] = []
     cases: list[dict[str, Any]] = []
+    cross_verify: list[dict[str, Any]] = []
     failure: Optional[dict[str, Any]] = None
     started_at = time.time()
 
@@ -651,6 +1154,7 @@ def main() -> int:
             ],
             steps=steps,
         )
+        assert_pow_zero_bits_convention(args.rust_toolchain, steps)
 
         run_step(
             name="zig_interop_proof_wire_test",
@@ -684,6 +1188,17 @@ def main() -> int:
             )
             cases.append(case)
 
+        for example in args.examples:
+            if example in CROSS_VERIFY_STATEMENT_ARGS:
+                cross_verify.append(
+                    run_cross_verify_case(
+                        example=example,
+                        artifact_dir=artifact_dir,
+                        rust_toolchain=args.rust_toolchain,
+                        all_steps=steps,
+                    )
+                )
+
         status = "ok"
     except Exception as exc:  # pylint: disable=broad-except
         status = "failed"
@@ -697,6 +1212,7 @@ def main() -> int:
         "rust_toolchain": args.rust_toolchain,
         "summary": compute_summary(examples=list(args.examples), steps=steps),
         "cases": cases,
+        "cross_verify": cross_verify,
         "steps": steps,
         "artifacts": {
             "artifact_dir": rel(artifact_dir),
@@ -3,7 +3,8 @@
 
 Default mode:
 - Regenerate vectors into a temporary file.
-- Compare with committed vectors/fields.json.
+- Compare with committed vectors/fields.json, ignoring the wall-clock
+  `generation_stats` section.
 - Fail on mismatch.
 - Run `zig build test` unless --skip-zig is passed.
 
@@ -45,6 +46,7 @@ def run_generator(out_path: Path, count: int) -> None:
             str(out_path),
             "--count",
             str(count),
+            "--self-verify",
         ]
     )
 
@@ -54,6 +56,13 @@ def load_json(path: Path) -> object:
         return json.load(f)
 
 
+def strip_generation_stats(vectors: object) -> object:
+    if isinstance(vectors, dict):
+        vectors = dict(vectors)
+        vectors.pop("generation_stats", None)
+    return vectors
+
+
 def main() -> int:
     parser = argparse.ArgumentParser(description="Field parity gate")
     parser.add_argument("--count", type=int, default=256)
@@ -83,8 +92,8 @@ def main() -> int:
             )
             return 1
 
-        committed_json = load_json(COMMITTED)
-        generated_json = load_json(TMP)
+        committed_json = strip_generation_stats(load_json(COMMITTED))
+        generated_json = strip_generation_stats(load_json(TMP))
         TMP.unlink(missing_ok=True)
         if committed_json != generated_json:
             print(
@@ -34,6 +34,16 @@ REJECTION_CLASS_VERIFIER = "verifier_semantic"
 REJECTION_CLASS_PARSER = "parser"
 REJECTION_CLASS_METADATA = "metadata_policy"
 
+# Case flags that carry statement parameters, by example, mapped to batch manifest fields.
+STATEMENT_FLAGS = {
+    "blake": {"blake-log-n-rows": "log_n_rows", "blake-n-rounds": "n_rounds"},
+    "plonk": {"plonk-log-n-rows": "log_n_rows"},
+    "poseidon": {"poseidon-log-n-instances": "log_n_instances"},
+    "xor": {"xor-log-size": "log_size", "xor-log-step": "log_step", "xor-offset": "offset"},
+    "state_machine": {"sm-log-n-rows": "log_n_rows"},
+    "wide_fibonacci": {"wf-log-n-rows": "log_n_rows", "wf-sequence-len": "sequence_len"},
+}
+
 
 @dataclass(frozen=True)
 class Case:
@@ -342,6 +352,7 @@ def tamper_proof_bytes_hex(artifact_path: Path, out_path: Path) -> None:
     commitments[0][0] = (int(commitments[0][0]) + 1) % 256
 
     artifact["proof_bytes_hex"] = json.dumps(proof_wire, separators=(",", ":")).encode("utf-8").hex()
+    artifact.pop("proof_bytes_blake3", None)
     write_artifact(out_path, artifact)
 
 
@@ -351,15 +362,29 @@ def tamper_prove_mode(artifact_path: Path, out_path: Path) -> None:
     write_artifact(out_path, artifact)
 
 
-def rust_generate_cmd(
-    *,
-    toolchain: str,
-    example: str,
-    artifact_path: Path,
-    prove_mode: str,
-    args: dict[str, str],
-) -> list[str]:
-    cmd = [
+def batch_manifest_entry(case: Case, prove_mode: str, artifact_path: Path) -> dict[str, Any]:
+    statement: dict[str, Any] = {"example": case.example}
+    for flag, field in STATEMENT_FLAGS[case.example].items():
+        statement[field] = int(case.args[flag])
+    if case.example == "state_machine":
+        statement["initial_state"] = [int(case.args["sm-initial-0"]), int(case.args["sm-initial-1"])]
+    return {
+        "statement": statement,
+        "pcs_config": {
+            "pow_bits": int(case.args["pow-bits"]),
+            "fri_config": {
+                "log_blowup_factor": int(case.args["fri-log-blowup"]),
+                "log_last_layer_degree_bound": int(case.args["fri-log-last-layer"]),
+                "n_queries": int(case.args["fri-n-queries"]),
+            },
+        },
+        "prove_mode": prove_mode,
+        "output": str(artifact_path),
+    }
+
+
+def rust_batch_generate_cmd(*, toolchain: str, manifest_path: Path) -> list[str]:
+    return [
         "cargo",
         f"+{toolchain}",
         "run",
@@ -367,17 +392,10 @@ def rust_generate_cmd(
         str(RUST_MANIFEST),
         "--",
         "--mode",
-        "generate",
-        "--example",
-        example,
-        "--artifact",
-        str(artifact_path),
-        "--prove-mode",
-        prove_mode,
+        "batch-generate",
+        "--manifest",
+        str(manifest_path),
     ]
-    for key, value in args.items():
-        cmd.extend([f"--{key}", value])
-    return cmd
 
 
 def rust_verify_cmd(*, toolchain: str, artifact_path: Path) -> list[str]:
@@ -422,33 +440,27 @@ def main() -> int:
     steps: list[dict[str, Any]] = []
     case_reports: list[dict[str, Any]] = []
 
+    # Every Rust artifact comes from one batch-generate process, which shares twiddles between
+    # cases over the same domain size.
+    manifest_entries = []
+    for case in CASES:
+        for prove_mode in ("prove", "prove_ex"):
+            artifact_path = artifact_dir / f"{case.case_id}_{prove_mode}_rust.json"
+            manifest_entries.append(batch_manifest_entry(case, prove_mode, artifact_path))
+    manifest_path = artifact_dir / "batch_generate_manifest.json"
+    with manifest_path.open("w", encoding="utf-8") as f:
+        json.dump({"entries": manifest_entries}, f, indent=2)
+        f.write("\n")
+    run_step(
+        name="batch_generate_rust",
+        cmd=rust_batch_generate_cmd(toolchain=args.rust_toolchain, manifest_path=manifest_path),
+        steps=steps,
+    )
+
     for case in CASES:
         prove_artifact = artifact_dir / f"{case.case_id}_prove_rust.json"
         prove_ex_artifact = artifact_dir / f"{case.case_id}_prove_ex_rust.json"
 
-        run_step(
-            name=f"{case.case_id}_prove_generate_rust",
-            cmd=rust_generate_cmd(
-                toolchain=args.rust_toolchain,
-                example=case.example,
-                artifact_path=prove_artifact,
-                prove_mode="prove",
-                args=case.args,
-            ),
-            steps=steps,
-        )
-        run_step(
-            name=f"{case.case_id}_prove_ex_generate_rust",
-            cmd=rust_generate_cmd(
-                toolchain=args.rust_toolchain,
-                example=case.example,
-                artifact_path=prove_ex_artifact,
-                prove_mode="prove_ex",
-                args=case.args,
-            ),
-            steps=steps,
-        )
-
         run_step(
             name=f"{case.case_id}_prove_verify_zig",
             cmd=zig_verify_cmd(artifact_path=prove_artifact),
@@ -0,0 +1,74 @@
+#!/usr/bin/env python3
+"""Unit tests for the interop transcript diff."""
+
+from __future__ import annotations
+
+import importlib.util
+import json
+import tempfile
+import unittest
+from pathlib import Path
+
+
+ROOT = Path(__file__).resolve().parents[2]
+MODULE_PATH = ROOT / "scripts" / "diff_transcripts.py"
+
+
+def load_module():
+    spec = importlib.util.spec_from_file_location("diff_transcripts", MODULE_PATH)
+    if spec is None or spec.loader is None:
+        raise RuntimeError(f"failed to load module from {MODULE_PATH}")
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+def transcript(*steps: tuple[str, str]) -> list[dict[str, object]]:
+    return [
+        {"index": index, "step": step, "digest": digest}
+        for index, (step, digest) in enumerate(steps)
+    ]
+
+
+class FirstMismatchTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+
+    def test_identical_transcripts_match(self) -> None:
+        steps = transcript(("config_mix", "aa"), ("main_trace_commit", "bb"))
+        self.assertIsNone(self.mod.first_mismatch(steps, list(steps)))
+
+    def test_reports_the_first_differing_digest(self) -> None:
+        left = transcript(("config_mix", "aa"), ("sampled_values_mix", "bb"), ("queries_draw", "cc"))
+        right = transcript(("config_mix", "aa"), ("sampled_values_mix", "bd"), ("queries_draw", "ce"))
+        mismatch = self.mod.first_mismatch(left, right)
+        self.assertEqual(mismatch["index"], 1)
+        self.assertEqual(mismatch["left"]["step"], "sampled_values_mix")
+        self.assertEqual(mismatch["right"]["digest"], "bd")
+
+    def test_reports_differing_step_names(self) -> None:
+        left = transcript(("config_mix", "aa"), ("statement_mix", "bb"))
+        right = transcript(("config_mix", "aa"), ("stmt0_mix", "bb"))
+        self.assertEqual(self.mod.first_mismatch(left, right)["index"], 1)
+
+    def test_a_shorter_transcript_mismatches_where_it_stops(self) -> None:
+        left = transcript(("config_mix", "aa"), ("queries_draw", "bb"))
+        mismatch = self.mod.first_mismatch(left, left[:1])
+        self.assertEqual(mismatch["index"], 1)
+        self.assertIsNone(mismatch["right"])
+
+    def test_loads_json_lines_and_skips_blank_lines(self) -> None:
+        steps = transcript(("config_mix", "aa"), ("queries_draw", "bb"))
+        with tempfile.TemporaryDirectory() as tmp:
+            path = Path(tmp) / "dump.jsonl"
+            path.write_text(
+                "\n".join(json.dumps(step) for step in steps) + "\n\n", encoding="utf-8"
+            )
+            self.assertEqual(self.mod.load_transcript(path), steps)
+            path.write_text("{not json\n", encoding="utf-8")
+            with self.assertRaisesRegex(ValueError, "dump.jsonl:1"):
+                self.mod.load_transcript(path)
+
+
+if __name__ == "__main__":
+    unittest.main()
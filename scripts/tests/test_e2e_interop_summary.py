@@ -4,6 +4,8 @@
 from __future__ import annotations
 
 import importlib.util
+import json
+import tempfile
 import unittest
 from pathlib import Path
 
@@ -51,7 +53,7 @@ class ComputeSummaryTests(unittest.TestCase):
         self.assertEqual(summary["cases_executed"], 4)
         self.assertEqual(summary["cases_passed"], 4)
         self.assertEqual(summary["cases_failed"], 0)
-        self.assertEqual(summary["tamper_cases_total"], 16)
+        self.assertEqual(summary["tamper_cases_total"], 26)
         self.assertEqual(summary["tamper_cases_executed"], 2)
         self.assertEqual(summary["tamper_cases_passed"], 2)
         self.assertEqual(summary["tamper_cases_failed"], 0)
@@ -87,7 +89,7 @@ class ComputeSummaryTests(unittest.TestCase):
         self.assertEqual(summary["cases_executed"], 2)
         self.assertEqual(summary["cases_passed"], 1)
         self.assertEqual(summary["cases_failed"], 1)
-        self.assertEqual(summary["tamper_cases_total"], 16)
+        self.assertEqual(summary["tamper_cases_total"], 26)
         self.assertEqual(summary["tamper_cases_executed"], 2)
         self.assertEqual(summary["tamper_cases_passed"], 1)
         self.assertEqual(summary["tamper_cases_failed"], 1)
@@ -100,5 +102,299 @@ class ComputeSummaryTests(unittest.TestCase):
         )
 
 
+class ExpectedVerifyResultTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+
+    def step(self, return_code: int, report: dict) -> dict:
+        return {
+            "name": "xor_zig_to_rust_tamper_reject",
+            "return_code": return_code,
+            "stdout_tail": json.dumps(report) + "\n",
+        }
+
+    def test_negative_fixture_failing_at_expected_stage_passes(self) -> None:
+        step = self.step(
+            1,
+            {
+                "status": "failed",
+                "error": "verify failed: OodsNotMatching",
+                "actual_verify_result": "verify",
+                "expected_verify_result": "verify",
+                "matches_expected": True,
+            },
+        )
+        self.assertEqual(self.mod.check_expected_verify_result(step), "verify")
+
+    def test_negative_fixture_that_verifies_fails_the_gate(self) -> None:
+        step = self.step(
+            0,
+            {
+                "status": "ok",
+                "actual_verify_result": "ok",
+                "expected_verify_result": "verify",
+                "matches_expected": False,
+            },
+        )
+        with self.assertRaisesRegex(RuntimeError, "unexpectedly verified"):
+            self.mod.check_expected_verify_result(step)
+
+    def test_negative_fixture_failing_at_wrong_stage_reports_drift(self) -> None:
+        step = self.step(
+            1,
+            {
+                "status": "failed",
+                "error": "unsupported generator invalid-generator",
+                "actual_verify_result": "metadata",
+                "expected_verify_result": "verify",
+                "matches_expected": False,
+            },
+        )
+        with self.assertRaisesRegex(
+            RuntimeError, "classification drift: expected failure at verify, failed at metadata"
+        ):
+            self.mod.check_expected_verify_result(step)
+
+
+class ProofBytesLenTamperTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+        self.tmp = tempfile.TemporaryDirectory()
+        self.addCleanup(self.tmp.cleanup)
+        self.src = Path(self.tmp.name) / "artifact.json"
+        self.dst = Path(self.tmp.name) / "tampered.json"
+
+    def tamper(self, artifact: dict, kind: str) -> dict:
+        self.src.write_text(json.dumps(artifact), encoding="utf-8")
+        self.mod.tamper_proof_bytes_len(self.src, self.dst, kind=kind)
+        return json.loads(self.dst.read_text(encoding="utf-8"))
+
+    def test_mismatched_declared_length(self) -> None:
+        tampered = self.tamper(
+            {"proof_bytes_hex": "0a0b0c", "proof_bytes_len": 3},
+            self.mod.TAMPER_KIND_PROOF_BYTES_LEN_MISMATCH,
+        )
+        self.assertEqual(tampered["proof_bytes_hex"], "0a0b0c")
+        self.assertEqual(tampered["proof_bytes_len"], 4)
+        self.assertEqual(tampered["expected_verify_result"], self.mod.VERIFY_STAGE_PROOF_DECODE)
+
+    def test_odd_length_hex(self) -> None:
+        tampered = self.tamper(
+            {"proof_bytes_hex": "0a0b0c", "proof_bytes_len": 3},
+            self.mod.TAMPER_KIND_PROOF_BYTES_HEX_ODD_LENGTH,
+        )
+        self.assertEqual(tampered["proof_bytes_hex"], "0a0b0")
+        self.assertEqual(tampered["proof_bytes_len"], 3)
+
+    def test_missing_declared_length_is_rejected(self) -> None:
+        with self.assertRaisesRegex(RuntimeError, "missing proof_bytes_len"):
+            self.tamper({"proof_bytes_hex": "0a0b0c"}, self.mod.TAMPER_KIND_PROOF_BYTES_LEN_MISMATCH)
+
+
+class ComponentMetadataTamperTests(unittest.TestCase):
+    METADATA = {
+        "n_constraints": 2,
+        "max_constraint_log_degree_bound": 6,
+        "preprocessed_column_indices": [0, 1],
+        "mask_shape": [[0, 0], [1, 2]],
+    }
+
+    def setUp(self) -> None:
+        self.mod = load_module()
+        self.tmp = tempfile.TemporaryDirectory()
+        self.addCleanup(self.tmp.cleanup)
+        self.src = Path(self.tmp.name) / "artifact.json"
+        self.dst = Path(self.tmp.name) / "tampered.json"
+
+    def tamper(self, artifact: dict, field: str) -> dict:
+        self.src.write_text(json.dumps(artifact), encoding="utf-8")
+        self.mod.tamper_component_metadata(self.src, self.dst, field=field)
+        return json.loads(self.dst.read_text(encoding="utf-8"))
+
+    def test_each_field_is_changed_alone(self) -> None:
+        for field in self.mod.COMPONENT_METADATA_FIELDS:
+            with self.subTest(field=field):
+                tampered = self.tamper({"component_metadata": dict(self.METADATA)}, field)
+                metadata = tampered["component_metadata"]
+                self.assertNotEqual(metadata[field], self.METADATA[field])
+                for other in self.mod.COMPONENT_METADATA_FIELDS:
+                    if other != field:
+                        self.assertEqual(metadata[other], self.METADATA[other])
+                self.assertEqual(
+                    tampered["expected_verify_result"], self.mod.VERIFY_STAGE_COMPONENT_METADATA
+                )
+
+    def test_empty_preprocessed_indices_gain_an_index(self) -> None:
+        metadata = {**self.METADATA, "preprocessed_column_indices": []}
+        tampered = self.tamper({"component_metadata": metadata}, "preprocessed_column_indices")
+        self.assertEqual(tampered["component_metadata"]["preprocessed_column_indices"], [0])
+
+    def test_multi_component_artifacts_tamper_the_first_component(self) -> None:
+        components = [
+            {"name": "state_machine_x_axis", "metadata": dict(self.METADATA)},
+            {"name": "state_machine_y_axis", "metadata": dict(self.METADATA)},
+        ]
+        tampered = self.tamper({"components": components}, "n_constraints")
+        self.assertEqual(tampered["components"][0]["metadata"]["n_constraints"], 3)
+        self.assertEqual(tampered["components"][1]["metadata"], self.METADATA)
+        self.assertNotIn("component_metadata", tampered)
+
+    def test_missing_metadata_is_rejected(self) -> None:
+        with self.assertRaisesRegex(RuntimeError, "missing component_metadata"):
+            self.tamper({"proof_bytes_hex": "0a0b0c"}, "n_constraints")
+
+    def test_mismatch_is_classified_as_metadata(self) -> None:
+        self.assertEqual(
+            self.mod.classify_rejection(
+                "", "Error: component_metadata_mismatch: n_constraints: artifact declares 3"
+            ),
+            self.mod.REJECTION_CLASS_METADATA,
+        )
+
+    def test_preprocessed_columns_mismatch_is_classified_as_metadata(self) -> None:
+        for stderr in (
+            "Error: preprocessed_columns_mismatch: plonk preprocessed columns [0, 1] omit column 3",
+            "error: PreprocessedColumnsMismatch",
+        ):
+            with self.subTest(stderr=stderr):
+                self.assertEqual(
+                    self.mod.classify_rejection("", stderr),
+                    self.mod.REJECTION_CLASS_METADATA,
+                )
+
+
+class RejectionKindTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+
+    @staticmethod
+    def report(kind: str) -> str:
+        return json.dumps({"status": "fail", "kind": kind, "error": "reworded at will"})
+
+    def test_rust_failures_are_classified_by_kind(self) -> None:
+        for kind, expected in (
+            ("schema_mismatch", self.mod.REJECTION_CLASS_METADATA),
+            ("decode_error", self.mod.REJECTION_CLASS_PARSER),
+            ("pow_failed", self.mod.REJECTION_CLASS_METADATA),
+            ("oods_mismatch", self.mod.REJECTION_CLASS_VERIFIER),
+            ("merkle_root_mismatch", self.mod.REJECTION_CLASS_VERIFIER),
+            ("kind_from_a_newer_verifier", self.mod.REJECTION_CLASS_OTHER),
+        ):
+            with self.subTest(kind=kind):
+                stdout = f"progress line\n{self.report(kind)}\n"
+                self.assertEqual(self.mod.verify_failure_kind(stdout), kind)
+                # The kind wins over any marker in the error text.
+                self.assertEqual(
+                    self.mod.classify_rejection(stdout, "Error: verify failed: Oods not matching"),
+                    expected,
+                )
+
+    def test_every_rust_kind_has_a_class(self) -> None:
+        self.assertEqual(
+            set(self.mod.REJECTION_CLASS_BY_KIND),
+            {
+                "schema_mismatch",
+                "checksum_mismatch",
+                "decode_error",
+                "statement_invalid",
+                "proof_shape_invalid",
+                "merkle_root_mismatch",
+                "fri_verification_failed",
+                "pow_failed",
+                "oods_mismatch",
+                "other",
+            },
+        )
+
+    def test_output_without_a_fail_report_falls_back_to_markers(self) -> None:
+        stdout = json.dumps({"status": "ok", "example": "xor"})
+        self.assertIsNone(self.mod.verify_failure_kind(stdout))
+        self.assertEqual(
+            self.mod.classify_rejection(stdout, "error: OodsNotMatching"),
+            self.mod.REJECTION_CLASS_VERIFIER,
+        )
+
+
+class GenerateTimingsTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+        self.tmp = tempfile.TemporaryDirectory()
+        self.addCleanup(self.tmp.cleanup)
+        self.path = Path(self.tmp.name) / "timings.json"
+
+    def check(self, stages: dict) -> None:
+        self.path.write_text(
+            json.dumps({"example": "xor", "total_seconds": 0.5, "stages": stages}),
+            encoding="utf-8",
+        )
+        self.mod.assert_generate_timings(self.path, example="xor")
+
+    def test_all_stages_present_and_non_negative(self) -> None:
+        self.check({stage: 0.0 for stage in self.mod.GENERATE_TIMING_STAGES})
+
+    def test_missing_stage_is_rejected(self) -> None:
+        stages = {stage: 0.1 for stage in self.mod.GENERATE_TIMING_STAGES}
+        del stages["hex_encode"]
+        with self.assertRaisesRegex(RuntimeError, "missing timing stage hex_encode"):
+            self.check(stages)
+
+    def test_negative_stage_is_rejected(self) -> None:
+        stages = {stage: 0.1 for stage in self.mod.GENERATE_TIMING_STAGES}
+        stages["commit"] = -0.01
+        with self.assertRaisesRegex(RuntimeError, "negative timing for stage commit"):
+            self.check(stages)
+
+
+class CapabilityLimitsTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.mod = load_module()
+
+    def capabilities(self) -> dict:
+        return {
+            "examples": ["poseidon", "xor"],
+            "limits": [
+                {
+                    "example": "poseidon",
+                    "param": "log_n_instances",
+                    "min": 8,
+                    "max": 4294967295,
+                    "kind": "implementation_cap",
+                    "reason": "instances packed per row",
+                },
+                {
+                    "example": "xor",
+                    "param": "log_size",
+                    "min": 1,
+                    "max": 30,
+                    "kind": "protocol_bound",
+                    "reason": "circle group order",
+                },
+            ],
+        }
+
+    def test_well_formed_limits_pass(self) -> None:
+        self.assertEqual(self.mod.capability_limit_problems(self.capabilities()), [])
+
+    def test_missing_limits_table_is_reported(self) -> None:
+        capabilities = self.capabilities()
+        del capabilities["limits"]
+        self.assertEqual(self.mod.capability_limit_problems(capabilities), ["missing limits"])
+
+    def test_unknown_kind_and_inverted_bounds_are_reported(self) -> None:
+        capabilities = self.capabilities()
+        capabilities["limits"][1].update(kind="soft_cap", min=31)
+        self.assertEqual(
+            self.mod.capability_limit_problems(capabilities),
+            ["limits[1]: unknown kind 'soft_cap'", "limits[1]: min exceeds max"],
+        )
+
+    def test_example_without_limits_is_reported(self) -> None:
+        capabilities = self.capabilities()
+        capabilities["examples"].append("blake")
+        self.assertEqual(
+            self.mod.capability_limit_problems(capabilities), ["blake: no limits listed"]
+        )
+
+
 if __name__ == "__main__":
     unittest.main()
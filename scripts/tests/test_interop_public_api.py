@@ -0,0 +1,93 @@
+#!/usr/bin/env python3
+"""Unit tests for the stwo-interop-rs public API snapshot check."""
+
+from __future__ import annotations
+
+import importlib.util
+import unittest
+from pathlib import Path
+
+
+ROOT = Path(__file__).resolve().parents[2]
+CHECK_PATH = ROOT / "scripts" / "check_interop_public_api.py"
+
+
+def load_module(path: Path, name: str):
+    spec = importlib.util.spec_from_file_location(name, path)
+    if spec is None or spec.loader is None:
+        raise RuntimeError(f"failed to load module from {path}")
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+class InteropPublicApiTests(unittest.TestCase):
+    def setUp(self) -> None:
+        self.check = load_module(CHECK_PATH, "check_interop_public_api")
+
+    def test_extracts_public_items_and_skips_private_and_hidden(self) -> None:
+        source = """
+/// Doc.
+pub const VERSION: u32 = 1;
+const PRIVATE: u32 = 2;
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Options {
+    /// Doc.
+    pub mode: Mode,
+    hidden: bool,
+}
+
+#[non_exhaustive]
+pub enum Input {
+    Small { log_size: u32 },
+    Large {
+        log_size: u32,
+        pairs: Vec<(u32, u32)>,
+    },
+}
+
+impl Input {
+    pub fn log_size(&self) -> u32 {
+        0
+    }
+
+    fn private(&self) {}
+}
+
+#[doc(hidden)]
+pub fn run_cli(args: Vec<String>) -> Result<()> {
+    Ok(())
+}
+
+pub fn generate(
+    input: Input,
+    options: &Options,
+) -> Result<Vec<u8>> {
+    Ok(Vec::new())
+}
+"""
+        self.assertEqual(
+            self.check.extract_public_api(source),
+            [
+                "pub const VERSION: u32",
+                "pub struct Options #[non_exhaustive]",
+                "pub Options::mode: Mode",
+                "pub enum Input #[non_exhaustive]",
+                "pub Input::Small { log_size: u32 }",
+                "pub Input::Large { log_size: u32, pairs: Vec<(u32, u32)> }",
+                "pub fn Input::log_size(&self) -> u32",
+                "pub fn generate(input: Input, options: &Options) -> Result<Vec<u8>>",
+            ],
+        )
+
+    def test_snapshot_matches_library(self) -> None:
+        current = self.check.render(
+            self.check.extract_public_api(self.check.LIB_PATH.read_text(encoding="utf-8"))
+        )
+        self.assertEqual(current, self.check.SNAPSHOT_PATH.read_text(encoding="utf-8"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -6,6 +6,7 @@ const fri_mod = @import("../fri.zig");
 const pcs_mod = @import("../pcs/mod.zig");
 const pcs_utils_mod = @import("../pcs/utils.zig");
 const proof_mod = @import("../proof.zig");
+const queries_mod = @import("../queries.zig");
 const quotients_mod = @import("../pcs/quotients.zig");
 const canonic_mod = @import("../poly/circle/canonic.zig");
 const line_mod = @import("../poly/line.zig");
@@ -16,8 +17,10 @@ const prover_fri_mod = @import("../../prover/fri.zig");
 const prover_secure_column_mod = @import("../../prover/secure_column.zig");
 const vcs_prover_mod = @import("../../prover/vcs/prover.zig");
 const vcs_lifted_prover_mod = @import("../../prover/vcs_lifted/prover.zig");
+const prover_pcs_mod = @import("../../prover/pcs/mod.zig");
 const prover_line_mod = @import("../../prover/line.zig");
 const example_plonk_mod = @import("../../examples/plonk.zig");
+const example_poseidon_mod = @import("../../examples/poseidon.zig");
 const example_state_machine_mod = @import("../../examples/state_machine.zig");
 const example_wide_fibonacci_mod = @import("../../examples/wide_fibonacci.zig");
 const example_xor_mod = @import("../../examples/xor.zig");
@@ -38,6 +41,7 @@ const ColumnSampleBatch = quotients_mod.ColumnSampleBatch;
 const LineCoeffs = constraints_mod.LineCoeffs;
 
 const M31Vector = struct {
+    id: ?[]const u8 = null,
     a: u32,
     b: u32,
     add: u32,
@@ -48,6 +52,7 @@ const M31Vector = struct {
 };
 
 const CM31Vector = struct {
+    id: ?[]const u8 = null,
     a: [2]u32,
     b: [2]u32,
     add: [2]u32,
@@ -58,6 +63,7 @@ const CM31Vector = struct {
 };
 
 const QM31Vector = struct {
+    id: ?[]const u8 = null,
     a: [4]u32,
     b: [4]u32,
     add: [4]u32,
@@ -67,7 +73,47 @@ const QM31Vector = struct {
     div_ab: [4]u32,
 };
 
+const KnownAnswerFieldVector = struct {
+    name: []const u8,
+    op: []const u8,
+    inputs: [][]u32,
+    expected: []u32,
+};
+
+const KnownAnswerMerkleVector = struct {
+    name: []const u8,
+    leaves: []u32,
+    root: [32]u8,
+};
+
+/// Hand-computed answers; a single object rather than a family of entries with ids.
+const KnownAnswerVectors = struct {
+    field_ops: []KnownAnswerFieldVector,
+    merkle: []KnownAnswerMerkleVector,
+};
+
+const QM31StructureVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    value: [4]u32,
+    complex_conjugate: [4]u32,
+    m31_array: [4]u32,
+    from_m31_array: [4]u32,
+    partial_evals: [4][4]u32,
+    from_partial_evals: [4]u32,
+};
+
+const SecureColumnVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    column: [][4]u32,
+    coordinates: [4][]u32,
+    reconstructed: [][4]u32,
+    leaf_hashes: [][32]u8,
+};
+
 const CircleM31Vector = struct {
+    id: ?[]const u8 = null,
     a_scalar: u64,
     b_scalar: u64,
     log_order_a: u32,
@@ -79,7 +125,18 @@ const CircleM31Vector = struct {
     conjugate_a: [2]u32,
 };
 
+const CircleScalarMulVector = struct {
+    id: ?[]const u8 = null,
+    generator: []const u8,
+    case: []const u8,
+    scalar: []const u8,
+    x: []u32,
+    y: []u32,
+    log_order: ?u32,
+};
+
 const FftM31Vector = struct {
+    id: ?[]const u8 = null,
     a: u32,
     b: u32,
     twid: u32,
@@ -87,7 +144,21 @@ const FftM31Vector = struct {
     ibutterfly: [2]u32,
 };
 
+const FftEdgeVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    a: u32,
+    b: u32,
+    twid: u32,
+    butterfly: [2]u32,
+    itwid: ?u32,
+    ibutterfly: ?[2]u32,
+    ibutterfly_with_twid: [2]u32,
+    undefined: [][]const u8,
+};
+
 const Blake3Vector = struct {
+    id: ?[]const u8 = null,
     data: []u8,
     hash: [32]u8,
     left: [32]u8,
@@ -95,6 +166,31 @@ const Blake3Vector = struct {
     concat_hash: [32]u8,
 };
 
+const Blake2sVector = struct {
+    id: ?[]const u8 = null,
+    data: []u8,
+    hash: [32]u8,
+    node_values: []u32,
+    left: [32]u8,
+    right: [32]u8,
+    hash_node_with_children: [32]u8,
+    hash_node_leaf: [32]u8,
+    lifted_leaf_updates: [][]u32,
+    lifted_leaf_digests: [][32]u8,
+};
+
+const ChannelMixRootVector = struct {
+    id: ?[]const u8 = null,
+    root_pattern: []const u8,
+    prefix_mix_u64: u64,
+    prefix_mix_u32s: []u32,
+    digest_before: [32]u8,
+    root: [32]u8,
+    digest_after: [32]u8,
+    draw_secure_felt: [4]u32,
+    wrong_variant_digests: std.json.ArrayHashMap([32]u8),
+};
+
 const PointSampleVector = struct {
     point: [2][4]u32,
     value: [4]u32,
@@ -123,11 +219,13 @@ const LineCoeffVector = struct {
 };
 
 const PcsQuotientsVector = struct {
+    id: ?[]const u8 = null,
     lifting_log_size: u32,
     column_log_sizes: [][]u32,
     samples: [][][]PointSampleVector,
     random_coeff: [4]u32,
     query_positions: []usize,
+    domain_points: ?[][2]u32 = null,
     queried_values: [][][]u32,
     samples_with_randomness: [][][]SampleWithRandomnessVector,
     sample_batches: []ColumnSampleBatchVector,
@@ -138,14 +236,61 @@ const PcsQuotientsVector = struct {
     fri_answers: [][4]u32,
 };
 
+const SampleProvenanceVector = struct {
+    sample: PointSampleVector,
+    random_coeff: [4]u32,
+    power_index: usize,
+    tree: usize,
+    column: usize,
+    sample_index: usize,
+    periodic: bool,
+};
+
+const SamplesWithRandomnessVector = struct {
+    id: ?[]const u8 = null,
+    lifting_log_size: u32,
+    column_log_sizes: [][]u32,
+    samples: [][][]PointSampleVector,
+    random_coeff: [4]u32,
+    samples_with_randomness: [][][]SampleProvenanceVector,
+};
+
+const DenominatorInversesVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    sample_points: [][2][4]u32,
+    u_perturbations: []?u32,
+    domain_log_size: u32,
+    domain_index: usize,
+    domain_point: [2]u32,
+    denominators: [][2]u32,
+    expected: []const u8,
+    inverses: [][2]u32,
+};
+
 const PcsPreprocessedQueryVector = struct {
+    id: ?[]const u8 = null,
+    case: ?[]const u8 = null,
     query_positions: []usize,
     max_log_size: u32,
     pp_max_log_size: u32,
     expected: []usize,
 };
 
+const FriAnswersErrorVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    lifting_log_size: u32,
+    column_log_sizes: [][]u32,
+    samples: [][][]PointSampleVector,
+    random_coeff: [4]u32,
+    query_positions: []usize,
+    queried_values: [][][]u32,
+    expected: []const u8,
+};
+
 const FriFoldVector = struct {
+    id: ?[]const u8 = null,
     line_log_size: u32,
     line_eval: [][4]u32,
     alpha: [4]u32,
@@ -155,7 +300,78 @@ const FriFoldVector = struct {
     fold_circle_values: [][4]u32,
 };
 
+const FriFoldChainLayerVector = struct {
+    domain_log_size: u32,
+    domain_initial_index: usize,
+    domain_step_size: usize,
+    values: [][4]u32,
+};
+
+const FriFoldChainVector = struct {
+    id: ?[]const u8 = null,
+    circle_log_size: u32,
+    circle_eval: [][4]u32,
+    last_layer_log_size: u32,
+    alphas: [][4]u32,
+    layers: []FriFoldChainLayerVector,
+    last_layer_coeffs_bit_reversed: [][4]u32,
+    last_layer_coeffs_ordered: [][4]u32,
+};
+
+const FriFoldOrderingVector = struct {
+    id: ?[]const u8 = null,
+    fold: []const u8,
+    input_order: []const u8,
+    log_size: u32,
+    domain_initial_index: usize,
+    domain_step_size: usize,
+    alpha: [4]u32,
+    evaluations_bit_reversed: [][4]u32,
+    evaluations_natural: [][4]u32,
+    folded_bit_reversed: [][4]u32,
+    folded_natural: [][4]u32,
+    output_permutation: []usize,
+    misordered_fold: [][4]u32,
+};
+
+const FriLayerCountVector = struct {
+    id: ?[]const u8 = null,
+    column_log_size: u32,
+    log_blowup: u32,
+    log_last_layer_degree_bound: u32,
+    valid: bool,
+    first_layer_domain_log_size: u32,
+    n_inner_layers: u32,
+    last_layer_domain_log_size: u32,
+    last_layer_poly_len: usize,
+    source: []const u8,
+};
+
+const FriLastLayerVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    log_blowup_factor: u32,
+    log_last_layer_degree_bound: u32,
+    last_layer_domain_log_size: u32,
+    coeffs: [][4]u32,
+    query_positions: []usize,
+    query_x: []u32,
+    evaluations: [][4]u32,
+    expected: []const u8,
+};
+
+const LinePolyConstructionVector = struct {
+    id: ?[]const u8 = null,
+    coeff_count: usize,
+    coeffs: [][4]u32,
+    expected: []const u8,
+    log_size: ?u32,
+    eval_point: [4]u32,
+    evaluation: ?[4]u32,
+};
+
 const FriDecommitVector = struct {
+    id: ?[]const u8 = null,
     case: []const u8,
     fold_step: u32,
     column: [][4]u32,
@@ -168,6 +384,7 @@ const FriDecommitVector = struct {
 };
 
 const FriLayerDecommitVector = struct {
+    id: ?[]const u8 = null,
     case: []const u8,
     fold_step: u32,
     column: [][4]u32,
@@ -181,11 +398,45 @@ const FriLayerDecommitVector = struct {
     expected: []const u8,
 };
 
+const FriLayerChainStepVector = struct {
+    log_size: u32,
+    fold_step: u32,
+    column: [][4]u32,
+    query_positions: []usize,
+    commitment: [32]u8,
+    decommitment_positions: []usize,
+    fri_witness: [][4]u32,
+    hash_witness: [][32]u8,
+};
+
+const FriLayerChainDecommitVector = struct {
+    id: ?[]const u8 = null,
+    query_positions: []usize,
+    layers: []FriLayerChainStepVector,
+};
+
+const FriFirstLayerDecommitVector = struct {
+    id: ?[]const u8 = null,
+    log_sizes: []u32,
+    columns: [][][4]u32,
+    query_positions: []usize,
+    column_query_positions: [][]usize,
+    commitment: [32]u8,
+    decommitment_positions: []usize,
+    fri_witness: [][4]u32,
+    hash_witness: [][32]u8,
+};
+
 const ProofExtractOodsVector = struct {
+    id: ?[]const u8 = null,
+    /// Schema 2+; schema-1 entries are all valid extractions from a composition tree.
+    case: []const u8 = "valid",
     composition_log_size: u32,
     oods_point: [2][4]u32,
+    has_composition_tree: bool = true,
     composition_values: [][4]u32,
-    expected: [4]u32,
+    expected: ?[4]u32,
+    expected_error: ?[]const u8 = null,
 };
 
 const ProofSizeBreakdownVector = struct {
@@ -202,7 +453,21 @@ const ProofSizeInnerLayerVector = struct {
     commitment: [32]u8,
 };
 
+const ConfigSecurityVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    pow_bits: u32,
+    log_blowup_factor: u32,
+    log_last_layer_degree_bound: u32,
+    n_queries: usize,
+    security_bits: u32,
+    classification: []const u8,
+    target_bits: ?u32,
+    meets_target: ?bool,
+};
+
 const ProofSizeVector = struct {
+    id: ?[]const u8 = null,
     commitments: [][32]u8,
     sampled_values: [][][][4]u32,
     decommitments: [][][32]u8,
@@ -217,6 +482,7 @@ const ProofSizeVector = struct {
 };
 
 const ProverLineVector = struct {
+    id: ?[]const u8 = null,
     line_log_size: u32,
     values: [][4]u32,
     coeffs_bit_reversed: [][4]u32,
@@ -229,6 +495,7 @@ const VcsLogSizeQueriesVector = struct {
 };
 
 const VcsVerifierVector = struct {
+    id: ?[]const u8 = null,
     case: []const u8,
     root: [32]u8,
     column_log_sizes: []u32,
@@ -240,6 +507,7 @@ const VcsVerifierVector = struct {
 };
 
 const VcsProverVector = struct {
+    id: ?[]const u8 = null,
     root: [32]u8,
     column_log_sizes: []u32,
     columns: [][]u32,
@@ -250,15 +518,19 @@ const VcsProverVector = struct {
 };
 
 const VcsLiftedProverVector = struct {
+    id: ?[]const u8 = null,
+    case: ?[]const u8 = null,
     root: [32]u8,
     column_log_sizes: []u32,
     columns: [][]u32,
     query_positions: []usize,
     queried_values: [][]u32,
     hash_witness: [][32]u8,
+    layer_queries: ?[][]usize = null,
 };
 
 const VcsLiftedVerifierVector = struct {
+    id: ?[]const u8 = null,
     case: []const u8,
     root: [32]u8,
     column_log_sizes: []u32,
@@ -268,7 +540,27 @@ const VcsLiftedVerifierVector = struct {
     expected: []const u8,
 };
 
+const QueriedRowsReshapeVector = struct {
+    id: ?[]const u8 = null,
+    root: [32]u8,
+    column_log_sizes: []u32,
+    query_log_size: u32,
+    query_positions: []usize,
+    hash_witness: [][32]u8,
+    queried_values: [][]u32,
+    source_rows: [][]usize,
+    query_rows: [][]u32,
+};
+
+const VcsLiftedErrorVariantVector = struct {
+    id: ?[]const u8 = null,
+    variant: []const u8,
+    status: []const u8,
+    probes: []VcsLiftedVerifierVector,
+};
+
 const ExampleStateMachineTraceVector = struct {
+    id: ?[]const u8 = null,
     log_size: u32,
     initial_state: [2]u32,
     inc_index: usize,
@@ -276,6 +568,7 @@ const ExampleStateMachineTraceVector = struct {
 };
 
 const ExampleStateMachineTransitionVector = struct {
+    id: ?[]const u8 = null,
     log_n_rows: u32,
     initial_state: [2]u32,
     intermediate_state: [2]u32,
@@ -283,6 +576,7 @@ const ExampleStateMachineTransitionVector = struct {
 };
 
 const ExampleStateMachineClaimedSumVector = struct {
+    id: ?[]const u8 = null,
     log_size: u32,
     initial_state: [2]u32,
     inc_index: usize,
@@ -293,6 +587,7 @@ const ExampleStateMachineClaimedSumVector = struct {
 };
 
 const ExampleStateMachineLookupDrawVector = struct {
+    id: ?[]const u8 = null,
     mix_u64: u64,
     mix_u32s: []u32,
     z: [4]u32,
@@ -300,6 +595,7 @@ const ExampleStateMachineLookupDrawVector = struct {
 };
 
 const ExampleStateMachineStatementVector = struct {
+    id: ?[]const u8 = null,
     log_n_rows: u32,
     initial_state: [2]u32,
     z: [4]u32,
@@ -310,12 +606,35 @@ const ExampleStateMachineStatementVector = struct {
     y_axis_claimed_sum: [4]u32,
 };
 
+const ExampleStateMachineComponentTrace = struct {
+    log_size: u32,
+    inc_index: usize,
+    initial_state: [2]u32,
+    final_state: [2]u32,
+    columns: [][]u32,
+    claimed_sum: [4]u32,
+};
+
+const ExampleStateMachineComponentsVector = struct {
+    id: ?[]const u8 = null,
+    axis_order: []const u8,
+    log_n_rows: u32,
+    initial_state: [2]u32,
+    intermediate_state: [2]u32,
+    final_state: [2]u32,
+    z: [4]u32,
+    alpha: [4]u32,
+    components: []ExampleStateMachineComponentTrace,
+};
+
 const ExampleXorIsFirstVector = struct {
+    id: ?[]const u8 = null,
     log_size: u32,
     values: []u32,
 };
 
 const ExampleXorIsStepWithOffsetVector = struct {
+    id: ?[]const u8 = null,
     log_size: u32,
     log_step: u32,
     offset: usize,
@@ -323,52 +642,300 @@ const ExampleXorIsStepWithOffsetVector = struct {
 };
 
 const ExampleWideFibonacciTraceVector = struct {
+    id: ?[]const u8 = null,
     log_n_rows: u32,
     sequence_len: u32,
     columns: [][]u32,
+    seed_a: ?[]u32 = null,
+    seed_b: ?[]u32 = null,
+    constraints_satisfied: ?[][]bool = null,
 };
 
 const ExamplePlonkTraceVector = struct {
+    id: ?[]const u8 = null,
     log_n_rows: u32,
     preprocessed: [][]u32,
     main: [][]u32,
 };
 
+const BlowupSizingVector = struct {
+    id: ?[]const u8 = null,
+    trace_log_size: u32,
+    constraint_log_degree_increment: u32,
+    log_blowup: u32,
+    max_constraint_log_degree_bound: u32,
+    twiddle_log_size: u32,
+    trace_commitment_log_size: u32,
+    composition_poly_log_size: u32,
+    composition_commitment_log_size: u32,
+    first_fri_layer_log_size: u32,
+};
+
+const CompositionSampledCell = struct {
+    tree: usize,
+    column: usize,
+    mask: usize,
+    value: [4]u32,
+    composition_coordinate: ?struct { []const u8, usize },
+};
+
+const CompositionSampledOrderVector = struct {
+    id: ?[]const u8 = null,
+    composition_log_size: u32,
+    oods_point: [2][4]u32,
+    sampled_values: [][][][4]u32,
+    cells: []CompositionSampledCell,
+    composition_cells: []usize,
+    composition_oods_eval: [4]u32,
+};
+
+const ExamplePoseidonTraceVector = struct {
+    id: ?[]const u8 = null,
+    log_n_rows: u32,
+    columns: [][]u32,
+};
+
+const ExamplePoseidonRoundsVector = struct {
+    id: ?[]const u8 = null,
+    external_round: usize,
+    internal_round: usize,
+    input: []u32,
+    external_round_constants: []u32,
+    internal_round_constant: u32,
+    after_add_external_round_constants: []u32,
+    after_add_internal_round_constant: []u32,
+    after_apply_m4: []u32,
+    after_external_round_matrix: []u32,
+    after_internal_round_matrix: []u32,
+    after_pow5: []u32,
+};
+
+const PcsCommitTreeVector = struct {
+    column_log_sizes: []u32,
+    columns: [][]u32,
+    sorted_column_order: []usize,
+    n_layers: usize,
+    root: [32]u8,
+};
+
+const PcsCommitVector = struct {
+    id: ?[]const u8 = null,
+    case: []const u8,
+    log_blowup_factor: u32,
+    trees: []PcsCommitTreeVector,
+};
+
+const E2eTreeShapeVector = struct {
+    tree: []const u8,
+    n_columns: usize,
+    column_log_sizes: []u32,
+    commitment: [32]u8,
+    sampled_values_shape: []usize,
+    queried_values_shape: []usize,
+    hash_witness_len: usize,
+};
+
+const E2eTreeShapesVector = struct {
+    id: ?[]const u8 = null,
+    log_size: u32,
+    initial_state: [2]u32,
+    log_blowup_factor: u32,
+    n_queries: usize,
+    trees: []E2eTreeShapeVector,
+};
+
+const ChannelMixVector = struct {
+    op: []const u8,
+    value_u64: ?u64,
+    values_u32: ?[]u32,
+    felts: ?[][4]u32,
+    root: ?[32]u8,
+};
+
+const OodsDrawStepVector = struct {
+    t: [4]u32,
+    point: [2][4]u32,
+    digest_after: [32]u8,
+};
+
+const OodsDrawVector = struct {
+    id: ?[]const u8 = null,
+    mixes: []ChannelMixVector,
+    digest_before: [32]u8,
+    draws: []OodsDrawStepVector,
+};
+
+const AccumulationColumnVector = struct {
+    log_size: u32,
+    n_cols: usize,
+    random_coeff_powers: [][4]u32,
+    values: [][4]u32,
+};
+
+const AccumulationCallVector = struct {
+    column: usize,
+    index: usize,
+    coeff_index: usize,
+    evaluation: [4]u32,
+};
+
+const AccumulationVector = struct {
+    id: ?[]const u8 = null,
+    random_coeff: [4]u32,
+    evaluations: [][4]u32,
+    accumulation: [4]u32,
+    max_log_size: u32,
+    total_columns: usize,
+    requests: []AccumulationColumnVector,
+    calls: []AccumulationCallVector,
+};
+
+const MerklePerturbationVector = struct {
+    column: usize,
+    index: usize,
+    bit: u32,
+    value: u32,
+    root: [32]u8,
+    root_changed: bool,
+};
+
+const MerkleAvalancheVector = struct {
+    id: ?[]const u8 = null,
+    scheme: []const u8,
+    column_log_sizes: []u32,
+    columns: [][]u32,
+    root: [32]u8,
+    perturbations: []MerklePerturbationVector,
+};
+
+const LookupDrawStepVector = struct {
+    op: []const u8,
+    felts: [][4]u32,
+    digest_after: [32]u8,
+};
+
+const LookupDrawV2Vector = struct {
+    id: ?[]const u8 = null,
+    mix_u64: u64,
+    mix_u32s: []u32,
+    digest_before: [32]u8,
+    steps: []LookupDrawStepVector,
+};
+
+const FieldBoundaryVector = struct {
+    id: ?[]const u8 = null,
+    field: []const u8,
+    a: []u32,
+    b: []u32,
+    add: []u32,
+    sub: []u32,
+    mul: []u32,
+    inv_a: ?[]u32,
+    div_ab: ?[]u32,
+    undefined: [][]const u8,
+};
+
+const FamilyMeta = struct {
+    count: usize,
+    seed: ?u64 = null,
+    generator: []const []const u8,
+};
+
+const FamilyGenerationStats = struct {
+    family: []const u8,
+    cases: usize,
+    seconds: f64,
+    rejections: ?u64,
+};
+
+const ErrorTaxonomyEntry = struct {
+    field: []const u8,
+    source: ?[]const u8 = null,
+    errors: []const []const u8,
+    outcomes: []const []const u8,
+};
+
+/// Mirrors `vectors/fields.json`; `stwo-vector-gen --list-families` and
+/// `stwo-vector-gen --describe <family>` document every family and its fields.
+/// Every entry struct starts with the schema-5 `id`, a short hash of the entry's family and
+/// contents that stays the same across regenerations; report failures by it.
+/// Families the committed file predates are optional and their tests skip when absent; make one
+/// required once `python3 scripts/parity_fields.py --regenerate` has written it.
 const VectorFile = struct {
+    /// Schema 3+: family name -> what the generator claims to have produced.
+    families: ?std.json.ArrayHashMap(FamilyMeta) = null,
+    /// Schema 4+: family name -> every error name or outcome its `field` can hold.
+    error_taxonomy: ?std.json.ArrayHashMap(ErrorTaxonomyEntry) = null,
     meta: struct {
         upstream_commit: []const u8,
+        profile: ?[]const u8 = null,
         sample_count: usize,
         schema_version: u32,
         seed: u64,
         seed_strategy: []const u8,
     },
+    /// Generation phases in run order; not a vector family.
+    generation_stats: ?[]FamilyGenerationStats = null,
+    known_answer: ?KnownAnswerVectors = null,
     m31: []M31Vector,
     cm31: []CM31Vector,
     qm31: []QM31Vector,
+    qm31_structure: ?[]QM31StructureVector = null,
+    secure_column: ?[]SecureColumnVector = null,
     circle_m31: []CircleM31Vector,
+    circle_scalar_mul: ?[]CircleScalarMulVector = null,
     fft_m31: []FftM31Vector,
+    fft_edge: ?[]FftEdgeVector = null,
     blake3: []Blake3Vector,
+    blake2s: ?[]Blake2sVector = null,
+    channel_mix_root: ?[]ChannelMixRootVector = null,
+    oods_draw: ?[]OodsDrawVector = null,
+    accumulation: ?[]AccumulationVector = null,
+    merkle_avalanche: ?[]MerkleAvalancheVector = null,
+    lookup_draw_v2: ?[]LookupDrawV2Vector = null,
+    field_boundaries: ?[]FieldBoundaryVector = null,
     pcs_quotients: []PcsQuotientsVector,
+    pcs_quotients_shared_points: ?[]PcsQuotientsVector = null,
+    denominator_inverses: ?[]DenominatorInversesVector = null,
+    samples_with_randomness: ?[]SamplesWithRandomnessVector = null,
     pcs_preprocessed_queries: []PcsPreprocessedQueryVector,
+    fri_answers_errors: ?[]FriAnswersErrorVector = null,
     fri_folds: []FriFoldVector,
+    fri_layer_count: ?[]FriLayerCountVector = null,
+    fri_fold_chain: ?[]FriFoldChainVector = null,
+    fri_fold_ordering: ?[]FriFoldOrderingVector = null,
+    fri_last_layer: ?[]FriLastLayerVector = null,
+    line_poly_construction: ?[]LinePolyConstructionVector = null,
     fri_decommit: []FriDecommitVector,
     fri_layer_decommit: []FriLayerDecommitVector,
+    fri_layer_chain_decommit: ?[]FriLayerChainDecommitVector = null,
+    fri_first_layer_decommit: ?[]FriFirstLayerDecommitVector = null,
     proof_extract_oods: []ProofExtractOodsVector,
+    config_security: ?[]ConfigSecurityVector = null,
     proof_sizes: []ProofSizeVector,
     prover_line: []ProverLineVector,
     vcs_verifier: []VcsVerifierVector,
     vcs_prover: []VcsProverVector,
     vcs_lifted_verifier: []VcsLiftedVerifierVector,
+    vcs_lifted_error_variants: ?[]VcsLiftedErrorVariantVector = null,
     vcs_lifted_prover: []VcsLiftedProverVector,
+    queried_rows_reshape: ?[]QueriedRowsReshapeVector = null,
+    pcs_commit: ?[]PcsCommitVector = null,
+    e2e_tree_shapes: ?[]E2eTreeShapesVector = null,
     example_state_machine_trace: []ExampleStateMachineTraceVector,
     example_state_machine_transitions: []ExampleStateMachineTransitionVector,
     example_state_machine_claimed_sum: []ExampleStateMachineClaimedSumVector,
     example_state_machine_lookup_draw: []ExampleStateMachineLookupDrawVector,
     example_state_machine_statement: []ExampleStateMachineStatementVector,
+    example_state_machine_components: ?[]ExampleStateMachineComponentsVector = null,
     example_xor_is_first: []ExampleXorIsFirstVector,
     example_xor_is_step_with_offset: []ExampleXorIsStepWithOffsetVector,
     example_wide_fibonacci_trace: []ExampleWideFibonacciTraceVector,
     example_plonk_trace: []ExamplePlonkTraceVector,
+    composition_sampled_order: ?[]CompositionSampledOrderVector = null,
+    blowup_sizing: ?[]BlowupSizingVector = null,
+    example_poseidon_trace: ?[]ExamplePoseidonTraceVector = null,
+    example_poseidon_rounds: ?[]ExamplePoseidonRoundsVector = null,
 };
 
 fn parseVectors(allocator: std.mem.Allocator) !std.json.Parsed(VectorFile) {
@@ -617,12 +1184,144 @@ fn decodeQm31Tree(
     return quotients_mod.TreeVec([][]QM31).initOwned(try trees_builder.toOwnedSlice(allocator));
 }
 
+/// Decodes one column written by `stwo-vector-gen --encoding packed` (`base64_u32_le`): standard
+/// padded base64 of the column's values as little-endian u32s.
+fn decodePackedColumn(allocator: std.mem.Allocator, encoded: []const u8) ![]u32 {
+    const decoder = std.base64.standard.Decoder;
+    const byte_len = try decoder.calcSizeForSlice(encoded);
+    if (byte_len % 4 != 0) return error.InvalidPackedColumn;
+    const bytes = try allocator.alloc(u8, byte_len);
+    defer allocator.free(bytes);
+    try decoder.decode(bytes, encoded);
+
+    const out = try allocator.alloc(u32, byte_len / 4);
+    for (out, 0..) |*value, i| value.* = std.mem.readInt(u32, bytes[i * 4 ..][0..4], .little);
+    return out;
+}
+
 fn decodeQm31Slice(allocator: std.mem.Allocator, encoded: [][4]u32) ![]QM31 {
     const out = try allocator.alloc(QM31, encoded.len);
     for (encoded, 0..) |value, i| out[i] = qm31From(value);
     return out;
 }
 
+fn m31FromCoords(v: []const u32) M31 {
+    return m31From(v[0]);
+}
+
+fn cm31FromCoords(v: []const u32) CM31 {
+    return cm31From(v[0..2].*);
+}
+
+fn qm31FromCoords(v: []const u32) QM31 {
+    return qm31From(v[0..4].*);
+}
+
+fn isUndefinedOp(v: FieldBoundaryVector, op: []const u8) bool {
+    for (v.undefined) |name| {
+        if (std.mem.eql(u8, name, op)) return true;
+    }
+    return false;
+}
+
+fn checkFieldBoundary(comptime F: type, comptime decode: fn ([]const u32) F, v: FieldBoundaryVector) !void {
+    const a = decode(v.a);
+    const b = decode(v.b);
+    try std.testing.expect(a.add(b).eql(decode(v.add)));
+    try std.testing.expect(a.sub(b).eql(decode(v.sub)));
+    try std.testing.expect(a.mul(b).eql(decode(v.mul)));
+    if (v.inv_a) |inv_a| {
+        try std.testing.expect(!isUndefinedOp(v, "inv_a"));
+        try std.testing.expect((try a.inv()).eql(decode(inv_a)));
+    } else {
+        try std.testing.expect(isUndefinedOp(v, "inv_a"));
+        try std.testing.expectError(F.Error.DivisionByZero, a.inv());
+    }
+    if (v.div_ab) |div_ab| {
+        try std.testing.expect(!isUndefinedOp(v, "div_ab"));
+        try std.testing.expect((try a.div(b)).eql(decode(div_ab)));
+    } else {
+        try std.testing.expect(isUndefinedOp(v, "div_ab"));
+        try std.testing.expectError(F.Error.DivisionByZero, a.div(b));
+    }
+}
+
+fn checkVcsLiftedVerifierCase(alloc: std.mem.Allocator, v: VcsLiftedVerifierVector) !void {
+    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+    const Verifier = @import("../vcs_lifted/verifier.zig").MerkleVerifierLifted(Hasher);
+    const Decommitment = @import("../vcs_lifted/verifier.zig").MerkleDecommitmentLifted(Hasher);
+
+    var verifier = try Verifier.init(alloc, v.root, v.column_log_sizes);
+    defer verifier.deinit(alloc);
+
+    const queried_values = try alloc.alloc([]const M31, v.queried_values.len);
+    defer alloc.free(queried_values);
+
+    const queried_values_owned = try alloc.alloc([]M31, v.queried_values.len);
+    defer {
+        for (queried_values_owned) |col| alloc.free(col);
+        alloc.free(queried_values_owned);
+    }
+
+    for (v.queried_values, 0..) |column, i| {
+        queried_values_owned[i] = try alloc.alloc(M31, column.len);
+        for (column, 0..) |value, j| queried_values_owned[i][j] = m31From(value);
+        queried_values[i] = queried_values_owned[i];
+    }
+
+    var decommitment = Decommitment{
+        .hash_witness = try alloc.dupe(Hasher.Hash, v.hash_witness),
+    };
+    defer decommitment.deinit(alloc);
+
+    if (std.mem.eql(u8, v.expected, "ok")) {
+        try verifier.verify(
+            alloc,
+            v.query_positions,
+            queried_values,
+            decommitment,
+        );
+    } else {
+        try std.testing.expectError(
+            expectedVcsLiftedError(v.expected),
+            verifier.verify(
+                alloc,
+                v.query_positions,
+                queried_values,
+                decommitment,
+            ),
+        );
+    }
+}
+
+fn isVcsLiftedErrorName(name: []const u8) bool {
+    return errorSetHasName(@import("../vcs_lifted/verifier.zig").MerkleVerificationError, name);
+}
+
+fn errorSetHasName(comptime E: type, name: []const u8) bool {
+    const errors = @typeInfo(E).error_set.?;
+    inline for (errors) |err| {
+        if (std.mem.eql(u8, name, err.name)) return true;
+    }
+    return false;
+}
+
+fn expectTaxonomyErrorsIn(comptime E: type, taxonomy: std.json.ArrayHashMap(ErrorTaxonomyEntry), family: []const u8) !void {
+    const entry = taxonomy.map.get(family) orelse return error.MissingTaxonomyEntry;
+    for (entry.errors) |name| {
+        if (!errorSetHasName(E, name)) {
+            std.debug.print("{s}: error {s} has no Zig counterpart\n", .{ family, name });
+            return error.UnknownTaxonomyError;
+        }
+    }
+}
+
+fn merkleRoot(comptime Prover: type, allocator: std.mem.Allocator, columns: []const []const M31) ![32]u8 {
+    var prover = try Prover.commit(allocator, columns);
+    defer prover.deinit(allocator);
+    return prover.root();
+}
+
 test "field vectors: m31 parity" {
     var parsed = try parseVectors(std.testing.allocator);
     defer parsed.deinit();
@@ -671,12 +1370,154 @@ test "field vectors: qm31 parity" {
     }
 }
 
-test "field vectors: circle m31 parity" {
-    var parsed = try parseVectors(std.testing.allocator);
-    defer parsed.deinit();
-
-    try std.testing.expect(parsed.value.circle_m31.len == parsed.value.meta.sample_count);
-    for (parsed.value.circle_m31) |v| {
+/// Evaluates a known-answer op with the Zig field code into `out`, returning the limbs written.
+fn evaluateKnownAnswerFieldOp(op: []const u8, inputs: []const []const u32, out: *[4]u32) ![]const u32 {
+    const eq = std.mem.eql;
+    if (eq(u8, op, "m31_add")) {
+        out[0] = m31From(inputs[0][0]).add(m31From(inputs[1][0])).toU32();
+        return out[0..1];
+    } else if (eq(u8, op, "m31_mul")) {
+        out[0] = m31From(inputs[0][0]).mul(m31From(inputs[1][0])).toU32();
+        return out[0..1];
+    } else if (eq(u8, op, "m31_inverse")) {
+        out[0] = (try m31From(inputs[0][0]).inv()).toU32();
+        return out[0..1];
+    } else if (eq(u8, op, "cm31_add")) {
+        out[0..2].* = encodeCM31(cm31FromCoords(inputs[0]).add(cm31FromCoords(inputs[1])));
+        return out[0..2];
+    } else if (eq(u8, op, "cm31_mul")) {
+        out[0..2].* = encodeCM31(cm31FromCoords(inputs[0]).mul(cm31FromCoords(inputs[1])));
+        return out[0..2];
+    } else if (eq(u8, op, "cm31_inverse")) {
+        out[0..2].* = encodeCM31(try cm31FromCoords(inputs[0]).inv());
+        return out[0..2];
+    } else if (eq(u8, op, "qm31_add")) {
+        out.* = encodeQM31(qm31FromCoords(inputs[0]).add(qm31FromCoords(inputs[1])));
+        return out[0..4];
+    } else if (eq(u8, op, "qm31_mul")) {
+        out.* = encodeQM31(qm31FromCoords(inputs[0]).mul(qm31FromCoords(inputs[1])));
+        return out[0..4];
+    } else if (eq(u8, op, "qm31_inverse")) {
+        out.* = encodeQM31(try qm31FromCoords(inputs[0]).inv());
+        return out[0..4];
+    } else if (eq(u8, op, "m31_butterfly") or eq(u8, op, "m31_ibutterfly")) {
+        var v0 = m31From(inputs[0][0]);
+        var v1 = m31From(inputs[1][0]);
+        if (eq(u8, op, "m31_butterfly")) {
+            fft_mod.butterfly(M31, &v0, &v1, m31From(inputs[2][0]));
+        } else {
+            fft_mod.ibutterfly(M31, &v0, &v1, m31From(inputs[2][0]));
+        }
+        out[0] = v0.toU32();
+        out[1] = v1.toU32();
+        return out[0..2];
+    }
+    return error.UnknownKnownAnswerOp;
+}
+
+test "field vectors: known answers" {
+    const Hasher = @import("../vcs/blake2_merkle.zig").Blake2sMerkleHasher;
+
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const known_answer = parsed.value.known_answer orelse return;
+    try std.testing.expect(known_answer.field_ops.len > 0);
+    for (known_answer.field_ops) |v| {
+        var out: [4]u32 = undefined;
+        const actual = try evaluateKnownAnswerFieldOp(v.op, v.inputs, &out);
+        std.testing.expectEqualSlices(u32, v.expected, actual) catch |err| {
+            std.debug.print("known answer {s} disagrees\n", .{v.name});
+            return err;
+        };
+    }
+
+    try std.testing.expect(known_answer.merkle.len > 0);
+    for (known_answer.merkle) |v| {
+        var layer: [8][32]u8 = undefined;
+        try std.testing.expect(std.math.isPowerOfTwo(v.leaves.len) and v.leaves.len <= layer.len);
+        for (v.leaves, 0..) |leaf, i| layer[i] = Hasher.hashNode(null, &.{m31From(leaf)});
+        var len = v.leaves.len;
+        while (len > 1) : (len /= 2) {
+            for (0..len / 2) |i| {
+                layer[i] = Hasher.hashNode(.{ .left = layer[2 * i], .right = layer[2 * i + 1] }, &.{});
+            }
+        }
+        try std.testing.expectEqualSlices(u8, v.root[0..], layer[0][0..]);
+    }
+}
+
+test "field vectors: qm31 structure parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.qm31_structure orelse return;
+    var self_conjugate: usize = 0;
+    for (vectors) |v| {
+        const value = qm31From(v.value);
+        const conjugate = value.complexConjugate();
+        try std.testing.expect(conjugate.eql(qm31From(v.complex_conjugate)));
+        if (std.mem.eql(u8, v.case, "self_conjugate")) {
+            try std.testing.expect(conjugate.eql(value));
+            self_conjugate += 1;
+        }
+
+        const coords = value.toM31Array();
+        for (coords, v.m31_array) |coord, expected| try std.testing.expectEqual(expected, coord.toU32());
+        try std.testing.expect(QM31.fromM31Array(coords).eql(qm31From(v.from_m31_array)));
+
+        var partial_evals: [4]QM31 = undefined;
+        for (v.partial_evals, 0..) |eval, i| partial_evals[i] = qm31From(eval);
+        try std.testing.expect(QM31.fromPartialEvals(partial_evals).eql(qm31From(v.from_partial_evals)));
+    }
+    try std.testing.expect(self_conjugate > 0);
+    try std.testing.expectEqual(parsed.value.meta.sample_count + self_conjugate, vectors.len);
+}
+
+test "field vectors: secure column parity" {
+    const alloc = std.testing.allocator;
+    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.secure_column orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const column = try alloc.alloc(QM31, v.column.len);
+        defer alloc.free(column);
+        for (v.column, 0..) |value, i| column[i] = qm31From(value);
+
+        var secure_column = try prover_secure_column_mod.SecureColumnByCoords.fromSecureSlice(alloc, column);
+        defer secure_column.deinit(alloc);
+        try std.testing.expectEqual(v.column.len, secure_column.len());
+        for (secure_column.columns, v.coordinates) |coords, expected| {
+            try std.testing.expectEqual(expected.len, coords.len);
+            for (coords, expected) |coord, want| try std.testing.expectEqual(want, coord.toU32());
+        }
+
+        const rebuilt = try secure_column.toVec(alloc);
+        defer alloc.free(rebuilt);
+        for (rebuilt, v.reconstructed) |value, expected| {
+            try std.testing.expect(value.eql(qm31From(expected)));
+        }
+
+        try std.testing.expectEqual(v.column.len, v.leaf_hashes.len);
+        for (v.leaf_hashes, 0..) |expected, row| {
+            var hasher = Hasher.defaultWithInitialState();
+            for (secure_column.columns) |coords| hasher.updateLeaf(&.{coords[row]});
+            const leaf = hasher.finalize();
+            try std.testing.expect(std.mem.eql(u8, &expected, std.mem.asBytes(&leaf)));
+        }
+    }
+}
+
+test "field vectors: circle m31 parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    try std.testing.expect(parsed.value.circle_m31.len == parsed.value.meta.sample_count);
+    for (parsed.value.circle_m31) |v| {
         const a = M31_CIRCLE_GEN.mul(@as(u128, v.a_scalar));
         const b = M31_CIRCLE_GEN.mul(@as(u128, v.b_scalar));
         try std.testing.expect(a.eql(circleM31From(v.a)));
@@ -689,6 +1530,30 @@ test "field vectors: circle m31 parity" {
     }
 }
 
+test "field vectors: circle scalar mul parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.circle_scalar_mul orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const scalar = try std.fmt.parseInt(u128, v.scalar, 10);
+        if (std.mem.eql(u8, v.generator, "m31")) {
+            const point = M31_CIRCLE_GEN.mul(scalar);
+            try std.testing.expect(point.x.eql(m31FromCoords(v.x)));
+            try std.testing.expect(point.y.eql(m31FromCoords(v.y)));
+            try std.testing.expectEqual(v.log_order.?, point.logOrder());
+        } else if (std.mem.eql(u8, v.generator, "secure_field")) {
+            const point = circle_mod.SECURE_FIELD_CIRCLE_GEN.mul(scalar);
+            try std.testing.expect(point.x.eql(qm31FromCoords(v.x)));
+            try std.testing.expect(point.y.eql(qm31FromCoords(v.y)));
+            try std.testing.expect(v.log_order == null);
+        } else {
+            return error.UnknownGenerator;
+        }
+    }
+}
+
 test "field vectors: fft m31 parity" {
     var parsed = try parseVectors(std.testing.allocator);
     defer parsed.deinit();
@@ -710,6 +1575,45 @@ test "field vectors: fft m31 parity" {
     }
 }
 
+test "field vectors: fft edge twiddle parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.fft_edge orelse return;
+    try std.testing.expect(vectors.len > 0);
+    var undefined_count: usize = 0;
+    for (vectors) |v| {
+        var a = m31From(v.a);
+        var b = m31From(v.b);
+        const twid = m31From(v.twid);
+
+        var w0 = a;
+        var w1 = b;
+        fft_mod.ibutterfly(M31, &w0, &w1, twid);
+        try std.testing.expect(w0.eql(m31From(v.ibutterfly_with_twid[0])));
+        try std.testing.expect(w1.eql(m31From(v.ibutterfly_with_twid[1])));
+
+        fft_mod.butterfly(M31, &a, &b, twid);
+        try std.testing.expect(a.eql(m31From(v.butterfly[0])));
+        try std.testing.expect(b.eql(m31From(v.butterfly[1])));
+
+        if (v.itwid) |expected_itwid| {
+            const itwid = try twid.inv();
+            try std.testing.expect(itwid.eql(m31From(expected_itwid)));
+            fft_mod.ibutterfly(M31, &a, &b, itwid);
+            try std.testing.expect(a.eql(m31From(v.ibutterfly.?[0])));
+            try std.testing.expect(b.eql(m31From(v.ibutterfly.?[1])));
+        } else {
+            // Upstream cannot invert this twiddle; the Zig field must refuse it too.
+            try std.testing.expect(v.ibutterfly == null);
+            try std.testing.expectEqual(@as(usize, 2), v.undefined.len);
+            try std.testing.expectError(M31.Error.DivisionByZero, twid.inv());
+            undefined_count += 1;
+        }
+    }
+    try std.testing.expect(undefined_count > 0);
+}
+
 test "field vectors: blake3 parity" {
     var parsed = try parseVectors(std.testing.allocator);
     defer parsed.deinit();
@@ -724,155 +1628,391 @@ test "field vectors: blake3 parity" {
     }
 }
 
-test "field vectors: pcs quotients parity" {
+test "field vectors: blake2s parity" {
     const alloc = std.testing.allocator;
+    const Hasher = @import("../vcs/blake2_hash.zig").Blake2sHasher;
+    const MerkleHasher = @import("../vcs/blake2_merkle.zig").Blake2sMerkleHasher;
+    const LiftedHasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+
     var parsed = try parseVectors(alloc);
     defer parsed.deinit();
 
-    try std.testing.expect(parsed.value.pcs_quotients.len > 0);
-    for (parsed.value.pcs_quotients) |v| {
-        var column_log_sizes = try decodeColumnLogSizes(alloc, v.column_log_sizes);
-        defer column_log_sizes.deinitDeep(alloc);
-        var samples = try decodeSamplesTree(alloc, v.samples);
-        defer samples.deinitDeep(alloc);
-        var split_samples = try splitPointSamplesTree(alloc, samples);
-        defer split_samples.deinit(alloc);
-        var queried_values = try decodeQueriedValuesTree(alloc, v.queried_values);
-        defer queried_values.deinitDeep(alloc);
-        const random_coeff = qm31From(v.random_coeff);
+    const vectors = parsed.value.blake2s orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const hash = Hasher.hash(v.data);
+        try std.testing.expectEqualSlices(u8, v.hash[0..], hash[0..]);
 
-        var samples_with_randomness = try quotients_mod.buildSamplesWithRandomnessAndPeriodicity(
-            alloc,
-            split_samples.points,
-            split_samples.values,
-            column_log_sizes,
-            v.lifting_log_size,
-            random_coeff,
-        );
-        defer samples_with_randomness.deinitDeep(alloc);
-
-        try std.testing.expectEqual(v.samples_with_randomness.len, samples_with_randomness.items.len);
-        for (v.samples_with_randomness, 0..) |expected_tree, tree_idx| {
-            try std.testing.expectEqual(expected_tree.len, samples_with_randomness.items[tree_idx].len);
-            for (expected_tree, 0..) |expected_col, col_idx| {
-                try std.testing.expectEqual(expected_col.len, samples_with_randomness.items[tree_idx][col_idx].len);
-                for (expected_col, 0..) |expected_sample, sample_idx| {
-                    const actual = samples_with_randomness.items[tree_idx][col_idx][sample_idx];
-                    const decoded_expected = sampleWithRandomnessFrom(expected_sample);
-                    try std.testing.expect(actual.point.eql(decoded_expected.point));
-                    try std.testing.expect(actual.value.eql(decoded_expected.value));
-                    try std.testing.expect(actual.random_coeff.eql(decoded_expected.random_coeff));
-                }
+        const node_values = try alloc.alloc(M31, v.node_values.len);
+        defer alloc.free(node_values);
+        for (v.node_values, 0..) |value, i| node_values[i] = m31From(value);
+        const with_children = MerkleHasher.hashNode(.{ .left = v.left, .right = v.right }, node_values);
+        try std.testing.expectEqualSlices(u8, v.hash_node_with_children[0..], with_children[0..]);
+        const leaf = MerkleHasher.hashNode(null, node_values);
+        try std.testing.expectEqualSlices(u8, v.hash_node_leaf[0..], leaf[0..]);
+
+        // One digest for the bare initial state, then one after each update.
+        try std.testing.expectEqual(v.lifted_leaf_updates.len + 1, v.lifted_leaf_digests.len);
+        var lifted = LiftedHasher.defaultWithInitialState();
+        var snapshot = lifted;
+        const initial = snapshot.finalize();
+        try std.testing.expectEqualSlices(u8, v.lifted_leaf_digests[0][0..], initial[0..]);
+        for (v.lifted_leaf_updates, v.lifted_leaf_digests[1..]) |update, expected| {
+            const values = try alloc.alloc(M31, update.len);
+            defer alloc.free(values);
+            for (update, 0..) |value, i| values[i] = m31From(value);
+            lifted.updateLeaf(values);
+            snapshot = lifted;
+            const digest = snapshot.finalize();
+            try std.testing.expectEqualSlices(u8, expected[0..], digest[0..]);
+        }
+    }
+}
+
+test "field vectors: channel mix root parity" {
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+    const MerkleChannel = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleChannel;
+
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.channel_mix_root orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var channel = Channel{};
+        channel.mixU64(v.prefix_mix_u64);
+        channel.mixU32s(v.prefix_mix_u32s);
+        try std.testing.expectEqualSlices(u8, v.digest_before[0..], channel.digestBytes()[0..]);
+
+        // Name the wrong convention a mismatch matches, if any, before failing on it.
+        var mixed = channel;
+        MerkleChannel.mixRoot(&mixed, v.root);
+        const digest_after = mixed.digestBytes();
+        var it = v.wrong_variant_digests.map.iterator();
+        while (it.next()) |variant| {
+            if (std.mem.eql(u8, variant.value_ptr[0..], digest_after[0..])) {
+                std.debug.print("mix_root matches the wrong variant {s}\n", .{variant.key_ptr.*});
+                return error.WrongMixRootVariant;
             }
         }
+        try std.testing.expectEqualSlices(u8, v.digest_after[0..], digest_after[0..]);
+        try std.testing.expect(mixed.drawSecureFelt().eql(qm31From(v.draw_secure_felt)));
+
+        var words: [8]u32 = undefined;
+        for (&words, 0..) |*word, i| word.* = std.mem.readInt(u32, v.root[i * 4 ..][0..4], .little);
+        var u32s_le = channel;
+        u32s_le.mixU32s(words[0..]);
+        const u32s_le_digest = u32s_le.digestBytes();
+        const expected_le = v.wrong_variant_digests.map.get("mix_u32s_le") orelse return error.MissingWrongVariant;
+        try std.testing.expectEqualSlices(u8, expected_le[0..], u32s_le_digest[0..]);
+    }
+}
 
-        var flat_samples = std.ArrayList([]const SampleWithRandomness).empty;
-        defer flat_samples.deinit(alloc);
-        for (samples_with_randomness.items) |tree| {
-            for (tree) |col| try flat_samples.append(alloc, col);
+/// Checks every intermediate of one PCS quotient vector, from the randomized samples through the
+/// sample batches and per-row quotients to the final FRI answers.
+fn checkPcsQuotientsVector(alloc: std.mem.Allocator, v: PcsQuotientsVector) !void {
+    var column_log_sizes = try decodeColumnLogSizes(alloc, v.column_log_sizes);
+    defer column_log_sizes.deinitDeep(alloc);
+    var samples = try decodeSamplesTree(alloc, v.samples);
+    defer samples.deinitDeep(alloc);
+    var split_samples = try splitPointSamplesTree(alloc, samples);
+    defer split_samples.deinit(alloc);
+    var queried_values = try decodeQueriedValuesTree(alloc, v.queried_values);
+    defer queried_values.deinitDeep(alloc);
+    const random_coeff = qm31From(v.random_coeff);
+
+    var samples_with_randomness = try quotients_mod.buildSamplesWithRandomnessAndPeriodicity(
+        alloc,
+        split_samples.points,
+        split_samples.values,
+        column_log_sizes,
+        v.lifting_log_size,
+        random_coeff,
+    );
+    defer samples_with_randomness.deinitDeep(alloc);
+
+    try std.testing.expectEqual(v.samples_with_randomness.len, samples_with_randomness.items.len);
+    for (v.samples_with_randomness, 0..) |expected_tree, tree_idx| {
+        try std.testing.expectEqual(expected_tree.len, samples_with_randomness.items[tree_idx].len);
+        for (expected_tree, 0..) |expected_col, col_idx| {
+            try std.testing.expectEqual(expected_col.len, samples_with_randomness.items[tree_idx][col_idx].len);
+            for (expected_col, 0..) |expected_sample, sample_idx| {
+                const actual = samples_with_randomness.items[tree_idx][col_idx][sample_idx];
+                const decoded_expected = sampleWithRandomnessFrom(expected_sample);
+                try std.testing.expect(actual.point.eql(decoded_expected.point));
+                try std.testing.expect(actual.value.eql(decoded_expected.value));
+                try std.testing.expect(actual.random_coeff.eql(decoded_expected.random_coeff));
+            }
         }
+    }
 
-        const sample_batches = try ColumnSampleBatch.newVec(alloc, flat_samples.items);
-        defer ColumnSampleBatch.deinitSlice(alloc, sample_batches);
+    var flat_samples = std.ArrayList([]const SampleWithRandomness).empty;
+    defer flat_samples.deinit(alloc);
+    for (samples_with_randomness.items) |tree| {
+        for (tree) |col| try flat_samples.append(alloc, col);
+    }
 
-        try std.testing.expectEqual(v.sample_batches.len, sample_batches.len);
-        for (v.sample_batches, 0..) |expected_batch, batch_idx| {
-            const actual_batch = sample_batches[batch_idx];
-            try std.testing.expect(actual_batch.point.eql(circleQM31From(expected_batch.point)));
-            try std.testing.expectEqual(expected_batch.cols_vals_randpows.len, actual_batch.cols_vals_randpows.len);
-            for (expected_batch.cols_vals_randpows, 0..) |expected_num, num_idx| {
-                const actual_num: NumeratorData = actual_batch.cols_vals_randpows[num_idx];
-                try std.testing.expectEqual(expected_num.column_index, actual_num.column_index);
-                try std.testing.expect(actual_num.sample_value.eql(qm31From(expected_num.sample_value)));
-                try std.testing.expect(actual_num.random_coeff.eql(qm31From(expected_num.random_coeff)));
-            }
+    const sample_batches = try ColumnSampleBatch.newVec(alloc, flat_samples.items);
+    defer ColumnSampleBatch.deinitSlice(alloc, sample_batches);
+
+    try std.testing.expectEqual(v.sample_batches.len, sample_batches.len);
+    for (v.sample_batches, 0..) |expected_batch, batch_idx| {
+        const actual_batch = sample_batches[batch_idx];
+        try std.testing.expect(actual_batch.point.eql(circleQM31From(expected_batch.point)));
+        try std.testing.expectEqual(expected_batch.cols_vals_randpows.len, actual_batch.cols_vals_randpows.len);
+        for (expected_batch.cols_vals_randpows, 0..) |expected_num, num_idx| {
+            const actual_num: NumeratorData = actual_batch.cols_vals_randpows[num_idx];
+            try std.testing.expectEqual(expected_num.column_index, actual_num.column_index);
+            try std.testing.expect(actual_num.sample_value.eql(qm31From(expected_num.sample_value)));
+            try std.testing.expect(actual_num.random_coeff.eql(qm31From(expected_num.random_coeff)));
         }
+    }
 
-        var q_consts = try quotients_mod.quotientConstants(alloc, sample_batches);
-        defer q_consts.deinit(alloc);
+    var q_consts = try quotients_mod.quotientConstants(alloc, sample_batches);
+    defer q_consts.deinit(alloc);
+
+    try std.testing.expectEqual(v.line_coeffs.len, q_consts.line_coeffs.len);
+    for (v.line_coeffs, 0..) |expected_batch_coeffs, batch_idx| {
+        try std.testing.expectEqual(expected_batch_coeffs.len, q_consts.line_coeffs[batch_idx].len);
+        for (expected_batch_coeffs, 0..) |expected_coeff, coeff_idx| {
+            const actual: LineCoeffs = q_consts.line_coeffs[batch_idx][coeff_idx];
+            try std.testing.expect(actual.a.eql(qm31From(expected_coeff.a)));
+            try std.testing.expect(actual.b.eql(qm31From(expected_coeff.b)));
+            try std.testing.expect(actual.c.eql(qm31From(expected_coeff.c)));
+        }
+    }
 
-        try std.testing.expectEqual(v.line_coeffs.len, q_consts.line_coeffs.len);
-        for (v.line_coeffs, 0..) |expected_batch_coeffs, batch_idx| {
-            try std.testing.expectEqual(expected_batch_coeffs.len, q_consts.line_coeffs[batch_idx].len);
-            for (expected_batch_coeffs, 0..) |expected_coeff, coeff_idx| {
-                const actual: LineCoeffs = q_consts.line_coeffs[batch_idx][coeff_idx];
-                try std.testing.expect(actual.a.eql(qm31From(expected_coeff.a)));
-                try std.testing.expect(actual.b.eql(qm31From(expected_coeff.b)));
-                try std.testing.expect(actual.c.eql(qm31From(expected_coeff.c)));
-            }
+    var queried_values_flat = std.ArrayList([]const M31).empty;
+    defer queried_values_flat.deinit(alloc);
+    for (queried_values.items) |tree| {
+        for (tree) |col| try queried_values_flat.append(alloc, col);
+    }
+
+    const row_values = try alloc.alloc(M31, queried_values_flat.items.len);
+    defer alloc.free(row_values);
+    const sample_points = try alloc.alloc(CirclePointQM31, sample_batches.len);
+    defer alloc.free(sample_points);
+    for (sample_batches, 0..) |batch, i| sample_points[i] = batch.point;
+
+    const domain = canonic_mod.CanonicCoset.new(v.lifting_log_size).circleDomain();
+    try std.testing.expectEqual(v.query_positions.len, v.denominator_inverses.len);
+    try std.testing.expectEqual(v.query_positions.len, v.partial_numerators.len);
+    try std.testing.expectEqual(v.query_positions.len, v.row_quotients.len);
+    try std.testing.expectEqual(v.query_positions.len, v.fri_answers.len);
+
+    for (v.query_positions, 0..) |position, row_idx| {
+        for (queried_values_flat.items, 0..) |column, col_idx| {
+            row_values[col_idx] = column[row_idx];
+        }
+        const domain_index = utils_mod.bitReverseIndex(position, v.lifting_log_size);
+        const domain_point = domain.at(domain_index);
+        if (v.domain_points) |domain_points| {
+            try std.testing.expectEqual(domain_points[row_idx][0], domain_point.x.toU32());
+            try std.testing.expectEqual(domain_points[row_idx][1], domain_point.y.toU32());
         }
 
-        var queried_values_flat = std.ArrayList([]const M31).empty;
-        defer queried_values_flat.deinit(alloc);
-        for (queried_values.items) |tree| {
-            for (tree) |col| try queried_values_flat.append(alloc, col);
+        const den_inv = try quotients_mod.denominatorInverses(alloc, sample_points, domain_point);
+        defer alloc.free(den_inv);
+        try std.testing.expectEqual(v.denominator_inverses[row_idx].len, den_inv.len);
+        for (v.denominator_inverses[row_idx], 0..) |expected_inv, i| {
+            const encoded_inv = encodeCM31(den_inv[i]);
+            try std.testing.expectEqualSlices(u32, expected_inv[0..], encoded_inv[0..]);
         }
 
-        const row_values = try alloc.alloc(M31, queried_values_flat.items.len);
-        defer alloc.free(row_values);
-        const sample_points = try alloc.alloc(CirclePointQM31, sample_batches.len);
-        defer alloc.free(sample_points);
-        for (sample_batches, 0..) |batch, i| sample_points[i] = batch.point;
+        try std.testing.expectEqual(v.partial_numerators[row_idx].len, sample_batches.len);
+        for (sample_batches, 0..) |batch, batch_idx| {
+            const partial = try quotients_mod.accumulateRowPartialNumerators(
+                &batch,
+                row_values,
+                q_consts.line_coeffs[batch_idx],
+            );
+            try std.testing.expectEqualSlices(
+                u32,
+                v.partial_numerators[row_idx][batch_idx][0..],
+                encodeQM31(partial)[0..],
+            );
+        }
+
+        const row_quot = try quotients_mod.accumulateRowQuotients(
+            alloc,
+            sample_batches,
+            row_values,
+            &q_consts,
+            domain_point,
+        );
+        try std.testing.expectEqualSlices(u32, v.row_quotients[row_idx][0..], encodeQM31(row_quot)[0..]);
+    }
 
-        const domain = canonic_mod.CanonicCoset.new(v.lifting_log_size).circleDomain();
-        try std.testing.expectEqual(v.query_positions.len, v.denominator_inverses.len);
-        try std.testing.expectEqual(v.query_positions.len, v.partial_numerators.len);
-        try std.testing.expectEqual(v.query_positions.len, v.row_quotients.len);
-        try std.testing.expectEqual(v.query_positions.len, v.fri_answers.len);
+    const fri_answers = try quotients_mod.friAnswers(
+        alloc,
+        column_log_sizes,
+        split_samples.points,
+        split_samples.values,
+        random_coeff,
+        v.query_positions,
+        queried_values,
+        v.lifting_log_size,
+    );
+    defer alloc.free(fri_answers);
+    for (v.fri_answers, 0..) |expected, i| {
+        try std.testing.expectEqualSlices(u32, expected[0..], encodeQM31(fri_answers[i])[0..]);
+    }
+}
 
-        for (v.query_positions, 0..) |position, row_idx| {
-            for (queried_values_flat.items, 0..) |column, col_idx| {
-                row_values[col_idx] = column[row_idx];
-            }
-            const domain_point = domain.at(utils_mod.bitReverseIndex(position, v.lifting_log_size));
-
-            const den_inv = try quotients_mod.denominatorInverses(alloc, sample_points, domain_point);
-            defer alloc.free(den_inv);
-            try std.testing.expectEqual(v.denominator_inverses[row_idx].len, den_inv.len);
-            for (v.denominator_inverses[row_idx], 0..) |expected_inv, i| {
-                const encoded_inv = encodeCM31(den_inv[i]);
-                try std.testing.expectEqualSlices(u32, expected_inv[0..], encoded_inv[0..]);
+test "field vectors: pcs quotients parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    try std.testing.expect(parsed.value.pcs_quotients.len > 0);
+    var vectors_with_position_zero: usize = 0;
+    var vectors_with_conjugate_half: usize = 0;
+    for (parsed.value.pcs_quotients) |v| {
+        const half_domain_size = @as(usize, 1) << @intCast(v.lifting_log_size - 1);
+        if (std.mem.indexOfScalar(usize, v.query_positions, 0) != null) vectors_with_position_zero += 1;
+        for (v.query_positions) |position| {
+            if (utils_mod.bitReverseIndex(position, v.lifting_log_size) >= half_domain_size) {
+                vectors_with_conjugate_half += 1;
+                break;
             }
+        }
+        try checkPcsQuotientsVector(alloc, v);
+    }
 
-            try std.testing.expectEqual(v.partial_numerators[row_idx].len, sample_batches.len);
-            for (sample_batches, 0..) |batch, batch_idx| {
-                const partial = try quotients_mod.accumulateRowPartialNumerators(
-                    &batch,
-                    row_values,
-                    q_consts.line_coeffs[batch_idx],
-                );
-                try std.testing.expectEqualSlices(
-                    u32,
-                    v.partial_numerators[row_idx][batch_idx][0..],
-                    encodeQM31(partial)[0..],
-                );
+    // Generators that emit `domain_points` also force index 0 and conjugate-half coverage.
+    if (parsed.value.pcs_quotients[0].domain_points != null) {
+        try std.testing.expect(vectors_with_position_zero >= 4);
+        try std.testing.expect(vectors_with_conjugate_half >= 4);
+    }
+}
+
+test "field vectors: pcs quotients with shared sample points" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.pcs_quotients_shared_points orelse return;
+    try std.testing.expect(vectors.len >= 8);
+    for (vectors) |v| {
+        try checkPcsQuotientsVector(alloc, v);
+
+        // Columns sampled at the same point collapse into one batch per distinct point; the batch
+        // order itself is pinned by `checkPcsQuotientsVector`.
+        var distinct_points = std.ArrayList([2][4]u32).empty;
+        defer distinct_points.deinit(alloc);
+        for (v.samples) |tree| {
+            for (tree) |column| {
+                for (column) |sample| {
+                    for (distinct_points.items) |point| {
+                        if (std.meta.eql(point, sample.point)) break;
+                    } else try distinct_points.append(alloc, sample.point);
+                }
             }
+        }
+        try std.testing.expectEqual(distinct_points.items.len, v.sample_batches.len);
+        var shared_batch = false;
+        for (v.sample_batches) |batch| {
+            for (distinct_points.items) |point| {
+                if (std.meta.eql(point, batch.point)) break;
+            } else return error.BatchPointNotSampled;
+            if (batch.cols_vals_randpows.len > 1) shared_batch = true;
+        }
+        try std.testing.expect(shared_batch);
+    }
+}
 
-            const row_quot = try quotients_mod.accumulateRowQuotients(
-                alloc,
-                sample_batches,
-                row_values,
-                &q_consts,
-                domain_point,
-            );
-            try std.testing.expectEqualSlices(u32, v.row_quotients[row_idx][0..], encodeQM31(row_quot)[0..]);
+test "field vectors: denominator inverses parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    var degenerate: usize = 0;
+    const vectors = parsed.value.denominator_inverses orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const domain_point = canonic_mod.CanonicCoset.new(v.domain_log_size).circleDomain().at(v.domain_index);
+        try std.testing.expect(domain_point.eql(circleM31From(v.domain_point)));
+        try std.testing.expectEqual(v.sample_points.len, v.denominators.len);
+        try std.testing.expectEqual(v.sample_points.len, v.u_perturbations.len);
+
+        const sample_points = try alloc.alloc(CirclePointQM31, v.sample_points.len);
+        defer alloc.free(sample_points);
+        for (v.sample_points, 0..) |point, i| sample_points[i] = circleQM31From(point);
+
+        const result = quotients_mod.denominatorInverses(alloc, sample_points, domain_point);
+        var has_zero = false;
+        for (v.denominators) |denominator| has_zero = has_zero or cm31From(denominator).isZero();
+        if (has_zero) {
+            // Upstream batch-inverts a zero denominator unchecked, whatever it records; Zig
+            // refuses it.
+            try std.testing.expectError(error.DivisionByZero, result);
+            degenerate += 1;
+            continue;
+        }
+        try std.testing.expectEqualStrings("ok", v.expected);
+        const inverses = try result;
+        defer alloc.free(inverses);
+        try std.testing.expectEqual(v.inverses.len, inverses.len);
+        for (v.inverses, v.denominators, inverses) |expected, denominator, inverse| {
+            try std.testing.expectEqualSlices(u32, expected[0..], encodeCM31(inverse)[0..]);
+            try std.testing.expect(cm31From(denominator).mul(inverse).eql(CM31.one()));
         }
+    }
+    try std.testing.expect(degenerate > 0);
+}
+
+test "field vectors: samples with randomness provenance" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    // Columns 1, 2 and 3 below the lifting size each vary the periodic replication factor.
+    var lifting_gaps = [_]bool{false} ** 4;
+    const vectors = parsed.value.samples_with_randomness orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var column_log_sizes = try decodeColumnLogSizes(alloc, v.column_log_sizes);
+        defer column_log_sizes.deinitDeep(alloc);
+        var samples = try decodeSamplesTree(alloc, v.samples);
+        defer samples.deinitDeep(alloc);
+        var split_samples = try splitPointSamplesTree(alloc, samples);
+        defer split_samples.deinit(alloc);
+        const random_coeff = qm31From(v.random_coeff);
 
-        const fri_answers = try quotients_mod.friAnswers(
+        var expanded = try quotients_mod.buildSamplesWithRandomnessAndPeriodicity(
             alloc,
-            column_log_sizes,
             split_samples.points,
             split_samples.values,
-            random_coeff,
-            v.query_positions,
-            queried_values,
+            column_log_sizes,
             v.lifting_log_size,
+            random_coeff,
         );
-        defer alloc.free(fri_answers);
-        for (v.fri_answers, 0..) |expected, i| {
-            try std.testing.expectEqualSlices(u32, expected[0..], encodeQM31(fri_answers[i])[0..]);
+        defer expanded.deinitDeep(alloc);
+
+        try std.testing.expectEqual(v.samples_with_randomness.len, expanded.items.len);
+        for (v.samples_with_randomness, expanded.items, 0..) |expected_tree, tree, tree_idx| {
+            try std.testing.expectEqual(expected_tree.len, tree.len);
+            for (expected_tree, tree, 0..) |expected_col, col, col_idx| {
+                const gap = v.lifting_log_size - v.column_log_sizes[tree_idx][col_idx];
+                if (gap < lifting_gaps.len) lifting_gaps[gap] = true;
+                try std.testing.expectEqual(expected_col.len, col.len);
+                for (expected_col, col) |expected, actual| {
+                    // The same provenance sequence, not merely the same multiset of values.
+                    try std.testing.expectEqual(tree_idx, expected.tree);
+                    try std.testing.expectEqual(col_idx, expected.column);
+                    try std.testing.expect(actual.point.eql(circleQM31From(expected.sample.point)));
+                    try std.testing.expect(actual.value.eql(qm31From(expected.sample.value)));
+                    try std.testing.expect(actual.random_coeff.eql(qm31From(expected.random_coeff)));
+                    try std.testing.expect(actual.random_coeff.eql(random_coeff.pow(expected.power_index)));
+
+                    const input = v.samples[tree_idx][col_idx][expected.sample_index];
+                    try std.testing.expect(actual.value.eql(qm31From(input.value)));
+                    try std.testing.expectEqual(!expected.periodic, actual.point.eql(circleQM31From(input.point)));
+                }
+            }
         }
     }
+    for (lifting_gaps[1..]) |covered| try std.testing.expect(covered);
 }
 
 test "field vectors: pcs preprocessed query positions parity" {
@@ -893,6 +2033,65 @@ test "field vectors: pcs preprocessed query positions parity" {
     }
 }
 
+/// Upstream `VerificationError` variant a `friAnswers` failure corresponds to. Upstream reports
+/// every malformed shape as `InvalidStructure`; Zig splits it into finer errors.
+fn upstreamFriAnswersErrorName(err: anyerror) []const u8 {
+    return switch (err) {
+        error.InvalidStructure,
+        error.ShapeMismatch,
+        error.EmptySampledSet,
+        error.ColumnIndexOutOfBounds,
+        error.QueryPositionOutOfRange,
+        => "InvalidStructure",
+        else => @errorName(err),
+    };
+}
+
+test "field vectors: fri answers error classification" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    var failing: usize = 0;
+    const vectors = parsed.value.fri_answers_errors orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var column_log_sizes = try decodeColumnLogSizes(alloc, v.column_log_sizes);
+        defer column_log_sizes.deinitDeep(alloc);
+        var samples = try decodeSamplesTree(alloc, v.samples);
+        defer samples.deinitDeep(alloc);
+        var split_samples = try splitPointSamplesTree(alloc, samples);
+        defer split_samples.deinit(alloc);
+        var queried_values = try decodeQueriedValuesTree(alloc, v.queried_values);
+        defer queried_values.deinitDeep(alloc);
+
+        const result = quotients_mod.friAnswers(
+            alloc,
+            column_log_sizes,
+            split_samples.points,
+            split_samples.values,
+            qm31From(v.random_coeff),
+            v.query_positions,
+            queried_values,
+            v.lifting_log_size,
+        );
+        if (result) |answers| {
+            alloc.free(answers);
+            try std.testing.expectEqualStrings(v.expected, "ok");
+        } else |err| {
+            failing += 1;
+            // Upstream panics where Zig must still fail cleanly; any error matches "Panic".
+            if (!std.mem.eql(u8, v.expected, "Panic")) {
+                std.testing.expectEqualStrings(v.expected, upstreamFriAnswersErrorName(err)) catch |mismatch| {
+                    std.debug.print("fri answers case {s} failed with {s}\n", .{ v.case, @errorName(err) });
+                    return mismatch;
+                };
+            }
+        }
+    }
+    try std.testing.expect(failing > 0);
+}
+
 test "field vectors: fri fold parity" {
     const alloc = std.testing.allocator;
     var parsed = try parseVectors(alloc);
@@ -928,6 +2127,208 @@ test "field vectors: fri fold parity" {
     }
 }
 
+test "field vectors: fri layer count parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    var zero_inner_layers: usize = 0;
+    const vectors = parsed.value.fri_layer_count orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const config = try fri_mod.FriConfig.init(v.log_last_layer_degree_bound, v.log_blowup, 1);
+        try std.testing.expectEqual(v.column_log_size + config.log_blowup_factor, v.first_layer_domain_log_size);
+        try std.testing.expectEqual(
+            @as(usize, 1) << @intCast(v.last_layer_domain_log_size),
+            config.lastLayerDomainSize(),
+        );
+        try std.testing.expectEqual(@as(usize, 1) << @intCast(v.log_last_layer_degree_bound), v.last_layer_poly_len);
+
+        // The verifier's walk: fold the line bound until it reaches the last-layer bound.
+        var layer_bound = fri_mod.CirclePolyDegreeBound.init(v.column_log_size).foldToLine();
+        try std.testing.expectEqual(v.valid, layer_bound.logDegreeBound() >= v.log_last_layer_degree_bound);
+        if (!v.valid) continue;
+        var verifier_layers: u32 = 0;
+        while (layer_bound.logDegreeBound() != v.log_last_layer_degree_bound) : (verifier_layers += 1) {
+            layer_bound = layer_bound.fold(fri_mod.FOLD_STEP).?;
+        }
+        try std.testing.expectEqual(v.n_inner_layers, verifier_layers);
+
+        // The prover's walk: halve the first inner line domain down to the last-layer domain.
+        var domain_size = @as(usize, 1) << @intCast(v.first_layer_domain_log_size - fri_mod.CIRCLE_TO_LINE_FOLD_STEP);
+        var prover_layers: u32 = 0;
+        while (domain_size > config.lastLayerDomainSize()) : (prover_layers += 1) domain_size >>= 1;
+        try std.testing.expectEqual(v.n_inner_layers, prover_layers);
+        if (v.n_inner_layers == 0) zero_inner_layers += 1;
+    }
+    try std.testing.expect(zero_inner_layers > 0);
+}
+
+test "field vectors: fri fold chain parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.fri_fold_chain orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expectEqual(v.alphas.len, v.layers.len);
+        const circle_domain = canonic_mod.CanonicCoset.new(v.circle_log_size).circleDomain();
+        const circle_eval = try decodeQm31Slice(alloc, v.circle_eval);
+        defer alloc.free(circle_eval);
+
+        var values = try alloc.alloc(QM31, circle_eval.len / 2);
+        defer alloc.free(values);
+        @memset(values, QM31.zero());
+        try fri_mod.foldCircleIntoLine(values, circle_eval, circle_domain, qm31From(v.alphas[0]));
+        var domain = try line_mod.LineDomain.init(circle_domain.half_coset);
+
+        // Layer 0 is the circle-to-line fold; every later layer folds the previous line once.
+        for (v.layers, 0..) |layer, i| {
+            if (i > 0) {
+                const folded = try fri_mod.foldLine(alloc, values, domain, qm31From(v.alphas[i]));
+                alloc.free(values);
+                values = folded.values;
+                domain = folded.domain;
+            }
+            try std.testing.expectEqual(layer.domain_log_size, domain.logSize());
+            try std.testing.expectEqual(layer.domain_initial_index, domain.coset().initial_index.v);
+            try std.testing.expectEqual(layer.domain_step_size, domain.coset().step_size.v);
+            try std.testing.expectEqual(layer.values.len, values.len);
+            for (layer.values, values) |expected, value| try std.testing.expect(value.eql(qm31From(expected)));
+        }
+        try std.testing.expectEqual(v.last_layer_log_size, domain.logSize());
+
+        var evaluation = try prover_line_mod.LineEvaluation.initBorrowed(domain, values);
+        var poly = try evaluation.interpolate(alloc);
+        defer poly.deinit(alloc);
+        try std.testing.expectEqual(v.last_layer_coeffs_bit_reversed.len, poly.len());
+        for (v.last_layer_coeffs_bit_reversed, poly.coefficients()) |expected, coeff| {
+            try std.testing.expect(coeff.eql(qm31From(expected)));
+        }
+        for (v.last_layer_coeffs_ordered, poly.intoOrderedCoefficients()) |expected, coeff| {
+            try std.testing.expect(coeff.eql(qm31From(expected)));
+        }
+    }
+}
+
+/// Applies `fold` (`fold_line` over the half-odds line domain or `fold_circle_into_line` over the
+/// canonic circle domain) of size `2^log_size` to `values`; the caller owns the result.
+fn applyFriFold(alloc: std.mem.Allocator, fold: []const u8, log_size: u32, values: []const QM31, alpha: QM31) ![]QM31 {
+    if (std.mem.eql(u8, fold, "fold_line")) {
+        const domain = try line_mod.LineDomain.init(circle_mod.Coset.halfOdds(log_size));
+        return (try fri_mod.foldLine(alloc, values, domain, alpha)).values;
+    }
+    if (!std.mem.eql(u8, fold, "fold_circle_into_line")) return error.UnknownFriFold;
+    const folded = try alloc.alloc(QM31, values.len / 2);
+    errdefer alloc.free(folded);
+    @memset(folded, QM31.zero());
+    try fri_mod.foldCircleIntoLine(folded, values, canonic_mod.CanonicCoset.new(log_size).circleDomain(), alpha);
+    return folded;
+}
+
+test "field vectors: fri fold ordering contract" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.fri_fold_ordering orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expectEqualStrings("bit_reversed", v.input_order);
+        const coset = if (std.mem.eql(u8, v.fold, "fold_line"))
+            circle_mod.Coset.halfOdds(v.log_size)
+        else
+            canonic_mod.CanonicCoset.new(v.log_size).halfCoset();
+        try std.testing.expectEqual(v.domain_initial_index, coset.initial_index.v);
+        try std.testing.expectEqual(v.domain_step_size, coset.step_size.v);
+
+        const bit_reversed = try decodeQm31Slice(alloc, v.evaluations_bit_reversed);
+        defer alloc.free(bit_reversed);
+        const natural = try decodeQm31Slice(alloc, v.evaluations_natural);
+        defer alloc.free(natural);
+        utils_mod.bitReverse(QM31, natural);
+        for (natural, bit_reversed) |a, b| try std.testing.expect(a.eql(b));
+        utils_mod.bitReverse(QM31, natural);
+
+        const alpha = qm31From(v.alpha);
+        const folded = try applyFriFold(alloc, v.fold, v.log_size, bit_reversed, alpha);
+        defer alloc.free(folded);
+        for (v.folded_bit_reversed, folded) |expected, value| try std.testing.expect(value.eql(qm31From(expected)));
+        for (v.folded_natural, v.output_permutation) |expected, index| {
+            try std.testing.expect(folded[index].eql(qm31From(expected)));
+        }
+
+        // Natural-order input folds to something that is not a reordering of the correct output.
+        const misordered = try applyFriFold(alloc, v.fold, v.log_size, natural, alpha);
+        defer alloc.free(misordered);
+        for (v.misordered_fold, misordered) |expected, value| try std.testing.expect(value.eql(qm31From(expected)));
+    }
+}
+
+test "field vectors: fri last layer parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    var rejected: usize = 0;
+    const vectors = parsed.value.fri_last_layer orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const config = try fri_mod.FriConfig.init(v.log_last_layer_degree_bound, v.log_blowup_factor, 1);
+        const coeffs = try alloc.alloc(QM31, v.coeffs.len);
+        for (v.coeffs, 0..) |value, i| coeffs[i] = qm31From(value);
+        var poly = line_mod.LinePoly.initOwned(coeffs);
+        defer poly.deinit(alloc);
+
+        if (fri_mod.checkLastLayerDegree(config, poly)) |_| {
+            try std.testing.expectEqualStrings("ok", v.expected);
+        } else |err| {
+            try std.testing.expectEqualStrings(v.expected, @errorName(err));
+            rejected += 1;
+        }
+
+        const domain = try line_mod.LineDomain.init(circle_mod.Coset.halfOdds(v.last_layer_domain_log_size));
+        try std.testing.expectEqual(v.query_positions.len, v.query_x.len);
+        try std.testing.expectEqual(v.query_positions.len, v.evaluations.len);
+        for (v.query_positions, v.query_x, v.evaluations) |position, x, expected| {
+            const point = domain.at(utils_mod.bitReverseIndex(position, v.last_layer_domain_log_size));
+            try std.testing.expectEqual(x, point.toU32());
+            const value = try poly.evalAtPoint(alloc, QM31.fromBase(point));
+            try std.testing.expect(value.eql(qm31From(expected)));
+        }
+    }
+    try std.testing.expect(rejected > 0);
+}
+
+test "field vectors: line poly construction parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    var rejected: usize = 0;
+    const vectors = parsed.value.line_poly_construction orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expectEqual(v.coeff_count, v.coeffs.len);
+        const coeffs = try decodeQm31Slice(alloc, v.coeffs);
+        // Where upstream panics, the checked constructor must reject instead.
+        var poly = line_mod.LinePoly.init(coeffs) catch |err| {
+            alloc.free(coeffs);
+            try std.testing.expectEqual(line_mod.LinePoly.Error.InvalidCoefficientCount, err);
+            try std.testing.expectEqualStrings("Panic", v.expected);
+            try std.testing.expect(v.log_size == null and v.evaluation == null);
+            rejected += 1;
+            continue;
+        };
+        defer poly.deinit(alloc);
+        try std.testing.expectEqualStrings("ok", v.expected);
+        try std.testing.expectEqual(v.log_size.?, poly.log_size);
+        const value = try poly.evalAtPoint(alloc, qm31From(v.eval_point));
+        try std.testing.expect(value.eql(qm31From(v.evaluation.?)));
+    }
+    try std.testing.expect(rejected > 0);
+}
+
 test "field vectors: fri decommit parity" {
     const alloc = std.testing.allocator;
 
@@ -1104,6 +2505,159 @@ test "field vectors: fri layer decommit parity" {
     }
 }
 
+test "field vectors: fri layer chain decommit parity" {
+    const alloc = std.testing.allocator;
+    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+    const Prover = vcs_lifted_prover_mod.MerkleProverLifted(Hasher);
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const chains = parsed.value.fri_layer_chain_decommit orelse return;
+    try std.testing.expect(chains.len > 0);
+    for (chains) |chain| {
+        var queries = try queries_mod.Queries.init(alloc, chain.query_positions, chain.layers[0].log_size);
+        defer queries.deinit(alloc);
+
+        for (chain.layers) |layer| {
+            // Folded positions must already be deduplicated when they reach the next layer.
+            try std.testing.expectEqual(layer.log_size, queries.log_domain_size);
+            try std.testing.expectEqualSlices(usize, layer.query_positions, queries.positions);
+
+            const column = try alloc.alloc(QM31, layer.column.len);
+            defer alloc.free(column);
+            for (layer.column, 0..) |value, i| column[i] = qm31From(value);
+            var secure_column = try prover_secure_column_mod.SecureColumnByCoords.fromSecureSlice(alloc, column);
+            defer secure_column.deinit(alloc);
+
+            const coord_columns = [_][]const M31{
+                secure_column.columns[0],
+                secure_column.columns[1],
+                secure_column.columns[2],
+                secure_column.columns[3],
+            };
+            var merkle = try Prover.commit(alloc, coord_columns[0..]);
+            defer merkle.deinit(alloc);
+
+            var result = try prover_fri_mod.decommitLayer(
+                Hasher,
+                alloc,
+                merkle,
+                secure_column,
+                queries.positions,
+                layer.fold_step,
+            );
+            defer result.deinit(alloc);
+
+            try std.testing.expect(std.mem.eql(
+                u8,
+                std.mem.asBytes(&layer.commitment),
+                std.mem.asBytes(&result.proof.commitment),
+            ));
+            try std.testing.expectEqualSlices(usize, layer.decommitment_positions, result.decommitment_positions);
+            try std.testing.expectEqual(layer.fri_witness.len, result.proof.fri_witness.len);
+            for (layer.fri_witness, 0..) |expected, i| {
+                try std.testing.expect(result.proof.fri_witness[i].eql(qm31From(expected)));
+            }
+            try std.testing.expectEqual(layer.hash_witness.len, result.proof.decommitment.hash_witness.len);
+            for (layer.hash_witness, 0..) |expected, i| {
+                try std.testing.expect(std.mem.eql(
+                    u8,
+                    std.mem.asBytes(&expected),
+                    std.mem.asBytes(&result.proof.decommitment.hash_witness[i]),
+                ));
+            }
+
+            const folded = try queries.fold(alloc, layer.fold_step);
+            queries.deinit(alloc);
+            queries = folded;
+        }
+    }
+}
+
+test "field vectors: fri first layer decommit parity" {
+    const alloc = std.testing.allocator;
+    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+    const Prover = vcs_lifted_prover_mod.MerkleProverLifted(Hasher);
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.fri_first_layer_decommit orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const max_log_size = v.log_sizes[v.log_sizes.len - 1];
+
+        const secure_columns = try alloc.alloc(prover_secure_column_mod.SecureColumnByCoords, v.columns.len);
+        var n_secure_columns: usize = 0;
+        defer {
+            for (secure_columns[0..n_secure_columns]) |*column| column.deinit(alloc);
+            alloc.free(secure_columns);
+        }
+        const coord_columns = try alloc.alloc([]const M31, 4 * v.columns.len);
+        defer alloc.free(coord_columns);
+
+        var fri_witness = std.ArrayList(QM31).empty;
+        defer fri_witness.deinit(alloc);
+
+        for (v.columns, v.log_sizes, v.column_query_positions, 0..) |values, log_size, positions, i| {
+            const column = try alloc.alloc(QM31, values.len);
+            defer alloc.free(column);
+            for (values, 0..) |value, j| column[j] = qm31From(value);
+
+            // Each column answers the shared queries at their lifted index.
+            const shift: std.math.Log2Int(usize) = @intCast(max_log_size - log_size + 1);
+            var lifted = std.ArrayList(usize).empty;
+            defer lifted.deinit(alloc);
+            for (v.query_positions) |position| {
+                try lifted.append(alloc, ((position >> shift) << 1) + (position & 1));
+            }
+            std.mem.sort(usize, lifted.items, {}, std.sort.asc(usize));
+            var n_unique: usize = 0;
+            for (lifted.items) |position| {
+                if (n_unique == 0 or lifted.items[n_unique - 1] != position) {
+                    lifted.items[n_unique] = position;
+                    n_unique += 1;
+                }
+            }
+            try std.testing.expectEqualSlices(usize, positions, lifted.items[0..n_unique]);
+
+            var helper = try prover_fri_mod.computeDecommitmentPositionsAndWitnessEvals(
+                alloc,
+                column,
+                positions,
+                fri_mod.CIRCLE_TO_LINE_FOLD_STEP,
+            );
+            defer helper.deinit(alloc);
+            try fri_witness.appendSlice(alloc, helper.witness_evals);
+            if (log_size == max_log_size) {
+                try std.testing.expectEqualSlices(usize, v.decommitment_positions, helper.decommitment_positions);
+            }
+
+            secure_columns[i] = try prover_secure_column_mod.SecureColumnByCoords.fromSecureSlice(alloc, column);
+            n_secure_columns += 1;
+            for (0..4) |coord| coord_columns[4 * i + coord] = secure_columns[i].columns[coord];
+        }
+
+        try std.testing.expectEqual(v.fri_witness.len, fri_witness.items.len);
+        for (v.fri_witness, 0..) |expected, i| {
+            try std.testing.expect(fri_witness.items[i].eql(qm31From(expected)));
+        }
+
+        var merkle = try Prover.commit(alloc, coord_columns);
+        defer merkle.deinit(alloc);
+        try std.testing.expect(std.mem.eql(u8, std.mem.asBytes(&v.commitment), std.mem.asBytes(&merkle.root())));
+
+        var decommitment = try merkle.decommit(alloc, v.decommitment_positions, coord_columns);
+        defer decommitment.deinit(alloc);
+        const hash_witness = decommitment.decommitment.decommitment.hash_witness;
+        try std.testing.expectEqual(v.hash_witness.len, hash_witness.len);
+        for (v.hash_witness, 0..) |expected, i| {
+            try std.testing.expect(std.mem.eql(u8, std.mem.asBytes(&expected), std.mem.asBytes(&hash_witness[i])));
+        }
+    }
+}
+
 test "field vectors: proof extract oods parity" {
     const alloc = std.testing.allocator;
     const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
@@ -1125,9 +2679,14 @@ test "field vectors: proof extract oods parity" {
             initialized += 1;
         }
 
-        const sampled_values = quotients_mod.TreeVec([][]QM31).initOwned(
-            try alloc.dupe([][]QM31, &[_][][]QM31{composition_tree}),
-        );
+        const sampled_values = if (v.has_composition_tree)
+            quotients_mod.TreeVec([][]QM31).initOwned(
+                try alloc.dupe([][]QM31, &[_][][]QM31{composition_tree}),
+            )
+        else blk: {
+            alloc.free(composition_tree);
+            break :blk quotients_mod.TreeVec([][]QM31).initOwned(try alloc.alloc([][]QM31, 0));
+        };
         var proof = proof_mod.StarkProof(Hasher){
             .commitment_scheme_proof = .{
                 .config = pcs_mod.PcsConfig.default(),
@@ -1160,8 +2719,108 @@ test "field vectors: proof extract oods parity" {
         const extracted = proof.extractCompositionOodsEval(
             circleQM31From(v.oods_point),
             v.composition_log_size,
-        ) orelse unreachable;
-        try std.testing.expectEqualSlices(u32, v.expected[0..], encodeQM31(extracted)[0..]);
+        );
+        if (v.expected_error != null) {
+            try std.testing.expect(extracted == null);
+            continue;
+        }
+        const expected = v.expected orelse return error.MissingExpectedValue;
+        const value = extracted orelse return error.UnexpectedExtractFailure;
+        try std.testing.expectEqualSlices(u32, expected[0..], encodeQM31(value)[0..]);
+    }
+}
+
+test "field vectors: composition sampled order parity" {
+    const alloc = std.testing.allocator;
+    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
+    const vcs_verifier = @import("../vcs_lifted/verifier.zig");
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.composition_sampled_order orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expect(v.sampled_values.len >= 3);
+        const composition_tree = v.sampled_values.len - 1;
+        try std.testing.expectEqual(2 * qm31_mod.SECURE_EXTENSION_DEGREE, v.sampled_values[composition_tree].len);
+
+        // Cells walk the TreeVec tree by tree, column by column, mask by mask.
+        var cell_index: usize = 0;
+        var composition_index: usize = 0;
+        for (v.sampled_values, 0..) |columns, tree| {
+            for (columns, 0..) |masks, column| {
+                for (masks, 0..) |value, mask| {
+                    const cell = v.cells[cell_index];
+                    try std.testing.expectEqual(tree, cell.tree);
+                    try std.testing.expectEqual(column, cell.column);
+                    try std.testing.expectEqual(mask, cell.mask);
+                    try std.testing.expectEqualSlices(u32, value[0..], cell.value[0..]);
+                    if (tree == composition_tree) {
+                        try std.testing.expectEqual(cell_index, v.composition_cells[composition_index]);
+                        composition_index += 1;
+                        const coordinate = cell.composition_coordinate orelse return error.MissingCompositionCoordinate;
+                        const half: []const u8 = if (column < qm31_mod.SECURE_EXTENSION_DEGREE) "left" else "right";
+                        try std.testing.expectEqualStrings(half, coordinate[0]);
+                        try std.testing.expectEqual(column % qm31_mod.SECURE_EXTENSION_DEGREE, coordinate[1]);
+                    } else {
+                        try std.testing.expect(cell.composition_coordinate == null);
+                    }
+                    cell_index += 1;
+                }
+            }
+        }
+        try std.testing.expectEqual(v.cells.len, cell_index);
+        try std.testing.expectEqual(v.composition_cells.len, composition_index);
+
+        var left: [qm31_mod.SECURE_EXTENSION_DEGREE]QM31 = undefined;
+        var right: [qm31_mod.SECURE_EXTENSION_DEGREE]QM31 = undefined;
+        for (v.composition_cells) |index| {
+            const cell = v.cells[index];
+            const coordinate = cell.composition_coordinate.?;
+            if (std.mem.eql(u8, coordinate[0], "left")) {
+                left[coordinate[1]] = qm31From(cell.value);
+            } else {
+                right[coordinate[1]] = qm31From(cell.value);
+            }
+        }
+        const oods_point = circleQM31From(v.oods_point);
+        const from_cells = QM31.fromPartialEvals(left).add(
+            oods_point.repeatedDouble(v.composition_log_size - 2).x.mul(QM31.fromPartialEvals(right)),
+        );
+        try std.testing.expectEqualSlices(u32, v.composition_oods_eval[0..], encodeQM31(from_cells)[0..]);
+
+        var proof = proof_mod.StarkProof(Hasher){
+            .commitment_scheme_proof = .{
+                .config = pcs_mod.PcsConfig.default(),
+                .commitments = quotients_mod.TreeVec(Hasher.Hash).initOwned(
+                    try alloc.alloc(Hasher.Hash, 0),
+                ),
+                .sampled_values = try decodeQm31Tree(alloc, v.sampled_values),
+                .decommitments = quotients_mod.TreeVec(vcs_verifier.MerkleDecommitmentLifted(Hasher)).initOwned(
+                    try alloc.alloc(vcs_verifier.MerkleDecommitmentLifted(Hasher), 0),
+                ),
+                .queried_values = quotients_mod.TreeVec([][]M31).initOwned(
+                    try alloc.alloc([][]M31, 0),
+                ),
+                .proof_of_work = 0,
+                .fri_proof = .{
+                    .first_layer = .{
+                        .fri_witness = try alloc.alloc(QM31, 0),
+                        .decommitment = .{ .hash_witness = try alloc.alloc(Hasher.Hash, 0) },
+                        .commitment = [_]u8{0} ** 32,
+                    },
+                    .inner_layers = try alloc.alloc(fri_mod.FriLayerProof(Hasher), 0),
+                    .last_layer_poly = line_mod.LinePoly.initOwned(
+                        try alloc.dupe(QM31, &[_]QM31{QM31.one()}),
+                    ),
+                },
+            },
+        };
+        defer proof.deinit(alloc);
+
+        const extracted = proof.extractCompositionOodsEval(oods_point, v.composition_log_size) orelse
+            return error.UnexpectedExtractFailure;
+        try std.testing.expectEqualSlices(u32, v.composition_oods_eval[0..], encodeQM31(extracted)[0..]);
     }
 }
 
@@ -1266,6 +2925,120 @@ test "field vectors: proof size breakdown parity" {
     }
 }
 
+test "field vectors: families map matches loaded families" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    // Every family loaded here must be one the generator claims, with the claimed count.
+    const families = parsed.value.families orelse return;
+    inline for (@typeInfo(VectorFile).@"struct".fields) |field| {
+        const info = @typeInfo(field.type);
+        const optional = info == .optional;
+        const family = comptime !std.mem.eql(u8, field.name, "generation_stats");
+        if (family and (info == .pointer or (optional and @typeInfo(info.optional.child) == .pointer))) {
+            const value = @field(parsed.value, field.name);
+            const entries = if (optional) value orelse continue else value;
+            const claimed = families.map.get(field.name) orelse return error.UnclaimedVectorFamily;
+            try std.testing.expectEqual(claimed.count, entries.len);
+        }
+    }
+    const known_answer = parsed.value.known_answer orelse return;
+    const claimed = families.map.get("known_answer") orelse return error.UnclaimedVectorFamily;
+    try std.testing.expectEqual(claimed.count, known_answer.field_ops.len + known_answer.merkle.len);
+}
+
+test "field vectors: generation stats cover every family" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const stats = parsed.value.generation_stats orelse return;
+    try std.testing.expect(stats.len > 0);
+    for (stats) |phase| try std.testing.expect(phase.seconds >= 0);
+
+    // Each family's claimed count is the sum of the cases of the phases that generated it.
+    const families = parsed.value.families orelse return;
+    var it = families.map.iterator();
+    while (it.next()) |family| {
+        var cases: usize = 0;
+        for (family.value_ptr.generator) |generator| {
+            for (stats) |phase| {
+                if (std.mem.eql(u8, phase.family, generator)) cases += phase.cases;
+            }
+        }
+        try std.testing.expectEqual(family.value_ptr.count, cases);
+    }
+}
+
+test "field vectors: error taxonomy names exist in zig error sets" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const taxonomy = parsed.value.error_taxonomy orelse return;
+    const lifted_verifier = @import("../vcs_lifted/verifier.zig");
+    try expectTaxonomyErrorsIn(vcs_verifier_mod.MerkleVerificationError, taxonomy, "vcs_verifier");
+    try expectTaxonomyErrorsIn(lifted_verifier.MerkleVerificationError, taxonomy, "vcs_lifted_verifier");
+    try expectTaxonomyErrorsIn(lifted_verifier.MerkleVerificationError, taxonomy, "vcs_lifted_error_variants");
+    try expectTaxonomyErrorsIn(prover_fri_mod.FriDecommitError, taxonomy, "fri_decommit");
+    try expectTaxonomyErrorsIn(prover_fri_mod.FriDecommitError, taxonomy, "fri_layer_decommit");
+    try expectTaxonomyErrorsIn(fri_mod.FriVerificationError, taxonomy, "fri_last_layer");
+}
+
+test "field vectors: entry ids are unique across the file" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    // Schema 5+ gives every entry an id; older files carry none and pass trivially.
+    var seen = std.StringHashMap([]const u8).init(alloc);
+    defer seen.deinit();
+    inline for (std.meta.fields(VectorFile)) |field| {
+        const info = @typeInfo(field.type);
+        const optional = info == .optional;
+        const family = comptime !std.mem.eql(u8, field.name, "generation_stats");
+        if (family and (info == .pointer or (optional and @typeInfo(info.optional.child) == .pointer))) {
+            const value = @field(parsed.value, field.name);
+            const entries = if (optional) value orelse continue else value;
+            for (entries) |entry| {
+                const id = entry.id orelse continue;
+                if (try seen.fetchPut(id, field.name)) |previous| {
+                    std.debug.print("duplicate vector id {s} in {s} and {s}\n", .{ id, previous.value, field.name });
+                    return error.DuplicateVectorId;
+                }
+            }
+        }
+    }
+}
+
+test "field vectors: packed column round trip" {
+    const alloc = std.testing.allocator;
+
+    // Fixed string from the generator's encoder: [1, P - 1, 0x00010203] as little-endian bytes.
+    const fixed = try decodePackedColumn(alloc, "AQAAAP7//38DAgEA");
+    defer alloc.free(fixed);
+    try std.testing.expectEqualSlices(u32, &[_]u32{ 1, 0x7ffffffe, 0x00010203 }, fixed);
+    try std.testing.expectError(error.InvalidPackedColumn, decodePackedColumn(alloc, "AQA="));
+
+    // Packing the loaded plain trace columns and decoding them gives the columns back.
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+    const encoder = std.base64.standard.Encoder;
+    for (parsed.value.example_plonk_trace) |v| {
+        for ([_][][]u32{ v.preprocessed, v.main }) |columns| {
+            for (columns) |column| {
+                const encoded = try alloc.alloc(u8, encoder.calcSize(column.len * 4));
+                defer alloc.free(encoded);
+                _ = encoder.encode(encoded, std.mem.sliceAsBytes(column));
+                const decoded = try decodePackedColumn(alloc, encoded);
+                defer alloc.free(decoded);
+                try std.testing.expectEqualSlices(u32, column, decoded);
+            }
+        }
+    }
+}
+
 test "field vectors: prover line interpolation parity" {
     const alloc = std.testing.allocator;
     const LineEvaluation = prover_line_mod.LineEvaluation;
@@ -1329,6 +3102,8 @@ test "field vectors: vcs verifier parity" {
 
     try std.testing.expect(parsed.value.vcs_verifier.len > 0);
     for (parsed.value.vcs_verifier) |v| {
+        // Upstream panics cannot be replayed here; the Zig verifier asserts on those layouts.
+        if (std.mem.eql(u8, v.expected, "Panic")) continue;
         var verifier = try Verifier.init(alloc, v.root, v.column_log_sizes);
         defer verifier.deinit(alloc);
 
@@ -1445,57 +3220,112 @@ test "field vectors: vcs prover parity" {
     }
 }
 
-test "field vectors: vcs lifted verifier parity" {
+test "field vectors: pcs config security accounting" {
     const alloc = std.testing.allocator;
-    const Hasher = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher;
-    const Verifier = @import("../vcs_lifted/verifier.zig").MerkleVerifierLifted(Hasher);
-    const Decommitment = @import("../vcs_lifted/verifier.zig").MerkleDecommitmentLifted(Hasher);
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.config_security orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const fri_config = fri_mod.FriConfig.init(
+            v.log_last_layer_degree_bound,
+            v.log_blowup_factor,
+            v.n_queries,
+        );
+        if (std.mem.eql(u8, v.classification, "rejected_fri_config")) {
+            try std.testing.expect(std.meta.isError(fri_config));
+        } else {
+            _ = try fri_config;
+            try std.testing.expectEqual(
+                std.mem.eql(u8, v.classification, "no_queries"),
+                v.n_queries == 0,
+            );
+        }
+
+        // The estimate is defined on the raw fields, so rejected configs are checked too.
+        const config = pcs_mod.PcsConfig{
+            .pow_bits = v.pow_bits,
+            .fri_config = .{
+                .log_blowup_factor = v.log_blowup_factor,
+                .log_last_layer_degree_bound = v.log_last_layer_degree_bound,
+                .n_queries = v.n_queries,
+            },
+        };
+        try std.testing.expectEqual(v.security_bits, config.securityBits());
+        if (v.target_bits) |target_bits| {
+            try std.testing.expectEqual(v.meets_target.?, config.securityBits() >= target_bits);
+        }
+    }
+}
 
+test "field vectors: vcs lifted verifier parity" {
+    const alloc = std.testing.allocator;
     var parsed = try parseVectors(alloc);
     defer parsed.deinit();
 
     try std.testing.expect(parsed.value.vcs_lifted_verifier.len > 0);
-    for (parsed.value.vcs_lifted_verifier) |v| {
-        var verifier = try Verifier.init(alloc, v.root, v.column_log_sizes);
-        defer verifier.deinit(alloc);
+    for (parsed.value.vcs_lifted_verifier) |v| try checkVcsLiftedVerifierCase(alloc, v);
+}
 
-        const queried_values = try alloc.alloc([]const M31, v.queried_values.len);
-        defer alloc.free(queried_values);
+test "field vectors: queried rows reshape parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
 
-        const queried_values_owned = try alloc.alloc([]M31, v.queried_values.len);
-        defer {
-            for (queried_values_owned) |col| alloc.free(col);
-            alloc.free(queried_values_owned);
+    const vectors = parsed.value.queried_rows_reshape orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try checkVcsLiftedVerifierCase(alloc, .{
+            .case = "queried_rows_reshape",
+            .root = v.root,
+            .column_log_sizes = v.column_log_sizes,
+            .query_positions = v.query_positions,
+            .queried_values = v.queried_values,
+            .hash_witness = v.hash_witness,
+            .expected = "ok",
+        });
+
+        try std.testing.expectEqual(v.column_log_sizes.len, v.queried_values.len);
+        try std.testing.expectEqual(v.column_log_sizes.len, v.source_rows.len);
+        try std.testing.expectEqual(v.query_positions.len, v.query_rows.len);
+        for (v.column_log_sizes, v.queried_values, v.source_rows, 0..) |log_size, values, rows, c| {
+            try std.testing.expect(log_size <= v.query_log_size);
+            try std.testing.expectEqual(v.query_positions.len, values.len);
+            try std.testing.expectEqual(v.query_positions.len, rows.len);
+            // A column smaller than the query domain is lifted: the position keeps its low bit
+            // and drops the `shift` bits above it.
+            const shift: u6 = @intCast(v.query_log_size - log_size);
+            for (v.query_positions, values, rows, 0..) |pos, value, row, q| {
+                try std.testing.expectEqual(((pos >> (shift + 1)) << 1) + (pos & 1), row);
+                // Sentinel cells name their source, so this pins the row the VCS read.
+                const sentinel = (@as(u32, @intCast(c + 1)) << 16) | @as(u32, @intCast(row));
+                try std.testing.expectEqual(sentinel, value);
+                try std.testing.expectEqual(value, v.query_rows[q][c]);
+            }
         }
-
-        for (v.queried_values, 0..) |column, i| {
-            queried_values_owned[i] = try alloc.alloc(M31, column.len);
-            for (column, 0..) |value, j| queried_values_owned[i][j] = m31From(value);
-            queried_values[i] = queried_values_owned[i];
+        for (v.query_rows) |query_row| {
+            try std.testing.expectEqual(v.column_log_sizes.len, query_row.len);
         }
+    }
+}
 
-        var decommitment = Decommitment{
-            .hash_witness = try alloc.dupe(Hasher.Hash, v.hash_witness),
-        };
-        defer decommitment.deinit(alloc);
+test "field vectors: vcs lifted error variants parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
 
-        if (std.mem.eql(u8, v.expected, "ok")) {
-            try verifier.verify(
-                alloc,
-                v.query_positions,
-                queried_values,
-                decommitment,
-            );
-        } else {
-            try std.testing.expectError(
-                expectedVcsLiftedError(v.expected),
-                verifier.verify(
-                    alloc,
-                    v.query_positions,
-                    queried_values,
-                    decommitment,
-                ),
-            );
+    const vectors = parsed.value.vcs_lifted_error_variants orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        // The Zig error set must carry exactly the variants upstream can emit.
+        const reachable = std.mem.eql(u8, v.status, "reachable");
+        try std.testing.expect(reachable or std.mem.eql(u8, v.status, "unreachable_variant"));
+        try std.testing.expectEqual(reachable, isVcsLiftedErrorName(v.variant));
+        for (v.probes) |probe| {
+            // Upstream panics cannot be replayed here; the Zig verifier asserts on those layouts.
+            if (std.mem.eql(u8, probe.expected, "Panic")) continue;
+            try checkVcsLiftedVerifierCase(alloc, probe);
         }
     }
 }
@@ -1553,6 +3383,32 @@ test "field vectors: vcs lifted prover parity" {
                 std.mem.asBytes(&decommitment.decommitment.decommitment.hash_witness[i]),
             ));
         }
+        if (v.case) |case| {
+            if (std.mem.eql(u8, case, "all_leaves_queried")) {
+                try std.testing.expectEqual(@as(usize, 0), v.hash_witness.len);
+            }
+        }
+
+        // Each layer's node values are the sibling pairs of its queries, in consumption order.
+        if (v.layer_queries) |layer_queries| {
+            const all_node_values = decommitment.decommitment.aux.all_node_values;
+            try std.testing.expectEqual(layer_queries.len, all_node_values.len);
+            var prev_len: usize = 0;
+            for (v.query_positions, 0..) |position, i| {
+                if (i == 0 or v.query_positions[i - 1] != position) prev_len += 1;
+            }
+            var witness_consumed: usize = 0;
+            for (layer_queries, all_node_values) |queries, node_values| {
+                try std.testing.expectEqual(2 * queries.len, node_values.len);
+                for (queries, 0..) |query, q| {
+                    try std.testing.expectEqual(2 * query, node_values[2 * q].index);
+                    try std.testing.expectEqual(2 * query + 1, node_values[2 * q + 1].index);
+                }
+                witness_consumed += 2 * queries.len - prev_len;
+                prev_len = queries.len;
+            }
+            try std.testing.expectEqual(v.hash_witness.len, witness_consumed);
+        }
 
         const queried_values = try alloc.alloc([]const M31, decommitment.queried_values.len);
         defer alloc.free(queried_values);
@@ -1569,6 +3425,325 @@ test "field vectors: vcs lifted prover parity" {
     }
 }
 
+test "field vectors: oods point draw parity" {
+    const alloc = std.testing.allocator;
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+    const MerkleChannel = @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleChannel;
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.oods_draw orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var channel = Channel{};
+        for (v.mixes) |mix| {
+            if (std.mem.eql(u8, mix.op, "mix_u64")) {
+                channel.mixU64(mix.value_u64.?);
+            } else if (std.mem.eql(u8, mix.op, "mix_u32s")) {
+                channel.mixU32s(mix.values_u32.?);
+            } else if (std.mem.eql(u8, mix.op, "mix_felts")) {
+                const felts = try decodeQm31Slice(alloc, mix.felts.?);
+                defer alloc.free(felts);
+                channel.mixFelts(felts);
+            } else if (std.mem.eql(u8, mix.op, "mix_root")) {
+                MerkleChannel.mixRoot(&channel, mix.root.?);
+            } else {
+                return error.UnknownChannelMix;
+            }
+        }
+        try std.testing.expectEqualSlices(u8, v.digest_before[0..], channel.digestBytes()[0..]);
+
+        for (v.draws) |draw| {
+            var peek = channel;
+            try std.testing.expect(peek.drawSecureFelt().eql(qm31From(draw.t)));
+            const point = circle_mod.randomSecureFieldPoint(&channel);
+            try std.testing.expect(point.eql(circleQM31From(draw.point)));
+            try std.testing.expectEqualSlices(u8, draw.digest_after[0..], channel.digestBytes()[0..]);
+        }
+    }
+}
+
+test "field vectors: accumulation parity" {
+    const alloc = std.testing.allocator;
+    const air_accumulation = @import("../air/accumulation.zig");
+    const prover_accumulation = @import("../../prover/air/accumulation.zig");
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.accumulation orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const random_coeff = qm31From(v.random_coeff);
+
+        var point_accumulator = air_accumulation.PointEvaluationAccumulator.init(random_coeff);
+        for (v.evaluations) |evaluation| point_accumulator.accumulate(qm31From(evaluation));
+        try std.testing.expect(point_accumulator.finalize().eql(qm31From(v.accumulation)));
+
+        var domain_accumulator = try prover_accumulation.DomainEvaluationAccumulator.init(
+            alloc,
+            random_coeff,
+            v.max_log_size,
+            v.total_columns,
+        );
+        defer domain_accumulator.deinit();
+
+        const requests = try alloc.alloc(prover_accumulation.ColumnRequest, v.requests.len);
+        defer alloc.free(requests);
+        for (v.requests, requests) |request, *out| {
+            out.* = .{ .log_size = request.log_size, .n_cols = request.n_cols };
+        }
+        const columns = try domain_accumulator.columns(alloc, requests);
+        defer alloc.free(columns);
+
+        for (v.calls) |call| {
+            const column = &columns[call.column];
+            const coeff = column.random_coeff_powers[call.coeff_index];
+            column.accumulate(call.index, qm31From(call.evaluation).mul(coeff));
+        }
+        for (v.requests, columns) |request, column| {
+            try std.testing.expectEqual(request.random_coeff_powers.len, column.random_coeff_powers.len);
+            for (request.random_coeff_powers, column.random_coeff_powers) |expected, actual| {
+                try std.testing.expect(actual.eql(qm31From(expected)));
+            }
+            try std.testing.expectEqual(request.values.len, column.col.len());
+            for (request.values, 0..) |expected, row| {
+                try std.testing.expect(column.col.at(row).eql(qm31From(expected)));
+            }
+        }
+    }
+}
+
+test "field vectors: merkle avalanche parity" {
+    const alloc = std.testing.allocator;
+    const Prover = vcs_prover_mod.MerkleProver(@import("../vcs/blake2_merkle.zig").Blake2sMerkleHasher);
+    const LiftedProver = vcs_lifted_prover_mod.MerkleProverLifted(
+        @import("../vcs_lifted/blake2_merkle.zig").Blake2sMerkleHasher,
+    );
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.merkle_avalanche orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const lifted = std.mem.eql(u8, v.scheme, "vcs_lifted");
+        if (!lifted and !std.mem.eql(u8, v.scheme, "vcs")) return error.UnknownMerkleScheme;
+
+        const columns = try alloc.alloc([]const M31, v.columns.len);
+        defer alloc.free(columns);
+        const owned_columns = try alloc.alloc([]M31, v.columns.len);
+        defer {
+            for (owned_columns) |col| alloc.free(col);
+            alloc.free(owned_columns);
+        }
+        for (v.columns, 0..) |column, i| {
+            owned_columns[i] = try alloc.alloc(M31, column.len);
+            for (column, 0..) |value, j| owned_columns[i][j] = m31From(value);
+            columns[i] = owned_columns[i];
+        }
+
+        const root = if (lifted)
+            try merkleRoot(LiftedProver, alloc, columns)
+        else
+            try merkleRoot(Prover, alloc, columns);
+        try std.testing.expectEqualSlices(u8, v.root[0..], root[0..]);
+
+        for (v.perturbations) |p| {
+            try std.testing.expect(p.root_changed);
+            const original = owned_columns[p.column][p.index];
+            const flipped_bits = original.toU32() ^ p.value;
+            try std.testing.expect((flipped_bits >> @intCast(p.bit)) & 1 == 1);
+
+            owned_columns[p.column][p.index] = m31From(p.value);
+            const perturbed_root = if (lifted)
+                merkleRoot(LiftedProver, alloc, columns)
+            else
+                merkleRoot(Prover, alloc, columns);
+            owned_columns[p.column][p.index] = original;
+            const perturbed = try perturbed_root;
+            try std.testing.expectEqualSlices(u8, p.root[0..], perturbed[0..]);
+            try std.testing.expect(!std.mem.eql(u8, root[0..], perturbed[0..]));
+        }
+    }
+}
+
+test "field vectors: pcs commit roots parity" {
+    const alloc = std.testing.allocator;
+    const blake2_merkle = @import("../vcs_lifted/blake2_merkle.zig");
+    const Scheme = prover_pcs_mod.CommitmentSchemeProver(
+        blake2_merkle.Blake2sMerkleHasher,
+        blake2_merkle.Blake2sMerkleChannel,
+    );
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.pcs_commit orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var scheme = try Scheme.init(alloc, .{
+            .pow_bits = 0,
+            .fri_config = try fri_mod.FriConfig.init(0, v.log_blowup_factor, 3),
+        });
+        defer scheme.deinit(alloc);
+        var channel = Channel{};
+
+        for (v.trees) |tree| {
+            const columns = try alloc.alloc(prover_pcs_mod.ColumnEvaluation, tree.columns.len);
+            defer alloc.free(columns);
+            const owned_columns = try alloc.alloc([]M31, tree.columns.len);
+            defer {
+                for (owned_columns) |col| alloc.free(col);
+                alloc.free(owned_columns);
+            }
+
+            for (tree.columns, tree.column_log_sizes, 0..) |column, log_size, i| {
+                owned_columns[i] = try alloc.alloc(M31, column.len);
+                for (column, 0..) |value, j| owned_columns[i][j] = m31From(value);
+                columns[i] = .{ .log_size = log_size, .values = owned_columns[i] };
+            }
+            try scheme.commit(alloc, columns, &channel);
+        }
+
+        var roots = try scheme.roots(alloc);
+        defer roots.deinit(alloc);
+        try std.testing.expectEqual(v.trees.len, roots.items.len);
+        for (v.trees, roots.items, scheme.trees.items) |tree, root, committed| {
+            try std.testing.expect(std.mem.eql(u8, std.mem.asBytes(&tree.root), std.mem.asBytes(&root)));
+            try std.testing.expectEqual(tree.n_layers, committed.commitment.layers.len);
+
+            // Hash order is a stable ascending sort of the extended columns by log size.
+            try std.testing.expectEqual(tree.columns.len, tree.sorted_column_order.len);
+            for (tree.sorted_column_order, 0..) |column, i| {
+                try std.testing.expectEqual(
+                    tree.column_log_sizes[column] + v.log_blowup_factor,
+                    committed.columns[column].log_size,
+                );
+                if (i == 0) continue;
+                const prev = tree.sorted_column_order[i - 1];
+                const prev_log_size = tree.column_log_sizes[prev];
+                const log_size = tree.column_log_sizes[column];
+                try std.testing.expect(prev_log_size < log_size or (prev_log_size == log_size and prev < column));
+            }
+        }
+    }
+}
+
+test "field vectors: e2e tree shapes" {
+    const alloc = std.testing.allocator;
+    const blake2_merkle = @import("../vcs_lifted/blake2_merkle.zig");
+    const Verifier = @import("../pcs/verifier.zig").CommitmentSchemeVerifier(
+        blake2_merkle.Blake2sMerkleHasher,
+        blake2_merkle.Blake2sMerkleChannel,
+    );
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+    const COMPOSITION_LOG_SPLIT = @import("../verifier_types.zig").COMPOSITION_LOG_SPLIT;
+    const tree_names = [_][]const u8{ "preprocessed", "main", "interaction", "composition" };
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.e2e_tree_shapes orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expectEqual(tree_names.len, v.trees.len);
+        var verifier = try Verifier.init(alloc, .{
+            .pow_bits = 0,
+            .fri_config = try fri_mod.FriConfig.init(0, v.log_blowup_factor, v.n_queries),
+        });
+        defer verifier.deinit(alloc);
+        var channel = Channel{};
+
+        for (v.trees, tree_names) |tree, name| {
+            try std.testing.expectEqualStrings(name, tree.tree);
+            try std.testing.expectEqual(tree.n_columns, tree.column_log_sizes.len);
+            try std.testing.expectEqual(tree.n_columns, tree.sampled_values_shape.len);
+            try std.testing.expectEqual(tree.n_columns, tree.queried_values_shape.len);
+            try verifier.commit(alloc, tree.commitment, tree.column_log_sizes, &channel);
+        }
+
+        // Per-tree sample counts of the state machine layout: no preprocessed samples, one per
+        // main column, the row and the previous row per interaction coordinate, one per
+        // composition column.
+        const expected_samples = [_]usize{ 0, 1, 2, 1 };
+        for (v.trees, expected_samples) |tree, samples| {
+            for (tree.sampled_values_shape) |n| try std.testing.expectEqual(samples, n);
+        }
+        try std.testing.expectEqual(@as(usize, qm31_mod.SECURE_EXTENSION_DEGREE), v.trees[2].n_columns);
+        try std.testing.expectEqual(2 * @as(usize, qm31_mod.SECURE_EXTENSION_DEGREE), v.trees[3].n_columns);
+        for (v.trees[3].column_log_sizes) |log_size| {
+            try std.testing.expectEqual(v.log_size + 1 - COMPOSITION_LOG_SPLIT, log_size);
+        }
+
+        var log_sizes = try verifier.columnLogSizes(alloc);
+        defer log_sizes.deinitDeep(alloc);
+        for (v.trees, log_sizes.items) |tree, extended| {
+            try std.testing.expectEqual(tree.column_log_sizes.len, extended.len);
+            for (tree.column_log_sizes, extended) |log_size, extended_log_size| {
+                try std.testing.expectEqual(log_size + v.log_blowup_factor, extended_log_size);
+            }
+        }
+    }
+}
+
+test "field vectors: blowup sizing parity" {
+    const alloc = std.testing.allocator;
+    const blake2_merkle = @import("../vcs_lifted/blake2_merkle.zig");
+    const Verifier = @import("../pcs/verifier.zig").CommitmentSchemeVerifier(
+        blake2_merkle.Blake2sMerkleHasher,
+        blake2_merkle.Blake2sMerkleChannel,
+    );
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+    const COMPOSITION_LOG_SPLIT = @import("../verifier_types.zig").COMPOSITION_LOG_SPLIT;
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.blowup_sizing orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expectEqual(
+            v.trace_log_size + v.constraint_log_degree_increment,
+            v.max_constraint_log_degree_bound,
+        );
+        try std.testing.expectEqual(
+            v.max_constraint_log_degree_bound - COMPOSITION_LOG_SPLIT,
+            v.composition_poly_log_size,
+        );
+
+        var verifier = try Verifier.init(alloc, .{
+            .pow_bits = 0,
+            .fri_config = try fri_mod.FriConfig.init(0, v.log_blowup, 3),
+        });
+        defer verifier.deinit(alloc);
+        var channel = Channel{};
+        try verifier.commit(alloc, [_]u8{0} ** 32, &[_]u32{v.trace_log_size}, &channel);
+        var composition_log_sizes: [2 * qm31_mod.SECURE_EXTENSION_DEGREE]u32 = undefined;
+        @memset(composition_log_sizes[0..], v.composition_poly_log_size);
+        try verifier.commit(alloc, [_]u8{0} ** 32, composition_log_sizes[0..], &channel);
+
+        var committed = try verifier.columnLogSizes(alloc);
+        defer committed.deinitDeep(alloc);
+        try std.testing.expectEqual(v.trace_commitment_log_size, committed.items[0][0]);
+        for (committed.items[1]) |log_size| {
+            try std.testing.expectEqual(v.composition_commitment_log_size, log_size);
+        }
+        var first_fri_layer_log_size: u32 = 0;
+        for (committed.items) |tree| {
+            for (tree) |log_size| first_fri_layer_log_size = @max(first_fri_layer_log_size, log_size);
+        }
+        try std.testing.expectEqual(v.first_fri_layer_log_size, first_fri_layer_log_size);
+
+        // The prover's twiddles are sized from the constraint bound, and must cover every
+        // committed domain.
+        try std.testing.expectEqual(v.max_constraint_log_degree_bound + v.log_blowup, v.twiddle_log_size);
+        try std.testing.expect(v.twiddle_log_size >= first_fri_layer_log_size);
+    }
+}
+
 test "field vectors: examples state machine trace parity" {
     const alloc = std.testing.allocator;
     var parsed = try parseVectors(alloc);
@@ -1729,6 +3904,64 @@ test "field vectors: examples state machine lookup draw parity" {
     }
 }
 
+test "field vectors: lookup draw v2 parity" {
+    const alloc = std.testing.allocator;
+    const Channel = @import("../channel/blake2s.zig").Blake2sChannel;
+
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.lookup_draw_v2 orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        var channel = Channel{};
+        channel.mixU64(v.mix_u64);
+        channel.mixU32s(v.mix_u32s);
+        try std.testing.expectEqualSlices(u8, v.digest_before[0..], channel.digestBytes()[0..]);
+
+        for (v.steps) |step| {
+            const felts = try decodeQm31Slice(alloc, step.felts);
+            defer alloc.free(felts);
+            if (std.mem.eql(u8, step.op, "mix_felts")) {
+                channel.mixFelts(felts);
+            } else if (std.mem.eql(u8, step.op, "draw_secure_felt")) {
+                try std.testing.expectEqual(@as(usize, 1), felts.len);
+                try std.testing.expect(channel.drawSecureFelt().eql(felts[0]));
+            } else if (std.mem.eql(u8, step.op, "draw_secure_felts")) {
+                const drawn = try channel.drawSecureFelts(alloc, felts.len);
+                defer alloc.free(drawn);
+                for (felts, drawn) |expected, actual| try std.testing.expect(actual.eql(expected));
+            } else {
+                return error.UnknownLookupDrawStep;
+            }
+            try std.testing.expectEqualSlices(u8, step.digest_after[0..], channel.digestBytes()[0..]);
+        }
+    }
+}
+
+test "field vectors: field boundaries parity" {
+    var parsed = try parseVectors(std.testing.allocator);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.field_boundaries orelse return;
+    try std.testing.expect(vectors.len > 0);
+    var undefined_count: usize = 0;
+    for (vectors) |v| {
+        if (std.mem.eql(u8, v.field, "m31")) {
+            try checkFieldBoundary(M31, m31FromCoords, v);
+        } else if (std.mem.eql(u8, v.field, "cm31")) {
+            try checkFieldBoundary(CM31, cm31FromCoords, v);
+        } else if (std.mem.eql(u8, v.field, "qm31")) {
+            try checkFieldBoundary(QM31, qm31FromCoords, v);
+        } else {
+            return error.UnknownField;
+        }
+        undefined_count += v.undefined.len;
+    }
+    // Zero is an operand of every field, so some cases must exercise the error path.
+    try std.testing.expect(undefined_count > 0);
+}
+
 test "field vectors: examples state machine statement parity" {
     const alloc = std.testing.allocator;
     var parsed = try parseVectors(alloc);
@@ -1818,6 +4051,65 @@ test "field vectors: examples state machine statement parity" {
     }
 }
 
+fn stateFrom(v: [2]u32) example_state_machine_mod.State {
+    return .{ m31From(v[0]), m31From(v[1]) };
+}
+
+test "field vectors: examples state machine components parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    var axis_orders = [_]bool{ false, false };
+    const vectors = parsed.value.example_state_machine_components orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        const x_first = std.mem.eql(u8, v.axis_order, "x_first");
+        if (!x_first and !std.mem.eql(u8, v.axis_order, "y_first")) return error.UnknownAxisOrder;
+        axis_orders[@intFromBool(x_first)] = true;
+        if (x_first) {
+            // The interop example's own x-then-y transition.
+            const states = try example_state_machine_mod.transitionStates(v.log_n_rows, stateFrom(v.initial_state));
+            try std.testing.expect(std.meta.eql(states.intermediate, stateFrom(v.intermediate_state)));
+            try std.testing.expect(std.meta.eql(states.final, stateFrom(v.final_state)));
+        }
+
+        const elements: example_state_machine_mod.Elements = .{ .z = qm31From(v.z), .alpha = qm31From(v.alpha) };
+        const boundaries = [_][2]u32{ v.initial_state, v.intermediate_state, v.final_state };
+        try std.testing.expectEqual(@as(usize, 2), v.components.len);
+        for (v.components, 0..) |component, i| {
+            try std.testing.expectEqual(v.log_n_rows - @as(u32, @intCast(i)), component.log_size);
+            try std.testing.expectEqual(@as(usize, @intFromBool(x_first == (i == 1))), component.inc_index);
+            try std.testing.expectEqual(boundaries[i], component.initial_state);
+            try std.testing.expectEqual(boundaries[i + 1], component.final_state);
+
+            const initial = stateFrom(component.initial_state);
+            var trace = try example_state_machine_mod.genTrace(alloc, component.log_size, initial, component.inc_index);
+            defer example_state_machine_mod.deinitTrace(alloc, &trace);
+            try std.testing.expectEqual(@as(usize, 2), component.columns.len);
+            for (component.columns, trace) |expected_column, column| {
+                try std.testing.expectEqual(expected_column.len, column.len);
+                for (expected_column, column) |expected, value| try std.testing.expect(value.eql(m31From(expected)));
+            }
+
+            var next = initial;
+            next[component.inc_index] = next[component.inc_index].add(
+                M31.fromCanonical(@as(u32, 1) << @intCast(component.log_size)),
+            );
+            try std.testing.expect(std.meta.eql(next, stateFrom(component.final_state)));
+
+            const claimed_sum = try example_state_machine_mod.claimedSumFromInitial(
+                component.log_size,
+                initial,
+                component.inc_index,
+                elements,
+            );
+            try std.testing.expect(claimed_sum.eql(qm31From(component.claimed_sum)));
+        }
+    }
+    try std.testing.expect(axis_orders[0] and axis_orders[1]);
+}
+
 test "field vectors: examples xor is_first parity" {
     const alloc = std.testing.allocator;
     var parsed = try parseVectors(alloc);
@@ -1895,6 +4187,22 @@ test "field vectors: examples wide_fibonacci trace parity" {
 
     try std.testing.expect(parsed.value.example_wide_fibonacci_trace.len > 0);
     for (parsed.value.example_wide_fibonacci_trace, 0..) |v, vec_idx| {
+        if (v.constraints_satisfied) |constraints_satisfied| {
+            // Random-seed traces: the example generator only emits (1, row) seeds, so check the
+            // recorded relation flags row-wise instead of regenerating the trace.
+            try std.testing.expectEqual(v.columns.len - 2, constraints_satisfied.len);
+            try std.testing.expectEqualSlices(u32, v.seed_a.?, v.columns[0]);
+            try std.testing.expectEqualSlices(u32, v.seed_b.?, v.columns[1]);
+            for (constraints_satisfied, 0..) |flags, c| {
+                for (flags, 0..) |expected, row_idx| {
+                    const a = m31From(v.columns[c][row_idx]);
+                    const b = m31From(v.columns[c + 1][row_idx]);
+                    const next = m31From(v.columns[c + 2][row_idx]);
+                    try std.testing.expectEqual(expected, next.eql(a.square().add(b.square())));
+                }
+            }
+            continue;
+        }
         const statement: example_wide_fibonacci_mod.Statement = .{
             .log_n_rows = v.log_n_rows,
             .sequence_len = v.sequence_len,
@@ -1977,6 +4285,94 @@ test "field vectors: examples plonk trace parity" {
     }
 }
 
+test "field vectors: examples poseidon trace parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.example_poseidon_trace orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        // The example packs 2^3 instances per row.
+        const statement: example_poseidon_mod.Statement = .{
+            .log_n_instances = v.log_n_rows + 3,
+        };
+        const trace = try example_poseidon_mod.genTrace(alloc, statement);
+        defer example_poseidon_mod.deinitTrace(alloc, trace);
+
+        try std.testing.expectEqual(v.columns.len, trace.len);
+        for (v.columns, 0..) |expected_col, col_idx| {
+            try std.testing.expectEqual(@as(usize, 1) << @intCast(v.log_n_rows), expected_col.len);
+            try std.testing.expectEqual(expected_col.len, trace[col_idx].len);
+            for (expected_col, 0..) |expected, row_idx| {
+                try std.testing.expect(trace[col_idx][row_idx].eql(m31From(expected)));
+            }
+        }
+    }
+}
+
+fn poseidonStateFrom(values: []const u32) ![example_poseidon_mod.N_STATE]M31 {
+    if (values.len != example_poseidon_mod.N_STATE) return error.InvalidPoseidonState;
+    var state: [example_poseidon_mod.N_STATE]M31 = undefined;
+    for (values, 0..) |value, i| state[i] = m31From(value);
+    return state;
+}
+
+fn expectPoseidonState(expected: []const u32, actual: [example_poseidon_mod.N_STATE]M31) !void {
+    try std.testing.expectEqual(expected.len, actual.len);
+    for (expected, actual) |e, a| try std.testing.expect(a.eql(m31From(e)));
+}
+
+test "field vectors: examples poseidon round primitives parity" {
+    const alloc = std.testing.allocator;
+    var parsed = try parseVectors(alloc);
+    defer parsed.deinit();
+
+    const vectors = parsed.value.example_poseidon_rounds orelse return;
+    try std.testing.expect(vectors.len > 0);
+    for (vectors) |v| {
+        try std.testing.expect(v.external_round < example_poseidon_mod.N_FULL_ROUNDS);
+        try std.testing.expect(v.internal_round < example_poseidon_mod.N_PARTIAL_ROUNDS);
+        const input = try poseidonStateFrom(v.input);
+
+        var external_constants: [example_poseidon_mod.N_STATE]M31 = undefined;
+        for (&external_constants, 0..) |*constant, state_i| {
+            constant.* = example_poseidon_mod.externalRoundConst(v.external_round, state_i);
+        }
+        try expectPoseidonState(v.external_round_constants, external_constants);
+        const internal_constant = example_poseidon_mod.internalRoundConst(v.internal_round);
+        try std.testing.expect(internal_constant.eql(m31From(v.internal_round_constant)));
+
+        var added_external = input;
+        for (&added_external, external_constants) |*value, constant| value.* = value.add(constant);
+        try expectPoseidonState(v.after_add_external_round_constants, added_external);
+
+        var added_internal = input;
+        added_internal[0] = added_internal[0].add(internal_constant);
+        try expectPoseidonState(v.after_add_internal_round_constant, added_internal);
+
+        var after_m4: [example_poseidon_mod.N_STATE]M31 = undefined;
+        var chunk: usize = 0;
+        while (chunk < example_poseidon_mod.N_STATE) : (chunk += 4) {
+            const mixed = example_poseidon_mod.applyM4(input[chunk..][0..4].*);
+            @memcpy(after_m4[chunk..][0..4], &mixed);
+        }
+        try expectPoseidonState(v.after_apply_m4, after_m4);
+
+        var external_matrix = input;
+        example_poseidon_mod.applyExternalRoundMatrix(&external_matrix);
+        try expectPoseidonState(v.after_external_round_matrix, external_matrix);
+
+        var internal_matrix = input;
+        example_poseidon_mod.applyInternalRoundMatrix(&internal_matrix);
+        try expectPoseidonState(v.after_internal_round_matrix, internal_matrix);
+
+        var powered = input;
+        for (&powered) |*value| value.* = example_poseidon_mod.pow5(value.*);
+        try expectPoseidonState(v.after_pow5, powered);
+    }
+}
+
 fn expectedVcsError(name: []const u8) vcs_verifier_mod.MerkleVerificationError {
     if (std.mem.eql(u8, name, "WitnessTooShort")) return vcs_verifier_mod.MerkleVerificationError.WitnessTooShort;
     if (std.mem.eql(u8, name, "WitnessTooLong")) return vcs_verifier_mod.MerkleVerificationError.WitnessTooLong;
@@ -81,6 +81,14 @@ pub const FriVerificationError = error{
     LastLayerEvaluationsInvalid,
 };
 
+/// Rejects a last-layer polynomial with more coefficients than the config's degree bound allows.
+/// Upstream checks the coefficient count, so zero-padded high coefficients fail too.
+pub fn checkLastLayerDegree(config: FriConfig, last_layer_poly: line.LinePoly) FriVerificationError!void {
+    if (last_layer_poly.len() > (@as(usize, 1) << @intCast(config.log_last_layer_degree_bound))) {
+        return FriVerificationError.LastLayerDegreeInvalid;
+    }
+}
+
 pub const CirclePolyDegreeBound = struct {
     log_degree_bound: u32,
 
@@ -182,9 +190,7 @@ pub fn FriVerifier(comptime H: type, comptime MC: type) type {
                 try allocator.dupe(QM31, proof_in.last_layer_poly.coefficients()),
             );
             errdefer last_layer_poly.deinit(allocator);
-            if (last_layer_poly.len() > (@as(usize, 1) << @intCast(config.log_last_layer_degree_bound))) {
-                return FriVerificationError.LastLayerDegreeInvalid;
-            }
+            try checkLastLayerDegree(config, last_layer_poly);
 
             channel.mixFelts(last_layer_poly.coefficients());
 
@@ -80,6 +80,17 @@ pub const LinePoly = struct {
     coeffs: []QM31,
     log_size: u32,
 
+    pub const Error = error{
+        InvalidCoefficientCount,
+    };
+
+    /// Checked `initOwned` for coefficients from untrusted input. Upstream `LinePoly::new` panics
+    /// unless the count is a nonzero power of two.
+    pub fn init(coeffs: []QM31) Error!LinePoly {
+        if (!std.math.isPowerOfTwo(coeffs.len)) return Error.InvalidCoefficientCount;
+        return initOwned(coeffs);
+    }
+
     pub fn initOwned(coeffs: []QM31) LinePoly {
         std.debug.assert(coeffs.len != 0 and (coeffs.len & (coeffs.len - 1)) == 0);
         return .{
@@ -225,3 +236,12 @@ test "line poly: constant polynomial evaluates to constant" {
     const value = try poly.evalAtPoint(alloc, point);
     try std.testing.expect(value.eql(QM31.fromBase(M31.fromCanonical(19))));
 }
+
+test "line poly: checked init rejects non power of two counts" {
+    var coeffs = [_]QM31{QM31.one()} ** 3;
+    for ([_]usize{ 0, 3 }) |n| {
+        try std.testing.expectError(LinePoly.Error.InvalidCoefficientCount, LinePoly.init(coeffs[0..n]));
+    }
+    const poly = try LinePoly.init(coeffs[0..2]);
+    try std.testing.expectEqual(@as(u32, 1), poly.log_size);
+}
@@ -3,22 +3,27 @@ const core_air_accumulation = @import("../core/air/accumulation.zig");
 const core_air_components = @import("../core/air/components.zig");
 const core_air_derive = @import("../core/air/derive.zig");
 const channel_blake2s = @import("../core/channel/blake2s.zig");
+const constraints = @import("../core/constraints.zig");
 const m31 = @import("../core/fields/m31.zig");
 const qm31 = @import("../core/fields/qm31.zig");
 const pcs_core = @import("../core/pcs/mod.zig");
 const pcs_verifier = @import("../core/pcs/verifier.zig");
+const canonic = @import("../core/poly/circle/canonic.zig");
 const core_proof = @import("../core/proof.zig");
+const core_utils = @import("../core/utils.zig");
 const core_verifier = @import("../core/verifier.zig");
 const blake2_merkle = @import("../core/vcs_lifted/blake2_merkle.zig");
 const prover_air_accumulation = @import("../prover/air/accumulation.zig");
 const prover_component = @import("../prover/air/component_prover.zig");
 const prover_pcs = @import("../prover/pcs/mod.zig");
+const circle_evaluation = @import("../prover/poly/circle/evaluation.zig");
+const circle_poly = @import("../prover/poly/circle/poly.zig");
 const prover_prove = @import("../prover/prove.zig");
-const secure_column = @import("../prover/secure_column.zig");
 
 const M31 = m31.M31;
 const QM31 = qm31.QM31;
 const CirclePointQM31 = @import("../core/circle.zig").CirclePointQM31;
+const CircleDomain = @import("../core/poly/circle/domain.zig").CircleDomain;
 
 pub const Hasher = blake2_merkle.Blake2sMerkleHasher;
 pub const MerkleChannel = blake2_merkle.Blake2sMerkleChannel;
@@ -241,6 +246,15 @@ pub fn verify(
     );
 }
 
+/// Preprocessed column holding the gate selector; the other preprocessed columns are the wire
+/// indices, which only a lookup argument would read.
+const op_column: usize = 3;
+/// Preprocessed columns the component samples.
+pub const preprocessed_columns = [_]usize{op_column};
+/// Main-trace columns of the `a`, `b` and `c` wires; column `0` is the wire multiplicity, which
+/// the gate constraint does not read.
+const wire_columns = [3]usize{ 1, 2, 3 };
+
 const PlonkComponent = struct {
     statement: Statement,
 
@@ -271,12 +285,7 @@ const PlonkComponent = struct {
         self: *const @This(),
         allocator: std.mem.Allocator,
     ) !core_air_components.TraceLogDegreeBounds {
-        const preprocessed = try allocator.dupe(u32, &[_]u32{
-            self.statement.log_n_rows,
-            self.statement.log_n_rows,
-            self.statement.log_n_rows,
-            self.statement.log_n_rows,
-        });
+        const preprocessed = try allocator.dupe(u32, &[_]u32{self.statement.log_n_rows});
         const main = try allocator.dupe(u32, &[_]u32{
             self.statement.log_n_rows,
             self.statement.log_n_rows,
@@ -294,8 +303,12 @@ const PlonkComponent = struct {
         point: CirclePointQM31,
         _: u32,
     ) !core_air_components.MaskPoints {
-        const preprocessed_cols = try allocMaskCols(allocator, 4, point);
+        const preprocessed_cols = try allocMaskCols(allocator, 1, point);
         const main_cols = try allocMaskCols(allocator, 4, point);
+        // The multiplicity column is committed but not read by the gate constraint.
+        const unread = try allocator.alloc(CirclePointQM31, 0);
+        allocator.free(main_cols[0]);
+        main_cols[0] = unread;
         return core_air_components.MaskPoints.initOwned(
             try allocator.dupe([][]CirclePointQM31, &[_][][]CirclePointQM31{
                 preprocessed_cols,
@@ -308,46 +321,93 @@ const PlonkComponent = struct {
         _: *const @This(),
         allocator: std.mem.Allocator,
     ) ![]usize {
-        return allocator.dupe(usize, &[_]usize{ 0, 1, 2, 3 });
+        return allocator.dupe(usize, &preprocessed_columns);
     }
 
     pub fn evaluateConstraintQuotientsAtPoint(
         self: *const @This(),
-        _: CirclePointQM31,
-        _: *const core_air_components.MaskValues,
+        point: CirclePointQM31,
+        mask: *const core_air_components.MaskValues,
         evaluation_accumulator: *core_air_accumulation.PointEvaluationAccumulator,
         _: u32,
     ) !void {
-        evaluation_accumulator.accumulate(compositionEval(self.statement));
+        const trace_coset = canonic.CanonicCoset.new(self.statement.log_n_rows).coset();
+        const denom_inverse = try constraints.cosetVanishing(QM31, trace_coset, point).inv();
+        const main = mask.items[1];
+        const constraint = gateConstraint(
+            QM31,
+            mask.items[0][op_column][0],
+            main[wire_columns[0]][0],
+            main[wire_columns[1]][0],
+            main[wire_columns[2]][0],
+        );
+        evaluation_accumulator.accumulate(constraint.mul(denom_inverse));
     }
 
     pub fn evaluateConstraintQuotientsOnDomain(
         self: *const @This(),
-        _: *const prover_component.Trace,
+        trace: *const prover_component.Trace,
         evaluation_accumulator: *prover_air_accumulation.DomainEvaluationAccumulator,
     ) !void {
-        const composition_eval = compositionEval(self.statement);
-        const domain_size = @as(usize, 1) << @intCast(self.statement.log_n_rows + 1);
-        const values = try evaluation_accumulator.allocator.alloc(QM31, domain_size);
-        defer evaluation_accumulator.allocator.free(values);
-        @memset(values, composition_eval);
-
-        var col = try secure_column.SecureColumnByCoords.fromSecureSlice(
-            evaluation_accumulator.allocator,
-            values,
-        );
-        defer col.deinit(evaluation_accumulator.allocator);
-        try evaluation_accumulator.accumulateColumn(self.statement.log_n_rows + 1, &col);
+        const allocator = evaluation_accumulator.allocator;
+        const log_n_rows = self.statement.log_n_rows;
+        const eval_log_size = self.maxConstraintLogDegreeBound();
+        const log_expand = eval_log_size - log_n_rows;
+        const eval_domain = canonic.CanonicCoset.new(eval_log_size).circleDomain();
+
+        const op = try evaluateOnDomain(allocator, trace.polys.items[0][op_column], eval_domain);
+        defer allocator.free(op);
+        var wires: [3][]M31 = undefined;
+        var n_evaluated: usize = 0;
+        defer for (wires[0..n_evaluated]) |wire| allocator.free(wire);
+        for (&wires, wire_columns) |*wire, column| {
+            wire.* = try evaluateOnDomain(allocator, trace.polys.items[1][column], eval_domain);
+            n_evaluated += 1;
+        }
+
+        const trace_coset = canonic.CanonicCoset.new(log_n_rows).coset();
+        const denom_inverses = try allocator.alloc(M31, @as(usize, 1) << @intCast(log_expand));
+        defer allocator.free(denom_inverses);
+        for (denom_inverses, 0..) |*denom_inverse, i| {
+            const point = eval_domain.at(core_utils.bitReverseIndex(i, log_expand));
+            denom_inverse.* = try constraints.cosetVanishing(M31, trace_coset, point).inv();
+        }
+
+        const accumulators = try evaluation_accumulator.columns(allocator, &.{.{
+            .log_size = eval_log_size,
+            .n_cols = 1,
+        }});
+        defer allocator.free(accumulators);
+        const accumulator = &accumulators[0];
+        const power = accumulator.random_coeff_powers[0];
+        for (0..@as(usize, 1) << @intCast(eval_log_size)) |row| {
+            const constraint = gateConstraint(M31, op[row], wires[0][row], wires[1][row], wires[2][row]);
+            accumulator.accumulate(row, power.mulM31(constraint.mul(denom_inverses[row >> @intCast(log_n_rows)])));
+        }
     }
 };
 
-fn compositionEval(statement: Statement) QM31 {
-    return QM31.fromM31(
-        M31.fromCanonical(statement.log_n_rows),
-        M31.fromCanonical(4),
-        M31.fromCanonical(1),
-        M31.one(),
+/// The output wire `c` is `a + b` where the selector `op` is one and `a * b` where it is zero.
+fn gateConstraint(comptime F: type, op: F, a: F, b: F, c: F) F {
+    const sum = op.mul(a.add(b));
+    const product = F.one().sub(op).mul(a.mul(b));
+    return c.sub(sum.add(product));
+}
+
+/// Re-evaluates a trace column, given by its bit-reversed values over its canonic domain, over
+/// the larger `domain`.
+fn evaluateOnDomain(
+    allocator: std.mem.Allocator,
+    poly: prover_component.Poly,
+    domain: CircleDomain,
+) ![]M31 {
+    const trace_domain = canonic.CanonicCoset.new(poly.log_size).circleDomain();
+    var coeffs = try circle_poly.interpolateFromEvaluation(
+        allocator,
+        try circle_evaluation.CircleEvaluation.init(trace_domain, poly.values),
     );
+    defer coeffs.deinit(allocator);
+    return @constCast((try coeffs.evaluate(allocator, domain)).values);
 }
 
 fn mixStatement(channel: *Channel, statement: Statement) void {
@@ -459,3 +519,21 @@ test "examples plonk: verify wrapper rejects statement mismatch" {
         );
     }
 }
+
+test "examples plonk: tampered wire sample fails the oods check" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+    const statement: Statement = .{ .log_n_rows = 5 };
+    var output = try prove(std.testing.allocator, config, statement);
+
+    const sample = &output.proof.commitment_scheme_proof.sampled_values.items[1][wire_columns[2]][0];
+    sample.* = sample.add(QM31.one());
+
+    const verification_error = @import("../core/verifier_types.zig").VerificationError;
+    try std.testing.expectError(
+        verification_error.OodsNotMatching,
+        verify(std.testing.allocator, config, statement, output.proof),
+    );
+}
@@ -28,10 +28,10 @@ pub const ExtendedProof = core_proof.ExtendedStarkProof(Hasher);
 
 const N_LOG_INSTANCES_PER_ROW: u32 = 3;
 const N_INSTANCES_PER_ROW: usize = 1 << N_LOG_INSTANCES_PER_ROW;
-const N_STATE: usize = 16;
-const N_PARTIAL_ROUNDS: usize = 14;
+pub const N_STATE: usize = 16;
+pub const N_PARTIAL_ROUNDS: usize = 14;
 const N_HALF_FULL_ROUNDS: usize = 4;
-const N_FULL_ROUNDS: usize = N_HALF_FULL_ROUNDS * 2;
+pub const N_FULL_ROUNDS: usize = N_HALF_FULL_ROUNDS * 2;
 const N_COLUMNS_PER_REP: usize = N_STATE * (1 + N_FULL_ROUNDS) + N_PARTIAL_ROUNDS;
 const N_COLUMNS: usize = N_COLUMNS_PER_REP * N_INSTANCES_PER_ROW;
 
@@ -401,21 +401,21 @@ fn mixStatement(channel: *Channel, statement: Statement) void {
     channel.mixU32s(&[_]u32{statement.log_n_instances});
 }
 
-fn pow5(x: M31) M31 {
+pub fn pow5(x: M31) M31 {
     const x2 = x.mul(x);
     const x4 = x2.mul(x2);
     return x4.mul(x);
 }
 
-fn externalRoundConst(round: usize, state_i: usize) M31 {
+pub fn externalRoundConst(round: usize, state_i: usize) M31 {
     return M31.fromU64(1234 + (@as(u64, @intCast(round)) * 37) + @as(u64, @intCast(state_i)));
 }
 
-fn internalRoundConst(round: usize) M31 {
+pub fn internalRoundConst(round: usize) M31 {
     return M31.fromU64(9876 + (@as(u64, @intCast(round)) * 17));
 }
 
-fn applyM4(x: [4]M31) [4]M31 {
+pub fn applyM4(x: [4]M31) [4]M31 {
     const t0 = x[0].add(x[1]);
     const t02 = t0.add(t0);
     const t1 = x[2].add(x[3]);
@@ -429,7 +429,7 @@ fn applyM4(x: [4]M31) [4]M31 {
     return .{ t6, t5, t7, t4 };
 }
 
-fn applyExternalRoundMatrix(state: *[N_STATE]M31) void {
+pub fn applyExternalRoundMatrix(state: *[N_STATE]M31) void {
     for (0..4) |i| {
         const offset = i * 4;
         const mixed = applyM4(.{
@@ -453,7 +453,7 @@ fn applyExternalRoundMatrix(state: *[N_STATE]M31) void {
     }
 }
 
-fn applyInternalRoundMatrix(state: *[N_STATE]M31) void {
+pub fn applyInternalRoundMatrix(state: *[N_STATE]M31) void {
     var sum = state[0];
     for (1..N_STATE) |i| {
         sum = sum.add(state[i]);
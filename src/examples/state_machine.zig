@@ -4,22 +4,28 @@ const core_air_components = @import("../core/air/components.zig");
 const core_air_derive = @import("../core/air/derive.zig");
 const core_air_utils = @import("../core/air/utils.zig");
 const channel_blake2s = @import("../core/channel/blake2s.zig");
+const constraints = @import("../core/constraints.zig");
 const m31 = @import("../core/fields/m31.zig");
 const qm31 = @import("../core/fields/qm31.zig");
 const pcs_core = @import("../core/pcs/mod.zig");
 const pcs_verifier = @import("../core/pcs/verifier.zig");
+const canonic = @import("../core/poly/circle/canonic.zig");
 const core_proof = @import("../core/proof.zig");
+const core_utils = @import("../core/utils.zig");
 const core_verifier = @import("../core/verifier.zig");
 const blake2_merkle = @import("../core/vcs_lifted/blake2_merkle.zig");
 const prover_air_accumulation = @import("../prover/air/accumulation.zig");
 const prover_component = @import("../prover/air/component_prover.zig");
 const prover_pcs = @import("../prover/pcs/mod.zig");
+const circle_evaluation = @import("../prover/poly/circle/evaluation.zig");
+const circle_poly = @import("../prover/poly/circle/poly.zig");
 const prover_prove = @import("../prover/prove.zig");
 const secure_column = @import("../prover/secure_column.zig");
 
 const M31 = m31.M31;
 const QM31 = qm31.QM31;
 const CirclePointQM31 = @import("../core/circle.zig").CirclePointQM31;
+const CircleDomain = @import("../core/poly/circle/domain.zig").CircleDomain;
 
 pub const State = [2]M31;
 pub const Hasher = blake2_merkle.Blake2sMerkleHasher;
@@ -343,6 +349,7 @@ pub fn proveEx(
 
     const component = ExampleStateMachineComponent{
         .trace_log_size = log_n_rows,
+        .is_first_column = 0,
         .composition_eval = statement.stmt1.x_axis_claimed_sum.add(statement.stmt1.y_axis_claimed_sum),
     };
     const components = [_]prover_component.ComponentProver{
@@ -426,6 +433,7 @@ pub fn verify(
 
     const component = ExampleStateMachineComponent{
         .trace_log_size = log_n_rows,
+        .is_first_column = 0,
         .composition_eval = statement.stmt1.x_axis_claimed_sum.add(statement.stmt1.y_axis_claimed_sum),
     };
     const verifier_components = [_]core_air_components.Component{
@@ -444,9 +452,234 @@ pub fn verify(
     );
 }
 
+/// Proves the state machine as two components, x-axis over `log_n_rows` and y-axis over
+/// `log_n_rows - 1`, against one commitment scheme. The statement and channel transcript match
+/// `prove`; the trees carry one `is_first` column and two main columns per component.
+pub fn provePair(
+    allocator: std.mem.Allocator,
+    pcs_config: pcs_core.PcsConfig,
+    log_n_rows: u32,
+    initial_state: State,
+) anyerror!ProveOutput {
+    const output = try provePairEx(allocator, pcs_config, log_n_rows, initial_state, false);
+    var ext_proof = output.proof;
+    const proof = ext_proof.proof;
+    ext_proof.aux.deinit(allocator);
+    return .{
+        .statement = output.statement,
+        .proof = proof,
+    };
+}
+
+/// Extended proving wrapper for the two-component layout of `provePair`.
+pub fn provePairEx(
+    allocator: std.mem.Allocator,
+    pcs_config: pcs_core.PcsConfig,
+    log_n_rows: u32,
+    initial_state: State,
+    include_all_preprocessed_columns: bool,
+) anyerror!ProveExOutput {
+    if (log_n_rows < 2 or log_n_rows >= 31) return Error.InvalidLogSize;
+    const log_m_rows = log_n_rows - 1;
+
+    var channel = Channel{};
+    pcs_config.mixInto(&channel);
+
+    var scheme = try prover_pcs.CommitmentSchemeProver(Hasher, MerkleChannel).init(
+        allocator,
+        pcs_config,
+    );
+
+    const is_first_x = try genIsFirst(allocator, log_n_rows);
+    var is_first_x_moved = false;
+    defer if (!is_first_x_moved) allocator.free(is_first_x);
+    const is_first_y = try genIsFirst(allocator, log_m_rows);
+    var is_first_y_moved = false;
+    defer if (!is_first_y_moved) allocator.free(is_first_y);
+
+    const preprocessed_owned = try allocator.alloc(prover_pcs.ColumnEvaluation, 2);
+    errdefer allocator.free(preprocessed_owned);
+    preprocessed_owned[0] = .{
+        .log_size = log_n_rows,
+        .values = is_first_x,
+    };
+    preprocessed_owned[1] = .{
+        .log_size = log_m_rows,
+        .values = is_first_y,
+    };
+    is_first_x_moved = true;
+    is_first_y_moved = true;
+    try scheme.commitOwned(allocator, preprocessed_owned, &channel);
+
+    const transition = try transitionStates(log_n_rows, initial_state);
+    var x_axis_trace = try genTrace(allocator, log_n_rows, initial_state, 0);
+    var x_axis_moved = false;
+    defer if (!x_axis_moved) deinitTrace(allocator, &x_axis_trace);
+    var y_axis_trace = try genTrace(allocator, log_m_rows, transition.intermediate, 1);
+    var y_axis_moved = false;
+    defer if (!y_axis_moved) deinitTrace(allocator, &y_axis_trace);
+
+    const trace_owned = try allocator.alloc(prover_pcs.ColumnEvaluation, 4);
+    errdefer allocator.free(trace_owned);
+    for (x_axis_trace, 0..) |column, i| {
+        trace_owned[i] = .{
+            .log_size = log_n_rows,
+            .values = column,
+        };
+    }
+    for (y_axis_trace, 0..) |column, i| {
+        trace_owned[2 + i] = .{
+            .log_size = log_m_rows,
+            .values = column,
+        };
+    }
+    x_axis_moved = true;
+    y_axis_moved = true;
+    try scheme.commitOwned(allocator, trace_owned, &channel);
+
+    mixStatement0(&channel, .{
+        .n = log_n_rows,
+        .m = log_m_rows,
+    });
+    const elements = Elements.draw(&channel);
+    const statement = try prepareStatement(log_n_rows, initial_state, elements);
+    mixPublicInput(&channel, statement.public_input);
+    mixStatement1(&channel, statement.stmt1);
+
+    const pair = pairComponents(statement);
+    const components = [_]prover_component.ComponentProver{
+        pair[0].asProverComponent(),
+        pair[1].asProverComponent(),
+    };
+
+    const proof = try prover_prove.proveEx(
+        Hasher,
+        MerkleChannel,
+        allocator,
+        components[0..],
+        &channel,
+        scheme,
+        include_all_preprocessed_columns,
+    );
+    return .{
+        .statement = statement,
+        .proof = proof,
+    };
+}
+
+/// Verifies a two-component proof generated by `provePair`.
+///
+/// Preconditions:
+/// - `statement` and `proof` come from matching execution parameters.
+/// - `proof` is consumed by this function.
+pub fn verifyPair(
+    allocator: std.mem.Allocator,
+    pcs_config: pcs_core.PcsConfig,
+    statement: PreparedStatement,
+    proof_in: Proof,
+) anyerror!void {
+    var proof = proof_in;
+    var proof_moved = false;
+    defer if (!proof_moved) proof.deinit(allocator);
+
+    if (statement.stmt0.n < 2 or statement.stmt0.n >= 31) return Error.InvalidLogSize;
+    if (statement.stmt0.m != statement.stmt0.n - 1) return Error.InvalidLogSize;
+    if (proof.commitment_scheme_proof.commitments.items.len < 2) return Error.InvalidProofShape;
+
+    var channel = Channel{};
+    pcs_config.mixInto(&channel);
+
+    var commitment_scheme = try pcs_verifier.CommitmentSchemeVerifier(Hasher, MerkleChannel).init(
+        allocator,
+        pcs_config,
+    );
+    defer commitment_scheme.deinit(allocator);
+
+    const log_n_rows = statement.stmt0.n;
+    const log_m_rows = statement.stmt0.m;
+    try commitment_scheme.commit(
+        allocator,
+        proof.commitment_scheme_proof.commitments.items[0],
+        &[_]u32{ log_n_rows, log_m_rows },
+        &channel,
+    );
+    try commitment_scheme.commit(
+        allocator,
+        proof.commitment_scheme_proof.commitments.items[1],
+        &[_]u32{ log_n_rows, log_n_rows, log_m_rows, log_m_rows },
+        &channel,
+    );
+
+    mixStatement0(&channel, statement.stmt0);
+    const elements = Elements.draw(&channel);
+    try verifyStatement(statement, elements);
+    mixPublicInput(&channel, statement.public_input);
+    mixStatement1(&channel, statement.stmt1);
+
+    const pair = pairComponents(statement);
+    const verifier_components = [_]core_air_components.Component{
+        pair[0].asVerifierComponent(),
+        pair[1].asVerifierComponent(),
+    };
+
+    proof_moved = true;
+    try core_verifier.verify(
+        Hasher,
+        MerkleChannel,
+        allocator,
+        verifier_components[0..],
+        &channel,
+        &commitment_scheme,
+        proof,
+    );
+}
+
+/// The x-axis and y-axis components of `provePair`: each reads its own `is_first` column and
+/// constrains its own two main columns, stepping x from the public initial state and then y from
+/// the intermediate state, which pairs the final x with the initial y.
+fn pairComponents(statement: PreparedStatement) [2]ExampleStateMachineComponent {
+    const initial_state = statement.public_input[0];
+    const final_state = statement.public_input[1];
+    return .{
+        .{
+            .trace_log_size = statement.stmt0.n,
+            .is_first_column = 0,
+            .composition_eval = statement.stmt1.x_axis_claimed_sum,
+            .transition = .{
+                .first_column = 0,
+                .inc_index = 0,
+                .initial_state = initial_state,
+            },
+        },
+        .{
+            .trace_log_size = statement.stmt0.m,
+            .is_first_column = 1,
+            .composition_eval = statement.stmt1.y_axis_claimed_sum,
+            .transition = .{
+                .first_column = 2,
+                .inc_index = 1,
+                .initial_state = .{ final_state[0], initial_state[1] },
+            },
+        },
+    };
+}
+
+/// Where one `provePair` component's two main columns start in the main tree, which coordinate
+/// it steps and the state its first row holds.
+const Transition = struct {
+    first_column: usize,
+    inc_index: usize,
+    initial_state: State,
+};
+
 const ExampleStateMachineComponent = struct {
     trace_log_size: u32,
+    /// Index of this component's `is_first` column in the preprocessed tree.
+    is_first_column: usize,
     composition_eval: QM31,
+    /// Transition constraints over the component's own main columns; `provePair` sets it, and
+    /// `composition_eval` then only records the axis claimed sum.
+    transition: ?Transition = null,
 
     const Adapter = core_air_derive.ComponentAdapter(
         @This(),
@@ -463,8 +696,8 @@ const ExampleStateMachineComponent = struct {
         return Adapter.asProverComponent(self);
     }
 
-    pub fn nConstraints(_: *const @This()) usize {
-        return 1;
+    pub fn nConstraints(self: *const @This()) usize {
+        return if (self.transition != null) 4 else 1;
     }
 
     pub fn maxConstraintLogDegreeBound(self: *const @This()) u32 {
@@ -489,20 +722,25 @@ const ExampleStateMachineComponent = struct {
     }
 
     pub fn maskPoints(
-        _: *const @This(),
+        self: *const @This(),
         allocator: std.mem.Allocator,
         point: CirclePointQM31,
         _: u32,
     ) !core_air_components.MaskPoints {
-        const preprocessed_col = try allocator.alloc(CirclePointQM31, 0);
+        // Transition constraints read `is_first` and the previous row; the constant composition
+        // reads neither.
+        const points = [_]CirclePointQM31{ prevPoint(point, self.trace_log_size), point };
+        const has_transition = self.transition != null;
+        const preprocessed_points: []const CirclePointQM31 = if (has_transition) points[1..] else points[2..];
+        const main_points: []const CirclePointQM31 = if (has_transition) points[0..] else points[1..];
+
+        const preprocessed_col = try allocator.dupe(CirclePointQM31, preprocessed_points);
         const preprocessed_cols = try allocator.dupe([]CirclePointQM31, &[_][]CirclePointQM31{
             preprocessed_col,
         });
 
-        const main_col0 = try allocator.alloc(CirclePointQM31, 1);
-        main_col0[0] = point;
-        const main_col1 = try allocator.alloc(CirclePointQM31, 1);
-        main_col1[0] = point;
+        const main_col0 = try allocator.dupe(CirclePointQM31, main_points);
+        const main_col1 = try allocator.dupe(CirclePointQM31, main_points);
         const main_cols = try allocator.dupe([]CirclePointQM31, &[_][]CirclePointQM31{
             main_col0,
             main_col1,
@@ -517,38 +755,166 @@ const ExampleStateMachineComponent = struct {
     }
 
     pub fn preprocessedColumnIndices(
-        _: *const @This(),
+        self: *const @This(),
         allocator: std.mem.Allocator,
     ) ![]usize {
-        return allocator.dupe(usize, &[_]usize{0});
+        return allocator.dupe(usize, &[_]usize{self.is_first_column});
     }
 
     pub fn evaluateConstraintQuotientsAtPoint(
         self: *const @This(),
-        _: CirclePointQM31,
-        _: *const core_air_components.MaskValues,
+        point: CirclePointQM31,
+        mask: *const core_air_components.MaskValues,
         evaluation_accumulator: *core_air_accumulation.PointEvaluationAccumulator,
         _: u32,
     ) !void {
-        evaluation_accumulator.accumulate(self.composition_eval);
+        const transition = self.transition orelse {
+            evaluation_accumulator.accumulate(self.composition_eval);
+            return;
+        };
+        const trace_coset = canonic.CanonicCoset.new(self.trace_log_size).coset();
+        const denom_inverse = try constraints.cosetVanishing(QM31, trace_coset, point).inv();
+        const state0 = mask.items[1][transition.first_column];
+        const state1 = mask.items[1][transition.first_column + 1];
+        const row_constraints = transitionConstraints(
+            QM31,
+            transition.inc_index,
+            .{
+                QM31.fromBase(transition.initial_state[0]),
+                QM31.fromBase(transition.initial_state[1]),
+            },
+            mask.items[0][self.is_first_column][0],
+            .{ state0[0], state1[0] },
+            .{ state0[1], state1[1] },
+        );
+        for (row_constraints) |constraint| {
+            evaluation_accumulator.accumulate(constraint.mul(denom_inverse));
+        }
     }
 
     pub fn evaluateConstraintQuotientsOnDomain(
         self: *const @This(),
-        _: *const prover_component.Trace,
+        trace: *const prover_component.Trace,
         evaluation_accumulator: *prover_air_accumulation.DomainEvaluationAccumulator,
     ) !void {
-        const domain_size = @as(usize, 1) << @intCast(self.trace_log_size + 1);
-        const values = try evaluation_accumulator.allocator.alloc(QM31, domain_size);
-        defer evaluation_accumulator.allocator.free(values);
-        @memset(values, self.composition_eval);
-
-        var col = try secure_column.SecureColumnByCoords.fromSecureSlice(evaluation_accumulator.allocator, values);
-        defer col.deinit(evaluation_accumulator.allocator);
-        try evaluation_accumulator.accumulateColumn(self.trace_log_size + 1, &col);
+        const transition = self.transition orelse {
+            const domain_size = @as(usize, 1) << @intCast(self.trace_log_size + 1);
+            const values = try evaluation_accumulator.allocator.alloc(QM31, domain_size);
+            defer evaluation_accumulator.allocator.free(values);
+            @memset(values, self.composition_eval);
+
+            var col = try secure_column.SecureColumnByCoords.fromSecureSlice(evaluation_accumulator.allocator, values);
+            defer col.deinit(evaluation_accumulator.allocator);
+            try evaluation_accumulator.accumulateColumn(self.trace_log_size + 1, &col);
+            return;
+        };
+        const allocator = evaluation_accumulator.allocator;
+        const log_n_rows = self.trace_log_size;
+        const eval_log_size = self.maxConstraintLogDegreeBound();
+        const log_expand = eval_log_size - log_n_rows;
+        const eval_domain = canonic.CanonicCoset.new(eval_log_size).circleDomain();
+
+        const is_first = try evaluateOnDomain(
+            allocator,
+            trace.polys.items[0][self.is_first_column],
+            eval_domain,
+        );
+        defer allocator.free(is_first);
+        var state: [2][]M31 = undefined;
+        var n_evaluated: usize = 0;
+        defer for (state[0..n_evaluated]) |column| allocator.free(column);
+        for (&state, 0..) |*column, k| {
+            column.* = try evaluateOnDomain(
+                allocator,
+                trace.polys.items[1][transition.first_column + k],
+                eval_domain,
+            );
+            n_evaluated += 1;
+        }
+
+        const trace_coset = canonic.CanonicCoset.new(log_n_rows).coset();
+        const denom_inverses = try allocator.alloc(M31, @as(usize, 1) << @intCast(log_expand));
+        defer allocator.free(denom_inverses);
+        for (denom_inverses, 0..) |*denom_inverse, i| {
+            const point = eval_domain.at(core_utils.bitReverseIndex(i, log_expand));
+            denom_inverse.* = try constraints.cosetVanishing(M31, trace_coset, point).inv();
+        }
+
+        const accumulators = try evaluation_accumulator.columns(allocator, &.{.{
+            .log_size = eval_log_size,
+            .n_cols = 4,
+        }});
+        defer allocator.free(accumulators);
+        const accumulator = &accumulators[0];
+        // As in `wide_fibonacci`, the first constraint takes the highest power.
+        const powers = accumulator.random_coeff_powers;
+        for (0..@as(usize, 1) << @intCast(eval_log_size)) |row| {
+            const prev_row = core_utils.offsetBitReversedCircleDomainIndex(
+                row,
+                log_n_rows,
+                eval_log_size,
+                -1,
+            );
+            const row_constraints = transitionConstraints(
+                M31,
+                transition.inc_index,
+                transition.initial_state,
+                is_first[row],
+                .{ state[0][prev_row], state[1][prev_row] },
+                .{ state[0][row], state[1][row] },
+            );
+            var combined = QM31.zero();
+            for (row_constraints, 0..) |constraint, i| {
+                combined = combined.add(powers[row_constraints.len - 1 - i].mulM31(constraint));
+            }
+            accumulator.accumulate(row, combined.mulM31(denom_inverses[row >> @intCast(log_n_rows)]));
+        }
     }
 };
 
+/// Transition constraints of one `provePair` component, given `is_first` and its two main columns
+/// at the previous and current rows: the first row holds `initial`, the stepped coordinate grows
+/// by one per row (except into the first row, whose cyclic predecessor is the last) and the other
+/// coordinate stays constant.
+fn transitionConstraints(
+    comptime F: type,
+    inc_index: usize,
+    initial: [2]F,
+    is_first: F,
+    prev: [2]F,
+    curr: [2]F,
+) [4]F {
+    const fixed = 1 - inc_index;
+    return .{
+        is_first.mul(curr[0].sub(initial[0])),
+        is_first.mul(curr[1].sub(initial[1])),
+        F.one().sub(is_first).mul(curr[inc_index].sub(prev[inc_index]).sub(F.one())),
+        curr[fixed].sub(prev[fixed]),
+    };
+}
+
+/// The mask point one trace row before `point`.
+fn prevPoint(point: CirclePointQM31, log_size: u32) CirclePointQM31 {
+    const step = canonic.CanonicCoset.new(log_size).step().mulSigned(-1);
+    return point.add(.{ .x = QM31.fromBase(step.x), .y = QM31.fromBase(step.y) });
+}
+
+/// Re-evaluates a trace column, given by its bit-reversed values over its canonic domain, over
+/// the larger `domain`.
+fn evaluateOnDomain(
+    allocator: std.mem.Allocator,
+    poly: prover_component.Poly,
+    domain: CircleDomain,
+) ![]M31 {
+    const trace_domain = canonic.CanonicCoset.new(poly.log_size).circleDomain();
+    var coeffs = try circle_poly.interpolateFromEvaluation(
+        allocator,
+        try circle_evaluation.CircleEvaluation.init(trace_domain, poly.values),
+    );
+    defer coeffs.deinit(allocator);
+    return @constCast((try coeffs.evaluate(allocator, domain)).values);
+}
+
 fn genIsFirst(allocator: std.mem.Allocator, log_size: u32) (std.mem.Allocator.Error || Error)![]M31 {
     const n = checkedPow2(log_size) catch return Error.InvalidLogSize;
     const col = try allocator.alloc(M31, n);
@@ -813,3 +1179,78 @@ test "examples state_machine: verify wrapper rejects tampered statement" {
         ),
     );
 }
+
+test "examples state_machine: pair prove/verify roundtrip" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+
+    const output = try provePair(
+        std.testing.allocator,
+        config,
+        5,
+        .{
+            M31.fromCanonical(9),
+            M31.fromCanonical(3),
+        },
+    );
+    try std.testing.expectEqual(@as(u32, 4), output.statement.stmt0.m);
+    try verifyPair(
+        std.testing.allocator,
+        config,
+        output.statement,
+        output.proof,
+    );
+}
+
+test "examples state_machine: pair verify rejects tampered statement and size" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+    const initial = State{
+        M31.fromCanonical(14),
+        M31.fromCanonical(6),
+    };
+
+    const output = try provePair(std.testing.allocator, config, 5, initial);
+    var bad_statement = output.statement;
+    bad_statement.stmt1.y_axis_claimed_sum = bad_statement.stmt1.y_axis_claimed_sum.add(QM31.one());
+    try std.testing.expectError(
+        Error.StatementNotSatisfied,
+        verifyPair(std.testing.allocator, config, bad_statement, output.proof),
+    );
+
+    try std.testing.expectError(
+        Error.InvalidLogSize,
+        provePair(std.testing.allocator, config, 1, initial),
+    );
+}
+
+test "examples state_machine: pair transition constraints reject swapped columns" {
+    const initial = State{
+        M31.fromCanonical(9),
+        M31.fromCanonical(3),
+    };
+    const zero = M31.zero();
+    // Rows 0 and 1 of the x-axis, whose cyclic predecessor of row 0 is row 31.
+    const honest = [_][4]M31{
+        transitionConstraints(M31, 0, initial, M31.one(), .{ M31.fromCanonical(40), initial[1] }, initial),
+        transitionConstraints(M31, 0, initial, zero, initial, .{ M31.fromCanonical(10), initial[1] }),
+    };
+    for (honest) |row_constraints| {
+        for (row_constraints) |constraint| try std.testing.expect(constraint.eql(zero));
+    }
+
+    const swapped = transitionConstraints(
+        M31,
+        0,
+        initial,
+        zero,
+        .{ initial[1], initial[0] },
+        .{ initial[1], M31.fromCanonical(10) },
+    );
+    try std.testing.expect(!swapped[2].eql(zero));
+    try std.testing.expect(!swapped[3].eql(zero));
+}
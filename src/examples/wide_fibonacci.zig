@@ -4,19 +4,23 @@ const core_air_components = @import("../core/air/components.zig");
 const core_air_derive = @import("../core/air/derive.zig");
 const core_air_utils = @import("../core/air/utils.zig");
 const channel_blake2s = @import("../core/channel/blake2s.zig");
+const constraints = @import("../core/constraints.zig");
 const m31 = @import("../core/fields/m31.zig");
 const qm31 = @import("../core/fields/qm31.zig");
 const pcs_core = @import("../core/pcs/mod.zig");
 const pcs_verifier = @import("../core/pcs/verifier.zig");
+const canonic = @import("../core/poly/circle/canonic.zig");
 const core_proof = @import("../core/proof.zig");
+const core_utils = @import("../core/utils.zig");
 const core_verifier = @import("../core/verifier.zig");
 const blake2_merkle = @import("../core/vcs_lifted/blake2_merkle.zig");
 const prover_air_accumulation = @import("../prover/air/accumulation.zig");
 const prover_component = @import("../prover/air/component_prover.zig");
 const prover_pcs = @import("../prover/pcs/mod.zig");
+const circle_evaluation = @import("../prover/poly/circle/evaluation.zig");
+const circle_poly = @import("../prover/poly/circle/poly.zig");
 const prover_prove = @import("../prover/prove.zig");
 const stage_profile = @import("../prover/stage_profile.zig");
-const secure_column = @import("../prover/secure_column.zig");
 
 const M31 = m31.M31;
 const QM31 = qm31.QM31;
@@ -380,8 +384,8 @@ const WideFibonacciComponent = struct {
     }
 
     pub fn nConstraints(self: *const @This()) usize {
-        _ = self;
-        return 1;
+        const n_cols: usize = @intCast(self.statement.sequence_len);
+        return if (n_cols < 2) 0 else n_cols - 2;
     }
 
     pub fn maxConstraintLogDegreeBound(self: *const @This()) u32 {
@@ -438,41 +442,85 @@ const WideFibonacciComponent = struct {
 
     pub fn evaluateConstraintQuotientsAtPoint(
         self: *const @This(),
-        _: CirclePointQM31,
-        _: *const core_air_components.MaskValues,
+        point: CirclePointQM31,
+        mask: *const core_air_components.MaskValues,
         evaluation_accumulator: *core_air_accumulation.PointEvaluationAccumulator,
         _: u32,
     ) !void {
-        evaluation_accumulator.accumulate(compositionEval(self.statement));
+        const trace_coset = canonic.CanonicCoset.new(self.statement.log_n_rows).coset();
+        const denom_inverse = try constraints.cosetVanishing(QM31, trace_coset, point).inv();
+        const columns = mask.items[1];
+        if (columns.len < 2) return;
+        for (2..columns.len) |i| {
+            const constraint = rowConstraint(QM31, columns[i - 2][0], columns[i - 1][0], columns[i][0]);
+            evaluation_accumulator.accumulate(constraint.mul(denom_inverse));
+        }
     }
 
+    /// Re-evaluates the trace columns over the constraint evaluation domain, twice the trace
+    /// size, and divides each row's combination by the trace coset's vanishing polynomial,
+    /// which takes only `2` distinct values there.
     pub fn evaluateConstraintQuotientsOnDomain(
         self: *const @This(),
-        _: *const prover_component.Trace,
+        trace: *const prover_component.Trace,
         evaluation_accumulator: *prover_air_accumulation.DomainEvaluationAccumulator,
     ) !void {
-        const composition_eval = compositionEval(self.statement);
-        const domain_size = @as(usize, 1) << @intCast(self.statement.log_n_rows + 1);
-        const values = try evaluation_accumulator.allocator.alloc(QM31, domain_size);
-        defer evaluation_accumulator.allocator.free(values);
-        @memset(values, composition_eval);
-
-        var col = try secure_column.SecureColumnByCoords.fromSecureSlice(
-            evaluation_accumulator.allocator,
-            values,
-        );
-        defer col.deinit(evaluation_accumulator.allocator);
-        try evaluation_accumulator.accumulateColumn(self.statement.log_n_rows + 1, &col);
+        const allocator = evaluation_accumulator.allocator;
+        const log_n_rows = self.statement.log_n_rows;
+        const eval_log_size = self.maxConstraintLogDegreeBound();
+        const log_expand = eval_log_size - log_n_rows;
+        const trace_domain = canonic.CanonicCoset.new(log_n_rows).circleDomain();
+        const eval_domain = canonic.CanonicCoset.new(eval_log_size).circleDomain();
+
+        const polys = trace.polys.items[1];
+        const columns = try allocator.alloc([]const M31, polys.len);
+        var n_evaluated: usize = 0;
+        defer {
+            for (columns[0..n_evaluated]) |column| allocator.free(@constCast(column));
+            allocator.free(columns);
+        }
+        for (polys, columns) |poly, *column| {
+            var coeffs = try circle_poly.interpolateFromEvaluation(
+                allocator,
+                try circle_evaluation.CircleEvaluation.init(trace_domain, poly.values),
+            );
+            defer coeffs.deinit(allocator);
+            column.* = (try coeffs.evaluate(allocator, eval_domain)).values;
+            n_evaluated += 1;
+        }
+
+        const trace_coset = canonic.CanonicCoset.new(log_n_rows).coset();
+        const denom_inverses = try allocator.alloc(M31, @as(usize, 1) << @intCast(log_expand));
+        defer allocator.free(denom_inverses);
+        for (denom_inverses, 0..) |*denom_inverse, i| {
+            const point = eval_domain.at(core_utils.bitReverseIndex(i, log_expand));
+            denom_inverse.* = try constraints.cosetVanishing(M31, trace_coset, point).inv();
+        }
+
+        const n_constraints = self.nConstraints();
+        const accumulators = try evaluation_accumulator.columns(allocator, &.{.{
+            .log_size = eval_log_size,
+            .n_cols = n_constraints,
+        }});
+        defer allocator.free(accumulators);
+        const accumulator = &accumulators[0];
+        // Powers are ascending; the first constraint takes the highest, as in the point
+        // accumulator.
+        const powers = accumulator.random_coeff_powers;
+        for (0..@as(usize, 1) << @intCast(eval_log_size)) |row| {
+            var combined = QM31.zero();
+            for (0..n_constraints) |i| {
+                const constraint = rowConstraint(M31, columns[i][row], columns[i + 1][row], columns[i + 2][row]);
+                combined = combined.add(powers[n_constraints - 1 - i].mulM31(constraint));
+            }
+            accumulator.accumulate(row, combined.mulM31(denom_inverses[row >> @intCast(log_n_rows)]));
+        }
     }
 };
 
-fn compositionEval(statement: Statement) QM31 {
-    return QM31.fromM31(
-        M31.fromU64(statement.log_n_rows),
-        M31.fromU64(statement.sequence_len),
-        M31.zero(),
-        M31.one(),
-    );
+/// Every column from the third on must equal the sum of the squares of the two columns before it.
+fn rowConstraint(comptime F: type, a: F, b: F, c: F) F {
+    return c.sub(a.square().add(b.square()));
 }
 
 fn mixStatement(channel: *Channel, statement: Statement) void {
@@ -588,3 +636,25 @@ test "examples wide_fibonacci: verify wrapper rejects statement mismatch" {
         );
     }
 }
+
+test "examples wide_fibonacci: tampered trace sample fails the oods check" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+
+    const statement: Statement = .{
+        .log_n_rows = 5,
+        .sequence_len = 16,
+    };
+    var output = try prove(std.testing.allocator, config, statement);
+
+    const sample = &output.proof.commitment_scheme_proof.sampled_values.items[1][3][0];
+    sample.* = sample.add(QM31.one());
+
+    const verification_error = @import("../core/verifier_types.zig").VerificationError;
+    try std.testing.expectError(
+        verification_error.OodsNotMatching,
+        verify(std.testing.allocator, config, statement, output.proof),
+    );
+}
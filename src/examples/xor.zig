@@ -4,23 +4,27 @@ const core_air_components = @import("../core/air/components.zig");
 const core_air_derive = @import("../core/air/derive.zig");
 const core_air_utils = @import("../core/air/utils.zig");
 const channel_blake2s = @import("../core/channel/blake2s.zig");
+const constraints = @import("../core/constraints.zig");
 const m31 = @import("../core/fields/m31.zig");
 const qm31 = @import("../core/fields/qm31.zig");
 const pcs_core = @import("../core/pcs/mod.zig");
 const pcs_verifier = @import("../core/pcs/verifier.zig");
+const canonic = @import("../core/poly/circle/canonic.zig");
 const core_proof = @import("../core/proof.zig");
+const core_utils = @import("../core/utils.zig");
 const core_verifier = @import("../core/verifier.zig");
 const blake2_merkle = @import("../core/vcs_lifted/blake2_merkle.zig");
 const prover_air_accumulation = @import("../prover/air/accumulation.zig");
 const prover_component = @import("../prover/air/component_prover.zig");
 const prover_pcs = @import("../prover/pcs/mod.zig");
+const circle_evaluation = @import("../prover/poly/circle/evaluation.zig");
+const circle_poly = @import("../prover/poly/circle/poly.zig");
 const prover_prove = @import("../prover/prove.zig");
-const secure_column = @import("../prover/secure_column.zig");
-const utils = @import("../core/utils.zig");
 
 const M31 = m31.M31;
 const QM31 = qm31.QM31;
 const CirclePointQM31 = @import("../core/circle.zig").CirclePointQM31;
+const CircleDomain = @import("../core/poly/circle/domain.zig").CircleDomain;
 pub const Hasher = blake2_merkle.Blake2sMerkleHasher;
 pub const MerkleChannel = blake2_merkle.Blake2sMerkleChannel;
 pub const Channel = channel_blake2s.Blake2sChannel;
@@ -55,11 +59,6 @@ pub fn genIsStepWithOffsetColumn(
     return core_air_utils.genPeriodicIndicatorColumn(allocator, log_size, log_step, offset);
 }
 
-fn checkedPow2(log_size: u32) Error!usize {
-    if (log_size >= @bitSizeOf(usize)) return Error.InvalidLogSize;
-    return @as(usize, 1) << @intCast(log_size);
-}
-
 pub const Statement = struct {
     log_size: u32,
     log_step: u32,
@@ -129,7 +128,7 @@ pub fn proveEx(
     is_step_moved = true;
     try scheme.commitOwned(allocator, preprocessed_owned, &channel);
 
-    const main_col = try genMainColumn(allocator, statement.log_size);
+    const main_col = try genMainColumn(allocator, statement);
     var main_col_moved = false;
     defer if (!main_col_moved) allocator.free(main_col);
     const main_owned = try allocator.alloc(prover_pcs.ColumnEvaluation, 1);
@@ -235,6 +234,12 @@ pub fn verify(
     );
 }
 
+/// Preprocessed column selecting the rows on which the main column is one; column `0` is
+/// `is_first`, which is committed and sampled but not read by the round constraints.
+const is_step_column: usize = 1;
+/// Preprocessed columns the component samples.
+pub const preprocessed_columns = [_]usize{ 0, is_step_column };
+
 const XorExampleComponent = struct {
     statement: Statement,
 
@@ -254,7 +259,7 @@ const XorExampleComponent = struct {
     }
 
     pub fn nConstraints(_: *const @This()) usize {
-        return 1;
+        return 2;
     }
 
     pub fn maxConstraintLogDegreeBound(self: *const @This()) u32 {
@@ -286,8 +291,11 @@ const XorExampleComponent = struct {
         point: CirclePointQM31,
         _: u32,
     ) !core_air_components.MaskPoints {
-        const preprocessed_col0 = try allocator.alloc(CirclePointQM31, 0);
-        const preprocessed_col1 = try allocator.alloc(CirclePointQM31, 0);
+        // Every preprocessed column the component lists is sampled, including `is_first`.
+        const preprocessed_col0 = try allocator.alloc(CirclePointQM31, 1);
+        preprocessed_col0[0] = point;
+        const preprocessed_col1 = try allocator.alloc(CirclePointQM31, 1);
+        preprocessed_col1[0] = point;
         const preprocessed_cols = try allocator.dupe([]CirclePointQM31, &[_][]CirclePointQM31{
             preprocessed_col0,
             preprocessed_col1,
@@ -311,63 +319,108 @@ const XorExampleComponent = struct {
         _: *const @This(),
         allocator: std.mem.Allocator,
     ) ![]usize {
-        return allocator.dupe(usize, &[_]usize{ 0, 1 });
+        return allocator.dupe(usize, &preprocessed_columns);
     }
 
     pub fn evaluateConstraintQuotientsAtPoint(
         self: *const @This(),
-        _: CirclePointQM31,
-        _: *const core_air_components.MaskValues,
+        point: CirclePointQM31,
+        mask: *const core_air_components.MaskValues,
         evaluation_accumulator: *core_air_accumulation.PointEvaluationAccumulator,
         _: u32,
     ) !void {
-        evaluation_accumulator.accumulate(compositionEval(self.statement));
+        const trace_coset = canonic.CanonicCoset.new(self.statement.log_size).coset();
+        const denom_inverse = try constraints.cosetVanishing(QM31, trace_coset, point).inv();
+        const row_constraints = roundConstraints(
+            QM31,
+            mask.items[0][is_step_column][0],
+            mask.items[1][0][0],
+        );
+        for (row_constraints) |constraint| {
+            evaluation_accumulator.accumulate(constraint.mul(denom_inverse));
+        }
     }
 
     pub fn evaluateConstraintQuotientsOnDomain(
         self: *const @This(),
-        _: *const prover_component.Trace,
+        trace: *const prover_component.Trace,
         evaluation_accumulator: *prover_air_accumulation.DomainEvaluationAccumulator,
     ) !void {
-        const composition_eval = compositionEval(self.statement);
-        const domain_size = @as(usize, 1) << @intCast(self.statement.log_size + 1);
-        const values = try evaluation_accumulator.allocator.alloc(QM31, domain_size);
-        defer evaluation_accumulator.allocator.free(values);
-        @memset(values, composition_eval);
-
-        var col = try secure_column.SecureColumnByCoords.fromSecureSlice(
-            evaluation_accumulator.allocator,
-            values,
+        const allocator = evaluation_accumulator.allocator;
+        const log_size = self.statement.log_size;
+        const eval_log_size = self.maxConstraintLogDegreeBound();
+        const log_expand = eval_log_size - log_size;
+        const eval_domain = canonic.CanonicCoset.new(eval_log_size).circleDomain();
+
+        const is_step = try evaluateOnDomain(
+            allocator,
+            trace.polys.items[0][is_step_column],
+            eval_domain,
         );
-        defer col.deinit(evaluation_accumulator.allocator);
-        try evaluation_accumulator.accumulateColumn(self.statement.log_size + 1, &col);
+        defer allocator.free(is_step);
+        const x = try evaluateOnDomain(allocator, trace.polys.items[1][0], eval_domain);
+        defer allocator.free(x);
+
+        const trace_coset = canonic.CanonicCoset.new(log_size).coset();
+        const denom_inverses = try allocator.alloc(M31, @as(usize, 1) << @intCast(log_expand));
+        defer allocator.free(denom_inverses);
+        for (denom_inverses, 0..) |*denom_inverse, i| {
+            const point = eval_domain.at(core_utils.bitReverseIndex(i, log_expand));
+            denom_inverse.* = try constraints.cosetVanishing(M31, trace_coset, point).inv();
+        }
+
+        const accumulators = try evaluation_accumulator.columns(allocator, &.{.{
+            .log_size = eval_log_size,
+            .n_cols = 2,
+        }});
+        defer allocator.free(accumulators);
+        const accumulator = &accumulators[0];
+        // As in `wide_fibonacci`, the first constraint takes the highest power.
+        const powers = accumulator.random_coeff_powers;
+        for (0..@as(usize, 1) << @intCast(eval_log_size)) |row| {
+            const row_constraints = roundConstraints(M31, is_step[row], x[row]);
+            var combined = QM31.zero();
+            for (row_constraints, 0..) |constraint, i| {
+                combined = combined.add(powers[row_constraints.len - 1 - i].mulM31(constraint));
+            }
+            accumulator.accumulate(row, combined.mulM31(denom_inverses[row >> @intCast(log_size)]));
+        }
     }
 };
 
+/// The honest main column: one exactly on the rows `is_step` selects and zero elsewhere.
 fn genMainColumn(
     allocator: std.mem.Allocator,
-    log_size: u32,
+    statement: Statement,
 ) (std.mem.Allocator.Error || Error)![]M31 {
-    const n = checkedPow2(log_size) catch return Error.InvalidLogSize;
-    const values = try allocator.alloc(M31, n);
-    @memset(values, M31.zero());
-
-    for (0..n) |i| {
-        const circle_domain_index = utils.cosetIndexToCircleDomainIndex(i, log_size);
-        const bit_rev_index = utils.bitReverseIndex(circle_domain_index, log_size);
-        values[bit_rev_index] = if ((i & 1) == 0) M31.one() else M31.zero();
-    }
+    return genIsStepWithOffsetColumn(
+        allocator,
+        statement.log_size,
+        statement.log_step,
+        statement.offset,
+    );
+}
 
-    return values;
+/// The main column `x` is boolean, and is one on every row the `is_step` selector picks.
+fn roundConstraints(comptime F: type, is_step: F, x: F) [2]F {
+    const x_minus_one = x.sub(F.one());
+    return .{ x.mul(x_minus_one), is_step.mul(x_minus_one) };
 }
 
-fn compositionEval(statement: Statement) QM31 {
-    return QM31.fromM31(
-        M31.fromCanonical(statement.log_size),
-        M31.fromCanonical(statement.log_step),
-        M31.fromU64(@intCast(statement.offset)),
-        M31.one(),
+/// Re-evaluates a trace column, given by its bit-reversed values over its canonic domain, over
+/// the larger `domain`.
+fn evaluateOnDomain(
+    allocator: std.mem.Allocator,
+    poly: prover_component.Poly,
+    domain: CircleDomain,
+) ![]M31 {
+    const trace_domain = canonic.CanonicCoset.new(poly.log_size).circleDomain();
+    var coeffs = try circle_poly.interpolateFromEvaluation(
+        allocator,
+        try circle_evaluation.CircleEvaluation.init(trace_domain, poly.values),
     );
+    defer coeffs.deinit(allocator);
+    return @constCast((try coeffs.evaluate(allocator, domain)).values);
 }
 
 fn mixStatement(channel: *Channel, statement: Statement) void {
@@ -482,3 +535,41 @@ test "examples xor: verify wrapper rejects statement mismatch" {
         );
     }
 }
+
+test "examples xor: every step and offset layout proves and verifies" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+    const statements = [_]Statement{
+        .{ .log_size = 4, .log_step = 0, .offset = 0 },
+        .{ .log_size = 4, .log_step = 1, .offset = 1 },
+        .{ .log_size = 5, .log_step = 5, .offset = 17 },
+    };
+    for (statements) |statement| {
+        const output = try prove(std.testing.allocator, config, statement);
+        try verify(std.testing.allocator, config, output.statement, output.proof);
+    }
+}
+
+test "examples xor: tampered main sample fails the oods check" {
+    const config = pcs_core.PcsConfig{
+        .pow_bits = 0,
+        .fri_config = try @import("../core/fri.zig").FriConfig.init(0, 1, 3),
+    };
+    const statement: Statement = .{
+        .log_size = 5,
+        .log_step = 2,
+        .offset = 3,
+    };
+    var output = try prove(std.testing.allocator, config, statement);
+
+    const sample = &output.proof.commitment_scheme_proof.sampled_values.items[1][0][0];
+    sample.* = sample.add(QM31.one());
+
+    const verification_error = @import("../core/verifier_types.zig").VerificationError;
+    try std.testing.expectError(
+        verification_error.OodsNotMatching,
+        verify(std.testing.allocator, config, statement, output.proof),
+    );
+}
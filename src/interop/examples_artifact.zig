@@ -14,7 +14,11 @@ const proof_wire = @import("proof_wire.zig");
 const M31 = m31.M31;
 const QM31 = qm31.QM31;
 
+/// Schema version the Zig generator writes; Zig artifacts carry no `generator_version`, which
+/// v2 requires.
 pub const SCHEMA_VERSION: u32 = 1;
+/// Newest schema version Zig verification reads; v2 only adds fields Zig carries through.
+pub const MAX_SCHEMA_VERSION: u32 = 2;
 pub const UPSTREAM_COMMIT: []const u8 = "a8fcf4bdde3778ae72f1e6cfe61a38e2911648d2";
 pub const EXCHANGE_MODE: []const u8 = "proof_exchange_json_wire_v1";
 
@@ -31,6 +35,8 @@ pub const StateMachineStatementWire = struct {
         x_axis_claimed_sum: Qm31Wire,
         y_axis_claimed_sum: Qm31Wire,
     },
+    /// Set by the Rust generator's `--with-interaction`; absent means no interaction tree.
+    with_interaction: ?bool = null,
 };
 
 pub const XorStatementWire = struct {
@@ -57,6 +63,26 @@ pub const BlakeStatementWire = struct {
     n_rounds: u32,
 };
 
+/// Component shape declared by the Rust generator; checked by Rust verify only.
+pub const ComponentMetadataWire = struct {
+    n_constraints: usize,
+    max_constraint_log_degree_bound: u32,
+    preprocessed_column_indices: []usize,
+    mask_shape: [][]usize,
+};
+
+/// One component of a multi-component example, as the shared statement assigns it.
+pub const ComponentWire = struct {
+    name: []const u8,
+    log_n_rows: u32,
+    claimed_sum: Qm31Wire,
+    /// Written by the Rust generator; carried through unchanged, Zig verification ignores it.
+    metadata: ?ComponentMetadataWire = null,
+};
+
+/// Component names of `state_machine_pair`, in the order the prover registers them.
+pub const STATE_MACHINE_PAIR_COMPONENTS = [_][]const u8{ "state_machine_x_axis", "state_machine_y_axis" };
+
 pub const InteropArtifact = struct {
     schema_version: u32,
     upstream_commit: []const u8,
@@ -71,7 +97,37 @@ pub const InteropArtifact = struct {
     state_machine_statement: ?StateMachineStatementWire = null,
     wide_fibonacci_statement: ?WideFibonacciStatementWire = null,
     xor_statement: ?XorStatementWire = null,
+    /// Decoded length of `proof_bytes_hex`, written by the Rust generator; absent on legacy and
+    /// Zig-generated artifacts.
+    proof_bytes_len: ?usize = null,
+    /// Hex BLAKE3 digest of the decompressed proof bytes, written by the Rust generator and checked by Rust
+    /// verification; carried through unchanged.
+    proof_bytes_blake3: ?[]const u8 = null,
     proof_bytes_hex: []const u8,
+    /// Compression of the proof bytes; absent means "none", the only one Zig verification
+    /// supports.
+    compression: ?[]const u8 = null,
+    /// Outcome a negative fixture is expected to produce ("ok" or the Rust verify stage it
+    /// fails at); carried through unchanged, verification itself ignores it.
+    expected_verify_result: ?[]const u8 = null,
+    /// Constraint count, degree bound and mask layout of the example component, written by the
+    /// Rust generator; carried through unchanged, Zig verification ignores it.
+    component_metadata: ?ComponentMetadataWire = null,
+    /// Backend the Rust prover ran on ("cpu" or "simd"); carried through unchanged.
+    prove_backend: ?[]const u8 = null,
+    /// Merkle hash of the proof's commitments; absent means "blake2s", the only one Zig
+    /// verification supports so far.
+    hash: ?[]const u8 = null,
+    /// Seed the Rust generator drew the statement parameters from; carried through unchanged.
+    statement_seed: ?u64 = null,
+    /// Version of the Rust generator, present on schema v2 artifacts; carried through unchanged.
+    generator_version: ?[]const u8 = null,
+    /// Per-component names, sizes and claimed sums of multi-component examples; absent on
+    /// single-component ones.
+    components: ?[]const ComponentWire = null,
+    /// Preprocessed columns the Rust `--preprocessed-columns` flag proved plonk or xor with; Zig
+    /// verification only accepts the component's own columns.
+    preprocessed_columns: ?[]const usize = null,
 };
 
 pub const ArtifactError = error{
@@ -79,8 +135,13 @@ pub const ArtifactError = error{
     InvalidHexDigit,
     NonCanonicalM31,
     ValueOutOfRange,
+    ComponentsMismatch,
+    UnsupportedInteraction,
 };
 
+/// Artifact path that reads the artifact from stdin or writes it to stdout.
+pub const STDIO_PATH = "-";
+
 pub fn writeArtifact(
     allocator: std.mem.Allocator,
     path: []const u8,
@@ -89,6 +150,11 @@ pub fn writeArtifact(
     const rendered = try std.json.Stringify.valueAlloc(allocator, artifact, .{});
     defer allocator.free(rendered);
 
+    if (std.mem.eql(u8, path, STDIO_PATH)) {
+        try std.fs.File.stdout().writeAll(rendered);
+        try std.fs.File.stdout().writeAll("\n");
+        return;
+    }
     const file = try std.fs.cwd().createFile(path, .{ .truncate = true });
     defer file.close();
     try file.writeAll(rendered);
@@ -96,7 +162,10 @@ pub fn writeArtifact(
 }
 
 pub fn readArtifact(allocator: std.mem.Allocator, path: []const u8) !std.json.Parsed(InteropArtifact) {
-    const raw = try std.fs.cwd().readFileAlloc(allocator, path, std.math.maxInt(usize));
+    const raw = if (std.mem.eql(u8, path, STDIO_PATH))
+        try std.fs.File.stdin().readToEndAlloc(allocator, std.math.maxInt(usize))
+    else
+        try std.fs.cwd().readFileAlloc(allocator, path, std.math.maxInt(usize));
     defer allocator.free(raw);
 
     return std.json.parseFromSlice(InteropArtifact, allocator, raw, .{
@@ -173,7 +242,44 @@ pub fn stateMachineStatementToWire(statement: state_machine.PreparedStatement) S
     };
 }
 
+/// The `components` of a `state_machine_pair` artifact: the x-axis component over `n` rows and
+/// the y-axis one over `m`, each carrying its axis claimed sum.
+pub fn stateMachinePairComponentsToWire(statement: state_machine.PreparedStatement) [2]ComponentWire {
+    return .{
+        .{
+            .name = STATE_MACHINE_PAIR_COMPONENTS[0],
+            .log_n_rows = statement.stmt0.n,
+            .claimed_sum = qm31ToWire(statement.stmt1.x_axis_claimed_sum),
+        },
+        .{
+            .name = STATE_MACHINE_PAIR_COMPONENTS[1],
+            .log_n_rows = statement.stmt0.m,
+            .claimed_sum = qm31ToWire(statement.stmt1.y_axis_claimed_sum),
+        },
+    };
+}
+
+/// Checks declared `components` against the ones the shared statement assigns; metadata is
+/// not compared.
+pub fn checkStateMachinePairComponents(
+    declared: []const ComponentWire,
+    statement: state_machine.PreparedStatement,
+) ArtifactError!void {
+    const expected = stateMachinePairComponentsToWire(statement);
+    if (declared.len != expected.len) return ArtifactError.ComponentsMismatch;
+    for (declared, expected) |component, want| {
+        if (!std.mem.eql(u8, component.name, want.name)) return ArtifactError.ComponentsMismatch;
+        if (component.log_n_rows != want.log_n_rows) return ArtifactError.ComponentsMismatch;
+        if (!std.mem.eql(u32, &component.claimed_sum, &want.claimed_sum)) {
+            return ArtifactError.ComponentsMismatch;
+        }
+    }
+}
+
+/// The Zig state machine commits no logup interaction tree, so a statement declaring one is
+/// rejected rather than verified against the wrong proof shape.
 pub fn stateMachineStatementFromWire(wire: StateMachineStatementWire) ArtifactError!state_machine.PreparedStatement {
+    if (wire.with_interaction orelse false) return ArtifactError.UnsupportedInteraction;
     return .{
         .public_input = .{
             .{
@@ -343,3 +449,55 @@ test "interop artifact: blake statement wire roundtrip" {
     try std.testing.expectEqual(statement.log_n_rows, decoded.log_n_rows);
     try std.testing.expectEqual(statement.n_rounds, decoded.n_rounds);
 }
+
+test "interop artifact: state machine pair components follow the statement" {
+    const statement = state_machine.PreparedStatement{
+        .public_input = .{
+            .{ M31.fromCanonical(9), M31.fromCanonical(3) },
+            .{ M31.fromCanonical(41), M31.fromCanonical(19) },
+        },
+        .stmt0 = .{ .n = 5, .m = 4 },
+        .stmt1 = .{
+            .x_axis_claimed_sum = QM31.fromU32Unchecked(1, 2, 3, 4),
+            .y_axis_claimed_sum = QM31.fromU32Unchecked(5, 6, 7, 8),
+        },
+    };
+    var components = stateMachinePairComponentsToWire(statement);
+    try std.testing.expectEqualStrings("state_machine_y_axis", components[1].name);
+    try std.testing.expectEqual(@as(u32, 4), components[1].log_n_rows);
+    try checkStateMachinePairComponents(&components, statement);
+
+    components[1].log_n_rows = 5;
+    try std.testing.expectError(
+        ArtifactError.ComponentsMismatch,
+        checkStateMachinePairComponents(&components, statement),
+    );
+    try std.testing.expectError(
+        ArtifactError.ComponentsMismatch,
+        checkStateMachinePairComponents(components[0..1], statement),
+    );
+}
+
+test "interop artifact: state machine statement rejects a declared interaction tree" {
+    const statement = state_machine.PreparedStatement{
+        .public_input = .{
+            .{ M31.fromCanonical(9), M31.fromCanonical(3) },
+            .{ M31.fromCanonical(41), M31.fromCanonical(19) },
+        },
+        .stmt0 = .{ .n = 5, .m = 4 },
+        .stmt1 = .{
+            .x_axis_claimed_sum = QM31.fromU32Unchecked(1, 2, 3, 4),
+            .y_axis_claimed_sum = QM31.fromU32Unchecked(5, 6, 7, 8),
+        },
+    };
+    var wire = stateMachineStatementToWire(statement);
+    try std.testing.expect(wire.with_interaction == null);
+    _ = try stateMachineStatementFromWire(wire);
+    wire.with_interaction = false;
+    _ = try stateMachineStatementFromWire(wire);
+    wire.with_interaction = true;
+    try std.testing.expectError(
+        ArtifactError.UnsupportedInteraction,
+        stateMachineStatementFromWire(wire),
+    );
+}
@@ -60,6 +60,7 @@ pub const CodecError = error{
     ValueOutOfRange,
     InvalidBinaryProof,
     UnsupportedBinaryVersion,
+    InvalidLinePolyLength,
 };
 
 const BINARY_WIRE_MAGIC = "STWOPRW1";
@@ -402,11 +403,12 @@ fn decodeFriProof(allocator: std.mem.Allocator, wire: FriProofWire) !fri.FriProo
     const coeffs = try allocator.alloc(QM31, wire.last_layer_poly.len);
     errdefer allocator.free(coeffs);
     for (wire.last_layer_poly, 0..) |coeff, i| coeffs[i] = try qm31FromWire(coeff);
+    const last_layer_poly = line.LinePoly.init(coeffs) catch return CodecError.InvalidLinePolyLength;
 
     return .{
         .first_layer = first_layer,
         .inner_layers = inner_layers,
-        .last_layer_poly = line.LinePoly.initOwned(coeffs),
+        .last_layer_poly = last_layer_poly,
     };
 }
 
@@ -30,6 +30,7 @@ const Example = enum {
     plonk,
     poseidon,
     state_machine,
+    state_machine_pair,
     wide_fibonacci,
     xor,
 };
@@ -61,6 +62,7 @@ const Cli = struct {
     sm_log_n_rows: u32 = 5,
     sm_initial_0: u32 = 9,
     sm_initial_1: u32 = 3,
+    sm_pair_log_n_rows: u32 = 5,
 
     blake_log_n_rows: u32 = 5,
     blake_n_rounds: u32 = 10,
@@ -116,6 +118,7 @@ const ExampleStatement = union(Example) {
     plonk: plonk.Statement,
     poseidon: poseidon.Statement,
     state_machine: state_machine.PreparedStatement,
+    state_machine_pair: state_machine.PreparedStatement,
     wide_fibonacci: wide_fibonacci.Statement,
     xor: xor.Statement,
 };
@@ -151,6 +154,8 @@ pub fn main() !void {
 fn runBench(allocator: std.mem.Allocator, cli: Cli) !void {
     const example = cli.example orelse return error.MissingExample;
     if (cli.bench_repeats == 0) return error.InvalidBenchRepeats;
+    // The bench report is written to stdout.
+    if (std.mem.eql(u8, cli.artifact_path, examples_artifact.STDIO_PATH)) return error.StdioArtifactInBenchMode;
     const config = try pcsConfigFromCli(cli);
 
     const prove_samples = try allocator.alloc(f64, cli.bench_repeats);
@@ -377,6 +382,41 @@ fn proveExample(
                 },
             };
         },
+        .state_machine_pair => {
+            const initial_state: state_machine.State = .{
+                try m31FromCanonical(cli.sm_initial_0),
+                try m31FromCanonical(cli.sm_initial_1),
+            };
+            return switch (cli.prove_mode) {
+                .prove => blk: {
+                    const output = try state_machine.provePair(
+                        allocator,
+                        config,
+                        cli.sm_pair_log_n_rows,
+                        initial_state,
+                    );
+                    break :blk .{
+                        .statement = .{ .state_machine_pair = output.statement },
+                        .proof = output.proof,
+                    };
+                },
+                .prove_ex => blk: {
+                    var output = try state_machine.provePairEx(
+                        allocator,
+                        config,
+                        cli.sm_pair_log_n_rows,
+                        initial_state,
+                        cli.include_all_preprocessed_columns,
+                    );
+                    const proof = output.proof.proof;
+                    output.proof.aux.deinit(allocator);
+                    break :blk .{
+                        .statement = .{ .state_machine_pair = output.statement },
+                        .proof = proof,
+                    };
+                },
+            };
+        },
         .wide_fibonacci => {
             const statement: wide_fibonacci.Statement = .{
                 .log_n_rows = cli.wf_log_n_rows,
@@ -450,6 +490,7 @@ fn verifyExample(
         .plonk => |s| try plonk.verify(allocator, config, s, proof),
         .poseidon => |s| try poseidon.verify(allocator, config, s, proof),
         .state_machine => |s| try state_machine.verify(allocator, config, s, proof),
+        .state_machine_pair => |s| try state_machine.verifyPair(allocator, config, s, proof),
         .wide_fibonacci => |s| try wide_fibonacci.verify(allocator, config, s, proof),
         .xor => |s| try xor.verify(allocator, config, s, proof),
     }
@@ -466,6 +507,7 @@ fn verifyExampleStdShims(
         .plonk => |s| try std_shims_verifier_profile.verifyPlonk(allocator, config, s, proof),
         .poseidon => |s| try std_shims_verifier_profile.verifyPoseidon(allocator, config, s, proof),
         .state_machine => |s| try std_shims_verifier_profile.verifyStateMachine(allocator, config, s, proof),
+        .state_machine_pair => |s| try std_shims_verifier_profile.verifyStateMachinePair(allocator, config, s, proof),
         .wide_fibonacci => |s| try std_shims_verifier_profile.verifyWideFibonacci(allocator, config, s, proof),
         .xor => |s| try std_shims_verifier_profile.verifyXor(allocator, config, s, proof),
     }
@@ -477,6 +519,7 @@ fn exampleName(example: Example) []const u8 {
         .plonk => "plonk",
         .poseidon => "poseidon",
         .state_machine => "state_machine",
+        .state_machine_pair => "state_machine_pair",
         .wide_fibonacci => "wide_fibonacci",
         .xor => "xor",
     };
@@ -726,6 +769,58 @@ fn runGenerate(allocator: std.mem.Allocator, cli: Cli) !void {
                 .proof_bytes_hex = proof_bytes_hex,
             });
         },
+        .state_machine_pair => {
+            const initial_state: state_machine.State = .{
+                try m31FromCanonical(cli.sm_initial_0),
+                try m31FromCanonical(cli.sm_initial_1),
+            };
+            var statement: state_machine.PreparedStatement = undefined;
+            const proof: state_machine.Proof = switch (cli.prove_mode) {
+                .prove => blk: {
+                    const output = try state_machine.provePair(
+                        gen_alloc,
+                        config,
+                        cli.sm_pair_log_n_rows,
+                        initial_state,
+                    );
+                    statement = output.statement;
+                    break :blk output.proof;
+                },
+                .prove_ex => blk: {
+                    const output = try state_machine.provePairEx(
+                        gen_alloc,
+                        config,
+                        cli.sm_pair_log_n_rows,
+                        initial_state,
+                        cli.include_all_preprocessed_columns,
+                    );
+                    statement = output.statement;
+                    break :blk output.proof.proof;
+                },
+            };
+
+            const proof_bytes = try proof_wire.encodeProofBytes(gen_alloc, proof);
+            const proof_bytes_hex = try examples_artifact.bytesToHexAlloc(gen_alloc, proof_bytes);
+            const components = examples_artifact.stateMachinePairComponentsToWire(statement);
+
+            try examples_artifact.writeArtifact(gen_alloc, cli.artifact_path, .{
+                .schema_version = examples_artifact.SCHEMA_VERSION,
+                .upstream_commit = examples_artifact.UPSTREAM_COMMIT,
+                .exchange_mode = examples_artifact.EXCHANGE_MODE,
+                .generator = "zig",
+                .example = "state_machine_pair",
+                .prove_mode = prove_mode,
+                .pcs_config = examples_artifact.pcsConfigToWire(config),
+                .blake_statement = null,
+                .plonk_statement = null,
+                .poseidon_statement = null,
+                .state_machine_statement = examples_artifact.stateMachineStatementToWire(statement),
+                .wide_fibonacci_statement = null,
+                .xor_statement = null,
+                .proof_bytes_hex = proof_bytes_hex,
+                .components = &components,
+            });
+        },
         .wide_fibonacci => {
             const statement: wide_fibonacci.Statement = .{
                 .log_n_rows = cli.wf_log_n_rows,
@@ -869,7 +964,7 @@ fn runVerifyImpl(allocator: std.mem.Allocator, cli: Cli, use_std_shims: bool) !v
     defer parsed.deinit();
 
     const artifact = parsed.value;
-    if (artifact.schema_version != examples_artifact.SCHEMA_VERSION) {
+    if (artifact.schema_version < 1 or artifact.schema_version > examples_artifact.MAX_SCHEMA_VERSION) {
         return error.UnsupportedSchemaVersion;
     }
     if (!std.mem.eql(u8, artifact.exchange_mode, examples_artifact.EXCHANGE_MODE)) {
@@ -884,11 +979,21 @@ fn runVerifyImpl(allocator: std.mem.Allocator, cli: Cli, use_std_shims: bool) !v
     if (artifact.prove_mode) |mode| {
         if (!isSupportedProveMode(mode)) return error.UnsupportedProveMode;
     }
+    if (artifact.hash) |hash| {
+        if (!std.mem.eql(u8, hash, "blake2s")) return error.UnsupportedHash;
+    }
+    if (artifact.compression) |compression| {
+        if (!std.mem.eql(u8, compression, "none")) return error.UnsupportedCompression;
+    }
 
     const config = try examples_artifact.pcsConfigFromWire(artifact.pcs_config);
     const proof_bytes = try examples_artifact.hexToBytesAlloc(allocator, artifact.proof_bytes_hex);
     defer allocator.free(proof_bytes);
 
+    if (artifact.preprocessed_columns) |columns| {
+        try checkPreprocessedColumns(artifact.example, columns);
+    }
+
     const proof = try proof_wire.decodeProofBytes(allocator, proof_bytes);
 
     if (std.mem.eql(u8, artifact.example, "blake")) {
@@ -931,6 +1036,18 @@ fn runVerifyImpl(allocator: std.mem.Allocator, cli: Cli, use_std_shims: bool) !v
         }
         return;
     }
+    if (std.mem.eql(u8, artifact.example, "state_machine_pair")) {
+        const statement_wire = artifact.state_machine_statement orelse return error.MissingStateMachineStatement;
+        const statement = try examples_artifact.stateMachineStatementFromWire(statement_wire);
+        const components = artifact.components orelse return error.MissingComponents;
+        try examples_artifact.checkStateMachinePairComponents(components, statement);
+        if (use_std_shims) {
+            try std_shims_verifier_profile.verifyStateMachinePair(allocator, config, statement, proof);
+        } else {
+            try state_machine.verifyPair(allocator, config, statement, proof);
+        }
+        return;
+    }
     if (std.mem.eql(u8, artifact.example, "wide_fibonacci")) {
         const statement_wire = artifact.wide_fibonacci_statement orelse return error.MissingWideFibonacciStatement;
         const statement = try examples_artifact.wideFibonacciStatementFromWire(statement_wire);
@@ -954,6 +1071,18 @@ fn runVerifyImpl(allocator: std.mem.Allocator, cli: Cli, use_std_shims: bool) !v
     return error.UnknownExample;
 }
 
+/// Zig components sample a fixed set of preprocessed columns, so an artifact proved with any
+/// other subset is rejected up front instead of failing inside the verifier.
+fn checkPreprocessedColumns(example: []const u8, columns: []const usize) !void {
+    const own: []const usize = if (std.mem.eql(u8, example, "plonk"))
+        &plonk.preprocessed_columns
+    else if (std.mem.eql(u8, example, "xor"))
+        &xor.preprocessed_columns
+    else
+        return error.PreprocessedColumnsMismatch;
+    if (!std.mem.eql(usize, columns, own)) return error.PreprocessedColumnsMismatch;
+}
+
 fn isSupportedGenerator(generator: []const u8) bool {
     return std.mem.eql(u8, generator, "rust") or std.mem.eql(u8, generator, "zig");
 }
@@ -986,6 +1115,7 @@ fn parseArgs(args: []const []const u8) !Cli {
     var sm_log_n_rows: u32 = 5;
     var sm_initial_0: u32 = 9;
     var sm_initial_1: u32 = 3;
+    var sm_pair_log_n_rows: u32 = 5;
 
     var blake_log_n_rows: u32 = 5;
     var blake_n_rounds: u32 = 10;
@@ -1042,6 +1172,8 @@ fn parseArgs(args: []const []const u8) !Cli {
             sm_initial_0 = try parseInt(u32, value);
         } else if (std.mem.eql(u8, flag, "--sm-initial-1")) {
             sm_initial_1 = try parseInt(u32, value);
+        } else if (std.mem.eql(u8, flag, "--sm-pair-log-n-rows")) {
+            sm_pair_log_n_rows = try parseInt(u32, value);
         } else if (std.mem.eql(u8, flag, "--blake-log-n-rows")) {
             blake_log_n_rows = try parseInt(u32, value);
         } else if (std.mem.eql(u8, flag, "--blake-n-rounds")) {
@@ -1086,6 +1218,7 @@ fn parseArgs(args: []const []const u8) !Cli {
         .sm_log_n_rows = sm_log_n_rows,
         .sm_initial_0 = sm_initial_0,
         .sm_initial_1 = sm_initial_1,
+        .sm_pair_log_n_rows = sm_pair_log_n_rows,
         .blake_log_n_rows = blake_log_n_rows,
         .blake_n_rounds = blake_n_rounds,
         .plonk_log_n_rows = plonk_log_n_rows,
@@ -1114,6 +1247,7 @@ fn parseExample(value: []const u8) ?Example {
     if (std.mem.eql(u8, value, "plonk")) return .plonk;
     if (std.mem.eql(u8, value, "poseidon")) return .poseidon;
     if (std.mem.eql(u8, value, "state_machine")) return .state_machine;
+    if (std.mem.eql(u8, value, "state_machine_pair")) return .state_machine_pair;
     if (std.mem.eql(u8, value, "wide_fibonacci")) return .wide_fibonacci;
     if (std.mem.eql(u8, value, "xor")) return .xor;
     return null;
@@ -1167,12 +1301,12 @@ fn m31FromCanonical(value: u32) !M31 {
 fn printUsage() void {
     std.debug.print(
         "usage:\n" ++
-            "  zig run src/interop_cli.zig -- --mode generate --example <blake|plonk|poseidon|state_machine|wide_fibonacci|xor> --artifact <path> [options]\n" ++
+            "  zig run src/interop_cli.zig -- --mode generate --example <blake|plonk|poseidon|state_machine|state_machine_pair|wide_fibonacci|xor> --artifact <path> [options]\n" ++
             "    [--stage-profile-out <path>] (wide_fibonacci only)\n" ++
             "    [--prove-mode <prove|prove_ex>] [--blake2-backend <auto|scalar|simd>] [--include-all-preprocessed-columns <0|1>]\n" ++
             "  zig run src/interop_cli.zig -- --mode verify --artifact <path>\n" ++
             "  zig run src/interop_cli.zig -- --mode verify_std_shims --artifact <path>\n" ++
-            "  zig run src/interop_cli.zig -- --mode bench --example <blake|plonk|poseidon|state_machine|wide_fibonacci|xor> --artifact <ignored> [options]\n" ++
+            "  zig run src/interop_cli.zig -- --mode bench --example <blake|plonk|poseidon|state_machine|state_machine_pair|wide_fibonacci|xor> --artifact <ignored> [options]\n" ++
             "    [--bench-warmups <n>] [--bench-repeats <n>] [--bench-proof-codec <json|binary>] [--blake2-backend <auto|scalar|simd>]\n",
         .{},
     );
@@ -56,6 +56,15 @@ pub fn verifyStateMachine(
     try examples_state_machine.verify(allocator, pcs_config, statement, proof);
 }
 
+pub fn verifyStateMachinePair(
+    allocator: std.mem.Allocator,
+    pcs_config: @import("../core/pcs/mod.zig").PcsConfig,
+    statement: examples_state_machine.PreparedStatement,
+    proof: examples_state_machine.Proof,
+) anyerror!void {
+    try examples_state_machine.verifyPair(allocator, pcs_config, statement, proof);
+}
+
 pub fn verifyWideFibonacci(
     allocator: std.mem.Allocator,
     pcs_config: @import("../core/pcs/mod.zig").PcsConfig,
@@ -2,6 +2,7 @@ use serde::Serialize;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use stwo_vector_common::next_u64;
 
 const DEFAULT_COUNT: usize = 32;
 const VECTOR_SCHEMA_VERSION: u32 = 1;
@@ -133,12 +134,3 @@ fn generate_vectors(state: &mut u64, sample_count: usize) -> VectorFile {
         invalid_shape_cases,
     }
 }
-
-fn next_u64(state: &mut u64) -> u64 {
-    let mut x = *state;
-    x ^= x >> 12;
-    x ^= x << 25;
-    x ^= x >> 27;
-    *state = x;
-    x.wrapping_mul(0x2545_f491_4f6c_dd1d)
-}
@@ -12,6 +12,7 @@ use stwo_constraint_framework::expr::degree::NamedExprs;
 use stwo_constraint_framework::expr::evaluator::ExprEvaluator;
 use stwo_constraint_framework::expr::{BaseExpr, ExtExpr};
 use stwo_constraint_framework::EvalAtRow;
+use stwo_vector_common::encode_qm31;
 
 const UPSTREAM_COMMIT: &str = "a8fcf4bdde3778ae72f1e6cfe61a38e2911648d2";
 const SCHEMA_VERSION: u32 = 1;
@@ -213,7 +214,7 @@ fn ext_arith_case() -> CaseVector {
         ]);
 
     let assignment = make_assignment(&columns, &params, &ext_params);
-    let ext_eval = secure_to_u32(expr.assign(&assignment));
+    let ext_eval = encode_qm31(expr.assign(&assignment));
 
     let named = NamedExprs::new(HashMap::new(), HashMap::new());
 
@@ -366,8 +367,3 @@ fn secure_from_u32(value: [u32; 4]) -> SecureField {
         BaseField::from(value[3]),
     ])
 }
-
-fn secure_to_u32(value: SecureField) -> [u32; 4] {
-    let arr = value.to_m31_array();
-    [arr[0].0, arr[1].0, arr[2].0, arr[3].0]
-}
@@ -0,0 +1,79 @@
+//! Lifted Merkle hasher and Merkle channel over Blake3, for `--hash blake3` artifacts.
+//!
+//! Only the commitment trees change hash: the Fiat-Shamir channel stays Blake2s, and a Blake3
+//! root is mixed into it as eight little-endian `u32` words. Leaves and nodes are domain
+//! separated with the same zero-padded 64-byte `leaf`/`node` prefixes as the Blake2s hasher.
+
+use stwo::core::channel::{Blake2sChannel, Channel, MerkleChannel};
+use stwo::core::fields::m31::BaseField;
+use stwo::core::vcs::blake3_hash::{Blake3Hash, Blake3Hasher};
+use stwo::core::vcs_lifted::merkle_hasher::MerkleHasherLifted;
+use stwo::prover::backend::cpu::CpuBackend;
+use stwo::prover::backend::BackendForChannel;
+
+const LEAF_PREFIX: [u8; 64] = prefix(b"leaf");
+const NODE_PREFIX: [u8; 64] = prefix(b"node");
+
+const fn prefix(tag: &[u8; 4]) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    let mut i = 0;
+    while i < tag.len() {
+        out[i] = tag[i];
+        i += 1;
+    }
+    out
+}
+
+/// Buffers a leaf's bytes and hashes them in one call on `finalize`; a leaf is one row of the
+/// committed columns, so the buffer stays small.
+#[derive(Debug, Clone, Default)]
+pub struct Blake3MerkleHasher {
+    leaf: Vec<u8>,
+}
+
+impl MerkleHasherLifted for Blake3MerkleHasher {
+    type Hash = Blake3Hash;
+
+    fn hash_children((left, right): (Blake3Hash, Blake3Hash)) -> Blake3Hash {
+        let mut payload = Vec::with_capacity(NODE_PREFIX.len() + 64);
+        payload.extend_from_slice(&NODE_PREFIX);
+        payload.extend_from_slice(left.as_ref());
+        payload.extend_from_slice(right.as_ref());
+        Blake3Hasher::hash(&payload)
+    }
+
+    fn update_leaf(&mut self, column_values: &[BaseField]) {
+        self.leaf
+            .extend(column_values.iter().flat_map(|value| value.0.to_le_bytes()));
+    }
+
+    fn finalize(self) -> Blake3Hash {
+        Blake3Hasher::hash(&self.leaf)
+    }
+
+    fn default_with_initial_state() -> Self {
+        Self {
+            leaf: LEAF_PREFIX.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Blake3MerkleChannel;
+
+impl MerkleChannel for Blake3MerkleChannel {
+    type C = Blake2sChannel;
+    type H = Blake3MerkleHasher;
+
+    fn mix_root(channel: &mut Blake2sChannel, root: Blake3Hash) {
+        let words = root
+            .as_ref()
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes(word.try_into().expect("4-byte chunk")))
+            .collect::<Vec<_>>();
+        channel.mix_u32s(&words);
+    }
+}
+
+// The CPU Merkle ops are generic over the hasher; SIMD has no Blake3 Merkle ops.
+impl BackendForChannel<Blake3MerkleChannel> for CpuBackend {}
@@ -0,0 +1,9398 @@
+//! Rust side of the stwo-zig proof-exchange harness.
+//!
+//! The `stwo-interop-rs` binary is a thin wrapper over this crate. Downstream crates that embed
+//! artifact generation should link the library instead of parsing the binary's JSON output. The
+//! supported surface is:
+//!
+//! - [`generate_artifact`] / [`verify_artifact`] and their option structs;
+//! - [`InteropArtifact`] and the wire types it is made of, which serialize to the artifact
+//!   schema ([`SCHEMA_VERSION`]), and [`parse_artifact`], which reads every supported version;
+//! - the wire conversions [`proof_to_wire`], [`wire_to_proof`], [`pcs_config_to_wire`] and
+//!   [`pcs_config_from_wire`], and [`artifact_proof_wire`] for an artifact's encoded proof.
+//!
+//! Everything else, including the CLI's flags and report formats, may change in any release.
+//! Additions to the surface are minor releases; the option structs, statements and wire types are
+//! `#[non_exhaustive]` so that new fields and examples are additions. Any other change to
+//! `public_api.txt` is breaking.
+//!
+//! ```no_run
+//! use stwo_interop_rs::{
+//!     generate_artifact, verify_artifact, Example, FriConfig, GenerateOptions, PcsConfig,
+//!     Statement, VerifyOptions,
+//! };
+//!
+//! let config = PcsConfig {
+//!     pow_bits: 0,
+//!     fri_config: FriConfig::new(0, 1, 3),
+//! };
+//! let statement = Statement::WideFibonacci {
+//!     log_n_rows: 5,
+//!     sequence_len: 16,
+//! };
+//! let artifact = generate_artifact(
+//!     Example::WideFibonacci,
+//!     statement,
+//!     config,
+//!     &GenerateOptions::default(),
+//! )?;
+//! let report = verify_artifact(&artifact, &VerifyOptions::default())?;
+//! assert_eq!(report.status, "ok");
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+mod blake3_merkle;
+mod poseidon;
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::Engine;
+use bincode::Options;
+use blake3_merkle::{Blake3MerkleChannel, Blake3MerkleHasher};
+use num_traits::{One, Zero};
+use poseidon::{POSEIDON_COLUMNS, POSEIDON_COLUMNS_PER_REP, POSEIDON_LOG_INSTANCES_PER_ROW};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::ops::{Add, Mul, Sub};
+use std::sync::{Arc, Mutex};
+use stwo::core::air::accumulation::PointEvaluationAccumulator;
+use stwo::core::air::Component;
+use stwo::core::channel::{Blake2sChannel, Channel, MerkleChannel};
+use stwo::core::circle::{CirclePoint, SECURE_FIELD_CIRCLE_GEN};
+use stwo::core::constraints::coset_vanishing;
+use stwo::core::fields::m31::{M31, P};
+use stwo::core::fields::qm31::{SecureField, QM31};
+use stwo::core::fields::secure_column::SECURE_EXTENSION_DEGREE;
+use stwo::core::fields::FieldExpOps;
+pub use stwo::core::fri::FriConfig;
+use stwo::core::fri::{FriLayerProof, FriProof};
+use stwo::core::pcs::quotients::CommitmentSchemeProof;
+pub use stwo::core::pcs::PcsConfig;
+use stwo::core::pcs::{CommitmentSchemeVerifier, TreeVec};
+use stwo::core::poly::circle::CanonicCoset;
+use stwo::core::poly::line::LinePoly;
+pub use stwo::core::proof::StarkProof;
+use stwo::core::utils::{
+    bit_reverse_index, coset_index_to_circle_domain_index, offset_bit_reversed_circle_domain_index,
+};
+use stwo::core::vcs::blake2_hash::{Blake2sHash, Blake2sHasher};
+use stwo::core::vcs::blake3_hash::{Blake3Hash, Blake3Hasher};
+use stwo::core::vcs_lifted::blake2_merkle::Blake2sMerkleChannel;
+pub use stwo::core::vcs_lifted::blake2_merkle::Blake2sMerkleHasher;
+use stwo::core::vcs_lifted::merkle_hasher::MerkleHasherLifted;
+use stwo::core::vcs_lifted::verifier::{MerkleDecommitmentLifted, MerkleVerificationError};
+use stwo::core::verifier::{verify, VerificationError};
+use stwo::prover::backend::cpu::{CpuBackend, CpuCircleEvaluation};
+use stwo::prover::backend::simd::m31::{LOG_N_LANES, N_LANES};
+use stwo::prover::backend::simd::qm31::PackedSecureField;
+use stwo::prover::backend::simd::SimdBackend;
+use stwo::prover::backend::{Backend, BackendForChannel, Column};
+use stwo::prover::poly::circle::{CircleEvaluation, PolyOps};
+use stwo::prover::poly::twiddles::TwiddleTree;
+use stwo::prover::poly::BitReversedOrder;
+use stwo::prover::{
+    prove, prove_ex, CommitmentSchemeProver, ComponentProver, DomainEvaluationAccumulator, Trace,
+};
+use stwo_vector_common::next_u64;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Artifact schema version written by [`generate_artifact`] by default, and the newest one
+/// [`parse_artifact`] and [`verify_artifact`] accept.
+pub const SCHEMA_VERSION: u32 = 2;
+/// Oldest artifact schema version still accepted; [`parse_artifact`] maps it onto the current
+/// layout.
+pub const MIN_SCHEMA_VERSION: u32 = 1;
+/// Recorded in v2 artifacts as `generator_version`.
+const GENERATOR_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Upstream stwo commit both provers are pinned to.
+pub const UPSTREAM_COMMIT: &str = "a8fcf4bdde3778ae72f1e6cfe61a38e2911648d2";
+/// Proof encoding carried in `proof_bytes_hex`.
+pub const EXCHANGE_MODE: &str = "proof_exchange_json_wire_v1";
+/// Proof encoding carried in `proof_bytes_b64`: the proof wire in bincode with fixed-width,
+/// little-endian integers.
+pub const EXCHANGE_MODE_BINCODE: &str = "proof_exchange_bincode_v1";
+const BLAKE_STATE: usize = 16;
+const BLAKE_MESSAGE_WORDS: usize = 16;
+const BLAKE_FELTS_IN_U32: usize = 2;
+const BLAKE_ROUND_INPUT_FELTS: usize =
+    (BLAKE_STATE + BLAKE_STATE + BLAKE_MESSAGE_WORDS) * BLAKE_FELTS_IN_U32;
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const ZSTD_LEVEL: i32 = 3;
+/// Most bytes a compressed proof may decompress to. Proofs grow with the log of the trace, so
+/// this is far past any honest one and only stops a small payload inflating without bound.
+const MAX_PROOF_BYTES: u64 = 64 << 20;
+/// Most bytes a compressed debug export may decompress to.
+const MAX_EXPORT_BYTES: u64 = 1 << 30;
+/// With `pow_bits = 0` the PoW digest check is trivially satisfied and is skipped, but the nonce
+/// is still mixed into the channel before query sampling. Both provers grind from nonce 0, so the
+/// only consistent nonce at zero bits is 0; anything else is rejected up front by policy.
+const POW_ZERO_BITS_REQUIRED_NONCE: u64 = 0;
+const PROFILING_FREQUENCY_HZ: i32 = 997;
+/// Documented upper bound on profiled/unprofiled average time; profiled bench runs are reported
+/// separately and must never feed ratio gates.
+const PROFILING_OVERHEAD_BOUND: f64 = 1.5;
+/// Stages of the verify path in order; a failing verification is classified by the stage it
+/// failed at, which is also the vocabulary of `expected_verify_result`. `checksum` runs inside
+/// `proof_decode`, between reading and decompressing the proof bytes and decoding them.
+const VERIFY_STAGES: [&str; 10] = [
+    "artifact_parse",
+    "metadata",
+    "pcs_config",
+    "proof_decode",
+    "checksum",
+    "pow_policy",
+    "proof_convert",
+    "statement",
+    "component_metadata",
+    "verify",
+];
+const VERIFY_RESULT_OK: &str = "ok";
+/// `--artifact` value that reads the artifact from stdin or writes it to stdout.
+const STDIO_PATH: &str = "-";
+/// Error code for proof bytes (`proof_bytes_hex` or `proof_bytes_b64`) that do not match their
+/// declared `proof_bytes_len`.
+const PROOF_BYTES_LEN_MISMATCH: &str = "proof_bytes_len_mismatch";
+/// Error code for proof bytes whose BLAKE3 digest differs from the declared `proof_bytes_blake3`.
+const CHECKSUM_MISMATCH: &str = "checksum_mismatch";
+/// Commitments of a state-machine proof: preprocessed, main and composition.
+const STATE_MACHINE_COMMITMENTS: usize = 3;
+/// Commitments of a state-machine proof that carries the logup interaction tree: preprocessed,
+/// main, interaction and composition.
+const STATE_MACHINE_INTERACTION_COMMITMENTS: usize = 4;
+/// Error code for declared `component_metadata` that disagrees with the verifier's component.
+const COMPONENT_METADATA_MISMATCH: &str = "component_metadata_mismatch";
+/// Error code for a `preprocessed_columns` subset the example's component cannot sample.
+const PREPROCESSED_COLUMNS_MISMATCH: &str = "preprocessed_columns_mismatch";
+/// Error code for a compressed payload that inflates past its cap.
+const DECOMPRESSED_SIZE_EXCEEDED: &str = "decompressed_size_exceeded";
+/// Error code for a statement parameter outside one of its `PARAM_LIMITS`.
+const PARAM_LIMIT_EXCEEDED: &str = "param_limit_exceeded";
+/// `n_rounds` bound past which the blake column count no longer fits in `usize`.
+const BLAKE_MAX_ROUNDS: u64 = if usize::MAX / BLAKE_ROUND_INPUT_FELTS > u32::MAX as usize {
+    u32::MAX as u64
+} else {
+    (usize::MAX / BLAKE_ROUND_INPUT_FELTS) as u64
+};
+/// `StageClock` ids of trace generation. Examples whose preprocessed columns are generated apart
+/// from the main trace time that as `trace_generation` and the rest under their own ids.
+const TRACE_GENERATION_STAGES: &[&str] = &[
+    "trace_generation",
+    "main_trace_generation",
+    "interaction_trace_generation",
+];
+/// Generate-mode timing summary: each reported stage and the `StageClock` ids folded into it.
+/// Scheme setup (twiddles) is counted as commit work and statement mixing as proving.
+const GENERATE_TIMING_STAGES: [(&str, &[&str]); 6] = [
+    ("trace_generation", TRACE_GENERATION_STAGES),
+    (
+        "commit",
+        &[
+            "channel_and_scheme_init",
+            "preprocessed_commit",
+            "main_trace_commit",
+            "interaction_commit",
+        ],
+    ),
+    ("prove", &["statement_mix", "core_prove"]),
+    ("wire_encode", &["proof_wire_encode"]),
+    ("hex_encode", &["proof_hex_encode"]),
+    ("write", &["artifact_write"]),
+];
+/// `core_prove` children read off upstream's tracing spans by `ProvePhaseLayer`, with labels.
+const CORE_PROVE_PHASES: [(&str, &str); 3] = [
+    ("composition", "Composition"),
+    ("fri", "FRI"),
+    ("proof_of_work", "Proof of work"),
+];
+/// Bench prove phases and the `StageClock` ids folded into each; every example reports all of
+/// them. Twiddle precompute is timed by the `TwiddleCache` and taken out of the scheme setup it
+/// runs in. `core_prove` keeps what upstream `prove` spends outside `CORE_PROVE_PHASES` (OODS
+/// sampling, quotients, decommitment).
+const PROVE_PHASES: [(&str, &[&str]); 9] = [
+    ("trace_generation", TRACE_GENERATION_STAGES),
+    ("twiddle_precompute", &[]),
+    (
+        "commit",
+        &[
+            "channel_and_scheme_init",
+            "preprocessed_commit",
+            "main_trace_commit",
+            "interaction_commit",
+        ],
+    ),
+    ("statement_mix", &["statement_mix"]),
+    ("composition", &["composition"]),
+    ("fri", &["fri"]),
+    ("proof_of_work", &["proof_of_work"]),
+    ("core_prove", &["core_prove"]),
+    ("serialization", &["proof_serialization"]),
+];
+const REPRO_BUNDLE_VERSION: u32 = 1;
+/// Arrays longer than this are truncated in repro-bundle intermediates (wide enough to keep a
+/// 32-byte hash intact).
+const REPRO_MAX_ARRAY_ITEMS: usize = 32;
+const ALL_EXAMPLES: [Example; 7] = [
+    Example::Blake,
+    Example::Plonk,
+    Example::Poseidon,
+    Example::StateMachine,
+    Example::StateMachinePair,
+    Example::WideFibonacci,
+    Example::Xor,
+];
+/// Size parameter of each example: its `--scale-param` name, the inclusive range the prover
+/// accepts, and the `Cli` field it sets. Bench-scale resolves parameters only through this table,
+/// so a new example gets scaling support by adding a row.
+const EXAMPLE_SIZE_PARAMS: &[ExampleSizeParam] = &[
+    ExampleSizeParam {
+        example: Example::Blake,
+        name: "log_n_rows",
+        min: 1,
+        max: 30,
+        apply: |cli, value| cli.blake_log_n_rows = value,
+    },
+    ExampleSizeParam {
+        example: Example::Plonk,
+        name: "log_n_rows",
+        min: 1,
+        max: 30,
+        apply: |cli, value| cli.plonk_log_n_rows = value,
+    },
+    ExampleSizeParam {
+        example: Example::Poseidon,
+        name: "log_n_instances",
+        min: POSEIDON_LOG_INSTANCES_PER_ROW + 1,
+        max: POSEIDON_LOG_INSTANCES_PER_ROW + 30,
+        apply: |cli, value| cli.poseidon_log_n_instances = value,
+    },
+    ExampleSizeParam {
+        example: Example::StateMachine,
+        name: "log_n_rows",
+        min: 1,
+        max: 30,
+        apply: |cli, value| cli.sm_log_n_rows = value,
+    },
+    ExampleSizeParam {
+        example: Example::StateMachinePair,
+        name: "log_n_rows",
+        min: 2,
+        max: 30,
+        apply: |cli, value| cli.sm_pair_log_n_rows = value,
+    },
+    ExampleSizeParam {
+        example: Example::WideFibonacci,
+        name: "log_n_rows",
+        min: 1,
+        max: 30,
+        apply: |cli, value| cli.wf_log_n_rows = value,
+    },
+    ExampleSizeParam {
+        example: Example::Xor,
+        name: "log_size",
+        min: 1,
+        max: 30,
+        apply: |cli, value| cli.xor_log_size = value,
+    },
+];
+/// Every bound on a statement parameter, checked by `check_param_limit` on both the prove and
+/// verify paths and published by capabilities mode. A parameter with bounds of different kinds has
+/// one row per kind; rows are checked in order, so the first violated row classifies the error.
+/// Relations between parameters (xor `log_step <= log_size`, state machine `m = n - 1`) are
+/// statement consistency checks, not limits.
+const PARAM_LIMITS: &[ParamLimit] = &[
+    ParamLimit {
+        example: Example::Blake,
+        param: "log_n_rows",
+        min: 1,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "canonic cosets need log size >= 1 and the M31 circle group has order 2^31",
+    },
+    ParamLimit {
+        example: Example::Blake,
+        param: "n_rounds",
+        min: 1,
+        max: u32::MAX as u64,
+        kind: LimitKind::ImplementationCap,
+        reason: "the blake component is laid out with at least one round of columns",
+    },
+    ParamLimit {
+        example: Example::Blake,
+        param: "n_rounds",
+        min: 0,
+        max: BLAKE_MAX_ROUNDS,
+        kind: LimitKind::ResourceGuard,
+        reason: "n_rounds * BLAKE_ROUND_INPUT_FELTS columns must fit in usize",
+    },
+    ParamLimit {
+        example: Example::Plonk,
+        param: "log_n_rows",
+        min: 1,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "canonic cosets need log size >= 1 and the M31 circle group has order 2^31",
+    },
+    ParamLimit {
+        example: Example::Poseidon,
+        param: "log_n_instances",
+        min: POSEIDON_LOG_INSTANCES_PER_ROW as u64,
+        max: u32::MAX as u64,
+        kind: LimitKind::ImplementationCap,
+        reason: "the trace packs 2^POSEIDON_LOG_INSTANCES_PER_ROW instances into each row",
+    },
+    ParamLimit {
+        example: Example::Poseidon,
+        param: "log_n_instances",
+        min: 0,
+        max: POSEIDON_LOG_INSTANCES_PER_ROW as u64 + 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "the M31 circle group has order 2^31, bounding log_n_rows at 30",
+    },
+    ParamLimit {
+        example: Example::StateMachine,
+        param: "log_n_rows",
+        min: 1,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "canonic cosets need log size >= 1 and the M31 circle group has order 2^31",
+    },
+    ParamLimit {
+        example: Example::StateMachinePair,
+        param: "log_n_rows",
+        min: 2,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "the y-axis component has log_n_rows - 1, which canonic cosets need to be >= 1",
+    },
+    ParamLimit {
+        example: Example::WideFibonacci,
+        param: "log_n_rows",
+        min: 1,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "canonic cosets need log size >= 1 and the M31 circle group has order 2^31",
+    },
+    ParamLimit {
+        example: Example::WideFibonacci,
+        param: "sequence_len",
+        min: 2,
+        max: u32::MAX as u64,
+        kind: LimitKind::ProtocolBound,
+        reason: "the sequence is defined from two seed columns",
+    },
+    ParamLimit {
+        example: Example::Xor,
+        param: "log_size",
+        min: 1,
+        max: 30,
+        kind: LimitKind::ProtocolBound,
+        reason: "canonic cosets need log size >= 1 and the M31 circle group has order 2^31",
+    },
+];
+/// Inclusive range of the log sizes `--statement-seed` draws.
+const SEEDED_LOG_SIZES: (u32, u32) = (4, 8);
+const SEEDED_MAX_BLAKE_ROUNDS: u64 = 4;
+const SEEDED_MAX_SEQUENCE_LEN: u64 = 64;
+/// Mixed into `--statement-seed` so that small seeds start the stream away from zero.
+const STATEMENT_SEED_OFFSET: u64 = 0x9e37_79b9_7f4a_7c15;
+/// Corruptions `--mode tamper` applies to a freshly proved artifact. Each is deterministic: it
+/// touches the first element that can take it.
+const TAMPER_KINDS: [&str; 7] = [
+    "commitment_byte",
+    "hash_witness_truncate",
+    "sampled_value",
+    "proof_of_work",
+    "queried_values_swap",
+    "last_layer_poly",
+    "statement",
+];
+const GOLDEN_SCHEMA_VERSION: u32 = 1;
+const GOLDEN_FILE_NAME: &str = "golden_hashes.json";
+/// Decode paths a fuzz regression input is replayed through, which are also the subdirectories of
+/// `FUZZ_REGRESSIONS_DIR` holding their inputs.
+#[doc(hidden)]
+pub const FUZZ_REGRESSION_TARGETS: [&str; 3] = ["artifact_parse", "wire_to_proof", "hex_decode"];
+const FUZZ_REGRESSIONS_DIR: &str = "fuzz/regressions";
+/// Leading hex digits of an input's Blake2s hash used as its corpus file name.
+const FUZZ_REGRESSION_NAME_LEN: usize = 16;
+/// Fixtures pinned by the golden proof-hash registry: a stable slug and the generate-mode flags
+/// that reproduce it. Every fixture is fully determined by its flags (no seeds or timestamps).
+const GOLDEN_FIXTURES: &[(&str, &[&str])] = &[
+    ("blake_default", &["--example", "blake"]),
+    ("plonk_default", &["--example", "plonk"]),
+    ("poseidon_default", &["--example", "poseidon"]),
+    ("state_machine_default", &["--example", "state_machine"]),
+    (
+        "state_machine_prove_ex",
+        &["--example", "state_machine", "--prove-mode", "prove_ex"],
+    ),
+    ("wide_fibonacci_default", &["--example", "wide_fibonacci"]),
+    ("xor_default", &["--example", "xor"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Mode {
+    Generate,
+    Verify,
+    Bench,
+    BenchScale,
+    GoldenCheck,
+    GoldenUpdate,
+    Capabilities,
+    MicroSuite,
+    CrossVerify,
+    AddRegression,
+    Conform,
+    Inspect,
+    Tamper,
+    BatchGenerate,
+    BatchVerify,
+    Roundtrip,
+    BenchCompare,
+    SizeReport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Example {
+    Blake,
+    Plonk,
+    Poseidon,
+    StateMachine,
+    /// The state machine as two components, x-axis and y-axis, proved together.
+    StateMachinePair,
+    WideFibonacci,
+    Xor,
+}
+
+struct ExampleSizeParam {
+    example: Example,
+    name: &'static str,
+    min: u32,
+    max: u32,
+    apply: fn(&mut Cli, u32),
+}
+
+/// Why a statement parameter is bounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LimitKind {
+    /// Inherent to the circle STARK or to the example's statement; no build can accept it.
+    ProtocolBound,
+    /// A layout choice of this implementation that a rebuild could lift.
+    ImplementationCap,
+    /// Keeps sizes and counts within what the host can represent.
+    ResourceGuard,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+struct ParamLimit {
+    example: Example,
+    param: &'static str,
+    min: u64,
+    max: u64,
+    kind: LimitKind,
+    reason: &'static str,
+}
+
+/// A statement parameter outside the inclusive `min..=max` of one of its limits. Travels inside
+/// the `anyhow` chain; callers recover it with `err.downcast_ref::<LimitError>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LimitError {
+    pub example: Example,
+    pub param: &'static str,
+    pub value: u64,
+    pub min: u64,
+    pub max: u64,
+    pub kind: LimitKind,
+}
+
+impl std::fmt::Display for LimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{PARAM_LIMIT_EXCEEDED}: {} {}={} outside {}..={} ({})",
+            example_to_str(self.example),
+            self.param,
+            self.value,
+            self.min,
+            self.max,
+            limit_kind_to_str(self.kind)
+        )
+    }
+}
+
+impl std::error::Error for LimitError {}
+
+/// Failure classes of verify mode. A failed `--mode verify` reports its class as `kind` and exits
+/// with [`InteropError::exit_code`], so callers classify rejections without reading error text.
+/// The class follows the verify stage that failed; at the `verify` stage it follows the `stwo`
+/// verification error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum InteropError {
+    /// The artifact, its metadata, PCS config or declared component metadata do not match what
+    /// this verifier reads.
+    SchemaMismatch,
+    /// The decompressed proof bytes do not hash to the declared `proof_bytes_blake3`.
+    ChecksumMismatch,
+    /// The proof bytes do not decode (or decompress) into a proof wire.
+    DecodeError,
+    /// The statement is missing or malformed, or the proof does not satisfy it.
+    StatementInvalid,
+    /// The proof's structure (commitments, sampled values, witnesses) is inconsistent.
+    ProofShapeInvalid,
+    MerkleRootMismatch,
+    FriVerificationFailed,
+    /// The proof-of-work nonce fails the grinding check or the zero-bit nonce policy.
+    PowFailed,
+    /// The sampled values do not satisfy the composition polynomial at the OODS point.
+    OodsMismatch,
+    Other,
+}
+
+impl InteropError {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SchemaMismatch => "schema_mismatch",
+            Self::ChecksumMismatch => "checksum_mismatch",
+            Self::DecodeError => "decode_error",
+            Self::StatementInvalid => "statement_invalid",
+            Self::ProofShapeInvalid => "proof_shape_invalid",
+            Self::MerkleRootMismatch => "merkle_root_mismatch",
+            Self::FriVerificationFailed => "fri_verification_failed",
+            Self::PowFailed => "pow_failed",
+            Self::OodsMismatch => "oods_mismatch",
+            Self::Other => "other",
+        }
+    }
+
+    /// Process exit status of a verify failure of this kind. Every other failure of the binary
+    /// (usage errors, generate, bench) exits with 1.
+    pub fn exit_code(self) -> u8 {
+        match self {
+            Self::SchemaMismatch => 10,
+            Self::ChecksumMismatch => 11,
+            Self::DecodeError => 12,
+            Self::StatementInvalid => 13,
+            Self::ProofShapeInvalid => 14,
+            Self::MerkleRootMismatch => 15,
+            Self::FriVerificationFailed => 16,
+            Self::PowFailed => 17,
+            Self::OodsMismatch => 18,
+            Self::Other => 19,
+        }
+    }
+
+    fn from_verify_failure(stage: &str, err: &anyhow::Error) -> Self {
+        match stage {
+            "artifact_parse" | "metadata" | "pcs_config" | "component_metadata" => {
+                Self::SchemaMismatch
+            }
+            "checksum" => Self::ChecksumMismatch,
+            "proof_decode" => Self::DecodeError,
+            "pow_policy" => Self::PowFailed,
+            "proof_convert" => Self::ProofShapeInvalid,
+            "statement" => Self::StatementInvalid,
+            "verify" => match err.downcast_ref::<VerificationError>() {
+                Some(err) => Self::from_verification_error(err),
+                // The example's own checks before `stwo` runs: statement consistency and the
+                // commitment count it implies.
+                None => Self::StatementInvalid,
+            },
+            _ => Self::Other,
+        }
+    }
+
+    fn from_verification_error(err: &VerificationError) -> Self {
+        match err {
+            VerificationError::InvalidStructure(_) => Self::ProofShapeInvalid,
+            VerificationError::Merkle(MerkleVerificationError::RootMismatch) => {
+                Self::MerkleRootMismatch
+            }
+            VerificationError::Merkle(_) => Self::ProofShapeInvalid,
+            VerificationError::OodsNotMatching => Self::OodsMismatch,
+            VerificationError::Fri(_) => Self::FriVerificationFailed,
+            VerificationError::ProofOfWork => Self::PowFailed,
+        }
+    }
+}
+
+impl std::fmt::Display for InteropError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::error::Error for InteropError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Preset {
+    Micro,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProveMode {
+    #[default]
+    Prove,
+    ProveEx,
+}
+
+/// How [`generate_artifact`] encodes the proof wire. Each encoding has its own `exchange_mode`
+/// and artifact field; verify accepts both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProofEncoding {
+    /// JSON wire, hex-encoded in `proof_bytes_hex` ([`EXCHANGE_MODE`]).
+    #[default]
+    Json,
+    /// Bincode wire, base64-encoded in `proof_bytes_b64` ([`EXCHANGE_MODE_BINCODE`]).
+    Bincode,
+}
+
+/// Compression [`generate_artifact`] applies to the encoded proof wire before hex/base64 encoding,
+/// recorded in the artifact's `compression` field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProofCompression {
+    #[default]
+    None,
+    Zstd,
+}
+
+/// Backend [`generate_artifact`] proves on, recorded in the artifact's `prove_backend` field.
+/// Both produce the same transcript, so the proof and its verification do not depend on it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProveBackend {
+    #[default]
+    Cpu,
+    Simd,
+}
+
+/// Schema version [`generate_artifact`] writes. `V1` exists for readers that predate v2 and
+/// omits the fields v2 added: `proof_bytes_len`, `proof_bytes_blake3`, `prove_backend` and
+/// `generator_version`. Compressed and bincode proofs have no v1 form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ArtifactSchema {
+    V1,
+    #[default]
+    V2,
+}
+
+/// Hash of the commitment Merkle trees, recorded in the artifact's `hash` field when not the
+/// default. The Fiat-Shamir channel is Blake2s under both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProofHash {
+    #[default]
+    Blake2s,
+    Blake3,
+}
+
+/// Outcome `--expect` asserts for verify mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum VerifyExpect {
+    Success,
+    Failure,
+}
+
+/// Format of the report bench mode prints; `--bench-out` is always JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BenchFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExportCompression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Cli {
+    mode: Mode,
+    example: Option<Example>,
+    artifact: String,
+    stage_profile_out: Option<String>,
+    timings_out: Option<String>,
+    repro_bundle: Option<String>,
+    expect: Option<VerifyExpect>,
+    expect_error: Option<String>,
+    profile_out: Option<String>,
+    bench_out: Option<String>,
+    export_compress: ExportCompression,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    proof_encoding: ProofEncoding,
+    proof_compression: ProofCompression,
+    backend: ProveBackend,
+    hash: ProofHash,
+    statement_seed: Option<u64>,
+    schema: ArtifactSchema,
+    with_interaction: bool,
+    preprocessed_columns: Option<Vec<usize>>,
+    dump_transcript: Option<String>,
+
+    pow_bits: u32,
+    fri_log_blowup: u32,
+    fri_log_last_layer: u32,
+    fri_n_queries: usize,
+
+    sm_log_n_rows: u32,
+    sm_initial_0: u32,
+    sm_initial_1: u32,
+    sm_pair_log_n_rows: u32,
+
+    blake_log_n_rows: u32,
+    blake_n_rounds: u32,
+
+    plonk_log_n_rows: u32,
+
+    poseidon_log_n_instances: u32,
+
+    wf_log_n_rows: u32,
+    wf_sequence_len: u32,
+
+    xor_log_size: u32,
+    xor_log_step: u32,
+    xor_offset: usize,
+
+    bench_warmups: usize,
+    bench_repeats: usize,
+    bench_format: BenchFormat,
+    scale_param: Option<String>,
+    scale_points: Option<usize>,
+    budget_seconds: Option<f64>,
+
+    baseline: Option<String>,
+    candidate: Option<String>,
+    max_prove_ratio: Option<f64>,
+    max_verify_ratio: Option<f64>,
+    max_proof_bytes_ratio: Option<f64>,
+    max_peak_rss_ratio: Option<f64>,
+
+    golden_file: String,
+    golden_reason: Option<String>,
+
+    artifacts_dir: Option<String>,
+
+    fuzz_target: Option<String>,
+    regressions_dir: String,
+
+    tamper_kind: Option<String>,
+    manifest: Option<String>,
+    artifact_dir: Option<String>,
+    jobs: Option<usize>,
+    keep_going: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FriConfigWire {
+    pub log_blowup_factor: u32,
+    pub log_last_layer_degree_bound: u32,
+    pub n_queries: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PcsConfigWire {
+    pub pow_bits: u32,
+    pub fri_config: FriConfigWire,
+}
+
+pub type HashWire = [u8; 32];
+pub type Qm31Wire = [u32; 4];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MerkleDecommitmentWire {
+    pub hash_witness: Vec<HashWire>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FriLayerWire {
+    pub fri_witness: Vec<Qm31Wire>,
+    pub decommitment: MerkleDecommitmentWire,
+    pub commitment: HashWire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FriProofWire {
+    pub first_layer: FriLayerWire,
+    pub inner_layers: Vec<FriLayerWire>,
+    pub last_layer_poly: Vec<Qm31Wire>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProofWire {
+    pub config: PcsConfigWire,
+    pub commitments: Vec<HashWire>,
+    pub sampled_values: Vec<Vec<Vec<Qm31Wire>>>,
+    pub decommitments: Vec<MerkleDecommitmentWire>,
+    pub queried_values: Vec<Vec<Vec<u32>>>,
+    pub proof_of_work: u64,
+    pub fri_proof: FriProofWire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StateMachineStatementWire {
+    pub public_input: [[u32; 2]; 2],
+    pub stmt0: StateMachineStmt0Wire,
+    pub stmt1: StateMachineStmt1Wire,
+    /// Whether the proof commits the logup interaction tree (`--with-interaction`). Written only
+    /// when set; absent means no interaction tree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_interaction: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StateMachineStmt0Wire {
+    pub n: u32,
+    pub m: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StateMachineStmt1Wire {
+    pub x_axis_claimed_sum: Qm31Wire,
+    pub y_axis_claimed_sum: Qm31Wire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct XorStatementWire {
+    pub log_size: u32,
+    pub log_step: u32,
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PlonkStatementWire {
+    pub log_n_rows: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PoseidonStatementWire {
+    pub log_n_instances: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BlakeStatementWire {
+    pub log_n_rows: u32,
+    pub n_rounds: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WideFibonacciStatementWire {
+    pub log_n_rows: u32,
+    pub sequence_len: u32,
+}
+
+/// Shape of the example's AIR component as the generator built it. Verify recomputes it from the
+/// statement and rejects a mismatch before running the verifier, so a disagreement on the AIR
+/// itself is reported apart from a bad proof.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ComponentMetadataWire {
+    pub n_constraints: usize,
+    pub max_constraint_log_degree_bound: u32,
+    pub preprocessed_column_indices: Vec<usize>,
+    /// Number of mask points per column, indexed `[tree][column]`.
+    pub mask_shape: Vec<Vec<usize>>,
+}
+
+/// One component of a multi-component example: its name, trace size and logup claimed sum, as
+/// the shared statement assigns them. Verify recomputes the list from that statement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ComponentWire {
+    pub name: String,
+    pub log_n_rows: u32,
+    pub claimed_sum: Qm31Wire,
+    /// The component's shape, as `component_metadata` records it for single-component examples.
+    /// Absent on Zig-generated artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ComponentMetadataWire>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct InteropArtifact {
+    pub schema_version: u32,
+    pub upstream_commit: String,
+    pub exchange_mode: String,
+    pub generator: String,
+    pub example: String,
+    pub prove_mode: Option<String>,
+    pub pcs_config: PcsConfigWire,
+    pub blake_statement: Option<BlakeStatementWire>,
+    pub plonk_statement: Option<PlonkStatementWire>,
+    pub poseidon_statement: Option<PoseidonStatementWire>,
+    pub state_machine_statement: Option<StateMachineStatementWire>,
+    pub wide_fibonacci_statement: Option<WideFibonacciStatementWire>,
+    pub xor_statement: Option<XorStatementWire>,
+    /// Decoded length of the proof bytes (compressed, if `compression` is set); when present,
+    /// verify checks the encoded field against it before decoding. Absent on legacy artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_bytes_len: Option<usize>,
+    /// Hex BLAKE3 digest of the proof wire bytes after decompression, so a compressed and an
+    /// uncompressed artifact of one proof share it; verify checks it before decoding the proof
+    /// wire, so a corrupted transfer fails as a checksum mismatch rather than deep in the
+    /// verifier. Absent on legacy and Zig-generated artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_bytes_blake3: Option<String>,
+    /// Hex of the JSON proof wire. Empty (and omitted) under `proof_exchange_bincode_v1`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub proof_bytes_hex: String,
+    /// Base64 of the bincode proof wire; set only under `proof_exchange_bincode_v1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_bytes_b64: Option<String>,
+    /// Compression of the proof bytes, `"none"` or `"zstd"`. Absent means `"none"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+    /// Outcome a fixture is expected to produce: `"ok"` or the verification stage it must fail
+    /// at (see `VERIFY_STAGES`). Absent on regular artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_verify_result: Option<String>,
+    /// Constraint count, degree bound and mask layout of the example component. Absent on legacy
+    /// and Zig-generated artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_metadata: Option<ComponentMetadataWire>,
+    /// Components of a multi-component example, in proving order; their shapes are recorded here
+    /// instead of in `component_metadata`. Absent for single-component examples.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ComponentWire>>,
+    /// Preprocessed columns the component samples, set by `--preprocessed-columns` on plonk and
+    /// xor; verify builds its component with the same list. Absent means the component's own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preprocessed_columns: Option<Vec<usize>>,
+    /// Corruption applied by `--mode tamper` (see `TAMPER_KINDS`). Absent on regular artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tamper_kind: Option<String>,
+    /// Error the Rust verifier reported for the tampered artifact, alongside the stage recorded
+    /// in `expected_verify_result`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tamper_observed_error: Option<String>,
+    /// Backend the Rust prover ran on (`"cpu"` or `"simd"`). Absent on legacy and Zig-generated
+    /// artifacts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prove_backend: Option<String>,
+    /// Merkle hash of the proof's commitments, `"blake2s"` or `"blake3"`. Absent means
+    /// `"blake2s"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// `--statement-seed` the statement parameters were drawn from. Absent when they came from
+    /// the example flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_seed: Option<u64>,
+    /// Version of the Rust generator that wrote the artifact. Always present from schema v2 on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generator_version: Option<String>,
+}
+
+/// The one field [`parse_artifact`] reads before choosing how to read the rest.
+#[derive(Debug, Deserialize)]
+struct ArtifactSchemaProbe {
+    schema_version: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchTiming {
+    warmups: usize,
+    repeats: usize,
+    samples_seconds: Vec<f64>,
+    min_seconds: f64,
+    max_seconds: f64,
+    avg_seconds: f64,
+    /// Percentiles interpolate linearly between the two nearest ranks of the sorted samples.
+    median_seconds: f64,
+    p90_seconds: f64,
+    p99_seconds: f64,
+    /// Sample standard deviation; zero for a single sample.
+    stddev_seconds: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchProofMetrics {
+    proof_wire_bytes: usize,
+    commitments_count: usize,
+    decommitments_count: usize,
+    trace_decommit_hashes: usize,
+    fri_inner_layers_count: usize,
+    fri_first_layer_witness_len: usize,
+    fri_last_layer_poly_len: usize,
+    fri_decommit_hashes_total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchProfiling {
+    profile_dir: String,
+    frequency_hz: i32,
+    prove: BenchTiming,
+    verify: BenchTiming,
+    prove_overhead_ratio: f64,
+    verify_overhead_ratio: f64,
+    overhead_bound: f64,
+    /// Whether both ratios stayed under `overhead_bound` in this run. Wall-clock ratios are noisy
+    /// at small sizes, so this is reported for the bench reader rather than gated on.
+    within_overhead_bound: bool,
+    files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchReport {
+    runtime: String,
+    example: String,
+    prove_mode: String,
+    prove_backend: String,
+    include_all_preprocessed_columns: bool,
+    pcs_config: PcsConfigWire,
+    /// Wall time of the whole bench run: warmups, samples, the baseline proof and profiling.
+    total_wall_seconds: f64,
+    prove: BenchTiming,
+    /// Mean seconds per `PROVE_PHASES` phase over the sampled prove runs.
+    prove_phases: BTreeMap<&'static str, f64>,
+    verify: BenchTiming,
+    /// Peak RSS over the prove loop and over the verify loop, warmups included: a warmup that
+    /// allocates more than the sampled runs raises the peak. `None` where it cannot be read.
+    prove_peak_rss_bytes: Option<u64>,
+    verify_peak_rss_bytes: Option<u64>,
+    /// Set when the peak could not be reset between the loops, so the verify peak also covers
+    /// the prove loop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_rss_note: Option<String>,
+    proof_metrics: BenchProofMetrics,
+    profiling: Option<BenchProfiling>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchScalePoint {
+    value: u32,
+    /// Process-wide peak RSS (`VmHWM`) after this size ran; monotone across points, so growth
+    /// between points is what the size added. `None` where `/proc` is unavailable.
+    peak_rss_kib: Option<u64>,
+    report: BenchReport,
+}
+
+/// Least-squares slope of log2(metric) against the size parameter, which is itself a log2 size,
+/// so 1.0 means linear scaling. `None` with fewer than two points.
+#[derive(Debug, Clone, Serialize)]
+struct BenchScaleFit {
+    prove_slope: Option<f64>,
+    verify_slope: Option<f64>,
+    proof_wire_bytes_slope: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchScaleReport {
+    runtime: String,
+    example: String,
+    param: String,
+    range: [u32; 2],
+    planned_values: Vec<u32>,
+    budget_seconds: Option<f64>,
+    budget_exhausted: bool,
+    points: Vec<BenchScalePoint>,
+    /// Sizes rejected by an `ImplementationCap` limit; they are left out of `points` and the fit.
+    skipped: Vec<BenchScaleSkip>,
+    fit: BenchScaleFit,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchScaleSkip {
+    value: u32,
+    status: String,
+    error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchCompareRatio {
+    metric: String,
+    baseline: f64,
+    candidate: f64,
+    /// `candidate / baseline`; below 1.0 is an improvement.
+    ratio: f64,
+    max_ratio: Option<f64>,
+    /// `ok`, `exceeded`, or `unchecked` when no threshold was given.
+    status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BenchCompareReport {
+    mode: String,
+    example: String,
+    baseline: String,
+    candidate: String,
+    status: String,
+    ratios: Vec<BenchCompareRatio>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StageNode {
+    id: String,
+    label: String,
+    seconds: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    children: Option<Vec<StageNode>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StageProfile {
+    schema_version: u32,
+    runtime: String,
+    example: String,
+    stages: Vec<StageNode>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GenerateTimings {
+    example: String,
+    total_seconds: f64,
+    stages: BTreeMap<&'static str, f64>,
+}
+
+/// Wall-clock stage recorder shared by the provers and generate mode: each `lap` closes the stage
+/// that ran since the previous lap (or since creation). Stage ids match the Zig
+/// `stage_profile` recorder so the two profiles line up.
+struct StageClock {
+    last: std::time::Instant,
+    stages: Vec<StageNode>,
+}
+
+impl StageClock {
+    fn new() -> Self {
+        Self {
+            last: std::time::Instant::now(),
+            stages: Vec::new(),
+        }
+    }
+
+    fn lap(&mut self, id: &str, label: &str) {
+        let now = std::time::Instant::now();
+        self.stages.push(StageNode {
+            id: id.to_string(),
+            label: label.to_string(),
+            seconds: now.duration_since(self.last).as_secs_f64(),
+            children: None,
+        });
+        self.last = now;
+    }
+
+    /// Runs upstream `prove` as the `core_prove` stage, with the time its composition, FRI and
+    /// PoW spans were entered recorded as children.
+    fn core_prove<T>(&mut self, prove: impl FnOnce() -> Result<T>) -> Result<T> {
+        let totals = Arc::new(Mutex::new(BTreeMap::new()));
+        let subscriber = tracing_subscriber::registry().with(ProvePhaseLayer {
+            totals: Arc::clone(&totals),
+        });
+        let result = tracing::subscriber::with_default(subscriber, prove);
+        let now = std::time::Instant::now();
+        let totals = totals
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let children = CORE_PROVE_PHASES
+            .iter()
+            .map(|(id, label)| StageNode {
+                id: id.to_string(),
+                label: label.to_string(),
+                seconds: totals.get(id).copied().unwrap_or(0.0),
+                children: None,
+            })
+            .collect();
+        self.stages.push(StageNode {
+            id: "core_prove".to_string(),
+            label: "Core prove".to_string(),
+            seconds: now.duration_since(self.last).as_secs_f64(),
+            children: Some(children),
+        });
+        self.last = now;
+        result
+    }
+}
+
+thread_local! {
+    /// Spans entered on this thread under a `ProvePhaseLayer`, innermost last, with their phase.
+    static PROVE_SPAN_STACK: RefCell<Vec<(Option<&'static str>, std::time::Instant)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Sums the time upstream `prove` spends in its composition, FRI and PoW tracing spans. Only the
+/// outermost classified span is counted, so nested spans are not double-counted.
+struct ProvePhaseLayer {
+    totals: Arc<Mutex<BTreeMap<&'static str, f64>>>,
+}
+
+impl<S> tracing_subscriber::Layer<S> for ProvePhaseLayer
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_enter(&self, id: &tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let phase = ctx.metadata(id).and_then(core_prove_phase);
+        PROVE_SPAN_STACK.with(|stack| stack.borrow_mut().push((phase, std::time::Instant::now())));
+    }
+
+    fn on_exit(&self, _id: &tracing::span::Id, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+        PROVE_SPAN_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if let Some((Some(phase), entered)) = stack.pop() {
+                if stack.iter().all(|(outer, _)| outer.is_none()) {
+                    let mut totals = self
+                        .totals
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    *totals.entry(phase).or_default() += entered.elapsed().as_secs_f64();
+                }
+            }
+        });
+    }
+}
+
+/// `CORE_PROVE_PHASES` id of an upstream span, by its name or module; `None` for the rest of
+/// `prove`.
+fn core_prove_phase(meta: &tracing::Metadata<'_>) -> Option<&'static str> {
+    let name = meta.name().to_ascii_lowercase();
+    let module = meta.module_path().unwrap_or_default().to_ascii_lowercase();
+    if name.contains("proof of work") || name.contains("grind") || module.contains("proof_of_work")
+    {
+        Some("proof_of_work")
+    } else if name.contains("fri") || module.contains("fri") {
+        Some("fri")
+    } else if name.contains("composition") || name.contains("constraint") {
+        Some("composition")
+    } else {
+        None
+    }
+}
+
+/// Twiddle trees by backend and by the log size of the domain they were precomputed for. The
+/// provers take their tree from here, so a batch of proofs over one domain size precomputes it
+/// once.
+#[derive(Default)]
+struct TwiddleCache {
+    cpu: BTreeMap<u32, TwiddleTree<CpuBackend>>,
+    simd: BTreeMap<u32, TwiddleTree<SimdBackend>>,
+    /// Time spent precomputing the trees.
+    precompute_seconds: f64,
+}
+
+impl TwiddleCache {
+    fn get<B: InteropBackend>(&mut self, log_size: u32) -> &TwiddleTree<B> {
+        if !B::twiddle_trees(self).contains_key(&log_size) {
+            let start = std::time::Instant::now();
+            let tree =
+                B::precompute_twiddles(CanonicCoset::new(log_size).circle_domain().half_coset);
+            self.precompute_seconds += start.elapsed().as_secs_f64();
+            B::twiddle_trees(self).insert(log_size, tree);
+        }
+        &B::twiddle_trees(self)[&log_size]
+    }
+
+    fn len(&self) -> usize {
+        self.cpu.len() + self.simd.len()
+    }
+}
+
+/// The per-backend pieces of the generic provers: where their twiddles are cached, how a trace
+/// column becomes a backend evaluation, and how the constant composition is accumulated.
+trait InteropBackend: Backend {
+    /// Smallest column log size the backend can hold.
+    const MIN_LOG_SIZE: u32;
+
+    fn twiddle_trees(cache: &mut TwiddleCache) -> &mut BTreeMap<u32, TwiddleTree<Self>>;
+
+    fn eval(
+        eval: CpuCircleEvaluation<M31, BitReversedOrder>,
+    ) -> CircleEvaluation<Self, M31, BitReversedOrder>;
+
+    /// Adds `value` to every row of the composition column of log size `log_size`.
+    fn accumulate_constant(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        value: SecureField,
+    );
+
+    /// Adds one value per row, in bit-reversed order, to the composition column of log size
+    /// `log_size`. `rows` receives the random coefficient powers of the `n_constraints`
+    /// constraints, highest first, so constraint `i` pairs with power `i` as it does in
+    /// `PointEvaluationAccumulator`.
+    fn accumulate_rows(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        n_constraints: usize,
+        rows: impl FnOnce(&[SecureField]) -> Vec<SecureField>,
+    );
+}
+
+impl InteropBackend for CpuBackend {
+    const MIN_LOG_SIZE: u32 = 0;
+
+    fn twiddle_trees(cache: &mut TwiddleCache) -> &mut BTreeMap<u32, TwiddleTree<Self>> {
+        &mut cache.cpu
+    }
+
+    fn eval(
+        eval: CpuCircleEvaluation<M31, BitReversedOrder>,
+    ) -> CircleEvaluation<Self, M31, BitReversedOrder> {
+        eval
+    }
+
+    fn accumulate_constant(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        value: SecureField,
+    ) {
+        let [mut col] = accumulator.columns([(log_size, 1)]);
+        for i in 0..1usize << log_size {
+            col.accumulate(i, value);
+        }
+    }
+
+    fn accumulate_rows(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        n_constraints: usize,
+        rows: impl FnOnce(&[SecureField]) -> Vec<SecureField>,
+    ) {
+        let [mut col] = accumulator.columns([(log_size, n_constraints)]);
+        col.random_coeff_powers.reverse();
+        for (row, value) in rows(&col.random_coeff_powers).into_iter().enumerate() {
+            col.accumulate(row, value);
+        }
+    }
+}
+
+impl InteropBackend for SimdBackend {
+    const MIN_LOG_SIZE: u32 = LOG_N_LANES;
+
+    fn twiddle_trees(cache: &mut TwiddleCache) -> &mut BTreeMap<u32, TwiddleTree<Self>> {
+        &mut cache.simd
+    }
+
+    fn eval(
+        eval: CpuCircleEvaluation<M31, BitReversedOrder>,
+    ) -> CircleEvaluation<Self, M31, BitReversedOrder> {
+        CircleEvaluation::new(eval.domain, eval.values.into_iter().collect())
+    }
+
+    fn accumulate_constant(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        value: SecureField,
+    ) {
+        let [mut col] = accumulator.columns([(log_size, 1)]);
+        let value = PackedSecureField::broadcast(value);
+        for vec_row in 0..1usize << (log_size - LOG_N_LANES) {
+            // SAFETY: the column holds `1 << log_size` values, so `vec_row` is in range.
+            unsafe { col.accumulate(vec_row, value) };
+        }
+    }
+
+    fn accumulate_rows(
+        accumulator: &mut DomainEvaluationAccumulator<Self>,
+        log_size: u32,
+        n_constraints: usize,
+        rows: impl FnOnce(&[SecureField]) -> Vec<SecureField>,
+    ) {
+        let [mut col] = accumulator.columns([(log_size, n_constraints)]);
+        col.random_coeff_powers.reverse();
+        let values = rows(&col.random_coeff_powers);
+        for (vec_row, lanes) in values.chunks_exact(N_LANES).enumerate() {
+            let packed = PackedSecureField::from_array(lanes.try_into().expect("N_LANES values"));
+            // SAFETY: `values` holds one value per row of the column, so `vec_row` is in range.
+            unsafe { col.accumulate(vec_row, packed) };
+        }
+    }
+}
+
+/// Merkle channel named by an artifact's `hash`, and how its hashes map to the 32-byte wire
+/// encoding.
+trait InteropMerkle: MerkleChannel<C = Blake2sChannel> {
+    fn hash_to_wire(hash: &WireHash<Self>) -> [u8; 32];
+
+    fn hash_from_wire(bytes: [u8; 32]) -> WireHash<Self>;
+}
+
+type WireHash<MC> = <<MC as MerkleChannel>::H as MerkleHasherLifted>::Hash;
+
+impl InteropMerkle for Blake2sMerkleChannel {
+    fn hash_to_wire(hash: &Blake2sHash) -> [u8; 32] {
+        hash.0
+    }
+
+    fn hash_from_wire(bytes: [u8; 32]) -> Blake2sHash {
+        Blake2sHash(bytes)
+    }
+}
+
+impl InteropMerkle for Blake3MerkleChannel {
+    fn hash_to_wire(hash: &Blake3Hash) -> [u8; 32] {
+        hash.as_ref()
+            .try_into()
+            .expect("blake3 hash should be 32 bytes")
+    }
+
+    fn hash_from_wire(bytes: [u8; 32]) -> Blake3Hash {
+        Blake3Hash::from(bytes.as_slice())
+    }
+}
+
+/// A decoded proof under the Merkle hash its artifact names.
+enum HashedProof {
+    Blake2s(StarkProof<Blake2sMerkleHasher>),
+    Blake3(StarkProof<Blake3MerkleHasher>),
+}
+
+/// Rejects trace columns too small for backend `B`.
+fn check_backend_log_size<B: InteropBackend>(log_size: u32) -> Result<()> {
+    if log_size < B::MIN_LOG_SIZE {
+        bail!(
+            "log size {log_size} is below the backend minimum of {}",
+            B::MIN_LOG_SIZE
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GoldenHashes {
+    schema_version: u32,
+    upstream_commit: String,
+    hash: String,
+    entries: BTreeMap<String, GoldenEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GoldenEntry {
+    proof_wire_blake2s: String,
+    reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GoldenFixtureReport {
+    slug: String,
+    expected: Option<String>,
+    actual: String,
+    status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct GoldenReport {
+    mode: String,
+    status: String,
+    golden_file: String,
+    fixtures: Vec<GoldenFixtureReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct MicroSuiteCase {
+    example: String,
+    status: String,
+    artifact_bytes: usize,
+    seconds: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct MicroSuiteReport {
+    mode: String,
+    preset: String,
+    status: String,
+    passed: usize,
+    /// Examples whose preset parameters hit an `ImplementationCap` limit.
+    skipped: usize,
+    failed: usize,
+    cases: Vec<MicroSuiteCase>,
+}
+
+/// `--mode batch-generate` input. Entries are parsed one at a time so a malformed entry fails
+/// alone.
+#[derive(Debug, Clone, Deserialize)]
+struct BatchManifest {
+    entries: Vec<serde_json::Value>,
+}
+
+/// One artifact to generate. `output` is relative to the working directory; a missing
+/// `pcs_config` or `prove_mode` takes the batch's own flags.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchEntry {
+    statement: BatchStatement,
+    output: String,
+    #[serde(default)]
+    pcs_config: Option<PcsConfigWire>,
+    #[serde(default)]
+    prove_mode: Option<String>,
+}
+
+/// A [`Statement`] as written in a batch manifest: `example` plus the variant's fields.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(tag = "example", rename_all = "snake_case", deny_unknown_fields)]
+enum BatchStatement {
+    Blake {
+        log_n_rows: u32,
+        n_rounds: u32,
+    },
+    Plonk {
+        log_n_rows: u32,
+    },
+    Poseidon {
+        log_n_instances: u32,
+    },
+    StateMachine {
+        log_n_rows: u32,
+        initial_state: [u32; 2],
+    },
+    StateMachinePair {
+        log_n_rows: u32,
+        initial_state: [u32; 2],
+    },
+    WideFibonacci {
+        log_n_rows: u32,
+        sequence_len: u32,
+    },
+    Xor {
+        log_size: u32,
+        log_step: u32,
+        offset: usize,
+    },
+}
+
+impl From<BatchStatement> for Statement {
+    fn from(statement: BatchStatement) -> Self {
+        match statement {
+            BatchStatement::Blake {
+                log_n_rows,
+                n_rounds,
+            } => Self::Blake {
+                log_n_rows,
+                n_rounds,
+            },
+            BatchStatement::Plonk { log_n_rows } => Self::Plonk { log_n_rows },
+            BatchStatement::Poseidon { log_n_instances } => Self::Poseidon { log_n_instances },
+            BatchStatement::StateMachine {
+                log_n_rows,
+                initial_state,
+            } => Self::StateMachine {
+                log_n_rows,
+                initial_state,
+            },
+            BatchStatement::StateMachinePair {
+                log_n_rows,
+                initial_state,
+            } => Self::StateMachinePair {
+                log_n_rows,
+                initial_state,
+            },
+            BatchStatement::WideFibonacci {
+                log_n_rows,
+                sequence_len,
+            } => Self::WideFibonacci {
+                log_n_rows,
+                sequence_len,
+            },
+            BatchStatement::Xor {
+                log_size,
+                log_step,
+                offset,
+            } => Self::Xor {
+                log_size,
+                log_step,
+                offset,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchEntryReport {
+    index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<String>,
+    status: String,
+    seconds: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_bytes_len: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stages: Option<BTreeMap<&'static str, f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchGenerateReport {
+    mode: String,
+    manifest: String,
+    status: String,
+    generated: usize,
+    failed: usize,
+    /// Distinct domain sizes whose twiddles were precomputed; every other entry reused a tree.
+    twiddle_trees_computed: usize,
+    seconds: f64,
+    entries: Vec<BatchEntryReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchVerifyEntry {
+    artifact: String,
+    /// `ok` when the outcome matches `expected_verify_result` (`ok` when the artifact has none).
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_verify_result: Option<String>,
+    /// Verify stage that rejected the artifact.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    verify_seconds: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_bytes_len: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchVerifyReport {
+    mode: String,
+    artifact_dir: String,
+    status: String,
+    jobs: usize,
+    total: usize,
+    passed: usize,
+    failed: usize,
+    /// Passing artifacts that were rejected, as their `expected_verify_result` says they must be.
+    expected_rejections: usize,
+    seconds: f64,
+    artifacts: Vec<BatchVerifyEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RoundtripReport {
+    mode: String,
+    example: String,
+    prove_mode: String,
+    status: String,
+    wire_bytes: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    canonical_mismatch: Option<CanonicalMismatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    total_seconds: f64,
+    phases: Vec<StageNode>,
+}
+
+/// Where the re-serialized proof wire first departs from the original serialization. `offset` is
+/// the shorter length when one is a prefix of the other.
+#[derive(Debug, Clone, Serialize)]
+struct CanonicalMismatch {
+    offset: usize,
+    serialized_len: usize,
+    reserialized_len: usize,
+}
+
+/// Sidecar `<name>.note.json` of a fuzz regression input; `outcome` is `"ok"` or `"error"`.
+#[derive(Debug, Clone, Serialize)]
+struct FuzzRegressionNote {
+    target: String,
+    source: String,
+    size: usize,
+    blake2s: String,
+    outcome: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AddRegressionReport {
+    mode: String,
+    status: String,
+    path: String,
+    note: FuzzRegressionNote,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct VerifyReport {
+    pub status: String,
+    pub example: String,
+    pub generator: String,
+    pub pow_bits: u32,
+    pub proof_of_work: u64,
+    pub pow_check: String,
+    /// The artifact's `statement_seed`, echoed so a failing seed can be replayed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_seed: Option<u64>,
+    #[serde(flatten)]
+    pub expectation: Option<VerifyExpectation>,
+}
+
+/// Actual vs expected outcome, reported when the artifact carries `expected_verify_result`.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct VerifyExpectation {
+    pub actual_verify_result: String,
+    pub expected_verify_result: String,
+    pub matches_expected: bool,
+}
+
+/// One-line result of verify mode under `--expect`: `status` is `pass` when the outcome matched
+/// the expectation. `error_kind` is the verify stage that failed (or `panic`) and `message` the
+/// error, both `None` when verification succeeded.
+#[derive(Debug, Clone, Serialize)]
+struct ExpectReport {
+    status: String,
+    error_kind: Option<String>,
+    message: Option<String>,
+}
+
+/// One-line result of a failed verify mode: `status` is `fail`, `kind` classifies the failure
+/// and `error` is its message. The expectation fields are present when the artifact declares
+/// `expected_verify_result`.
+#[derive(Debug, Clone, Serialize)]
+struct VerifyFailureReport {
+    status: String,
+    kind: InteropError,
+    error: String,
+    #[serde(flatten)]
+    expectation: Option<VerifyExpectation>,
+}
+
+/// Artifacts (file names) of one example sharing a PCS config. `results[i][j]` is the proof of
+/// artifact `i` verified against the statement of artifact `j`: `"ok"` or the verify stage it
+/// failed at (`"panic"` if the verifier panicked). Cells with `same_statement` (the diagonal, or
+/// artifacts that happen to carry equal statements) must verify; every other cell must fail.
+#[derive(Debug, Clone, Serialize)]
+struct CrossVerifyGroup {
+    example: String,
+    pcs_config: PcsConfigWire,
+    artifacts: Vec<String>,
+    results: Vec<Vec<String>>,
+    same_statement: Vec<Vec<bool>>,
+    diagonal_failures: Vec<String>,
+    critical_findings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CrossVerifyReport {
+    status: String,
+    artifacts_dir: String,
+    skipped_negative_fixtures: Vec<String>,
+    groups: Vec<CrossVerifyGroup>,
+}
+
+/// Outcome of `--mode conform`, in precedence order: a statement the Rust prover cannot
+/// reproduce outranks a rejected Rust proof, which outranks a rejected Zig proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ConformVerdict {
+    BothValid,
+    ZigInvalid,
+    RustInvalid,
+    StatementIncompatible,
+}
+
+/// One proof commitment (tree root) of each side, hex-encoded.
+#[derive(Debug, Clone, Serialize)]
+struct ConformCommitment {
+    tree: usize,
+    zig: String,
+    rust: String,
+    matches: bool,
+}
+
+/// Serialized size of one top-level proof wire field. `zig_bytes` is `None` when the Zig proof
+/// does not decode.
+#[derive(Debug, Clone, Serialize)]
+struct ConformSectionSize {
+    section: String,
+    zig_bytes: Option<usize>,
+    rust_bytes: usize,
+}
+
+/// A transcript-visible value compared across the two proofs. Shape checks are always
+/// `required`; values derived from the commitments are only required to match when every
+/// commitment does, since the provers are deterministic given the trace.
+#[derive(Debug, Clone, Serialize)]
+struct ConformCheck {
+    name: String,
+    matches: bool,
+    required: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ConformReport {
+    mode: String,
+    artifact: String,
+    example: String,
+    generator: String,
+    pcs_config: PcsConfigWire,
+    zig_verify_result: String,
+    rust_verify_result: Option<String>,
+    commitments: Vec<ConformCommitment>,
+    /// First tree whose commitment differs; everything committed after it differs as a
+    /// consequence, so this is the tree whose trace diverged.
+    first_divergent_tree: Option<usize>,
+    section_sizes: Vec<ConformSectionSize>,
+    checks: Vec<ConformCheck>,
+    required_mismatches: Vec<String>,
+    /// Why the Rust prover could not reproduce the statement; set with `statement_incompatible`.
+    incompatibility: Option<String>,
+    verdict: ConformVerdict,
+}
+
+/// Value counts of one commitment tree: sampled (OODS) values, queried values and Merkle witness
+/// hashes. Trees are indexed as in the proof, so a missing tail shows up as absent rows.
+#[derive(Debug, Clone, Serialize)]
+struct InspectTree {
+    tree: usize,
+    commitment: String,
+    sampled_columns: usize,
+    sampled_values: usize,
+    queried_columns: usize,
+    queried_values: usize,
+    decommitment_hashes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct InspectFriLayer {
+    fri_witness_len: usize,
+    decommitment_hashes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct InspectFri {
+    first_layer: InspectFriLayer,
+    inner_layers: Vec<InspectFriLayer>,
+    last_layer_poly_len: usize,
+}
+
+/// Upstream `StarkProof::size_breakdown_estimate`, in bytes.
+#[derive(Debug, Clone, Serialize)]
+struct InspectSizeBreakdown {
+    oods_samples: usize,
+    queries_values: usize,
+    fri_samples: usize,
+    fri_decommitments: usize,
+    trace_decommitments: usize,
+}
+
+/// `--mode inspect` output. Everything is read off the decoded proof wire without verifying;
+/// `size_breakdown_estimate` needs the wire to convert into a proof and is `None` (with
+/// `proof_convert_error` set) when it does not.
+#[derive(Debug, Clone, Serialize)]
+struct InspectReport {
+    mode: String,
+    artifact: String,
+    schema_version: u32,
+    exchange_mode: String,
+    compression: String,
+    generator: String,
+    example: String,
+    prove_mode: Option<String>,
+    statement: serde_json::Value,
+    pcs_config: PcsConfigWire,
+    proof_bytes_len: usize,
+    commitments_count: usize,
+    trees: Vec<InspectTree>,
+    fri: InspectFri,
+    proof_of_work: u64,
+    proof_metrics: BenchProofMetrics,
+    size_breakdown_estimate: Option<InspectSizeBreakdown>,
+    proof_convert_error: Option<String>,
+}
+
+/// Sizes of one proof in `--mode size-report`, in bytes: upstream's
+/// `StarkProof::size_breakdown_estimate`, the decoded proof wire, and the artifact JSON that
+/// carries it. Totals sum them and averages (`T = f64`) divide the totals by the artifact count.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+struct ProofSizes<T> {
+    oods_samples: T,
+    queries_values: T,
+    fri_samples: T,
+    fri_decommitments: T,
+    trace_decommitments: T,
+    wire_bytes: T,
+    artifact_bytes: T,
+}
+
+impl ProofSizes<usize> {
+    fn add(&mut self, other: &Self) {
+        self.oods_samples += other.oods_samples;
+        self.queries_values += other.queries_values;
+        self.fri_samples += other.fri_samples;
+        self.fri_decommitments += other.fri_decommitments;
+        self.trace_decommitments += other.trace_decommitments;
+        self.wire_bytes += other.wire_bytes;
+        self.artifact_bytes += other.artifact_bytes;
+    }
+
+    fn average(&self, count: usize) -> ProofSizes<f64> {
+        let average = |total: usize| total as f64 / count as f64;
+        ProofSizes {
+            oods_samples: average(self.oods_samples),
+            queries_values: average(self.queries_values),
+            fri_samples: average(self.fri_samples),
+            fri_decommitments: average(self.fri_decommitments),
+            trace_decommitments: average(self.trace_decommitments),
+            wire_bytes: average(self.wire_bytes),
+            artifact_bytes: average(self.artifact_bytes),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SizeReportArtifact {
+    artifact: String,
+    example: String,
+    #[serde(flatten)]
+    sizes: ProofSizes<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SizeReportExample {
+    example: String,
+    artifacts: usize,
+    totals: ProofSizes<usize>,
+    averages: ProofSizes<f64>,
+}
+
+/// `--mode size-report` output: one row per artifact, then totals and averages per example and
+/// totals across the corpus. Negative fixtures in a directory are skipped, as in cross-verify.
+#[derive(Debug, Clone, Serialize)]
+struct SizeReport {
+    mode: String,
+    input: String,
+    artifacts: Vec<SizeReportArtifact>,
+    skipped_negative_fixtures: Vec<String>,
+    examples: Vec<SizeReportExample>,
+    totals: ProofSizes<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TamperReport {
+    mode: String,
+    artifact: String,
+    example: String,
+    tamper_kind: String,
+    expected_verify_result: String,
+    tamper_observed_error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReproIntermediate {
+    stage: String,
+    name: String,
+    value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReproErrorReport {
+    status: String,
+    failed_stage: String,
+    error: String,
+    error_chain: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReproBundleFile {
+    name: String,
+    size: usize,
+    blake2s: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReproBundleManifest {
+    bundle_version: u32,
+    upstream_commit: String,
+    failed_stage: String,
+    error: String,
+    max_array_items: usize,
+    files: Vec<ReproBundleFile>,
+}
+
+/// The artifact as the `metadata` stage records it: every field but the proof bytes, which are
+/// replaced by their encoded lengths. Borrows the artifact, so it costs nothing unless the trace
+/// is capturing.
+#[derive(Serialize)]
+struct ArtifactTraceMetadata<'a> {
+    schema_version: u32,
+    upstream_commit: &'a str,
+    exchange_mode: &'a str,
+    generator: &'a str,
+    example: &'a str,
+    prove_mode: &'a Option<String>,
+    pcs_config: &'a PcsConfigWire,
+    blake_statement: &'a Option<BlakeStatementWire>,
+    plonk_statement: &'a Option<PlonkStatementWire>,
+    poseidon_statement: &'a Option<PoseidonStatementWire>,
+    state_machine_statement: &'a Option<StateMachineStatementWire>,
+    wide_fibonacci_statement: &'a Option<WideFibonacciStatementWire>,
+    xor_statement: &'a Option<XorStatementWire>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_bytes_len: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_bytes_blake3: Option<&'a str>,
+    proof_bytes_hex_len: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_bytes_b64_len: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compression: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_verify_result: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component_metadata: Option<&'a ComponentMetadataWire>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<&'a [ComponentWire]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preprocessed_columns: Option<&'a [usize]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tamper_kind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tamper_observed_error: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prove_backend: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statement_seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generator_version: Option<&'a str>,
+}
+
+impl<'a> ArtifactTraceMetadata<'a> {
+    fn new(artifact: &'a InteropArtifact) -> Self {
+        Self {
+            schema_version: artifact.schema_version,
+            upstream_commit: &artifact.upstream_commit,
+            exchange_mode: &artifact.exchange_mode,
+            generator: &artifact.generator,
+            example: &artifact.example,
+            prove_mode: &artifact.prove_mode,
+            pcs_config: &artifact.pcs_config,
+            blake_statement: &artifact.blake_statement,
+            plonk_statement: &artifact.plonk_statement,
+            poseidon_statement: &artifact.poseidon_statement,
+            state_machine_statement: &artifact.state_machine_statement,
+            wide_fibonacci_statement: &artifact.wide_fibonacci_statement,
+            xor_statement: &artifact.xor_statement,
+            proof_bytes_len: artifact.proof_bytes_len,
+            proof_bytes_blake3: artifact.proof_bytes_blake3.as_deref(),
+            proof_bytes_hex_len: artifact.proof_bytes_hex.len(),
+            proof_bytes_b64_len: artifact.proof_bytes_b64.as_ref().map(String::len),
+            compression: artifact.compression.as_deref(),
+            expected_verify_result: artifact.expected_verify_result.as_deref(),
+            component_metadata: artifact.component_metadata.as_ref(),
+            components: artifact.components.as_deref(),
+            preprocessed_columns: artifact.preprocessed_columns.as_deref(),
+            tamper_kind: artifact.tamper_kind.as_deref(),
+            tamper_observed_error: artifact.tamper_observed_error.as_deref(),
+            prove_backend: artifact.prove_backend.as_deref(),
+            hash: artifact.hash.as_deref(),
+            statement_seed: artifact.statement_seed,
+            generator_version: artifact.generator_version.as_deref(),
+        }
+    }
+}
+
+/// Records the stage the staged verification path is in and, when capturing, the intermediates
+/// it has produced so far. On failure `stage` names the stage that failed.
+struct VerifyTrace {
+    capture: bool,
+    stage: &'static str,
+    intermediates: Vec<ReproIntermediate>,
+    transcript: Transcript,
+}
+
+impl VerifyTrace {
+    fn disabled() -> Self {
+        Self {
+            capture: false,
+            stage: "read",
+            intermediates: Vec::new(),
+            transcript: Transcript::disabled(),
+        }
+    }
+
+    fn capturing() -> Self {
+        Self {
+            capture: true,
+            ..Self::disabled()
+        }
+    }
+
+    fn enter(&mut self, stage: &'static str) {
+        self.stage = stage;
+    }
+
+    fn record<T: Serialize>(&mut self, name: &str, value: &T) -> Result<()> {
+        if !self.capture {
+            return Ok(());
+        }
+        self.intermediates.push(ReproIntermediate {
+            stage: self.stage.to_string(),
+            name: name.to_string(),
+            value: bounded_json(serde_json::to_value(value)?)?,
+        });
+        Ok(())
+    }
+}
+
+/// One channel interaction of a prove or verify run and the channel digest right after it; one
+/// line of a `--dump-transcript` file.
+#[derive(Debug, Clone, Serialize)]
+struct TranscriptStep {
+    index: usize,
+    step: String,
+    digest: String,
+}
+
+/// Records the channel interactions of an example driver for `--dump-transcript`. The prover and
+/// the verifier of an example record the same steps under the same names, so two dumps of one
+/// proof agree step by step up to the first transcript divergence. A disabled recorder ignores
+/// every call, so the drivers record unconditionally.
+struct Transcript {
+    steps: Option<Vec<TranscriptStep>>,
+}
+
+impl Transcript {
+    fn disabled() -> Self {
+        Self { steps: None }
+    }
+
+    fn recording() -> Self {
+        Self {
+            steps: Some(Vec::new()),
+        }
+    }
+
+    fn record(&mut self, step: &str, channel: &Blake2sChannel) {
+        if let Some(steps) = &mut self.steps {
+            steps.push(TranscriptStep {
+                index: steps.len(),
+                step: step.to_string(),
+                digest: hex::encode(channel.digest().0),
+            });
+        }
+    }
+
+    /// Records the steps `prove` and `verify` take on the channel after the driver hands it over
+    /// in state `channel`. They all follow from `proof`, so they are replayed on a copy of the
+    /// channel in the verifier's order: composition commitment, OODS point, sampled values, FRI
+    /// layers, proof of work and query draw.
+    fn record_core<MC: InteropMerkle>(
+        &mut self,
+        channel: &Blake2sChannel,
+        config: PcsConfig,
+        proof: &StarkProof<MC::H>,
+    ) {
+        if self.steps.is_none() {
+            return;
+        }
+        let pcs_proof = &proof.0;
+        let mut channel = channel.clone();
+        channel.draw_secure_felt();
+        self.record("composition_random_coeff_draw", &channel);
+        let Some(&composition_commitment) = pcs_proof.commitments.last() else {
+            return;
+        };
+        MC::mix_root(&mut channel, composition_commitment);
+        self.record("composition_commit", &channel);
+        CirclePoint::<SecureField>::get_random_point(&mut channel);
+        self.record("oods_point_draw", &channel);
+
+        let sampled_values = pcs_proof
+            .sampled_values
+            .iter()
+            .flatten()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        channel.mix_felts(&sampled_values);
+        self.record("sampled_values_mix", &channel);
+        channel.draw_secure_felt();
+        self.record("quotient_random_coeff_draw", &channel);
+
+        let fri_proof = &pcs_proof.fri_proof;
+        let layers = std::iter::once(&fri_proof.first_layer).chain(&fri_proof.inner_layers);
+        for (layer, layer_proof) in layers.enumerate() {
+            MC::mix_root(&mut channel, layer_proof.commitment);
+            self.record(&format!("fri_layer_{layer}_commit"), &channel);
+            channel.draw_secure_felt();
+            self.record(&format!("fri_layer_{layer}_alpha_draw"), &channel);
+        }
+        let last_layer_poly = fri_proof
+            .last_layer_poly
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        channel.mix_felts(&last_layer_poly);
+        self.record("fri_last_layer_mix", &channel);
+
+        channel.mix_u64(pcs_proof.proof_of_work);
+        self.record("proof_of_work_mix", &channel);
+        // Each draw yields eight query words.
+        for _ in 0..config.fri_config.n_queries.div_ceil(8) {
+            channel.draw_u32s();
+        }
+        self.record("queries_draw", &channel);
+    }
+
+    /// Writes the recorded steps to `path` as JSON lines.
+    fn write(&self, path: &str) -> Result<()> {
+        let mut out = String::new();
+        for step in self.steps.iter().flatten() {
+            out.push_str(&serde_json::to_string(step)?);
+            out.push('\n');
+        }
+        fs::write(path, out).with_context(|| format!("failed writing transcript {path}"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PowZeroBitsConvention {
+    pow_check: String,
+    required_nonce: u64,
+    nonce_mixed_into_channel: bool,
+    rejection: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Capabilities {
+    schema_version: u32,
+    upstream_commit: String,
+    exchange_mode: String,
+    exchange_modes: Vec<String>,
+    proof_compressions: Vec<String>,
+    examples: Vec<String>,
+    prove_modes: Vec<String>,
+    export_compressions: Vec<String>,
+    pow_zero_bits_convention: PowZeroBitsConvention,
+    limits: Vec<ParamLimit>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExportManifest {
+    path: String,
+    compression: String,
+    uncompressed_size: usize,
+    uncompressed_blake2s: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ExampleStatement {
+    Blake(BlakeStatement),
+    Plonk(PlonkStatement),
+    Poseidon(PoseidonStatement),
+    StateMachine(StateMachineStatement),
+    StateMachinePair(StateMachineStatement),
+    WideFibonacci(WideFibonacciStatement),
+    Xor(XorStatement),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StateMachineElements {
+    z: SecureField,
+    alpha: SecureField,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StateMachineStatement {
+    public_input: [[M31; 2]; 2],
+    stmt0_n: u32,
+    stmt0_m: u32,
+    stmt1_x_axis_claimed_sum: SecureField,
+    stmt1_y_axis_claimed_sum: SecureField,
+    /// Whether the proof commits the logup interaction tree. Verify checks the proof's commitment
+    /// count against it.
+    with_interaction: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct XorStatement {
+    log_size: u32,
+    log_step: u32,
+    offset: usize,
+    /// Set only by the CLI's `--preprocessed-columns`, and checked against the number of
+    /// committed preprocessed columns.
+    preprocessed_columns: Option<PreprocessedColumns>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WideFibonacciStatement {
+    log_n_rows: u32,
+    sequence_len: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PlonkStatement {
+    log_n_rows: u32,
+    /// Set only by the CLI's `--preprocessed-columns`, and checked against the number of
+    /// committed preprocessed columns.
+    preprocessed_columns: Option<PreprocessedColumns>,
+}
+
+/// A subset of an example's committed preprocessed columns, as a bit set over column indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PreprocessedColumns(u64);
+
+impl PreprocessedColumns {
+    /// Checks `columns` against the preprocessed tree of `example`: strictly increasing, all
+    /// committed, and covering every column its constraints read.
+    fn select(example: Example, columns: &[usize]) -> Result<Self> {
+        let (committed, read): (usize, &[usize]) = match example {
+            Example::Plonk => (PLONK_PREPROCESSED_COLUMNS, &[PLONK_OP_COLUMN]),
+            Example::Xor => (XOR_PREPROCESSED_COLUMNS, &[XOR_IS_STEP_COLUMN]),
+            other => bail!(
+                "preprocessed columns are only supported for the plonk and xor examples, not {}",
+                example_to_str(other)
+            ),
+        };
+        let name = example_to_str(example);
+        if columns.windows(2).any(|pair| pair[0] >= pair[1]) {
+            bail!("{PREPROCESSED_COLUMNS_MISMATCH}: {name} preprocessed columns {columns:?} are not strictly increasing");
+        }
+        if let Some(column) = columns.iter().find(|&&column| column >= committed) {
+            bail!("{PREPROCESSED_COLUMNS_MISMATCH}: {name} commits {committed} preprocessed columns, so column {column} does not exist");
+        }
+        if let Some(column) = read.iter().find(|&&column| !columns.contains(&column)) {
+            bail!("{PREPROCESSED_COLUMNS_MISMATCH}: {name} preprocessed columns {columns:?} omit column {column}, which the component's constraints read");
+        }
+        Ok(Self(
+            columns.iter().fold(0, |bits, &column| bits | (1 << column)),
+        ))
+    }
+
+    fn indices(self) -> Vec<usize> {
+        (0..u64::BITS as usize)
+            .filter(|&column| (self.0 >> column) & 1 == 1)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PoseidonStatement {
+    log_n_instances: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BlakeStatement {
+    log_n_rows: u32,
+    n_rounds: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StateMachineComponent {
+    trace_log_size: u32,
+    /// Preprocessed column holding this component's `is_first`; the pair commits one per
+    /// component.
+    is_first_column: usize,
+    composition_eval: SecureField,
+    /// Logup constraints over the interaction tree; without it or `transition` the component
+    /// accumulates `composition_eval`.
+    interaction: Option<StateMachineInteraction>,
+    /// Transition constraints over the component's own main columns; the pair sets it, and
+    /// `composition_eval` then only records the axis claimed sum.
+    transition: Option<StateMachineTransition>,
+}
+
+/// Where one state-machine pair component's two main columns start in the main tree, which
+/// coordinate it steps and the state its first row holds.
+#[derive(Debug, Clone, Copy)]
+struct StateMachineTransition {
+    first_column: usize,
+    inc_index: usize,
+    initial_state: [M31; 2],
+}
+
+/// Lookup elements and x-axis claimed sum the state-machine interaction tree is checked against.
+#[derive(Debug, Clone, Copy)]
+struct StateMachineInteraction {
+    elements: StateMachineElements,
+    claimed_sum: SecureField,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct XorComponent {
+    statement: XorStatement,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct WideFibonacciComponent {
+    statement: WideFibonacciStatement,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PlonkComponent {
+    statement: PlonkStatement,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PoseidonComponent {
+    statement: PoseidonStatement,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BlakeComponent {
+    statement: BlakeStatement,
+}
+
+/// Public inputs of an example proof, as taken by [`generate_artifact`]. Field meanings and
+/// accepted ranges match the binary's `--<example>-*` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Statement {
+    Blake {
+        log_n_rows: u32,
+        n_rounds: u32,
+    },
+    Plonk {
+        log_n_rows: u32,
+    },
+    Poseidon {
+        log_n_instances: u32,
+    },
+    StateMachine {
+        log_n_rows: u32,
+        initial_state: [u32; 2],
+    },
+    StateMachinePair {
+        log_n_rows: u32,
+        initial_state: [u32; 2],
+    },
+    WideFibonacci {
+        log_n_rows: u32,
+        sequence_len: u32,
+    },
+    Xor {
+        log_size: u32,
+        log_step: u32,
+        offset: usize,
+    },
+}
+
+impl Statement {
+    pub fn example(&self) -> Example {
+        match self {
+            Self::Blake { .. } => Example::Blake,
+            Self::Plonk { .. } => Example::Plonk,
+            Self::Poseidon { .. } => Example::Poseidon,
+            Self::StateMachine { .. } => Example::StateMachine,
+            Self::StateMachinePair { .. } => Example::StateMachinePair,
+            Self::WideFibonacci { .. } => Example::WideFibonacci,
+            Self::Xor { .. } => Example::Xor,
+        }
+    }
+}
+
+/// Prover options for [`generate_artifact`]; `Default` matches the binary's defaults.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct GenerateOptions {
+    pub prove_mode: ProveMode,
+    /// Commit every preprocessed column rather than only the ones the example reads.
+    pub include_all_preprocessed_columns: bool,
+    pub proof_encoding: ProofEncoding,
+    pub proof_compression: ProofCompression,
+    pub backend: ProveBackend,
+    pub hash: ProofHash,
+    pub schema: ArtifactSchema,
+    /// Commit the logup interaction tree of the state-machine example and constrain its claimed
+    /// sum. Other examples reject it.
+    pub with_interaction: bool,
+    /// Preprocessed columns the plonk or xor component samples under `prove_ex`, in place of its
+    /// own `preprocessed_column_indices`: a strictly increasing subset of the committed columns
+    /// that covers every column the constraints read.
+    pub preprocessed_columns: Option<Vec<usize>>,
+    /// Edits the main trace before it is committed, so tests can prove a trace that breaks the
+    /// constraints. Only the plonk, state-machine pair, wide fibonacci and xor examples take it.
+    #[doc(hidden)]
+    pub trace_perturbation: Option<TracePerturbation>,
+}
+
+/// An edit of the generated main trace. Either must change the trace.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracePerturbation {
+    /// Overwrites one cell: `row` indexes the column as generated, before it is interpolated.
+    Cell {
+        column: usize,
+        row: usize,
+        value: u32,
+    },
+    /// Exchanges two columns of the same size.
+    SwapColumns { columns: [usize; 2] },
+}
+
+/// Options for [`verify_artifact`]. There are none yet; the struct exists so options can be
+/// added without breaking callers.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct VerifyOptions {}
+
+/// Exit status of the `stwo-interop-rs` binary for an error returned by [`run_cli`]: the
+/// [`InteropError::exit_code`] of a failed verify, 1 otherwise.
+#[doc(hidden)]
+pub fn cli_exit_code(err: &anyhow::Error) -> u8 {
+    err.downcast_ref::<InteropError>()
+        .map_or(1, |kind| kind.exit_code())
+}
+
+/// Entry point of the `stwo-interop-rs` binary. Not part of the supported library surface.
+#[doc(hidden)]
+pub fn run_cli(args: Vec<String>) -> Result<()> {
+    let cli = parse_cli(args)?;
+    if cli.stage_profile_out.is_some() && cli.mode != Mode::Generate {
+        bail!("--stage-profile-out is only supported for generate mode");
+    }
+    if cli.timings_out.is_some() && cli.mode != Mode::Generate {
+        bail!("--timings-out is only supported for generate mode");
+    }
+    if cli.repro_bundle.is_some() && cli.mode != Mode::Verify {
+        bail!("--repro-bundle is only supported for verify mode");
+    }
+    if cli.dump_transcript.is_some() && !matches!(cli.mode, Mode::Generate | Mode::Verify) {
+        bail!("--dump-transcript is only supported for generate and verify modes");
+    }
+    if (cli.expect.is_some() || cli.expect_error.is_some()) && cli.mode != Mode::Verify {
+        bail!("--expect and --expect-error are only supported for verify mode");
+    }
+    if cli.expect_error.is_some() && cli.expect == Some(VerifyExpect::Success) {
+        bail!("--expect-error requires --expect failure");
+    }
+    // `--mode size-report --example` proves a fresh artifact, so it takes the generate flags too.
+    let generates =
+        cli.mode == Mode::Generate || (cli.mode == Mode::SizeReport && cli.example.is_some());
+    if cli.proof_encoding != ProofEncoding::Json && !generates {
+        bail!("--proof-encoding is only supported for generate mode");
+    }
+    if cli.proof_compression != ProofCompression::None && !generates {
+        bail!("--compress is only supported for generate mode");
+    }
+    if cli.hash != ProofHash::Blake2s && !generates {
+        bail!("--hash is only supported for generate mode");
+    }
+    if cli.statement_seed.is_some() && !generates {
+        bail!("--statement-seed is only supported for generate mode");
+    }
+    if cli.schema != ArtifactSchema::V2 && !generates {
+        bail!("--schema is only supported for generate mode");
+    }
+    if cli.with_interaction && !generates {
+        bail!("--with-interaction is only supported for generate mode");
+    }
+    if cli.preprocessed_columns.is_some() && !generates {
+        bail!("--preprocessed-columns is only supported for generate mode");
+    }
+    if cli.artifact == STDIO_PATH && cli.mode == Mode::Bench {
+        bail!(
+            "--artifact - is not supported for bench mode: the bench report is written to stdout"
+        );
+    }
+    if cli.bench_out.is_some() && cli.mode != Mode::Bench {
+        bail!("--bench-out is only supported for bench mode");
+    }
+    if cli.bench_format != BenchFormat::Json && cli.mode != Mode::Bench {
+        bail!("--bench-format is only supported for bench mode");
+    }
+    if cli.mode != Mode::BenchCompare
+        && (cli.baseline.is_some()
+            || cli.candidate.is_some()
+            || cli.max_prove_ratio.is_some()
+            || cli.max_verify_ratio.is_some()
+            || cli.max_proof_bytes_ratio.is_some()
+            || cli.max_peak_rss_ratio.is_some())
+    {
+        bail!(
+            "--baseline, --candidate and --max-*-ratio are only supported for bench-compare mode"
+        );
+    }
+    if cli.profile_out.is_some() {
+        if cli.mode != Mode::Bench {
+            bail!("--profile-out is only supported for bench mode");
+        }
+        if !cfg!(feature = "profiling") {
+            bail!("--profile-out requires building with --features profiling");
+        }
+    }
+    if cli.artifacts_dir.is_some() != (cli.mode == Mode::CrossVerify) {
+        bail!("--artifacts is required for, and only supported in, cross-verify mode");
+    }
+    if cli.fuzz_target.is_some() != (cli.mode == Mode::AddRegression) {
+        bail!("--fuzz-target is required for, and only supported in, add-regression mode");
+    }
+    if cli.tamper_kind.is_some() != (cli.mode == Mode::Tamper) {
+        bail!("--tamper-kind is required for, and only supported in, tamper mode");
+    }
+    if cli.manifest.is_some() != (cli.mode == Mode::BatchGenerate) {
+        bail!("--manifest is required for, and only supported in, batch-generate mode");
+    }
+    if cli.artifact_dir.is_some() != (cli.mode == Mode::BatchVerify) {
+        bail!("--artifact-dir is required for, and only supported in, batch-verify mode");
+    }
+    if (cli.jobs.is_some() || cli.keep_going) && cli.mode != Mode::BatchVerify {
+        bail!("--jobs and --keep-going are only supported for batch-verify mode");
+    }
+    if cli.jobs == Some(0) {
+        bail!("--jobs must be at least 1");
+    }
+    if cli.mode != Mode::BenchScale
+        && (cli.scale_param.is_some() || cli.scale_points.is_some() || cli.budget_seconds.is_some())
+    {
+        bail!("--scale-param, --scale-points and --budget-seconds are only supported for bench-scale mode");
+    }
+    match cli.mode {
+        Mode::Generate => run_generate(&cli),
+        Mode::Verify => run_verify(&cli),
+        Mode::Bench => run_bench(&cli),
+        Mode::BenchScale => run_bench_scale(&cli),
+        Mode::GoldenCheck => run_golden_check(&cli),
+        Mode::GoldenUpdate => run_golden_update(&cli),
+        Mode::Capabilities => run_capabilities(),
+        Mode::MicroSuite => run_micro_suite(&cli),
+        Mode::CrossVerify => run_cross_verify(&cli),
+        Mode::AddRegression => run_add_regression(&cli),
+        Mode::Conform => run_conform(&cli),
+        Mode::Inspect => run_inspect(&cli),
+        Mode::Tamper => run_tamper(&cli),
+        Mode::BatchGenerate => run_batch_generate(&cli),
+        Mode::BatchVerify => run_batch_verify(&cli),
+        Mode::Roundtrip => run_roundtrip(&cli),
+        Mode::BenchCompare => run_bench_compare(&cli),
+        Mode::SizeReport => run_size_report(&cli),
+    }
+}
+
+fn write_stage_profile(
+    path: &str,
+    stages: Vec<StageNode>,
+    compression: ExportCompression,
+) -> Result<()> {
+    let profile = StageProfile {
+        schema_version: 1,
+        runtime: "rust".to_string(),
+        example: "wide_fibonacci".to_string(),
+        stages,
+    };
+    write_export(
+        path,
+        serde_json::to_string_pretty(&profile)?.as_bytes(),
+        compression,
+    )
+    .with_context(|| format!("failed writing stage profile {path}"))?;
+    Ok(())
+}
+
+fn export_compression_to_str(compression: ExportCompression) -> &'static str {
+    match compression {
+        ExportCompression::None => "none",
+        ExportCompression::Gzip => "gzip",
+        ExportCompression::Zstd => "zstd",
+    }
+}
+
+fn export_compression_from_str(value: &str) -> Option<ExportCompression> {
+    match value {
+        "none" => Some(ExportCompression::None),
+        "gzip" => Some(ExportCompression::Gzip),
+        "zstd" => Some(ExportCompression::Zstd),
+        _ => None,
+    }
+}
+
+/// Writes a debug export (stage profiles and other intermediates, never the artifact itself)
+/// with the requested compression, plus a `<path>.manifest.json` sidecar recording the
+/// compression, the uncompressed size, and the Blake2s hash of the uncompressed bytes.
+fn write_export(path: &str, data: &[u8], compression: ExportCompression) -> Result<()> {
+    let encoded = match compression {
+        ExportCompression::None => data.to_vec(),
+        ExportCompression::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()?
+        }
+        ExportCompression::Zstd => zstd::stream::encode_all(data, ZSTD_LEVEL)?,
+    };
+    fs::write(path, encoded).with_context(|| format!("failed writing export {path}"))?;
+
+    let manifest = ExportManifest {
+        path: path.to_string(),
+        compression: export_compression_to_str(compression).to_string(),
+        uncompressed_size: data.len(),
+        uncompressed_blake2s: hex::encode(Blake2sHasher::hash(data).0),
+    };
+    let manifest_path = format!("{path}.manifest.json");
+    fs::write(
+        &manifest_path,
+        format!("{}\n", serde_json::to_string_pretty(&manifest)?),
+    )
+    .with_context(|| format!("failed writing export manifest {manifest_path}"))?;
+    Ok(())
+}
+
+/// Reads a debug export written by `--export-compress` (a stage profile or other intermediate)
+/// whichever of plain, gzip or zstd it was written as, sniffing the magic bytes. Artifacts are
+/// never compressed this way and are read as they are.
+pub fn read_export(path: &str) -> Result<Vec<u8>> {
+    let raw = read_input(path)?;
+    decode_export_bytes(raw).with_context(|| format!("failed decompressing {path}"))
+}
+
+/// Reads `path`, or all of stdin when it is `STDIO_PATH`.
+fn read_input(path: &str) -> Result<Vec<u8>> {
+    if path == STDIO_PATH {
+        let mut raw = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut raw)
+            .context("failed reading stdin")?;
+        return Ok(raw);
+    }
+    fs::read(path).with_context(|| format!("failed reading {path}"))
+}
+
+/// Writes `contents` to `path`, or to stdout when it is `STDIO_PATH`.
+fn write_output(path: &str, contents: &str) -> Result<()> {
+    if path == STDIO_PATH {
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(contents.as_bytes())?;
+        return Ok(stdout.flush()?);
+    }
+    fs::write(path, contents).with_context(|| format!("failed writing {path}"))
+}
+
+fn decode_export_bytes(raw: Vec<u8>) -> Result<Vec<u8>> {
+    if raw.starts_with(&GZIP_MAGIC) {
+        return read_bounded(
+            flate2::read::GzDecoder::new(raw.as_slice()),
+            MAX_EXPORT_BYTES,
+        );
+    }
+    if raw.starts_with(&ZSTD_MAGIC) {
+        return read_bounded(
+            zstd::stream::read::Decoder::new(raw.as_slice())?,
+            MAX_EXPORT_BYTES,
+        );
+    }
+    Ok(raw)
+}
+
+/// Reads a decompressing `reader` to the end, failing as soon as it yields more than `cap` bytes
+/// rather than inflating the whole stream first.
+fn read_bounded(reader: impl Read, cap: u64) -> Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+    reader.take(cap + 1).read_to_end(&mut decoded)?;
+    if decoded.len() as u64 > cap {
+        bail!("{DECOMPRESSED_SIZE_EXCEEDED}: stream decompresses past {cap} bytes");
+    }
+    Ok(decoded)
+}
+
+fn run_generate(cli: &Cli) -> Result<()> {
+    let example = cli
+        .example
+        .ok_or_else(|| anyhow!("--example is required for generate mode"))?;
+    if cli.stage_profile_out.is_some() && example != Example::WideFibonacci {
+        bail!("--stage-profile-out is only supported for wide_fibonacci generate runs");
+    }
+
+    let mut clock = StageClock::new();
+    let mut transcript = if cli.dump_transcript.is_some() {
+        Transcript::recording()
+    } else {
+        Transcript::disabled()
+    };
+    let artifact = build_artifact(cli, example, &mut clock, &mut transcript)?;
+    if let Some(path) = &cli.dump_transcript {
+        transcript.write(path)?;
+    }
+    let rendered = serde_json::to_string_pretty(&artifact)?;
+    write_output(&cli.artifact, &format!("{rendered}\n"))
+        .with_context(|| format!("failed writing artifact {}", cli.artifact))?;
+    clock.lap("artifact_write", "Artifact write");
+
+    let timings = generate_timings(example, &clock.stages);
+    let rendered_timings = serde_json::to_string(&timings)?;
+    match &cli.timings_out {
+        Some(path) => fs::write(path, format!("{rendered_timings}\n"))
+            .with_context(|| format!("failed writing timings {path}"))?,
+        None => eprintln!("{rendered_timings}"),
+    }
+    if let Some(stage_profile_out) = &cli.stage_profile_out {
+        // The stage profile keeps the top-level ids shared with the Zig recorder; hex encoding has
+        // no Zig counterpart.
+        let stages = clock
+            .stages
+            .into_iter()
+            .filter(|stage| stage.id != "proof_hex_encode")
+            .collect();
+        write_stage_profile(stage_profile_out, stages, cli.export_compress)?;
+    }
+    Ok(())
+}
+
+/// Folds clock stages into the `GENERATE_TIMING_STAGES` summary; every summary stage is present,
+/// at zero if nothing was recorded for it.
+fn generate_timings(example: Example, stages: &[StageNode]) -> GenerateTimings {
+    let stage_seconds = GENERATE_TIMING_STAGES
+        .iter()
+        .map(|(name, ids)| {
+            let seconds: f64 = stages
+                .iter()
+                .filter(|stage| ids.contains(&stage.id.as_str()))
+                .map(|stage| stage.seconds)
+                .sum();
+            (*name, seconds)
+        })
+        .collect();
+    GenerateTimings {
+        example: example_to_str(example).to_string(),
+        total_seconds: stages.iter().map(|stage| stage.seconds).sum(),
+        stages: stage_seconds,
+    }
+}
+
+/// Statement of `example` with the CLI parameters.
+fn statement_from_cli(cli: &Cli, example: Example) -> Statement {
+    match example {
+        Example::Blake => Statement::Blake {
+            log_n_rows: cli.blake_log_n_rows,
+            n_rounds: cli.blake_n_rounds,
+        },
+        Example::Plonk => Statement::Plonk {
+            log_n_rows: cli.plonk_log_n_rows,
+        },
+        Example::Poseidon => Statement::Poseidon {
+            log_n_instances: cli.poseidon_log_n_instances,
+        },
+        Example::StateMachine => Statement::StateMachine {
+            log_n_rows: cli.sm_log_n_rows,
+            initial_state: [cli.sm_initial_0, cli.sm_initial_1],
+        },
+        Example::StateMachinePair => Statement::StateMachinePair {
+            log_n_rows: cli.sm_pair_log_n_rows,
+            initial_state: [cli.sm_initial_0, cli.sm_initial_1],
+        },
+        Example::WideFibonacci => Statement::WideFibonacci {
+            log_n_rows: cli.wf_log_n_rows,
+            sequence_len: cli.wf_sequence_len,
+        },
+        Example::Xor => Statement::Xor {
+            log_size: cli.xor_log_size,
+            log_step: cli.xor_log_step,
+            offset: cli.xor_offset,
+        },
+    }
+}
+
+/// Proves `example` with the CLI parameters and wraps the proof in an in-memory artifact.
+fn build_artifact(
+    cli: &Cli,
+    example: Example,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<InteropArtifact> {
+    let statement = match cli.statement_seed {
+        Some(seed) => seeded_statement(example, seed),
+        None => statement_from_cli(cli, example),
+    };
+    let options = GenerateOptions {
+        prove_mode: cli.prove_mode,
+        include_all_preprocessed_columns: cli.include_all_preprocessed_columns,
+        proof_encoding: cli.proof_encoding,
+        proof_compression: cli.proof_compression,
+        backend: cli.backend,
+        hash: cli.hash,
+        schema: cli.schema,
+        with_interaction: cli.with_interaction,
+        preprocessed_columns: cli.preprocessed_columns.clone(),
+        trace_perturbation: None,
+    };
+    let mut artifact = generate_artifact_timed(
+        example,
+        statement,
+        pcs_config_from_cli(cli)?,
+        &options,
+        &mut TwiddleCache::default(),
+        clock,
+        transcript,
+    )?;
+    artifact.statement_seed = cli.statement_seed;
+    Ok(artifact)
+}
+
+/// Statement of `example` with every parameter drawn from a xorshift stream seeded by `seed`.
+/// Log sizes stay in `SEEDED_LOG_SIZES`, which every backend can hold and which proves in well
+/// under a second; the other parameters cover their valid range, up to `SEEDED_MAX_*`.
+fn seeded_statement(example: Example, seed: u64) -> Statement {
+    let mut rng = StatementRng::new(seed);
+    match example {
+        Example::Blake => Statement::Blake {
+            log_n_rows: rng.log_size(),
+            n_rounds: 1 + rng.below(SEEDED_MAX_BLAKE_ROUNDS) as u32,
+        },
+        Example::Plonk => Statement::Plonk {
+            log_n_rows: rng.log_size(),
+        },
+        Example::Poseidon => Statement::Poseidon {
+            log_n_instances: rng.log_size() + POSEIDON_LOG_INSTANCES_PER_ROW,
+        },
+        Example::StateMachine => Statement::StateMachine {
+            log_n_rows: rng.log_size(),
+            initial_state: [rng.below(P.into()) as u32, rng.below(P.into()) as u32],
+        },
+        // The drawn size is the y-axis component's; the x-axis one is twice as large.
+        Example::StateMachinePair => Statement::StateMachinePair {
+            log_n_rows: rng.log_size() + 1,
+            initial_state: [rng.below(P.into()) as u32, rng.below(P.into()) as u32],
+        },
+        Example::WideFibonacci => Statement::WideFibonacci {
+            log_n_rows: rng.log_size(),
+            sequence_len: 2 + rng.below(SEEDED_MAX_SEQUENCE_LEN - 1) as u32,
+        },
+        Example::Xor => {
+            let log_size = rng.log_size();
+            let log_step = rng.below(u64::from(log_size) + 1) as u32;
+            Statement::Xor {
+                log_size,
+                log_step,
+                offset: rng.below(1 << log_size) as usize,
+            }
+        }
+    }
+}
+
+/// The vector generators' xorshift64* stream (`stwo_vector_common::next_u64`) behind
+/// `--statement-seed`.
+struct StatementRng(u64);
+
+impl StatementRng {
+    fn new(seed: u64) -> Self {
+        // Xorshift has a fixed point at zero, so the seed is offset before the first step, and
+        // the one seed the offset would zero takes the state seed 0 gets instead.
+        match seed ^ STATEMENT_SEED_OFFSET {
+            0 => Self(STATEMENT_SEED_OFFSET),
+            state => Self(state),
+        }
+    }
+
+    /// Uniform in `0..bound`: draws below `2^64 mod bound` are rejected so that every residue is
+    /// hit by the same number of outputs.
+    fn below(&mut self, bound: u64) -> u64 {
+        let rejected = bound.wrapping_neg() % bound;
+        loop {
+            let draw = next_u64(&mut self.0);
+            if draw >= rejected {
+                return draw % bound;
+            }
+        }
+    }
+
+    fn log_size(&mut self) -> u32 {
+        let (min, max) = SEEDED_LOG_SIZES;
+        min + self.below(u64::from(max - min + 1)) as u32
+    }
+}
+
+/// Proves `statement` with the Rust prover and wraps the proof in an in-memory artifact, exactly
+/// as `--mode generate` would write it. `example` must be the statement's example.
+pub fn generate_artifact(
+    example: Example,
+    statement: Statement,
+    config: PcsConfig,
+    options: &GenerateOptions,
+) -> Result<InteropArtifact> {
+    generate_artifact_timed(
+        example,
+        statement,
+        config,
+        options,
+        &mut TwiddleCache::default(),
+        &mut StageClock::new(),
+        &mut Transcript::disabled(),
+    )
+}
+
+fn generate_artifact_timed(
+    example: Example,
+    statement: Statement,
+    config: PcsConfig,
+    options: &GenerateOptions,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<InteropArtifact> {
+    if statement.example() != example {
+        bail!(
+            "statement is for {}, not {}",
+            example_to_str(statement.example()),
+            example_to_str(example)
+        );
+    }
+    if options.with_interaction && example != Example::StateMachine {
+        bail!("--with-interaction is only supported for the state_machine example");
+    }
+    if options.preprocessed_columns.is_some() {
+        if options.prove_mode != ProveMode::ProveEx {
+            bail!("--preprocessed-columns requires --prove-mode prove_ex");
+        }
+        if options.include_all_preprocessed_columns {
+            bail!("--preprocessed-columns conflicts with --include-all-preprocessed-columns 1");
+        }
+    }
+    if options.schema == ArtifactSchema::V1 {
+        if options.proof_encoding != ProofEncoding::Json {
+            bail!("--proof-encoding bincode requires --schema 2");
+        }
+        if options.proof_compression != ProofCompression::None {
+            bail!("--compress requires --schema 2");
+        }
+    }
+    let mut artifact = InteropArtifact {
+        schema_version: artifact_schema_version(options.schema),
+        upstream_commit: UPSTREAM_COMMIT.to_string(),
+        exchange_mode: proof_encoding_exchange_mode(options.proof_encoding).to_string(),
+        generator: "rust".to_string(),
+        example: example_to_str(example).to_string(),
+        prove_mode: Some(prove_mode_to_str(options.prove_mode).to_string()),
+        pcs_config: pcs_config_to_wire(config),
+        blake_statement: None,
+        plonk_statement: None,
+        poseidon_statement: None,
+        state_machine_statement: None,
+        wide_fibonacci_statement: None,
+        xor_statement: None,
+        proof_bytes_len: None,
+        proof_bytes_blake3: None,
+        proof_bytes_hex: String::new(),
+        proof_bytes_b64: None,
+        compression: None,
+        expected_verify_result: None,
+        component_metadata: None,
+        components: None,
+        preprocessed_columns: None,
+        tamper_kind: None,
+        tamper_observed_error: None,
+        prove_backend: Some(prove_backend_to_str(options.backend).to_string()),
+        hash: match options.hash {
+            ProofHash::Blake2s => None,
+            hash => Some(proof_hash_to_str(hash).to_string()),
+        },
+        statement_seed: None,
+        generator_version: Some(GENERATOR_VERSION.to_string()),
+    };
+    let (example_statement, proof_wire) =
+        prove_statement_wire(statement, config, options, twiddles, clock, transcript)?;
+    match example_statement {
+        ExampleStatement::Blake(statement) => {
+            artifact.blake_statement = Some(blake_statement_to_wire(statement))
+        }
+        ExampleStatement::Plonk(statement) => {
+            artifact.plonk_statement = Some(plonk_statement_to_wire(statement))
+        }
+        ExampleStatement::Poseidon(statement) => {
+            artifact.poseidon_statement = Some(poseidon_statement_to_wire(statement))
+        }
+        ExampleStatement::StateMachine(statement) => {
+            artifact.state_machine_statement = Some(state_machine_statement_to_wire(statement))
+        }
+        ExampleStatement::StateMachinePair(statement) => {
+            artifact.state_machine_statement = Some(state_machine_statement_to_wire(statement));
+            artifact.components = Some(state_machine_pair_component_wires(statement));
+        }
+        ExampleStatement::WideFibonacci(statement) => {
+            artifact.wide_fibonacci_statement = Some(wide_fibonacci_statement_to_wire(statement))
+        }
+        ExampleStatement::Xor(statement) => {
+            artifact.xor_statement = Some(xor_statement_to_wire(statement)?)
+        }
+    }
+    artifact.component_metadata = example_component_metadata(example_statement);
+    artifact.preprocessed_columns = example_preprocessed_columns(example_statement);
+    let mut proof_bytes = encode_proof_wire(&proof_wire, options.proof_encoding)?;
+    artifact.proof_bytes_blake3 = Some(proof_bytes_blake3(&proof_bytes));
+    if options.proof_compression == ProofCompression::Zstd {
+        proof_bytes = zstd::stream::encode_all(proof_bytes.as_slice(), ZSTD_LEVEL)?;
+        artifact.compression =
+            Some(proof_compression_to_str(options.proof_compression).to_string());
+    }
+    clock.lap("proof_wire_encode", "Proof wire encode");
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    match options.proof_encoding {
+        ProofEncoding::Json => artifact.proof_bytes_hex = hex::encode(proof_bytes),
+        ProofEncoding::Bincode => {
+            artifact.proof_bytes_b64 =
+                Some(base64::engine::general_purpose::STANDARD.encode(proof_bytes))
+        }
+    }
+    clock.lap("proof_hex_encode", "Proof hex encode");
+    if options.schema == ArtifactSchema::V1 {
+        strip_v2_fields(&mut artifact);
+    }
+    Ok(artifact)
+}
+
+/// Drops the fields schema v2 added, so a v1 artifact carries only what v1 readers know.
+fn strip_v2_fields(artifact: &mut InteropArtifact) {
+    artifact.proof_bytes_len = None;
+    artifact.proof_bytes_blake3 = None;
+    artifact.prove_backend = None;
+    artifact.generator_version = None;
+}
+
+fn proof_encoding_exchange_mode(encoding: ProofEncoding) -> &'static str {
+    match encoding {
+        ProofEncoding::Json => EXCHANGE_MODE,
+        ProofEncoding::Bincode => EXCHANGE_MODE_BINCODE,
+    }
+}
+
+fn proof_encoding_from_exchange_mode(exchange_mode: &str) -> Option<ProofEncoding> {
+    match exchange_mode {
+        EXCHANGE_MODE => Some(ProofEncoding::Json),
+        EXCHANGE_MODE_BINCODE => Some(ProofEncoding::Bincode),
+        _ => None,
+    }
+}
+
+fn proof_encoding_from_str(value: &str) -> Option<ProofEncoding> {
+    match value {
+        "json" => Some(ProofEncoding::Json),
+        "bincode" => Some(ProofEncoding::Bincode),
+        _ => None,
+    }
+}
+
+fn proof_compression_to_str(compression: ProofCompression) -> &'static str {
+    match compression {
+        ProofCompression::None => "none",
+        ProofCompression::Zstd => "zstd",
+    }
+}
+
+fn proof_compression_from_str(value: &str) -> Option<ProofCompression> {
+    match value {
+        "none" => Some(ProofCompression::None),
+        "zstd" => Some(ProofCompression::Zstd),
+        _ => None,
+    }
+}
+
+fn artifact_schema_version(schema: ArtifactSchema) -> u32 {
+    match schema {
+        ArtifactSchema::V1 => 1,
+        ArtifactSchema::V2 => 2,
+    }
+}
+
+fn artifact_schema_from_str(value: &str) -> Option<ArtifactSchema> {
+    match value {
+        "1" => Some(ArtifactSchema::V1),
+        "2" => Some(ArtifactSchema::V2),
+        _ => None,
+    }
+}
+
+/// Parses artifact JSON of any supported schema version into the current layout. The version is
+/// read first, so a newer artifact is rejected by version rather than by whatever field it added;
+/// a v1 artifact gets the values v1 left implicit (`compression` and `hash`) filled in.
+pub fn parse_artifact(raw: &[u8]) -> Result<InteropArtifact> {
+    let probe: ArtifactSchemaProbe = serde_json::from_slice(raw)?;
+    check_schema_version(probe.schema_version)?;
+    let mut artifact: InteropArtifact = serde_json::from_slice(raw)?;
+    if artifact.schema_version == 1 {
+        artifact
+            .compression
+            .get_or_insert_with(|| proof_compression_to_str(ProofCompression::None).to_string());
+        artifact
+            .hash
+            .get_or_insert_with(|| proof_hash_to_str(ProofHash::Blake2s).to_string());
+    }
+    Ok(artifact)
+}
+
+fn check_schema_version(version: u32) -> Result<()> {
+    if !(MIN_SCHEMA_VERSION..=SCHEMA_VERSION).contains(&version) {
+        bail!(
+            "unsupported schema version {version}: supported versions are {MIN_SCHEMA_VERSION} through {SCHEMA_VERSION}"
+        );
+    }
+    Ok(())
+}
+
+/// Schema checks shared by verify and inspect: a supported version, and from v2 on the fields
+/// that version made mandatory.
+fn check_artifact_schema(artifact: &InteropArtifact) -> Result<()> {
+    check_schema_version(artifact.schema_version)?;
+    if artifact.schema_version >= 2 && artifact.generator_version.is_none() {
+        bail!(
+            "schema version {} artifact is missing generator_version",
+            artifact.schema_version
+        );
+    }
+    Ok(())
+}
+
+/// Merkle hash named by an artifact's `hash` field; absence means Blake2s.
+fn artifact_proof_hash(artifact: &InteropArtifact) -> Result<ProofHash> {
+    match artifact.hash.as_deref() {
+        None => Ok(ProofHash::Blake2s),
+        Some(name) => proof_hash_from_str(name).ok_or_else(|| anyhow!("unsupported hash {name}")),
+    }
+}
+
+fn proof_hash_to_str(hash: ProofHash) -> &'static str {
+    match hash {
+        ProofHash::Blake2s => "blake2s",
+        ProofHash::Blake3 => "blake3",
+    }
+}
+
+fn proof_hash_from_str(value: &str) -> Option<ProofHash> {
+    match value {
+        "blake2s" => Some(ProofHash::Blake2s),
+        "blake3" => Some(ProofHash::Blake3),
+        _ => None,
+    }
+}
+
+/// Compression named by an artifact's `compression` field; absence means none.
+fn artifact_proof_compression(artifact: &InteropArtifact) -> Result<ProofCompression> {
+    match artifact.compression.as_deref() {
+        None => Ok(ProofCompression::None),
+        Some(name) => proof_compression_from_str(name)
+            .ok_or_else(|| anyhow!("unsupported proof compression {name}: expected none or zstd")),
+    }
+}
+
+/// Bincode configuration of `proof_exchange_bincode_v1`. Trailing bytes are rejected.
+fn proof_bincode_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_little_endian()
+}
+
+fn encode_proof_wire(wire: &ProofWire, encoding: ProofEncoding) -> Result<Vec<u8>> {
+    Ok(match encoding {
+        ProofEncoding::Json => serde_json::to_vec(wire)?,
+        ProofEncoding::Bincode => proof_bincode_options().serialize(wire)?,
+    })
+}
+
+fn decode_proof_wire_bytes(proof_bytes: &[u8], encoding: ProofEncoding) -> Result<ProofWire> {
+    Ok(match encoding {
+        ProofEncoding::Json => serde_json::from_slice(proof_bytes)?,
+        ProofEncoding::Bincode => proof_bincode_options().deserialize(proof_bytes)?,
+    })
+}
+
+/// Decoded proof bytes of `artifact`, read from the field its `exchange_mode` selects and
+/// decompressed as its `compression` field says.
+fn artifact_proof_bytes(artifact: &InteropArtifact) -> Result<(ProofEncoding, Vec<u8>)> {
+    let (encoding, proof_bytes) = artifact_stored_proof_bytes(artifact)?;
+    Ok((encoding, decompress_proof_bytes(artifact, proof_bytes)?))
+}
+
+/// Proof bytes of `artifact` as stored, before decompression: the bytes `proof_bytes_len`
+/// counts.
+fn artifact_stored_proof_bytes(artifact: &InteropArtifact) -> Result<(ProofEncoding, Vec<u8>)> {
+    let encoding = proof_encoding_from_exchange_mode(&artifact.exchange_mode)
+        .ok_or_else(|| anyhow!("unsupported exchange mode {}", artifact.exchange_mode))?;
+    let proof_bytes = match encoding {
+        ProofEncoding::Json => {
+            decode_proof_bytes(&artifact.proof_bytes_hex, artifact.proof_bytes_len)?
+        }
+        ProofEncoding::Bincode => {
+            let proof_bytes_b64 = artifact
+                .proof_bytes_b64
+                .as_deref()
+                .ok_or_else(|| anyhow!("missing proof_bytes_b64"))?;
+            decode_proof_bytes_b64(proof_bytes_b64, artifact.proof_bytes_len)?
+        }
+    };
+    Ok((encoding, proof_bytes))
+}
+
+fn decompress_proof_bytes(artifact: &InteropArtifact, proof_bytes: Vec<u8>) -> Result<Vec<u8>> {
+    Ok(match artifact_proof_compression(artifact)? {
+        ProofCompression::None => proof_bytes,
+        ProofCompression::Zstd => read_bounded(
+            zstd::stream::read::Decoder::new(proof_bytes.as_slice())?,
+            MAX_PROOF_BYTES,
+        )
+        .context("failed decompressing zstd proof bytes")?,
+    })
+}
+
+fn proof_bytes_blake3(proof_bytes: &[u8]) -> String {
+    hex::encode(Blake3Hasher::hash(proof_bytes).as_ref())
+}
+
+/// Compares decompressed proof bytes against the artifact's `proof_bytes_blake3`; artifacts
+/// without one (legacy and Zig-generated) pass unchecked.
+fn check_proof_bytes_checksum(artifact: &InteropArtifact, proof_bytes: &[u8]) -> Result<()> {
+    let Some(declared) = artifact.proof_bytes_blake3.as_deref() else {
+        return Ok(());
+    };
+    let actual = proof_bytes_blake3(proof_bytes);
+    if !declared.eq_ignore_ascii_case(&actual) {
+        bail!("{CHECKSUM_MISMATCH}: proof_bytes_blake3 declares {declared}, proof bytes hash to {actual}");
+    }
+    Ok(())
+}
+
+/// Decodes `proof_bytes_b64`, checking the result against a declared `proof_bytes_len`.
+fn decode_proof_bytes_b64(proof_bytes_b64: &str, declared_len: Option<usize>) -> Result<Vec<u8>> {
+    let proof_bytes = base64::engine::general_purpose::STANDARD.decode(proof_bytes_b64)?;
+    if let Some(declared_len) = declared_len {
+        if proof_bytes.len() != declared_len {
+            bail!(
+                "{PROOF_BYTES_LEN_MISMATCH}: proof_bytes_len declares {declared_len} bytes, proof_bytes_b64 encodes {}",
+                proof_bytes.len()
+            );
+        }
+    }
+    Ok(proof_bytes)
+}
+
+/// Decodes the proof wire of `artifact` in whichever encoding its `exchange_mode` names.
+pub fn artifact_proof_wire(artifact: &InteropArtifact) -> Result<ProofWire> {
+    let (encoding, proof_bytes) = artifact_proof_bytes(artifact)?;
+    decode_proof_wire_bytes(&proof_bytes, encoding)
+}
+
+fn run_verify(cli: &Cli) -> Result<()> {
+    let raw = read_input(&cli.artifact)
+        .with_context(|| format!("failed reading artifact {}", cli.artifact))?;
+    if let Some(expect) = cli.expect {
+        return run_verify_expect(
+            expect,
+            cli.expect_error.as_deref(),
+            cli.dump_transcript.as_deref(),
+            &raw,
+        );
+    }
+    let expected_verify_result = read_expected_verify_result(&raw)?;
+    let expectation = |actual: &str| {
+        expected_verify_result
+            .as_ref()
+            .map(|expected| VerifyExpectation {
+                actual_verify_result: actual.to_string(),
+                expected_verify_result: expected.clone(),
+                matches_expected: actual == expected,
+            })
+    };
+
+    let mut trace = if cli.repro_bundle.is_some() {
+        VerifyTrace::capturing()
+    } else {
+        VerifyTrace::disabled()
+    };
+    if cli.dump_transcript.is_some() {
+        trace.transcript = Transcript::recording();
+    }
+    let outcome = verify_artifact_bytes_traced(&raw, &mut trace);
+    // The transcript is written whatever the outcome; a failed verification is what it is for.
+    if let Some(path) = &cli.dump_transcript {
+        trace.transcript.write(path)?;
+    }
+    match outcome {
+        Ok(mut report) => {
+            report.expectation = expectation(VERIFY_RESULT_OK);
+            println!("{}", serde_json::to_string(&report)?);
+            Ok(())
+        }
+        Err(err) => {
+            // The exit status follows the actual outcome; whether it matched the fixture's
+            // expectation is for the caller (a gate) to judge from the report.
+            let kind = InteropError::from_verify_failure(trace.stage, &err);
+            let report = VerifyFailureReport {
+                status: "fail".to_string(),
+                kind,
+                error: format!("{err:#}"),
+                expectation: expectation(trace.stage),
+            };
+            println!("{}", serde_json::to_string(&report)?);
+            // The verification error stays the primary failure; a bundle write error is only
+            // reported alongside it.
+            if let Some(bundle_dir) = &cli.repro_bundle {
+                match write_repro_bundle(bundle_dir, cli, &raw, &trace, &err) {
+                    Ok(()) => eprintln!("repro bundle written to {bundle_dir}"),
+                    Err(bundle_err) => eprintln!("failed writing repro bundle: {bundle_err:#}"),
+                }
+            }
+            Err(err.context(kind))
+        }
+    }
+}
+
+/// Verify mode under `--expect`. A failure matches `--expect-error` when the pattern names its
+/// verify stage or occurs in its message; the comparison ignores case and anything that is not a
+/// letter or digit, so `RootMismatch` matches "Root mismatch". The process fails exactly when the
+/// report's status is `fail`.
+fn run_verify_expect(
+    expect: VerifyExpect,
+    expect_error: Option<&str>,
+    dump_transcript: Option<&str>,
+    raw: &[u8],
+) -> Result<()> {
+    let mut trace = VerifyTrace::disabled();
+    if dump_transcript.is_some() {
+        trace.transcript = Transcript::recording();
+    }
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        verify_artifact_bytes_traced(raw, &mut trace)
+    }));
+    if let Some(path) = dump_transcript {
+        trace.transcript.write(path)?;
+    }
+    let failure = match outcome {
+        Ok(Ok(_)) => None,
+        Ok(Err(err)) => Some((trace.stage.to_string(), format!("{err:#}"))),
+        Err(_) => Some(("panic".to_string(), "verifier panicked".to_string())),
+    };
+    let mismatch = match (expect, &failure, expect_error) {
+        (VerifyExpect::Success, None, _) | (VerifyExpect::Failure, Some(_), None) => None,
+        (VerifyExpect::Success, Some((kind, _)), _) => {
+            Some(format!("expected success, verification failed at {kind}"))
+        }
+        (VerifyExpect::Failure, None, _) => Some("expected failure, verification succeeded".into()),
+        (VerifyExpect::Failure, Some((kind, message)), Some(pattern)) => {
+            let pattern = normalize_error_pattern(pattern);
+            if normalize_error_pattern(kind) == pattern
+                || normalize_error_pattern(message).contains(&pattern)
+            {
+                None
+            } else {
+                Some(format!(
+                    "verification failed at {kind} without matching --expect-error"
+                ))
+            }
+        }
+    };
+    let (error_kind, message) = failure.unzip();
+    let report = ExpectReport {
+        status: if mismatch.is_none() { "pass" } else { "fail" }.to_string(),
+        error_kind,
+        message,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    match mismatch {
+        Some(mismatch) => bail!(mismatch),
+        None => Ok(()),
+    }
+}
+
+fn normalize_error_pattern(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|ch| ch.to_ascii_lowercase())
+        .collect()
+}
+
+/// Reads `expected_verify_result` without the full artifact schema, so the expectation is known
+/// even for fixtures that are meant to fail parsing. Unparseable input has no expectation.
+fn read_expected_verify_result(raw: &[u8]) -> Result<Option<String>> {
+    let Ok(value) = serde_json::from_slice::<serde_json::Value>(raw) else {
+        return Ok(None);
+    };
+    let Some(expected) = value
+        .get("expected_verify_result")
+        .filter(|expected| !expected.is_null())
+    else {
+        return Ok(None);
+    };
+    let expected = expected
+        .as_str()
+        .ok_or_else(|| anyhow!("expected_verify_result must be a string"))?;
+    if expected != VERIFY_RESULT_OK && !VERIFY_STAGES.contains(&expected) {
+        bail!(
+            "invalid expected_verify_result {expected}: expected {VERIFY_RESULT_OK} or one of {}",
+            VERIFY_STAGES.join(", ")
+        );
+    }
+    Ok(Some(expected.to_string()))
+}
+
+/// Decodes `proof_bytes_hex`. With a declared `proof_bytes_len` the hex length is checked first
+/// and the output is allocated once at the declared size; without it (legacy artifacts) the hex
+/// is decoded as-is.
+fn decode_proof_bytes(proof_bytes_hex: &str, declared_len: Option<usize>) -> Result<Vec<u8>> {
+    let Some(declared_len) = declared_len else {
+        return Ok(hex::decode(proof_bytes_hex)?);
+    };
+    if proof_bytes_hex.len() % 2 != 0 {
+        bail!(
+            "{PROOF_BYTES_LEN_MISMATCH}: proof_bytes_hex has odd length {}",
+            proof_bytes_hex.len()
+        );
+    }
+    if proof_bytes_hex.len() / 2 != declared_len {
+        bail!(
+            "{PROOF_BYTES_LEN_MISMATCH}: proof_bytes_len declares {declared_len} bytes, proof_bytes_hex encodes {}",
+            proof_bytes_hex.len() / 2
+        );
+    }
+    let mut proof_bytes = vec![0u8; declared_len];
+    hex::decode_to_slice(proof_bytes_hex, &mut proof_bytes)?;
+    Ok(proof_bytes)
+}
+
+/// Statement binding check: within each (example, PCS config) group of `--artifacts`, verifies
+/// every artifact's proof against every artifact's statement. Negative fixtures (artifacts that
+/// carry `expected_verify_result`) are skipped. Fails if a proof is rejected against its own
+/// statement or accepted against a different one.
+fn run_cross_verify(cli: &Cli) -> Result<()> {
+    let dir = cli
+        .artifacts_dir
+        .as_deref()
+        .ok_or_else(|| anyhow!("--artifacts is required"))?;
+    let mut paths = fs::read_dir(dir)
+        .with_context(|| format!("failed reading artifacts directory {dir}"))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    let mut skipped_negative_fixtures = Vec::new();
+    let mut groups = BTreeMap::<(String, String), Vec<(String, InteropArtifact)>>::new();
+    for path in paths {
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into(),
+        );
+        let raw = read_input(&path.display().to_string())
+            .with_context(|| format!("failed reading artifact {name}"))?;
+        let artifact =
+            parse_artifact(&raw).with_context(|| format!("failed parsing artifact {name}"))?;
+        if artifact.expected_verify_result.is_some() {
+            skipped_negative_fixtures.push(name);
+            continue;
+        }
+        let key = (
+            artifact.example.clone(),
+            serde_json::to_string(&artifact.pcs_config)?,
+        );
+        groups.entry(key).or_default().push((name, artifact));
+    }
+    if groups.is_empty() {
+        bail!("no artifacts to cross-verify in {dir}");
+    }
+
+    let mut report_groups = Vec::with_capacity(groups.len());
+    for ((example, _), members) in groups {
+        let mut results = Vec::with_capacity(members.len());
+        let mut same_statement = Vec::with_capacity(members.len());
+        let mut diagonal_failures = Vec::new();
+        let mut critical_findings = Vec::new();
+        for (proof_name, proof_artifact) in &members {
+            let mut result_row = Vec::with_capacity(members.len());
+            let mut same_row = Vec::with_capacity(members.len());
+            for (statement_name, statement_artifact) in &members {
+                let same = statement_json(proof_artifact)? == statement_json(statement_artifact)?;
+                let result = cross_verify_cell(proof_artifact, statement_artifact)?;
+                if same && result != VERIFY_RESULT_OK {
+                    diagonal_failures.push(format!(
+                        "{proof_name} failed against its own statement at {result}"
+                    ));
+                }
+                if !same && result == VERIFY_RESULT_OK {
+                    critical_findings.push(format!(
+                        "proof of {proof_name} verified against the statement of {statement_name}"
+                    ));
+                }
+                result_row.push(result);
+                same_row.push(same);
+            }
+            results.push(result_row);
+            same_statement.push(same_row);
+        }
+        report_groups.push(CrossVerifyGroup {
+            example,
+            pcs_config: members[0].1.pcs_config.clone(),
+            artifacts: members.iter().map(|(name, _)| name.clone()).collect(),
+            results,
+            same_statement,
+            diagonal_failures,
+            critical_findings,
+        });
+    }
+
+    let n_critical = report_groups
+        .iter()
+        .map(|group| group.critical_findings.len())
+        .sum::<usize>();
+    let n_diagonal = report_groups
+        .iter()
+        .map(|group| group.diagonal_failures.len())
+        .sum::<usize>();
+    let report = CrossVerifyReport {
+        status: if n_critical + n_diagonal == 0 {
+            "ok"
+        } else {
+            "failed"
+        }
+        .to_string(),
+        artifacts_dir: dir.to_string(),
+        skipped_negative_fixtures,
+        groups: report_groups,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    if n_critical > 0 {
+        bail!("cross-verify: {n_critical} proof(s) verified against a foreign statement");
+    }
+    if n_diagonal > 0 {
+        bail!("cross-verify: {n_diagonal} proof(s) failed against their own statement");
+    }
+    Ok(())
+}
+
+/// The statement fields of an artifact, for equality checks.
+fn statement_json(artifact: &InteropArtifact) -> Result<String> {
+    Ok(serde_json::to_string(&(
+        &artifact.blake_statement,
+        &artifact.plonk_statement,
+        &artifact.poseidon_statement,
+        &artifact.state_machine_statement,
+        &artifact.wide_fibonacci_statement,
+        &artifact.xor_statement,
+        &artifact.components,
+    ))?)
+}
+
+/// Verifies the proof of `proof_artifact` under the statement of `statement_artifact`; see
+/// `CrossVerifyGroup::results` for the returned value.
+fn cross_verify_cell(
+    proof_artifact: &InteropArtifact,
+    statement_artifact: &InteropArtifact,
+) -> Result<String> {
+    let mut crossed = proof_artifact.clone();
+    crossed.blake_statement = statement_artifact.blake_statement.clone();
+    crossed.plonk_statement = statement_artifact.plonk_statement.clone();
+    crossed.poseidon_statement = statement_artifact.poseidon_statement.clone();
+    crossed.state_machine_statement = statement_artifact.state_machine_statement.clone();
+    crossed.wide_fibonacci_statement = statement_artifact.wide_fibonacci_statement.clone();
+    crossed.xor_statement = statement_artifact.xor_statement.clone();
+    crossed.components = statement_artifact.components.clone();
+    verify_outcome(&crossed)
+}
+
+/// `"ok"`, the verify stage the artifact failed at, or `"panic"`.
+fn verify_outcome(artifact: &InteropArtifact) -> Result<String> {
+    Ok(verify_outcome_with_error(artifact)?.0)
+}
+
+/// Verifies every `*.json` artifact in `--artifact-dir` on `--jobs` threads and prints one report.
+/// An artifact fails when its outcome differs from its `expected_verify_result` (`ok` when absent);
+/// any failure makes the run fail unless `--keep-going` is set.
+fn run_batch_verify(cli: &Cli) -> Result<()> {
+    let dir = cli
+        .artifact_dir
+        .as_deref()
+        .ok_or_else(|| anyhow!("--artifact-dir is required"))?;
+    let mut paths = fs::read_dir(dir)
+        .with_context(|| format!("failed reading artifact directory {dir}"))?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    paths.retain(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"));
+    paths.sort();
+    if paths.is_empty() {
+        bail!("no *.json artifacts in {dir}");
+    }
+
+    let start = std::time::Instant::now();
+    let jobs = cli.jobs.unwrap_or(1).min(paths.len());
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let (next, paths) = (&next, &paths);
+    let mut results = std::thread::scope(|scope| {
+        let workers = (0..jobs)
+            .map(|_| {
+                scope.spawn(move || {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        let Some(path) = paths.get(index) else {
+                            return done;
+                        };
+                        done.push((index, batch_verify_entry(path)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut results = Vec::with_capacity(paths.len());
+        for worker in workers {
+            results.extend(
+                worker
+                    .join()
+                    .map_err(|_| anyhow!("batch-verify worker panicked"))?,
+            );
+        }
+        Ok::<_, anyhow::Error>(results)
+    })?;
+    results.sort_by_key(|(index, _)| *index);
+    let artifacts: Vec<_> = results.into_iter().map(|(_, entry)| entry).collect();
+
+    let passed = artifacts
+        .iter()
+        .filter(|entry| entry.status == "ok")
+        .count();
+    let failed = artifacts.len() - passed;
+    let expected_rejections = artifacts
+        .iter()
+        .filter(|entry| entry.status == "ok" && entry.error_kind.is_some())
+        .count();
+    let report = BatchVerifyReport {
+        mode: "batch-verify".to_string(),
+        artifact_dir: dir.to_string(),
+        status: if failed == 0 { "ok" } else { "fail" }.to_string(),
+        jobs,
+        total: artifacts.len(),
+        passed,
+        failed,
+        expected_rejections,
+        seconds: start.elapsed().as_secs_f64(),
+        artifacts,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    if failed > 0 && !cli.keep_going {
+        bail!("batch-verify: {failed} artifact(s) failed");
+    }
+    Ok(())
+}
+
+fn batch_verify_entry(path: &std::path::Path) -> BatchVerifyEntry {
+    let name = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into(),
+    );
+    let raw = match read_input(&path.display().to_string()) {
+        Ok(raw) => raw,
+        Err(err) => {
+            return BatchVerifyEntry {
+                artifact: name,
+                status: "failed".to_string(),
+                expected_verify_result: None,
+                error_kind: Some("read".to_string()),
+                error: Some(format!("{err:#}")),
+                verify_seconds: 0.0,
+                proof_bytes_len: None,
+            }
+        }
+    };
+    let expected_verify_result = read_expected_verify_result(&raw).ok().flatten();
+    let proof_bytes_len = parse_artifact(&raw)
+        .ok()
+        .and_then(|artifact| artifact.proof_bytes_len);
+
+    let start = std::time::Instant::now();
+    let mut trace = VerifyTrace::disabled();
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        verify_artifact_bytes_traced(&raw, &mut trace)
+    }));
+    let verify_seconds = start.elapsed().as_secs_f64();
+    let (error_kind, error) = match outcome {
+        Ok(Ok(_)) => (None, None),
+        Ok(Err(err)) => (Some(trace.stage.to_string()), Some(format!("{err:#}"))),
+        Err(_) => (
+            Some("panic".to_string()),
+            Some("verifier panicked".to_string()),
+        ),
+    };
+    let actual = error_kind.as_deref().unwrap_or(VERIFY_RESULT_OK);
+    let expected = expected_verify_result
+        .as_deref()
+        .unwrap_or(VERIFY_RESULT_OK);
+    BatchVerifyEntry {
+        artifact: name,
+        status: if actual == expected { "ok" } else { "failed" }.to_string(),
+        expected_verify_result,
+        error_kind,
+        error,
+        verify_seconds,
+        proof_bytes_len,
+    }
+}
+
+/// `verify_outcome` together with the error the verifier reported, if any.
+fn verify_outcome_with_error(artifact: &InteropArtifact) -> Result<(String, Option<String>)> {
+    let raw = serde_json::to_vec(artifact)?;
+    let mut trace = VerifyTrace::disabled();
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        verify_artifact_bytes_traced(&raw, &mut trace)
+    }));
+    Ok(match outcome {
+        Ok(Ok(_)) => (VERIFY_RESULT_OK.to_string(), None),
+        Ok(Err(err)) => (trace.stage.to_string(), Some(format!("{err:#}"))),
+        Err(_) => ("panic".to_string(), Some("verifier panicked".to_string())),
+    })
+}
+
+/// Proves `--example` as generate mode would, applies `--tamper-kind`, and writes the corrupted
+/// artifact with the kind, the verify stage the Rust verifier failed at (as
+/// `expected_verify_result`) and its error. Fails if the corruption still verifies.
+fn run_tamper(cli: &Cli) -> Result<()> {
+    let example = cli
+        .example
+        .ok_or_else(|| anyhow!("--example is required for tamper mode"))?;
+    let kind = cli
+        .tamper_kind
+        .as_deref()
+        .ok_or_else(|| anyhow!("--tamper-kind is required for tamper mode"))?;
+    if !TAMPER_KINDS.contains(&kind) {
+        bail!(
+            "invalid --tamper-kind {kind}: expected one of {}",
+            TAMPER_KINDS.join(", ")
+        );
+    }
+
+    let mut artifact = build_artifact(
+        cli,
+        example,
+        &mut StageClock::new(),
+        &mut Transcript::disabled(),
+    )?;
+    apply_tamper(&mut artifact, kind)?;
+    let (stage, error) = verify_outcome_with_error(&artifact)?;
+    let Some(error) = error else {
+        bail!("tamper kind {kind} still verifies for {}", artifact.example);
+    };
+    artifact.tamper_kind = Some(kind.to_string());
+    artifact.tamper_observed_error = Some(error.clone());
+    artifact.expected_verify_result = Some(stage.clone());
+
+    let rendered = serde_json::to_string_pretty(&artifact)?;
+    write_output(&cli.artifact, &format!("{rendered}\n"))
+        .with_context(|| format!("failed writing artifact {}", cli.artifact))?;
+    let report = TamperReport {
+        mode: "tamper".to_string(),
+        artifact: cli.artifact.clone(),
+        example: artifact.example.clone(),
+        tamper_kind: kind.to_string(),
+        expected_verify_result: stage,
+        tamper_observed_error: error,
+    };
+    // With the artifact on stdout, the report moves to stderr so stdout stays parseable.
+    if cli.artifact == STDIO_PATH {
+        eprintln!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", serde_json::to_string(&report)?);
+    }
+    Ok(())
+}
+
+/// Applies one of `TAMPER_KINDS` to a JSON-wire artifact. Every kind but `statement` rewrites the
+/// proof wire and keeps `proof_bytes_len` and `proof_bytes_blake3` consistent, so the corruption
+/// is what verify sees.
+fn apply_tamper(artifact: &mut InteropArtifact, kind: &str) -> Result<()> {
+    if kind == "statement" {
+        return tamper_statement(artifact);
+    }
+    let mut wire = artifact_proof_wire(artifact)?;
+    match kind {
+        "commitment_byte" => {
+            let commitment = wire
+                .commitments
+                .first_mut()
+                .ok_or_else(|| anyhow!("proof has no commitments"))?;
+            commitment[0] ^= 1;
+        }
+        "hash_witness_truncate" => {
+            wire.decommitments
+                .iter_mut()
+                .find(|decommitment| !decommitment.hash_witness.is_empty())
+                .ok_or_else(|| anyhow!("proof has no decommitment hashes"))?
+                .hash_witness
+                .pop();
+        }
+        "sampled_value" => {
+            let value = wire
+                .sampled_values
+                .iter_mut()
+                .flatten()
+                .flatten()
+                .next()
+                .ok_or_else(|| anyhow!("proof has no sampled values"))?;
+            value[0] = (value[0] + 1) % P;
+        }
+        "proof_of_work" => wire.proof_of_work = wire.proof_of_work.wrapping_add(1),
+        "queried_values_swap" => {
+            let column = wire
+                .queried_values
+                .iter_mut()
+                .flatten()
+                .find(|column| column.iter().any(|value| *value != column[0]))
+                .ok_or_else(|| anyhow!("proof has no queried column with distinct values"))?;
+            let other = column
+                .iter()
+                .position(|value| *value != column[0])
+                .expect("column has a distinct value");
+            column.swap(0, other);
+        }
+        "last_layer_poly" => {
+            let coefficient = wire
+                .fri_proof
+                .last_layer_poly
+                .first_mut()
+                .ok_or_else(|| anyhow!("proof has an empty last layer polynomial"))?;
+            coefficient[0] = (coefficient[0] + 1) % P;
+        }
+        other => bail!("unknown tamper kind {other}"),
+    }
+    let proof_bytes = encode_proof_wire(&wire, ProofEncoding::Json)?;
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    artifact.proof_bytes_blake3 = Some(proof_bytes_blake3(&proof_bytes));
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    Ok(())
+}
+
+/// Bumps one statement parameter while keeping the proof, mirroring the e2e statement tamper.
+fn tamper_statement(artifact: &mut InteropArtifact) -> Result<()> {
+    let missing = || anyhow!("missing {}_statement", artifact.example);
+    match artifact.example.as_str() {
+        "blake" => {
+            let statement = artifact.blake_statement.as_mut().ok_or_else(missing)?;
+            statement.n_rounds += 1;
+        }
+        "plonk" => {
+            let statement = artifact.plonk_statement.as_mut().ok_or_else(missing)?;
+            statement.log_n_rows += 1;
+        }
+        "poseidon" => {
+            let statement = artifact.poseidon_statement.as_mut().ok_or_else(missing)?;
+            statement.log_n_instances += 1;
+        }
+        "state_machine" | "state_machine_pair" => {
+            let statement = artifact
+                .state_machine_statement
+                .as_mut()
+                .ok_or_else(|| anyhow!("missing state_machine_statement"))?;
+            statement.public_input[1][0] = (statement.public_input[1][0] + 1) % P;
+        }
+        "wide_fibonacci" => {
+            let statement = artifact
+                .wide_fibonacci_statement
+                .as_mut()
+                .ok_or_else(missing)?;
+            statement.sequence_len += 1;
+        }
+        "xor" => {
+            let statement = artifact.xor_statement.as_mut().ok_or_else(missing)?;
+            statement.offset += 1;
+        }
+        other => bail!("unknown example {other}"),
+    }
+    Ok(())
+}
+
+/// Proves `--example` and takes the proof through the whole exchange path in memory: wire
+/// conversion, JSON serialization, parsing, conversion back and verification. The parsed wire must
+/// re-serialize to the same bytes; the report gives the first differing offset when it does not.
+fn run_roundtrip(cli: &Cli) -> Result<()> {
+    let example = cli
+        .example
+        .ok_or_else(|| anyhow!("--example is required for roundtrip mode"))?;
+    let mut clock = StageClock::new();
+    let mut report = RoundtripReport {
+        mode: "roundtrip".to_string(),
+        example: example_to_str(example).to_string(),
+        prove_mode: prove_mode_to_str(cli.prove_mode).to_string(),
+        status: VERIFY_RESULT_OK.to_string(),
+        wire_bytes: 0,
+        canonical_mismatch: None,
+        error: None,
+        total_seconds: 0.0,
+        phases: Vec::new(),
+    };
+    let outcome = roundtrip_phases(cli, example, &mut clock, &mut report);
+    if let Err(err) = &outcome {
+        report.status = "failed".to_string();
+        report.error = Some(format!("{err:#}"));
+    }
+    report.total_seconds = clock.stages.iter().map(|stage| stage.seconds).sum();
+    report.phases = clock.stages;
+    println!("{}", serde_json::to_string(&report)?);
+    outcome
+}
+
+fn roundtrip_phases(
+    cli: &Cli,
+    example: Example,
+    clock: &mut StageClock,
+    report: &mut RoundtripReport,
+) -> Result<()> {
+    let config = pcs_config_from_cli(cli)?;
+    let (statement, proof) = prove_example(
+        config,
+        example,
+        cli,
+        cli.prove_mode,
+        cli.include_all_preprocessed_columns,
+    )?;
+    clock.lap("prove", "Prove");
+    let wire = proof_to_wire(&proof)?;
+    clock.lap("proof_to_wire", "Proof to wire");
+    let serialized = encode_proof_wire(&wire, ProofEncoding::Json)?;
+    report.wire_bytes = serialized.len();
+    clock.lap("serialize", "Serialize");
+    let parsed = decode_proof_wire_bytes(&serialized, ProofEncoding::Json)
+        .context("failed parsing the serialized proof wire")?;
+    clock.lap("deserialize", "Deserialize");
+    let reserialized = encode_proof_wire(&parsed, ProofEncoding::Json)?;
+    clock.lap("reserialize", "Re-serialize");
+    if serialized != reserialized {
+        let offset = serialized
+            .iter()
+            .zip(&reserialized)
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| serialized.len().min(reserialized.len()));
+        report.canonical_mismatch = Some(CanonicalMismatch {
+            offset,
+            serialized_len: serialized.len(),
+            reserialized_len: reserialized.len(),
+        });
+        bail!("re-serialized proof wire differs from the original at byte {offset}");
+    }
+    let proof = wire_to_proof(parsed)?;
+    clock.lap("wire_to_proof", "Wire to proof");
+    verify_example::<Blake2sMerkleChannel>(config, statement, proof, &mut Transcript::disabled())?;
+    clock.lap("verify", "Verify");
+    Ok(())
+}
+
+/// Prints what an artifact holds without running the verifier: metadata, the statement, and
+/// counts read off the decoded proof wire. Only the schema, exchange mode, compression and proof
+/// encoding must be sound, so tampered fixtures can be inspected too.
+fn run_inspect(cli: &Cli) -> Result<()> {
+    let raw = read_input(&cli.artifact)
+        .with_context(|| format!("failed reading artifact {}", cli.artifact))?;
+    let artifact = parse_artifact(&raw)
+        .with_context(|| format!("failed parsing artifact {}", cli.artifact))?;
+    check_artifact_schema(&artifact)?;
+    let compression = artifact_proof_compression(&artifact)?;
+    let (encoding, proof_bytes) = artifact_proof_bytes(&artifact)?;
+    let wire = decode_proof_wire_bytes(&proof_bytes, encoding)?;
+
+    let n_trees = wire
+        .commitments
+        .len()
+        .max(wire.sampled_values.len())
+        .max(wire.queried_values.len())
+        .max(wire.decommitments.len());
+    let trees = (0..n_trees)
+        .map(|tree| {
+            let sampled = wire.sampled_values.get(tree);
+            let queried = wire.queried_values.get(tree);
+            InspectTree {
+                tree,
+                commitment: wire
+                    .commitments
+                    .get(tree)
+                    .map(hex::encode)
+                    .unwrap_or_default(),
+                sampled_columns: sampled.map_or(0, Vec::len),
+                sampled_values: sampled.map_or(0, |columns| columns.iter().map(Vec::len).sum()),
+                queried_columns: queried.map_or(0, Vec::len),
+                queried_values: queried.map_or(0, |columns| columns.iter().map(Vec::len).sum()),
+                decommitment_hashes: wire
+                    .decommitments
+                    .get(tree)
+                    .map_or(0, |decommitment| decommitment.hash_witness.len()),
+            }
+        })
+        .collect();
+    let fri_layer = |layer: &FriLayerWire| InspectFriLayer {
+        fri_witness_len: layer.fri_witness.len(),
+        decommitment_hashes: layer.decommitment.hash_witness.len(),
+    };
+    let fri = InspectFri {
+        first_layer: fri_layer(&wire.fri_proof.first_layer),
+        inner_layers: wire.fri_proof.inner_layers.iter().map(fri_layer).collect(),
+        last_layer_poly_len: wire.fri_proof.last_layer_poly.len(),
+    };
+    let proof_metrics = proof_metrics_from_wire(&wire)?;
+    let proof_of_work = wire.proof_of_work;
+    let (size_breakdown_estimate, proof_convert_error) = match wire_to_proof(wire) {
+        Ok(proof) => (
+            Some(proof_size_breakdown::<Blake2sMerkleChannel>(&proof)),
+            None,
+        ),
+        Err(err) => (None, Some(format!("{err:#}"))),
+    };
+
+    let report = InspectReport {
+        mode: "inspect".to_string(),
+        artifact: cli.artifact.clone(),
+        schema_version: artifact.schema_version,
+        exchange_mode: artifact.exchange_mode.clone(),
+        compression: proof_compression_to_str(compression).to_string(),
+        generator: artifact.generator.clone(),
+        example: artifact.example.clone(),
+        prove_mode: artifact.prove_mode.clone(),
+        statement: artifact_statement_json(&artifact)?,
+        pcs_config: artifact.pcs_config.clone(),
+        proof_bytes_len: proof_bytes.len(),
+        commitments_count: proof_metrics.commitments_count,
+        trees,
+        fri,
+        proof_of_work,
+        proof_metrics,
+        size_breakdown_estimate,
+        proof_convert_error,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+fn proof_size_breakdown<MC: InteropMerkle>(proof: &StarkProof<MC::H>) -> InspectSizeBreakdown {
+    let breakdown = proof.size_breakdown_estimate();
+    InspectSizeBreakdown {
+        oods_samples: breakdown.oods_samples,
+        queries_values: breakdown.queries_values,
+        fri_samples: breakdown.fri_samples,
+        fri_decommitments: breakdown.fri_decommitments,
+        trace_decommitments: breakdown.trace_decommitments,
+    }
+}
+
+/// Reports proof sizes for the release notes: of `--artifact`, of every `*.json` artifact in
+/// `--artifact` when it is a directory, or of a fresh proof when generate flags with `--example`
+/// are given instead. Each proof is converted into a `StarkProof` under its artifact's hash but
+/// not verified.
+fn run_size_report(cli: &Cli) -> Result<()> {
+    let mut artifacts = Vec::new();
+    let mut skipped_negative_fixtures = Vec::new();
+    let input = match (cli.example, cli.artifact.is_empty()) {
+        (Some(example), true) => {
+            let artifact = build_artifact(
+                cli,
+                example,
+                &mut StageClock::new(),
+                &mut Transcript::disabled(),
+            )?;
+            // Measured as generate mode writes it, trailing newline included.
+            let artifact_bytes = serde_json::to_string_pretty(&artifact)?.len() + 1;
+            artifacts.push(artifact_sizes("generated", &artifact, artifact_bytes)?);
+            "generated".to_string()
+        }
+        (Some(_), false) => bail!("size-report takes either --artifact or --example, not both"),
+        (None, true) => bail!("--artifact or --example is required for size-report mode"),
+        (None, false) if std::path::Path::new(&cli.artifact).is_dir() => {
+            let dir = &cli.artifact;
+            let mut paths = fs::read_dir(dir)
+                .with_context(|| format!("failed reading artifact directory {dir}"))?
+                .map(|entry| Ok(entry?.path()))
+                .collect::<Result<Vec<_>>>()?;
+            paths
+                .retain(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"));
+            paths.sort();
+            for path in paths {
+                let name = path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into(),
+                );
+                let raw = read_input(&path.display().to_string())
+                    .with_context(|| format!("failed reading artifact {name}"))?;
+                let artifact = parse_artifact(&raw)
+                    .with_context(|| format!("failed parsing artifact {name}"))?;
+                if artifact.expected_verify_result.is_some() {
+                    skipped_negative_fixtures.push(name);
+                    continue;
+                }
+                artifacts.push(artifact_sizes(&name, &artifact, raw.len())?);
+            }
+            if artifacts.is_empty() {
+                bail!("no *.json artifacts to report in {dir}");
+            }
+            dir.clone()
+        }
+        (None, false) => {
+            let raw = read_input(&cli.artifact)
+                .with_context(|| format!("failed reading artifact {}", cli.artifact))?;
+            let artifact = parse_artifact(&raw)
+                .with_context(|| format!("failed parsing artifact {}", cli.artifact))?;
+            artifacts.push(artifact_sizes(&cli.artifact, &artifact, raw.len())?);
+            cli.artifact.clone()
+        }
+    };
+
+    let mut totals = ProofSizes::default();
+    let mut by_example = BTreeMap::<&str, (usize, ProofSizes<usize>)>::new();
+    for entry in &artifacts {
+        totals.add(&entry.sizes);
+        let (count, example_totals) = by_example.entry(entry.example.as_str()).or_default();
+        *count += 1;
+        example_totals.add(&entry.sizes);
+    }
+    let examples = by_example
+        .into_iter()
+        .map(|(example, (count, totals))| SizeReportExample {
+            example: example.to_string(),
+            artifacts: count,
+            totals,
+            averages: totals.average(count),
+        })
+        .collect();
+    let report = SizeReport {
+        mode: "size-report".to_string(),
+        input,
+        artifacts,
+        skipped_negative_fixtures,
+        examples,
+        totals,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+fn artifact_sizes(
+    name: &str,
+    artifact: &InteropArtifact,
+    artifact_bytes: usize,
+) -> Result<SizeReportArtifact> {
+    let sizes = proof_sizes(artifact, artifact_bytes)
+        .with_context(|| format!("failed sizing the proof of {name}"))?;
+    Ok(SizeReportArtifact {
+        artifact: name.to_string(),
+        example: artifact.example.clone(),
+        sizes,
+    })
+}
+
+/// Sizes of the proof `artifact` carries, whose JSON takes `artifact_bytes` bytes.
+fn proof_sizes(artifact: &InteropArtifact, artifact_bytes: usize) -> Result<ProofSizes<usize>> {
+    check_artifact_schema(artifact)?;
+    let hash = artifact_proof_hash(artifact)?;
+    let (encoding, proof_bytes) = artifact_proof_bytes(artifact)?;
+    let wire = decode_proof_wire_bytes(&proof_bytes, encoding)?;
+    let breakdown = match hash {
+        ProofHash::Blake2s => proof_size_breakdown::<Blake2sMerkleChannel>(&wire_to_proof(wire)?),
+        ProofHash::Blake3 => proof_size_breakdown::<Blake3MerkleChannel>(&wire_to_proof_with::<
+            Blake3MerkleChannel,
+        >(wire)?),
+    };
+    Ok(ProofSizes {
+        oods_samples: breakdown.oods_samples,
+        queries_values: breakdown.queries_values,
+        fri_samples: breakdown.fri_samples,
+        fri_decommitments: breakdown.fri_decommitments,
+        trace_decommitments: breakdown.trace_decommitments,
+        wire_bytes: proof_bytes.len(),
+        artifact_bytes,
+    })
+}
+
+/// The statement wire of the artifact's example as JSON, or `null` if it is missing.
+fn artifact_statement_json(artifact: &InteropArtifact) -> Result<serde_json::Value> {
+    Ok(match artifact.example.as_str() {
+        "blake" => serde_json::to_value(&artifact.blake_statement)?,
+        "plonk" => serde_json::to_value(&artifact.plonk_statement)?,
+        "poseidon" => serde_json::to_value(&artifact.poseidon_statement)?,
+        "state_machine" | "state_machine_pair" => {
+            serde_json::to_value(&artifact.state_machine_statement)?
+        }
+        "wide_fibonacci" => serde_json::to_value(&artifact.wide_fibonacci_statement)?,
+        "xor" => serde_json::to_value(&artifact.xor_statement)?,
+        _ => serde_json::Value::Null,
+    })
+}
+
+/// Regenerates the statement of a (Zig) `--artifact` with the Rust prover, verifies both proofs
+/// with the Rust verifier and compares what the transcript exposes. The prove mode is taken from
+/// the artifact; `--include-all-preprocessed-columns` applies to the Rust side since artifacts do
+/// not record it. Fails unless the verdict is `both_valid` with no required mismatch.
+fn run_conform(cli: &Cli) -> Result<()> {
+    let raw = read_input(&cli.artifact)
+        .with_context(|| format!("failed reading artifact {}", cli.artifact))?;
+    let zig = parse_artifact(&raw)
+        .with_context(|| format!("failed parsing artifact {}", cli.artifact))?;
+    let zig_verify_result = verify_outcome(&zig)?;
+
+    let mut report = ConformReport {
+        mode: "conform".to_string(),
+        artifact: cli.artifact.clone(),
+        example: zig.example.clone(),
+        generator: zig.generator.clone(),
+        pcs_config: zig.pcs_config.clone(),
+        zig_verify_result,
+        rust_verify_result: None,
+        commitments: Vec::new(),
+        first_divergent_tree: None,
+        section_sizes: Vec::new(),
+        checks: Vec::new(),
+        required_mismatches: Vec::new(),
+        incompatibility: None,
+        verdict: ConformVerdict::StatementIncompatible,
+    };
+    match conform_rust_artifact(cli, &raw, &zig) {
+        Ok(rust) => {
+            let rust_verify_result = verify_outcome(&rust)?;
+            fill_conform_comparison(&mut report, &zig, &rust)?;
+            report.verdict = if rust_verify_result != VERIFY_RESULT_OK {
+                ConformVerdict::RustInvalid
+            } else if report.zig_verify_result != VERIFY_RESULT_OK {
+                ConformVerdict::ZigInvalid
+            } else {
+                ConformVerdict::BothValid
+            };
+            report.rust_verify_result = Some(rust_verify_result);
+        }
+        Err(err) => report.incompatibility = Some(format!("{err:#}")),
+    }
+    println!("{}", serde_json::to_string(&report)?);
+    if report.verdict != ConformVerdict::BothValid {
+        bail!(
+            "conform: verdict {}",
+            conform_verdict_to_str(report.verdict)
+        );
+    }
+    if !report.required_mismatches.is_empty() {
+        bail!(
+            "conform: required transcript values differ: {}",
+            report.required_mismatches.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// The Rust artifact for the statement and config of `zig`; any error means the statement cannot
+/// be reproduced.
+fn conform_rust_artifact(cli: &Cli, raw: &[u8], zig: &InteropArtifact) -> Result<InteropArtifact> {
+    let value: serde_json::Value = serde_json::from_slice(raw)?;
+    if value.get("trace_seed").is_some_and(|seed| !seed.is_null()) {
+        bail!(
+            "trace_seed is set but the Rust examples derive their traces from the statement alone"
+        );
+    }
+    let example = match zig.example.as_str() {
+        "blake" => Example::Blake,
+        "plonk" => Example::Plonk,
+        "poseidon" => Example::Poseidon,
+        "state_machine" => Example::StateMachine,
+        "state_machine_pair" => Example::StateMachinePair,
+        "wide_fibonacci" => Example::WideFibonacci,
+        "xor" => Example::Xor,
+        other => bail!("unknown example {other}"),
+    };
+    let missing = || anyhow!("missing {}_statement", zig.example);
+    let statement = match example {
+        Example::Blake => {
+            let wire = zig.blake_statement.as_ref().ok_or_else(missing)?;
+            Statement::Blake {
+                log_n_rows: wire.log_n_rows,
+                n_rounds: wire.n_rounds,
+            }
+        }
+        Example::Plonk => Statement::Plonk {
+            log_n_rows: zig.plonk_statement.as_ref().ok_or_else(missing)?.log_n_rows,
+        },
+        Example::Poseidon => Statement::Poseidon {
+            log_n_instances: zig
+                .poseidon_statement
+                .as_ref()
+                .ok_or_else(missing)?
+                .log_n_instances,
+        },
+        Example::StateMachine => {
+            let wire = zig.state_machine_statement.as_ref().ok_or_else(missing)?;
+            Statement::StateMachine {
+                log_n_rows: wire.stmt0.n,
+                initial_state: wire.public_input[0],
+            }
+        }
+        Example::StateMachinePair => {
+            let wire = zig
+                .state_machine_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing state_machine_statement"))?;
+            Statement::StateMachinePair {
+                log_n_rows: wire.stmt0.n,
+                initial_state: wire.public_input[0],
+            }
+        }
+        Example::WideFibonacci => {
+            let wire = zig.wide_fibonacci_statement.as_ref().ok_or_else(missing)?;
+            Statement::WideFibonacci {
+                log_n_rows: wire.log_n_rows,
+                sequence_len: wire.sequence_len,
+            }
+        }
+        Example::Xor => {
+            let wire = zig.xor_statement.as_ref().ok_or_else(missing)?;
+            Statement::Xor {
+                log_size: wire.log_size,
+                log_step: wire.log_step,
+                offset: usize::try_from(wire.offset)?,
+            }
+        }
+    };
+    let prove_mode = match &zig.prove_mode {
+        Some(mode) => {
+            prove_mode_from_str(mode).ok_or_else(|| anyhow!("unsupported prove mode {mode}"))?
+        }
+        None => ProveMode::default(),
+    };
+    let options = GenerateOptions {
+        prove_mode,
+        include_all_preprocessed_columns: cli.include_all_preprocessed_columns,
+        proof_encoding: ProofEncoding::Json,
+        proof_compression: ProofCompression::None,
+        backend: cli.backend,
+        hash: ProofHash::Blake2s,
+        schema: ArtifactSchema::V2,
+        with_interaction: false,
+        preprocessed_columns: None,
+        trace_perturbation: None,
+    };
+    generate_artifact(
+        example,
+        statement,
+        pcs_config_from_wire(&zig.pcs_config)?,
+        &options,
+    )
+}
+
+fn conform_verdict_to_str(verdict: ConformVerdict) -> &'static str {
+    match verdict {
+        ConformVerdict::BothValid => "both_valid",
+        ConformVerdict::ZigInvalid => "zig_invalid",
+        ConformVerdict::RustInvalid => "rust_invalid",
+        ConformVerdict::StatementIncompatible => "statement_incompatible",
+    }
+}
+
+/// Fills the commitment, size and transcript sections of `report`. A Zig proof that does not
+/// decode leaves all but the Rust sizes empty; its verify result already records why.
+fn fill_conform_comparison(
+    report: &mut ConformReport,
+    zig: &InteropArtifact,
+    rust: &InteropArtifact,
+) -> Result<()> {
+    let rust_wire = artifact_proof_wire(rust)?;
+    let zig_wire = artifact_proof_wire(zig).ok();
+    let rust_sections = proof_wire_section_sizes(&rust_wire)?;
+    let zig_sections = zig_wire
+        .as_ref()
+        .map(proof_wire_section_sizes)
+        .transpose()?;
+    report.section_sizes = rust_sections
+        .into_iter()
+        .map(|(section, rust_bytes)| ConformSectionSize {
+            zig_bytes: zig_sections.as_ref().and_then(|sections| {
+                sections
+                    .iter()
+                    .find(|(name, _)| *name == section)
+                    .map(|(_, size)| *size)
+            }),
+            section,
+            rust_bytes,
+        })
+        .collect();
+    let Some(zig_wire) = zig_wire else {
+        return Ok(());
+    };
+
+    report.commitments = zig_wire
+        .commitments
+        .iter()
+        .zip(&rust_wire.commitments)
+        .enumerate()
+        .map(|(tree, (zig_root, rust_root))| ConformCommitment {
+            tree,
+            zig: hex::encode(zig_root),
+            rust: hex::encode(rust_root),
+            matches: zig_root == rust_root,
+        })
+        .collect();
+    report.first_divergent_tree = report
+        .commitments
+        .iter()
+        .find(|commitment| !commitment.matches)
+        .map(|commitment| commitment.tree);
+    let commitments_match = report.first_divergent_tree.is_none()
+        && zig_wire.commitments.len() == rust_wire.commitments.len();
+
+    let sampled_shape = |wire: &ProofWire| -> Vec<Vec<usize>> {
+        wire.sampled_values
+            .iter()
+            .map(|tree| tree.iter().map(Vec::len).collect())
+            .collect()
+    };
+    let queried_shape = |wire: &ProofWire| -> Vec<Vec<usize>> {
+        wire.queried_values
+            .iter()
+            .map(|tree| tree.iter().map(Vec::len).collect())
+            .collect()
+    };
+    let fri_commitments = |wire: &ProofWire| -> Vec<HashWire> {
+        std::iter::once(&wire.fri_proof.first_layer)
+            .chain(&wire.fri_proof.inner_layers)
+            .map(|layer| layer.commitment)
+            .collect()
+    };
+    let checks = [
+        (
+            "config",
+            same_json(&zig_wire.config, &rust_wire.config)?,
+            true,
+        ),
+        (
+            "commitments_count",
+            zig_wire.commitments.len() == rust_wire.commitments.len(),
+            true,
+        ),
+        (
+            "sampled_values_shape",
+            sampled_shape(&zig_wire) == sampled_shape(&rust_wire),
+            true,
+        ),
+        (
+            "queried_values_shape",
+            queried_shape(&zig_wire) == queried_shape(&rust_wire),
+            true,
+        ),
+        (
+            "fri_inner_layers_count",
+            zig_wire.fri_proof.inner_layers.len() == rust_wire.fri_proof.inner_layers.len(),
+            true,
+        ),
+        (
+            "fri_last_layer_poly_len",
+            zig_wire.fri_proof.last_layer_poly.len() == rust_wire.fri_proof.last_layer_poly.len(),
+            true,
+        ),
+        (
+            "statement",
+            statement_json(zig)? == statement_json(rust)?,
+            commitments_match,
+        ),
+        (
+            "sampled_values",
+            zig_wire.sampled_values == rust_wire.sampled_values,
+            commitments_match,
+        ),
+        (
+            "proof_of_work",
+            zig_wire.proof_of_work == rust_wire.proof_of_work,
+            commitments_match,
+        ),
+        (
+            "fri_commitments",
+            fri_commitments(&zig_wire) == fri_commitments(&rust_wire),
+            commitments_match,
+        ),
+        (
+            "fri_last_layer_poly",
+            zig_wire.fri_proof.last_layer_poly == rust_wire.fri_proof.last_layer_poly,
+            commitments_match,
+        ),
+        (
+            "queried_values",
+            zig_wire.queried_values == rust_wire.queried_values,
+            commitments_match,
+        ),
+        (
+            "decommitments",
+            same_json(&zig_wire.decommitments, &rust_wire.decommitments)?,
+            commitments_match,
+        ),
+    ];
+    report.checks = checks
+        .into_iter()
+        .map(|(name, matches, required)| ConformCheck {
+            name: name.to_string(),
+            matches,
+            required,
+        })
+        .collect();
+    report.required_mismatches = report
+        .checks
+        .iter()
+        .filter(|check| check.required && !check.matches)
+        .map(|check| check.name.clone())
+        .collect();
+    Ok(())
+}
+
+/// Serialized JSON size of each top-level field of a proof wire, by field name.
+fn proof_wire_section_sizes(wire: &ProofWire) -> Result<Vec<(String, usize)>> {
+    let serde_json::Value::Object(fields) = serde_json::to_value(wire)? else {
+        bail!("proof wire did not serialize to an object");
+    };
+    fields
+        .into_iter()
+        .map(|(name, value)| Ok((name, serde_json::to_vec(&value)?.len())))
+        .collect()
+}
+
+fn same_json<T: Serialize>(a: &T, b: &T) -> Result<bool> {
+    Ok(serde_json::to_value(a)? == serde_json::to_value(b)?)
+}
+
+/// Validates artifact metadata and verifies the embedded proof from in-memory artifact bytes.
+fn verify_artifact_bytes(raw: &[u8]) -> Result<VerifyReport> {
+    verify_artifact_bytes_traced(raw, &mut VerifyTrace::disabled())
+}
+
+fn verify_artifact_bytes_traced(raw: &[u8], trace: &mut VerifyTrace) -> Result<VerifyReport> {
+    trace.enter("artifact_parse");
+    let artifact = parse_artifact(raw)?;
+    verify_artifact_traced(&artifact, trace)
+}
+
+/// Verifies an in-memory artifact with the Rust verifier, exactly as `--mode verify` would. The
+/// report's `expectation` is left unset; comparing against `expected_verify_result` is up to the
+/// caller.
+pub fn verify_artifact(
+    artifact: &InteropArtifact,
+    _options: &VerifyOptions,
+) -> Result<VerifyReport> {
+    verify_artifact_traced(artifact, &mut VerifyTrace::disabled())
+}
+
+fn verify_artifact_traced(
+    artifact: &InteropArtifact,
+    trace: &mut VerifyTrace,
+) -> Result<VerifyReport> {
+    trace.enter("metadata");
+    trace.record("artifact", &ArtifactTraceMetadata::new(artifact))?;
+    check_artifact_schema(artifact)?;
+    if proof_encoding_from_exchange_mode(&artifact.exchange_mode).is_none() {
+        bail!("unsupported exchange mode {}", artifact.exchange_mode);
+    }
+    artifact_proof_compression(artifact)?;
+    if artifact.upstream_commit != UPSTREAM_COMMIT {
+        bail!("unsupported upstream commit {}", artifact.upstream_commit);
+    }
+    if artifact.generator != "rust" && artifact.generator != "zig" {
+        bail!("unsupported generator {}", artifact.generator);
+    }
+    if let Some(mode) = &artifact.prove_mode {
+        if prove_mode_from_str(mode).is_none() {
+            bail!("unsupported prove mode {}", mode);
+        }
+    }
+    let hash = artifact_proof_hash(artifact)?;
+
+    trace.enter("pcs_config");
+    let config = pcs_config_from_wire(&artifact.pcs_config)?;
+
+    trace.enter("proof_decode");
+    let (encoding, proof_bytes) = artifact_proof_bytes(artifact)?;
+    trace.enter("checksum");
+    check_proof_bytes_checksum(artifact, &proof_bytes)?;
+    trace.enter("proof_decode");
+    trace.record(
+        "proof_bytes_blake2s",
+        &hex::encode(Blake2sHasher::hash(&proof_bytes).0),
+    )?;
+    let proof_wire = decode_proof_wire_bytes(&proof_bytes, encoding)?;
+    trace.record("proof_wire", &proof_wire)?;
+    let n_commitments = proof_wire.commitments.len();
+
+    trace.enter("pow_policy");
+    let proof_of_work = proof_wire.proof_of_work;
+    let pow_check = check_pow_convention(config.pow_bits, proof_of_work)?;
+    trace.record("pow_check", &pow_check)?;
+
+    trace.enter("proof_convert");
+    let proof = match hash {
+        ProofHash::Blake2s => HashedProof::Blake2s(wire_to_proof(proof_wire)?),
+        ProofHash::Blake3 => {
+            HashedProof::Blake3(wire_to_proof_with::<Blake3MerkleChannel>(proof_wire)?)
+        }
+    };
+
+    trace.enter("statement");
+    if artifact.components.is_some() && artifact.example != "state_machine_pair" {
+        bail!(
+            "components is only written for multi-component examples, not {}",
+            artifact.example
+        );
+    }
+    let statement = match artifact.example.as_str() {
+        "blake" => {
+            let statement_wire = artifact
+                .blake_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing blake_statement"))?;
+            ExampleStatement::Blake(blake_statement_from_wire(statement_wire)?)
+        }
+        "plonk" => {
+            let statement_wire = artifact
+                .plonk_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing plonk_statement"))?;
+            ExampleStatement::Plonk(plonk_statement_from_wire(statement_wire)?)
+        }
+        "poseidon" => {
+            let statement_wire = artifact
+                .poseidon_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing poseidon_statement"))?;
+            ExampleStatement::Poseidon(poseidon_statement_from_wire(statement_wire)?)
+        }
+        "state_machine" => {
+            let statement_wire = artifact
+                .state_machine_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing state_machine_statement"))?;
+            let statement = state_machine_statement_from_wire(statement_wire)?;
+            check_state_machine_commitments(statement, n_commitments)?;
+            ExampleStatement::StateMachine(statement)
+        }
+        "state_machine_pair" => {
+            let statement_wire = artifact
+                .state_machine_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing state_machine_statement"))?;
+            let statement = state_machine_statement_from_wire(statement_wire)?;
+            if statement.with_interaction {
+                bail!("with_interaction is only supported for the state_machine example");
+            }
+            let declared = artifact
+                .components
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing components"))?;
+            check_components(declared, &state_machine_pair_component_wires(statement))?;
+            ExampleStatement::StateMachinePair(statement)
+        }
+        "wide_fibonacci" => {
+            let statement_wire = artifact
+                .wide_fibonacci_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing wide_fibonacci_statement"))?;
+            ExampleStatement::WideFibonacci(wide_fibonacci_statement_from_wire(statement_wire)?)
+        }
+        "xor" => {
+            let statement_wire = artifact
+                .xor_statement
+                .as_ref()
+                .ok_or_else(|| anyhow!("missing xor_statement"))?;
+            ExampleStatement::Xor(xor_statement_from_wire(statement_wire)?)
+        }
+        other => bail!("unknown example {other}"),
+    };
+
+    trace.enter("component_metadata");
+    let statement = match &artifact.preprocessed_columns {
+        Some(columns) => with_preprocessed_columns(statement, columns)?,
+        None => statement,
+    };
+    if let Some(declared) = &artifact.component_metadata {
+        let expected = example_component_metadata(statement).ok_or_else(|| {
+            anyhow!(
+                "{COMPONENT_METADATA_MISMATCH}: {} records its metadata per component",
+                artifact.example
+            )
+        })?;
+        trace.record("component_metadata", &expected)?;
+        check_component_metadata(declared, &expected)?;
+    }
+    if let (Some(declared), ExampleStatement::StateMachinePair(statement)) =
+        (&artifact.components, statement)
+    {
+        let expected = state_machine_pair_component_wires(statement);
+        trace.record("components", &expected)?;
+        for (component, expected) in declared.iter().zip(&expected) {
+            if let (Some(declared), Some(metadata)) = (&component.metadata, &expected.metadata) {
+                check_component_metadata(declared, metadata)
+                    .with_context(|| format!("component {}", expected.name))?;
+            }
+        }
+    }
+
+    trace.enter("verify");
+    match proof {
+        HashedProof::Blake2s(proof) => {
+            verify_example::<Blake2sMerkleChannel>(config, statement, proof, &mut trace.transcript)?
+        }
+        HashedProof::Blake3(proof) => {
+            verify_example::<Blake3MerkleChannel>(config, statement, proof, &mut trace.transcript)?
+        }
+    }
+
+    Ok(VerifyReport {
+        status: "ok".to_string(),
+        example: artifact.example.clone(),
+        generator: artifact.generator.clone(),
+        pow_bits: config.pow_bits,
+        proof_of_work,
+        pow_check: pow_check.to_string(),
+        statement_seed: artifact.statement_seed,
+        expectation: None,
+    })
+}
+
+/// Replaces every JSON array longer than `REPRO_MAX_ARRAY_ITEMS` with its length, its first
+/// items, and the Blake2s hash of the full serialized array, so bundle intermediates stay bounded.
+fn bounded_json(value: serde_json::Value) -> Result<serde_json::Value> {
+    Ok(match value {
+        serde_json::Value::Array(items) if items.len() > REPRO_MAX_ARRAY_ITEMS => {
+            let blake2s = hex::encode(Blake2sHasher::hash(&serde_json::to_vec(&items)?).0);
+            let len = items.len();
+            let head = items
+                .into_iter()
+                .take(REPRO_MAX_ARRAY_ITEMS)
+                .map(bounded_json)
+                .collect::<Result<Vec<_>>>()?;
+            serde_json::json!({ "truncated": true, "len": len, "head": head, "blake2s": blake2s })
+        }
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .map(bounded_json)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        serde_json::Value::Object(fields) => serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| Ok((key, bounded_json(value)?)))
+                .collect::<Result<serde_json::Map<_, _>>>()?,
+        ),
+        other => other,
+    })
+}
+
+/// Writes a self-contained reproduction of a failed verification into `dir`: the artifact, the
+/// resolved CLI, the error report, the intermediates captured up to the failing stage, and a
+/// `replay.sh` that re-runs the same verification (extra arguments are forwarded).
+fn write_repro_bundle(
+    dir: &str,
+    cli: &Cli,
+    raw: &[u8],
+    trace: &VerifyTrace,
+    err: &anyhow::Error,
+) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("failed creating repro bundle dir {dir}"))?;
+
+    let error_report = ReproErrorReport {
+        status: "error".to_string(),
+        failed_stage: trace.stage.to_string(),
+        error: format!("{err:#}"),
+        error_chain: err.chain().map(|cause| cause.to_string()).collect(),
+    };
+    let replay = format!(
+        "#!/bin/sh\nexec cargo run --manifest-path '{}/Cargo.toml' -- --mode verify --artifact \"$(dirname \"$0\")/artifact.json\" \"$@\"\n",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let files: Vec<(&str, Vec<u8>)> = vec![
+        ("artifact.json", raw.to_vec()),
+        ("cli.json", serde_json::to_vec_pretty(cli)?),
+        ("error.json", serde_json::to_vec_pretty(&error_report)?),
+        (
+            "intermediates.json",
+            serde_json::to_vec_pretty(&trace.intermediates)?,
+        ),
+        ("replay.sh", replay.into_bytes()),
+    ];
+
+    let mut manifest_files = Vec::with_capacity(files.len());
+    for (name, data) in &files {
+        let path = format!("{dir}/{name}");
+        fs::write(&path, data).with_context(|| format!("failed writing {path}"))?;
+        manifest_files.push(ReproBundleFile {
+            name: name.to_string(),
+            size: data.len(),
+            blake2s: hex::encode(Blake2sHasher::hash(data).0),
+        });
+    }
+
+    let manifest = ReproBundleManifest {
+        bundle_version: REPRO_BUNDLE_VERSION,
+        upstream_commit: UPSTREAM_COMMIT.to_string(),
+        failed_stage: error_report.failed_stage,
+        error: error_report.error,
+        max_array_items: REPRO_MAX_ARRAY_ITEMS,
+        files: manifest_files,
+    };
+    let manifest_path = format!("{dir}/manifest.json");
+    fs::write(
+        &manifest_path,
+        format!("{}\n", serde_json::to_string_pretty(&manifest)?),
+    )
+    .with_context(|| format!("failed writing repro bundle manifest {manifest_path}"))?;
+    Ok(())
+}
+
+/// Applies the proof-of-work convention ahead of upstream verification and reports whether the
+/// PoW digest check is skipped (`pow_bits = 0`) or evaluated by the verifier.
+fn check_pow_convention(pow_bits: u32, proof_of_work: u64) -> Result<&'static str> {
+    if pow_bits == 0 {
+        if proof_of_work != POW_ZERO_BITS_REQUIRED_NONCE {
+            bail!(
+                "pow nonce policy: proof_of_work must be {POW_ZERO_BITS_REQUIRED_NONCE} when pow_bits = 0, got {proof_of_work}"
+            );
+        }
+        return Ok("skipped_zero_bits");
+    }
+    Ok("evaluated")
+}
+
+fn run_capabilities() -> Result<()> {
+    let capabilities = Capabilities {
+        schema_version: SCHEMA_VERSION,
+        upstream_commit: UPSTREAM_COMMIT.to_string(),
+        exchange_mode: EXCHANGE_MODE.to_string(),
+        exchange_modes: [ProofEncoding::Json, ProofEncoding::Bincode]
+            .iter()
+            .map(|encoding| proof_encoding_exchange_mode(*encoding).to_string())
+            .collect(),
+        proof_compressions: [ProofCompression::None, ProofCompression::Zstd]
+            .iter()
+            .map(|compression| proof_compression_to_str(*compression).to_string())
+            .collect(),
+        examples: ALL_EXAMPLES
+            .iter()
+            .map(|example| example_to_str(*example).to_string())
+            .collect(),
+        prove_modes: [ProveMode::Prove, ProveMode::ProveEx]
+            .iter()
+            .map(|mode| prove_mode_to_str(*mode).to_string())
+            .collect(),
+        export_compressions: [
+            ExportCompression::None,
+            ExportCompression::Gzip,
+            ExportCompression::Zstd,
+        ]
+        .iter()
+        .map(|compression| export_compression_to_str(*compression).to_string())
+        .collect(),
+        pow_zero_bits_convention: PowZeroBitsConvention {
+            pow_check: "skipped".to_string(),
+            required_nonce: POW_ZERO_BITS_REQUIRED_NONCE,
+            nonce_mixed_into_channel: true,
+            rejection: "pow nonce policy".to_string(),
+        },
+        limits: PARAM_LIMITS.to_vec(),
+    };
+    println!("{}", serde_json::to_string(&capabilities)?);
+    Ok(())
+}
+
+fn run_bench(cli: &Cli) -> Result<()> {
+    let example = cli
+        .example
+        .ok_or_else(|| anyhow!("--example is required for bench mode"))?;
+    let report = bench_report(cli, example)?;
+    // The file is the report compare tooling reads; the stdout line stays for older callers.
+    if let Some(path) = &cli.bench_out {
+        fs::write(
+            path,
+            format!("{}\n", serde_json::to_string_pretty(&report)?),
+        )
+        .with_context(|| format!("failed writing bench report {path}"))?;
+    }
+    match cli.bench_format {
+        BenchFormat::Json => println!("{}", serde_json::to_string(&report)?),
+        BenchFormat::Csv => {
+            let mut metrics = Vec::new();
+            collect_numeric_metrics("", &serde_json::to_value(&report)?, &mut metrics);
+            println!("example,metric,value");
+            for (metric, value) in metrics {
+                println!("{},{metric},{value}", report.example);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Flattens the numbers in `value` into `(dotted.path, number)` pairs in key order; strings,
+/// booleans, nulls and arrays (the raw samples) are left out.
+fn collect_numeric_metrics(
+    prefix: &str,
+    value: &serde_json::Value,
+    out: &mut Vec<(String, serde_json::Number)>,
+) {
+    match value {
+        serde_json::Value::Number(number) => out.push((prefix.to_string(), number.clone())),
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                collect_numeric_metrics(&path, field, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Compares two bench reports (`--mode bench --bench-out`) metric by metric and fails when a
+/// candidate/baseline ratio exceeds its `--max-*-ratio`. Peak RSS is compared only when both
+/// reports carry it.
+fn run_bench_compare(cli: &Cli) -> Result<()> {
+    let read_report = |flag: &str, path: Option<&str>| -> Result<(String, serde_json::Value)> {
+        let path = path.ok_or_else(|| anyhow!("{flag} is required for bench-compare mode"))?;
+        let raw = fs::read(path).with_context(|| format!("failed reading bench report {path}"))?;
+        let report = serde_json::from_slice(&raw)
+            .with_context(|| format!("failed parsing bench report {path}"))?;
+        Ok((path.to_string(), report))
+    };
+    let (baseline_path, baseline) = read_report("--baseline", cli.baseline.as_deref())?;
+    let (candidate_path, candidate) = read_report("--candidate", cli.candidate.as_deref())?;
+    let example = baseline["example"].as_str().unwrap_or_default().to_string();
+    if candidate["example"].as_str() != Some(example.as_str()) {
+        bail!(
+            "bench reports are for different examples: baseline {}, candidate {}",
+            baseline["example"],
+            candidate["example"]
+        );
+    }
+
+    let metrics = [
+        (
+            "prove.avg_seconds",
+            "/prove/avg_seconds",
+            cli.max_prove_ratio,
+            true,
+        ),
+        (
+            "verify.avg_seconds",
+            "/verify/avg_seconds",
+            cli.max_verify_ratio,
+            true,
+        ),
+        (
+            "proof_metrics.proof_wire_bytes",
+            "/proof_metrics/proof_wire_bytes",
+            cli.max_proof_bytes_ratio,
+            true,
+        ),
+        (
+            "prove_peak_rss_bytes",
+            "/prove_peak_rss_bytes",
+            cli.max_peak_rss_ratio,
+            false,
+        ),
+        (
+            "verify_peak_rss_bytes",
+            "/verify_peak_rss_bytes",
+            cli.max_peak_rss_ratio,
+            false,
+        ),
+    ];
+    let mut ratios = Vec::with_capacity(metrics.len());
+    for (metric, pointer, max_ratio, required) in metrics {
+        let (Some(baseline_value), Some(candidate_value)) = (
+            baseline
+                .pointer(pointer)
+                .and_then(serde_json::Value::as_f64),
+            candidate
+                .pointer(pointer)
+                .and_then(serde_json::Value::as_f64),
+        ) else {
+            if required {
+                bail!("bench reports are missing {metric}");
+            }
+            continue;
+        };
+        if baseline_value <= 0.0 {
+            bail!("baseline {metric} is {baseline_value}; cannot form a ratio");
+        }
+        let ratio = candidate_value / baseline_value;
+        let status = match max_ratio {
+            None => "unchecked",
+            Some(max_ratio) if ratio > max_ratio => "exceeded",
+            Some(_) => "ok",
+        };
+        ratios.push(BenchCompareRatio {
+            metric: metric.to_string(),
+            baseline: baseline_value,
+            candidate: candidate_value,
+            ratio,
+            max_ratio,
+            status: status.to_string(),
+        });
+    }
+
+    let exceeded: Vec<_> = ratios
+        .iter()
+        .filter(|ratio| ratio.status == "exceeded")
+        .map(|ratio| ratio.metric.clone())
+        .collect();
+    let report = BenchCompareReport {
+        mode: "bench-compare".to_string(),
+        example,
+        baseline: baseline_path,
+        candidate: candidate_path,
+        status: if exceeded.is_empty() { "ok" } else { "fail" }.to_string(),
+        ratios,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    if !exceeded.is_empty() {
+        bail!(
+            "bench-compare: ratio threshold exceeded for {}",
+            exceeded.join(", ")
+        );
+    }
+    Ok(())
+}
+
+fn bench_report(cli: &Cli, example: Example) -> Result<BenchReport> {
+    if cli.bench_repeats == 0 {
+        bail!("--bench-repeats must be positive");
+    }
+    let started = std::time::Instant::now();
+    let config = pcs_config_from_cli(cli)?;
+
+    let mut no_files = Vec::new();
+    reset_peak_rss();
+    let (prove_samples, prove_phases) =
+        bench_prove_samples(cli, config, example, None, &mut no_files)?;
+    let prove_peak_rss_bytes = peak_rss_bytes();
+
+    let (statement, baseline_proof) = prove_example(
+        config,
+        example,
+        cli,
+        cli.prove_mode,
+        cli.include_all_preprocessed_columns,
+    )?;
+    let proof_metrics = proof_metrics_from_proof(&baseline_proof)?;
+    let baseline_wire = proof_to_wire(&baseline_proof)?;
+    let baseline_wire_bytes = serde_json::to_vec(&baseline_wire)?;
+
+    let verify_isolated = reset_peak_rss();
+    let verify_samples = bench_verify_samples(
+        cli,
+        config,
+        example,
+        statement,
+        &baseline_wire_bytes,
+        None,
+        &mut no_files,
+    )?;
+    let verify_peak_rss_bytes = peak_rss_bytes();
+    let peak_rss_note = (!verify_isolated).then(|| {
+        "peak RSS cannot be reset on this platform, so verify_peak_rss_bytes includes the prove \
+         loop; bench verify in a fresh process to isolate it"
+            .to_string()
+    });
+    let prove = summarize_timing(cli.bench_warmups, cli.bench_repeats, prove_samples)?;
+    let verify = summarize_timing(cli.bench_warmups, cli.bench_repeats, verify_samples)?;
+
+    // Profiled samples are collected in a second pass so the headline timings stay unprofiled.
+    let profiling = match &cli.profile_out {
+        Some(profile_dir) => {
+            fs::create_dir_all(profile_dir)
+                .with_context(|| format!("failed creating profile dir {profile_dir}"))?;
+            let mut files = Vec::new();
+            let profiled_prove = summarize_timing(
+                cli.bench_warmups,
+                cli.bench_repeats,
+                bench_prove_samples(cli, config, example, Some(profile_dir), &mut files)?.0,
+            )?;
+            let profiled_verify = summarize_timing(
+                cli.bench_warmups,
+                cli.bench_repeats,
+                bench_verify_samples(
+                    cli,
+                    config,
+                    example,
+                    statement,
+                    &baseline_wire_bytes,
+                    Some(profile_dir),
+                    &mut files,
+                )?,
+            )?;
+            let prove_overhead_ratio = profiled_prove.avg_seconds / prove.avg_seconds;
+            let verify_overhead_ratio = profiled_verify.avg_seconds / verify.avg_seconds;
+            Some(BenchProfiling {
+                profile_dir: profile_dir.clone(),
+                frequency_hz: PROFILING_FREQUENCY_HZ,
+                prove_overhead_ratio,
+                verify_overhead_ratio,
+                prove: profiled_prove,
+                verify: profiled_verify,
+                overhead_bound: PROFILING_OVERHEAD_BOUND,
+                within_overhead_bound: prove_overhead_ratio < PROFILING_OVERHEAD_BOUND
+                    && verify_overhead_ratio < PROFILING_OVERHEAD_BOUND,
+                files,
+            })
+        }
+        None => None,
+    };
+
+    let report = BenchReport {
+        runtime: "rust".to_string(),
+        example: example_to_str(example).to_string(),
+        prove_mode: prove_mode_to_str(cli.prove_mode).to_string(),
+        prove_backend: prove_backend_to_str(cli.backend).to_string(),
+        include_all_preprocessed_columns: cli.include_all_preprocessed_columns,
+        pcs_config: pcs_config_to_wire(config),
+        total_wall_seconds: started.elapsed().as_secs_f64(),
+        prove,
+        prove_phases,
+        verify,
+        prove_peak_rss_bytes,
+        verify_peak_rss_bytes,
+        peak_rss_note,
+        proof_metrics,
+        profiling,
+    };
+    Ok(report)
+}
+
+/// Runs the bench core once per value of the example's size parameter and fits a log-log slope
+/// per phase. With `--budget-seconds`, no new size is started once the budget is spent; sizes
+/// already run are still reported.
+fn run_bench_scale(cli: &Cli) -> Result<()> {
+    let example = cli
+        .example
+        .ok_or_else(|| anyhow!("--example is required for bench-scale mode"))?;
+    if cli.bench_repeats == 0 {
+        bail!("--bench-repeats must be positive");
+    }
+    let spec = cli
+        .scale_param
+        .as_deref()
+        .ok_or_else(|| anyhow!("--scale-param is required for bench-scale mode"))?;
+    let (param, start, end) = parse_scale_param(example, spec)?;
+    let planned_values = subsample_scale_values(start, end, cli.scale_points)?;
+    if let Some(budget) = cli.budget_seconds {
+        if !budget.is_finite() || budget <= 0.0 {
+            bail!("--budget-seconds must be positive");
+        }
+    }
+
+    let started = std::time::Instant::now();
+    let mut budget_exhausted = false;
+    let mut points = Vec::with_capacity(planned_values.len());
+    let mut skipped = Vec::new();
+    for &value in &planned_values {
+        if let Some(budget) = cli.budget_seconds {
+            if started.elapsed().as_secs_f64() >= budget {
+                budget_exhausted = true;
+                break;
+            }
+        }
+        let mut point_cli = cli.clone();
+        (param.apply)(&mut point_cli, value);
+        let report = match bench_report(&point_cli, example) {
+            Ok(report) => report,
+            Err(err) if limit_kind_of(&err) == Some(LimitKind::ImplementationCap) => {
+                skipped.push(BenchScaleSkip {
+                    value,
+                    status: "skipped".to_string(),
+                    error: format!("{err:#}"),
+                });
+                continue;
+            }
+            Err(err) => {
+                return Err(err.context(format!("bench-scale failed at {}={value}", param.name)))
+            }
+        };
+        points.push(BenchScalePoint {
+            value,
+            peak_rss_kib: peak_rss_kib(),
+            report,
+        });
+    }
+
+    let fit_slope = |metric: fn(&BenchReport) -> f64| {
+        log_log_slope(
+            &points
+                .iter()
+                .map(|point| (f64::from(point.value), metric(&point.report)))
+                .collect::<Vec<_>>(),
+        )
+    };
+    let fit = BenchScaleFit {
+        prove_slope: fit_slope(|report| report.prove.avg_seconds),
+        verify_slope: fit_slope(|report| report.verify.avg_seconds),
+        proof_wire_bytes_slope: fit_slope(|report| report.proof_metrics.proof_wire_bytes as f64),
+    };
+
+    let report = BenchScaleReport {
+        runtime: "rust".to_string(),
+        example: example_to_str(example).to_string(),
+        param: param.name.to_string(),
+        range: [start, end],
+        planned_values,
+        budget_seconds: cli.budget_seconds,
+        budget_exhausted,
+        points,
+        skipped,
+        fit,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+/// Parses `<name>=<start>..<end>` (inclusive) against the example's row in `EXAMPLE_SIZE_PARAMS`.
+fn parse_scale_param(
+    example: Example,
+    spec: &str,
+) -> Result<(&'static ExampleSizeParam, u32, u32)> {
+    let (name, range) = spec
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid --scale-param {spec}: expected <name>=<start>..<end>"))?;
+    let param = EXAMPLE_SIZE_PARAMS
+        .iter()
+        .find(|param| param.example == example)
+        .ok_or_else(|| anyhow!("example {} has no size parameter", example_to_str(example)))?;
+    if param.name != name {
+        bail!(
+            "invalid --scale-param {name} for example {}: expected {}",
+            example_to_str(example),
+            param.name
+        );
+    }
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| anyhow!("invalid --scale-param range {range}: expected <start>..<end>"))?;
+    let start: u32 = start.parse().context("invalid --scale-param start")?;
+    let end: u32 = end.parse().context("invalid --scale-param end")?;
+    if start > end {
+        bail!("invalid --scale-param range {range}: start exceeds end");
+    }
+    if start < param.min || end > param.max {
+        bail!(
+            "--scale-param {name}={range} is outside the legal range {}..{} for {}",
+            param.min,
+            param.max,
+            example_to_str(example)
+        );
+    }
+    Ok((param, start, end))
+}
+
+/// Picks `points` values spread evenly over `start..=end`, always keeping both endpoints.
+fn subsample_scale_values(start: u32, end: u32, points: Option<usize>) -> Result<Vec<u32>> {
+    let all = (start..=end).collect::<Vec<_>>();
+    let Some(points) = points else {
+        return Ok(all);
+    };
+    if points == 0 || (points == 1 && all.len() > 1) {
+        bail!("--scale-points must be at least 2 when the range has more than one value");
+    }
+    if points >= all.len() {
+        return Ok(all);
+    }
+    let mut values = (0..points)
+        .map(|i| all[(i * (all.len() - 1) + (points - 1) / 2) / (points - 1)])
+        .collect::<Vec<_>>();
+    values.dedup();
+    Ok(values)
+}
+
+/// Least-squares slope of `log2(y)` against `x`; `None` with fewer than two distinct `x` or any
+/// non-positive `y`.
+fn log_log_slope(samples: &[(f64, f64)]) -> Option<f64> {
+    if samples.len() < 2 || !samples.iter().all(|&(_, y)| y > 0.0) {
+        return None;
+    }
+    let n = samples.len() as f64;
+    let mean_x = samples.iter().map(|&(x, _)| x).sum::<f64>() / n;
+    let mean_y = samples.iter().map(|&(_, y)| y.log2()).sum::<f64>() / n;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for &(x, y) in samples {
+        covariance += (x - mean_x) * (y.log2() - mean_y);
+        variance += (x - mean_x) * (x - mean_x);
+    }
+    if variance > 0.0 {
+        Some(covariance / variance)
+    } else {
+        None
+    }
+}
+
+/// Peak resident set size of this process in KiB, from `VmHWM` in `/proc/self/status`.
+fn peak_rss_kib() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|value| value.trim().strip_suffix("kB"))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// Peak resident set size of this process in bytes: `VmHWM` on Linux, `ru_maxrss` elsewhere.
+fn peak_rss_bytes() -> Option<u64> {
+    match peak_rss_kib() {
+        Some(kib) => Some(kib * 1024),
+        None => max_rss_bytes(),
+    }
+}
+
+#[cfg(unix)]
+fn max_rss_bytes() -> Option<u64> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+    // SAFETY: `getrusage` only writes the `rusage` it is handed, which is initialized on success.
+    let usage = unsafe {
+        if libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) != 0 {
+            return None;
+        }
+        usage.assume_init()
+    };
+    let max_rss = u64::try_from(usage.ru_maxrss).ok()?;
+    // `ru_maxrss` is in bytes on macOS and in KiB on the other unixes.
+    Some(if cfg!(target_os = "macos") {
+        max_rss
+    } else {
+        max_rss * 1024
+    })
+}
+
+#[cfg(not(unix))]
+fn max_rss_bytes() -> Option<u64> {
+    None
+}
+
+/// Resets `VmHWM` to the current RSS (Linux `clear_refs` value 5) so the next peak read covers only
+/// what runs after it. Returns whether the reset happened; elsewhere the peak is process-wide.
+fn reset_peak_rss() -> bool {
+    fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// Times prove + wire encode for warmups and repeats, with the mean `PROVE_PHASES` breakdown of
+/// the sampled runs; with `profile_dir`, each iteration runs under the sampling profiler and
+/// writes `<example>_prove_<iteration>.folded` there.
+fn bench_prove_samples(
+    cli: &Cli,
+    config: PcsConfig,
+    example: Example,
+    profile_dir: Option<&str>,
+    profile_files: &mut Vec<String>,
+) -> Result<(Vec<f64>, BTreeMap<&'static str, f64>)> {
+    let total_runs = cli.bench_warmups + cli.bench_repeats;
+    let mut samples = Vec::with_capacity(cli.bench_repeats);
+    let mut phases = BTreeMap::new();
+    for i in 0..total_runs {
+        let profiler = profile_dir.map(|_| start_phase_profiler()).transpose()?;
+        let start = std::time::Instant::now();
+        let mut clock = StageClock::new();
+        let mut twiddles = TwiddleCache::default();
+        let (_, proof) = prove_example_timed(
+            config,
+            example,
+            cli,
+            cli.prove_mode,
+            cli.include_all_preprocessed_columns,
+            &mut twiddles,
+            &mut clock,
+        )?;
+        let _encoded = serde_json::to_vec(&proof_to_wire(&proof)?)?;
+        clock.lap("proof_serialization", "Proof serialization");
+        let elapsed = start.elapsed().as_secs_f64();
+        drop(proof);
+        if let (Some(profiler), Some(dir)) = (profiler, profile_dir) {
+            let path = format!("{dir}/{}_prove_{i}.folded", example_to_str(example));
+            write_folded_profile(profiler, &path)?;
+            profile_files.push(path);
+        }
+        if i >= cli.bench_warmups {
+            samples.push(elapsed);
+            for (phase, seconds) in prove_phases(&clock.stages, twiddles.precompute_seconds) {
+                *phases.entry(phase).or_insert(0.0) += seconds / cli.bench_repeats as f64;
+            }
+        }
+    }
+    Ok((samples, phases))
+}
+
+/// Folds the stages of one prove run into `PROVE_PHASES`; `twiddle_seconds` is moved from the
+/// scheme setup into its own phase. A stage with children keeps only the time they don't cover.
+fn prove_phases(stages: &[StageNode], twiddle_seconds: f64) -> Vec<(&'static str, f64)> {
+    fn self_seconds<'a>(stages: &'a [StageNode], out: &mut Vec<(&'a str, f64)>) {
+        for stage in stages {
+            let children = stage.children.as_deref().unwrap_or_default();
+            let covered: f64 = children.iter().map(|child| child.seconds).sum();
+            out.push((stage.id.as_str(), (stage.seconds - covered).max(0.0)));
+            self_seconds(children, out);
+        }
+    }
+    let mut flat = Vec::new();
+    self_seconds(stages, &mut flat);
+    PROVE_PHASES
+        .iter()
+        .map(|(phase, ids)| {
+            let seconds: f64 = flat
+                .iter()
+                .filter(|(id, _)| ids.contains(id))
+                .map(|(_, seconds)| seconds)
+                .sum();
+            let seconds = match *phase {
+                "twiddle_precompute" => twiddle_seconds,
+                "commit" => (seconds - twiddle_seconds).max(0.0),
+                _ => seconds,
+            };
+            (*phase, seconds)
+        })
+        .collect()
+}
+
+/// Times wire decode + verify of `wire_bytes`, profiled per iteration like `bench_prove_samples`.
+fn bench_verify_samples(
+    cli: &Cli,
+    config: PcsConfig,
+    example: Example,
+    statement: ExampleStatement,
+    wire_bytes: &[u8],
+    profile_dir: Option<&str>,
+    profile_files: &mut Vec<String>,
+) -> Result<Vec<f64>> {
+    let total_runs = cli.bench_warmups + cli.bench_repeats;
+    let mut samples = Vec::with_capacity(cli.bench_repeats);
+    for i in 0..total_runs {
+        let profiler = profile_dir.map(|_| start_phase_profiler()).transpose()?;
+        let start = std::time::Instant::now();
+        let decoded_wire: ProofWire = serde_json::from_slice(wire_bytes)?;
+        let decoded_proof = wire_to_proof(decoded_wire)?;
+        verify_example::<Blake2sMerkleChannel>(
+            config,
+            statement,
+            decoded_proof,
+            &mut Transcript::disabled(),
+        )?;
+        let elapsed = start.elapsed().as_secs_f64();
+        if let (Some(profiler), Some(dir)) = (profiler, profile_dir) {
+            let path = format!("{dir}/{}_verify_{i}.folded", example_to_str(example));
+            write_folded_profile(profiler, &path)?;
+            profile_files.push(path);
+        }
+        if i >= cli.bench_warmups {
+            samples.push(elapsed);
+        }
+    }
+    Ok(samples)
+}
+
+#[cfg(feature = "profiling")]
+type PhaseProfiler = pprof::ProfilerGuard<'static>;
+
+#[cfg(not(feature = "profiling"))]
+enum PhaseProfiler {}
+
+#[cfg(feature = "profiling")]
+fn start_phase_profiler() -> Result<PhaseProfiler> {
+    Ok(pprof::ProfilerGuardBuilder::default()
+        .frequency(PROFILING_FREQUENCY_HZ)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()?)
+}
+
+#[cfg(not(feature = "profiling"))]
+fn start_phase_profiler() -> Result<PhaseProfiler> {
+    bail!("--profile-out requires building with --features profiling")
+}
+
+/// Writes the samples collected by `profiler` as folded stacks (`thread;outer;...;leaf count`),
+/// the input format of inferno and flamegraph.pl. Lines are sorted for stable diffs.
+#[cfg(feature = "profiling")]
+fn write_folded_profile(profiler: PhaseProfiler, path: &str) -> Result<()> {
+    let report = profiler.report().build()?;
+    let mut lines = report
+        .data
+        .iter()
+        .map(|(frames, count)| {
+            let mut stack = vec![frames.thread_name.clone()];
+            for frame in frames.frames.iter().rev() {
+                for symbol in frame.iter().rev() {
+                    stack.push(symbol.name());
+                }
+            }
+            format!("{} {count}", stack.join(";"))
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+    let mut folded = lines.join("\n");
+    folded.push('\n');
+    fs::write(path, folded).with_context(|| format!("failed writing profile {path}"))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "profiling"))]
+fn write_folded_profile(profiler: PhaseProfiler, _path: &str) -> Result<()> {
+    match profiler {}
+}
+
+/// Runs generate + verify for every example under the micro preset, keeping artifacts in memory.
+fn run_micro_suite(cli: &Cli) -> Result<()> {
+    let mut micro_cli = cli.clone();
+    apply_preset(&mut micro_cli, Preset::Micro);
+
+    let mut cases = Vec::with_capacity(ALL_EXAMPLES.len());
+    for example in ALL_EXAMPLES {
+        let start = std::time::Instant::now();
+        let outcome = build_artifact(
+            &micro_cli,
+            example,
+            &mut StageClock::new(),
+            &mut Transcript::disabled(),
+        )
+        .and_then(|artifact| {
+            let bytes = serde_json::to_vec(&artifact)?;
+            verify_artifact_bytes(&bytes)?;
+            Ok(bytes.len())
+        });
+        let seconds = start.elapsed().as_secs_f64();
+        cases.push(match outcome {
+            Ok(artifact_bytes) => MicroSuiteCase {
+                example: example_to_str(example).to_string(),
+                status: "ok".to_string(),
+                artifact_bytes,
+                seconds,
+                error: None,
+            },
+            Err(err) => MicroSuiteCase {
+                example: example_to_str(example).to_string(),
+                status: if limit_kind_of(&err) == Some(LimitKind::ImplementationCap) {
+                    "skipped"
+                } else {
+                    "failed"
+                }
+                .to_string(),
+                artifact_bytes: 0,
+                seconds,
+                error: Some(format!("{err:#}")),
+            },
+        });
+    }
+
+    let count = |status: &str| cases.iter().filter(|case| case.status == status).count();
+    let (passed, skipped) = (count("ok"), count("skipped"));
+    let failed = cases.len() - passed - skipped;
+    let report = MicroSuiteReport {
+        mode: "micro-suite".to_string(),
+        preset: preset_to_str(Preset::Micro).to_string(),
+        status: if failed == 0 { "ok" } else { "fail" }.to_string(),
+        passed,
+        skipped,
+        failed,
+        cases,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    if failed > 0 {
+        bail!("micro-suite: {failed} example(s) failed");
+    }
+    Ok(())
+}
+
+/// Generates every artifact a manifest lists in one process, sharing twiddles between entries
+/// over the same domain size. A failing entry is recorded in the summary and the rest still run.
+fn run_batch_generate(cli: &Cli) -> Result<()> {
+    let path = cli
+        .manifest
+        .as_deref()
+        .ok_or_else(|| anyhow!("--manifest is required"))?;
+    let raw = fs::read(path).with_context(|| format!("failed reading manifest {path}"))?;
+    let manifest: BatchManifest =
+        serde_json::from_slice(&raw).with_context(|| format!("failed parsing manifest {path}"))?;
+
+    let start = std::time::Instant::now();
+    let mut twiddles = TwiddleCache::default();
+    let mut entries = Vec::with_capacity(manifest.entries.len());
+    for (index, entry) in manifest.entries.into_iter().enumerate() {
+        let field = |value: Option<&serde_json::Value>| {
+            value
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string)
+        };
+        let output = field(entry.get("output"));
+        let example = field(
+            entry
+                .get("statement")
+                .and_then(|statement| statement.get("example")),
+        );
+        let entry_start = std::time::Instant::now();
+        let mut clock = StageClock::new();
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            batch_generate_entry(cli, entry, &mut twiddles, &mut clock)
+        }))
+        .unwrap_or_else(|_| Err(anyhow!("prover panicked")));
+        let seconds = entry_start.elapsed().as_secs_f64();
+        entries.push(match outcome {
+            Ok((example, proof_bytes_len)) => BatchEntryReport {
+                index,
+                example: Some(example_to_str(example).to_string()),
+                output,
+                status: "ok".to_string(),
+                seconds,
+                proof_bytes_len,
+                stages: Some(generate_timings(example, &clock.stages).stages),
+                error: None,
+            },
+            Err(err) => BatchEntryReport {
+                index,
+                example,
+                output,
+                status: "failed".to_string(),
+                seconds,
+                proof_bytes_len: None,
+                stages: None,
+                error: Some(format!("{err:#}")),
+            },
+        });
+    }
+
+    let failed = entries.iter().filter(|entry| entry.status != "ok").count();
+    let report = BatchGenerateReport {
+        mode: "batch-generate".to_string(),
+        manifest: path.to_string(),
+        status: if failed == 0 { "ok" } else { "fail" }.to_string(),
+        generated: entries.len() - failed,
+        failed,
+        twiddle_trees_computed: twiddles.len(),
+        seconds: start.elapsed().as_secs_f64(),
+        entries,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    if failed > 0 {
+        bail!("batch-generate: {failed} entry(ies) failed");
+    }
+    Ok(())
+}
+
+/// Generates and writes one manifest entry, returning its example and proof byte length.
+fn batch_generate_entry(
+    cli: &Cli,
+    entry: serde_json::Value,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+) -> Result<(Example, Option<usize>)> {
+    let entry: BatchEntry = serde_json::from_value(entry).context("invalid manifest entry")?;
+    let config = match &entry.pcs_config {
+        Some(wire) => pcs_config_from_wire(wire)?,
+        None => pcs_config_from_cli(cli)?,
+    };
+    let prove_mode = match &entry.prove_mode {
+        Some(mode) => {
+            prove_mode_from_str(mode).ok_or_else(|| anyhow!("unsupported prove mode {mode}"))?
+        }
+        None => cli.prove_mode,
+    };
+    let options = GenerateOptions {
+        prove_mode,
+        include_all_preprocessed_columns: cli.include_all_preprocessed_columns,
+        backend: cli.backend,
+        ..GenerateOptions::default()
+    };
+    let statement = Statement::from(entry.statement);
+    let example = statement.example();
+    let artifact = generate_artifact_timed(
+        example,
+        statement,
+        config,
+        &options,
+        twiddles,
+        clock,
+        &mut Transcript::disabled(),
+    )?;
+    let rendered = serde_json::to_string_pretty(&artifact)?;
+    fs::write(&entry.output, format!("{rendered}\n"))
+        .with_context(|| format!("failed writing artifact {}", entry.output))?;
+    clock.lap("artifact_write", "Artifact write");
+    Ok((example, artifact.proof_bytes_len))
+}
+
+fn golden_fixture_cli(args: &[&str]) -> Result<Cli> {
+    let mut argv = ["stwo-interop-rs", "--mode", "generate", "--artifact", "-"]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect::<Vec<_>>();
+    argv.extend(args.iter().map(|arg| arg.to_string()));
+    parse_cli(argv)
+}
+
+/// Regenerates a fixture in memory and hashes its canonical proof wire bytes.
+fn golden_fixture_hash(fixture_cli: &Cli) -> Result<String> {
+    let example = fixture_cli
+        .example
+        .ok_or_else(|| anyhow!("golden fixture is missing --example"))?;
+    let config = pcs_config_from_cli(fixture_cli)?;
+    let (_, proof) = prove_example(
+        config,
+        example,
+        fixture_cli,
+        fixture_cli.prove_mode,
+        fixture_cli.include_all_preprocessed_columns,
+    )?;
+    let proof_bytes = serde_json::to_vec(&proof_to_wire(&proof)?)?;
+    Ok(hex::encode(Blake2sHasher::hash(&proof_bytes).0))
+}
+
+fn read_golden_hashes(path: &str) -> Result<GoldenHashes> {
+    let raw =
+        fs::read_to_string(path).with_context(|| format!("failed reading golden hashes {path}"))?;
+    let golden: GoldenHashes = serde_json::from_str(&raw)?;
+    if golden.schema_version != GOLDEN_SCHEMA_VERSION {
+        bail!(
+            "unsupported golden hashes schema version {}",
+            golden.schema_version
+        );
+    }
+    if golden.upstream_commit != UPSTREAM_COMMIT {
+        bail!(
+            "golden hashes were recorded for upstream commit {}, expected {}",
+            golden.upstream_commit,
+            UPSTREAM_COMMIT
+        );
+    }
+    Ok(golden)
+}
+
+fn run_golden_check(cli: &Cli) -> Result<()> {
+    let golden = read_golden_hashes(&cli.golden_file)?;
+
+    let mut fixtures = Vec::with_capacity(GOLDEN_FIXTURES.len());
+    for (slug, args) in GOLDEN_FIXTURES {
+        let actual = golden_fixture_hash(&golden_fixture_cli(args)?)
+            .with_context(|| format!("failed regenerating golden fixture {slug}"))?;
+        let expected = golden
+            .entries
+            .get(*slug)
+            .map(|entry| entry.proof_wire_blake2s.clone());
+        let status = match &expected {
+            None => "missing",
+            Some(expected) if *expected == actual => "ok",
+            Some(_) => "drift",
+        };
+        fixtures.push(GoldenFixtureReport {
+            slug: slug.to_string(),
+            expected,
+            actual,
+            status: status.to_string(),
+        });
+    }
+
+    let failed = fixtures
+        .iter()
+        .filter(|fixture| fixture.status != "ok")
+        .map(|fixture| format!("{}={}", fixture.slug, fixture.status))
+        .collect::<Vec<_>>();
+    let report = GoldenReport {
+        mode: "golden-check".to_string(),
+        status: if failed.is_empty() { "ok" } else { "fail" }.to_string(),
+        golden_file: cli.golden_file.clone(),
+        fixtures,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+
+    if !failed.is_empty() {
+        bail!(
+            "golden proof hash drift: {} (rerun with --mode golden-update --golden-reason <text> if intended)",
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Feeds one fuzz input through the decode path `target` names: `artifact_parse` runs the whole
+/// verify path from raw artifact bytes as `--mode verify` does, `wire_to_proof` parses a proof
+/// wire and converts it, and `hex_decode` decodes `proof_bytes_hex` with and without a declared
+/// length. A regression input may be accepted or rejected; it must never panic.
+#[doc(hidden)]
+pub fn replay_fuzz_input(target: &str, data: &[u8]) -> Result<()> {
+    match target {
+        "artifact_parse" => verify_artifact_bytes(data).map(|_| ()),
+        "wire_to_proof" => {
+            let wire: ProofWire = serde_json::from_slice(data)?;
+            wire_to_proof(wire).map(|_| ())
+        }
+        "hex_decode" => {
+            let proof_bytes_hex = std::str::from_utf8(data)?;
+            decode_proof_bytes(proof_bytes_hex, None)?;
+            decode_proof_bytes(proof_bytes_hex, Some(proof_bytes_hex.len() / 2)).map(|_| ())
+        }
+        _ => bail!(
+            "unknown fuzz target {target}: expected one of {}",
+            FUZZ_REGRESSION_TARGETS.join(", ")
+        ),
+    }
+}
+
+/// Copies `--artifact` into `<regressions-dir>/<target>/` under a name derived from its hash, next
+/// to a `.note.json` recording where it came from and how it replays today. Inputs that still
+/// panic are refused: the corpus only holds fixed crashes, so `cargo test` stays green.
+fn run_add_regression(cli: &Cli) -> Result<()> {
+    let target = cli
+        .fuzz_target
+        .as_deref()
+        .ok_or_else(|| anyhow!("--fuzz-target is required for add-regression mode"))?;
+    if !FUZZ_REGRESSION_TARGETS.contains(&target) {
+        bail!(
+            "invalid --fuzz-target {target}: expected one of {}",
+            FUZZ_REGRESSION_TARGETS.join(", ")
+        );
+    }
+    let data = read_input(&cli.artifact)
+        .with_context(|| format!("failed reading regression input {}", cli.artifact))?;
+    if data.is_empty() {
+        bail!("regression input {} is empty", cli.artifact);
+    }
+    let outcome = match std::panic::catch_unwind(|| replay_fuzz_input(target, &data)) {
+        Ok(Ok(())) => "ok",
+        Ok(Err(_)) => "error",
+        Err(_) => bail!(
+            "regression input {} still panics in {target}; fix the panic before adding it",
+            cli.artifact
+        ),
+    };
+
+    let blake2s = hex::encode(Blake2sHasher::hash(&data).0);
+    let name = &blake2s[..FUZZ_REGRESSION_NAME_LEN];
+    let dir = std::path::Path::new(&cli.regressions_dir).join(target);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed creating regressions directory {}", dir.display()))?;
+    let path = dir.join(name);
+    fs::write(&path, &data)
+        .with_context(|| format!("failed writing regression input {}", path.display()))?;
+    let note = FuzzRegressionNote {
+        target: target.to_string(),
+        source: cli.artifact.clone(),
+        size: data.len(),
+        blake2s,
+        outcome: outcome.to_string(),
+    };
+    let note_path = dir.join(format!("{name}.note.json"));
+    fs::write(
+        &note_path,
+        format!("{}\n", serde_json::to_string_pretty(&note)?),
+    )
+    .with_context(|| format!("failed writing regression note {}", note_path.display()))?;
+
+    let report = AddRegressionReport {
+        mode: "add-regression".to_string(),
+        status: "ok".to_string(),
+        path: path.display().to_string(),
+        note,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+fn run_golden_update(cli: &Cli) -> Result<()> {
+    let reason = cli
+        .golden_reason
+        .as_ref()
+        .filter(|reason| !reason.trim().is_empty())
+        .ok_or_else(|| anyhow!("--golden-reason is required for golden-update mode"))?;
+    let previous = if std::path::Path::new(&cli.golden_file).exists() {
+        Some(read_golden_hashes(&cli.golden_file)?)
+    } else {
+        None
+    };
+
+    let mut entries = BTreeMap::new();
+    let mut fixtures = Vec::with_capacity(GOLDEN_FIXTURES.len());
+    for (slug, args) in GOLDEN_FIXTURES {
+        let actual = golden_fixture_hash(&golden_fixture_cli(args)?)
+            .with_context(|| format!("failed regenerating golden fixture {slug}"))?;
+        let previous_entry = previous
+            .as_ref()
+            .and_then(|golden| golden.entries.get(*slug))
+            .cloned();
+        let expected = previous_entry
+            .as_ref()
+            .map(|entry| entry.proof_wire_blake2s.clone());
+        let (entry, status) = match previous_entry {
+            Some(entry) if entry.proof_wire_blake2s == actual => (entry, "unchanged"),
+            Some(_) => (
+                GoldenEntry {
+                    proof_wire_blake2s: actual.clone(),
+                    reason: reason.clone(),
+                },
+                "updated",
+            ),
+            None => (
+                GoldenEntry {
+                    proof_wire_blake2s: actual.clone(),
+                    reason: reason.clone(),
+                },
+                "added",
+            ),
+        };
+        entries.insert(slug.to_string(), entry);
+        fixtures.push(GoldenFixtureReport {
+            slug: slug.to_string(),
+            expected,
+            actual,
+            status: status.to_string(),
+        });
+    }
+
+    let golden = GoldenHashes {
+        schema_version: GOLDEN_SCHEMA_VERSION,
+        upstream_commit: UPSTREAM_COMMIT.to_string(),
+        hash: "blake2s".to_string(),
+        entries,
+    };
+    let rendered = serde_json::to_string_pretty(&golden)?;
+    fs::write(&cli.golden_file, format!("{rendered}\n"))
+        .with_context(|| format!("failed writing golden hashes {}", cli.golden_file))?;
+
+    let report = GoldenReport {
+        mode: "golden-update".to_string(),
+        status: "ok".to_string(),
+        golden_file: cli.golden_file.clone(),
+        fixtures,
+    };
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+fn example_to_str(example: Example) -> &'static str {
+    match example {
+        Example::Blake => "blake",
+        Example::Plonk => "plonk",
+        Example::Poseidon => "poseidon",
+        Example::StateMachine => "state_machine",
+        Example::StateMachinePair => "state_machine_pair",
+        Example::WideFibonacci => "wide_fibonacci",
+        Example::Xor => "xor",
+    }
+}
+
+fn limit_kind_to_str(kind: LimitKind) -> &'static str {
+    match kind {
+        LimitKind::ProtocolBound => "protocol_bound",
+        LimitKind::ImplementationCap => "implementation_cap",
+        LimitKind::ResourceGuard => "resource_guard",
+    }
+}
+
+fn preset_to_str(preset: Preset) -> &'static str {
+    match preset {
+        Preset::Micro => "micro",
+    }
+}
+
+fn preset_from_str(value: &str) -> Option<Preset> {
+    match value {
+        "micro" => Some(Preset::Micro),
+        _ => None,
+    }
+}
+
+/// Overrides the PCS and example size parameters with the preset's values.
+///
+/// `micro` picks the smallest parameters each example accepts (one query, no PoW, log sizes at
+/// the per-example minimum) so every prove/verify branch runs in seconds natively and stays
+/// tractable under Miri or sanitizers. The resulting proofs carry no meaningful security.
+fn apply_preset(cli: &mut Cli, preset: Preset) {
+    match preset {
+        Preset::Micro => {
+            cli.pow_bits = 0;
+            cli.fri_log_blowup = 1;
+            cli.fri_log_last_layer = 0;
+            cli.fri_n_queries = 1;
+            cli.sm_log_n_rows = 1;
+            cli.sm_pair_log_n_rows = 2;
+            cli.blake_log_n_rows = 1;
+            cli.blake_n_rounds = 1;
+            cli.plonk_log_n_rows = 1;
+            cli.poseidon_log_n_instances = POSEIDON_LOG_INSTANCES_PER_ROW + 1;
+            cli.wf_log_n_rows = 1;
+            cli.wf_sequence_len = 2;
+            cli.xor_log_size = 1;
+            cli.xor_log_step = 0;
+            cli.xor_offset = 0;
+        }
+    }
+}
+
+fn prove_mode_to_str(mode: ProveMode) -> &'static str {
+    match mode {
+        ProveMode::Prove => "prove",
+        ProveMode::ProveEx => "prove_ex",
+    }
+}
+
+fn prove_mode_from_str(value: &str) -> Option<ProveMode> {
+    match value {
+        "prove" => Some(ProveMode::Prove),
+        "prove_ex" => Some(ProveMode::ProveEx),
+        _ => None,
+    }
+}
+
+fn prove_backend_to_str(backend: ProveBackend) -> &'static str {
+    match backend {
+        ProveBackend::Cpu => "cpu",
+        ProveBackend::Simd => "simd",
+    }
+}
+
+fn prove_backend_from_str(value: &str) -> Option<ProveBackend> {
+    match value {
+        "cpu" => Some(ProveBackend::Cpu),
+        "simd" => Some(ProveBackend::Simd),
+        _ => None,
+    }
+}
+
+fn summarize_timing(warmups: usize, repeats: usize, samples: Vec<f64>) -> Result<BenchTiming> {
+    if samples.is_empty() {
+        bail!("benchmark samples are empty");
+    }
+    let mut min_seconds = samples[0];
+    let mut max_seconds = samples[0];
+    let mut total = 0.0f64;
+    for sample in &samples {
+        min_seconds = min_seconds.min(*sample);
+        max_seconds = max_seconds.max(*sample);
+        total += *sample;
+    }
+    let avg_seconds = total / samples.len() as f64;
+    let stddev_seconds = if samples.len() > 1 {
+        let squares: f64 = samples
+            .iter()
+            .map(|sample| (sample - avg_seconds).powi(2))
+            .sum();
+        (squares / (samples.len() - 1) as f64).sqrt()
+    } else {
+        0.0
+    };
+    let mut sorted = samples.clone();
+    sorted.sort_by(f64::total_cmp);
+    Ok(BenchTiming {
+        warmups,
+        repeats,
+        avg_seconds,
+        min_seconds,
+        max_seconds,
+        median_seconds: percentile(&sorted, 0.5),
+        p90_seconds: percentile(&sorted, 0.9),
+        p99_seconds: percentile(&sorted, 0.99),
+        stddev_seconds,
+        samples_seconds: samples,
+    })
+}
+
+/// Percentile `q` (in `0.0..=1.0`) of non-empty ascending `sorted`, interpolating linearly
+/// between the two nearest ranks.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+fn parse_cli(args: Vec<String>) -> Result<Cli> {
+    let mut mode: Option<Mode> = None;
+    let mut example: Option<Example> = None;
+    let mut preset: Option<Preset> = None;
+    let mut artifact: Option<String> = None;
+    let mut stage_profile_out: Option<String> = None;
+    let mut timings_out: Option<String> = None;
+    let mut repro_bundle: Option<String> = None;
+    let mut dump_transcript: Option<String> = None;
+    let mut expect: Option<VerifyExpect> = None;
+    let mut expect_error: Option<String> = None;
+    let mut profile_out: Option<String> = None;
+    let mut bench_out: Option<String> = None;
+    let mut export_compress = ExportCompression::None;
+    let mut prove_mode = ProveMode::Prove;
+    let mut include_all_preprocessed_columns = false;
+    let mut proof_encoding = ProofEncoding::Json;
+    let mut proof_compression = ProofCompression::None;
+    let mut backend = ProveBackend::Cpu;
+    let mut hash = ProofHash::Blake2s;
+    let mut statement_seed: Option<u64> = None;
+    let mut schema = ArtifactSchema::V2;
+    let mut with_interaction = false;
+    let mut preprocessed_columns: Option<Vec<usize>> = None;
+
+    let mut pow_bits = 0u32;
+    let mut fri_log_blowup = 1u32;
+    let mut fri_log_last_layer = 0u32;
+    let mut fri_n_queries = 3usize;
+
+    let mut sm_log_n_rows = 5u32;
+    let mut sm_initial_0 = 9u32;
+    let mut sm_initial_1 = 3u32;
+    let mut sm_pair_log_n_rows = 5u32;
+
+    let mut blake_log_n_rows = 5u32;
+    let mut blake_n_rounds = 10u32;
+
+    let mut plonk_log_n_rows = 5u32;
+
+    let mut poseidon_log_n_instances = 8u32;
+
+    let mut wf_log_n_rows = 5u32;
+    let mut wf_sequence_len = 16u32;
+
+    let mut xor_log_size = 5u32;
+    let mut xor_log_step = 2u32;
+    let mut xor_offset = 3usize;
+
+    let mut bench_warmups = 1usize;
+    let mut bench_repeats = 5usize;
+    let mut scale_param: Option<String> = None;
+    let mut scale_points: Option<usize> = None;
+    let mut budget_seconds: Option<f64> = None;
+    let mut bench_format = BenchFormat::Json;
+    let mut baseline: Option<String> = None;
+    let mut candidate: Option<String> = None;
+    let mut max_prove_ratio: Option<f64> = None;
+    let mut max_verify_ratio: Option<f64> = None;
+    let mut max_proof_bytes_ratio: Option<f64> = None;
+    let mut max_peak_rss_ratio: Option<f64> = None;
+
+    let mut golden_file = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), GOLDEN_FILE_NAME);
+    let mut golden_reason: Option<String> = None;
+    let mut artifacts_dir: Option<String> = None;
+
+    let mut fuzz_target: Option<String> = None;
+    let mut tamper_kind: Option<String> = None;
+    let mut manifest: Option<String> = None;
+    let mut artifact_dir: Option<String> = None;
+    let mut jobs: Option<usize> = None;
+    let mut keep_going = false;
+    let mut regressions_dir = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), FUZZ_REGRESSIONS_DIR);
+
+    let mut i = 1usize;
+    while i < args.len() {
+        let flag = &args[i];
+        if !flag.starts_with("--") {
+            bail!("invalid argument {flag}");
+        }
+        if flag == "--keep-going" {
+            keep_going = true;
+            i += 1;
+            continue;
+        }
+        if flag == "--with-interaction" {
+            with_interaction = true;
+            i += 1;
+            continue;
+        }
+        if i + 1 >= args.len() {
+            bail!("missing value for {flag}");
+        }
+        let value = &args[i + 1];
+        i += 2;
+
+        match flag.as_str() {
+            "--mode" => {
+                mode = match value.as_str() {
+                    "generate" => Some(Mode::Generate),
+                    "verify" => Some(Mode::Verify),
+                    "bench" => Some(Mode::Bench),
+                    "bench-scale" => Some(Mode::BenchScale),
+                    "golden-check" => Some(Mode::GoldenCheck),
+                    "golden-update" => Some(Mode::GoldenUpdate),
+                    "capabilities" => Some(Mode::Capabilities),
+                    "micro-suite" => Some(Mode::MicroSuite),
+                    "cross-verify" => Some(Mode::CrossVerify),
+                    "add-regression" => Some(Mode::AddRegression),
+                    "conform" => Some(Mode::Conform),
+                    "inspect" => Some(Mode::Inspect),
+                    "tamper" => Some(Mode::Tamper),
+                    "batch-generate" => Some(Mode::BatchGenerate),
+                    "batch-verify" => Some(Mode::BatchVerify),
+                    "roundtrip" => Some(Mode::Roundtrip),
+                    "bench-compare" => Some(Mode::BenchCompare),
+                    "size-report" => Some(Mode::SizeReport),
+                    _ => bail!("invalid mode {value}"),
+                }
+            }
+            "--example" => {
+                example = match value.as_str() {
+                    "blake" => Some(Example::Blake),
+                    "plonk" => Some(Example::Plonk),
+                    "poseidon" => Some(Example::Poseidon),
+                    "state_machine" => Some(Example::StateMachine),
+                    "state_machine_pair" => Some(Example::StateMachinePair),
+                    "wide_fibonacci" => Some(Example::WideFibonacci),
+                    "xor" => Some(Example::Xor),
+                    _ => bail!("invalid example {value}"),
+                }
+            }
+            "--preset" => {
+                preset =
+                    Some(preset_from_str(value).ok_or_else(|| anyhow!("invalid preset {value}"))?)
+            }
+            "--artifact" => artifact = Some(value.clone()),
+            "--stage-profile-out" => stage_profile_out = Some(value.clone()),
+            "--timings-out" => timings_out = Some(value.clone()),
+            "--repro-bundle" => repro_bundle = Some(value.clone()),
+            "--dump-transcript" => dump_transcript = Some(value.clone()),
+            "--expect" => {
+                expect = Some(match value.as_str() {
+                    "success" => VerifyExpect::Success,
+                    "failure" => VerifyExpect::Failure,
+                    _ => bail!("invalid --expect {value}: expected success or failure"),
+                })
+            }
+            "--expect-error" => expect_error = Some(value.clone()),
+            "--profile-out" => profile_out = Some(value.clone()),
+            "--bench-out" => bench_out = Some(value.clone()),
+            "--export-compress" => {
+                export_compress = export_compression_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid export compression {value}"))?
+            }
+            "--prove-mode" => {
+                prove_mode = prove_mode_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid prove mode {value}"))?
+            }
+            "--include-all-preprocessed-columns" => {
+                include_all_preprocessed_columns = match value.as_str() {
+                    "0" | "false" => false,
+                    "1" | "true" => true,
+                    _ => bail!(
+                        "invalid boolean value for --include-all-preprocessed-columns: {value}"
+                    ),
+                };
+            }
+            "--preprocessed-columns" => {
+                preprocessed_columns = Some(
+                    value
+                        .split(',')
+                        .map(|column| column.trim().parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| anyhow!("invalid preprocessed columns {value}"))?,
+                );
+            }
+            "--proof-encoding" => {
+                proof_encoding = proof_encoding_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid proof encoding {value}"))?
+            }
+            "--compress" => {
+                proof_compression = proof_compression_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid proof compression {value}"))?
+            }
+            "--backend" => {
+                backend = prove_backend_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid backend {value}"))?
+            }
+            "--hash" => {
+                hash = proof_hash_from_str(value).ok_or_else(|| anyhow!("invalid hash {value}"))?
+            }
+            "--statement-seed" => statement_seed = Some(value.parse()?),
+            "--schema" => {
+                schema = artifact_schema_from_str(value)
+                    .ok_or_else(|| anyhow!("invalid schema {value}: expected 1 or 2"))?
+            }
+            "--pow-bits" => pow_bits = value.parse()?,
+            "--fri-log-blowup" => fri_log_blowup = value.parse()?,
+            "--fri-log-last-layer" => fri_log_last_layer = value.parse()?,
+            "--fri-n-queries" => fri_n_queries = value.parse()?,
+            "--sm-log-n-rows" => sm_log_n_rows = value.parse()?,
+            "--sm-initial-0" => sm_initial_0 = value.parse()?,
+            "--sm-initial-1" => sm_initial_1 = value.parse()?,
+            "--sm-pair-log-n-rows" => sm_pair_log_n_rows = value.parse()?,
+            "--blake-log-n-rows" => blake_log_n_rows = value.parse()?,
+            "--blake-n-rounds" => blake_n_rounds = value.parse()?,
+            "--plonk-log-n-rows" => plonk_log_n_rows = value.parse()?,
+            "--poseidon-log-n-instances" => poseidon_log_n_instances = value.parse()?,
+            "--wf-log-n-rows" => wf_log_n_rows = value.parse()?,
+            "--wf-sequence-len" => wf_sequence_len = value.parse()?,
+            "--xor-log-size" => xor_log_size = value.parse()?,
+            "--xor-log-step" => xor_log_step = value.parse()?,
+            "--xor-offset" => xor_offset = value.parse()?,
+            "--bench-warmups" => bench_warmups = value.parse()?,
+            "--bench-repeats" => bench_repeats = value.parse()?,
+            "--scale-param" => scale_param = Some(value.clone()),
+            "--scale-points" => scale_points = Some(value.parse()?),
+            "--budget-seconds" => budget_seconds = Some(value.parse()?),
+            "--bench-format" => {
+                bench_format = match value.as_str() {
+                    "json" => BenchFormat::Json,
+                    "csv" => BenchFormat::Csv,
+                    _ => bail!("invalid bench format {value}"),
+                }
+            }
+            "--baseline" => baseline = Some(value.clone()),
+            "--candidate" => candidate = Some(value.clone()),
+            "--max-prove-ratio" => max_prove_ratio = Some(value.parse()?),
+            "--max-verify-ratio" => max_verify_ratio = Some(value.parse()?),
+            "--max-proof-bytes-ratio" => max_proof_bytes_ratio = Some(value.parse()?),
+            "--max-peak-rss-ratio" => max_peak_rss_ratio = Some(value.parse()?),
+            "--golden-file" => golden_file = value.clone(),
+            "--golden-reason" => golden_reason = Some(value.clone()),
+            "--artifacts" => artifacts_dir = Some(value.clone()),
+            "--fuzz-target" => fuzz_target = Some(value.clone()),
+            "--tamper-kind" => tamper_kind = Some(value.clone()),
+            "--manifest" => manifest = Some(value.clone()),
+            "--artifact-dir" => artifact_dir = Some(value.clone()),
+            "--jobs" => jobs = Some(value.parse()?),
+            "--regressions-dir" => regressions_dir = value.clone(),
+            _ => bail!("unknown flag {flag}"),
+        }
+    }
+
+    let mode = mode.ok_or_else(|| anyhow!("--mode is required"))?;
+    let artifact = match mode {
+        Mode::BenchScale
+        | Mode::GoldenCheck
+        | Mode::GoldenUpdate
+        | Mode::Capabilities
+        | Mode::MicroSuite
+        | Mode::CrossVerify
+        | Mode::BatchGenerate
+        | Mode::BatchVerify
+        | Mode::Roundtrip
+        | Mode::BenchCompare
+        | Mode::SizeReport => artifact.unwrap_or_default(),
+        _ => artifact.ok_or_else(|| anyhow!("--artifact is required"))?,
+    };
+
+    let mut cli = Cli {
+        mode,
+        example,
+        artifact,
+        stage_profile_out,
+        timings_out,
+        repro_bundle,
+        dump_transcript,
+        expect,
+        expect_error,
+        profile_out,
+        bench_out,
+        export_compress,
+        prove_mode,
+        include_all_preprocessed_columns,
+        proof_encoding,
+        proof_compression,
+        backend,
+        hash,
+        statement_seed,
+        schema,
+        with_interaction,
+        preprocessed_columns,
+        pow_bits,
+        fri_log_blowup,
+        fri_log_last_layer,
+        fri_n_queries,
+        sm_log_n_rows,
+        sm_initial_0,
+        sm_initial_1,
+        sm_pair_log_n_rows,
+        blake_log_n_rows,
+        blake_n_rounds,
+        plonk_log_n_rows,
+        poseidon_log_n_instances,
+        wf_log_n_rows,
+        wf_sequence_len,
+        xor_log_size,
+        xor_log_step,
+        xor_offset,
+        bench_warmups,
+        bench_repeats,
+        scale_param,
+        scale_points,
+        budget_seconds,
+        bench_format,
+        baseline,
+        candidate,
+        max_prove_ratio,
+        max_verify_ratio,
+        max_proof_bytes_ratio,
+        max_peak_rss_ratio,
+        golden_file,
+        golden_reason,
+        artifacts_dir,
+        fuzz_target,
+        regressions_dir,
+        tamper_kind,
+        manifest,
+        artifact_dir,
+        jobs,
+        keep_going,
+    };
+    if let Some(preset) = preset {
+        apply_preset(&mut cli, preset);
+    }
+    Ok(cli)
+}
+
+fn pcs_config_from_cli(cli: &Cli) -> Result<PcsConfig> {
+    Ok(PcsConfig {
+        pow_bits: cli.pow_bits,
+        fri_config: FriConfig::new(
+            cli.fri_log_last_layer,
+            cli.fri_log_blowup,
+            cli.fri_n_queries,
+        ),
+    })
+}
+
+pub fn pcs_config_to_wire(config: PcsConfig) -> PcsConfigWire {
+    PcsConfigWire {
+        pow_bits: config.pow_bits,
+        fri_config: FriConfigWire {
+            log_blowup_factor: config.fri_config.log_blowup_factor,
+            log_last_layer_degree_bound: config.fri_config.log_last_layer_degree_bound,
+            n_queries: config.fri_config.n_queries as u64,
+        },
+    }
+}
+
+pub fn pcs_config_from_wire(wire: &PcsConfigWire) -> Result<PcsConfig> {
+    let n_queries: usize = wire
+        .fri_config
+        .n_queries
+        .try_into()
+        .map_err(|_| anyhow!("fri n_queries out of range"))?;
+    Ok(PcsConfig {
+        pow_bits: wire.pow_bits,
+        fri_config: FriConfig::new(
+            wire.fri_config.log_last_layer_degree_bound,
+            wire.fri_config.log_blowup_factor,
+            n_queries,
+        ),
+    })
+}
+
+fn checked_m31(value: u32) -> Result<M31> {
+    if value >= P {
+        bail!("non-canonical m31 value {value}");
+    }
+    Ok(M31::from_u32_unchecked(value))
+}
+
+fn qm31_to_wire(value: SecureField) -> Qm31Wire {
+    let arr = value.to_m31_array();
+    [arr[0].0, arr[1].0, arr[2].0, arr[3].0]
+}
+
+fn qm31_from_wire(value: Qm31Wire) -> Result<SecureField> {
+    Ok(QM31::from_m31(
+        checked_m31(value[0])?,
+        checked_m31(value[1])?,
+        checked_m31(value[2])?,
+        checked_m31(value[3])?,
+    ))
+}
+
+pub fn proof_to_wire(proof: &StarkProof<Blake2sMerkleHasher>) -> Result<ProofWire> {
+    proof_to_wire_with::<Blake2sMerkleChannel>(proof)
+}
+
+fn proof_to_wire_with<MC: InteropMerkle>(proof: &StarkProof<MC::H>) -> Result<ProofWire> {
+    let pcs_proof = &proof.0;
+
+    let commitments = pcs_proof
+        .commitments
+        .iter()
+        .map(MC::hash_to_wire)
+        .collect::<Vec<_>>();
+
+    let sampled_values = pcs_proof
+        .sampled_values
+        .0
+        .iter()
+        .map(|tree| {
+            tree.iter()
+                .map(|col| col.iter().copied().map(qm31_to_wire).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let decommitments = pcs_proof
+        .decommitments
+        .0
+        .iter()
+        .map(|decommitment| MerkleDecommitmentWire {
+            hash_witness: decommitment
+                .hash_witness
+                .iter()
+                .map(MC::hash_to_wire)
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let queried_values = pcs_proof
+        .queried_values
+        .0
+        .iter()
+        .map(|tree| {
+            tree.iter()
+                .map(|col| col.iter().map(|value| value.0).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let first_layer = fri_layer_to_wire::<MC>(&pcs_proof.fri_proof.first_layer);
+    let inner_layers = pcs_proof
+        .fri_proof
+        .inner_layers
+        .iter()
+        .map(fri_layer_to_wire::<MC>)
+        .collect::<Vec<_>>();
+    let last_layer_poly = pcs_proof
+        .fri_proof
+        .last_layer_poly
+        .iter()
+        .copied()
+        .map(qm31_to_wire)
+        .collect::<Vec<_>>();
+
+    Ok(ProofWire {
+        config: pcs_config_to_wire(pcs_proof.config),
+        commitments,
+        sampled_values,
+        decommitments,
+        queried_values,
+        proof_of_work: pcs_proof.proof_of_work,
+        fri_proof: FriProofWire {
+            first_layer,
+            inner_layers,
+            last_layer_poly,
+        },
+    })
+}
+
+pub fn wire_to_proof(wire: ProofWire) -> Result<StarkProof<Blake2sMerkleHasher>> {
+    wire_to_proof_with::<Blake2sMerkleChannel>(wire)
+}
+
+fn wire_to_proof_with<MC: InteropMerkle>(wire: ProofWire) -> Result<StarkProof<MC::H>> {
+    let config = pcs_config_from_wire(&wire.config)?;
+
+    let commitments = wire
+        .commitments
+        .into_iter()
+        .map(MC::hash_from_wire)
+        .collect::<Vec<_>>();
+
+    let sampled_values = wire
+        .sampled_values
+        .into_iter()
+        .map(|tree| {
+            tree.into_iter()
+                .map(|col| {
+                    col.into_iter()
+                        .map(qm31_from_wire)
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let decommitments = wire
+        .decommitments
+        .into_iter()
+        .map(|decommitment| MerkleDecommitmentLifted::<MC::H> {
+            hash_witness: decommitment
+                .hash_witness
+                .into_iter()
+                .map(MC::hash_from_wire)
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let queried_values = wire
+        .queried_values
+        .into_iter()
+        .map(|tree| {
+            tree.into_iter()
+                .map(|col| col.into_iter().map(checked_m31).collect::<Result<Vec<_>>>())
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let fri_proof = FriProof {
+        first_layer: wire_to_fri_layer::<MC>(wire.fri_proof.first_layer)?,
+        inner_layers: wire
+            .fri_proof
+            .inner_layers
+            .into_iter()
+            .map(wire_to_fri_layer::<MC>)
+            .collect::<Result<Vec<_>>>()?,
+        last_layer_poly: LinePoly::new(
+            wire.fri_proof
+                .last_layer_poly
+                .into_iter()
+                .map(qm31_from_wire)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+    };
+
+    Ok(StarkProof(CommitmentSchemeProof {
+        config,
+        commitments: TreeVec::new(commitments),
+        sampled_values: TreeVec::new(sampled_values),
+        decommitments: TreeVec::new(decommitments),
+        queried_values: TreeVec::new(queried_values),
+        proof_of_work: wire.proof_of_work,
+        fri_proof,
+    }))
+}
+
+fn fri_layer_to_wire<MC: InteropMerkle>(layer: &FriLayerProof<MC::H>) -> FriLayerWire {
+    FriLayerWire {
+        fri_witness: layer
+            .fri_witness
+            .iter()
+            .copied()
+            .map(qm31_to_wire)
+            .collect(),
+        decommitment: MerkleDecommitmentWire {
+            hash_witness: layer
+                .decommitment
+                .hash_witness
+                .iter()
+                .map(MC::hash_to_wire)
+                .collect(),
+        },
+        commitment: MC::hash_to_wire(&layer.commitment),
+    }
+}
+
+fn wire_to_fri_layer<MC: InteropMerkle>(layer: FriLayerWire) -> Result<FriLayerProof<MC::H>> {
+    Ok(FriLayerProof {
+        fri_witness: layer
+            .fri_witness
+            .into_iter()
+            .map(qm31_from_wire)
+            .collect::<Result<Vec<_>>>()?,
+        decommitment: MerkleDecommitmentLifted::<MC::H> {
+            hash_witness: layer
+                .decommitment
+                .hash_witness
+                .into_iter()
+                .map(MC::hash_from_wire)
+                .collect(),
+        },
+        commitment: MC::hash_from_wire(layer.commitment),
+    })
+}
+
+fn state_machine_statement_to_wire(statement: StateMachineStatement) -> StateMachineStatementWire {
+    StateMachineStatementWire {
+        public_input: [
+            [
+                statement.public_input[0][0].0,
+                statement.public_input[0][1].0,
+            ],
+            [
+                statement.public_input[1][0].0,
+                statement.public_input[1][1].0,
+            ],
+        ],
+        stmt0: StateMachineStmt0Wire {
+            n: statement.stmt0_n,
+            m: statement.stmt0_m,
+        },
+        stmt1: StateMachineStmt1Wire {
+            x_axis_claimed_sum: qm31_to_wire(statement.stmt1_x_axis_claimed_sum),
+            y_axis_claimed_sum: qm31_to_wire(statement.stmt1_y_axis_claimed_sum),
+        },
+        with_interaction: statement.with_interaction.then_some(true),
+    }
+}
+
+fn state_machine_statement_from_wire(
+    wire: &StateMachineStatementWire,
+) -> Result<StateMachineStatement> {
+    Ok(StateMachineStatement {
+        public_input: [
+            [
+                checked_m31(wire.public_input[0][0])?,
+                checked_m31(wire.public_input[0][1])?,
+            ],
+            [
+                checked_m31(wire.public_input[1][0])?,
+                checked_m31(wire.public_input[1][1])?,
+            ],
+        ],
+        stmt0_n: wire.stmt0.n,
+        stmt0_m: wire.stmt0.m,
+        with_interaction: wire.with_interaction.unwrap_or(false),
+        stmt1_x_axis_claimed_sum: qm31_from_wire(wire.stmt1.x_axis_claimed_sum)?,
+        stmt1_y_axis_claimed_sum: qm31_from_wire(wire.stmt1.y_axis_claimed_sum)?,
+    })
+}
+
+fn xor_statement_to_wire(statement: XorStatement) -> Result<XorStatementWire> {
+    Ok(XorStatementWire {
+        log_size: statement.log_size,
+        log_step: statement.log_step,
+        offset: statement.offset as u64,
+    })
+}
+
+fn xor_statement_from_wire(wire: &XorStatementWire) -> Result<XorStatement> {
+    let offset: usize = wire
+        .offset
+        .try_into()
+        .map_err(|_| anyhow!("xor offset out of range"))?;
+    Ok(XorStatement {
+        log_size: wire.log_size,
+        log_step: wire.log_step,
+        offset,
+        preprocessed_columns: None,
+    })
+}
+
+fn wide_fibonacci_statement_to_wire(
+    statement: WideFibonacciStatement,
+) -> WideFibonacciStatementWire {
+    WideFibonacciStatementWire {
+        log_n_rows: statement.log_n_rows,
+        sequence_len: statement.sequence_len,
+    }
+}
+
+fn wide_fibonacci_statement_from_wire(
+    wire: &WideFibonacciStatementWire,
+) -> Result<WideFibonacciStatement> {
+    Ok(WideFibonacciStatement {
+        log_n_rows: wire.log_n_rows,
+        sequence_len: wire.sequence_len,
+    })
+}
+
+fn plonk_statement_to_wire(statement: PlonkStatement) -> PlonkStatementWire {
+    PlonkStatementWire {
+        log_n_rows: statement.log_n_rows,
+    }
+}
+
+fn plonk_statement_from_wire(wire: &PlonkStatementWire) -> Result<PlonkStatement> {
+    Ok(PlonkStatement {
+        log_n_rows: wire.log_n_rows,
+        preprocessed_columns: None,
+    })
+}
+
+fn poseidon_statement_to_wire(statement: PoseidonStatement) -> PoseidonStatementWire {
+    PoseidonStatementWire {
+        log_n_instances: statement.log_n_instances,
+    }
+}
+
+fn poseidon_statement_from_wire(wire: &PoseidonStatementWire) -> Result<PoseidonStatement> {
+    Ok(PoseidonStatement {
+        log_n_instances: wire.log_n_instances,
+    })
+}
+
+fn blake_statement_to_wire(statement: BlakeStatement) -> BlakeStatementWire {
+    BlakeStatementWire {
+        log_n_rows: statement.log_n_rows,
+        n_rounds: statement.n_rounds,
+    }
+}
+
+fn blake_statement_from_wire(wire: &BlakeStatementWire) -> Result<BlakeStatement> {
+    Ok(BlakeStatement {
+        log_n_rows: wire.log_n_rows,
+        n_rounds: wire.n_rounds,
+    })
+}
+
+fn prove_example(
+    config: PcsConfig,
+    example: Example,
+    cli: &Cli,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+) -> Result<(ExampleStatement, StarkProof<Blake2sMerkleHasher>)> {
+    prove_example_timed(
+        config,
+        example,
+        cli,
+        prove_mode,
+        include_all_preprocessed_columns,
+        &mut TwiddleCache::default(),
+        &mut StageClock::new(),
+    )
+}
+
+fn prove_example_timed(
+    config: PcsConfig,
+    example: Example,
+    cli: &Cli,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+) -> Result<(ExampleStatement, StarkProof<Blake2sMerkleHasher>)> {
+    prove_statement_on(
+        cli.backend,
+        statement_from_cli(cli, example),
+        config,
+        prove_mode,
+        include_all_preprocessed_columns,
+        false,
+        None,
+        None,
+        twiddles,
+        clock,
+        &mut Transcript::disabled(),
+    )
+}
+
+/// Proves `statement` under `options.hash` and returns the proof in wire form, the one
+/// representation shared by both hashes. Blake3 Merkle ops exist only on the CPU backend.
+fn prove_statement_wire(
+    statement: Statement,
+    config: PcsConfig,
+    options: &GenerateOptions,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(ExampleStatement, ProofWire)> {
+    let preprocessed_columns = options
+        .preprocessed_columns
+        .as_deref()
+        .map(|columns| PreprocessedColumns::select(statement.example(), columns))
+        .transpose()?;
+    match options.hash {
+        ProofHash::Blake2s => {
+            let (statement, proof) = prove_statement_on(
+                options.backend,
+                statement,
+                config,
+                options.prove_mode,
+                options.include_all_preprocessed_columns,
+                options.with_interaction,
+                preprocessed_columns,
+                options.trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((statement, proof_to_wire(&proof)?))
+        }
+        ProofHash::Blake3 => {
+            if options.backend != ProveBackend::Cpu {
+                bail!("--hash blake3 requires --backend cpu");
+            }
+            let (statement, proof) = prove_statement::<CpuBackend, Blake3MerkleChannel>(
+                statement,
+                config,
+                options.prove_mode,
+                options.include_all_preprocessed_columns,
+                options.with_interaction,
+                preprocessed_columns,
+                options.trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((
+                statement,
+                proof_to_wire_with::<Blake3MerkleChannel>(&proof)?,
+            ))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prove_statement_on(
+    backend: ProveBackend,
+    statement: Statement,
+    config: PcsConfig,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    with_interaction: bool,
+    preprocessed_columns: Option<PreprocessedColumns>,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(ExampleStatement, StarkProof<Blake2sMerkleHasher>)> {
+    match backend {
+        ProveBackend::Cpu => prove_statement::<CpuBackend, Blake2sMerkleChannel>(
+            statement,
+            config,
+            prove_mode,
+            include_all_preprocessed_columns,
+            with_interaction,
+            preprocessed_columns,
+            trace_perturbation,
+            twiddles,
+            clock,
+            transcript,
+        ),
+        ProveBackend::Simd => prove_statement::<SimdBackend, Blake2sMerkleChannel>(
+            statement,
+            config,
+            prove_mode,
+            include_all_preprocessed_columns,
+            with_interaction,
+            preprocessed_columns,
+            trace_perturbation,
+            twiddles,
+            clock,
+            transcript,
+        ),
+    }
+}
+
+/// Proves `statement`. `with_interaction` only applies to the state-machine example and
+/// `preprocessed_columns` only to plonk and xor.
+#[allow(clippy::too_many_arguments)]
+fn prove_statement<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    statement: Statement,
+    config: PcsConfig,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    with_interaction: bool,
+    preprocessed_columns: Option<PreprocessedColumns>,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(ExampleStatement, StarkProof<MC::H>)> {
+    if trace_perturbation.is_some()
+        && !matches!(
+            statement,
+            Statement::Plonk { .. }
+                | Statement::StateMachinePair { .. }
+                | Statement::WideFibonacci { .. }
+                | Statement::Xor { .. }
+        )
+    {
+        bail!(
+            "trace perturbation is only supported for the plonk, state_machine_pair, \
+             wide_fibonacci and xor examples"
+        );
+    }
+    match statement {
+        Statement::Blake {
+            log_n_rows,
+            n_rounds,
+        } => {
+            let statement = BlakeStatement {
+                log_n_rows,
+                n_rounds,
+            };
+            let (statement, proof) = blake_prove::<B, MC>(
+                config,
+                statement,
+                prove_mode,
+                include_all_preprocessed_columns,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::Blake(statement), proof))
+        }
+        Statement::Plonk { log_n_rows } => {
+            let statement = PlonkStatement {
+                log_n_rows,
+                preprocessed_columns,
+            };
+            let (statement, proof) = plonk_prove::<B, MC>(
+                config,
+                statement,
+                prove_mode,
+                include_all_preprocessed_columns,
+                trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::Plonk(statement), proof))
+        }
+        Statement::Poseidon { log_n_instances } => {
+            let statement = PoseidonStatement { log_n_instances };
+            let (statement, proof) = poseidon_prove::<B, MC>(
+                config,
+                statement,
+                prove_mode,
+                include_all_preprocessed_columns,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::Poseidon(statement), proof))
+        }
+        Statement::StateMachine {
+            log_n_rows,
+            initial_state,
+        } => {
+            let initial_state = [
+                checked_m31(initial_state[0])?,
+                checked_m31(initial_state[1])?,
+            ];
+            let (statement, proof) = state_machine_prove::<B, MC>(
+                config,
+                log_n_rows,
+                initial_state,
+                prove_mode,
+                include_all_preprocessed_columns,
+                with_interaction,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::StateMachine(statement), proof))
+        }
+        Statement::StateMachinePair {
+            log_n_rows,
+            initial_state,
+        } => {
+            let initial_state = [
+                checked_m31(initial_state[0])?,
+                checked_m31(initial_state[1])?,
+            ];
+            let (statement, proof) = state_machine_pair_prove::<B, MC>(
+                config,
+                log_n_rows,
+                initial_state,
+                prove_mode,
+                include_all_preprocessed_columns,
+                trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::StateMachinePair(statement), proof))
+        }
+        Statement::WideFibonacci {
+            log_n_rows,
+            sequence_len,
+        } => {
+            let statement = WideFibonacciStatement {
+                log_n_rows,
+                sequence_len,
+            };
+            let (statement, proof) = wide_fibonacci_prove::<B, MC>(
+                config,
+                statement,
+                prove_mode,
+                include_all_preprocessed_columns,
+                trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::WideFibonacci(statement), proof))
+        }
+        Statement::Xor {
+            log_size,
+            log_step,
+            offset,
+        } => {
+            let statement = XorStatement {
+                log_size,
+                log_step,
+                offset,
+                preprocessed_columns,
+            };
+            let (statement, proof) = xor_prove::<B, MC>(
+                config,
+                statement,
+                prove_mode,
+                include_all_preprocessed_columns,
+                trace_perturbation,
+                twiddles,
+                clock,
+                transcript,
+            )?;
+            Ok((ExampleStatement::Xor(statement), proof))
+        }
+    }
+}
+
+fn component_metadata(component: &dyn Component) -> ComponentMetadataWire {
+    let mask_points = component.mask_points(
+        SECURE_FIELD_CIRCLE_GEN,
+        component.max_constraint_log_degree_bound(),
+    );
+    ComponentMetadataWire {
+        n_constraints: component.n_constraints(),
+        max_constraint_log_degree_bound: component.max_constraint_log_degree_bound(),
+        preprocessed_column_indices: component.preprocessed_column_indices(),
+        mask_shape: mask_points
+            .iter()
+            .map(|tree| tree.iter().map(Vec::len).collect())
+            .collect(),
+    }
+}
+
+/// Metadata of the component the verifier builds for `statement`, mirroring `verify_example`.
+/// `None` for multi-component examples, whose `components` carry one record each.
+fn example_component_metadata(statement: ExampleStatement) -> Option<ComponentMetadataWire> {
+    Some(match statement {
+        ExampleStatement::Blake(statement) => component_metadata(&BlakeComponent { statement }),
+        ExampleStatement::Plonk(statement) => component_metadata(&PlonkComponent { statement }),
+        ExampleStatement::Poseidon(statement) => {
+            component_metadata(&PoseidonComponent { statement })
+        }
+        ExampleStatement::StateMachine(statement) => {
+            // The lookup elements are drawn mid-transcript; they do not change the shape the
+            // metadata describes.
+            let elements = StateMachineElements {
+                z: SecureField::zero(),
+                alpha: SecureField::zero(),
+            };
+            component_metadata(&state_machine_component(statement, elements))
+        }
+        ExampleStatement::StateMachinePair(_) => return None,
+        ExampleStatement::WideFibonacci(statement) => {
+            component_metadata(&WideFibonacciComponent { statement })
+        }
+        ExampleStatement::Xor(statement) => component_metadata(&XorComponent { statement }),
+    })
+}
+
+/// The `--preprocessed-columns` subset `statement` was proved with, as the artifact records it.
+fn example_preprocessed_columns(statement: ExampleStatement) -> Option<Vec<usize>> {
+    let columns = match statement {
+        ExampleStatement::Plonk(statement) => statement.preprocessed_columns,
+        ExampleStatement::Xor(statement) => statement.preprocessed_columns,
+        _ => None,
+    };
+    columns.map(PreprocessedColumns::indices)
+}
+
+/// Rebuilds the subset an artifact's `preprocessed_columns` records into its statement, so
+/// verify samples the same preprocessed columns the prover did.
+fn with_preprocessed_columns(
+    statement: ExampleStatement,
+    columns: &[usize],
+) -> Result<ExampleStatement> {
+    Ok(match statement {
+        ExampleStatement::Plonk(mut statement) => {
+            statement.preprocessed_columns =
+                Some(PreprocessedColumns::select(Example::Plonk, columns)?);
+            ExampleStatement::Plonk(statement)
+        }
+        ExampleStatement::Xor(mut statement) => {
+            statement.preprocessed_columns =
+                Some(PreprocessedColumns::select(Example::Xor, columns)?);
+            ExampleStatement::Xor(statement)
+        }
+        _ => bail!("preprocessed_columns is only written for plonk and xor artifacts"),
+    })
+}
+
+/// Checks the declared `components` against the ones the statement assigns: same names, in the
+/// same order, with the same trace sizes and claimed sums. Shapes are checked at the
+/// `component_metadata` stage.
+fn check_components(declared: &[ComponentWire], expected: &[ComponentWire]) -> Result<()> {
+    let names = |components: &[ComponentWire]| {
+        components
+            .iter()
+            .map(|component| component.name.clone())
+            .collect::<Vec<_>>()
+    };
+    if names(declared) != names(expected) {
+        bail!(
+            "components: artifact declares {:?}, statement has {:?}",
+            names(declared),
+            names(expected)
+        );
+    }
+    for (declared, expected) in declared.iter().zip(expected) {
+        if declared.log_n_rows != expected.log_n_rows {
+            bail!(
+                "component {}: artifact declares log_n_rows {}, statement has {}",
+                expected.name,
+                declared.log_n_rows,
+                expected.log_n_rows
+            );
+        }
+        if declared.claimed_sum != expected.claimed_sum {
+            bail!(
+                "component {}: claimed_sum does not match the statement",
+                expected.name
+            );
+        }
+    }
+    Ok(())
+}
+
+fn check_component_metadata(
+    declared: &ComponentMetadataWire,
+    expected: &ComponentMetadataWire,
+) -> Result<()> {
+    fn check<T: PartialEq + std::fmt::Debug>(
+        field: &str,
+        declared: &T,
+        expected: &T,
+    ) -> Result<()> {
+        if declared != expected {
+            bail!(
+                "{COMPONENT_METADATA_MISMATCH}: {field}: artifact declares {declared:?}, verifier component has {expected:?}"
+            );
+        }
+        Ok(())
+    }
+    check(
+        "n_constraints",
+        &declared.n_constraints,
+        &expected.n_constraints,
+    )?;
+    check(
+        "max_constraint_log_degree_bound",
+        &declared.max_constraint_log_degree_bound,
+        &expected.max_constraint_log_degree_bound,
+    )?;
+    check(
+        "preprocessed_column_indices",
+        &declared.preprocessed_column_indices,
+        &expected.preprocessed_column_indices,
+    )?;
+    check("mask_shape", &declared.mask_shape, &expected.mask_shape)
+}
+
+fn verify_example<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: ExampleStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    match statement {
+        ExampleStatement::Blake(s) => blake_verify::<MC>(config, s, proof, transcript),
+        ExampleStatement::Plonk(s) => plonk_verify::<MC>(config, s, proof, transcript),
+        ExampleStatement::Poseidon(s) => poseidon_verify::<MC>(config, s, proof, transcript),
+        ExampleStatement::StateMachine(s) => {
+            state_machine_verify::<MC>(config, s, proof, transcript)
+        }
+        ExampleStatement::StateMachinePair(s) => {
+            state_machine_pair_verify::<MC>(config, s, proof, transcript)
+        }
+        ExampleStatement::WideFibonacci(s) => {
+            wide_fibonacci_verify::<MC>(config, s, proof, transcript)
+        }
+        ExampleStatement::Xor(s) => xor_verify::<MC>(config, s, proof, transcript),
+    }
+}
+
+fn proof_metrics_from_proof(proof: &StarkProof<Blake2sMerkleHasher>) -> Result<BenchProofMetrics> {
+    proof_metrics_from_wire(&proof_to_wire(proof)?)
+}
+
+fn proof_metrics_from_wire(wire: &ProofWire) -> Result<BenchProofMetrics> {
+    let proof_wire_bytes = serde_json::to_vec(wire)?.len();
+    let trace_decommit_hashes: usize = wire
+        .decommitments
+        .iter()
+        .map(|decommitment| decommitment.hash_witness.len())
+        .sum();
+    let fri_decommit_hashes_total = wire.fri_proof.first_layer.decommitment.hash_witness.len()
+        + wire
+            .fri_proof
+            .inner_layers
+            .iter()
+            .map(|layer| layer.decommitment.hash_witness.len())
+            .sum::<usize>();
+
+    Ok(BenchProofMetrics {
+        proof_wire_bytes,
+        commitments_count: wire.commitments.len(),
+        decommitments_count: wire.decommitments.len(),
+        trace_decommit_hashes,
+        fri_inner_layers_count: wire.fri_proof.inner_layers.len(),
+        fri_first_layer_witness_len: wire.fri_proof.first_layer.fri_witness.len(),
+        fri_last_layer_poly_len: wire.fri_proof.last_layer_poly.len(),
+        fri_decommit_hashes_total,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn state_machine_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    log_n_rows: u32,
+    initial_state: [M31; 2],
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    with_interaction: bool,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(StateMachineStatement, StarkProof<MC::H>)> {
+    check_param_limit(Example::StateMachine, "log_n_rows", log_n_rows.into())?;
+    check_backend_log_size::<B>(log_n_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    // The logup constraints have degree 4, so the composition polynomial is twice as large.
+    let log_expand = if with_interaction { 2 } else { 1 };
+    let twiddles = twiddles.get::<B>(log_n_rows + config.fri_config.log_blowup_factor + log_expand);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let preprocessed = gen_is_first(log_n_rows)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![B::eval(cpu_eval(log_n_rows, preprocessed))]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let [trace0, trace1] = gen_trace(log_n_rows, initial_state, 0)?;
+    clock.lap("main_trace_generation", "Main trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![
+        B::eval(cpu_eval(log_n_rows, trace0)),
+        B::eval(cpu_eval(log_n_rows, trace1)),
+    ]);
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    let stmt0_n = log_n_rows;
+    let stmt0_m = log_n_rows - 1;
+    mix_state_machine_stmt0(&mut channel, stmt0_n, stmt0_m);
+    transcript.record("stmt0_mix", &channel);
+
+    let elements = StateMachineElements {
+        z: channel.draw_secure_felt(),
+        alpha: channel.draw_secure_felt(),
+    };
+    transcript.record("lookup_elements_draw", &channel);
+
+    let mut statement = prepare_state_machine_statement(log_n_rows, initial_state, elements)?;
+    statement.with_interaction = with_interaction;
+    if with_interaction {
+        let interaction = gen_state_machine_interaction_trace(log_n_rows, initial_state, elements)?;
+        clock.lap(
+            "interaction_trace_generation",
+            "Interaction trace generation",
+        );
+        let mut builder = scheme.tree_builder();
+        builder.extend_evals(
+            interaction
+                .into_iter()
+                .map(|col| B::eval(cpu_eval(log_n_rows, col)))
+                .collect(),
+        );
+        builder.commit(&mut channel);
+        transcript.record("interaction_commit", &channel);
+        clock.lap("interaction_commit", "Interaction commit");
+    }
+    mix_state_machine_public_input(&mut channel, &statement.public_input);
+    transcript.record("public_input_mix", &channel);
+    mix_state_machine_stmt1(
+        &mut channel,
+        statement.stmt1_x_axis_claimed_sum,
+        statement.stmt1_y_axis_claimed_sum,
+    );
+    transcript.record("stmt1_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = state_machine_component(statement, elements);
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+/// Checks that a state-machine proof commits the trees its statement declares: the interaction
+/// tree exactly when `with_interaction` is set.
+fn check_state_machine_commitments(
+    statement: StateMachineStatement,
+    n_commitments: usize,
+) -> Result<()> {
+    let expected = if statement.with_interaction {
+        STATE_MACHINE_INTERACTION_COMMITMENTS
+    } else {
+        STATE_MACHINE_COMMITMENTS
+    };
+    if n_commitments != expected {
+        bail!(
+            "invalid proof shape: statement declares with_interaction={}, expected {expected} commitments, proof has {n_commitments}",
+            statement.with_interaction
+        );
+    }
+    Ok(())
+}
+
+fn state_machine_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: StateMachineStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    check_param_limit(
+        Example::StateMachine,
+        "log_n_rows",
+        statement.stmt0_n.into(),
+    )?;
+    if statement.stmt0_m != statement.stmt0_n - 1 {
+        bail!("invalid statement m");
+    }
+    check_state_machine_commitments(statement, proof.0.commitments.len())?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[statement.stmt0_n], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    commitment_scheme.commit(c1, &[statement.stmt0_n, statement.stmt0_n], &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_state_machine_stmt0(&mut channel, statement.stmt0_n, statement.stmt0_m);
+    transcript.record("stmt0_mix", &channel);
+    let elements = StateMachineElements {
+        z: channel.draw_secure_felt(),
+        alpha: channel.draw_secure_felt(),
+    };
+    transcript.record("lookup_elements_draw", &channel);
+    verify_state_machine_statement(statement, elements)?;
+    if statement.with_interaction {
+        let c2 = proof.0.commitments[2];
+        commitment_scheme.commit(c2, &[statement.stmt0_n; 4], &mut channel);
+        transcript.record("interaction_commit", &channel);
+    }
+    mix_state_machine_public_input(&mut channel, &statement.public_input);
+    transcript.record("public_input_mix", &channel);
+    mix_state_machine_stmt1(
+        &mut channel,
+        statement.stmt1_x_axis_claimed_sum,
+        statement.stmt1_y_axis_claimed_sum,
+    );
+    transcript.record("stmt1_mix", &channel);
+
+    let component = state_machine_component(statement, elements);
+
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("state_machine verify failed"))
+}
+
+/// Proves the x-axis and y-axis state-machine components in one `prove` call. Both trees hold
+/// the x-axis columns first: `is_first` of each size, then the `2^n`-row x-axis trace and the
+/// `2^m`-row y-axis trace, which starts from the x-axis trace's final state.
+#[allow(clippy::too_many_arguments)]
+fn state_machine_pair_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    log_n_rows: u32,
+    initial_state: [M31; 2],
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(StateMachineStatement, StarkProof<MC::H>)> {
+    check_param_limit(Example::StateMachinePair, "log_n_rows", log_n_rows.into())?;
+    let log_m_rows = log_n_rows - 1;
+    check_backend_log_size::<B>(log_m_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles = twiddles.get::<B>(log_n_rows + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let is_first_x = gen_is_first(log_n_rows)?;
+    let is_first_y = gen_is_first(log_m_rows)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![
+        B::eval(cpu_eval(log_n_rows, is_first_x)),
+        B::eval(cpu_eval(log_m_rows, is_first_y)),
+    ]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let (intermediate, _) = transition_states(log_n_rows, initial_state)?;
+    let [x_axis0, x_axis1] = gen_trace(log_n_rows, initial_state, 0)?;
+    let [y_axis0, y_axis1] = gen_trace(log_m_rows, intermediate, 1)?;
+    let mut main = [x_axis0, x_axis1, y_axis0, y_axis1];
+    perturb_trace(&mut main, trace_perturbation)?;
+    let [x_axis0, x_axis1, y_axis0, y_axis1] = main;
+    clock.lap("main_trace_generation", "Main trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![
+        B::eval(cpu_eval(log_n_rows, x_axis0)),
+        B::eval(cpu_eval(log_n_rows, x_axis1)),
+        B::eval(cpu_eval(log_m_rows, y_axis0)),
+        B::eval(cpu_eval(log_m_rows, y_axis1)),
+    ]);
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_state_machine_stmt0(&mut channel, log_n_rows, log_m_rows);
+    transcript.record("stmt0_mix", &channel);
+    let elements = StateMachineElements {
+        z: channel.draw_secure_felt(),
+        alpha: channel.draw_secure_felt(),
+    };
+    transcript.record("lookup_elements_draw", &channel);
+    let statement = prepare_state_machine_statement(log_n_rows, initial_state, elements)?;
+    mix_state_machine_public_input(&mut channel, &statement.public_input);
+    transcript.record("public_input_mix", &channel);
+    mix_state_machine_stmt1(
+        &mut channel,
+        statement.stmt1_x_axis_claimed_sum,
+        statement.stmt1_y_axis_claimed_sum,
+    );
+    transcript.record("stmt1_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let [x_axis, y_axis] = state_machine_pair_components(statement);
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&x_axis, &y_axis], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&x_axis, &y_axis],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn state_machine_pair_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: StateMachineStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    check_param_limit(
+        Example::StateMachinePair,
+        "log_n_rows",
+        statement.stmt0_n.into(),
+    )?;
+    if statement.stmt0_m != statement.stmt0_n - 1 {
+        bail!("invalid statement m");
+    }
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+    let (n, m) = (statement.stmt0_n, statement.stmt0_m);
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[n, m], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    commitment_scheme.commit(c1, &[n, n, m, m], &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_state_machine_stmt0(&mut channel, n, m);
+    transcript.record("stmt0_mix", &channel);
+    let elements = StateMachineElements {
+        z: channel.draw_secure_felt(),
+        alpha: channel.draw_secure_felt(),
+    };
+    transcript.record("lookup_elements_draw", &channel);
+    verify_state_machine_statement(statement, elements)?;
+    mix_state_machine_public_input(&mut channel, &statement.public_input);
+    transcript.record("public_input_mix", &channel);
+    mix_state_machine_stmt1(
+        &mut channel,
+        statement.stmt1_x_axis_claimed_sum,
+        statement.stmt1_y_axis_claimed_sum,
+    );
+    transcript.record("stmt1_mix", &channel);
+
+    let [x_axis, y_axis] = state_machine_pair_components(statement);
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(
+        &[&x_axis, &y_axis],
+        &mut channel,
+        &mut commitment_scheme,
+        proof,
+    )
+    .map_err(|err| anyhow::Error::new(err).context("state_machine_pair verify failed"))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn wide_fibonacci_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: WideFibonacciStatement,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(WideFibonacciStatement, StarkProof<MC::H>)> {
+    check_param_limit(
+        Example::WideFibonacci,
+        "log_n_rows",
+        statement.log_n_rows.into(),
+    )?;
+    check_param_limit(
+        Example::WideFibonacci,
+        "sequence_len",
+        statement.sequence_len.into(),
+    )?;
+    check_backend_log_size::<B>(statement.log_n_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles =
+        twiddles.get::<B>(statement.log_n_rows + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let mut trace = gen_wide_fibonacci_trace(statement.log_n_rows, statement.sequence_len)?;
+    perturb_trace(&mut trace, trace_perturbation)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(
+        trace
+            .into_iter()
+            .map(|col| B::eval(cpu_eval(statement.log_n_rows, col)))
+            .collect(),
+    );
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_wide_fibonacci_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = WideFibonacciComponent { statement };
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn wide_fibonacci_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: WideFibonacciStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    check_param_limit(
+        Example::WideFibonacci,
+        "log_n_rows",
+        statement.log_n_rows.into(),
+    )?;
+    check_param_limit(
+        Example::WideFibonacci,
+        "sequence_len",
+        statement.sequence_len.into(),
+    )?;
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    let main_log_sizes = vec![statement.log_n_rows; statement.sequence_len as usize];
+    commitment_scheme.commit(c1, &main_log_sizes, &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_wide_fibonacci_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+
+    let component = WideFibonacciComponent { statement };
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("wide_fibonacci verify failed"))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn plonk_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: PlonkStatement,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(PlonkStatement, StarkProof<MC::H>)> {
+    check_param_limit(Example::Plonk, "log_n_rows", statement.log_n_rows.into())?;
+    check_backend_log_size::<B>(statement.log_n_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles =
+        twiddles.get::<B>(statement.log_n_rows + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let (preprocessed, mut main) = gen_plonk_trace(statement.log_n_rows)?;
+    perturb_trace(&mut main, trace_perturbation)?;
+    clock.lap("trace_generation", "Trace generation");
+
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(
+        preprocessed
+            .into_iter()
+            .map(|col| B::eval(cpu_eval(statement.log_n_rows, col)))
+            .collect(),
+    );
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(
+        main.into_iter()
+            .map(|col| B::eval(cpu_eval(statement.log_n_rows, col)))
+            .collect(),
+    );
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_plonk_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = PlonkComponent { statement };
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn plonk_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: PlonkStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    check_param_limit(Example::Plonk, "log_n_rows", statement.log_n_rows.into())?;
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    let log_sizes = [statement.log_n_rows; 4];
+    commitment_scheme.commit(c0, &log_sizes, &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    commitment_scheme.commit(c1, &log_sizes, &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_plonk_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+
+    let component = PlonkComponent { statement };
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("plonk verify failed"))
+}
+
+fn poseidon_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: PoseidonStatement,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(PoseidonStatement, StarkProof<MC::H>)> {
+    let log_n_rows = poseidon_log_n_rows(statement)?;
+    check_backend_log_size::<B>(log_n_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles = twiddles.get::<B>(log_n_rows + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let trace = gen_poseidon_trace(log_n_rows)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(
+        trace
+            .into_iter()
+            .map(|col| B::eval(cpu_eval(log_n_rows, col)))
+            .collect(),
+    );
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_poseidon_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = PoseidonComponent { statement };
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn poseidon_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: PoseidonStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    let log_n_rows = poseidon_log_n_rows(statement)?;
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    let main_log_sizes = vec![log_n_rows; POSEIDON_COLUMNS];
+    commitment_scheme.commit(c1, &main_log_sizes, &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_poseidon_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+
+    let component = PoseidonComponent { statement };
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("poseidon verify failed"))
+}
+
+fn blake_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: BlakeStatement,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(BlakeStatement, StarkProof<MC::H>)> {
+    blake_validate_statement(statement)?;
+    check_backend_log_size::<B>(statement.log_n_rows)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles =
+        twiddles.get::<B>(statement.log_n_rows + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let trace = gen_blake_trace(statement)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(
+        trace
+            .into_iter()
+            .map(|col| B::eval(cpu_eval(statement.log_n_rows, col)))
+            .collect(),
+    );
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_blake_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = BlakeComponent { statement };
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn blake_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: BlakeStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    blake_validate_statement(statement)?;
+    let n_columns = blake_n_columns(statement)?;
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    let main_log_sizes = vec![statement.log_n_rows; n_columns];
+    commitment_scheme.commit(c1, &main_log_sizes, &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_blake_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+
+    let component = BlakeComponent { statement };
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("blake verify failed"))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn xor_prove<B: InteropBackend + BackendForChannel<MC>, MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: XorStatement,
+    prove_mode: ProveMode,
+    include_all_preprocessed_columns: bool,
+    trace_perturbation: Option<TracePerturbation>,
+    twiddles: &mut TwiddleCache,
+    clock: &mut StageClock,
+    transcript: &mut Transcript,
+) -> Result<(XorStatement, StarkProof<MC::H>)> {
+    check_param_limit(Example::Xor, "log_size", statement.log_size.into())?;
+    if statement.log_step > statement.log_size {
+        bail!("invalid xor log_step");
+    }
+    check_backend_log_size::<B>(statement.log_size)?;
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let twiddles = twiddles.get::<B>(statement.log_size + config.fri_config.log_blowup_factor + 1);
+    let mut scheme = CommitmentSchemeProver::<B, MC>::new(config, twiddles);
+    clock.lap("channel_and_scheme_init", "Channel and scheme init");
+
+    let is_first = gen_is_first(statement.log_size)?;
+    let is_step =
+        gen_is_step_with_offset(statement.log_size, statement.log_step, statement.offset)?;
+    clock.lap("trace_generation", "Trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![
+        B::eval(cpu_eval(statement.log_size, is_first)),
+        B::eval(cpu_eval(statement.log_size, is_step)),
+    ]);
+    builder.commit(&mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    clock.lap("preprocessed_commit", "Preprocessed commit");
+
+    let mut main = [gen_xor_main(statement)?];
+    perturb_trace(&mut main, trace_perturbation)?;
+    let [main] = main;
+    clock.lap("main_trace_generation", "Main trace generation");
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![B::eval(cpu_eval(statement.log_size, main))]);
+    builder.commit(&mut channel);
+    transcript.record("main_trace_commit", &channel);
+    clock.lap("main_trace_commit", "Main trace commit");
+
+    mix_xor_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+    clock.lap("statement_mix", "Statement mix");
+
+    let component = XorComponent { statement };
+    let core_channel = channel.clone();
+    let proof = clock.core_prove(|| -> Result<_> {
+        Ok(match prove_mode {
+            ProveMode::Prove => prove::<B, MC>(&[&component], &mut channel, scheme)?,
+            ProveMode::ProveEx => {
+                prove_ex::<B, MC>(
+                    &[&component],
+                    &mut channel,
+                    scheme,
+                    include_all_preprocessed_columns,
+                )?
+                .proof
+            }
+        })
+    })?;
+    transcript.record_core::<MC>(&core_channel, config, &proof);
+
+    Ok((statement, proof))
+}
+
+fn xor_verify<MC: InteropMerkle>(
+    config: PcsConfig,
+    statement: XorStatement,
+    proof: StarkProof<MC::H>,
+    transcript: &mut Transcript,
+) -> Result<()> {
+    check_param_limit(Example::Xor, "log_size", statement.log_size.into())?;
+    if statement.log_step > statement.log_size {
+        bail!("invalid xor log_step");
+    }
+    if proof.0.commitments.len() < 2 {
+        bail!("invalid proof shape: expected at least 2 commitments");
+    }
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    transcript.record("config_mix", &channel);
+
+    let c0 = proof.0.commitments[0];
+    let c1 = proof.0.commitments[1];
+
+    let mut commitment_scheme = CommitmentSchemeVerifier::<MC>::new(config);
+    commitment_scheme.commit(c0, &[statement.log_size, statement.log_size], &mut channel);
+    transcript.record("preprocessed_commit", &channel);
+    commitment_scheme.commit(c1, &[statement.log_size], &mut channel);
+    transcript.record("main_trace_commit", &channel);
+
+    mix_xor_statement(&mut channel, statement);
+    transcript.record("statement_mix", &channel);
+
+    let component = XorComponent { statement };
+    transcript.record_core::<MC>(&channel, config, &proof);
+    verify(&[&component], &mut channel, &mut commitment_scheme, proof)
+        .map_err(|err| anyhow::Error::new(err).context("xor verify failed"))
+}
+
+/// Checks `value` against each `PARAM_LIMITS` row of `example`'s `param`, in table order.
+fn check_param_limit(example: Example, param: &'static str, value: u64) -> Result<()> {
+    let violated = PARAM_LIMITS.iter().find(|limit| {
+        limit.example == example && limit.param == param && (value < limit.min || value > limit.max)
+    });
+    match violated {
+        Some(limit) => Err(LimitError {
+            example,
+            param,
+            value,
+            min: limit.min,
+            max: limit.max,
+            kind: limit.kind,
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// The kind of the first `LimitError` in `err`'s chain, if it is a limit rejection at all.
+fn limit_kind_of(err: &anyhow::Error) -> Option<LimitKind> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<LimitError>())
+        .map(|limit| limit.kind)
+}
+
+fn cpu_eval(log_size: u32, values: Vec<M31>) -> CpuCircleEvaluation<M31, BitReversedOrder> {
+    CpuCircleEvaluation::new(CanonicCoset::new(log_size).circle_domain(), values)
+}
+
+fn checked_pow2(log_size: u32) -> Result<usize> {
+    if log_size >= usize::BITS {
+        bail!("invalid log_size {log_size}");
+    }
+    Ok(1usize << log_size)
+}
+
+fn gen_is_first(log_size: u32) -> Result<Vec<M31>> {
+    let n = checked_pow2(log_size)?;
+    let mut values = vec![M31::zero(); n];
+    values[0] = M31::one();
+    Ok(values)
+}
+
+fn gen_trace(log_size: u32, initial_state: [M31; 2], inc_index: usize) -> Result<[Vec<M31>; 2]> {
+    if inc_index >= 2 {
+        bail!("invalid inc_index {inc_index}");
+    }
+    let n = checked_pow2(log_size)?;
+
+    let mut col0 = vec![M31::zero(); n];
+    let mut col1 = vec![M31::zero(); n];
+
+    let mut curr_state = initial_state;
+    for i in 0..n {
+        let bit_rev_index =
+            bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
+        col0[bit_rev_index] = curr_state[0];
+        col1[bit_rev_index] = curr_state[1];
+        curr_state[inc_index] += M31::one();
+    }
+
+    Ok([col0, col1])
+}
+
+/// Applies a test-only [`TracePerturbation`] to a generated main trace.
+fn perturb_trace(trace: &mut [Vec<M31>], perturbation: Option<TracePerturbation>) -> Result<()> {
+    let (column, row, value) = match perturbation {
+        None => return Ok(()),
+        Some(TracePerturbation::Cell { column, row, value }) => (column, row, value),
+        Some(TracePerturbation::SwapColumns { columns: [a, b] }) => {
+            let (Some(column_a), Some(column_b)) = (trace.get(a), trace.get(b)) else {
+                bail!("trace perturbation columns ({a}, {b}) are out of range");
+            };
+            if column_a.len() != column_b.len() {
+                bail!("trace perturbation columns ({a}, {b}) differ in size");
+            }
+            if column_a == column_b {
+                bail!("trace perturbation leaves columns ({a}, {b}) unchanged");
+            }
+            trace.swap(a, b);
+            return Ok(());
+        }
+    };
+    let cell = trace
+        .get_mut(column)
+        .and_then(|cells| cells.get_mut(row))
+        .ok_or_else(|| anyhow!("trace perturbation cell ({column}, {row}) is out of range"))?;
+    let value = checked_m31(value)?;
+    if *cell == value {
+        bail!("trace perturbation leaves cell ({column}, {row}) unchanged");
+    }
+    *cell = value;
+    Ok(())
+}
+
+fn gen_wide_fibonacci_trace(log_n_rows: u32, sequence_len: u32) -> Result<Vec<Vec<M31>>> {
+    check_param_limit(Example::WideFibonacci, "log_n_rows", log_n_rows.into())?;
+    check_param_limit(Example::WideFibonacci, "sequence_len", sequence_len.into())?;
+
+    let n = checked_pow2(log_n_rows)?;
+    let n_cols = sequence_len as usize;
+    let mut trace = vec![vec![M31::zero(); n]; n_cols];
+
+    for row in 0..n {
+        let bit_rev_index = bit_reverse_index(
+            coset_index_to_circle_domain_index(row, log_n_rows),
+            log_n_rows,
+        );
+        let mut a = M31::one();
+        let mut b = M31::from(row as u32);
+        trace[0][bit_rev_index] = a;
+        trace[1][bit_rev_index] = b;
+        for col in trace.iter_mut().skip(2) {
+            let c = a.square() + b.square();
+            col[bit_rev_index] = c;
+            a = b;
+            b = c;
+        }
+    }
+
+    Ok(trace)
+}
+
+fn gen_is_step_with_offset(log_size: u32, log_step: u32, offset: usize) -> Result<Vec<M31>> {
+    if log_step > log_size {
+        bail!("invalid step");
+    }
+    let n = checked_pow2(log_size)?;
+    let step = checked_pow2(log_step)?;
+
+    let mut values = vec![M31::zero(); n];
+    let mut i = offset % step;
+    while i < n {
+        let circle_domain_index = coset_index_to_circle_domain_index(i, log_size);
+        let bit_rev_index = bit_reverse_index(circle_domain_index, log_size);
+        values[bit_rev_index] = M31::one();
+        i += step;
+    }
+
+    Ok(values)
+}
+
+/// The honest XOR main column: one exactly on the rows `is_step` selects and zero elsewhere.
+fn gen_xor_main(statement: XorStatement) -> Result<Vec<M31>> {
+    gen_is_step_with_offset(statement.log_size, statement.log_step, statement.offset)
+}
+
+fn gen_plonk_trace(log_n_rows: u32) -> Result<([Vec<M31>; 4], [Vec<M31>; 4])> {
+    check_param_limit(Example::Plonk, "log_n_rows", log_n_rows.into())?;
+    let n = checked_pow2(log_n_rows)?;
+
+    let mut preprocessed = std::array::from_fn(|_| vec![M31::zero(); n]);
+    let mut main = std::array::from_fn(|_| vec![M31::zero(); n]);
+
+    let mut fib = vec![M31::zero(); n + 2];
+    fib[0] = M31::one();
+    fib[1] = M31::one();
+    for i in 2..fib.len() {
+        fib[i] = fib[i - 1] + fib[i - 2];
+    }
+
+    for i in 0..n {
+        preprocessed[0][i] = M31::from(i as u32);
+        preprocessed[1][i] = M31::from((i + 1) as u32);
+        preprocessed[2][i] = M31::from((i + 2) as u32);
+        preprocessed[3][i] = M31::one();
+
+        main[0][i] = M31::one();
+        main[1][i] = fib[i];
+        main[2][i] = fib[i + 1];
+        main[3][i] = fib[i + 2];
+    }
+
+    if n >= 2 {
+        main[0][n - 1] = M31::zero();
+        main[0][n - 2] = M31::one();
+    }
+
+    Ok((preprocessed, main))
+}
+
+fn poseidon_log_n_rows(statement: PoseidonStatement) -> Result<u32> {
+    check_param_limit(
+        Example::Poseidon,
+        "log_n_instances",
+        statement.log_n_instances.into(),
+    )?;
+    Ok(statement.log_n_instances - POSEIDON_LOG_INSTANCES_PER_ROW)
+}
+
+fn gen_poseidon_trace(log_n_rows: u32) -> Result<Vec<Vec<M31>>> {
+    check_param_limit(
+        Example::Poseidon,
+        "log_n_instances",
+        u64::from(log_n_rows) + u64::from(POSEIDON_LOG_INSTANCES_PER_ROW),
+    )?;
+    Ok(poseidon::gen_trace(log_n_rows))
+}
+
+fn blake_validate_statement(statement: BlakeStatement) -> Result<()> {
+    check_param_limit(Example::Blake, "log_n_rows", statement.log_n_rows.into())?;
+    check_param_limit(Example::Blake, "n_rounds", statement.n_rounds.into())?;
+    let _ = blake_n_columns(statement)?;
+    Ok(())
+}
+
+fn blake_n_columns(statement: BlakeStatement) -> Result<usize> {
+    (statement.n_rounds as usize)
+        .checked_mul(BLAKE_ROUND_INPUT_FELTS)
+        .ok_or_else(|| anyhow!("blake column count overflow"))
+}
+
+fn blake_next_seed(seed: u64) -> u64 {
+    let mut x = seed;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    x
+}
+
+fn gen_blake_trace(statement: BlakeStatement) -> Result<Vec<Vec<M31>>> {
+    blake_validate_statement(statement)?;
+    let n = checked_pow2(statement.log_n_rows)?;
+    let n_columns = blake_n_columns(statement)?;
+    let mut trace = vec![vec![M31::zero(); n]; n_columns];
+
+    for row in 0..n {
+        let mut col_index = 0usize;
+        let mut seed = row as u64 + 1;
+        for round in 0..statement.n_rounds as usize {
+            for cell in 0..BLAKE_ROUND_INPUT_FELTS {
+                seed = blake_next_seed(seed);
+                let mixed = seed
+                    ^ ((round as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+                    ^ (((cell + 1) as u64).wrapping_mul(0x517c_c1b7_2722_0a95));
+                trace[col_index][row] = M31::from((mixed % P as u64) as u32);
+                col_index += 1;
+            }
+        }
+        debug_assert_eq!(col_index, n_columns);
+    }
+
+    Ok(trace)
+}
+
+fn state_machine_component(
+    statement: StateMachineStatement,
+    elements: StateMachineElements,
+) -> StateMachineComponent {
+    StateMachineComponent {
+        trace_log_size: statement.stmt0_n,
+        is_first_column: 0,
+        composition_eval: statement.stmt1_x_axis_claimed_sum + statement.stmt1_y_axis_claimed_sum,
+        interaction: statement
+            .with_interaction
+            .then_some(StateMachineInteraction {
+                elements,
+                claimed_sum: statement.stmt1_x_axis_claimed_sum,
+            }),
+        transition: None,
+    }
+}
+
+/// Names of the state-machine pair's components, in proving order.
+const STATE_MACHINE_PAIR_COMPONENTS: [&str; 2] = ["state_machine_x_axis", "state_machine_y_axis"];
+
+/// The x-axis component over `2^n` rows and the y-axis component over `2^m` rows, as upstream
+/// lays out the state machine. Each constrains its own two main columns, stepping x from the
+/// public initial state and then y from the intermediate state, which pairs the final x with the
+/// initial y. The x-axis columns come first in every tree.
+fn state_machine_pair_components(statement: StateMachineStatement) -> [StateMachineComponent; 2] {
+    let [initial_state, final_state] = statement.public_input;
+    [
+        StateMachineComponent {
+            trace_log_size: statement.stmt0_n,
+            is_first_column: 0,
+            composition_eval: statement.stmt1_x_axis_claimed_sum,
+            interaction: None,
+            transition: Some(StateMachineTransition {
+                first_column: 0,
+                inc_index: 0,
+                initial_state,
+            }),
+        },
+        StateMachineComponent {
+            trace_log_size: statement.stmt0_m,
+            is_first_column: 1,
+            composition_eval: statement.stmt1_y_axis_claimed_sum,
+            interaction: None,
+            transition: Some(StateMachineTransition {
+                first_column: 2,
+                inc_index: 1,
+                initial_state: [final_state[0], initial_state[1]],
+            }),
+        },
+    ]
+}
+
+/// The `components` entries of a state-machine pair artifact.
+fn state_machine_pair_component_wires(statement: StateMachineStatement) -> Vec<ComponentWire> {
+    STATE_MACHINE_PAIR_COMPONENTS
+        .iter()
+        .zip(state_machine_pair_components(statement))
+        .map(|(name, component)| ComponentWire {
+            name: name.to_string(),
+            log_n_rows: component.trace_log_size,
+            claimed_sum: qm31_to_wire(component.composition_eval),
+            metadata: Some(component_metadata(&component)),
+        })
+        .collect()
+}
+
+/// Logup interaction trace of the x-axis transitions, as the four coordinate columns of one
+/// secure column: row `i` holds the prefix sum of `1 / q_j - 1 / (q_j + 1)` for `j <= i`, where
+/// `q_j` combines row `j`'s state and `q_j + 1` its successor's. The last row equals the x-axis
+/// claimed sum.
+fn gen_state_machine_interaction_trace(
+    log_size: u32,
+    initial_state: [M31; 2],
+    elements: StateMachineElements,
+) -> Result<[Vec<M31>; SECURE_EXTENSION_DEGREE]> {
+    let n = checked_pow2(log_size)?;
+    let mut columns = std::array::from_fn(|_| vec![M31::zero(); n]);
+
+    let mut state = initial_state;
+    let mut prefix_sum = SecureField::zero();
+    for i in 0..n {
+        let denom = state_machine_combine(elements, state);
+        let next_denom = denom + SecureField::one();
+        if denom.is_zero() || next_denom.is_zero() {
+            bail!("degenerate denominator");
+        }
+        prefix_sum += (denom * next_denom).inverse();
+        let bit_rev_index =
+            bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
+        for (column, value) in columns.iter_mut().zip(prefix_sum.to_m31_array()) {
+            column[bit_rev_index] = value;
+        }
+        state[0] += M31::one();
+    }
+
+    Ok(columns)
+}
+
+/// Logup constraints of one state-machine row, given `is_first`, the row's state and the
+/// prefix sums at the previous and current rows: the sum grows by the row's fraction (restarting
+/// at the first row, whose cyclic predecessor is the last), and the predecessor of the first row
+/// holds the claimed sum.
+fn state_machine_logup_constraints(
+    interaction: StateMachineInteraction,
+    is_first: SecureField,
+    state: [SecureField; 2],
+    prev_sum: SecureField,
+    sum: SecureField,
+) -> [SecureField; 2] {
+    let denom = state[0] + interaction.elements.alpha * state[1] - interaction.elements.z;
+    let transition =
+        (sum - (SecureField::one() - is_first) * prev_sum) * denom * (denom + SecureField::one())
+            - SecureField::one();
+    let boundary = is_first * (prev_sum - interaction.claimed_sum);
+    [transition, boundary]
+}
+
+/// Transition constraints of one state-machine pair component, given `is_first` and its two main
+/// columns at the previous and current rows: the first row holds the initial state, the stepped
+/// coordinate grows by one per row (except into the first row, whose cyclic predecessor is the
+/// last) and the other coordinate stays constant.
+fn state_machine_transition_constraints<F>(
+    transition: StateMachineTransition,
+    is_first: F,
+    prev_state: [F; 2],
+    state: [F; 2],
+) -> [F; 4]
+where
+    F: From<M31> + One + Copy + Sub<Output = F> + Mul<Output = F>,
+{
+    let inc = transition.inc_index;
+    let fixed = 1 - inc;
+    [
+        is_first * (state[0] - F::from(transition.initial_state[0])),
+        is_first * (state[1] - F::from(transition.initial_state[1])),
+        (F::one() - is_first) * (state[inc] - prev_state[inc] - F::one()),
+        state[fixed] - prev_state[fixed],
+    ]
+}
+
+fn state_machine_combine(elements: StateMachineElements, state: [M31; 2]) -> SecureField {
+    SecureField::from(state[0]) + elements.alpha * SecureField::from(state[1]) - elements.z
+}
+
+fn transition_states(log_n_rows: u32, initial_state: [M31; 2]) -> Result<([M31; 2], [M31; 2])> {
+    check_param_limit(Example::StateMachine, "log_n_rows", log_n_rows.into())?;
+    let mut intermediate = initial_state;
+    intermediate[0] += M31::from_u32_unchecked(1 << log_n_rows);
+
+    let mut final_state = intermediate;
+    final_state[1] += M31::from_u32_unchecked(1 << (log_n_rows - 1));
+
+    Ok((intermediate, final_state))
+}
+
+fn claimed_sum_telescoping(
+    log_size: u32,
+    initial_state: [M31; 2],
+    inc_index: usize,
+    elements: StateMachineElements,
+) -> Result<SecureField> {
+    if inc_index >= 2 {
+        bail!("invalid inc_index");
+    }
+    let n = checked_pow2(log_size)?;
+
+    let first = state_machine_combine(elements, initial_state);
+
+    let mut last_state = initial_state;
+    last_state[inc_index] += M31::from(n);
+    let last = state_machine_combine(elements, last_state);
+
+    if first.is_zero() || last.is_zero() {
+        bail!("degenerate denominator");
+    }
+
+    Ok(first.inverse() - last.inverse())
+}
+
+fn prepare_state_machine_statement(
+    log_n_rows: u32,
+    initial_state: [M31; 2],
+    elements: StateMachineElements,
+) -> Result<StateMachineStatement> {
+    let (intermediate, final_state) = transition_states(log_n_rows, initial_state)?;
+    let x_axis_claimed_sum = claimed_sum_telescoping(log_n_rows, initial_state, 0, elements)?;
+    let y_axis_claimed_sum = claimed_sum_telescoping(log_n_rows - 1, intermediate, 1, elements)?;
+
+    Ok(StateMachineStatement {
+        public_input: [initial_state, final_state],
+        stmt0_n: log_n_rows,
+        stmt0_m: log_n_rows - 1,
+        stmt1_x_axis_claimed_sum: x_axis_claimed_sum,
+        stmt1_y_axis_claimed_sum: y_axis_claimed_sum,
+        with_interaction: false,
+    })
+}
+
+fn verify_state_machine_statement(
+    statement: StateMachineStatement,
+    elements: StateMachineElements,
+) -> Result<()> {
+    let initial_comb = state_machine_combine(elements, statement.public_input[0]);
+    let final_comb = state_machine_combine(elements, statement.public_input[1]);
+    if initial_comb.is_zero() || final_comb.is_zero() {
+        bail!("degenerate denominator");
+    }
+
+    let lhs = (statement.stmt1_x_axis_claimed_sum + statement.stmt1_y_axis_claimed_sum)
+        * initial_comb
+        * final_comb;
+    let rhs = final_comb - initial_comb;
+    if lhs != rhs {
+        bail!("state_machine statement not satisfied");
+    }
+    Ok(())
+}
+
+fn mix_state_machine_stmt0(channel: &mut Blake2sChannel, n: u32, m: u32) {
+    channel.mix_u32s(&[n, m]);
+}
+
+fn mix_state_machine_public_input(channel: &mut Blake2sChannel, public_input: &[[M31; 2]; 2]) {
+    channel.mix_u32s(&[
+        public_input[0][0].0,
+        public_input[0][1].0,
+        public_input[1][0].0,
+        public_input[1][1].0,
+    ]);
+}
+
+fn mix_state_machine_stmt1(
+    channel: &mut Blake2sChannel,
+    x_claim: SecureField,
+    y_claim: SecureField,
+) {
+    channel.mix_felts(&[x_claim, y_claim]);
+}
+
+/// Constraint values of one wide-Fibonacci row: every column from the third on must equal the
+/// sum of the squares of the two columns before it.
+fn wide_fibonacci_constraints<F>(row: &[F]) -> impl Iterator<Item = F> + '_
+where
+    F: FieldExpOps + Copy + Add<Output = F> + Sub<Output = F>,
+{
+    row.windows(3)
+        .map(|window| window[2] - (window[0].square() + window[1].square()))
+}
+
+/// Inverses of the vanishing polynomial of the trace coset of log size `log_n_rows` over the
+/// constraint evaluation domain of log size `eval_log_size`. The polynomial is constant on each
+/// trace-sized block of the bit-reversed domain, so row `i` takes entry `i >> log_n_rows`.
+fn trace_vanishing_inverses(log_n_rows: u32, eval_log_size: u32) -> Vec<M31> {
+    let log_expand = eval_log_size - log_n_rows;
+    let trace_coset = CanonicCoset::new(log_n_rows).coset();
+    let eval_domain = CanonicCoset::new(eval_log_size).circle_domain();
+    (0..1usize << log_expand)
+        .map(|i| {
+            let point = eval_domain.at(bit_reverse_index(i, log_expand));
+            coset_vanishing(trace_coset, point).inverse()
+        })
+        .collect()
+}
+
+fn mix_wide_fibonacci_statement(channel: &mut Blake2sChannel, statement: WideFibonacciStatement) {
+    channel.mix_u32s(&[statement.log_n_rows, statement.sequence_len]);
+}
+
+/// Constraint value of one Plonk gate: the output wire `c` is `a + b` where the selector `op` is
+/// one and `a * b` where it is zero.
+fn plonk_constraint<F>(op: F, a: F, b: F, c: F) -> F
+where
+    F: One + Copy + Add<Output = F> + Sub<Output = F> + Mul<Output = F>,
+{
+    c - (op * (a + b) + (F::one() - op) * a * b)
+}
+
+fn mix_plonk_statement(channel: &mut Blake2sChannel, statement: PlonkStatement) {
+    channel.mix_u32s(&[statement.log_n_rows]);
+}
+
+fn poseidon_composition_eval(statement: PoseidonStatement) -> SecureField {
+    SecureField::from_m31(
+        M31::from(statement.log_n_instances),
+        M31::from(POSEIDON_COLUMNS_PER_REP as u32),
+        M31::from(POSEIDON_COLUMNS as u32),
+        M31::one(),
+    )
+}
+
+fn mix_poseidon_statement(channel: &mut Blake2sChannel, statement: PoseidonStatement) {
+    channel.mix_u32s(&[statement.log_n_instances]);
+}
+
+fn blake_composition_eval(statement: BlakeStatement) -> SecureField {
+    SecureField::from_m31(
+        M31::from(statement.log_n_rows),
+        M31::from(statement.n_rounds),
+        M31::from(blake_n_columns(statement).unwrap_or(0) as u32),
+        M31::one(),
+    )
+}
+
+fn mix_blake_statement(channel: &mut Blake2sChannel, statement: BlakeStatement) {
+    channel.mix_u32s(&[statement.log_n_rows, statement.n_rounds]);
+}
+
+/// The main column `x` is boolean, and is one on every row the `is_step` selector picks.
+fn xor_constraints<F>(is_step: F, x: F) -> [F; 2]
+where
+    F: One + Copy + Sub<Output = F> + Mul<Output = F>,
+{
+    [x * (x - F::one()), is_step * (x - F::one())]
+}
+
+fn mix_xor_statement(channel: &mut Blake2sChannel, statement: XorStatement) {
+    channel.mix_u32s(&[statement.log_size, statement.log_step]);
+    channel.mix_u64(statement.offset as u64);
+}
+
+impl Component for StateMachineComponent {
+    fn n_constraints(&self) -> usize {
+        match (self.interaction, self.transition) {
+            (Some(_), _) => 2,
+            (None, Some(_)) => 4,
+            (None, None) => 1,
+        }
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        if self.interaction.is_some() {
+            self.trace_log_size + 2
+        } else {
+            self.trace_log_size + 1
+        }
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        let mut bounds = vec![
+            vec![self.trace_log_size],
+            vec![self.trace_log_size, self.trace_log_size],
+        ];
+        if self.interaction.is_some() {
+            bounds.push(vec![self.trace_log_size; SECURE_EXTENSION_DEGREE]);
+        }
+        TreeVec::new(bounds)
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        if self.interaction.is_none() && self.transition.is_none() {
+            return TreeVec::new(vec![vec![vec![]], vec![vec![point], vec![point]]]);
+        }
+        let step = CanonicCoset::new(self.trace_log_size).step();
+        let prev_point = point + step.mul_signed(-1).into_ef();
+        if self.transition.is_some() {
+            return TreeVec::new(vec![vec![vec![point]], vec![vec![prev_point, point]; 2]]);
+        }
+        TreeVec::new(vec![
+            vec![vec![point]],
+            vec![vec![point], vec![point]],
+            vec![vec![prev_point, point]; SECURE_EXTENSION_DEGREE],
+        ])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        vec![self.is_first_column]
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        point: CirclePoint<SecureField>,
+        mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        let is_first = || mask[0][self.is_first_column][0];
+        let constraints = if let Some(transition) = self.transition {
+            let state_at = |offset: usize| {
+                std::array::from_fn(|k| mask[1][transition.first_column + k][offset])
+            };
+            state_machine_transition_constraints(transition, is_first(), state_at(0), state_at(1))
+                .to_vec()
+        } else if let Some(interaction) = self.interaction {
+            let sum_at = |offset: usize| {
+                SecureField::from_partial_evals(std::array::from_fn(|k| mask[2][k][offset]))
+            };
+            state_machine_logup_constraints(
+                interaction,
+                is_first(),
+                [mask[1][0][0], mask[1][1][0]],
+                sum_at(0),
+                sum_at(1),
+            )
+            .to_vec()
+        } else {
+            evaluation_accumulator.accumulate(self.composition_eval);
+            return;
+        };
+        let trace_coset = CanonicCoset::new(self.trace_log_size).coset();
+        let denom_inverse = coset_vanishing(trace_coset, point).inverse();
+        for constraint in constraints {
+            evaluation_accumulator.accumulate(constraint * denom_inverse);
+        }
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for StateMachineComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let log_n_rows = self.trace_log_size;
+        let eval_log_size = self.max_constraint_log_degree_bound();
+        let eval_domain = CanonicCoset::new(eval_log_size).circle_domain();
+        let evaluate = |tree: usize, column: usize| {
+            trace.polys[tree][column]
+                .evaluate(eval_domain)
+                .values
+                .to_cpu()
+        };
+        let prev_index = move |index: usize| {
+            offset_bit_reversed_circle_domain_index(index, log_n_rows, eval_log_size, -1)
+        };
+        let constraints_at: Box<dyn Fn(usize) -> Vec<SecureField>> = if let Some(transition) =
+            self.transition
+        {
+            let is_first = evaluate(0, self.is_first_column);
+            let state: [Vec<M31>; 2] =
+                std::array::from_fn(|k| evaluate(1, transition.first_column + k));
+            Box::new(move |index| {
+                let state_at =
+                    |index: usize| std::array::from_fn(|k| SecureField::from(state[k][index]));
+                state_machine_transition_constraints(
+                    transition,
+                    is_first[index].into(),
+                    state_at(prev_index(index)),
+                    state_at(index),
+                )
+                .to_vec()
+            })
+        } else if let Some(interaction) = self.interaction {
+            let is_first = evaluate(0, self.is_first_column);
+            let state = [evaluate(1, 0), evaluate(1, 1)];
+            let sum: [Vec<M31>; SECURE_EXTENSION_DEGREE] = std::array::from_fn(|k| evaluate(2, k));
+            Box::new(move |index| {
+                let sum_at = |index: usize| {
+                    SecureField::from_m31_array(std::array::from_fn(|k| sum[k][index]))
+                };
+                state_machine_logup_constraints(
+                    interaction,
+                    is_first[index].into(),
+                    [state[0][index].into(), state[1][index].into()],
+                    sum_at(prev_index(index)),
+                    sum_at(index),
+                )
+                .to_vec()
+            })
+        } else {
+            B::accumulate_constant(
+                evaluation_accumulator,
+                log_n_rows + 1,
+                self.composition_eval,
+            );
+            return;
+        };
+        let denom_inverses = trace_vanishing_inverses(log_n_rows, eval_log_size);
+
+        B::accumulate_rows(
+            evaluation_accumulator,
+            eval_log_size,
+            self.n_constraints(),
+            |powers| {
+                (0..1usize << eval_log_size)
+                    .map(|index| {
+                        let combined = constraints_at(index)
+                            .into_iter()
+                            .zip(powers)
+                            .fold(SecureField::zero(), |acc, (constraint, power)| {
+                                acc + *power * constraint
+                            });
+                        combined * denom_inverses[index >> log_n_rows]
+                    })
+                    .collect()
+            },
+        );
+    }
+}
+
+impl Component for WideFibonacciComponent {
+    fn n_constraints(&self) -> usize {
+        (self.statement.sequence_len as usize).saturating_sub(2)
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.statement.log_n_rows + 1
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        TreeVec::new(vec![
+            vec![],
+            vec![self.statement.log_n_rows; self.statement.sequence_len as usize],
+        ])
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        TreeVec::new(vec![
+            vec![],
+            vec![vec![point]; self.statement.sequence_len as usize],
+        ])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        point: CirclePoint<SecureField>,
+        mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        let trace_coset = CanonicCoset::new(self.statement.log_n_rows).coset();
+        let denom_inverse = coset_vanishing(trace_coset, point).inverse();
+        let row = mask[1].iter().map(|column| column[0]).collect::<Vec<_>>();
+        for constraint in wide_fibonacci_constraints(&row) {
+            evaluation_accumulator.accumulate(constraint * denom_inverse);
+        }
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for WideFibonacciComponent {
+    /// Evaluates the constraints row by row on the CPU over the constraint evaluation domain,
+    /// which is twice the trace size: the trace columns are re-evaluated there from their
+    /// polynomials, and each row's combination is divided by the trace coset's vanishing
+    /// polynomial, which only takes `2` distinct values on that domain.
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let log_n_rows = self.statement.log_n_rows;
+        let eval_log_size = self.max_constraint_log_degree_bound();
+        let eval_domain = CanonicCoset::new(eval_log_size).circle_domain();
+        let columns = trace.polys[1]
+            .iter()
+            .map(|poly| poly.evaluate(eval_domain).values.to_cpu())
+            .collect::<Vec<_>>();
+        let denom_inverses = trace_vanishing_inverses(log_n_rows, eval_log_size);
+
+        B::accumulate_rows(
+            evaluation_accumulator,
+            eval_log_size,
+            self.n_constraints(),
+            |powers| {
+                let mut row = vec![M31::zero(); columns.len()];
+                (0..1usize << eval_log_size)
+                    .map(|index| {
+                        for (value, column) in row.iter_mut().zip(&columns) {
+                            *value = column[index];
+                        }
+                        let combined = wide_fibonacci_constraints(&row)
+                            .zip(powers)
+                            .fold(SecureField::zero(), |acc, (constraint, power)| {
+                                acc + *power * constraint
+                            });
+                        combined * denom_inverses[index >> log_n_rows]
+                    })
+                    .collect()
+            },
+        );
+    }
+}
+
+/// Preprocessed column holding the Plonk gate selector; the other preprocessed columns are the
+/// wire indices, which only a lookup argument would read.
+const PLONK_OP_COLUMN: usize = 3;
+/// Preprocessed columns the Plonk example commits: the three wire indices, then the selector.
+const PLONK_PREPROCESSED_COLUMNS: usize = 4;
+/// Main-trace columns of the Plonk gate's `a`, `b` and `c` wires; column `0` is the wire
+/// multiplicity, which the gate constraint does not read.
+const PLONK_WIRE_COLUMNS: [usize; 3] = [1, 2, 3];
+
+impl Component for PlonkComponent {
+    fn n_constraints(&self) -> usize {
+        1
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.statement.log_n_rows + 1
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        let n_preprocessed = self.preprocessed_column_indices().len();
+        TreeVec::new(vec![
+            vec![self.statement.log_n_rows; n_preprocessed],
+            vec![self.statement.log_n_rows; 4],
+        ])
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        let mut main = vec![vec![]; 4];
+        for column in PLONK_WIRE_COLUMNS {
+            main[column] = vec![point];
+        }
+        let preprocessed = vec![vec![point]; self.preprocessed_column_indices().len()];
+        TreeVec::new(vec![preprocessed, main])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        match self.statement.preprocessed_columns {
+            Some(columns) => columns.indices(),
+            None => vec![PLONK_OP_COLUMN],
+        }
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        point: CirclePoint<SecureField>,
+        mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        let trace_coset = CanonicCoset::new(self.statement.log_n_rows).coset();
+        let denom_inverse = coset_vanishing(trace_coset, point).inverse();
+        let op = mask[0][PLONK_OP_COLUMN][0];
+        let [a, b, c] = PLONK_WIRE_COLUMNS.map(|column| mask[1][column][0]);
+        evaluation_accumulator.accumulate(plonk_constraint(op, a, b, c) * denom_inverse);
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for PlonkComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let log_n_rows = self.statement.log_n_rows;
+        let eval_log_size = self.max_constraint_log_degree_bound();
+        let eval_domain = CanonicCoset::new(eval_log_size).circle_domain();
+        let op = trace.polys[0][PLONK_OP_COLUMN]
+            .evaluate(eval_domain)
+            .values
+            .to_cpu();
+        let [a, b, c] = PLONK_WIRE_COLUMNS
+            .map(|column| trace.polys[1][column].evaluate(eval_domain).values.to_cpu());
+        let denom_inverses = trace_vanishing_inverses(log_n_rows, eval_log_size);
+
+        B::accumulate_rows(evaluation_accumulator, eval_log_size, 1, |powers| {
+            (0..1usize << eval_log_size)
+                .map(|index| {
+                    let constraint = plonk_constraint(op[index], a[index], b[index], c[index]);
+                    powers[0] * constraint * denom_inverses[index >> log_n_rows]
+                })
+                .collect()
+        });
+    }
+}
+
+impl Component for PoseidonComponent {
+    fn n_constraints(&self) -> usize {
+        1
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        poseidon_log_n_rows(self.statement).unwrap_or(0) + 1
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        let log_n_rows = poseidon_log_n_rows(self.statement).unwrap_or(0);
+        TreeVec::new(vec![vec![], vec![log_n_rows; POSEIDON_COLUMNS]])
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        TreeVec::new(vec![vec![], vec![vec![point]; POSEIDON_COLUMNS]])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        _point: CirclePoint<SecureField>,
+        _mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        evaluation_accumulator.accumulate(poseidon_composition_eval(self.statement));
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for PoseidonComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        _trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let log_n_rows = poseidon_log_n_rows(self.statement).unwrap_or(0);
+        let composition_eval = poseidon_composition_eval(self.statement);
+        B::accumulate_constant(evaluation_accumulator, log_n_rows + 1, composition_eval);
+    }
+}
+
+impl Component for BlakeComponent {
+    fn n_constraints(&self) -> usize {
+        1
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.statement.log_n_rows + 1
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        let n_columns = blake_n_columns(self.statement).unwrap_or(0);
+        TreeVec::new(vec![vec![], vec![self.statement.log_n_rows; n_columns]])
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        let n_columns = blake_n_columns(self.statement).unwrap_or(0);
+        TreeVec::new(vec![vec![], vec![vec![point]; n_columns]])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        _point: CirclePoint<SecureField>,
+        _mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        evaluation_accumulator.accumulate(blake_composition_eval(self.statement));
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for BlakeComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        _trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let composition_eval = blake_composition_eval(self.statement);
+        B::accumulate_constant(
+            evaluation_accumulator,
+            self.statement.log_n_rows + 1,
+            composition_eval,
+        );
+    }
+}
+
+/// Preprocessed column selecting the rows on which the XOR main column is one; column `0` is
+/// `is_first`, which is committed and sampled but not read by the round constraints.
+const XOR_IS_STEP_COLUMN: usize = 1;
+/// Preprocessed columns the XOR example commits: `is_first` and `is_step`.
+const XOR_PREPROCESSED_COLUMNS: usize = 2;
+
+impl Component for XorComponent {
+    fn n_constraints(&self) -> usize {
+        2
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.statement.log_size + 1
+    }
+
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        let n_preprocessed = self.preprocessed_column_indices().len();
+        TreeVec::new(vec![
+            vec![self.statement.log_size; n_preprocessed],
+            vec![self.statement.log_size],
+        ])
+    }
+
+    fn mask_points(
+        &self,
+        point: CirclePoint<SecureField>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<SecureField>>>> {
+        // Upstream samples every column in `preprocessed_column_indices` at the OODS point,
+        // whether or not a constraint reads it, so `is_first` gets a mask point too.
+        let n_preprocessed = self.preprocessed_column_indices().len();
+        TreeVec::new(vec![vec![vec![point]; n_preprocessed], vec![vec![point]]])
+    }
+
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        match self.statement.preprocessed_columns {
+            Some(columns) => columns.indices(),
+            None => vec![0, XOR_IS_STEP_COLUMN],
+        }
+    }
+
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        point: CirclePoint<SecureField>,
+        mask: &TreeVec<Vec<Vec<SecureField>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        let trace_coset = CanonicCoset::new(self.statement.log_size).coset();
+        let denom_inverse = coset_vanishing(trace_coset, point).inverse();
+        let is_step = mask[0][XOR_IS_STEP_COLUMN][0];
+        for constraint in xor_constraints(is_step, mask[1][0][0]) {
+            evaluation_accumulator.accumulate(constraint * denom_inverse);
+        }
+    }
+}
+
+impl<B: InteropBackend> ComponentProver<B> for XorComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        trace: &Trace<'_, B>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<B>,
+    ) {
+        let log_size = self.statement.log_size;
+        let eval_log_size = self.max_constraint_log_degree_bound();
+        let eval_domain = CanonicCoset::new(eval_log_size).circle_domain();
+        let is_step = trace.polys[0][XOR_IS_STEP_COLUMN]
+            .evaluate(eval_domain)
+            .values
+            .to_cpu();
+        let x = trace.polys[1][0].evaluate(eval_domain).values.to_cpu();
+        let denom_inverses = trace_vanishing_inverses(log_size, eval_log_size);
+
+        B::accumulate_rows(
+            evaluation_accumulator,
+            eval_log_size,
+            self.n_constraints(),
+            |powers| {
+                (0..1usize << eval_log_size)
+                    .map(|index| {
+                        let combined = xor_constraints(is_step[index], x[index])
+                            .into_iter()
+                            .zip(powers)
+                            .fold(SecureField::zero(), |acc, (constraint, power)| {
+                                acc + *power * constraint
+                            });
+                        combined * denom_inverses[index >> log_size]
+                    })
+                    .collect()
+            },
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Round primitives and trace generator of the interop Poseidon example.
+//!
+//! `stwo-vector-gen` includes this file by path so its Poseidon vectors are produced by the same
+//! formulas the interop prover commits to; it uses only part of the module.
+#![allow(dead_code)]
+
+use stwo::core::fields::m31::{M31, P};
+use stwo::core::fields::FieldExpOps;
+
+pub(crate) const POSEIDON_LOG_INSTANCES_PER_ROW: u32 = 3;
+pub(crate) const POSEIDON_INSTANCES_PER_ROW: usize = 1 << POSEIDON_LOG_INSTANCES_PER_ROW;
+pub(crate) const POSEIDON_STATE: usize = 16;
+pub(crate) const POSEIDON_PARTIAL_ROUNDS: usize = 14;
+pub(crate) const POSEIDON_HALF_FULL_ROUNDS: usize = 4;
+pub(crate) const POSEIDON_FULL_ROUNDS: usize = POSEIDON_HALF_FULL_ROUNDS * 2;
+pub(crate) const POSEIDON_COLUMNS_PER_REP: usize =
+    POSEIDON_STATE * (1 + POSEIDON_FULL_ROUNDS) + POSEIDON_PARTIAL_ROUNDS;
+pub(crate) const POSEIDON_COLUMNS: usize = POSEIDON_COLUMNS_PER_REP * POSEIDON_INSTANCES_PER_ROW;
+
+pub(crate) fn poseidon_external_round_const(round: usize, state_i: usize) -> M31 {
+    M31::from(((1234u64 + (round as u64 * 37) + state_i as u64) % P as u64) as u32)
+}
+
+pub(crate) fn poseidon_internal_round_const(round: usize) -> M31 {
+    M31::from(((9876u64 + (round as u64 * 17)) % P as u64) as u32)
+}
+
+pub(crate) fn poseidon_pow5(x: M31) -> M31 {
+    let x2 = x.square();
+    let x4 = x2.square();
+    x4 * x
+}
+
+pub(crate) fn poseidon_apply_m4(x: [M31; 4]) -> [M31; 4] {
+    let t0 = x[0] + x[1];
+    let t02 = t0 + t0;
+    let t1 = x[2] + x[3];
+    let t12 = t1 + t1;
+    let t2 = x[1] + x[1] + t1;
+    let t3 = x[3] + x[3] + t0;
+    let t4 = t12 + t12 + t3;
+    let t5 = t02 + t02 + t2;
+    let t6 = t3 + t5;
+    let t7 = t2 + t4;
+    [t6, t5, t7, t4]
+}
+
+pub(crate) fn poseidon_apply_external_round_matrix(state: &mut [M31; POSEIDON_STATE]) {
+    for i in 0..4 {
+        let offset = i * 4;
+        let mixed = poseidon_apply_m4([
+            state[offset],
+            state[offset + 1],
+            state[offset + 2],
+            state[offset + 3],
+        ]);
+        state[offset] = mixed[0];
+        state[offset + 1] = mixed[1];
+        state[offset + 2] = mixed[2];
+        state[offset + 3] = mixed[3];
+    }
+
+    for j in 0..4 {
+        let s = state[j] + state[j + 4] + state[j + 8] + state[j + 12];
+        for i in 0..4 {
+            let idx = i * 4 + j;
+            state[idx] += s;
+        }
+    }
+}
+
+pub(crate) fn poseidon_apply_internal_round_matrix(state: &mut [M31; POSEIDON_STATE]) {
+    let sum = state
+        .iter()
+        .copied()
+        .fold(M31::from(0), |acc, item| acc + item);
+    for (i, value) in state.iter_mut().enumerate() {
+        let coeff = M31::from_u32_unchecked(1u32 << ((i + 1) as u32));
+        *value = *value * coeff + sum;
+    }
+}
+
+/// Deterministic trace of the interop Poseidon example: `POSEIDON_COLUMNS` columns of
+/// `2^log_n_rows` rows in natural row order. Callers validate `log_n_rows`.
+pub(crate) fn gen_trace(log_n_rows: u32) -> Vec<Vec<M31>> {
+    let n = 1usize << log_n_rows;
+    let mut trace = vec![vec![M31::from(0); n]; POSEIDON_COLUMNS];
+
+    for row in 0..n {
+        let mut col_index = 0usize;
+        for rep_i in 0..POSEIDON_INSTANCES_PER_ROW {
+            let mut state = std::array::from_fn(|state_i| {
+                M31::from(((row * POSEIDON_STATE + state_i + rep_i) % P as usize) as u32)
+            });
+
+            for value in state {
+                trace[col_index][row] = value;
+                col_index += 1;
+            }
+
+            for round in 0..POSEIDON_HALF_FULL_ROUNDS {
+                for (state_i, value) in state.iter_mut().enumerate() {
+                    *value += poseidon_external_round_const(round, state_i);
+                }
+                poseidon_apply_external_round_matrix(&mut state);
+                for value in state.iter_mut() {
+                    *value = poseidon_pow5(*value);
+                    trace[col_index][row] = *value;
+                    col_index += 1;
+                }
+            }
+
+            for round in 0..POSEIDON_PARTIAL_ROUNDS {
+                state[0] += poseidon_internal_round_const(round);
+                poseidon_apply_internal_round_matrix(&mut state);
+                state[0] = poseidon_pow5(state[0]);
+                trace[col_index][row] = state[0];
+                col_index += 1;
+            }
+
+            for half_round in 0..POSEIDON_HALF_FULL_ROUNDS {
+                let round = half_round + POSEIDON_HALF_FULL_ROUNDS;
+                for (state_i, value) in state.iter_mut().enumerate() {
+                    *value += poseidon_external_round_const(round, state_i);
+                }
+                poseidon_apply_external_round_matrix(&mut state);
+                for value in state.iter_mut() {
+                    *value = poseidon_pow5(*value);
+                    trace[col_index][row] = *value;
+                    col_index += 1;
+                }
+            }
+        }
+        debug_assert_eq!(col_index, POSEIDON_COLUMNS);
+    }
+
+    trace
+}
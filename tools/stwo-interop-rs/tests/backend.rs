@@ -0,0 +1,55 @@
+//! The SIMD backend proves the same transcript as the CPU backend: the serialized proof wires are
+//! byte-equal, and only the recorded `prove_backend` differs.
+
+mod common;
+
+use std::process::Command;
+
+use stwo_interop_rs::{
+    verify_artifact, GenerateOptions, ProveBackend, ProveMode, Statement, VerifyOptions,
+};
+
+#[test]
+fn simd_and_cpu_wires_are_byte_equal() {
+    let statement = Statement::StateMachine {
+        log_n_rows: 6,
+        initial_state: [9, 3],
+    };
+    for prove_mode in [ProveMode::Prove, ProveMode::ProveEx] {
+        let mut artifacts = Vec::new();
+        for backend in [ProveBackend::Cpu, ProveBackend::Simd] {
+            let mut options = GenerateOptions::default();
+            options.prove_mode = prove_mode;
+            options.backend = backend;
+            let artifact = common::artifact_with(statement, &options);
+            let report =
+                verify_artifact(&artifact, &VerifyOptions::default()).expect("artifact verifies");
+            assert_eq!(report.status, "ok");
+            artifacts.push(artifact);
+        }
+        assert_eq!(artifacts[0].prove_backend.as_deref(), Some("cpu"));
+        assert_eq!(artifacts[1].prove_backend.as_deref(), Some("simd"));
+        assert_eq!(artifacts[0].proof_bytes_hex, artifacts[1].proof_bytes_hex);
+    }
+}
+
+#[test]
+fn simd_rejects_columns_below_the_lane_count() {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "generate",
+            "--example",
+            "state_machine",
+            "--sm-log-n-rows",
+            "3",
+            "--backend",
+            "simd",
+            "--artifact",
+            "unused.json",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("backend minimum"));
+}
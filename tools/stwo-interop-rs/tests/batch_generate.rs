@@ -0,0 +1,95 @@
+//! `--mode batch-generate` writes every manifest entry it can, records the ones it cannot, and
+//! precomputes twiddles once per domain size.
+
+use std::fs;
+use std::process::Command;
+
+use stwo_interop_rs::{verify_artifact, InteropArtifact, VerifyOptions};
+
+#[test]
+fn batch_generates_valid_artifacts_and_records_failures() {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-batch-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    let output = |name: &str| dir.join(name).to_str().expect("utf-8 path").to_string();
+    let statement = serde_json::json!({
+        "example": "wide_fibonacci",
+        "log_n_rows": 5,
+        "sequence_len": 16,
+    });
+    let manifest = serde_json::json!({
+        "entries": [
+            {
+                "statement": statement,
+                "prove_mode": "prove",
+                "output": output("prove.json"),
+            },
+            {
+                "statement": { "example": "wide_fibonacci", "log_n_rows": 5 },
+                "output": output("missing_param.json"),
+            },
+            {
+                "statement": statement,
+                "pcs_config": {
+                    "pow_bits": 0,
+                    "fri_config": {
+                        "log_blowup_factor": 1,
+                        "log_last_layer_degree_bound": 0,
+                        "n_queries": 3,
+                    },
+                },
+                "prove_mode": "prove_ex",
+                "output": output("prove_ex.json"),
+            },
+        ],
+    });
+    let manifest_path = dir.join("manifest.json");
+    fs::write(&manifest_path, manifest.to_string()).expect("write manifest");
+
+    let run = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "batch-generate", "--manifest"])
+        .arg(&manifest_path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!run.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&run.stdout).expect("json summary");
+    assert_eq!(summary["generated"], 2);
+    assert_eq!(summary["failed"], 1);
+    assert_eq!(summary["twiddle_trees_computed"], 1);
+    let entries = summary["entries"].as_array().expect("entries");
+    assert_eq!(entries[1]["status"], "failed");
+    assert!(entries[1]["error"].is_string());
+    assert!(!dir.join("missing_param.json").exists());
+
+    let mut proof_bytes = Vec::new();
+    for (entry, name) in [(&entries[0], "prove.json"), (&entries[2], "prove_ex.json")] {
+        assert_eq!(entry["status"], "ok");
+        assert!(entry["proof_bytes_len"].as_u64().is_some());
+        let artifact: InteropArtifact =
+            serde_json::from_slice(&fs::read(dir.join(name)).expect("artifact written"))
+                .expect("artifact parses");
+        let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+        proof_bytes.push(artifact.proof_bytes_hex);
+    }
+    assert_eq!(proof_bytes[0], proof_bytes[1]);
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn manifest_is_batch_generate_only() {
+    let run = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "generate",
+            "--example",
+            "xor",
+            "--artifact",
+            "unused.json",
+            "--manifest",
+            "manifest.json",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!run.status.success());
+    assert!(String::from_utf8_lossy(&run.stderr).contains("--manifest"));
+}
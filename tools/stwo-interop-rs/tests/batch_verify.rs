@@ -0,0 +1,88 @@
+//! `--mode batch-verify` verifies a directory of artifacts in parallel: artifacts pass when their
+//! outcome matches their `expected_verify_result`, and any other outcome fails the run unless
+//! `--keep-going` is set.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use stwo_interop_rs::Statement;
+
+fn artifact(log_n_rows: u32) -> serde_json::Value {
+    common::artifact_json(Statement::WideFibonacci {
+        log_n_rows,
+        sequence_len: 16,
+    })
+}
+
+fn with_tampered_pow(mut artifact: serde_json::Value) -> serde_json::Value {
+    let proof_bytes =
+        hex::decode(artifact["proof_bytes_hex"].as_str().expect("hex")).expect("hex decodes");
+    let mut wire: serde_json::Value = serde_json::from_slice(&proof_bytes).expect("wire json");
+    wire["proof_of_work"] = 1.into();
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire json");
+    artifact["proof_bytes_len"] = proof_bytes.len().into();
+    artifact
+        .as_object_mut()
+        .expect("artifact object")
+        .remove("proof_bytes_blake3");
+    artifact["proof_bytes_hex"] = hex::encode(proof_bytes).into();
+    artifact
+}
+
+fn batch_verify(dir: &Path, extra: &[&str]) -> (bool, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "batch-verify", "--jobs", "3", "--artifact-dir"])
+        .arg(dir)
+        .args(extra)
+        .output()
+        .expect("stwo-interop-rs runs");
+    let report = serde_json::from_slice(&output.stdout).expect("json report");
+    (output.status.success(), report)
+}
+
+#[test]
+fn batch_verify_reports_every_artifact() {
+    let dir =
+        std::env::temp_dir().join(format!("stwo-interop-batch-verify-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    let write = |name: &str, artifact: &serde_json::Value| {
+        fs::write(dir.join(name), artifact.to_string()).expect("write artifact")
+    };
+    write("a_valid.json", &artifact(5));
+    write("b_valid.json", &artifact(6));
+    let mut negative = with_tampered_pow(artifact(5));
+    negative["expected_verify_result"] = "pow_policy".into();
+    write("c_negative.json", &negative);
+    fs::write(dir.join("notes.txt"), "not an artifact").expect("write notes");
+
+    let (ok, report) = batch_verify(&dir, &[]);
+    assert!(ok);
+    assert_eq!(report["total"], 3);
+    assert_eq!(report["passed"], 3);
+    assert_eq!(report["expected_rejections"], 1);
+    let artifacts = report["artifacts"].as_array().expect("artifacts");
+    let names: Vec<_> = artifacts
+        .iter()
+        .map(|entry| entry["artifact"].as_str().expect("name"))
+        .collect();
+    assert_eq!(names, ["a_valid.json", "b_valid.json", "c_negative.json"]);
+    assert!(artifacts[0]["proof_bytes_len"].as_u64().is_some());
+    assert!(artifacts[0]["verify_seconds"].as_f64().is_some());
+    assert_eq!(artifacts[2]["error_kind"], "pow_policy");
+
+    write("d_tampered.json", &with_tampered_pow(artifact(5)));
+    let (ok, report) = batch_verify(&dir, &[]);
+    assert!(!ok);
+    assert_eq!(report["failed"], 1);
+    assert_eq!(report["artifacts"][3]["status"], "failed");
+    assert_eq!(report["artifacts"][3]["error_kind"], "pow_policy");
+
+    let (ok, report) = batch_verify(&dir, &["--keep-going"]);
+    assert!(ok);
+    assert_eq!(report["status"], "fail");
+
+    fs::remove_dir_all(&dir).ok();
+}
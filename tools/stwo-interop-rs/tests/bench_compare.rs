@@ -0,0 +1,110 @@
+//! `--mode bench-compare` forms candidate/baseline ratios from two bench reports and fails only
+//! when a ratio exceeds its threshold; `--bench-format csv` prints one row per metric.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn report(prove_avg: f64, proof_wire_bytes: u64, peak_rss: Option<u64>) -> serde_json::Value {
+    let mut report = serde_json::json!({
+        "runtime": "rust",
+        "example": "xor",
+        "prove": { "avg_seconds": prove_avg },
+        "verify": { "avg_seconds": 0.5 },
+        "proof_metrics": { "proof_wire_bytes": proof_wire_bytes },
+    });
+    if let Some(peak_rss) = peak_rss {
+        report["prove_peak_rss_bytes"] = peak_rss.into();
+        report["verify_peak_rss_bytes"] = peak_rss.into();
+    }
+    report
+}
+
+fn write(dir: &Path, name: &str, report: &serde_json::Value) -> PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, report.to_string()).expect("write report");
+    path
+}
+
+fn compare(baseline: &Path, candidate: &Path, extra: &[&str]) -> (bool, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "bench-compare", "--baseline"])
+        .arg(baseline)
+        .arg("--candidate")
+        .arg(candidate)
+        .args(extra)
+        .output()
+        .expect("stwo-interop-rs runs");
+    let report = serde_json::from_slice(&output.stdout).expect("json report");
+    (output.status.success(), report)
+}
+
+#[test]
+fn ratios_are_checked_against_thresholds() {
+    let dir =
+        std::env::temp_dir().join(format!("stwo-interop-bench-compare-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    let baseline = write(&dir, "baseline.json", &report(2.0, 1000, Some(4096)));
+    let candidate = write(&dir, "candidate.json", &report(1.9, 1000, Some(8192)));
+
+    let (ok, result) = compare(&baseline, &candidate, &["--max-prove-ratio", "0.98"]);
+    assert!(ok);
+    let ratios = result["ratios"].as_array().expect("ratios");
+    assert_eq!(ratios.len(), 5);
+    assert_eq!(ratios[0]["metric"], "prove.avg_seconds");
+    assert_eq!(ratios[0]["status"], "ok");
+    assert!((ratios[0]["ratio"].as_f64().expect("ratio") - 0.95).abs() < 1e-9);
+    assert_eq!(ratios[3]["status"], "unchecked");
+
+    let (ok, result) = compare(&baseline, &candidate, &["--max-peak-rss-ratio", "1.5"]);
+    assert!(!ok);
+    assert_eq!(result["status"], "fail");
+    assert_eq!(result["ratios"][3]["status"], "exceeded");
+
+    let without_rss = write(&dir, "without_rss.json", &report(2.0, 1000, None));
+    let (ok, result) = compare(&without_rss, &candidate, &["--max-peak-rss-ratio", "1.5"]);
+    assert!(ok);
+    assert_eq!(result["ratios"].as_array().expect("ratios").len(), 3);
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn csv_has_one_row_per_metric() {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "bench",
+            "--example",
+            "xor",
+            "--artifact",
+            "unused.json",
+            "--bench-warmups",
+            "0",
+            "--bench-repeats",
+            "2",
+            "--bench-format",
+            "csv",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(
+        output.status.success(),
+        "bench failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).expect("utf-8");
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("example,metric,value"));
+    let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+    assert!(rows.iter().all(|row| row.len() == 3 && row[0] == "xor"));
+    for metric in [
+        "prove.avg_seconds",
+        "prove.p90_seconds",
+        "verify.median_seconds",
+        "proof_metrics.proof_wire_bytes",
+        "prove_phases.core_prove",
+    ] {
+        assert!(rows.iter().any(|row| row[1] == metric), "missing {metric}");
+    }
+}
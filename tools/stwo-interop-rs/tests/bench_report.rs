@@ -0,0 +1,104 @@
+//! `--mode bench --bench-out` writes the same report it prints, with percentile statistics over
+//! the samples, a prove phase breakdown, the total wall time, per-phase peak RSS and the
+//! effective `PcsConfig`.
+
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn bench_out_matches_stdout_and_orders_percentiles() {
+    let path = std::env::temp_dir().join(format!("stwo-interop-bench-{}.json", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "bench",
+            "--example",
+            "xor",
+            "--artifact",
+            "unused.json",
+            "--fri-log-blowup",
+            "2",
+            "--bench-warmups",
+            "0",
+            "--bench-repeats",
+            "4",
+            "--bench-out",
+        ])
+        .arg(&path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(
+        output.status.success(),
+        "bench failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let printed: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout json");
+    let written: serde_json::Value =
+        serde_json::from_slice(&fs::read(&path).expect("bench report written")).expect("json");
+    fs::remove_file(&path).ok();
+    assert_eq!(printed, written);
+
+    assert_eq!(written["pcs_config"]["fri_config"]["log_blowup_factor"], 2);
+    let mut sampled_seconds = 0.0;
+    for phase in ["prove", "verify"] {
+        let timing = &written[phase];
+        let stat = |name: &str| timing[name].as_f64().expect(name);
+        assert_eq!(
+            timing["samples_seconds"].as_array().expect("samples").len(),
+            4
+        );
+        assert!(stat("min_seconds") <= stat("median_seconds"));
+        assert!(stat("median_seconds") <= stat("p90_seconds"));
+        assert!(stat("p90_seconds") <= stat("p99_seconds"));
+        assert!(stat("p99_seconds") <= stat("max_seconds"));
+        assert!(stat("stddev_seconds") >= 0.0);
+        sampled_seconds += stat("avg_seconds") * 4.0;
+    }
+    assert!(written["total_wall_seconds"].as_f64().expect("wall time") >= sampled_seconds);
+    let phases = written["prove_phases"].as_object().expect("prove phases");
+    let mut names: Vec<_> = phases.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    assert_eq!(
+        names,
+        [
+            "commit",
+            "composition",
+            "core_prove",
+            "fri",
+            "proof_of_work",
+            "serialization",
+            "statement_mix",
+            "trace_generation",
+            "twiddle_precompute"
+        ]
+    );
+    let phase_seconds: f64 = phases
+        .values()
+        .map(|seconds| seconds.as_f64().expect("seconds"))
+        .sum();
+    assert!(phase_seconds <= written["prove"]["avg_seconds"].as_f64().expect("avg") * 1.01);
+    if cfg!(unix) {
+        for field in ["prove_peak_rss_bytes", "verify_peak_rss_bytes"] {
+            assert!(written[field].as_u64().expect(field) > 0);
+        }
+    }
+}
+
+#[test]
+fn bench_out_is_bench_only() {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "generate",
+            "--example",
+            "xor",
+            "--artifact",
+            "unused.json",
+            "--bench-out",
+            "bench.json",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--bench-out"));
+}
@@ -0,0 +1,74 @@
+//! Generated artifacts carry `proof_bytes_blake3` over the decompressed proof wire bytes, and
+//! verify checks it before decoding the proof wire: a corrupted payload fails at the `checksum`
+//! stage, not deep in the verifier.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use stwo_interop_rs::{
+    verify_artifact, GenerateOptions, InteropArtifact, ProofCompression, VerifyOptions,
+};
+
+fn artifact() -> InteropArtifact {
+    artifact_with(ProofCompression::None)
+}
+
+fn artifact_with(proof_compression: ProofCompression) -> InteropArtifact {
+    let mut options = GenerateOptions::default();
+    options.proof_compression = proof_compression;
+    common::artifact_with(common::wide_fibonacci(), &options)
+}
+
+#[test]
+fn bit_flipped_payload_fails_at_the_checksum_stage() {
+    let mut artifact = artifact();
+    let checksum = artifact
+        .proof_bytes_blake3
+        .clone()
+        .expect("checksum written");
+    assert_eq!(checksum.len(), 64);
+
+    let mut proof_bytes = hex::decode(&artifact.proof_bytes_hex).expect("hex decodes");
+    let middle = proof_bytes.len() / 2;
+    proof_bytes[middle] ^= 0x01;
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("checksum fails");
+    assert!(format!("{err:#}").contains("checksum_mismatch"));
+
+    let path =
+        std::env::temp_dir().join(format!("stwo-interop-checksum-{}.json", std::process::id()));
+    fs::write(&path, serde_json::to_vec(&artifact).expect("json")).expect("write");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(&path)
+        .args(["--expect", "failure", "--expect-error", "ChecksumMismatch"])
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_file(&path).ok();
+    assert!(output.status.success());
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("one-line json report");
+    assert_eq!(report["error_kind"], "checksum");
+}
+
+#[test]
+fn artifacts_without_a_checksum_still_verify() {
+    let mut artifact = artifact();
+    artifact.proof_bytes_blake3 = None;
+    let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+}
+
+#[test]
+fn compressed_and_uncompressed_artifacts_share_a_checksum() {
+    let plain = artifact_with(ProofCompression::None);
+    let compressed = artifact_with(ProofCompression::Zstd);
+    assert_ne!(plain.proof_bytes_len, compressed.proof_bytes_len);
+    assert!(plain.proof_bytes_blake3.is_some());
+    assert_eq!(plain.proof_bytes_blake3, compressed.proof_bytes_blake3);
+    let report = verify_artifact(&compressed, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+}
@@ -0,0 +1,66 @@
+//! Artifact factories shared by the integration tests: every test proves under the same cheap
+//! `PcsConfig`, and most of them a small wide fibonacci statement.
+
+// Each test crate compiles its own copy and uses only some of these.
+#![allow(dead_code)]
+
+use stwo_interop_rs::{
+    generate_artifact, verify_artifact, FriConfig, GenerateOptions, InteropArtifact, PcsConfig,
+    Statement, TracePerturbation, VerifyOptions,
+};
+
+/// No proof of work, blowup 2 and three queries: cheap to prove, and enough for the verifier.
+pub fn config() -> PcsConfig {
+    PcsConfig {
+        pow_bits: 0,
+        fri_config: FriConfig::new(0, 1, 3),
+    }
+}
+
+/// 2^5 rows of 16-element fibonacci sequences.
+pub fn wide_fibonacci() -> Statement {
+    Statement::WideFibonacci {
+        log_n_rows: 5,
+        sequence_len: 16,
+    }
+}
+
+/// Proves `statement` under [`config`], leaving failures to the caller.
+pub fn generate(
+    statement: Statement,
+    options: &GenerateOptions,
+) -> anyhow::Result<InteropArtifact> {
+    generate_artifact(statement.example(), statement, config(), options)
+}
+
+/// Proves `statement` under [`config`] with `options`.
+pub fn artifact_with(statement: Statement, options: &GenerateOptions) -> InteropArtifact {
+    generate(statement, options).expect("artifact generates")
+}
+
+/// Proves `statement` under [`config`] with default options.
+pub fn artifact(statement: Statement) -> InteropArtifact {
+    artifact_with(statement, &GenerateOptions::default())
+}
+
+/// [`artifact`] as JSON, for tests that edit fields the typed artifact does not expose.
+pub fn artifact_json(statement: Statement) -> serde_json::Value {
+    serde_json::to_value(artifact(statement)).expect("artifact json")
+}
+
+/// Proves `statement` with its main trace perturbed and checks the broken constraint is
+/// caught: upstream `prove` refuses the trace, or the proof it writes fails the OODS check.
+pub fn assert_perturbed_trace_rejected(statement: Statement, perturbation: TracePerturbation) {
+    let mut options = GenerateOptions::default();
+    options.trace_perturbation = Some(perturbation);
+    let err = match generate(statement, &options) {
+        Err(err) => err,
+        Ok(artifact) => verify_artifact(&artifact, &VerifyOptions::default())
+            .expect_err("perturbed trace verifies"),
+    };
+    let message = format!("{err:#}").to_lowercase();
+    assert!(
+        message.contains("constraint") || message.contains("oods"),
+        "unexpected error: {message}"
+    );
+}
@@ -0,0 +1,120 @@
+//! Verify rebuilds the example component and compares it with the declared `component_metadata`
+//! field by field: tampering any one field alone fails at the `component_metadata` stage, names
+//! that field, and is classified as a schema mismatch.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use stwo_interop_rs::{
+    verify_artifact, ComponentMetadataWire, InteropArtifact, InteropError, Statement, VerifyOptions,
+};
+
+/// Error code the verifier puts in front of a metadata mismatch.
+const COMPONENT_METADATA_MISMATCH: &str = "component_metadata_mismatch";
+
+fn more_constraints(metadata: &mut ComponentMetadataWire) {
+    metadata.n_constraints += 1;
+}
+
+fn higher_degree_bound(metadata: &mut ComponentMetadataWire) {
+    metadata.max_constraint_log_degree_bound += 1;
+}
+
+fn extra_preprocessed_index(metadata: &mut ComponentMetadataWire) {
+    let next = metadata
+        .preprocessed_column_indices
+        .last()
+        .map_or(0, |index| index + 1);
+    metadata.preprocessed_column_indices.push(next);
+}
+
+fn extra_mask_point(metadata: &mut ComponentMetadataWire) {
+    metadata
+        .mask_shape
+        .last_mut()
+        .expect("mask shape has trees")
+        .push(1);
+}
+
+const TAMPERS: [(&str, fn(&mut ComponentMetadataWire)); 4] = [
+    ("n_constraints", more_constraints),
+    ("max_constraint_log_degree_bound", higher_degree_bound),
+    ("preprocessed_column_indices", extra_preprocessed_index),
+    ("mask_shape", extra_mask_point),
+];
+
+fn artifacts() -> Vec<(&'static str, InteropArtifact)> {
+    [
+        ("wide_fibonacci", common::wide_fibonacci()),
+        (
+            "xor",
+            Statement::Xor {
+                log_size: 5,
+                log_step: 2,
+                offset: 3,
+            },
+        ),
+    ]
+    .into_iter()
+    .map(|(name, statement)| (name, common::artifact(statement)))
+    .collect()
+}
+
+/// Runs `--mode verify` on `artifact` and returns the failure report's `kind` after checking the
+/// exit status is that kind's.
+fn verify_failure_kind(name: &str, artifact: &InteropArtifact) -> String {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-component-metadata-{name}-{}.json",
+        std::process::id()
+    ));
+    fs::write(&path, serde_json::to_vec(artifact).expect("json")).expect("write");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(&path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_file(&path).ok();
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("one-line json report");
+    assert_eq!(report["status"], "fail", "{name}");
+    assert_eq!(
+        output.status.code(),
+        Some(i32::from(InteropError::SchemaMismatch.exit_code())),
+        "{name}"
+    );
+    report["kind"].as_str().expect("kind").to_string()
+}
+
+#[test]
+fn each_tampered_field_is_rejected_by_name() {
+    for (example, artifact) in artifacts() {
+        verify_artifact(&artifact, &VerifyOptions::default())
+            .unwrap_or_else(|err| panic!("{example} untampered: {err:#}"));
+
+        for (field, tamper) in TAMPERS {
+            let mut tampered = artifact.clone();
+            tamper(
+                tampered
+                    .component_metadata
+                    .as_mut()
+                    .expect("component metadata written"),
+            );
+            assert_ne!(tampered.component_metadata, artifact.component_metadata);
+
+            let err = verify_artifact(&tampered, &VerifyOptions::default())
+                .expect_err("tampered metadata fails");
+            let message = format!("{err:#}");
+            assert!(
+                message.starts_with(&format!("{COMPONENT_METADATA_MISMATCH}: {field}: ")),
+                "{example} {field}: {message}"
+            );
+            assert_eq!(
+                verify_failure_kind(&format!("{example}-{field}"), &tampered),
+                InteropError::SchemaMismatch.as_str(),
+                "{example} {field}"
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! A failed `--mode verify` prints `{"status":"fail","kind":...}` and exits with the kind's own
+//! status, so gates classify rejections from the kind instead of the error text. Usage errors and
+//! the other modes keep exiting with 1.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use stwo_interop_rs::{InteropArtifact, InteropError};
+
+const EXIT_CODES: [(InteropError, &str, u8); 10] = [
+    (InteropError::SchemaMismatch, "schema_mismatch", 10),
+    (InteropError::ChecksumMismatch, "checksum_mismatch", 11),
+    (InteropError::DecodeError, "decode_error", 12),
+    (InteropError::StatementInvalid, "statement_invalid", 13),
+    (InteropError::ProofShapeInvalid, "proof_shape_invalid", 14),
+    (InteropError::MerkleRootMismatch, "merkle_root_mismatch", 15),
+    (
+        InteropError::FriVerificationFailed,
+        "fri_verification_failed",
+        16,
+    ),
+    (InteropError::PowFailed, "pow_failed", 17),
+    (InteropError::OodsMismatch, "oods_mismatch", 18),
+    (InteropError::Other, "other", 19),
+];
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "stwo-interop-error-taxonomy-{name}-{}.json",
+        std::process::id()
+    ))
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+/// Verifies `path` and returns the failure report's `kind` after checking the exit status is
+/// that kind's.
+fn verify_failure_kind(path: &Path) -> String {
+    let output = run(&[
+        "--mode",
+        "verify",
+        "--artifact",
+        path.to_str().expect("utf-8 path"),
+    ]);
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("one-line json report");
+    assert_eq!(report["status"], "fail");
+    let kind = report["kind"].as_str().expect("kind").to_string();
+    let (_, _, code) = EXIT_CODES
+        .iter()
+        .find(|(_, name, _)| *name == kind)
+        .unwrap_or_else(|| panic!("unknown kind {kind}"));
+    assert_eq!(output.status.code(), Some(i32::from(*code)), "{kind}");
+    kind
+}
+
+fn wide_fibonacci_artifact() -> InteropArtifact {
+    common::artifact(common::wide_fibonacci())
+}
+
+#[test]
+fn every_kind_has_a_distinct_stable_exit_code() {
+    for (kind, name, code) in EXIT_CODES {
+        assert_eq!(kind.as_str(), name);
+        assert_eq!(kind.to_string(), name);
+        assert_eq!(kind.exit_code(), code);
+        assert_eq!(serde_json::to_value(kind).expect("json"), name);
+    }
+    let mut codes: Vec<_> = EXIT_CODES.iter().map(|(_, _, code)| *code).collect();
+    codes.sort_unstable();
+    codes.dedup();
+    assert_eq!(codes.len(), EXIT_CODES.len());
+    assert!(!codes.contains(&1));
+}
+
+#[test]
+fn pre_verifier_stages_map_to_their_kinds() {
+    let mut cases = Vec::new();
+
+    let mut artifact = wide_fibonacci_artifact();
+    artifact.upstream_commit = "0".repeat(40);
+    cases.push(("schema", artifact, "schema_mismatch"));
+
+    let mut artifact = wide_fibonacci_artifact();
+    let mut proof_bytes = hex::decode(&artifact.proof_bytes_hex).expect("hex decodes");
+    let middle = proof_bytes.len() / 2;
+    proof_bytes[middle] ^= 0x01;
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    cases.push(("checksum", artifact, "checksum_mismatch"));
+
+    let mut artifact = wide_fibonacci_artifact();
+    artifact.proof_bytes_hex = hex::encode(b"not a proof wire");
+    artifact.proof_bytes_len = Some(16);
+    artifact.proof_bytes_blake3 = None;
+    cases.push(("decode", artifact, "decode_error"));
+
+    let mut artifact = wide_fibonacci_artifact();
+    artifact.wide_fibonacci_statement = None;
+    cases.push(("statement", artifact, "statement_invalid"));
+
+    for (name, artifact, expected) in cases {
+        let path = temp_path(name);
+        fs::write(&path, serde_json::to_vec(&artifact).expect("json")).expect("write");
+        let kind = verify_failure_kind(&path);
+        fs::remove_file(&path).ok();
+        assert_eq!(kind, expected, "{name}");
+    }
+}
+
+#[test]
+fn tampered_proofs_map_to_verifier_kinds() {
+    for (tamper_kind, expected) in [
+        ("sampled_value", Some("oods_mismatch")),
+        ("queried_values_swap", Some("merkle_root_mismatch")),
+        ("proof_of_work", Some("pow_failed")),
+        ("commitment_byte", None),
+        ("hash_witness_truncate", None),
+        ("last_layer_poly", None),
+    ] {
+        let path = temp_path(tamper_kind);
+        let path_str = path.to_str().expect("utf-8 path");
+        let tampered = run(&[
+            "--mode",
+            "tamper",
+            "--example",
+            "wide_fibonacci",
+            "--tamper-kind",
+            tamper_kind,
+            "--artifact",
+            path_str,
+        ]);
+        assert!(tampered.status.success(), "{tamper_kind}");
+        let kind = verify_failure_kind(&path);
+        fs::remove_file(&path).ok();
+        match expected {
+            Some(expected) => assert_eq!(kind, expected, "{tamper_kind}"),
+            None => assert!(
+                ![
+                    "schema_mismatch",
+                    "checksum_mismatch",
+                    "decode_error",
+                    "other"
+                ]
+                .contains(&kind.as_str()),
+                "{tamper_kind}: {kind}"
+            ),
+        }
+    }
+}
+
+#[test]
+fn honest_verify_and_other_modes_keep_their_exit_codes() {
+    let path = temp_path("honest");
+    let path_str = path.to_str().expect("utf-8 path");
+    fs::write(
+        &path,
+        serde_json::to_vec(&wide_fibonacci_artifact()).expect("json"),
+    )
+    .expect("write");
+    let verified = run(&["--mode", "verify", "--artifact", path_str]);
+    fs::remove_file(&path).ok();
+    assert_eq!(verified.status.code(), Some(0));
+    let report: serde_json::Value = serde_json::from_slice(&verified.stdout).expect("report");
+    assert_eq!(report["status"], "ok");
+    assert!(report.get("kind").is_none());
+
+    let missing = run(&["--mode", "verify", "--artifact", path_str]);
+    assert_eq!(missing.status.code(), Some(1));
+    assert!(missing.stdout.is_empty());
+
+    let generate = run(&["--mode", "generate", "--example", "unknown"]);
+    assert_eq!(generate.status.code(), Some(1));
+}
@@ -0,0 +1,90 @@
+//! Verify mode under `--expect`: the process succeeds exactly when the outcome matches the
+//! expectation, and a one-line `{status, error_kind, message}` report is printed either way.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn write_artifact(name: &str, tamper_pow: bool) -> PathBuf {
+    let mut artifact = common::artifact_json(common::wide_fibonacci());
+    if tamper_pow {
+        let proof_bytes =
+            hex::decode(artifact["proof_bytes_hex"].as_str().expect("hex")).expect("hex decodes");
+        let mut wire: serde_json::Value = serde_json::from_slice(&proof_bytes).expect("wire json");
+        wire["proof_of_work"] = 1.into();
+        let proof_bytes = serde_json::to_vec(&wire).expect("wire json");
+        artifact["proof_bytes_len"] = proof_bytes.len().into();
+        artifact
+            .as_object_mut()
+            .expect("artifact object")
+            .remove("proof_bytes_blake3");
+        artifact["proof_bytes_hex"] = hex::encode(proof_bytes).into();
+    }
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-expect-{}-{name}.json",
+        std::process::id()
+    ));
+    fs::write(&path, serde_json::to_vec(&artifact).expect("json")).expect("write");
+    path
+}
+
+fn verify(artifact: &Path, expect: &[&str]) -> (bool, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(artifact)
+        .args(expect)
+        .output()
+        .expect("stwo-interop-rs runs");
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 output");
+    let report = serde_json::from_str(stdout.trim()).expect("one-line json report");
+    (output.status.success(), report)
+}
+
+#[test]
+fn expectations_on_a_valid_artifact() {
+    let artifact = write_artifact("valid", false);
+
+    let (ok, report) = verify(&artifact, &["--expect", "success"]);
+    assert!(ok);
+    assert_eq!(report["status"], "pass");
+    assert!(report["error_kind"].is_null());
+
+    let (ok, report) = verify(&artifact, &["--expect", "failure"]);
+    assert!(!ok);
+    assert_eq!(report["status"], "fail");
+
+    fs::remove_file(artifact).ok();
+}
+
+#[test]
+fn expectations_on_a_tampered_artifact() {
+    let artifact = write_artifact("pow", true);
+
+    let (ok, report) = verify(&artifact, &["--expect", "failure"]);
+    assert!(ok);
+    assert_eq!(report["status"], "pass");
+    assert_eq!(report["error_kind"], "pow_policy");
+    assert!(report["message"].is_string());
+
+    let (ok, _) = verify(
+        &artifact,
+        &["--expect", "failure", "--expect-error", "pow_policy"],
+    );
+    assert!(ok);
+
+    let (ok, report) = verify(
+        &artifact,
+        &["--expect", "failure", "--expect-error", "RootMismatch"],
+    );
+    assert!(!ok);
+    assert_eq!(report["status"], "fail");
+    assert_eq!(report["error_kind"], "pow_policy");
+
+    let (ok, report) = verify(&artifact, &["--expect", "success"]);
+    assert!(!ok);
+    assert_eq!(report["status"], "fail");
+
+    fs::remove_file(artifact).ok();
+}
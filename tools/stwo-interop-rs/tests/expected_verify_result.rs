@@ -0,0 +1,148 @@
+//! Verify mode reports the gate outcome of `expected_verify_result`: a negative fixture passes
+//! only when it fails at the expected stage, fails the gate when it verifies or drifts to another
+//! stage, and an artifact without the field carries no expectation and is skipped by
+//! cross-verify.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn artifact() -> serde_json::Value {
+    common::artifact_json(common::wide_fibonacci())
+}
+
+/// A nonzero nonce at `pow_bits = 0`, which verify rejects at the `pow_policy` stage.
+fn with_tampered_pow(mut artifact: serde_json::Value) -> serde_json::Value {
+    let proof_bytes =
+        hex::decode(artifact["proof_bytes_hex"].as_str().expect("hex")).expect("hex decodes");
+    let mut wire: serde_json::Value = serde_json::from_slice(&proof_bytes).expect("wire json");
+    wire["proof_of_work"] = 1.into();
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire json");
+    artifact["proof_bytes_len"] = proof_bytes.len().into();
+    artifact
+        .as_object_mut()
+        .expect("artifact object")
+        .remove("proof_bytes_blake3");
+    artifact["proof_bytes_hex"] = hex::encode(proof_bytes).into();
+    artifact
+}
+
+/// Runs verify mode on `artifact` and returns the exit status and the printed report.
+fn verify(dir: &Path, name: &str, artifact: &serde_json::Value) -> (bool, serde_json::Value) {
+    let path = dir.join(name);
+    fs::write(&path, artifact.to_string()).expect("write artifact");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(&path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report = serde_json::from_str(stdout.lines().last().expect("report line")).expect("json");
+    (output.status.success(), report)
+}
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    dir
+}
+
+#[test]
+fn negative_fixture_failing_at_the_expected_stage_passes() {
+    let dir = temp_dir("expect-pass");
+    let mut negative = with_tampered_pow(artifact());
+    negative["expected_verify_result"] = "pow_policy".into();
+    let (ok, report) = verify(&dir, "negative.json", &negative);
+    fs::remove_dir_all(&dir).ok();
+
+    assert!(!ok);
+    assert_eq!(report["status"], "fail");
+    assert_eq!(report["actual_verify_result"], "pow_policy");
+    assert_eq!(report["expected_verify_result"], "pow_policy");
+    assert_eq!(report["matches_expected"], true);
+}
+
+#[test]
+fn negative_fixture_that_verifies_fails_the_gate() {
+    let dir = temp_dir("expect-verifies");
+    let mut negative = artifact();
+    negative["expected_verify_result"] = "verify".into();
+    let (ok, report) = verify(&dir, "negative.json", &negative);
+    fs::remove_dir_all(&dir).ok();
+
+    assert!(ok);
+    assert_eq!(report["actual_verify_result"], "ok");
+    assert_eq!(report["expected_verify_result"], "verify");
+    assert_eq!(report["matches_expected"], false);
+}
+
+#[test]
+fn negative_fixture_failing_at_another_stage_fails_the_gate() {
+    let dir = temp_dir("expect-drift");
+    let mut negative = with_tampered_pow(artifact());
+    negative["expected_verify_result"] = "verify".into();
+    let (ok, report) = verify(&dir, "negative.json", &negative);
+    fs::remove_dir_all(&dir).ok();
+
+    assert!(!ok);
+    assert_eq!(report["actual_verify_result"], "pow_policy");
+    assert_eq!(report["expected_verify_result"], "verify");
+    assert_eq!(report["matches_expected"], false);
+}
+
+#[test]
+fn artifact_without_expectation_is_skipped() {
+    let dir = temp_dir("expect-skipped");
+    let (ok, report) = verify(&dir, "a_regular.json", &artifact());
+    assert!(ok);
+    for field in [
+        "actual_verify_result",
+        "expected_verify_result",
+        "matches_expected",
+    ] {
+        assert!(report.get(field).is_none(), "{field}");
+    }
+
+    let mut negative = with_tampered_pow(artifact());
+    negative["expected_verify_result"] = "pow_policy".into();
+    fs::write(dir.join("b_negative.json"), negative.to_string()).expect("write artifact");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "cross-verify", "--artifacts"])
+        .arg(&dir)
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_dir_all(&dir).ok();
+    assert!(
+        output.status.success(),
+        "cross-verify failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json report");
+    assert_eq!(
+        report["skipped_negative_fixtures"],
+        serde_json::json!(["b_negative.json"])
+    );
+    assert_eq!(
+        report["groups"][0]["artifacts"],
+        serde_json::json!(["a_regular.json"])
+    );
+}
+
+#[test]
+fn invalid_expectation_is_rejected() {
+    let dir = temp_dir("expect-invalid");
+    let path = dir.join("invalid.json");
+    let mut invalid = artifact();
+    invalid["expected_verify_result"] = "no_such_stage".into();
+    fs::write(&path, invalid.to_string()).expect("write artifact");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(&path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_dir_all(&dir).ok();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid expected_verify_result"));
+}
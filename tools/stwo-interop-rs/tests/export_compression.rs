@@ -0,0 +1,143 @@
+//! `--export-compress` writes debug exports plain, gzip- or zstd-compressed next to a manifest of
+//! the uncompressed bytes, and `read_export` reads any of the three back by sniffing magic bytes.
+//! Artifacts are never sniffed: a compressed artifact is rejected rather than decompressed.
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+use stwo_interop_rs::read_export;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("stwo-interop-export-{name}-{}", std::process::id()))
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+/// Writes a wide_fibonacci stage profile with `compression` and returns its raw bytes, the bytes
+/// `read_export` reads back, and the manifest.
+fn stage_profile(compression: &str) -> (Vec<u8>, Vec<u8>, serde_json::Value) {
+    let artifact = temp_path(&format!("{compression}-artifact.json"));
+    let profile = temp_path(&format!("{compression}-profile.json"));
+    let profile_str = profile.to_str().expect("utf-8 path");
+    let output = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        "wide_fibonacci",
+        "--artifact",
+        artifact.to_str().expect("utf-8 path"),
+        "--stage-profile-out",
+        profile_str,
+        "--export-compress",
+        compression,
+    ]);
+    fs::remove_file(&artifact).ok();
+    assert!(output.status.success(), "{compression}");
+
+    let raw = fs::read(&profile).expect("profile written");
+    let read = read_export(profile_str).expect("export reads");
+    let manifest_path = format!("{profile_str}.manifest.json");
+    let manifest = serde_json::from_slice(&fs::read(&manifest_path).expect("manifest written"))
+        .expect("manifest json");
+    fs::remove_file(&profile).ok();
+    fs::remove_file(&manifest_path).ok();
+    (raw, read, manifest)
+}
+
+fn check_against_manifest(compression: &str, read: &[u8], manifest: &serde_json::Value) {
+    assert_eq!(manifest["compression"], compression);
+    assert_eq!(manifest["uncompressed_size"], read.len());
+    let profile: serde_json::Value = serde_json::from_slice(read).expect("profile json");
+    assert_eq!(profile["runtime"], "rust", "{compression}");
+}
+
+#[test]
+fn plain_exports_read_back_unchanged() {
+    let (raw, read, manifest) = stage_profile("none");
+    assert_eq!(raw, read);
+    check_against_manifest("none", &read, &manifest);
+}
+
+#[test]
+fn gzip_exports_are_sniffed_and_decompressed() {
+    let (raw, read, manifest) = stage_profile("gzip");
+    assert!(raw.starts_with(&[0x1f, 0x8b]));
+    assert_ne!(raw, read);
+    check_against_manifest("gzip", &read, &manifest);
+}
+
+#[test]
+fn zstd_exports_are_sniffed_and_decompressed() {
+    let (raw, read, manifest) = stage_profile("zstd");
+    assert!(raw.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]));
+    assert_ne!(raw, read);
+    check_against_manifest("zstd", &read, &manifest);
+}
+
+#[test]
+fn corrupt_streams_are_rejected() {
+    let mut truncated_gzip =
+        flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    truncated_gzip
+        .write_all(b"{\"runtime\":\"rust\"}")
+        .expect("gzip write");
+    let mut truncated_gzip = truncated_gzip.finish().expect("gzip finish");
+    truncated_gzip.truncate(truncated_gzip.len() - 6);
+    let mut truncated_zstd =
+        zstd::stream::encode_all(&b"{\"runtime\":\"rust\"}"[..], 3).expect("zstd encodes");
+    truncated_zstd.truncate(truncated_zstd.len() - 3);
+
+    for (name, bytes) in [
+        ("gzip-garbage", [&[0x1f, 0x8b][..], b"not gzip"].concat()),
+        ("gzip-truncated", truncated_gzip),
+        (
+            "zstd-garbage",
+            [&[0x28, 0xb5, 0x2f, 0xfd][..], b"not zstd"].concat(),
+        ),
+        ("zstd-truncated", truncated_zstd),
+    ] {
+        let path = temp_path(name);
+        fs::write(&path, bytes).expect("write");
+        let err = read_export(path.to_str().expect("utf-8 path")).expect_err("corrupt stream");
+        fs::remove_file(&path).ok();
+        assert!(
+            format!("{err:#}").contains("failed decompressing"),
+            "{name}: {err:#}"
+        );
+    }
+}
+
+#[test]
+fn compressed_artifacts_are_not_decompressed() {
+    let artifact = temp_path("artifact.json");
+    let artifact_str = artifact.to_str().expect("utf-8 path");
+    let generated = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        "xor",
+        "--artifact",
+        artifact_str,
+    ]);
+    assert!(generated.status.success());
+    let raw = fs::read(&artifact).expect("artifact written");
+    fs::write(
+        &artifact,
+        zstd::stream::encode_all(raw.as_slice(), 3).expect("zstd encodes"),
+    )
+    .expect("write");
+
+    let verified = run(&["--mode", "verify", "--artifact", artifact_str]);
+    fs::remove_file(&artifact).ok();
+    assert!(!verified.status.success());
+    let report: serde_json::Value =
+        serde_json::from_slice(&verified.stdout).expect("one-line json report");
+    assert_eq!(report["kind"], "schema_mismatch");
+}
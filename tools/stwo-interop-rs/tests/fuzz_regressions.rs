@@ -0,0 +1,65 @@
+//! Replays the checked-in fuzz regression corpus under `fuzz/regressions/<target>/` through the
+//! matching decode path. Each input may be accepted or rejected with an error, but must not panic.
+//! Inputs are added with `--mode add-regression`; this harness needs no fuzz tooling.
+
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use stwo_interop_rs::{replay_fuzz_input, FUZZ_REGRESSION_TARGETS};
+
+/// Per-input replay budget; regression inputs are small, so a slow one signals a hang.
+const REPLAY_BUDGET: Duration = Duration::from_secs(1);
+
+fn corpus_inputs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut inputs = entries
+        .map(|entry| entry.expect("readable corpus entry").path())
+        .filter(|path| {
+            path.is_file()
+                && !path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().ends_with(".note.json"))
+        })
+        .collect::<Vec<_>>();
+    inputs.sort();
+    inputs
+}
+
+#[test]
+fn fuzz_regressions_replay_without_panicking() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/regressions");
+    if let Ok(entries) = fs::read_dir(&root) {
+        for entry in entries {
+            let name = entry.expect("readable corpus entry").file_name();
+            let name = name.to_string_lossy();
+            assert!(
+                FUZZ_REGRESSION_TARGETS.contains(&name.as_ref()),
+                "unknown fuzz regression target directory {name}"
+            );
+        }
+    }
+
+    let mut failures = Vec::new();
+    for target in FUZZ_REGRESSION_TARGETS {
+        for input in corpus_inputs(&root.join(target)) {
+            let data = fs::read(&input).expect("readable corpus input");
+            let started = Instant::now();
+            let outcome = panic::catch_unwind(|| replay_fuzz_input(target, &data));
+            let elapsed = started.elapsed();
+            if outcome.is_err() {
+                failures.push(format!("{}: panicked", input.display()));
+            } else if elapsed > REPLAY_BUDGET {
+                failures.push(format!("{}: took {elapsed:?}", input.display()));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "fuzz regressions failed:\n{}",
+        failures.join("\n")
+    );
+}
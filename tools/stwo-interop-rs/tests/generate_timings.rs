@@ -0,0 +1,92 @@
+//! Generate mode reports per-stage seconds: written to `--timings-out` when set, printed as the
+//! last stderr line otherwise. Every stage is present, finite and non-negative, and the stages
+//! never add up to more than the recorded total.
+
+use std::fs;
+use std::process::{Command, Output};
+
+/// Stages of the generate timings summary.
+const STAGES: [&str; 6] = [
+    "trace_generation",
+    "commit",
+    "prove",
+    "wire_encode",
+    "hex_encode",
+    "write",
+];
+
+fn generate(example: &str, extra: &[&str]) -> Output {
+    let artifact = std::env::temp_dir().join(format!(
+        "stwo-interop-timings-{example}-{}.json",
+        std::process::id()
+    ));
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "generate", "--example", example, "--artifact"])
+        .arg(&artifact)
+        .args(extra)
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_file(&artifact).ok();
+    assert!(
+        output.status.success(),
+        "generate {example} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+fn check_timings(example: &str, timings: &serde_json::Value) {
+    assert_eq!(timings["example"], example);
+    let stages = timings["stages"].as_object().expect("stages object");
+    let mut names: Vec<_> = stages.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    let mut expected = STAGES.to_vec();
+    expected.sort_unstable();
+    assert_eq!(names, expected, "{example}");
+
+    let mut sum = 0.0;
+    for stage in STAGES {
+        let seconds = stages[stage].as_f64().expect("stage seconds");
+        assert!(
+            seconds.is_finite() && seconds >= 0.0,
+            "{example} {stage}: {seconds}"
+        );
+        sum += seconds;
+    }
+    let total = timings["total_seconds"].as_f64().expect("total seconds");
+    assert!(
+        total.is_finite() && total >= 0.0,
+        "{example} total: {total}"
+    );
+    assert!(
+        sum <= total + 1e-9,
+        "{example}: stages {sum} exceed total {total}"
+    );
+}
+
+#[test]
+fn timings_out_has_every_stage_non_negative() {
+    for example in ["wide_fibonacci", "xor", "state_machine"] {
+        let path = std::env::temp_dir().join(format!(
+            "stwo-interop-timings-{example}-{}.timings.json",
+            std::process::id()
+        ));
+        generate(
+            example,
+            &["--timings-out", path.to_str().expect("utf-8 path")],
+        );
+        let timings: serde_json::Value =
+            serde_json::from_slice(&fs::read(&path).expect("timings written")).expect("json");
+        fs::remove_file(&path).ok();
+        check_timings(example, &timings);
+    }
+}
+
+#[test]
+fn timings_go_to_stderr_without_timings_out() {
+    let output = generate("wide_fibonacci", &[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let timings: serde_json::Value =
+        serde_json::from_str(stderr.lines().last().expect("timings line")).expect("json");
+    check_timings("wide_fibonacci", &timings);
+}
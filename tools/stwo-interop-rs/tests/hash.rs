@@ -0,0 +1,47 @@
+//! `--hash blake3` commits with Blake3 Merkle trees and records `"hash": "blake3"`; verify
+//! dispatches on that field, and blake2s artifacts carry no `hash` at all.
+
+mod common;
+
+use stwo_interop_rs::{
+    verify_artifact, GenerateOptions, InteropArtifact, ProofHash, ProveBackend, Statement,
+    VerifyOptions,
+};
+
+fn generate(hash: ProofHash, backend: ProveBackend) -> anyhow::Result<InteropArtifact> {
+    let mut options = GenerateOptions::default();
+    options.hash = hash;
+    options.backend = backend;
+    common::generate(Statement::Plonk { log_n_rows: 5 }, &options)
+}
+
+#[test]
+fn blake3_artifacts_verify_and_are_tagged() {
+    let blake2s = generate(ProofHash::Blake2s, ProveBackend::Cpu).expect("blake2s generates");
+    assert!(blake2s.hash.is_none());
+    let json = serde_json::to_value(&blake2s).expect("artifact json");
+    assert!(json.get("hash").is_none());
+
+    let blake3 = generate(ProofHash::Blake3, ProveBackend::Cpu).expect("blake3 generates");
+    assert_eq!(blake3.hash.as_deref(), Some("blake3"));
+    assert_ne!(blake3.proof_bytes_hex, blake2s.proof_bytes_hex);
+    for artifact in [&blake2s, &blake3] {
+        let report = verify_artifact(artifact, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+    }
+
+    let mut relabeled = blake3.clone();
+    relabeled.hash = None;
+    assert!(verify_artifact(&relabeled, &VerifyOptions::default()).is_err());
+
+    let mut unknown = blake3;
+    unknown.hash = Some("sha256".to_string());
+    let err = verify_artifact(&unknown, &VerifyOptions::default()).expect_err("rejected");
+    assert!(err.to_string().contains("unsupported hash sha256"));
+}
+
+#[test]
+fn blake3_requires_the_cpu_backend() {
+    let err = generate(ProofHash::Blake3, ProveBackend::Simd).expect_err("rejected");
+    assert!(err.to_string().contains("--backend cpu"));
+}
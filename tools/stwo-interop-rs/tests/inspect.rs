@@ -0,0 +1,68 @@
+//! `--mode inspect` reports what an artifact holds without verifying it, so it also works on an
+//! artifact whose proof no longer verifies.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn inspect(name: &str, artifact: &serde_json::Value) -> serde_json::Value {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-inspect-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    let path: PathBuf = dir.join(format!("{name}.json"));
+    fs::write(&path, serde_json::to_vec(artifact).expect("artifact json")).expect("write");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "inspect",
+            "--artifact",
+            path.to_str().expect("utf-8 path"),
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_file(&path).ok();
+    assert!(
+        output.status.success(),
+        "inspect failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("json report")
+}
+
+fn wide_fibonacci_artifact() -> serde_json::Value {
+    common::artifact_json(common::wide_fibonacci())
+}
+
+#[test]
+fn inspect_reports_proof_shape() {
+    let report = inspect("valid", &wide_fibonacci_artifact());
+    assert_eq!(report["example"], "wide_fibonacci");
+    assert_eq!(report["statement"]["sequence_len"], 16);
+    assert_eq!(report["compression"], "none");
+    let commitments = report["commitments_count"].as_u64().expect("count");
+    assert_eq!(
+        report["trees"].as_array().expect("trees").len() as u64,
+        commitments
+    );
+    assert!(report["fri"]["first_layer"]["fri_witness_len"]
+        .as_u64()
+        .is_some());
+    assert!(report["size_breakdown_estimate"].is_object());
+    assert!(report["proof_convert_error"].is_null());
+}
+
+#[test]
+fn inspect_works_on_a_proof_that_does_not_verify() {
+    let mut artifact = wide_fibonacci_artifact();
+    let proof_bytes =
+        hex::decode(artifact["proof_bytes_hex"].as_str().expect("hex")).expect("hex decodes");
+    let mut wire: serde_json::Value = serde_json::from_slice(&proof_bytes).expect("wire json");
+    wire["proof_of_work"] = 7.into();
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire json");
+    artifact["proof_bytes_len"] = proof_bytes.len().into();
+    artifact["proof_bytes_hex"] = hex::encode(proof_bytes).into();
+
+    let report = inspect("tampered", &artifact);
+    assert_eq!(report["proof_of_work"], 7);
+}
@@ -0,0 +1,222 @@
+//! Every row of the statement parameter limits rejects a value just past it with a `LimitError`
+//! naming the parameter, the bound it crossed and that bound's `LimitKind`. The checks run before
+//! any trace is built, so crossing a limit costs nothing.
+
+mod common;
+
+use stwo_interop_rs::{GenerateOptions, LimitError, LimitKind, Statement};
+
+/// Error code in front of a limit rejection.
+const PARAM_LIMIT_EXCEEDED: &str = "param_limit_exceeded";
+/// `poseidon::POSEIDON_LOG_INSTANCES_PER_ROW`: instances packed into each trace row, as a log.
+const POSEIDON_LOG_INSTANCES_PER_ROW: u32 = 3;
+
+/// Generates `statement` and returns the limit rejection it must fail with.
+fn rejection(statement: Statement) -> LimitError {
+    let err = common::generate(statement, &GenerateOptions::default())
+        .expect_err("statement past its limit generates");
+    let message = format!("{err:#}");
+    assert!(message.contains(PARAM_LIMIT_EXCEEDED), "{message}");
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<LimitError>())
+        .cloned()
+        .unwrap_or_else(|| panic!("no LimitError in {message}"))
+}
+
+fn assert_rejected(
+    statement: Statement,
+    param: &str,
+    value: u64,
+    (min, max): (u64, u64),
+    kind: LimitKind,
+) {
+    let limit = rejection(statement);
+    assert_eq!(limit.example, statement.example(), "{statement:?}");
+    assert_eq!(limit.param, param, "{statement:?}");
+    assert_eq!(limit.value, value, "{statement:?}");
+    assert_eq!((limit.min, limit.max), (min, max), "{statement:?}");
+    assert_eq!(limit.kind, kind, "{statement:?}");
+}
+
+#[test]
+fn blake_log_n_rows_protocol_bound() {
+    for log_n_rows in [0, 31] {
+        assert_rejected(
+            Statement::Blake {
+                log_n_rows,
+                n_rounds: 1,
+            },
+            "log_n_rows",
+            log_n_rows.into(),
+            (1, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn blake_n_rounds_implementation_cap() {
+    assert_rejected(
+        Statement::Blake {
+            log_n_rows: 5,
+            n_rounds: 0,
+        },
+        "n_rounds",
+        0,
+        (1, u32::MAX.into()),
+        LimitKind::ImplementationCap,
+    );
+}
+
+/// The column count overflows `usize` only where it is narrower than 64 bits.
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn blake_n_rounds_resource_guard() {
+    let limit = rejection(Statement::Blake {
+        log_n_rows: 5,
+        n_rounds: u32::MAX,
+    });
+    assert_eq!(limit.param, "n_rounds");
+    assert_eq!(limit.kind, LimitKind::ResourceGuard);
+    assert!(limit.value > limit.max);
+}
+
+/// On 64-bit targets the guard sits at `u32::MAX`, so no `n_rounds` crosses it; capabilities must
+/// still publish the row.
+#[cfg(not(target_pointer_width = "32"))]
+#[test]
+fn blake_n_rounds_resource_guard() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "capabilities"])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(output.status.success());
+    let capabilities: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("capabilities json");
+    let guard = capabilities["limits"]
+        .as_array()
+        .expect("limits")
+        .iter()
+        .find(|limit| limit["example"] == "blake" && limit["kind"] == "resource_guard")
+        .expect("blake resource guard published");
+    assert_eq!(guard["param"], "n_rounds");
+    assert_eq!(guard["max"], u64::from(u32::MAX));
+}
+
+#[test]
+fn plonk_log_n_rows_protocol_bound() {
+    for log_n_rows in [0, 31] {
+        assert_rejected(
+            Statement::Plonk { log_n_rows },
+            "log_n_rows",
+            log_n_rows.into(),
+            (1, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn poseidon_log_n_instances_implementation_cap() {
+    let log_n_instances = POSEIDON_LOG_INSTANCES_PER_ROW - 1;
+    assert_rejected(
+        Statement::Poseidon { log_n_instances },
+        "log_n_instances",
+        log_n_instances.into(),
+        (POSEIDON_LOG_INSTANCES_PER_ROW.into(), u32::MAX.into()),
+        LimitKind::ImplementationCap,
+    );
+}
+
+#[test]
+fn poseidon_log_n_instances_protocol_bound() {
+    let log_n_instances = POSEIDON_LOG_INSTANCES_PER_ROW + 31;
+    assert_rejected(
+        Statement::Poseidon { log_n_instances },
+        "log_n_instances",
+        log_n_instances.into(),
+        (0, (POSEIDON_LOG_INSTANCES_PER_ROW + 30).into()),
+        LimitKind::ProtocolBound,
+    );
+}
+
+#[test]
+fn state_machine_log_n_rows_protocol_bound() {
+    for log_n_rows in [0, 31] {
+        assert_rejected(
+            Statement::StateMachine {
+                log_n_rows,
+                initial_state: [1, 2],
+            },
+            "log_n_rows",
+            log_n_rows.into(),
+            (1, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn state_machine_pair_log_n_rows_protocol_bound() {
+    for log_n_rows in [1, 31] {
+        assert_rejected(
+            Statement::StateMachinePair {
+                log_n_rows,
+                initial_state: [1, 2],
+            },
+            "log_n_rows",
+            log_n_rows.into(),
+            (2, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn wide_fibonacci_log_n_rows_protocol_bound() {
+    for log_n_rows in [0, 31] {
+        assert_rejected(
+            Statement::WideFibonacci {
+                log_n_rows,
+                sequence_len: 16,
+            },
+            "log_n_rows",
+            log_n_rows.into(),
+            (1, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn wide_fibonacci_sequence_len_protocol_bound() {
+    for sequence_len in [0, 1] {
+        assert_rejected(
+            Statement::WideFibonacci {
+                log_n_rows: 5,
+                sequence_len,
+            },
+            "sequence_len",
+            sequence_len.into(),
+            (2, u32::MAX.into()),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
+
+#[test]
+fn xor_log_size_protocol_bound() {
+    for log_size in [0, 31] {
+        assert_rejected(
+            Statement::Xor {
+                log_size,
+                log_step: 0,
+                offset: 0,
+            },
+            "log_size",
+            log_size.into(),
+            (1, 30),
+            LimitKind::ProtocolBound,
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! The Plonk component constrains each row's output wire to `a + b` or `a * b` according to the
+//! preprocessed selector, and samples only the columns that constraint reads: the selector from
+//! tree 0 and the three wires from tree 1. A trace whose output wire breaks the gate on one row
+//! does not prove.
+
+mod common;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, InteropArtifact, Statement, TracePerturbation,
+    VerifyOptions,
+};
+
+fn generate() -> InteropArtifact {
+    common::artifact(Statement::Plonk { log_n_rows: 5 })
+}
+
+#[test]
+fn honest_artifact_samples_only_the_gate_columns() {
+    let artifact = generate();
+    let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+
+    let metadata = artifact
+        .component_metadata
+        .as_ref()
+        .expect("component metadata written");
+    assert_eq!(metadata.preprocessed_column_indices, vec![3]);
+    assert_eq!(metadata.mask_shape, vec![vec![1], vec![0, 1, 1, 1]]);
+}
+
+#[test]
+fn perturbed_output_wire_fails_the_constraints() {
+    // Row 0 is an addition gate over `a = b = 1`; column 3 is `c`, honestly `2`.
+    common::assert_perturbed_trace_rejected(
+        Statement::Plonk { log_n_rows: 5 },
+        TracePerturbation::Cell {
+            column: 3,
+            row: 0,
+            value: 3,
+        },
+    );
+}
+
+#[test]
+fn tampered_wire_sample_fails_the_oods_check() {
+    let mut artifact = generate();
+    let mut wire = artifact_proof_wire(&artifact).expect("wire decodes");
+    // Tree 1, column 3 is the gate's output wire `c`.
+    let sample = &mut wire.sampled_values[1][3][0];
+    sample[0] = (sample[0] + 1) % ((1 << 31) - 1);
+
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire serializes");
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    artifact.proof_bytes_blake3 = None;
+
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("tamper fails");
+    assert!(
+        format!("{err:#}").to_lowercase().contains("oods"),
+        "unexpected error: {err:#}"
+    );
+}
@@ -0,0 +1,137 @@
+//! `--preprocessed-columns` replaces the plonk or xor component's preprocessed column indices
+//! under `prove_ex`, so the verifier samples a strict subset of the committed preprocessed tree.
+//! The list is recorded in the artifact and verify builds its component from it; a list the
+//! component cannot sample fails with `preprocessed_columns_mismatch`.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use stwo_interop_rs::{
+    verify_artifact, Example, GenerateOptions, InteropArtifact, ProveMode, Statement, VerifyOptions,
+};
+
+fn generate(example: Example, columns: &[usize]) -> anyhow::Result<InteropArtifact> {
+    let statement = match example {
+        Example::Plonk => Statement::Plonk { log_n_rows: 5 },
+        _ => Statement::Xor {
+            log_size: 5,
+            log_step: 2,
+            offset: 3,
+        },
+    };
+    let mut options = GenerateOptions::default();
+    options.prove_mode = ProveMode::ProveEx;
+    options.preprocessed_columns = Some(columns.to_vec());
+    common::generate(statement, &options)
+}
+
+#[test]
+fn subsets_are_recorded_and_verify() {
+    for (example, columns) in [
+        (Example::Plonk, vec![3]),
+        (Example::Plonk, vec![0, 3]),
+        (Example::Plonk, vec![0, 1, 2, 3]),
+        (Example::Xor, vec![1]),
+        (Example::Xor, vec![0, 1]),
+    ] {
+        let artifact = generate(example, &columns).expect("artifact generates");
+        assert_eq!(artifact.preprocessed_columns.as_ref(), Some(&columns));
+        let metadata = artifact
+            .component_metadata
+            .as_ref()
+            .expect("component metadata written");
+        assert_eq!(metadata.preprocessed_column_indices, columns);
+        assert_eq!(metadata.mask_shape[0], vec![1; columns.len()]);
+
+        let report = verify_artifact(&artifact, &VerifyOptions::default())
+            .unwrap_or_else(|err| panic!("{example:?} {columns:?}: {err:#}"));
+        assert_eq!(report.status, "ok");
+    }
+}
+
+#[test]
+fn subsets_the_component_cannot_sample_are_rejected() {
+    for (example, columns, reason) in [
+        (Example::Plonk, vec![0, 2], "omit column 3"),
+        (Example::Plonk, vec![3, 4], "column 4 does not exist"),
+        (Example::Xor, vec![1, 0], "not strictly increasing"),
+    ] {
+        let err = generate(example, &columns).expect_err("subset rejected");
+        let message = format!("{err:#}");
+        assert!(
+            message.contains("preprocessed_columns_mismatch"),
+            "{message}"
+        );
+        assert!(message.contains(reason), "{message}");
+    }
+
+    let mut artifact = generate(Example::Plonk, &[0, 3]).expect("artifact generates");
+    artifact.preprocessed_columns = Some(vec![0, 1]);
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("rejected");
+    assert!(
+        format!("{err:#}").contains("preprocessed_columns_mismatch"),
+        "{err:#}"
+    );
+
+    let mut artifact = generate(Example::Plonk, &[0, 3]).expect("artifact generates");
+    artifact.preprocessed_columns = None;
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("rejected");
+    assert!(
+        format!("{err:#}").contains("component_metadata_mismatch"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn subsets_need_prove_ex_and_a_multi_column_example() {
+    let mut options = GenerateOptions::default();
+    options.preprocessed_columns = Some(vec![3]);
+    let err = common::generate(Statement::Plonk { log_n_rows: 5 }, &options)
+        .expect_err("prove mode rejected");
+    assert!(format!("{err:#}").contains("prove_ex"), "{err:#}");
+
+    options.prove_mode = ProveMode::ProveEx;
+    let err = common::generate(common::wide_fibonacci(), &options).expect_err("example rejected");
+    assert!(
+        format!("{err:#}").contains("only supported for the plonk and xor examples"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn cli_records_the_subset_and_verifies() {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-preprocessed-columns-{}.json",
+        std::process::id()
+    ));
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+            .args(args)
+            .arg("--artifact")
+            .arg(&path)
+            .output()
+            .expect("stwo-interop-rs runs")
+    };
+    let generated = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        "xor",
+        "--prove-mode",
+        "prove_ex",
+        "--preprocessed-columns",
+        "1",
+    ]);
+    assert!(generated.status.success());
+    let artifact: serde_json::Value =
+        serde_json::from_slice(&fs::read(&path).expect("artifact written")).expect("json");
+    assert_eq!(artifact["preprocessed_columns"], serde_json::json!([1]));
+    assert!(run(&["--mode", "verify"]).status.success());
+
+    let rejected = run(&["--mode", "verify", "--preprocessed-columns", "1"]);
+    fs::remove_file(&path).ok();
+    assert!(!rejected.status.success());
+    assert!(String::from_utf8_lossy(&rejected.stderr).contains("generate mode"));
+}
@@ -0,0 +1,73 @@
+//! `--profile-out` samples a second pass of the bench, so its cost shows up as the reported
+//! profiled/unprofiled ratio. `scripts/bench_profiling_overhead.py` gates that ratio on the
+//! documented bound at bench sizes; wall-clock ratios are too noisy to gate on here, so only the
+//! report shape is checked. Without the `profiling` feature the flag is rejected instead of
+//! silently ignored.
+
+use std::process::{Command, Output};
+
+fn bench_with_profile_out(profile_dir: &std::path::Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "bench",
+            "--example",
+            "wide_fibonacci",
+            "--artifact",
+            "unused.json",
+            "--wf-log-n-rows",
+            "8",
+            "--wf-sequence-len",
+            "16",
+            "--fri-log-blowup",
+            "1",
+            "--bench-warmups",
+            "1",
+            "--bench-repeats",
+            "5",
+            "--profile-out",
+        ])
+        .arg(profile_dir)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+#[cfg(feature = "profiling")]
+#[test]
+fn profiling_report_carries_overhead_ratios() {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-profile-{}", std::process::id()));
+    let output = bench_with_profile_out(&dir);
+    assert!(
+        output.status.success(),
+        "bench failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout json");
+    let profiling = &report["profiling"];
+    let bound = profiling["overhead_bound"]
+        .as_f64()
+        .expect("overhead bound");
+    assert!(bound.is_finite() && bound > 1.0, "bound {bound}");
+    assert!(profiling["within_overhead_bound"].is_boolean());
+    for phase in ["prove", "verify"] {
+        let ratio = profiling[format!("{phase}_overhead_ratio")]
+            .as_f64()
+            .expect("overhead ratio");
+        assert!(ratio.is_finite() && ratio > 0.0, "{phase} ratio {ratio}");
+    }
+    let files = profiling["files"].as_array().expect("profile files");
+    assert_eq!(files.len(), 2 * (1 + 5));
+    for file in files {
+        assert!(std::path::Path::new(file.as_str().expect("path")).is_file());
+    }
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[cfg(not(feature = "profiling"))]
+#[test]
+fn profile_out_requires_the_profiling_feature() {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-profile-{}", std::process::id()));
+    let output = bench_with_profile_out(&dir);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--features profiling"));
+}
@@ -0,0 +1,104 @@
+//! `proof_bytes_hex` is decoded against the declared `proof_bytes_len`: a length that disagrees or
+//! odd-length hex fails as `proof_bytes_len_mismatch` before any hex is decoded, while a legacy
+//! artifact without the field is decoded as-is. Every case fails verify as a `decode_error`.
+
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, InteropArtifact, InteropError, VerifyOptions,
+};
+
+/// Error code the verifier puts in front of a declared-length mismatch.
+const PROOF_BYTES_LEN_MISMATCH: &str = "proof_bytes_len_mismatch";
+
+fn artifact() -> InteropArtifact {
+    common::artifact(common::wide_fibonacci())
+}
+
+/// An artifact as written before `proof_bytes_len` and its checksum existed.
+fn legacy_artifact() -> InteropArtifact {
+    let mut artifact = artifact();
+    artifact.proof_bytes_len = None;
+    artifact.proof_bytes_blake3 = None;
+    artifact
+}
+
+/// Runs `--mode verify` on `artifact` and returns the failure report's `kind` after checking the
+/// exit status is that kind's.
+fn verify_failure_kind(name: &str, artifact: &InteropArtifact) -> String {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-proof-bytes-len-{name}-{}.json",
+        std::process::id()
+    ));
+    fs::write(&path, serde_json::to_vec(artifact).expect("json")).expect("write");
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "verify", "--artifact"])
+        .arg(&path)
+        .output()
+        .expect("stwo-interop-rs runs");
+    fs::remove_file(&path).ok();
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("one-line json report");
+    assert_eq!(report["status"], "fail", "{name}");
+    assert_eq!(
+        output.status.code(),
+        Some(i32::from(InteropError::DecodeError.exit_code())),
+        "{name}"
+    );
+    report["kind"].as_str().expect("kind").to_string()
+}
+
+#[test]
+fn legacy_artifact_without_declared_length_decodes() {
+    let artifact = legacy_artifact();
+    artifact_proof_wire(&artifact).expect("legacy proof bytes decode");
+    verify_artifact(&artifact, &VerifyOptions::default()).expect("legacy artifact verifies");
+}
+
+#[test]
+fn legacy_artifact_with_odd_length_hex_fails_in_hex_decoding() {
+    let mut artifact = legacy_artifact();
+    artifact.proof_bytes_hex.pop();
+    let err = artifact_proof_wire(&artifact).expect_err("odd-length hex");
+    let message = format!("{err:#}");
+    assert!(!message.contains(PROOF_BYTES_LEN_MISMATCH), "{message}");
+    assert!(message.to_lowercase().contains("odd"), "{message}");
+    assert_eq!(
+        verify_failure_kind("legacy-odd", &artifact),
+        InteropError::DecodeError.as_str()
+    );
+}
+
+#[test]
+fn mismatched_declared_length_is_rejected() {
+    for delta in [-1i64, 1] {
+        let mut artifact = artifact();
+        let declared = artifact.proof_bytes_len.expect("declared length");
+        artifact.proof_bytes_len = Some((declared as i64 + delta) as usize);
+        let err = artifact_proof_wire(&artifact).expect_err("length mismatch");
+        let message = format!("{err:#}");
+        assert!(message.starts_with(PROOF_BYTES_LEN_MISMATCH), "{message}");
+        assert!(message.contains("declares"), "{message}");
+        assert_eq!(
+            verify_failure_kind("mismatch", &artifact),
+            InteropError::DecodeError.as_str()
+        );
+    }
+}
+
+#[test]
+fn odd_length_hex_is_rejected_before_decoding() {
+    let mut artifact = artifact();
+    artifact.proof_bytes_hex.pop();
+    let err = artifact_proof_wire(&artifact).expect_err("odd-length hex");
+    let message = format!("{err:#}");
+    assert!(message.starts_with(PROOF_BYTES_LEN_MISMATCH), "{message}");
+    assert!(message.contains("odd length"), "{message}");
+    assert_eq!(
+        verify_failure_kind("odd", &artifact),
+        InteropError::DecodeError.as_str()
+    );
+}
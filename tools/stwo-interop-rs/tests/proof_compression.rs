@@ -0,0 +1,109 @@
+//! zstd-compressed proof bytes: a compressed artifact verifies after a JSON round trip, a
+//! corrupted compressed payload, one inflating past the proof size cap and an unknown compression
+//! name are rejected, and artifacts without a `compression` field keep meaning uncompressed.
+
+mod common;
+
+use std::io::Write;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, GenerateOptions, InteropArtifact, ProofCompression,
+    ProofEncoding, VerifyOptions,
+};
+
+fn generate(proof_encoding: ProofEncoding, proof_compression: ProofCompression) -> InteropArtifact {
+    let mut options = GenerateOptions::default();
+    options.proof_encoding = proof_encoding;
+    options.proof_compression = proof_compression;
+    common::artifact_with(common::wide_fibonacci(), &options)
+}
+
+/// `MAX_PROOF_BYTES`: the most a compressed proof may decompress to.
+const MAX_PROOF_BYTES: usize = 64 << 20;
+/// Error code for a compressed payload that inflates past its cap.
+const DECOMPRESSED_SIZE_EXCEEDED: &str = "decompressed_size_exceeded";
+
+fn verify_error(artifact: &InteropArtifact) -> String {
+    let err = verify_artifact(artifact, &VerifyOptions::default()).expect_err("rejected");
+    format!("{err:#}")
+}
+
+#[test]
+fn compressed_artifacts_round_trip() {
+    let plain = generate(ProofEncoding::Json, ProofCompression::None);
+    assert!(plain.compression.is_none());
+    let plain_wire = serde_json::to_string(&artifact_proof_wire(&plain).expect("plain decodes"))
+        .expect("wire serializes");
+
+    for encoding in [ProofEncoding::Json, ProofEncoding::Bincode] {
+        let artifact = generate(encoding, ProofCompression::Zstd);
+        assert_eq!(artifact.compression.as_deref(), Some("zstd"));
+
+        let rendered = serde_json::to_string(&artifact).expect("artifact serializes");
+        let parsed: InteropArtifact = serde_json::from_str(&rendered).expect("artifact parses");
+        let report = verify_artifact(&parsed, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+
+        let wire = serde_json::to_string(&artifact_proof_wire(&parsed).expect("wire decodes"))
+            .expect("wire serializes");
+        assert_eq!(wire, plain_wire);
+    }
+}
+
+#[test]
+fn compressed_json_is_smaller() {
+    let plain = generate(ProofEncoding::Json, ProofCompression::None);
+    let compressed = generate(ProofEncoding::Json, ProofCompression::Zstd);
+    assert!(compressed.proof_bytes_hex.len() < plain.proof_bytes_hex.len());
+}
+
+#[test]
+fn tampered_compressed_payload_is_rejected() {
+    let mut artifact = generate(ProofEncoding::Json, ProofCompression::Zstd);
+    let mut proof_bytes = hex::decode(&artifact.proof_bytes_hex).expect("hex decodes");
+    let middle = proof_bytes.len() / 2;
+    proof_bytes[middle] ^= 0x5a;
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    verify_error(&artifact);
+}
+
+#[test]
+fn unknown_compression_is_rejected() {
+    let mut artifact = generate(ProofEncoding::Json, ProofCompression::None);
+    artifact.compression = Some("brotli".to_string());
+    let error = verify_error(&artifact);
+    assert!(
+        error.contains("unsupported proof compression brotli"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn explicit_none_matches_absent_field() {
+    let mut artifact = generate(ProofEncoding::Json, ProofCompression::None);
+    artifact.compression = Some("none".to_string());
+    let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+}
+
+#[test]
+fn payload_inflating_past_the_cap_is_rejected() {
+    let mut encoder = zstd::stream::write::Encoder::new(Vec::new(), 3).expect("zstd encoder");
+    let chunk = vec![0u8; 1 << 20];
+    for _ in 0..MAX_PROOF_BYTES / chunk.len() {
+        encoder.write_all(&chunk).expect("compress");
+    }
+    encoder.write_all(&[0]).expect("compress");
+    let bomb = encoder.finish().expect("zstd frame");
+    assert!(bomb.len() < 1 << 16, "bomb is {} bytes", bomb.len());
+
+    let mut artifact = generate(ProofEncoding::Json, ProofCompression::Zstd);
+    artifact.proof_bytes_len = Some(bomb.len());
+    artifact.proof_bytes_blake3 = None;
+    artifact.proof_bytes_hex = hex::encode(bomb);
+    let error = verify_error(&artifact);
+    assert!(
+        error.contains(DECOMPRESSED_SIZE_EXCEEDED),
+        "unexpected error: {error}"
+    );
+}
@@ -0,0 +1,61 @@
+//! The bincode proof exchange carries the same proof as the JSON wire: both encodings of one
+//! generated proof decode to identical `StarkProof`s and verify, and each encoding lives only in
+//! its own artifact field.
+
+mod common;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, wire_to_proof, GenerateOptions, InteropArtifact,
+    ProofEncoding, VerifyOptions, EXCHANGE_MODE, EXCHANGE_MODE_BINCODE,
+};
+
+fn generate(proof_encoding: ProofEncoding) -> InteropArtifact {
+    let mut options = GenerateOptions::default();
+    options.proof_encoding = proof_encoding;
+    common::artifact_with(common::wide_fibonacci(), &options)
+}
+
+#[test]
+fn bincode_and_json_decode_to_the_same_proof() {
+    let json = generate(ProofEncoding::Json);
+    let bincode = generate(ProofEncoding::Bincode);
+
+    assert_eq!(json.exchange_mode, EXCHANGE_MODE);
+    assert!(json.proof_bytes_b64.is_none());
+    assert_eq!(bincode.exchange_mode, EXCHANGE_MODE_BINCODE);
+    assert!(bincode.proof_bytes_hex.is_empty());
+    assert!(bincode.proof_bytes_b64.is_some());
+    assert!(bincode.proof_bytes_len < json.proof_bytes_len);
+
+    let json_proof = wire_to_proof(artifact_proof_wire(&json).expect("json wire decodes"))
+        .expect("json wire converts");
+    let bincode_proof = wire_to_proof(artifact_proof_wire(&bincode).expect("bincode wire decodes"))
+        .expect("bincode wire converts");
+    assert_eq!(format!("{json_proof:?}"), format!("{bincode_proof:?}"));
+
+    for artifact in [&json, &bincode] {
+        let report = verify_artifact(artifact, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+    }
+}
+
+#[test]
+fn bincode_artifact_round_trips_through_json() {
+    let artifact = generate(ProofEncoding::Bincode);
+    let rendered = serde_json::to_string(&artifact).expect("artifact serializes");
+    assert!(!rendered.contains("proof_bytes_hex"));
+    let parsed: InteropArtifact = serde_json::from_str(&rendered).expect("artifact parses");
+    let report = verify_artifact(&parsed, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+}
+
+#[test]
+fn bincode_length_mismatch_is_rejected() {
+    let mut artifact = generate(ProofEncoding::Bincode);
+    artifact.proof_bytes_len = artifact.proof_bytes_len.map(|len| len + 1);
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("rejected");
+    assert!(
+        format!("{err:#}").contains("proof_bytes_len_mismatch"),
+        "unexpected error: {err:#}"
+    );
+}
@@ -0,0 +1,102 @@
+//! A failed verification under `--repro-bundle` writes a bundle whose artifact replays to the same
+//! error code and stage, and whose manifest accounts for every file it wrote.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn interop(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+fn path(p: &Path) -> &str {
+    p.to_str().expect("utf-8 path")
+}
+
+/// The single-line failure report verify prints on stdout.
+fn failure_report(output: &Output) -> serde_json::Value {
+    assert!(!output.status.success(), "tampered artifact verified");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value =
+        serde_json::from_str(stdout.lines().last().expect("report line")).expect("json report");
+    assert_eq!(report["status"], "fail");
+    report
+}
+
+#[test]
+fn bundle_from_tampered_fixture_replays_to_the_same_error() {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-repro-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    let artifact = dir.join("tampered.json");
+    let bundle = dir.join("bundle");
+
+    let tampered = interop(&[
+        "--mode",
+        "tamper",
+        "--example",
+        "wide_fibonacci",
+        "--tamper-kind",
+        "commitment_byte",
+        "--artifact",
+        path(&artifact),
+    ]);
+    assert!(
+        tampered.status.success(),
+        "tamper failed: {}",
+        String::from_utf8_lossy(&tampered.stderr)
+    );
+
+    let original = failure_report(&interop(&[
+        "--mode",
+        "verify",
+        "--artifact",
+        path(&artifact),
+        "--repro-bundle",
+        path(&bundle),
+    ]));
+    // What `replay.sh` runs, without going through cargo.
+    let replayed = failure_report(&interop(&[
+        "--mode",
+        "verify",
+        "--artifact",
+        path(&bundle.join("artifact.json")),
+    ]));
+    assert!(original["kind"].is_string());
+    assert_eq!(original["kind"], replayed["kind"]);
+    assert_eq!(original["error"], replayed["error"]);
+
+    let read_json = |name: &str| -> serde_json::Value {
+        serde_json::from_slice(&fs::read(bundle.join(name)).expect(name)).expect("json")
+    };
+    let error = read_json("error.json");
+    let manifest = read_json("manifest.json");
+    assert_eq!(error["failed_stage"], manifest["failed_stage"]);
+    assert_eq!(error["error"], original["error"]);
+    assert_eq!(
+        fs::read(&artifact).expect("tampered artifact"),
+        fs::read(bundle.join("artifact.json")).expect("bundled artifact")
+    );
+    for file in manifest["files"].as_array().expect("manifest files") {
+        let name = file["name"].as_str().expect("file name");
+        let size = fs::metadata(bundle.join(name)).expect(name).len();
+        assert_eq!(file["size"], size, "{name}");
+    }
+
+    // The metadata intermediate stands in lengths for the proof bytes.
+    let intermediates = read_json("intermediates.json");
+    let metadata = intermediates
+        .as_array()
+        .expect("intermediates")
+        .iter()
+        .find(|intermediate| intermediate["name"] == "artifact")
+        .expect("artifact metadata intermediate");
+    assert_eq!(metadata["stage"], "metadata");
+    assert!(metadata["value"].get("proof_bytes_hex").is_none());
+    assert!(metadata["value"]["proof_bytes_hex_len"].is_u64());
+    assert_eq!(metadata["value"]["example"], "wide_fibonacci");
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,56 @@
+//! `--mode roundtrip` takes a fresh proof through wire conversion, serialization, parsing and
+//! verification in one process, and checks the wire re-serializes byte for byte.
+
+use std::process::Command;
+
+fn roundtrip(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(["--mode", "roundtrip"])
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+#[test]
+fn every_phase_runs_and_the_wire_is_canonical() {
+    for args in [
+        &["--example", "wide_fibonacci"][..],
+        &["--example", "state_machine", "--prove-mode", "prove_ex"][..],
+    ] {
+        let output = roundtrip(args);
+        assert!(
+            output.status.success(),
+            "roundtrip {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+        assert_eq!(report["status"], "ok");
+        assert!(report["canonical_mismatch"].is_null());
+        assert!(report["wire_bytes"].as_u64().expect("wire bytes") > 0);
+        let phases: Vec<_> = report["phases"]
+            .as_array()
+            .expect("phases")
+            .iter()
+            .map(|phase| phase["id"].as_str().expect("phase id"))
+            .collect();
+        assert_eq!(
+            phases,
+            [
+                "prove",
+                "proof_to_wire",
+                "serialize",
+                "deserialize",
+                "reserialize",
+                "wire_to_proof",
+                "verify"
+            ]
+        );
+    }
+}
+
+#[test]
+fn example_is_required() {
+    let output = roundtrip(&[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--example is required"));
+}
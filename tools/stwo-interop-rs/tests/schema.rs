@@ -0,0 +1,166 @@
+//! Artifact schema versions: verify reads v1 and v2, mapping v1 onto the v2 layout, and rejects
+//! anything newer by naming the newest supported version. The fixtures under `tests/fixtures`
+//! carry real metadata around a placeholder proof, so they get past every schema check and fail
+//! only once the proof wire is decoded.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use stwo_interop_rs::{
+    parse_artifact, verify_artifact, ArtifactSchema, GenerateOptions, ProofCompression,
+    ProofEncoding, VerifyOptions, SCHEMA_VERSION,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+fn verify_expecting_failure(path: &Path) -> serde_json::Value {
+    let output = run(&[
+        "--mode",
+        "verify",
+        "--artifact",
+        path.to_str().expect("utf-8 path"),
+        "--expect",
+        "failure",
+    ]);
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("one-line json report")
+}
+
+#[test]
+fn fixtures_of_both_versions_parse_into_the_current_layout() {
+    let v1 = parse_artifact(&fs::read(fixture("artifact_v1.json")).expect("v1 fixture"))
+        .expect("v1 parses");
+    assert_eq!(v1.schema_version, 1);
+    assert_eq!(v1.compression.as_deref(), Some("none"));
+    assert_eq!(v1.hash.as_deref(), Some("blake2s"));
+    assert!(v1.generator_version.is_none());
+
+    let v2 = parse_artifact(&fs::read(fixture("artifact_v2.json")).expect("v2 fixture"))
+        .expect("v2 parses");
+    assert_eq!(v2.schema_version, SCHEMA_VERSION);
+    assert_eq!(v2.generator_version.as_deref(), Some("0.1.0"));
+    assert!(v2.compression.is_none());
+
+    for name in ["artifact_v1.json", "artifact_v2.json"] {
+        let report = verify_expecting_failure(&fixture(name));
+        assert_eq!(report["error_kind"], "proof_decode", "{name}");
+    }
+}
+
+#[test]
+fn newer_schema_versions_are_rejected_by_version() {
+    let mut artifact: serde_json::Value =
+        serde_json::from_slice(&fs::read(fixture("artifact_v2.json")).expect("v2 fixture"))
+            .expect("json");
+    artifact["schema_version"] = 3.into();
+    artifact["field_added_in_v3"] = true.into();
+    let raw = serde_json::to_vec(&artifact).expect("json");
+    let err = parse_artifact(&raw).expect_err("v3 is rejected");
+    assert!(format!("{err:#}").contains("supported versions are 1 through 2"));
+
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-schema-v3-{}.json",
+        std::process::id()
+    ));
+    fs::write(&path, &raw).expect("write");
+    let report = verify_expecting_failure(&path);
+    fs::remove_file(&path).ok();
+    assert_eq!(report["error_kind"], "artifact_parse");
+}
+
+#[test]
+fn generated_artifacts_of_both_versions_verify() {
+    for (schema, version) in [(ArtifactSchema::V1, 1), (ArtifactSchema::V2, 2)] {
+        let mut options = GenerateOptions::default();
+        options.schema = schema;
+        let artifact = common::artifact_with(common::wide_fibonacci(), &options);
+        assert_eq!(artifact.schema_version, version);
+        assert_eq!(artifact.generator_version.is_some(), version == 2);
+        let parsed = parse_artifact(&serde_json::to_vec(&artifact).expect("json")).expect("parses");
+        let report = verify_artifact(&parsed, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+    }
+
+    let mut options = GenerateOptions::default();
+    options.schema = ArtifactSchema::V2;
+    let mut artifact = common::artifact_with(common::wide_fibonacci(), &options);
+    artifact.generator_version = None;
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("rejected");
+    assert!(format!("{err:#}").contains("generator_version"));
+}
+
+#[test]
+fn v1_artifacts_serialize_without_v2_fields() {
+    let mut options = GenerateOptions::default();
+    options.schema = ArtifactSchema::V1;
+    let artifact = common::artifact_with(common::wide_fibonacci(), &options);
+    let json = serde_json::to_value(&artifact).expect("artifact json");
+    for field in [
+        "proof_bytes_len",
+        "proof_bytes_blake3",
+        "prove_backend",
+        "generator_version",
+        "proof_bytes_b64",
+        "compression",
+    ] {
+        assert!(json.get(field).is_none(), "v1 artifact carries {field}");
+    }
+
+    let mut compressed = options.clone();
+    compressed.proof_compression = ProofCompression::Zstd;
+    let err = common::generate(common::wide_fibonacci(), &compressed).expect_err("rejected");
+    assert!(format!("{err:#}").contains("--schema 2"), "{err:#}");
+    let mut bincode = options;
+    bincode.proof_encoding = ProofEncoding::Bincode;
+    let err = common::generate(common::wide_fibonacci(), &bincode).expect_err("rejected");
+    assert!(format!("{err:#}").contains("--schema 2"), "{err:#}");
+}
+
+#[test]
+fn schema_flag_writes_v1_and_is_generate_only() {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-schema-v1-{}.json",
+        std::process::id()
+    ));
+    let path_str = path.to_str().expect("utf-8 path");
+    let output = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        "wide_fibonacci",
+        "--schema",
+        "1",
+        "--artifact",
+        path_str,
+    ]);
+    assert!(output.status.success());
+    let artifact: serde_json::Value =
+        serde_json::from_slice(&fs::read(&path).expect("artifact written")).expect("json");
+    assert_eq!(artifact["schema_version"], 1);
+    assert!(artifact.get("generator_version").is_none());
+    assert!(run(&["--mode", "verify", "--artifact", path_str])
+        .status
+        .success());
+    fs::remove_file(&path).ok();
+
+    let output = run(&["--mode", "verify", "--artifact", path_str, "--schema", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--schema"));
+    let output = run(&["--mode", "generate", "--example", "xor", "--schema", "3"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid schema 3"));
+}
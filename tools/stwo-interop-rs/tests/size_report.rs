@@ -0,0 +1,202 @@
+//! `--mode size-report` converts each artifact's proof into a `StarkProof` and reports upstream's
+//! `size_breakdown_estimate` next to the wire and artifact sizes, for one artifact, a directory of
+//! them with per-example totals and averages, or a proof generated from `--example` flags.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use stwo_interop_rs::{Example, InteropArtifact, Statement};
+
+const BREAKDOWN_FIELDS: [&str; 5] = [
+    "oods_samples",
+    "queries_values",
+    "fri_samples",
+    "fri_decommitments",
+    "trace_decommitments",
+];
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+fn size_report(args: &[&str]) -> serde_json::Value {
+    let mut full_args = vec!["--mode", "size-report"];
+    full_args.extend_from_slice(args);
+    let output = run(&full_args);
+    assert!(
+        output.status.success(),
+        "size-report failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("one-line json report")
+}
+
+fn artifact(example: Example, log_size: u32) -> InteropArtifact {
+    let statement = match example {
+        Example::Xor => Statement::Xor {
+            log_size,
+            log_step: 2,
+            offset: 3,
+        },
+        _ => Statement::WideFibonacci {
+            log_n_rows: log_size,
+            sequence_len: 16,
+        },
+    };
+    common::artifact(statement)
+}
+
+fn write(path: &Path, artifact: &InteropArtifact) -> usize {
+    let raw = serde_json::to_vec(artifact).expect("json");
+    fs::write(path, &raw).expect("write");
+    raw.len()
+}
+
+fn field(value: &serde_json::Value, name: &str) -> u64 {
+    value[name]
+        .as_u64()
+        .unwrap_or_else(|| panic!("{name} in {value}"))
+}
+
+#[test]
+fn single_artifact_reports_the_breakdown_and_byte_sizes() {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-size-report-{}.json",
+        std::process::id()
+    ));
+    let artifact = artifact(Example::WideFibonacci, 5);
+    let artifact_bytes = write(&path, &artifact);
+    let report = size_report(&["--artifact", path.to_str().expect("utf-8 path")]);
+    fs::remove_file(&path).ok();
+
+    assert_eq!(report["mode"], "size-report");
+    let entries = report["artifacts"].as_array().expect("artifacts");
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry["example"], "wide_fibonacci");
+    for name in BREAKDOWN_FIELDS {
+        assert!(field(entry, name) > 0, "{name}");
+    }
+    assert_eq!(
+        Some(field(entry, "wire_bytes") as usize),
+        artifact.proof_bytes_len
+    );
+    assert_eq!(field(entry, "artifact_bytes") as usize, artifact_bytes);
+    assert_eq!(report["totals"]["wire_bytes"], entry["wire_bytes"]);
+}
+
+#[test]
+fn directory_input_aggregates_per_example() {
+    let dir = std::env::temp_dir().join(format!(
+        "stwo-interop-size-report-dir-{}",
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).expect("temp dir");
+    write(&dir.join("a.json"), &artifact(Example::WideFibonacci, 5));
+    write(&dir.join("b.json"), &artifact(Example::WideFibonacci, 6));
+    write(&dir.join("c.json"), &artifact(Example::Xor, 5));
+    let mut negative = artifact(Example::Xor, 5);
+    negative.expected_verify_result = Some("verify".to_string());
+    negative.proof_bytes_hex = hex::encode(b"not a proof wire");
+    negative.proof_bytes_len = Some(16);
+    negative.proof_bytes_blake3 = None;
+    write(&dir.join("negative.json"), &negative);
+    fs::write(dir.join("notes.txt"), "not an artifact").expect("write");
+
+    let report = size_report(&["--artifact", dir.to_str().expect("utf-8 path")]);
+    fs::remove_dir_all(&dir).ok();
+
+    let names: Vec<_> = report["artifacts"]
+        .as_array()
+        .expect("artifacts")
+        .iter()
+        .map(|entry| entry["artifact"].as_str().expect("name"))
+        .collect();
+    assert_eq!(names, ["a.json", "b.json", "c.json"]);
+    assert_eq!(
+        report["skipped_negative_fixtures"],
+        serde_json::json!(["negative.json"])
+    );
+
+    let examples = report["examples"].as_array().expect("examples");
+    let summary: Vec<_> = examples
+        .iter()
+        .map(|example| {
+            (
+                example["example"].as_str().expect("example"),
+                field(example, "artifacts"),
+            )
+        })
+        .collect();
+    assert_eq!(summary, [("wide_fibonacci", 2), ("xor", 1)]);
+
+    let entries = report["artifacts"].as_array().expect("artifacts");
+    for name in BREAKDOWN_FIELDS
+        .iter()
+        .chain(&["wire_bytes", "artifact_bytes"])
+    {
+        let sum: u64 = entries.iter().map(|entry| field(entry, name)).sum();
+        assert_eq!(field(&report["totals"], name), sum, "{name}");
+        let wide_fibonacci = field(&entries[0], name) + field(&entries[1], name);
+        assert_eq!(
+            field(&examples[0]["totals"], name),
+            wide_fibonacci,
+            "{name}"
+        );
+        assert_eq!(
+            examples[0]["averages"][name].as_f64(),
+            Some(wide_fibonacci as f64 / 2.0),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn generate_flags_size_a_fresh_proof() {
+    let report = size_report(&[
+        "--example",
+        "xor",
+        "--hash",
+        "blake3",
+        "--xor-log-size",
+        "6",
+    ]);
+    assert_eq!(report["input"], "generated");
+    let entry = &report["artifacts"][0];
+    assert_eq!(entry["artifact"], "generated");
+    assert_eq!(entry["example"], "xor");
+    for name in BREAKDOWN_FIELDS {
+        assert!(field(entry, name) > 0, "{name}");
+    }
+    assert!(field(entry, "artifact_bytes") > 2 * field(entry, "wire_bytes"));
+
+    let output = run(&["--mode", "size-report"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--artifact or --example"));
+    let output = run(&[
+        "--mode",
+        "size-report",
+        "--example",
+        "xor",
+        "--artifact",
+        "artifact.json",
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not both"));
+    let output = run(&[
+        "--mode",
+        "size-report",
+        "--artifact",
+        "artifact.json",
+        "--hash",
+        "blake3",
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--hash is only supported"));
+}
@@ -0,0 +1,143 @@
+//! `--with-interaction` commits the state-machine logup interaction tree as a third trace tree
+//! and ties the x-axis claimed sum to it. The statement records `with_interaction` and verify
+//! rejects a proof whose commitment count disagrees with it; artifacts without the field have no
+//! tree and still verify.
+
+mod common;
+
+use std::process::Command;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, GenerateOptions, InteropArtifact, Statement,
+    VerifyOptions,
+};
+
+fn generate(with_interaction: bool) -> InteropArtifact {
+    let statement = Statement::StateMachine {
+        log_n_rows: 5,
+        initial_state: [9, 3],
+    };
+    let mut options = GenerateOptions::default();
+    options.with_interaction = with_interaction;
+    common::artifact_with(statement, &options)
+}
+
+#[test]
+fn both_proof_shapes_verify() {
+    for (with_interaction, n_commitments) in [(false, 3), (true, 4)] {
+        let artifact = generate(with_interaction);
+        let wire = artifact_proof_wire(&artifact).expect("wire decodes");
+        assert_eq!(wire.commitments.len(), n_commitments);
+        let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+        assert_eq!(report.status, "ok");
+    }
+
+    let metadata = generate(true)
+        .component_metadata
+        .expect("component metadata written");
+    assert_eq!(metadata.n_constraints, 2);
+    assert_eq!(metadata.mask_shape, vec![vec![1], vec![1, 1], vec![2; 4]]);
+}
+
+#[test]
+fn with_interaction_is_recorded_in_the_statement() {
+    let plain = generate(false);
+    let statement = plain.state_machine_statement.as_ref().expect("statement");
+    assert_eq!(statement.with_interaction, None);
+    let rendered = serde_json::to_value(&plain).expect("artifact serializes");
+    assert!(rendered["state_machine_statement"]
+        .get("with_interaction")
+        .is_none());
+
+    let interaction = generate(true);
+    let statement = interaction
+        .state_machine_statement
+        .as_ref()
+        .expect("statement");
+    assert_eq!(statement.with_interaction, Some(true));
+}
+
+#[test]
+fn commitment_count_disagreeing_with_the_statement_is_rejected() {
+    for (with_interaction, declared) in [(false, Some(true)), (true, None), (true, Some(false))] {
+        let mut artifact = generate(with_interaction);
+        artifact
+            .state_machine_statement
+            .as_mut()
+            .expect("statement")
+            .with_interaction = declared;
+        let err = verify_artifact(&artifact, &VerifyOptions::default())
+            .expect_err("mismatched statement fails");
+        let message = format!("{err:#}");
+        assert!(
+            message.contains("invalid proof shape") && message.contains("with_interaction="),
+            "{with_interaction} {declared:?}: {message}"
+        );
+    }
+
+    let mut artifact = generate(false);
+    artifact
+        .state_machine_statement
+        .as_mut()
+        .expect("statement")
+        .with_interaction = Some(false);
+    let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+}
+
+#[test]
+fn state_machine_pair_rejects_with_interaction() {
+    let mut artifact = common::artifact(Statement::StateMachinePair {
+        log_n_rows: 5,
+        initial_state: [9, 3],
+    });
+    artifact
+        .state_machine_statement
+        .as_mut()
+        .expect("statement")
+        .with_interaction = Some(true);
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("pair rejects");
+    assert!(format!("{err:#}").contains("with_interaction"), "{err:#}");
+}
+
+#[test]
+fn tampered_prefix_sum_fails_the_oods_check() {
+    let mut artifact = generate(true);
+    let mut wire = artifact_proof_wire(&artifact).expect("wire decodes");
+    // Tree 2 is the interaction tree; sample 1 of a column is at the OODS point itself.
+    let sample = &mut wire.sampled_values[2][0][1];
+    sample[0] = (sample[0] + 1) % ((1 << 31) - 1);
+
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire serializes");
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    artifact.proof_bytes_blake3 = None;
+
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("tamper fails");
+    assert!(
+        format!("{err:#}").to_lowercase().contains("oods"),
+        "unexpected error: {err:#}"
+    );
+}
+
+#[test]
+fn with_interaction_is_state_machine_and_generate_only() {
+    let mut options = GenerateOptions::default();
+    options.with_interaction = true;
+    let err = common::generate(Statement::Plonk { log_n_rows: 5 }, &options)
+        .expect_err("plonk rejects the flag");
+    assert!(format!("{err:#}").contains("state_machine"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "verify",
+            "--artifact",
+            "missing.json",
+            "--with-interaction",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--with-interaction"));
+}
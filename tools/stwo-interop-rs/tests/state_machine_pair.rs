@@ -0,0 +1,160 @@
+//! `state_machine_pair` proves the x-axis and y-axis state-machine components against one
+//! commitment scheme and records each in the artifact's `components`, which verify checks against
+//! the shared statement before running the verifier with both components registered. Each
+//! component constrains its own two main columns.
+
+mod common;
+
+use std::process::Command;
+
+use stwo_interop_rs::{
+    verify_artifact, InteropArtifact, Statement, TracePerturbation, VerifyOptions,
+};
+
+fn statement() -> Statement {
+    Statement::StateMachinePair {
+        log_n_rows: 5,
+        initial_state: [9, 3],
+    }
+}
+
+fn generate() -> InteropArtifact {
+    common::artifact(statement())
+}
+
+#[test]
+fn honest_pair_records_both_components_and_verifies() {
+    let artifact = generate();
+    let report = verify_artifact(&artifact, &VerifyOptions::default()).expect("verifies");
+    assert_eq!(report.status, "ok");
+    assert_eq!(report.example, "state_machine_pair");
+
+    assert!(artifact.component_metadata.is_none());
+    let components = artifact.components.as_ref().expect("components written");
+    let names: Vec<_> = components.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["state_machine_x_axis", "state_machine_y_axis"]);
+    assert_eq!(components[0].log_n_rows, 5);
+    assert_eq!(components[1].log_n_rows, 4);
+
+    let statement = artifact
+        .state_machine_statement
+        .as_ref()
+        .expect("shared statement written");
+    assert_eq!(
+        components[0].claimed_sum,
+        statement.stmt1.x_axis_claimed_sum
+    );
+    assert_eq!(
+        components[1].claimed_sum,
+        statement.stmt1.y_axis_claimed_sum
+    );
+
+    let x_axis = components[0].metadata.as_ref().expect("x-axis metadata");
+    let y_axis = components[1].metadata.as_ref().expect("y-axis metadata");
+    assert_eq!(x_axis.preprocessed_column_indices, vec![0]);
+    assert_eq!(y_axis.preprocessed_column_indices, vec![1]);
+    assert_eq!(x_axis.max_constraint_log_degree_bound, 6);
+    assert_eq!(y_axis.max_constraint_log_degree_bound, 5);
+    for metadata in [x_axis, y_axis] {
+        assert_eq!(metadata.n_constraints, 4);
+        assert_eq!(metadata.mask_shape, vec![vec![1], vec![2, 2]]);
+    }
+}
+
+#[test]
+fn swapped_component_columns_fail_the_constraints() {
+    // The x-axis steps column 0 from 9 and holds column 1 at 3, and the y-axis steps column 3
+    // and holds column 2; swapped, the stepped column is constant and the first row no longer
+    // holds the component's initial state.
+    for columns in [[0, 1], [2, 3]] {
+        common::assert_perturbed_trace_rejected(
+            statement(),
+            TracePerturbation::SwapColumns { columns },
+        );
+    }
+}
+
+#[test]
+fn components_disagreeing_with_the_statement_are_rejected() {
+    let mut swapped = generate();
+    swapped.components.as_mut().expect("components").swap(0, 1);
+    let err = verify_artifact(&swapped, &VerifyOptions::default()).expect_err("order checked");
+    assert!(format!("{err:#}").contains("components"), "{err:#}");
+
+    let mut resized = generate();
+    resized.components.as_mut().expect("components")[1].log_n_rows += 1;
+    let err = verify_artifact(&resized, &VerifyOptions::default()).expect_err("size checked");
+    assert!(format!("{err:#}").contains("log_n_rows"), "{err:#}");
+
+    let mut missing = generate();
+    missing.components = None;
+    let err = verify_artifact(&missing, &VerifyOptions::default()).expect_err("required");
+    assert!(format!("{err:#}").contains("missing components"), "{err:#}");
+
+    let mut reshaped = generate();
+    let metadata = reshaped.components.as_mut().expect("components")[1]
+        .metadata
+        .as_mut()
+        .expect("metadata");
+    metadata.mask_shape[1].push(1);
+    let err = verify_artifact(&reshaped, &VerifyOptions::default()).expect_err("shape checked");
+    let message = format!("{err:#}");
+    assert!(message.contains("state_machine_y_axis"), "{message}");
+    assert!(message.contains("component_metadata_mismatch"), "{message}");
+}
+
+#[test]
+fn components_are_rejected_on_single_component_examples() {
+    let mut artifact = common::artifact(Statement::StateMachine {
+        log_n_rows: 5,
+        initial_state: [9, 3],
+    });
+    artifact.components = generate().components;
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("rejected");
+    assert!(
+        format!("{err:#}").contains("only written for multi-component examples"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn cli_generates_and_verifies_the_pair() {
+    let path = std::env::temp_dir().join(format!(
+        "stwo-interop-state-machine-pair-{}.json",
+        std::process::id()
+    ));
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+            .args(args)
+            .arg("--artifact")
+            .arg(&path)
+            .output()
+            .expect("stwo-interop-rs runs")
+    };
+    let generated = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        "state_machine_pair",
+        "--sm-pair-log-n-rows",
+        "6",
+    ]);
+    assert!(generated.status.success());
+    let verified = run(&["--mode", "verify"]);
+    std::fs::remove_file(&path).ok();
+    assert!(verified.status.success());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "generate",
+            "--example",
+            "state_machine_pair",
+            "--sm-pair-log-n-rows",
+            "1",
+        ])
+        .output()
+        .expect("stwo-interop-rs runs");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("log_n_rows"));
+}
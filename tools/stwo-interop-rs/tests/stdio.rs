@@ -0,0 +1,76 @@
+//! `--artifact -` streams the artifact: generate writes exactly the file's bytes to stdout, and
+//! verify and inspect read it from stdin. Bench mode, whose report owns stdout, rejects it.
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const GENERATE: [&str; 6] = [
+    "--mode",
+    "generate",
+    "--example",
+    "wide_fibonacci",
+    "--wf-log-n-rows",
+    "5",
+];
+
+fn run(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("stwo-interop-rs runs");
+    child
+        .stdin
+        .take()
+        .expect("stdin piped")
+        .write_all(stdin)
+        .expect("write stdin");
+    child.wait_with_output().expect("stwo-interop-rs exits")
+}
+
+#[test]
+fn artifacts_stream_through_stdio() {
+    let path = std::env::temp_dir().join(format!("stwo-interop-stdio-{}.json", std::process::id()));
+    let path = path.to_str().expect("utf-8 path");
+    let to_file = run(&[&GENERATE[..], &["--artifact", path]].concat(), b"");
+    assert!(to_file.status.success());
+    assert!(to_file.stdout.is_empty());
+
+    let to_stdout = run(&[&GENERATE[..], &["--artifact", "-"]].concat(), b"");
+    assert!(
+        to_stdout.status.success(),
+        "generate failed: {}",
+        String::from_utf8_lossy(&to_stdout.stderr)
+    );
+    assert_eq!(to_stdout.stdout, fs::read(path).expect("artifact written"));
+    assert!(to_stdout.stdout.ends_with(b"}\n"));
+
+    let verify = run(&["--mode", "verify", "--artifact", "-"], &to_stdout.stdout);
+    assert!(
+        verify.status.success(),
+        "verify failed: {}",
+        String::from_utf8_lossy(&verify.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&verify.stdout).expect("report");
+    assert_eq!(report["status"], "ok");
+
+    let inspect = run(&["--mode", "inspect", "--artifact", "-"], &to_stdout.stdout);
+    assert!(inspect.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&inspect.stdout).expect("summary");
+    assert_eq!(summary["example"], "wide_fibonacci");
+
+    fs::remove_file(path).ok();
+}
+
+#[test]
+fn bench_rejects_stdio_artifact() {
+    let output = run(
+        &["--mode", "bench", "--example", "xor", "--artifact", "-"],
+        b"",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--artifact - is not supported"));
+}
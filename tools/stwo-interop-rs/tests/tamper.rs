@@ -0,0 +1,70 @@
+//! `--mode tamper` writes, for every kind, an artifact the Rust verifier rejects, recording the
+//! kind, the failing stage and the error; the same flags always produce the same artifact.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const KINDS: [&str; 7] = [
+    "commitment_byte",
+    "hash_witness_truncate",
+    "sampled_value",
+    "proof_of_work",
+    "queried_values_swap",
+    "last_layer_poly",
+    "statement",
+];
+
+fn tamper(kind: &str, out: &PathBuf) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args([
+            "--mode",
+            "tamper",
+            "--example",
+            "wide_fibonacci",
+            "--tamper-kind",
+            kind,
+            "--artifact",
+            out.to_str().expect("utf-8 path"),
+        ])
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+#[test]
+fn every_kind_is_rejected_and_recorded() {
+    let dir = std::env::temp_dir().join(format!("stwo-interop-tamper-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("temp dir");
+    for kind in KINDS {
+        let first = dir.join(format!("{kind}.json"));
+        let second = dir.join(format!("{kind}.again.json"));
+        for out in [&first, &second] {
+            let output = tamper(kind, out);
+            assert!(
+                output.status.success(),
+                "tamper {kind} failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        let written = fs::read(&first).expect("tampered artifact");
+        assert_eq!(
+            written,
+            fs::read(&second).expect("tampered artifact"),
+            "{kind}"
+        );
+        let artifact: serde_json::Value = serde_json::from_slice(&written).expect("json");
+        assert_eq!(artifact["tamper_kind"], kind);
+        assert_ne!(artifact["expected_verify_result"], "ok", "{kind}");
+        assert!(artifact["tamper_observed_error"].is_string(), "{kind}");
+    }
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unknown_kind_is_rejected() {
+    let out = std::env::temp_dir().join(format!("stwo-interop-tamper-{}.json", std::process::id()));
+    let output = tamper("reorder_layers", &out);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid --tamper-kind"));
+}
@@ -0,0 +1,155 @@
+//! `--dump-transcript` writes the channel digest after every step the example driver takes, as
+//! JSON lines. Generate and verify record the same steps under the same names, so the two dumps of
+//! an honest artifact are identical and a tampered proof diverges at the step that consumes the
+//! tampered value.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use stwo_interop_rs::{artifact_proof_wire, InteropArtifact};
+
+fn temp_path(name: &str, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "stwo-interop-transcript-{name}-{}.{extension}",
+        std::process::id()
+    ))
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_stwo-interop-rs"))
+        .args(args)
+        .output()
+        .expect("stwo-interop-rs runs")
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().expect("utf-8 path")
+}
+
+fn read_dump(path: &Path) -> Vec<serde_json::Value> {
+    let dump = fs::read_to_string(path).expect("transcript written");
+    fs::remove_file(path).ok();
+    dump.lines()
+        .map(|line| serde_json::from_str(line).expect("json line"))
+        .collect()
+}
+
+fn step_names(dump: &[serde_json::Value]) -> Vec<&str> {
+    dump.iter()
+        .map(|step| step["step"].as_str().expect("step name"))
+        .collect()
+}
+
+/// Generates `example` into the artifact `name` with a transcript dump and returns the artifact
+/// path and the dump.
+fn generate(example: &str, name: &str) -> (PathBuf, Vec<serde_json::Value>) {
+    let artifact = temp_path(name, "json");
+    let dump = temp_path(&format!("{name}-generate"), "jsonl");
+    let output = run(&[
+        "--mode",
+        "generate",
+        "--example",
+        example,
+        "--artifact",
+        path_str(&artifact),
+        "--dump-transcript",
+        path_str(&dump),
+    ]);
+    assert!(output.status.success(), "{example}");
+    (artifact, read_dump(&dump))
+}
+
+fn verify(artifact: &Path) -> (Output, Vec<serde_json::Value>) {
+    let dump = artifact.with_extension("verify.jsonl");
+    let output = run(&[
+        "--mode",
+        "verify",
+        "--artifact",
+        path_str(artifact),
+        "--dump-transcript",
+        path_str(&dump),
+    ]);
+    (output, read_dump(&dump))
+}
+
+#[test]
+fn generate_and_verify_dumps_agree_for_every_example() {
+    for example in [
+        "blake",
+        "plonk",
+        "poseidon",
+        "state_machine",
+        "state_machine_pair",
+        "wide_fibonacci",
+        "xor",
+    ] {
+        let (artifact, generated) = generate(example, example);
+        let (output, verified) = verify(&artifact);
+        fs::remove_file(&artifact).ok();
+        assert!(output.status.success(), "{example}");
+        assert_eq!(generated, verified, "{example}");
+
+        let names = step_names(&generated);
+        assert_eq!(names.first(), Some(&"config_mix"), "{example}");
+        assert_eq!(names.last(), Some(&"queries_draw"), "{example}");
+        for expected in [
+            "preprocessed_commit",
+            "main_trace_commit",
+            "oods_point_draw",
+            "sampled_values_mix",
+            "fri_layer_0_alpha_draw",
+            "proof_of_work_mix",
+        ] {
+            assert!(names.contains(&expected), "{example}: {expected}");
+        }
+        for (index, step) in generated.iter().enumerate() {
+            assert_eq!(step["index"], index);
+            assert_eq!(step["digest"].as_str().map(str::len), Some(64));
+        }
+    }
+}
+
+#[test]
+fn tampered_sample_diverges_at_the_sampled_values_mix() {
+    let (artifact, generated) = generate("wide_fibonacci", "tampered");
+    let mut parsed: InteropArtifact =
+        serde_json::from_slice(&fs::read(&artifact).expect("artifact written"))
+            .expect("artifact parses");
+    let mut wire = artifact_proof_wire(&parsed).expect("wire decodes");
+    let sample = &mut wire.sampled_values[1][0][0];
+    sample[0] = (sample[0] + 1) % ((1 << 31) - 1);
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire serializes");
+    parsed.proof_bytes_len = Some(proof_bytes.len());
+    parsed.proof_bytes_hex = hex::encode(proof_bytes);
+    parsed.proof_bytes_blake3 = None;
+    fs::write(&artifact, serde_json::to_vec(&parsed).expect("json")).expect("write");
+
+    let (output, verified) = verify(&artifact);
+    fs::remove_file(&artifact).ok();
+    assert!(!output.status.success());
+    assert_eq!(step_names(&generated), step_names(&verified));
+    let first_mismatch = generated
+        .iter()
+        .zip(&verified)
+        .position(|(generated, verified)| generated != verified)
+        .expect("dumps diverge");
+    assert_eq!(verified[first_mismatch]["step"], "sampled_values_mix");
+}
+
+#[test]
+fn dump_transcript_is_generate_and_verify_only() {
+    let dump = temp_path("bench", "jsonl");
+    let output = run(&[
+        "--mode",
+        "bench",
+        "--example",
+        "xor",
+        "--dump-transcript",
+        path_str(&dump),
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("--dump-transcript is only supported for generate and verify modes"));
+    assert!(!dump.exists());
+}
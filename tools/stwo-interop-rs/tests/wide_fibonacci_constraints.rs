@@ -0,0 +1,64 @@
+//! The wide-Fibonacci component constrains every trace column from the third on to the sum of the
+//! squares of the two before it, so honest proofs verify at every sequence length, a trace that
+//! breaks the relation on one row does not prove, and a proof whose sampled trace values
+//! disagree with the relation fails the OODS check.
+
+mod common;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, InteropArtifact, Statement, TracePerturbation,
+    VerifyOptions,
+};
+
+fn generate(sequence_len: u32) -> InteropArtifact {
+    common::artifact(Statement::WideFibonacci {
+        log_n_rows: 5,
+        sequence_len,
+    })
+}
+
+#[test]
+fn honest_artifacts_verify_at_every_sequence_length() {
+    for sequence_len in [2, 3, 4, 16] {
+        let artifact = generate(sequence_len);
+        let report = verify_artifact(&artifact, &VerifyOptions::default())
+            .unwrap_or_else(|err| panic!("sequence_len {sequence_len}: {err:#}"));
+        assert_eq!(report.status, "ok");
+    }
+}
+
+#[test]
+fn perturbed_trace_fails_the_constraints() {
+    // Row 0 starts from (1, 0), so its column 3 is `0^2 + 1^2 = 1` in the honest trace.
+    common::assert_perturbed_trace_rejected(
+        Statement::WideFibonacci {
+            log_n_rows: 5,
+            sequence_len: 16,
+        },
+        TracePerturbation::Cell {
+            column: 3,
+            row: 0,
+            value: 5,
+        },
+    );
+}
+
+#[test]
+fn tampered_trace_sample_fails_the_oods_check() {
+    let mut artifact = generate(16);
+    let mut wire = artifact_proof_wire(&artifact).expect("wire decodes");
+    // Tree 1 is the main trace; column 3 is constrained against columns 1 and 2.
+    let sample = &mut wire.sampled_values[1][3][0];
+    sample[0] = (sample[0] + 1) % ((1 << 31) - 1);
+
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire serializes");
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    artifact.proof_bytes_blake3 = None;
+
+    let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("tamper fails");
+    assert!(
+        format!("{err:#}").to_lowercase().contains("oods"),
+        "unexpected error: {err:#}"
+    );
+}
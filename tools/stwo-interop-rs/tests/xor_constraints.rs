@@ -0,0 +1,79 @@
+//! The XOR component constrains its main column to be boolean and to be one on every row the
+//! preprocessed `is_step` column selects, so both the `is_step` and main samples feed the OODS
+//! check, and a trace with a non-boolean main cell does not prove. `is_first` is committed and
+//! sampled with the other preprocessed columns, though no constraint reads it.
+
+mod common;
+
+use stwo_interop_rs::{
+    artifact_proof_wire, verify_artifact, InteropArtifact, Statement, TracePerturbation,
+    VerifyOptions,
+};
+
+fn generate(log_step: u32, offset: usize) -> InteropArtifact {
+    common::artifact(Statement::Xor {
+        log_size: 5,
+        log_step,
+        offset,
+    })
+}
+
+fn tamper_sample(artifact: &mut InteropArtifact, tree: usize, column: usize) {
+    let mut wire = artifact_proof_wire(artifact).expect("wire decodes");
+    let sample = &mut wire.sampled_values[tree][column][0];
+    sample[0] = (sample[0] + 1) % ((1 << 31) - 1);
+
+    let proof_bytes = serde_json::to_vec(&wire).expect("wire serializes");
+    artifact.proof_bytes_len = Some(proof_bytes.len());
+    artifact.proof_bytes_hex = hex::encode(proof_bytes);
+    artifact.proof_bytes_blake3 = None;
+}
+
+#[test]
+fn honest_artifacts_verify_for_every_step_layout() {
+    for (log_step, offset) in [(0, 0), (1, 1), (2, 3), (5, 17)] {
+        let artifact = generate(log_step, offset);
+        let report = verify_artifact(&artifact, &VerifyOptions::default())
+            .unwrap_or_else(|err| panic!("log_step {log_step} offset {offset}: {err:#}"));
+        assert_eq!(report.status, "ok");
+
+        let metadata = artifact
+            .component_metadata
+            .as_ref()
+            .expect("component metadata written");
+        assert_eq!(metadata.n_constraints, 2);
+        assert_eq!(metadata.preprocessed_column_indices, vec![0, 1]);
+        assert_eq!(metadata.mask_shape, vec![vec![1, 1], vec![1]]);
+    }
+}
+
+#[test]
+fn tampered_samples_fail_the_oods_check() {
+    // Tree 0, column 1 is `is_step`; tree 1, column 0 is the main column.
+    for (tree, column) in [(0, 1), (1, 0)] {
+        let mut artifact = generate(2, 3);
+        tamper_sample(&mut artifact, tree, column);
+        let err = verify_artifact(&artifact, &VerifyOptions::default()).expect_err("tamper fails");
+        assert!(
+            format!("{err:#}").to_lowercase().contains("oods"),
+            "tree {tree} column {column}: {err:#}"
+        );
+    }
+}
+
+#[test]
+fn perturbed_main_column_fails_the_constraints() {
+    // 2 is not boolean, whatever `is_step` says about row 0.
+    common::assert_perturbed_trace_rejected(
+        Statement::Xor {
+            log_size: 5,
+            log_step: 2,
+            offset: 3,
+        },
+        TracePerturbation::Cell {
+            column: 0,
+            row: 0,
+            value: 2,
+        },
+    );
+}
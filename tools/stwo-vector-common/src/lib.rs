@@ -0,0 +1,122 @@
+//! Deterministic sampling and JSON encoding helpers shared by the vector generators.
+//!
+//! Every generator draws from the same xorshift64* stream, so a vector file is reproducible from
+//! its recorded seed alone. `tests/stream.rs` pins the exact output of each sampler for a known
+//! seed: a change here changes every generator's output and must fail there first.
+
+#[cfg(feature = "fields")]
+pub use fields::*;
+
+/// Xorshift64* (deterministic, non-cryptographic). Advances `state` and returns the scrambled
+/// output; the multiplier only affects the output, not the next state.
+pub fn next_u64(state: &mut u64) -> u64 {
+    let mut x = *state;
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    *state = x;
+    x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+}
+
+/// Fills `bytes` with little-endian `next_u64` outputs, one draw per started 8-byte chunk.
+pub fn fill_bytes(state: &mut u64, bytes: &mut [u8]) {
+    for chunk in bytes.chunks_mut(8) {
+        let block = next_u64(state).to_le_bytes();
+        let n = chunk.len();
+        chunk.copy_from_slice(&block[..n]);
+    }
+}
+
+#[cfg(feature = "fields")]
+mod fields {
+    use stwo::core::circle::CirclePoint;
+    use stwo::core::fields::cm31::CM31;
+    use stwo::core::fields::m31::{M31, P};
+    use stwo::core::fields::qm31::QM31;
+    use stwo::core::vcs::blake2_hash::Blake2sHash;
+    use stwo::core::vcs::blake3_hash::Blake3Hash;
+
+    use super::{fill_bytes, next_u64};
+
+    /// Canonical M31 from the low 31 bits of one draw, redrawing `P` (and 0 if `non_zero`).
+    pub fn sample_m31(state: &mut u64, non_zero: bool) -> M31 {
+        loop {
+            let candidate = (next_u64(state) as u32) & 0x7fff_ffff;
+            if candidate == P {
+                continue;
+            }
+            if non_zero && candidate == 0 {
+                continue;
+            }
+            return M31::from_u32_unchecked(candidate);
+        }
+    }
+
+    pub fn sample_cm31(state: &mut u64, non_zero: bool) -> CM31 {
+        loop {
+            let out = CM31(sample_m31(state, false), sample_m31(state, false));
+            if non_zero && out.0 .0 == 0 && out.1 .0 == 0 {
+                continue;
+            }
+            return out;
+        }
+    }
+
+    pub fn sample_qm31(state: &mut u64, non_zero: bool) -> QM31 {
+        loop {
+            let out = QM31(
+                CM31(sample_m31(state, false), sample_m31(state, false)),
+                CM31(sample_m31(state, false), sample_m31(state, false)),
+            );
+            if non_zero && encode_qm31(out) == [0, 0, 0, 0] {
+                continue;
+            }
+            return out;
+        }
+    }
+
+    pub fn sample_hash(state: &mut u64) -> Blake2sHash {
+        let mut bytes = [0u8; 32];
+        fill_bytes(state, &mut bytes);
+        Blake2sHash(bytes)
+    }
+
+    pub fn decode_m31(x: u32) -> M31 {
+        M31::from_u32_unchecked(x)
+    }
+
+    pub fn decode_qm31(x: [u32; 4]) -> QM31 {
+        QM31::from_u32_unchecked(x[0], x[1], x[2], x[3])
+    }
+
+    pub fn encode_m31(x: M31) -> u32 {
+        x.0
+    }
+
+    pub fn encode_cm31(x: CM31) -> [u32; 2] {
+        [x.0 .0, x.1 .0]
+    }
+
+    /// Coordinates in `to_m31_array` order.
+    pub fn encode_qm31(x: QM31) -> [u32; 4] {
+        [x.0 .0 .0, x.0 .1 .0, x.1 .0 .0, x.1 .1 .0]
+    }
+
+    pub fn encode_hash(x: Blake2sHash) -> [u8; 32] {
+        x.0
+    }
+
+    pub fn encode_blake3_hash(x: Blake3Hash) -> [u8; 32] {
+        x.as_ref()
+            .try_into()
+            .expect("blake3 hash should be 32 bytes")
+    }
+
+    pub fn encode_circle_point(p: CirclePoint<M31>) -> [u32; 2] {
+        [p.x.0, p.y.0]
+    }
+
+    pub fn encode_secure_circle_point(p: CirclePoint<QM31>) -> [[u32; 4]; 2] {
+        [encode_qm31(p.x), encode_qm31(p.y)]
+    }
+}
@@ -0,0 +1,67 @@
+//! Pins the shared sampling stream for a known seed. Every vector generator draws through these
+//! helpers, so any change to their output must be deliberate and fail here first.
+
+use stwo_vector_common::{
+    encode_cm31, encode_hash, encode_m31, encode_qm31, fill_bytes, next_u64, sample_cm31,
+    sample_hash, sample_m31, sample_qm31,
+};
+
+/// `stwo-vector-gen`'s primary seed.
+const SEED: u64 = 0x243f_6a88_85a3_08d3;
+
+/// The first four `next_u64` outputs from `SEED`.
+const OUTPUTS: [u64; 4] = [
+    0x8f8f_22bc_585c_9ce6,
+    0x98f5_b9b9_9b03_bf7a,
+    0x51ae_b091_7dba_af99,
+    0x6f0a_8b88_c8b4_9dc2,
+];
+
+/// The same four outputs reduced to canonical M31 values.
+const M31_OUTPUTS: [u32; 4] = [1_482_464_486, 453_230_458, 2_109_386_649, 1_219_796_418];
+
+#[test]
+fn next_u64_sequence_is_pinned() {
+    let mut state = SEED;
+    let outputs: [u64; 4] = std::array::from_fn(|_| next_u64(&mut state));
+    assert_eq!(outputs, OUTPUTS);
+    assert_eq!(state, 0x044c_bffc_5c75_032a);
+}
+
+#[test]
+fn fill_bytes_draws_once_per_started_chunk() {
+    let mut state = SEED;
+    let mut bytes = [0u8; 11];
+    fill_bytes(&mut state, &mut bytes);
+    assert_eq!(bytes[..8], OUTPUTS[0].to_le_bytes());
+    assert_eq!(bytes[8..], OUTPUTS[1].to_le_bytes()[..3]);
+
+    // The tail of the second draw is discarded, so the stream continues with the third output.
+    assert_eq!(next_u64(&mut state), OUTPUTS[2]);
+}
+
+#[test]
+fn field_samplers_are_pinned() {
+    let mut state = SEED;
+    let m31: [u32; 4] = std::array::from_fn(|_| encode_m31(sample_m31(&mut state, false)));
+    assert_eq!(m31, M31_OUTPUTS);
+
+    let mut state = SEED;
+    assert_eq!(
+        encode_cm31(sample_cm31(&mut state, false)),
+        [M31_OUTPUTS[0], M31_OUTPUTS[1]]
+    );
+
+    let mut state = SEED;
+    assert_eq!(encode_qm31(sample_qm31(&mut state, true)), M31_OUTPUTS);
+}
+
+#[test]
+fn sample_hash_is_four_little_endian_draws() {
+    let mut state = SEED;
+    let expected = OUTPUTS
+        .iter()
+        .flat_map(|output| output.to_le_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(encode_hash(sample_hash(&mut state)).to_vec(), expected);
+}
@@ -1,85 +1,524 @@
-use std::collections::BTreeMap;
+#[path = "../../stwo-interop-rs/src/poseidon.rs"]
+mod poseidon;
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::{BTreeMap, HashSet};
 use std::env;
 use std::fs;
-use std::path::PathBuf;
-
-use serde::Serialize;
-use stwo::core::channel::{Blake2sChannel, Channel};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use poseidon::{
+    poseidon_apply_external_round_matrix, poseidon_apply_internal_round_matrix, poseidon_apply_m4,
+    poseidon_external_round_const, poseidon_internal_round_const, poseidon_pow5, POSEIDON_COLUMNS,
+    POSEIDON_FULL_ROUNDS, POSEIDON_PARTIAL_ROUNDS, POSEIDON_STATE,
+};
+use serde::{Serialize, Serializer};
+use stwo::core::air::accumulation::PointEvaluationAccumulator;
+use stwo::core::air::Component;
+use stwo::core::channel::{Blake2sChannel, Channel, MerkleChannel};
 use stwo::core::circle::{
     CirclePoint, Coset, M31_CIRCLE_GEN, M31_CIRCLE_LOG_ORDER, SECURE_FIELD_CIRCLE_GEN,
+    SECURE_FIELD_CIRCLE_ORDER,
 };
 use stwo::core::fft::{butterfly, ibutterfly};
 use stwo::core::fields::cm31::CM31;
 use stwo::core::fields::m31::{M31, P};
-use stwo::core::fields::qm31::QM31;
+use stwo::core::fields::qm31::{QM31, SECURE_EXTENSION_DEGREE};
 use stwo::core::fields::{ComplexConjugate, FieldExpOps};
-use stwo::core::fri::{fold_circle_into_line, fold_line, FriLayerProof, FriProof};
+use stwo::core::fri::{
+    fold_circle_into_line, fold_line, FriConfig, FriLayerProof, FriProof, FriVerificationError,
+    CIRCLE_TO_LINE_FOLD_STEP,
+};
 use stwo::core::pcs::quotients::{
     accumulate_row_partial_numerators, accumulate_row_quotients,
     build_samples_with_randomness_and_periodicity, denominator_inverses, fri_answers,
     quotient_constants, ColumnSampleBatch, CommitmentSchemeProof, PointSample,
 };
 use stwo::core::pcs::utils::prepare_preprocessed_query_positions;
-use stwo::core::pcs::PcsConfig;
 use stwo::core::pcs::TreeVec;
+use stwo::core::pcs::{CommitmentSchemeVerifier, PcsConfig};
 use stwo::core::poly::circle::CanonicCoset;
 use stwo::core::poly::line::{LineDomain, LinePoly};
 use stwo::core::proof::StarkProof;
+use stwo::core::queries::Queries;
 use stwo::core::utils::{bit_reverse, bit_reverse_index, coset_index_to_circle_domain_index};
-use stwo::core::vcs::blake2_hash::Blake2sHash;
+use stwo::core::vcs::blake2_hash::{Blake2sHash, Blake2sHasher};
 use stwo::core::vcs::blake2_merkle::Blake2sMerkleHasher as VcsMerkleHasher;
-use stwo::core::vcs::blake3_hash::{Blake3Hash, Blake3Hasher};
+use stwo::core::vcs::blake3_hash::Blake3Hasher;
 use stwo::core::vcs::verifier::{MerkleDecommitment, MerkleVerificationError, MerkleVerifier};
 use stwo::core::vcs::MerkleHasher;
-use stwo::core::vcs_lifted::blake2_merkle::Blake2sMerkleHasher as LiftedMerkleHasher;
+use stwo::core::vcs_lifted::blake2_merkle::{
+    Blake2sMerkleChannel, Blake2sMerkleHasher as LiftedMerkleHasher,
+};
 use stwo::core::vcs_lifted::verifier::{
     MerkleDecommitmentLifted, MerkleVerificationError as MerkleVerificationErrorLifted,
     MerkleVerifierLifted,
 };
 use stwo::core::vcs_lifted::MerkleHasherLifted;
+use stwo::core::verifier::{verify, VerificationError};
+use stwo::prover::backend::cpu::{CpuBackend, CpuCircleEvaluation};
+use stwo::prover::poly::circle::PolyOps;
+use stwo::prover::poly::BitReversedOrder;
+use stwo::prover::{
+    prove, CommitmentSchemeProver, ComponentProver, DomainEvaluationAccumulator, Trace,
+};
+use stwo_vector_common::{
+    decode_m31, decode_qm31, encode_blake3_hash, encode_circle_point, encode_cm31, encode_hash,
+    encode_m31, encode_qm31, encode_secure_circle_point, fill_bytes, next_u64, sample_cm31,
+    sample_hash, sample_m31, sample_qm31,
+};
 
 const UPSTREAM_COMMIT: &str = "a8fcf4bdde3778ae72f1e6cfe61a38e2911648d2";
-const VECTOR_SCHEMA_VERSION: u32 = 1;
+/// Version 5 adds a stable `id` to every entry; `--schema` selects an older layout.
+const VECTOR_SCHEMA_VERSION: u32 = 5;
+/// First version with the top-level `error_taxonomy`.
+const ERROR_TAXONOMY_SCHEMA_VERSION: u32 = 4;
+/// First version with the per-family `families` map.
+const FAMILIES_SCHEMA_VERSION: u32 = 3;
+/// First version whose entries carry an `id` (see `VectorIds`).
+const VECTOR_ID_SCHEMA_VERSION: u32 = 5;
+const LEGACY_VECTOR_SCHEMA_VERSION: u32 = 2;
 const VECTOR_SEED: u64 = 0x243f_6a88_85a3_08d3u64;
 const FRI_LAYER_DECOMMIT_SEED: u64 = 0x7b5f_1d0a_9c33_41f2u64;
 const PCS_PREPROCESSED_QUERY_SEED: u64 = 0x51f2_44ab_10ce_d9a7u64;
+/// Primary seeds of the two `--differential` sets (the 64-bit golden ratio and a splitmix64
+/// multiplier). Neither is `VECTOR_SEED`, so a consumer that hard-codes canonical values fails on
+/// both sets.
+const DIFFERENTIAL_SEEDS: [u64; 2] = [0x9e37_79b9_7f4a_7c15u64, 0xbf58_476d_1ce4_e5b9u64];
+/// `meta.column_encoding` value of families written by `--encoding packed`: each column is one
+/// standard (padded) base64 string of its values as little-endian u32s.
+const PACKED_COLUMN_ENCODING: &str = "base64_u32_le";
+/// Trace families whose `Vec<Vec<u32>>` column fields `--encoding packed` rewrites.
+const PACKED_COLUMN_FIELDS: [(&str, &[&str]); 4] = [
+    ("example_state_machine_trace", &["columns"]),
+    ("example_wide_fibonacci_trace", &["columns"]),
+    ("example_plonk_trace", &["preprocessed", "main"]),
+    ("example_poseidon_trace", &["columns"]),
+];
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+/// Default `--count` of each differential set; the sets only need to exercise code paths.
+const DIFFERENTIAL_SAMPLE_COUNT: usize = 16;
+/// Relations that hold in both differential sets without their values matching: family,
+/// relation id (what the harness dispatches on), and meaning. Generation asserts every relation
+/// before writing.
+const DIFFERENTIAL_CHECKS: [(&str, &str, &str); 9] = [
+    (
+        "m31",
+        "seed_dependent",
+        "the two sets disagree on at least one entry",
+    ),
+    (
+        "m31",
+        "count_matches_sample_count",
+        "each set has meta.sample_count entries",
+    ),
+    (
+        "fri_folds",
+        "fold_line_halves",
+        "len(fold_line_values) * 2 == len(line_eval) for every entry",
+    ),
+    (
+        "fri_folds",
+        "fold_circle_halves",
+        "len(fold_circle_values) * 2 == len(circle_eval) for every entry",
+    ),
+    (
+        "vcs_verifier",
+        "valid_case_verifies",
+        "every entry with case \"valid\" has expected \"ok\"",
+    ),
+    (
+        "fri_decommit",
+        "valid_case_verifies",
+        "every entry with case \"valid\" has expected \"ok\"",
+    ),
+    (
+        "pcs_quotients",
+        "row_quotient_per_query",
+        "len(row_quotients) == len(query_positions) for every entry",
+    ),
+    (
+        "example_state_machine_trace",
+        "column_length",
+        "every column has 2^log_size rows",
+    ),
+    (
+        "channel_mix_root",
+        "root_patterns_match",
+        "both sets list the same root_pattern sequence",
+    ),
+];
 const VECTOR_SEED_STRATEGY: &str =
-    "deterministic xorshift64* streams (primary stream + dedicated fri_layer_decommit and pcs_preprocessed_query streams)";
+    "deterministic xorshift64* stream per family, seeded from blake2s(primary seed, family name) \
+     unless dedicated or overridden with --family-seed (see families.<name>.seed)";
+/// Families whose stream is not derived from the primary seed: their dedicated seed, or `None`
+/// for fixed vectors that draw nothing.
+const FAMILY_SEEDS: [(&str, Option<u64>); 10] = [
+    ("known_answer", None),
+    ("fri_layer_decommit", Some(FRI_LAYER_DECOMMIT_SEED)),
+    ("fri_layer_count", None),
+    (
+        "pcs_preprocessed_queries",
+        Some(PCS_PREPROCESSED_QUERY_SEED),
+    ),
+    ("example_poseidon_trace", None),
+    ("blowup_sizing", None),
+    ("config_security", None),
+    ("e2e_tree_shapes", None),
+    ("field_boundaries", None),
+    ("fft_edge", None),
+];
+/// Generation phases that append to another family instead of producing their own.
+const FAMILY_EXTENSION_PHASES: [(&str, &str); 3] = [
+    ("proof_extract_oods_errors", "proof_extract_oods"),
+    ("proof_sizes_edges", "proof_sizes"),
+    (
+        "example_wide_fibonacci_trace_seeded",
+        "example_wide_fibonacci_trace",
+    ),
+];
 const DEFAULT_COUNT: usize = 256;
+/// Sizes and counts for the families whose Zig counterparts manage per-call buffers; every other
+/// family is generated the same way in every profile.
+#[derive(Debug, Clone, Copy)]
+struct GenerationProfile {
+    name: &'static str,
+    default_count: usize,
+    default_out: &'static str,
+    /// Inclusive log-size range of both `fri_folds` evaluations.
+    fri_fold_log_sizes: (u32, u32),
+    fri_fold_count: usize,
+    /// Largest column log size of the classic and lifted VCS families.
+    vcs_max_column_log_size: u32,
+    vcs_verifier_count: usize,
+    vcs_prover_count: usize,
+    vcs_lifted_verifier_count: usize,
+    vcs_lifted_prover_count: usize,
+    pcs_lifting_log_size: u32,
+    pcs_count: usize,
+    pcs_shared_point_count: usize,
+}
+const DEFAULT_PROFILE: GenerationProfile = GenerationProfile {
+    name: "default",
+    default_count: DEFAULT_COUNT,
+    default_out: "vectors/fields.json",
+    fri_fold_log_sizes: (2, 6),
+    fri_fold_count: FRI_FOLD_VECTOR_COUNT,
+    vcs_max_column_log_size: VCS_MAX_COLUMN_LOG_SIZE,
+    vcs_verifier_count: VCS_VERIFIER_VECTOR_COUNT,
+    vcs_prover_count: VCS_PROVER_VECTOR_COUNT,
+    vcs_lifted_verifier_count: VCS_LIFTED_VERIFIER_VECTOR_COUNT,
+    vcs_lifted_prover_count: VCS_LIFTED_PROVER_VECTOR_COUNT,
+    pcs_lifting_log_size: PCS_LIFTING_LOG_SIZE,
+    pcs_count: PCS_VECTOR_COUNT,
+    pcs_shared_point_count: PCS_SHARED_POINT_VECTOR_COUNT,
+};
+/// Stress sizes for the allocation paths that only misbehave at log size 16 and up; counts are
+/// cut so a run stays around a minute and well under 2 GB.
+const LARGE_PROFILE: GenerationProfile = GenerationProfile {
+    name: "large",
+    default_count: 16,
+    default_out: "vectors/fields_large.json",
+    fri_fold_log_sizes: (12, 16),
+    fri_fold_count: 4,
+    vcs_max_column_log_size: 14,
+    vcs_verifier_count: 8,
+    vcs_prover_count: 2,
+    vcs_lifted_verifier_count: 8,
+    vcs_lifted_prover_count: 4,
+    pcs_lifting_log_size: 14,
+    pcs_count: 2 * PCS_FORCED_QUERY_VECTOR_COUNT,
+    pcs_shared_point_count: 2,
+};
 const PCS_VECTOR_COUNT: usize = 16;
 const PCS_LIFTING_LOG_SIZE: u32 = 8;
+const PCS_SHARED_POINT_VECTOR_COUNT: usize = 8;
+const PCS_SHARED_POINT_PERCENT: u64 = 75;
+/// Leading `pcs_quotients` vectors per forced query kind (position 0, then a conjugate-half
+/// position); the rest draw every position at random.
+const PCS_FORCED_QUERY_VECTOR_COUNT: usize = 4;
+const SAMPLES_WITH_RANDOMNESS_VECTOR_COUNT: usize = 8;
+const SAMPLES_WITH_RANDOMNESS_COLS_PER_TREE: usize = 4;
+const DENOMINATOR_INVERSES_RANDOM_COUNT: usize = 6;
+const DENOMINATOR_INVERSES_NEAR_DEGENERATE_COUNT: usize = 5;
+const DENOMINATOR_INVERSES_MAX_POINTS: usize = 4;
+const DENOMINATOR_INVERSES_MAX_PERTURBATION: u64 = 3;
 const PCS_QUERY_COUNT: usize = 4;
+const FRI_ANSWERS_ERROR_VECTOR_COUNT: usize = 24;
 const FRI_FOLD_VECTOR_COUNT: usize = 32;
 const FRI_DECOMMIT_VECTOR_COUNT: usize = 32;
 const FRI_LAYER_DECOMMIT_VECTOR_COUNT: usize = 24;
+const FRI_LAYER_CHAIN_DECOMMIT_VECTOR_COUNT: usize = 12;
+const FRI_FIRST_LAYER_DECOMMIT_VECTOR_COUNT: usize = 12;
 const PROOF_OODS_VECTOR_COUNT: usize = 32;
 const PROOF_SIZE_VECTOR_COUNT: usize = 16;
 const PROVER_LINE_VECTOR_COUNT: usize = 32;
 const PCS_PREPROCESSED_QUERY_VECTOR_COUNT: usize = 64;
 const VCS_VERIFIER_VECTOR_COUNT: usize = 24;
 const VCS_PROVER_VECTOR_COUNT: usize = 16;
-const VCS_LIFTED_VERIFIER_VECTOR_COUNT: usize = 24;
+const VCS_SINGLE_ELEMENT_VERIFIER_VECTOR_COUNT: usize = 10;
+const VCS_SINGLE_ELEMENT_PROVER_VECTOR_COUNT: usize = 4;
+const VCS_MAX_COLUMN_LOG_SIZE: u32 = 4;
+const VCS_LIFTED_VERIFIER_VECTOR_COUNT: usize = 40;
 const VCS_LIFTED_PROVER_VECTOR_COUNT: usize = 16;
+/// Scalars multiplied into each generator by `circle_scalar_mul` besides the random full-width
+/// ones; `order` is the generator's group order and `max` is `u128::MAX`.
+const CIRCLE_SCALAR_MUL_EDGE_CASES: [&str; 6] = [
+    "zero",
+    "one",
+    "order_minus_one",
+    "order",
+    "order_plus_one",
+    "max",
+];
+const CIRCLE_SCALAR_MUL_RANDOM_COUNT: usize = 6;
+const CIRCLE_SCALAR_MUL_VECTOR_COUNT: usize =
+    2 * (CIRCLE_SCALAR_MUL_EDGE_CASES.len() + CIRCLE_SCALAR_MUL_RANDOM_COUNT);
+/// Query patterns cycled through by the lifted-VCS families. Random sampling at these sizes rarely
+/// queries both children of a node, so every other case forces sibling pairs or the whole leaf
+/// layer.
+const VCS_LIFTED_QUERY_CASES: [&str; 4] =
+    ["random", "adjacent_queries", "random", "all_leaves_queried"];
+/// Every `MerkleVerificationError` variant of the classic scheme with the tampered layouts that
+/// would trigger it there; `vcs_lifted_error_variants` replays them against the lifted verifier.
+const MERKLE_ERROR_VARIANT_PROBES: [(&str, &[&str]); 5] = [
+    ("WitnessTooShort", &["witness_too_short"]),
+    ("WitnessTooLong", &["witness_too_long"]),
+    (
+        "TooManyQueriedValues",
+        &["extra_column", "extra_query_value"],
+    ),
+    (
+        "TooFewQueriedValues",
+        &["missing_column", "missing_query_value"],
+    ),
+    ("RootMismatch", &["root_mismatch"]),
+];
+const QUERIED_ROWS_RESHAPE_VECTOR_COUNT: usize = 8;
+const COMPOSITION_SAMPLED_ORDER_VECTOR_COUNT: usize = 6;
 const BLAKE3_VECTOR_COUNT: usize = 64;
+const BLAKE2S_VECTOR_COUNT: usize = 64;
+const CHANNEL_MIX_ROOT_VECTOR_COUNT: usize = 24;
+const OODS_DRAW_VECTOR_COUNT: usize = 32;
+const OODS_DRAW_MAX_MIXES: usize = 4;
+const OODS_DRAW_MAX_DRAWS: usize = 4;
+const ACCUMULATION_VECTOR_COUNT: usize = 12;
+const ACCUMULATION_MAX_EVALUATIONS: usize = 8;
+const ACCUMULATION_MAX_CALLS: usize = 6;
+const MERKLE_AVALANCHE_VECTOR_COUNT: usize = 8;
+const LOOKUP_DRAW_V2_VECTOR_COUNT: usize = 24;
+const LOOKUP_DRAW_V2_MAX_ROUNDS: usize = 3;
+const LOOKUP_DRAW_V2_MAX_BATCH: usize = 6;
+const LOOKUP_DRAW_V2_MAX_MIXED_FELTS: usize = 4;
+/// M31 operands of `field_boundaries`: both identities, the smallest non-trivial value, the
+/// values next to the modulus and the midpoint `(P - 1) / 2` where doubling wraps.
+const FIELD_BOUNDARY_M31: [u32; 6] = [0, 1, 2, P - 1, P - 2, (P - 1) / 2];
+/// CM31 operands: zero, pure-real, pure-imaginary and conjugate-symmetric (`a ± ai`) values
+/// built from the M31 boundaries.
+const FIELD_BOUNDARY_CM31: [[u32; 2]; 10] = [
+    [0, 0],
+    [1, 0],
+    [P - 1, 0],
+    [0, 1],
+    [0, P - 1],
+    [1, 1],
+    [1, P - 1],
+    [P - 1, P - 1],
+    [(P - 1) / 2, (P - 1) / 2],
+    [2, P - 2],
+];
+/// QM31 operands: zero, the unit of each coordinate, all-ones, all-`P - 1`, the midpoint, a
+/// value with a zero CM31 half, and conjugate-symmetric halves.
+const FIELD_BOUNDARY_QM31: [[u32; 4]; 10] = [
+    [0, 0, 0, 0],
+    [1, 0, 0, 0],
+    [0, 1, 0, 0],
+    [0, 0, 1, 0],
+    [0, 0, 0, 1],
+    [1, 1, 1, 1],
+    [P - 1, P - 1, P - 1, P - 1],
+    [(P - 1) / 2, (P - 1) / 2, (P - 1) / 2, (P - 1) / 2],
+    [0, 0, P - 1, 1],
+    [2, P - 2, P - 2, 2],
+];
+const FIELD_BOUNDARIES_VECTOR_COUNT: usize = FIELD_BOUNDARY_M31.len() * FIELD_BOUNDARY_M31.len()
+    + FIELD_BOUNDARY_CM31.len() * FIELD_BOUNDARY_CM31.len()
+    + FIELD_BOUNDARY_QM31.len() * FIELD_BOUNDARY_QM31.len();
+/// `fft_edge` cases: name, butterfly operands `[a, b]` and twiddle. Zero has no inverse, so the
+/// zero-twiddle cases pin what an inverse butterfly does when it cannot be given `1 / twid`.
+const FFT_EDGE_CASES: [(&str, [u32; 2], u32); 5] = [
+    ("zero_twiddle", [P - 2, 3], 0),
+    ("unit_twiddle", [P - 2, 3], 1),
+    ("minus_one_twiddle", [P - 2, 3], P - 1),
+    ("zero_operands", [0, 0], 2),
+    ("zero_operands_zero_twiddle", [0, 0], 0),
+];
+const MERKLE_AVALANCHE_SCHEMES: [&str; 2] = ["vcs", "vcs_lifted"];
+const MERKLE_AVALANCHE_MAX_COLUMN_LOG_SIZE: u32 = 4;
+/// Random perturbations per vector on top of the forced high-bit, last-column and final-leaf ones.
+const MERKLE_AVALANCHE_RANDOM_PERTURBATIONS: usize = 4;
 const EXAMPLE_STATE_MACHINE_TRACE_VECTOR_COUNT: usize = 24;
 const EXAMPLE_STATE_MACHINE_TRANSITION_VECTOR_COUNT: usize = 24;
 const EXAMPLE_STATE_MACHINE_CLAIMED_SUM_VECTOR_COUNT: usize = 24;
 const EXAMPLE_STATE_MACHINE_LOOKUP_DRAW_VECTOR_COUNT: usize = 24;
 const EXAMPLE_STATE_MACHINE_STATEMENT_VECTOR_COUNT: usize = 24;
+const EXAMPLE_STATE_MACHINE_COMPONENTS_VECTOR_COUNT: usize = 12;
 const EXAMPLE_XOR_IS_FIRST_VECTOR_COUNT: usize = 24;
 const EXAMPLE_XOR_IS_STEP_WITH_OFFSET_VECTOR_COUNT: usize = 32;
 const EXAMPLE_WIDE_FIBONACCI_TRACE_VECTOR_COUNT: usize = 24;
 const EXAMPLE_PLONK_TRACE_VECTOR_COUNT: usize = 24;
+const PCS_COMMIT_VECTOR_COUNT: usize = 12;
+/// Trace sources of `pcs_commit`, cycled in order.
+const PCS_COMMIT_CASES: [&str; 3] = ["random", "state_machine", "plonk"];
+const PCS_COMMIT_LOG_BLOWUP_FACTOR: u32 = 1;
+const E2E_TREE_SHAPES_LOG_SIZES: [u32; 2] = [3, 4];
+/// Logical trees of an `e2e_tree_shapes` proof, in commitment order.
+const E2E_TREE_NAMES: [&str; 4] = ["preprocessed", "main", "interaction", "composition"];
+/// The composition polynomial is committed as two halves of one lower log degree bound.
+const E2E_COMPOSITION_LOG_SPLIT: u32 = 1;
+/// Every Poseidon row holds 1264 columns, so the trace vectors stay at a handful of rows.
+const EXAMPLE_POSEIDON_TRACE_LOG_N_ROWS: [u32; 3] = [0, 1, 2];
+const EXAMPLE_POSEIDON_ROUNDS_VECTOR_COUNT: usize = 16;
+const QM31_STRUCTURE_VECTOR_COUNT: usize = 32;
+const QM31_STRUCTURE_SELF_CONJUGATE_COUNT: usize = 4;
+/// `secure_column` cases and their column lengths: a single row, odd lengths, and powers of two
+/// whose leaf hashes are also checked against a full lifted commitment.
+const SECURE_COLUMN_CASES: [(&str, usize); 7] = [
+    ("single_row", 1),
+    ("power_of_two", 2),
+    ("odd_length", 3),
+    ("odd_length", 7),
+    ("power_of_two", 8),
+    ("odd_length", 31),
+    ("power_of_two", 32),
+];
+const FRI_FOLD_CHAIN_VECTOR_COUNT: usize = 16;
+const FRI_FOLD_ORDERING_VECTOR_COUNT: usize = 10;
+const FRI_FOLD_CHAIN_MIN_CIRCLE_LOG_SIZE: u32 = 4;
+const FRI_FOLD_CHAIN_MAX_CIRCLE_LOG_SIZE: u32 = 9;
+const FRI_FOLD_CHAIN_MAX_LAST_LAYER_LOG_SIZE: u32 = 3;
+const FRI_LAST_LAYER_VECTOR_COUNT: usize = 32;
+const LINE_POLY_CONSTRUCTION_LENGTHS: [usize; 6] = [0, 1, 2, 3, 5, 8];
+const LINE_POLY_CONSTRUCTION_SAMPLES_PER_LENGTH: usize = 2;
+const FRI_LAST_LAYER_MAX_LOG_DEGREE_BOUND: u32 = 4;
+const FRI_LAST_LAYER_MAX_LOG_BLOWUP: u32 = 3;
+const FRI_LAST_LAYER_MAX_QUERIES: usize = 4;
+const FRI_LAYER_COUNT_MIN_COLUMN_LOG_SIZE: u32 = 3;
+const FRI_LAYER_COUNT_MAX_COLUMN_LOG_SIZE: u32 = 20;
+const FRI_LAYER_COUNT_MAX_LOG_BLOWUP: u32 = 4;
+const FRI_LAYER_COUNT_MAX_LOG_LAST_LAYER: u32 = 4;
+const FRI_LAYER_COUNT_MAX_SIMULATED_DOMAIN_LOG_SIZE: u32 = 12;
 
 #[derive(Debug, Clone, Serialize)]
 struct Meta {
     upstream_commit: &'static str,
+    profile: &'static str,
     sample_count: usize,
     schema_version: u32,
     seed: u64,
     seed_strategy: &'static str,
 }
 
+/// Per-family entry of the schema-3 `families` map. `generator` lists the generation phases (as
+/// named in `generation_stats`) that produced the family's entries, in order.
+#[derive(Debug, Clone, Serialize)]
+struct FamilyMeta {
+    count: usize,
+    seed: Option<u64>,
+    generator: Vec<&'static str>,
+}
+
+/// Schema-4 `error_taxonomy` entry: every string a family's `field` can hold. `errors` are the
+/// variant names of `source` (listed next to an exhaustive match on it, so a new upstream variant
+/// fails to compile here) and `outcomes` the non-error markers (`ok`, `Panic`) the family uses.
+/// Generation asserts that every emitted value is listed.
+#[derive(Debug, Clone, Serialize)]
+struct ErrorTaxonomyEntry {
+    field: &'static str,
+    source: Option<&'static str>,
+    errors: Vec<&'static str>,
+    outcomes: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FamilyGenerationStats {
+    family: &'static str,
+    cases: usize,
+    seconds: f64,
+    /// Discarded draws for families whose builders retry degenerate samples; `None` otherwise.
+    rejections: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct RetryStats {
+    rejections: u64,
+}
+
+struct GenerationTimer {
+    started: Instant,
+    budget_seconds: Option<f64>,
+    families: Vec<FamilyGenerationStats>,
+}
+
+impl GenerationTimer {
+    fn new(budget_seconds: Option<f64>) -> Self {
+        Self {
+            started: Instant::now(),
+            budget_seconds,
+            families: Vec::new(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        family: &'static str,
+        cases: usize,
+        family_started: Instant,
+        rejections: Option<u64>,
+    ) {
+        let seconds = family_started.elapsed().as_secs_f64();
+        self.families.push(FamilyGenerationStats {
+            family,
+            cases,
+            seconds,
+            rejections,
+        });
+        if let Some(budget) = self.budget_seconds {
+            let total = self.started.elapsed().as_secs_f64();
+            if total > budget {
+                eprintln!(
+                    "vector generation exceeded --budget-seconds {budget}: family `{family}` \
+                     took {seconds:.3}s, bringing the total to {total:.3}s"
+                );
+                std::process::exit(i32::from(EXIT_GENERATION));
+            }
+        }
+    }
+
+    fn run<T>(&mut self, family: &'static str, generate: impl FnOnce() -> Vec<T>) -> Vec<T> {
+        let family_started = Instant::now();
+        let out = generate();
+        self.record(family, out.len(), family_started, None);
+        out
+    }
+
+    fn run_with_retries<T>(
+        &mut self,
+        family: &'static str,
+        generate: impl FnOnce(&mut RetryStats) -> Vec<T>,
+    ) -> Vec<T> {
+        let family_started = Instant::now();
+        let mut stats = RetryStats::default();
+        let out = generate(&mut stats);
+        self.record(family, out.len(), family_started, Some(stats.rejections));
+        out
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct M31Vector {
     a: u32,
@@ -113,6 +552,46 @@ struct QM31Vector {
     div_ab: [u32; 4],
 }
 
+/// One ordered operand pair of `field_boundaries`. Values are coordinate lists of length 1
+/// (`m31`), 2 (`cm31`) or 4 (`qm31`). `inv_a` and `div_ab` are null exactly when the operation
+/// divides by zero, and `undefined` then names them, so consumers can assert the error instead of
+/// skipping the case.
+#[derive(Debug, Clone, Serialize)]
+struct FieldBoundaryVector {
+    field: String,
+    a: Vec<u32>,
+    b: Vec<u32>,
+    add: Vec<u32>,
+    sub: Vec<u32>,
+    mul: Vec<u32>,
+    inv_a: Option<Vec<u32>>,
+    div_ab: Option<Vec<u32>>,
+    undefined: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct QM31StructureVector {
+    case: String,
+    value: [u32; 4],
+    complex_conjugate: [u32; 4],
+    m31_array: [u32; 4],
+    from_m31_array: [u32; 4],
+    partial_evals: [[u32; 4]; 4],
+    from_partial_evals: [u32; 4],
+}
+
+/// A QM31 column split into its four M31 coordinate columns (in `to_m31_array` order), the column
+/// rebuilt from them, and the lifted-hasher leaf hash each row gets when the four coordinate
+/// columns are committed together.
+#[derive(Debug, Clone, Serialize)]
+struct SecureColumnVector {
+    case: String,
+    column: Vec<[u32; 4]>,
+    coordinates: [Vec<u32>; 4],
+    reconstructed: Vec<[u32; 4]>,
+    leaf_hashes: Vec<[u8; 32]>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct CircleM31Vector {
     a_scalar: u64,
@@ -126,6 +605,19 @@ struct CircleM31Vector {
     conjugate_a: [u32; 2],
 }
 
+/// `generator.mul(scalar)` for `generator` `m31` (`M31_CIRCLE_GEN`) or `secure_field`
+/// (`SECURE_FIELD_CIRCLE_GEN`). `scalar` is a decimal string since it can exceed 2^64; `x` and `y`
+/// hold 1 or 4 limbs. `log_order` is only set for M31 points, whose group order is a power of two.
+#[derive(Debug, Clone, Serialize)]
+struct CircleScalarMulVector {
+    generator: String,
+    case: String,
+    scalar: String,
+    x: Vec<u32>,
+    y: Vec<u32>,
+    log_order: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct FftM31Vector {
     a: u32,
@@ -135,6 +627,23 @@ struct FftM31Vector {
     ibutterfly: [u32; 2],
 }
 
+/// A butterfly pair at an edge twiddle. `itwid` and `ibutterfly` (the inverse butterfly of the
+/// `butterfly` output, as in `fft_m31`) are null when upstream cannot invert `twid`, and
+/// `undefined` then names them. `ibutterfly_with_twid` passes `twid` itself as the inverse
+/// butterfly's twiddle, which upstream accepts for every value, zero included.
+#[derive(Debug, Clone, Serialize)]
+struct FftEdgeVector {
+    case: String,
+    a: u32,
+    b: u32,
+    twid: u32,
+    butterfly: [u32; 2],
+    itwid: Option<u32>,
+    ibutterfly: Option<[u32; 2]>,
+    ibutterfly_with_twid: [u32; 2],
+    undefined: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct Blake3Vector {
     data: Vec<u8>,
@@ -144,6 +653,114 @@ struct Blake3Vector {
     concat_hash: [u8; 32],
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct Blake2sVector {
+    data: Vec<u8>,
+    hash: [u8; 32],
+    node_values: Vec<u32>,
+    left: [u8; 32],
+    right: [u8; 32],
+    hash_node_with_children: [u8; 32],
+    hash_node_leaf: [u8; 32],
+    lifted_leaf_updates: Vec<Vec<u32>>,
+    lifted_leaf_digests: Vec<[u8; 32]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ChannelMixRootVector {
+    root_pattern: String,
+    prefix_mix_u64: u64,
+    prefix_mix_u32s: Vec<u32>,
+    digest_before: [u8; 32],
+    root: [u8; 32],
+    digest_after: [u8; 32],
+    draw_secure_felt: [u32; 4],
+    wrong_variant_digests: BTreeMap<&'static str, [u8; 32]>,
+}
+
+/// A channel prepared by `mixes`, then 1-4 consecutive `CirclePoint::get_random_point` draws.
+/// Each draw records the felt `t` the point is derived from, the point and the digest after it.
+#[derive(Debug, Clone, Serialize)]
+struct OodsDrawVector {
+    mixes: Vec<ChannelMixVector>,
+    digest_before: [u8; 32],
+    draws: Vec<OodsDrawStepVector>,
+}
+
+/// One channel mix; `op` names the call and only its operand field is set.
+#[derive(Debug, Clone, Serialize)]
+struct ChannelMixVector {
+    op: String,
+    value_u64: Option<u64>,
+    values_u32: Option<Vec<u32>>,
+    felts: Option<Vec<[u32; 4]>>,
+    root: Option<[u8; 32]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OodsDrawStepVector {
+    t: [u32; 4],
+    point: [[u32; 4]; 2],
+    digest_after: [u8; 32],
+}
+
+/// One `random_coeff` driving both accumulators. `PointEvaluationAccumulator` folds `evaluations`
+/// in order into `accumulation`. `DomainEvaluationAccumulator::new(random_coeff, max_log_size,
+/// total_columns)` then hands out one `columns` call over `requests`, and each call in `calls`
+/// adds `evaluation * random_coeff_powers[coeff_index]` at `index` of its request's column.
+/// Vectors cycle through zero, one and several constraints.
+#[derive(Debug, Clone, Serialize)]
+struct AccumulationVector {
+    random_coeff: [u32; 4],
+    evaluations: Vec<[u32; 4]>,
+    accumulation: [u32; 4],
+    max_log_size: u32,
+    total_columns: usize,
+    requests: Vec<AccumulationColumnVector>,
+    calls: Vec<AccumulationCallVector>,
+}
+
+/// A `(log_size, n_cols)` request with the coefficient powers it was handed and its bucket's
+/// values once every call has run.
+#[derive(Debug, Clone, Serialize)]
+struct AccumulationColumnVector {
+    log_size: u32,
+    n_cols: usize,
+    random_coeff_powers: Vec<[u32; 4]>,
+    values: Vec<[u32; 4]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AccumulationCallVector {
+    column: usize,
+    index: usize,
+    coeff_index: usize,
+    evaluation: [u32; 4],
+}
+
+/// A small column set committed under `scheme` (`vcs` or `vcs_lifted`), then recommitted once per
+/// perturbation with bit `bit` of `columns[column][index]` flipped to `value`. The generator
+/// asserts every `root_changed`; matching every perturbed `root` pins which bits of each value a
+/// leaf absorbs and in what order.
+#[derive(Debug, Clone, Serialize)]
+struct MerkleAvalancheVector {
+    scheme: String,
+    column_log_sizes: Vec<u32>,
+    columns: Vec<Vec<u32>>,
+    root: [u8; 32],
+    perturbations: Vec<MerklePerturbationVector>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct MerklePerturbationVector {
+    column: usize,
+    index: usize,
+    bit: u32,
+    value: u32,
+    root: [u8; 32],
+    root_changed: bool,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct PointSampleVector {
     point: [[u32; 4]; 2],
@@ -183,6 +800,8 @@ struct PcsQuotientsVector {
     samples: Vec<Vec<Vec<PointSampleVector>>>,
     random_coeff: [u32; 4],
     query_positions: Vec<usize>,
+    /// Lifting-domain point of each query position (`domain.at(bit_reverse_index(position))`).
+    domain_points: Vec<[u32; 2]>,
     queried_values: Vec<Vec<Vec<u32>>>,
     samples_with_randomness: Vec<Vec<Vec<SampleWithRandomnessVector>>>,
     sample_batches: Vec<ColumnSampleBatchVector>,
@@ -193,8 +812,65 @@ struct PcsQuotientsVector {
     fri_answers: Vec<[u32; 4]>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct SampleProvenanceVector {
+    sample: PointSampleVector,
+    random_coeff: [u32; 4],
+    power_index: usize,
+    tree: usize,
+    column: usize,
+    sample_index: usize,
+    periodic: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SamplesWithRandomnessVector {
+    lifting_log_size: u32,
+    column_log_sizes: Vec<Vec<u32>>,
+    samples: Vec<Vec<Vec<PointSampleVector>>>,
+    random_coeff: [u32; 4],
+    samples_with_randomness: Vec<Vec<Vec<SampleProvenanceVector>>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DenominatorInversesVector {
+    case: String,
+    sample_points: Vec<[[u32; 4]; 2]>,
+    u_perturbations: Vec<Option<u32>>,
+    domain_log_size: u32,
+    domain_index: usize,
+    domain_point: [u32; 2],
+    denominators: Vec<[u32; 2]>,
+    expected: String,
+    inverses: Vec<[u32; 2]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LinePolyConstructionVector {
+    coeff_count: usize,
+    coeffs: Vec<[u32; 4]>,
+    expected: String,
+    log_size: Option<u32>,
+    eval_point: [u32; 4],
+    evaluation: Option<[u32; 4]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FriAnswersErrorVector {
+    case: String,
+    lifting_log_size: u32,
+    column_log_sizes: Vec<Vec<u32>>,
+    samples: Vec<Vec<Vec<PointSampleVector>>>,
+    random_coeff: [u32; 4],
+    query_positions: Vec<usize>,
+    queried_values: Vec<Vec<Vec<u32>>>,
+    expected: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct PcsPreprocessedQueryVector {
+    /// `"random"`, or the boundary a deterministic edge vector pins.
+    case: String,
     query_positions: Vec<usize>,
     max_log_size: u32,
     pp_max_log_size: u32,
@@ -212,6 +888,73 @@ struct FriFoldVector {
     fold_circle_values: Vec<[u32; 4]>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct FriFoldChainLayerVector {
+    domain_log_size: u32,
+    domain_initial_index: usize,
+    domain_step_size: usize,
+    values: Vec<[u32; 4]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FriFoldChainVector {
+    circle_log_size: u32,
+    circle_eval: Vec<[u32; 4]>,
+    last_layer_log_size: u32,
+    alphas: Vec<[u32; 4]>,
+    layers: Vec<FriFoldChainLayerVector>,
+    last_layer_coeffs_bit_reversed: Vec<[u32; 4]>,
+    last_layer_coeffs_ordered: Vec<[u32; 4]>,
+}
+
+/// The same evaluations fed to a fold in both orderings. Upstream folds take bit-reversed input
+/// (`input_order`): position `i` holds the value at `domain.at(bit_reverse_index(i, log_size))`,
+/// so adjacent pairs are the `x`/`-x` (or conjugate) points a fold combines. `folded_natural` is
+/// the correct fold output in natural order, and `output_permutation[i]` is the index of
+/// `folded_natural[i]` in `folded_bit_reversed`. `misordered_fold` is what upstream returns when
+/// handed the natural-order input: same length, but not a reordering of the correct output.
+#[derive(Debug, Clone, Serialize)]
+struct FriFoldOrderingVector {
+    fold: &'static str,
+    input_order: &'static str,
+    log_size: u32,
+    domain_initial_index: usize,
+    domain_step_size: usize,
+    alpha: [u32; 4],
+    evaluations_bit_reversed: Vec<[u32; 4]>,
+    evaluations_natural: Vec<[u32; 4]>,
+    folded_bit_reversed: Vec<[u32; 4]>,
+    folded_natural: Vec<[u32; 4]>,
+    output_permutation: Vec<usize>,
+    misordered_fold: Vec<[u32; 4]>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FriLastLayerVector {
+    case: String,
+    log_blowup_factor: u32,
+    log_last_layer_degree_bound: u32,
+    last_layer_domain_log_size: u32,
+    coeffs: Vec<[u32; 4]>,
+    query_positions: Vec<usize>,
+    query_x: Vec<u32>,
+    evaluations: Vec<[u32; 4]>,
+    expected: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FriLayerCountVector {
+    column_log_size: u32,
+    log_blowup: u32,
+    log_last_layer_degree_bound: u32,
+    valid: bool,
+    first_layer_domain_log_size: u32,
+    n_inner_layers: u32,
+    last_layer_domain_log_size: u32,
+    last_layer_poly_len: usize,
+    source: String,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct FriDecommitVector {
     case: String,
@@ -240,12 +983,181 @@ struct FriLayerDecommitVector {
     expected: String,
 }
 
+/// Consecutive FRI layers decommitted for one query set. Layer 0 folds by
+/// `CIRCLE_TO_LINE_FOLD_STEP` and takes `query_positions`; every later layer lives on the domain
+/// shrunk by the previous fold step and takes the previous layer's positions folded by upstream
+/// `Queries::fold` (shifted right and deduplicated). Layer columns are independent random values:
+/// decommitment depends only on positions, not on the columns being actual folds.
+#[derive(Debug, Clone, Serialize)]
+struct FriLayerChainDecommitVector {
+    query_positions: Vec<usize>,
+    layers: Vec<FriLayerChainStepVector>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FriLayerChainStepVector {
+    log_size: u32,
+    fold_step: u32,
+    column: Vec<[u32; 4]>,
+    query_positions: Vec<usize>,
+    commitment: [u8; 32],
+    decommitment_positions: Vec<usize>,
+    fri_witness: Vec<[u32; 4]>,
+    hash_witness: Vec<[u8; 32]>,
+}
+
+/// A first FRI layer holding 2-3 secure columns of distinct log sizes, listed in ascending size
+/// order. The coordinate columns of every secure column go into one lifted Merkle tree in that
+/// order. `query_positions` live on the largest domain; a column of log size `k` answers them at
+/// the lifted index `((q >> (max - k + 1)) << 1) + (q & 1)`, sorted and deduplicated into
+/// `column_query_positions`. `fri_witness` concatenates every column's
+/// `CIRCLE_TO_LINE_FOLD_STEP` witness in column order, and `hash_witness` decommits the combined
+/// tree at the largest column's `decommitment_positions`.
+#[derive(Debug, Clone, Serialize)]
+struct FriFirstLayerDecommitVector {
+    log_sizes: Vec<u32>,
+    columns: Vec<Vec<[u32; 4]>>,
+    query_positions: Vec<usize>,
+    column_query_positions: Vec<Vec<usize>>,
+    commitment: [u8; 32],
+    decommitment_positions: Vec<usize>,
+    fri_witness: Vec<[u32; 4]>,
+    hash_witness: Vec<[u8; 32]>,
+}
+
+/// Roots a `CommitmentSchemeProver` (CPU backend, `PCS_COMMIT_LOG_BLOWUP_FACTOR`) produces for
+/// deterministic trees of trace columns, committed one tree after another on a fresh channel.
+#[derive(Debug, Clone, Serialize)]
+struct PcsCommitVector {
+    case: String,
+    log_blowup_factor: u32,
+    trees: Vec<PcsCommitTreeVector>,
+}
+
+/// One committed tree. `columns` are evaluations in bit-reversed circle-domain order and are
+/// extended by the blowup factor before hashing; `sorted_column_order` lists the input column
+/// indices in the order the lifted Merkle tree hashes them (stable, ascending log size) and
+/// `n_layers` counts the tree's layers from the root down to the leaves.
+#[derive(Debug, Clone, Serialize)]
+struct PcsCommitTreeVector {
+    column_log_sizes: Vec<u32>,
+    columns: Vec<Vec<u32>>,
+    sorted_column_order: Vec<usize>,
+    n_layers: usize,
+    root: [u8; 32],
+}
+
+/// Shape of every tree of one real `StarkProof` over the state machine's layout: preprocessed
+/// (`is_first`), main (the two state columns), interaction (the four coordinates of the logup
+/// running sum, sampled at the row and the previous row) and the composition tree `prove` adds.
+/// The proof is checked with upstream `verify` before it is recorded.
+#[derive(Debug, Clone, Serialize)]
+struct E2eTreeShapesVector {
+    log_size: u32,
+    initial_state: [u32; 2],
+    log_blowup_factor: u32,
+    n_queries: usize,
+    trees: Vec<E2eTreeShapeVector>,
+}
+
+/// `sampled_values_shape` and `queried_values_shape` hold one length per column.
+#[derive(Debug, Clone, Serialize)]
+struct E2eTreeShapeVector {
+    tree: String,
+    n_columns: usize,
+    column_log_sizes: Vec<u32>,
+    commitment: [u8; 32],
+    sampled_values_shape: Vec<usize>,
+    queried_values_shape: Vec<usize>,
+    hash_witness_len: usize,
+}
+
+/// Stand-in AIR for `e2e_tree_shapes` with the state machine's tree layout. Like the interop
+/// examples it evaluates to a constant composition, so the proof verifies without real
+/// constraints and only the tree shapes carry information.
+#[derive(Debug, Clone, Copy)]
+struct TreeShapesComponent {
+    log_size: u32,
+    composition_eval: QM31,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct ProofExtractOodsVector {
+    case: String,
     composition_log_size: u32,
     oods_point: [[u32; 4]; 2],
+    has_composition_tree: bool,
     composition_values: Vec<[u32; 4]>,
-    expected: [u32; 4],
+    expected: Option<[u32; 4]>,
+    expected_error: Option<String>,
+}
+
+/// One `sampled_values[tree][column][mask]` cell. `composition_coordinate` is set on the
+/// composition tree's cells: `"left"`/`"right"` half and coordinate index, as combined by
+/// `QM31::from_partial_evals`.
+#[derive(Debug, Clone, Serialize)]
+struct CompositionSampledCell {
+    tree: usize,
+    column: usize,
+    mask: usize,
+    value: [u32; 4],
+    composition_coordinate: Option<(String, usize)>,
+}
+
+/// A full `sampled_values` TreeVec laid out like an interop example proof: preprocessed tree,
+/// trace tree (one or two OODS samples per column), optional interaction tree, and the
+/// composition tree last. `composition_cells` indexes `cells` in the order the OODS composition
+/// reconstruction reads them; `composition_oods_eval` is upstream's
+/// `extract_composition_oods_eval` on the whole TreeVec.
+#[derive(Debug, Clone, Serialize)]
+struct CompositionSampledOrderVector {
+    composition_log_size: u32,
+    oods_point: [[u32; 4]; 2],
+    sampled_values: Vec<Vec<Vec<[u32; 4]>>>,
+    cells: Vec<CompositionSampledCell>,
+    composition_cells: Vec<usize>,
+    composition_oods_eval: [u32; 4],
+}
+
+/// Domain sizes for a component with `trace_log_size` rows and max constraint log degree bound
+/// `trace_log_size + constraint_log_degree_increment`. The commitment and first FRI layer sizes
+/// come from upstream's `CommitmentSchemeVerifier` (trace columns, then the
+/// `2 * SECURE_EXTENSION_DEGREE` composition columns of log size bound - 1, each extended by the
+/// blowup); the first FRI layer spans the largest committed column. `twiddle_log_size` is the
+/// prover sizing `CanonicCoset::new(max_constraint_log_degree_bound + log_blowup)`, which covers
+/// every domain above.
+#[derive(Debug, Clone, Serialize)]
+struct BlowupSizingVector {
+    trace_log_size: u32,
+    constraint_log_degree_increment: u32,
+    log_blowup: u32,
+    max_constraint_log_degree_bound: u32,
+    twiddle_log_size: u32,
+    trace_commitment_log_size: u32,
+    composition_poly_log_size: u32,
+    composition_commitment_log_size: u32,
+    first_fri_layer_log_size: u32,
+}
+
+/// One PcsConfig and its security estimate: `security_bits` is upstream
+/// `PcsConfig::security_bits()`, i.e. `pow_bits + log_blowup_factor * n_queries`. `classification`
+/// is `valid`, `no_queries` (accepted by `FriConfig::new` but with no FRI soundness) or
+/// `rejected_fri_config` (`FriConfig::new` panics; the estimate is still taken on the raw
+/// fields). `target_bits_rows` carry `target_bits` and whether the config reaches it: each
+/// target/blowup/pow combination lists the minimal query count and the one below it. `cli_grid`
+/// rows span the ranges the interop CLI accepts (pow bits 0-30, log blowup 1-4, 1-70 queries, log
+/// last layer degree bound 0-4) so both runtimes can be gated on the same estimate.
+#[derive(Debug, Clone, Serialize)]
+struct ConfigSecurityVector {
+    case: &'static str,
+    pow_bits: u32,
+    log_blowup_factor: u32,
+    log_last_layer_degree_bound: u32,
+    n_queries: usize,
+    security_bits: u32,
+    classification: &'static str,
+    target_bits: Option<u32>,
+    meets_target: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -316,14 +1228,36 @@ struct VcsProverVector {
     column_witness: Vec<u32>,
 }
 
+/// `layer_queries[i]` is the parent-layer query list after folding `i + 1` layers above the
+/// leaves (ending with `[0]` at the root); a layer consumes one witness hash per query without
+/// its sibling.
 #[derive(Debug, Clone, Serialize)]
 struct VcsLiftedProverVector {
+    case: String,
     root: [u8; 32],
     column_log_sizes: Vec<u32>,
     columns: Vec<Vec<u32>>,
     query_positions: Vec<usize>,
     queried_values: Vec<Vec<u32>>,
     hash_witness: Vec<[u8; 32]>,
+    layer_queries: Vec<Vec<usize>>,
+}
+
+/// A lifted commitment whose cells are sentinels `(column + 1) << 16 | row`, so every value names
+/// its source. `queried_values[c][q]` is what the VCS decommits for column `c` at
+/// `query_positions[q]`, read from row `source_rows[c][q]` (columns smaller than the query domain
+/// map adjacent queries onto the same rows). `query_rows[q]` is the reshape the quotients code
+/// consumes: one value per column, in flattened column order.
+#[derive(Debug, Clone, Serialize)]
+struct QueriedRowsReshapeVector {
+    root: [u8; 32],
+    column_log_sizes: Vec<u32>,
+    query_log_size: u32,
+    query_positions: Vec<usize>,
+    hash_witness: Vec<[u8; 32]>,
+    queried_values: Vec<Vec<u32>>,
+    source_rows: Vec<Vec<usize>>,
+    query_rows: Vec<Vec<u32>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -337,6 +1271,17 @@ struct VcsLiftedVerifierVector {
     expected: String,
 }
 
+/// What the lifted verifier does with the probes aimed at one classic `variant`. `status` is
+/// `reachable` when some probe's `expected` is the variant itself and `unreachable_variant`
+/// otherwise; the probes are kept either way so the actual outcome (another error, `ok`, or
+/// `Panic`) is pinned too.
+#[derive(Debug, Clone, Serialize)]
+struct VcsLiftedErrorVariantVector {
+    variant: String,
+    status: String,
+    probes: Vec<VcsLiftedVerifierVector>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct ExampleStateMachineTraceVector {
     log_size: u32,
@@ -372,6 +1317,27 @@ struct ExampleStateMachineLookupDrawVector {
     alpha: [u32; 4],
 }
 
+/// A channel seeded like `example_state_machine_lookup_draw`, then 2-3 rounds of lookup-element
+/// draws separated by `mix_felts` of claimed-sum-like felts, the transcript shape of the state
+/// machine and plonk components. A round draws either `z` and `alpha` with two `draw_secure_felt`
+/// calls or 3-6 felts with one `draw_secure_felts` call.
+#[derive(Debug, Clone, Serialize)]
+struct LookupDrawV2Vector {
+    mix_u64: u64,
+    mix_u32s: Vec<u32>,
+    digest_before: [u8; 32],
+    steps: Vec<LookupDrawStepVector>,
+}
+
+/// `felts` are the mixed felts for `mix_felts` and the drawn ones for `draw_secure_felt` and
+/// `draw_secure_felts`.
+#[derive(Debug, Clone, Serialize)]
+struct LookupDrawStepVector {
+    op: String,
+    felts: Vec<[u32; 4]>,
+    digest_after: [u8; 32],
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct ExampleStateMachineStatementVector {
     log_n_rows: u32,
@@ -384,16 +1350,40 @@ struct ExampleStateMachineStatementVector {
     y_axis_claimed_sum: [u32; 4],
 }
 
+/// One component of the two-component state machine: `2^log_size` rows starting at
+/// `initial_state`, incrementing `state[inc_index]` each row.
 #[derive(Debug, Clone, Serialize)]
-struct ExampleXorIsFirstVector {
+struct ExampleStateMachineComponentTrace {
     log_size: u32,
-    values: Vec<u32>,
+    inc_index: usize,
+    initial_state: [u32; 2],
+    final_state: [u32; 2],
+    columns: Vec<Vec<u32>>,
+    claimed_sum: [u32; 4],
 }
 
 #[derive(Debug, Clone, Serialize)]
-struct ExampleXorIsStepWithOffsetVector {
-    log_size: u32,
-    log_step: u32,
+struct ExampleStateMachineComponentsVector {
+    axis_order: &'static str,
+    log_n_rows: u32,
+    initial_state: [u32; 2],
+    intermediate_state: [u32; 2],
+    final_state: [u32; 2],
+    z: [u32; 4],
+    alpha: [u32; 4],
+    components: Vec<ExampleStateMachineComponentTrace>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExampleXorIsFirstVector {
+    log_size: u32,
+    values: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ExampleXorIsStepWithOffsetVector {
+    log_size: u32,
+    log_step: u32,
     offset: usize,
     values: Vec<u32>,
 }
@@ -403,6 +1393,14 @@ struct ExampleWideFibonacciTraceVector {
     log_n_rows: u32,
     sequence_len: u32,
     columns: Vec<Vec<u32>>,
+    /// Per-row starting pair, in the same (bit-reversed) order as `columns`; `None` for the
+    /// example's fixed `(1, row)` seeds.
+    seed_a: Option<Vec<u32>>,
+    seed_b: Option<Vec<u32>>,
+    /// `constraints_satisfied[c][i]` is whether `columns[c + 2][i]` equals
+    /// `columns[c][i]^2 + columns[c + 1][i]^2`; `None` for the fixed-seed vectors, which always
+    /// satisfy it.
+    constraints_satisfied: Option<Vec<Vec<bool>>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -412,6 +1410,31 @@ struct ExamplePlonkTraceVector {
     main: Vec<Vec<u32>>,
 }
 
+/// The interop Poseidon example's trace, columns in natural row order.
+#[derive(Debug, Clone, Serialize)]
+struct ExamplePoseidonTraceVector {
+    log_n_rows: u32,
+    columns: Vec<Vec<u32>>,
+}
+
+/// Each round primitive applied on its own to the same `input` state, so a trace divergence can
+/// be pinned to one primitive. `external_round` indexes the full-round constants and
+/// `internal_round` the partial-round constant (added to `state[0]` only).
+#[derive(Debug, Clone, Serialize)]
+struct ExamplePoseidonRoundsVector {
+    external_round: usize,
+    internal_round: usize,
+    input: Vec<u32>,
+    external_round_constants: Vec<u32>,
+    internal_round_constant: u32,
+    after_add_external_round_constants: Vec<u32>,
+    after_add_internal_round_constant: Vec<u32>,
+    after_apply_m4: Vec<u32>,
+    after_external_round_matrix: Vec<u32>,
+    after_internal_round_matrix: Vec<u32>,
+    after_pow5: Vec<u32>,
+}
+
 #[derive(Clone)]
 struct VcsBaseCase {
     root: Blake2sHash,
@@ -430,287 +1453,3391 @@ struct VcsLiftedBaseCase {
     query_positions: Vec<usize>,
     queried_values: Vec<Vec<M31>>,
     decommitment: MerkleDecommitmentLifted<LiftedMerkleHasher>,
+    layer_queries: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct KnownAnswerFieldVector {
+    name: &'static str,
+    op: &'static str,
+    inputs: Vec<Vec<u32>>,
+    expected: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct KnownAnswerMerkleVector {
+    name: &'static str,
+    leaves: Vec<u32>,
+    root: [u8; 32],
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct KnownAnswerVectors {
+    field_ops: Vec<KnownAnswerFieldVector>,
+    merkle: Vec<KnownAnswerMerkleVector>,
+}
+
+/// `--differential` output: two small vector sets from `DIFFERENTIAL_SEEDS` plus the relations a
+/// consumer must find true in both.
+#[derive(Debug, Clone, Serialize)]
+struct DifferentialVectors {
+    upstream_commit: &'static str,
+    seeds: [u64; 2],
+    sets: [FieldVectors; 2],
+    differential_checks: Vec<DifferentialCheck>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DifferentialCheck {
+    family: &'static str,
+    relation: &'static str,
+    description: &'static str,
 }
 
 #[derive(Debug, Clone, Serialize)]
 struct FieldVectors {
     meta: Meta,
-    m31: Vec<M31Vector>,
-    cm31: Vec<CM31Vector>,
-    qm31: Vec<QM31Vector>,
-    circle_m31: Vec<CircleM31Vector>,
-    fft_m31: Vec<FftM31Vector>,
-    blake3: Vec<Blake3Vector>,
-    pcs_quotients: Vec<PcsQuotientsVector>,
-    pcs_preprocessed_queries: Vec<PcsPreprocessedQueryVector>,
-    fri_folds: Vec<FriFoldVector>,
-    fri_decommit: Vec<FriDecommitVector>,
-    fri_layer_decommit: Vec<FriLayerDecommitVector>,
-    proof_extract_oods: Vec<ProofExtractOodsVector>,
-    proof_sizes: Vec<ProofSizeVector>,
-    prover_line: Vec<ProverLineVector>,
-    vcs_verifier: Vec<VcsVerifierVector>,
-    vcs_prover: Vec<VcsProverVector>,
-    vcs_lifted_verifier: Vec<VcsLiftedVerifierVector>,
-    vcs_lifted_prover: Vec<VcsLiftedProverVector>,
-    example_state_machine_trace: Vec<ExampleStateMachineTraceVector>,
-    example_state_machine_transitions: Vec<ExampleStateMachineTransitionVector>,
-    example_state_machine_claimed_sum: Vec<ExampleStateMachineClaimedSumVector>,
-    example_state_machine_lookup_draw: Vec<ExampleStateMachineLookupDrawVector>,
-    example_state_machine_statement: Vec<ExampleStateMachineStatementVector>,
-    example_xor_is_first: Vec<ExampleXorIsFirstVector>,
-    example_xor_is_step_with_offset: Vec<ExampleXorIsStepWithOffsetVector>,
-    example_wide_fibonacci_trace: Vec<ExampleWideFibonacciTraceVector>,
-    example_plonk_trace: Vec<ExamplePlonkTraceVector>,
-}
-
-fn main() {
-    let (out_path, sample_count) = parse_args();
-    let mut state = VECTOR_SEED;
-    let vectors = generate_vectors(&mut state, sample_count);
-
-    if let Some(parent) = out_path.parent() {
-        fs::create_dir_all(parent).expect("failed to create vector output directory");
-    }
-
-    let serialized = serde_json::to_string_pretty(&vectors).expect("failed to serialize vectors");
-    fs::write(&out_path, serialized).expect("failed to write vectors");
-}
-
-fn parse_args() -> (PathBuf, usize) {
-    let mut out = PathBuf::from("vectors/fields.json");
-    let mut sample_count = DEFAULT_COUNT;
-    let mut args = env::args().skip(1);
+    #[serde(skip_serializing_if = "Option::is_none")]
+    families: Option<BTreeMap<&'static str, FamilyMeta>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_taxonomy: Option<BTreeMap<&'static str, ErrorTaxonomyEntry>>,
+    generation_stats: Vec<FamilyGenerationStats>,
+    known_answer: KnownAnswerVectors,
+    m31: Family<M31Vector>,
+    cm31: Family<CM31Vector>,
+    qm31: Family<QM31Vector>,
+    qm31_structure: Family<QM31StructureVector>,
+    secure_column: Family<SecureColumnVector>,
+    circle_m31: Family<CircleM31Vector>,
+    circle_scalar_mul: Family<CircleScalarMulVector>,
+    fft_m31: Family<FftM31Vector>,
+    fft_edge: Family<FftEdgeVector>,
+    blake3: Family<Blake3Vector>,
+    blake2s: Family<Blake2sVector>,
+    channel_mix_root: Family<ChannelMixRootVector>,
+    oods_draw: Family<OodsDrawVector>,
+    accumulation: Family<AccumulationVector>,
+    merkle_avalanche: Family<MerkleAvalancheVector>,
+    lookup_draw_v2: Family<LookupDrawV2Vector>,
+    field_boundaries: Family<FieldBoundaryVector>,
+    pcs_quotients: Family<PcsQuotientsVector>,
+    pcs_quotients_shared_points: Family<PcsQuotientsVector>,
+    denominator_inverses: Family<DenominatorInversesVector>,
+    samples_with_randomness: Family<SamplesWithRandomnessVector>,
+    pcs_preprocessed_queries: Family<PcsPreprocessedQueryVector>,
+    fri_answers_errors: Family<FriAnswersErrorVector>,
+    fri_folds: Family<FriFoldVector>,
+    fri_layer_count: Family<FriLayerCountVector>,
+    fri_fold_chain: Family<FriFoldChainVector>,
+    fri_fold_ordering: Family<FriFoldOrderingVector>,
+    fri_last_layer: Family<FriLastLayerVector>,
+    line_poly_construction: Family<LinePolyConstructionVector>,
+    fri_decommit: Family<FriDecommitVector>,
+    fri_layer_decommit: Family<FriLayerDecommitVector>,
+    fri_layer_chain_decommit: Family<FriLayerChainDecommitVector>,
+    fri_first_layer_decommit: Family<FriFirstLayerDecommitVector>,
+    proof_extract_oods: Family<ProofExtractOodsVector>,
+    composition_sampled_order: Family<CompositionSampledOrderVector>,
+    blowup_sizing: Family<BlowupSizingVector>,
+    config_security: Family<ConfigSecurityVector>,
+    proof_sizes: Family<ProofSizeVector>,
+    prover_line: Family<ProverLineVector>,
+    vcs_verifier: Family<VcsVerifierVector>,
+    vcs_prover: Family<VcsProverVector>,
+    vcs_lifted_verifier: Family<VcsLiftedVerifierVector>,
+    vcs_lifted_error_variants: Family<VcsLiftedErrorVariantVector>,
+    vcs_lifted_prover: Family<VcsLiftedProverVector>,
+    queried_rows_reshape: Family<QueriedRowsReshapeVector>,
+    pcs_commit: Family<PcsCommitVector>,
+    e2e_tree_shapes: Family<E2eTreeShapesVector>,
+    example_state_machine_trace: Family<ExampleStateMachineTraceVector>,
+    example_state_machine_transitions: Family<ExampleStateMachineTransitionVector>,
+    example_state_machine_claimed_sum: Family<ExampleStateMachineClaimedSumVector>,
+    example_state_machine_lookup_draw: Family<ExampleStateMachineLookupDrawVector>,
+    example_state_machine_statement: Family<ExampleStateMachineStatementVector>,
+    example_state_machine_components: Family<ExampleStateMachineComponentsVector>,
+    example_xor_is_first: Family<ExampleXorIsFirstVector>,
+    example_xor_is_step_with_offset: Family<ExampleXorIsStepWithOffsetVector>,
+    example_wide_fibonacci_trace: Family<ExampleWideFibonacciTraceVector>,
+    example_plonk_trace: Family<ExamplePlonkTraceVector>,
+    example_poseidon_trace: Family<ExamplePoseidonTraceVector>,
+    example_poseidon_rounds: Family<ExamplePoseidonRoundsVector>,
+}
+
+/// One family's entries. Serializes as a plain array, or, once ids are assigned, with each entry's
+/// `id` ahead of its own fields.
+#[derive(Debug, Clone)]
+struct Family<T> {
+    entries: Vec<T>,
+    ids: Option<Vec<String>>,
+}
+
+impl<T> std::ops::Deref for Family<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.entries
+    }
+}
+
+impl<T: Serialize> Serialize for Family<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct IdentifiedEntry<'a, T> {
+            id: &'a str,
+            #[serde(flatten)]
+            entry: &'a T,
+        }
+
+        match &self.ids {
+            Some(ids) => serializer.collect_seq(
+                ids.iter()
+                    .zip(&self.entries)
+                    .map(|(id, entry)| IdentifiedEntry { id, entry }),
+            ),
+            None => self.entries.serialize(serializer),
+        }
+    }
+}
+
+/// Hands out the per-entry `id`s of one vector set: the first 16 hex digits of a Blake2s hash over
+/// the family name, the entry's JSON and how many identical entries precede it in the family.
+/// An id therefore names the same inputs and outputs whatever `--count` or seed produced them,
+/// and regeneration never reuses one for a different entry. Panics if two entries collide.
+struct VectorIds {
+    enabled: bool,
+    seen: HashSet<String>,
+}
+
+impl VectorIds {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            seen: HashSet::new(),
+        }
+    }
+
+    fn family<T: Serialize>(&mut self, family: &str, entries: Vec<T>) -> Family<T> {
+        if !self.enabled {
+            return Family { entries, ids: None };
+        }
+        let mut occurrences = BTreeMap::<Vec<u8>, u32>::new();
+        let ids = entries
+            .iter()
+            .map(|entry| {
+                let json = serde_json::to_vec(entry).expect("vector entries serialize to JSON");
+                let occurrence = occurrences.entry(json.clone()).or_default();
+                let mut data = Vec::with_capacity(family.len() + json.len() + 5);
+                data.extend_from_slice(family.as_bytes());
+                data.push(0);
+                data.extend_from_slice(&occurrence.to_le_bytes());
+                data.extend_from_slice(&json);
+                *occurrence += 1;
+                let id = Blake2sHasher::hash(&data).0[..8]
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>();
+                assert!(
+                    self.seen.insert(id.clone()),
+                    "vector id {id} of {family} collides with an earlier entry"
+                );
+                id
+            })
+            .collect();
+        Family {
+            entries,
+            ids: Some(ids),
+        }
+    }
+}
+
+/// One line per family for `--describe`, with the JSON field names of its entries in
+/// serialization order: the schema-5 `id` first, then the struct's fields (`known_answer` is a
+/// single object and has no `id`). Keep in step with the structs above;
+/// `tests/describe_families.rs` checks both against a generated file.
+const FAMILY_DESCRIPTIONS: [(&str, &str, &[&str]); 60] = [
+    (
+        "known_answer",
+        "Hand-computed field ops, a butterfly pair and 4-leaf Merkle roots, each checked against upstream.",
+        &["field_ops", "merkle"],
+    ),
+    (
+        "m31",
+        "Random M31 operand pairs with add, sub, mul, inverse and division results.",
+        &["id", "a", "b", "add", "sub", "mul", "inv_a", "div_ab"],
+    ),
+    (
+        "cm31",
+        "Random CM31 operand pairs with add, sub, mul, inverse and division results.",
+        &["id", "a", "b", "add", "sub", "mul", "inv_a", "div_ab"],
+    ),
+    (
+        "qm31",
+        "Random QM31 operand pairs with add, sub, mul, inverse and division results.",
+        &["id", "a", "b", "add", "sub", "mul", "inv_a", "div_ab"],
+    ),
+    (
+        "qm31_structure",
+        "QM31 conjugation, M31-array and partial-evaluation round trips for edge and random values.",
+        &["id", "case", "value", "complex_conjugate", "m31_array", "from_m31_array", "partial_evals", "from_partial_evals"],
+    ),
+    (
+        "secure_column",
+        "QM31 columns split into four M31 coordinate columns and rebuilt, with per-row lifted leaf hashes.",
+        &["id", "case", "column", "coordinates", "reconstructed", "leaf_hashes"],
+    ),
+    (
+        "circle_m31",
+        "Points on the M31 circle from scalar multiples of the generator, with add, sub, double and conjugate.",
+        &["id", "a_scalar", "b_scalar", "log_order_a", "a", "b", "add", "sub", "double_a", "conjugate_a"],
+    ),
+    (
+        "circle_scalar_mul",
+        "Generator scalar multiplication on the M31 and secure-field circles for edge and full-width u128 scalars.",
+        &["id", "generator", "case", "scalar", "x", "y", "log_order"],
+    ),
+    (
+        "fft_m31",
+        "M31 butterfly and inverse butterfly outputs for random operands and twiddles.",
+        &["id", "a", "b", "twid", "butterfly", "ibutterfly"],
+    ),
+    (
+        "fft_edge",
+        "Butterflies at zero, one and P - 1 twiddles and on zero operands, marking inverses upstream cannot take.",
+        &["id", "case", "a", "b", "twid", "butterfly", "itwid", "ibutterfly", "ibutterfly_with_twid", "undefined"],
+    ),
+    (
+        "blake3",
+        "Blake3 digests of random data and of two concatenated child hashes.",
+        &["id", "data", "hash", "left", "right", "concat_hash"],
+    ),
+    (
+        "blake2s",
+        "Blake2s digests plus classic Merkle node hashes and lifted leaf-hasher updates.",
+        &["id", "data", "hash", "node_values", "left", "right", "hash_node_with_children", "hash_node_leaf", "lifted_leaf_updates", "lifted_leaf_digests"],
+    ),
+    (
+        "channel_mix_root",
+        "Blake2s channel digests around mix_root for patterned roots, with the digests of the wrong mix calls.",
+        &["id", "root_pattern", "prefix_mix_u64", "prefix_mix_u32s", "digest_before", "root", "digest_after", "draw_secure_felt", "wrong_variant_digests"],
+    ),
+    (
+        "oods_draw",
+        "OODS point draws (get_random_point) from a channel prepared by a sequence of mixes.",
+        &["id", "mixes", "digest_before", "draws"],
+    ),
+    (
+        "accumulation",
+        "Point and domain evaluation accumulators driven by one random coefficient.",
+        &["id", "random_coeff", "evaluations", "accumulation", "max_log_size", "total_columns", "requests", "calls"],
+    ),
+    (
+        "merkle_avalanche",
+        "Roots of a small commitment recommitted with single-bit perturbations of one cell.",
+        &["id", "scheme", "column_log_sizes", "columns", "root", "perturbations"],
+    ),
+    (
+        "lookup_draw_v2",
+        "Multi-round lookup-element draws separated by claimed-sum mixes, as in the example components.",
+        &["id", "mix_u64", "mix_u32s", "digest_before", "steps"],
+    ),
+    (
+        "field_boundaries",
+        "Every ordered pair of fixed M31/CM31/QM31 edge operands, with undefined ops named.",
+        &["id", "field", "a", "b", "add", "sub", "mul", "inv_a", "div_ab", "undefined"],
+    ),
+    (
+        "pcs_quotients",
+        "Every intermediate of PCS quotient computation (batches, line coeffs, denominators, row quotients) up to fri_answers.",
+        &["id", "lifting_log_size", "column_log_sizes", "samples", "random_coeff", "query_positions", "domain_points", "queried_values", "samples_with_randomness", "sample_batches", "line_coeffs", "denominator_inverses", "partial_numerators", "row_quotients", "fri_answers"],
+    ),
+    (
+        "pcs_quotients_shared_points",
+        "pcs_quotients where most columns share one OODS point, so their samples merge into one batch.",
+        &["id", "lifting_log_size", "column_log_sizes", "samples", "random_coeff", "query_positions", "domain_points", "queried_values", "samples_with_randomness", "sample_batches", "line_coeffs", "denominator_inverses", "partial_numerators", "row_quotients", "fri_answers"],
+    ),
+    (
+        "denominator_inverses",
+        "Quotient denominators and their batch inverses, including near-degenerate sample points.",
+        &["id", "case", "sample_points", "u_perturbations", "domain_log_size", "domain_index", "domain_point", "denominators", "expected", "inverses"],
+    ),
+    (
+        "samples_with_randomness",
+        "build_samples_with_randomness_and_periodicity output for mixed column sizes under one lifting size.",
+        &["id", "lifting_log_size", "column_log_sizes", "samples", "random_coeff", "samples_with_randomness"],
+    ),
+    (
+        "pcs_preprocessed_queries",
+        "Query positions mapped onto the preprocessed tree by prepare_preprocessed_query_positions.",
+        &["id", "case", "query_positions", "max_log_size", "pp_max_log_size", "expected"],
+    ),
+    (
+        "fri_answers_errors",
+        "Malformed fri_answers inputs and the upstream error variant (or Panic) each produces.",
+        &["id", "case", "lifting_log_size", "column_log_sizes", "samples", "random_coeff", "query_positions", "queried_values", "expected"],
+    ),
+    (
+        "fri_folds",
+        "fold_line and fold_circle_into_line outputs for random evaluations and alpha.",
+        &["id", "line_log_size", "line_eval", "alpha", "fold_line_values", "circle_log_size", "circle_eval", "fold_circle_values"],
+    ),
+    (
+        "fri_layer_count",
+        "FRI layer counts and domain sizes for each column size, blowup and last-layer bound.",
+        &["id", "column_log_size", "log_blowup", "log_last_layer_degree_bound", "valid", "first_layer_domain_log_size", "n_inner_layers", "last_layer_domain_log_size", "last_layer_poly_len", "source"],
+    ),
+    (
+        "fri_fold_chain",
+        "A circle evaluation folded layer by layer down to the last-layer polynomial.",
+        &["id", "circle_log_size", "circle_eval", "last_layer_log_size", "alphas", "layers", "last_layer_coeffs_bit_reversed", "last_layer_coeffs_ordered"],
+    ),
+    (
+        "fri_fold_ordering",
+        "Folds fed bit-reversed and natural-order input, pinning the required input order.",
+        &["id", "fold", "input_order", "log_size", "domain_initial_index", "domain_step_size", "alpha", "evaluations_bit_reversed", "evaluations_natural", "folded_bit_reversed", "folded_natural", "output_permutation", "misordered_fold"],
+    ),
+    (
+        "fri_last_layer",
+        "Last-layer polynomials evaluated at queries, with over-long polynomials rejected.",
+        &["id", "case", "log_blowup_factor", "log_last_layer_degree_bound", "last_layer_domain_log_size", "coeffs", "query_positions", "query_x", "evaluations", "expected"],
+    ),
+    (
+        "line_poly_construction",
+        "LinePoly::new on coefficient counts around powers of two, with an evaluation when accepted.",
+        &["id", "coeff_count", "coeffs", "expected", "log_size", "eval_point", "evaluation"],
+    ),
+    (
+        "fri_decommit",
+        "Positions, witness and value map a FRI layer decommitment produces, plus rejected inputs.",
+        &["id", "case", "fold_step", "column", "query_positions", "decommitment_positions", "witness_evals", "value_map_positions", "value_map_values", "expected"],
+    ),
+    (
+        "fri_layer_decommit",
+        "fri_decommit plus the lifted Merkle commitment and hash witness of the layer column.",
+        &["id", "case", "fold_step", "column", "query_positions", "commitment", "decommitment_positions", "fri_witness", "hash_witness", "value_map_positions", "value_map_values", "expected"],
+    ),
+    (
+        "fri_layer_chain_decommit",
+        "Consecutive FRI layer decommitments for one query set, folding queries between layers.",
+        &["id", "query_positions", "layers"],
+    ),
+    (
+        "fri_first_layer_decommit",
+        "Secure columns of mixed log sizes committed as one lifted first FRI layer and decommitted at shared max-size queries.",
+        &["id", "log_sizes", "columns", "query_positions", "column_query_positions", "commitment", "decommitment_positions", "fri_witness", "hash_witness"],
+    ),
+    (
+        "proof_extract_oods",
+        "extract_composition_oods_eval on composition masks, valid and malformed.",
+        &["id", "case", "composition_log_size", "oods_point", "has_composition_tree", "composition_values", "expected", "expected_error"],
+    ),
+    (
+        "composition_sampled_order",
+        "A proof-shaped sampled_values TreeVec and the cells the OODS composition check reads, in order.",
+        &["id", "composition_log_size", "oods_point", "sampled_values", "cells", "composition_cells", "composition_oods_eval"],
+    ),
+    (
+        "blowup_sizing",
+        "Commitment, FRI and twiddle domain sizes for a trace size, degree increment and blowup.",
+        &["id", "trace_log_size", "constraint_log_degree_increment", "log_blowup", "max_constraint_log_degree_bound", "twiddle_log_size", "trace_commitment_log_size", "composition_poly_log_size", "composition_commitment_log_size", "first_fri_layer_log_size"],
+    ),
+    (
+        "config_security",
+        "PcsConfig security estimates and the minimal query counts reaching target bits.",
+        &["id", "case", "pow_bits", "log_blowup_factor", "log_last_layer_degree_bound", "n_queries", "security_bits", "classification", "target_bits", "meets_target"],
+    ),
+    (
+        "proof_sizes",
+        "Random proof-shaped values with their expected size breakdown.",
+        &["id", "commitments", "sampled_values", "decommitments", "queried_values", "proof_of_work", "first_layer_witness", "first_layer_decommitment", "first_layer_commitment", "inner_layers", "last_layer_poly", "expected_breakdown"],
+    ),
+    (
+        "prover_line",
+        "Line evaluations interpolated into coefficients in bit-reversed and natural order.",
+        &["id", "line_log_size", "values", "coeffs_bit_reversed", "coeffs_ordered"],
+    ),
+    (
+        "vcs_verifier",
+        "Classic Merkle decommitments, valid and tampered, with the verifier outcome.",
+        &["id", "case", "root", "column_log_sizes", "queries_per_log_size", "queried_values", "hash_witness", "column_witness", "expected"],
+    ),
+    (
+        "vcs_prover",
+        "Classic Merkle commitments and the decommitment the prover produces for random queries.",
+        &["id", "root", "column_log_sizes", "columns", "queries_per_log_size", "queried_values", "hash_witness", "column_witness"],
+    ),
+    (
+        "vcs_lifted_verifier",
+        "Lifted Merkle decommitments, valid and tampered, with the verifier outcome.",
+        &["id", "case", "root", "column_log_sizes", "query_positions", "queried_values", "hash_witness", "expected"],
+    ),
+    (
+        "vcs_lifted_error_variants",
+        "One entry per classic Merkle error variant: what the lifted verifier does with probes aimed at it.",
+        &["id", "variant", "status", "probes"],
+    ),
+    (
+        "vcs_lifted_prover",
+        "Lifted Merkle commitments, decommitments and per-layer query folding.",
+        &["id", "case", "root", "column_log_sizes", "columns", "query_positions", "queried_values", "hash_witness", "layer_queries"],
+    ),
+    (
+        "queried_rows_reshape",
+        "Lifted decommitment values reshaped into the per-query rows the quotients code consumes.",
+        &["id", "root", "column_log_sizes", "query_log_size", "query_positions", "hash_witness", "queried_values", "source_rows", "query_rows"],
+    ),
+    (
+        "pcs_commit",
+        "Roots CommitmentSchemeProver produces for deterministic trees committed in sequence.",
+        &["id", "case", "log_blowup_factor", "trees"],
+    ),
+    (
+        "e2e_tree_shapes",
+        "Tree shapes of a real state-machine StarkProof, checked with upstream verify.",
+        &["id", "log_size", "initial_state", "log_blowup_factor", "n_queries", "trees"],
+    ),
+    (
+        "example_state_machine_trace",
+        "State-machine example trace columns for one increment axis.",
+        &["id", "log_size", "initial_state", "inc_index", "columns"],
+    ),
+    (
+        "example_state_machine_transitions",
+        "Intermediate and final states of the state-machine example.",
+        &["id", "log_n_rows", "initial_state", "intermediate_state", "final_state"],
+    ),
+    (
+        "example_state_machine_claimed_sum",
+        "State-machine logup claimed sums, also as a telescoping claim.",
+        &["id", "log_size", "initial_state", "inc_index", "z", "alpha", "claimed_sum", "telescoping_claim"],
+    ),
+    (
+        "example_state_machine_lookup_draw",
+        "Lookup elements the state-machine example draws after its statement mixes.",
+        &["id", "mix_u64", "mix_u32s", "z", "alpha"],
+    ),
+    (
+        "example_state_machine_statement",
+        "A full state-machine statement: states, lookup elements and both claimed sums.",
+        &["id", "log_n_rows", "initial_state", "z", "alpha", "intermediate_state", "final_state", "x_axis_claimed_sum", "y_axis_claimed_sum"],
+    ),
+    (
+        "example_state_machine_components",
+        "The state-machine components in either axis order with their claimed sums.",
+        &["id", "axis_order", "log_n_rows", "initial_state", "intermediate_state", "final_state", "z", "alpha", "components"],
+    ),
+    (
+        "example_xor_is_first",
+        "The xor example's is_first preprocessed column.",
+        &["id", "log_size", "values"],
+    ),
+    (
+        "example_xor_is_step_with_offset",
+        "The xor example's is_step_with_offset preprocessed column.",
+        &["id", "log_size", "log_step", "offset", "values"],
+    ),
+    (
+        "example_wide_fibonacci_trace",
+        "Wide-Fibonacci example trace columns and whether the constraints hold.",
+        &["id", "log_n_rows", "sequence_len", "columns", "seed_a", "seed_b", "constraints_satisfied"],
+    ),
+    (
+        "example_plonk_trace",
+        "Plonk example preprocessed and main trace columns.",
+        &["id", "log_n_rows", "preprocessed", "main"],
+    ),
+    (
+        "example_poseidon_trace",
+        "Poseidon example trace columns in natural row order.",
+        &["id", "log_n_rows", "columns"],
+    ),
+    (
+        "example_poseidon_rounds",
+        "Each Poseidon round primitive applied on its own to one input state.",
+        &["id", "external_round", "internal_round", "input", "external_round_constants", "internal_round_constant", "after_add_external_round_constants", "after_add_internal_round_constant", "after_apply_m4", "after_external_round_matrix", "after_internal_round_matrix", "after_pow5"],
+    ),
+];
+
+/// Stable process exit codes; CI wrappers key off these.
+const EXIT_DIFF_CHANGED: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+const EXIT_IO: u8 = 3;
+const EXIT_GENERATION: u8 = 4;
+/// Top-level keys of a vector file that describe the run rather than hold vectors; `diff` skips
+/// them.
+const NON_FAMILY_KEYS: [&str; 4] = ["meta", "families", "error_taxonomy", "generation_stats"];
+/// Modified entries listed per family by `diff` before it only counts the rest.
+const DIFF_MODIFIED_SHOWN: usize = 5;
+
+/// `--help` family listing: name, default count, and a note on how the count is made up.
+const FAMILY_HELP: [(&str, usize, &str); 60] = [
+    ("known_answer", 0, "fixed table"),
+    ("m31", DEFAULT_COUNT, "--count"),
+    ("cm31", DEFAULT_COUNT, "--count"),
+    ("qm31", DEFAULT_COUNT, "--count"),
+    (
+        "qm31_structure",
+        QM31_STRUCTURE_VECTOR_COUNT + QM31_STRUCTURE_SELF_CONJUGATE_COUNT,
+        "",
+    ),
+    (
+        "secure_column",
+        SECURE_COLUMN_CASES.len(),
+        "single-row, odd and power-of-two lengths",
+    ),
+    ("circle_m31", DEFAULT_COUNT, "--count"),
+    (
+        "circle_scalar_mul",
+        CIRCLE_SCALAR_MUL_VECTOR_COUNT,
+        "edge and random scalars",
+    ),
+    ("fft_m31", DEFAULT_COUNT, "--count"),
+    ("fft_edge", FFT_EDGE_CASES.len(), "fixed"),
+    ("blake3", BLAKE3_VECTOR_COUNT, ""),
+    ("blake2s", BLAKE2S_VECTOR_COUNT, ""),
+    ("channel_mix_root", CHANNEL_MIX_ROOT_VECTOR_COUNT, ""),
+    ("oods_draw", OODS_DRAW_VECTOR_COUNT, ""),
+    ("accumulation", ACCUMULATION_VECTOR_COUNT, ""),
+    ("merkle_avalanche", MERKLE_AVALANCHE_VECTOR_COUNT, ""),
+    ("lookup_draw_v2", LOOKUP_DRAW_V2_VECTOR_COUNT, ""),
+    ("field_boundaries", FIELD_BOUNDARIES_VECTOR_COUNT, "fixed"),
+    ("pcs_quotients", PCS_VECTOR_COUNT, ""),
+    (
+        "pcs_quotients_shared_points",
+        PCS_SHARED_POINT_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "denominator_inverses",
+        DENOMINATOR_INVERSES_RANDOM_COUNT + DENOMINATOR_INVERSES_NEAR_DEGENERATE_COUNT + 1,
+        "",
+    ),
+    (
+        "samples_with_randomness",
+        SAMPLES_WITH_RANDOMNESS_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "pcs_preprocessed_queries",
+        PCS_PREPROCESSED_QUERY_VECTOR_COUNT,
+        "plus edge cases",
+    ),
+    ("fri_answers_errors", FRI_ANSWERS_ERROR_VECTOR_COUNT, ""),
+    ("fri_folds", FRI_FOLD_VECTOR_COUNT, ""),
+    ("fri_layer_count", 0, "fixed table"),
+    ("fri_fold_chain", FRI_FOLD_CHAIN_VECTOR_COUNT, ""),
+    ("fri_fold_ordering", FRI_FOLD_ORDERING_VECTOR_COUNT, ""),
+    ("fri_last_layer", FRI_LAST_LAYER_VECTOR_COUNT, ""),
+    ("line_poly_construction", 0, "fixed table"),
+    ("fri_decommit", FRI_DECOMMIT_VECTOR_COUNT, ""),
+    ("fri_layer_decommit", FRI_LAYER_DECOMMIT_VECTOR_COUNT, ""),
+    (
+        "fri_layer_chain_decommit",
+        FRI_LAYER_CHAIN_DECOMMIT_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "fri_first_layer_decommit",
+        FRI_FIRST_LAYER_DECOMMIT_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "proof_extract_oods",
+        PROOF_OODS_VECTOR_COUNT,
+        "plus error cases",
+    ),
+    (
+        "composition_sampled_order",
+        COMPOSITION_SAMPLED_ORDER_VECTOR_COUNT,
+        "",
+    ),
+    ("blowup_sizing", 56, "fixed"),
+    ("config_security", 0, "fixed table"),
+    ("proof_sizes", PROOF_SIZE_VECTOR_COUNT, "plus edge cases"),
+    ("prover_line", PROVER_LINE_VECTOR_COUNT, ""),
+    (
+        "vcs_verifier",
+        VCS_VERIFIER_VECTOR_COUNT + VCS_SINGLE_ELEMENT_VERIFIER_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "vcs_prover",
+        VCS_PROVER_VECTOR_COUNT + VCS_SINGLE_ELEMENT_PROVER_VECTOR_COUNT,
+        "",
+    ),
+    ("vcs_lifted_verifier", VCS_LIFTED_VERIFIER_VECTOR_COUNT, ""),
+    (
+        "vcs_lifted_error_variants",
+        MERKLE_ERROR_VARIANT_PROBES.len(),
+        "one per variant",
+    ),
+    ("vcs_lifted_prover", VCS_LIFTED_PROVER_VECTOR_COUNT, ""),
+    (
+        "queried_rows_reshape",
+        QUERIED_ROWS_RESHAPE_VECTOR_COUNT,
+        "",
+    ),
+    ("pcs_commit", PCS_COMMIT_VECTOR_COUNT, ""),
+    ("e2e_tree_shapes", E2E_TREE_SHAPES_LOG_SIZES.len(), "fixed"),
+    (
+        "example_state_machine_trace",
+        EXAMPLE_STATE_MACHINE_TRACE_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_state_machine_transitions",
+        EXAMPLE_STATE_MACHINE_TRANSITION_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_state_machine_claimed_sum",
+        EXAMPLE_STATE_MACHINE_CLAIMED_SUM_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_state_machine_lookup_draw",
+        EXAMPLE_STATE_MACHINE_LOOKUP_DRAW_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_state_machine_statement",
+        EXAMPLE_STATE_MACHINE_STATEMENT_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_state_machine_components",
+        EXAMPLE_STATE_MACHINE_COMPONENTS_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_xor_is_first",
+        EXAMPLE_XOR_IS_FIRST_VECTOR_COUNT,
+        "",
+    ),
+    (
+        "example_xor_is_step_with_offset",
+        EXAMPLE_XOR_IS_STEP_WITH_OFFSET_VECTOR_COUNT,
+        "plus boundary cases",
+    ),
+    (
+        "example_wide_fibonacci_trace",
+        2 * EXAMPLE_WIDE_FIBONACCI_TRACE_VECTOR_COUNT,
+        "default and seeded traces",
+    ),
+    ("example_plonk_trace", EXAMPLE_PLONK_TRACE_VECTOR_COUNT, ""),
+    (
+        "example_poseidon_trace",
+        EXAMPLE_POSEIDON_TRACE_LOG_N_ROWS.len(),
+        "fixed",
+    ),
+    (
+        "example_poseidon_rounds",
+        EXAMPLE_POSEIDON_ROUNDS_VECTOR_COUNT,
+        "",
+    ),
+];
+
+/// Bad command-line input; exits with `EXIT_USAGE`.
+#[derive(Debug)]
+struct UsageError(String);
+
+impl std::fmt::Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+struct Args {
+    out: Option<PathBuf>,
+    profile: GenerationProfile,
+    sample_count: Option<usize>,
+    budget_seconds: Option<f64>,
+    schema_version: u32,
+    self_verify: bool,
+    differential: bool,
+    packed: bool,
+    family_seeds: BTreeMap<&'static str, u64>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::from(exit_code(&err))
+        }
+    }
+}
+
+fn exit_code(err: &anyhow::Error) -> u8 {
+    if err.chain().any(|cause| cause.is::<UsageError>()) {
+        EXIT_USAGE
+    } else if err.chain().any(|cause| cause.is::<std::io::Error>()) {
+        EXIT_IO
+    } else {
+        EXIT_GENERATION
+    }
+}
+
+fn run() -> Result<ExitCode> {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("diff") => {
+            args.next();
+            return run_diff(parse_diff_args(args)?);
+        }
+        Some("--list-families") => {
+            args.next();
+            if let Some(arg) = args.next() {
+                return Err(
+                    UsageError(format!("--list-families takes no arguments, got {arg}")).into(),
+                );
+            }
+            print_family_list();
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some("--describe") => {
+            args.next();
+            let (Some(family), None) = (args.next(), args.next()) else {
+                return Err(
+                    UsageError("--describe takes exactly one family name".to_string()).into(),
+                );
+            };
+            describe_family(&family)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        _ => {}
+    }
+    let Some(args) = parse_args(args)? else {
+        print_help();
+        return Ok(ExitCode::SUCCESS);
+    };
+    let (seeds, default_count, default_out) = if args.differential {
+        (
+            DIFFERENTIAL_SEEDS.to_vec(),
+            DIFFERENTIAL_SAMPLE_COUNT,
+            "vectors/fields_differential.json",
+        )
+    } else {
+        (
+            vec![VECTOR_SEED],
+            args.profile.default_count,
+            args.profile.default_out,
+        )
+    };
+    let sample_count = args.sample_count.unwrap_or(default_count);
+    let out = args.out.unwrap_or_else(|| PathBuf::from(default_out));
+
+    let mut sets = Vec::with_capacity(seeds.len());
+    for seed in seeds {
+        // Generator asserts are internal failures; report them with the generation exit code.
+        let vectors = std::panic::catch_unwind(|| {
+            generate_vectors(
+                seed,
+                &args.family_seeds,
+                &args.profile,
+                sample_count,
+                args.budget_seconds,
+                args.schema_version,
+            )
+        })
+        .map_err(|_| anyhow!("vector generation panicked (seed {seed:#x})"))?;
+
+        if args.self_verify {
+            let mismatches = self_verify_vectors(&vectors);
+            if !mismatches.is_empty() {
+                for mismatch in &mismatches {
+                    eprintln!("self-verify mismatch (seed {seed:#x}): {mismatch}");
+                }
+                bail!(
+                    "self-verify failed with {} mismatch(es); vectors not written",
+                    mismatches.len()
+                );
+            }
+        }
+        sets.push(vectors);
+    }
+
+    if let Some(parent) = out.parent() {
+        fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "failed to create vector output directory {}",
+                parent.display()
+            )
+        })?;
+    }
+
+    if args.differential {
+        let sets: [FieldVectors; 2] = sets
+            .try_into()
+            .map_err(|_| anyhow!("differential mode generates exactly two sets"))?;
+        let failed = failed_differential_checks(&sets);
+        if !failed.is_empty() {
+            bail!(
+                "differential relations do not hold: {}; vectors not written",
+                failed.join(", ")
+            );
+        }
+        let differential = DifferentialVectors {
+            upstream_commit: UPSTREAM_COMMIT,
+            seeds: DIFFERENTIAL_SEEDS,
+            sets,
+            differential_checks: DIFFERENTIAL_CHECKS
+                .iter()
+                .map(|&(family, relation, description)| DifferentialCheck {
+                    family,
+                    relation,
+                    description,
+                })
+                .collect(),
+        };
+        write_vectors(&out, &differential, args.packed)?;
+    } else {
+        write_vectors(&out, &sets[0], args.packed)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Plain output is serialized straight into a buffered file, so even the large profile never
+/// holds a `Value` tree or a string copy of the vectors; packing rewrites the tree and goes
+/// through `Value`. Output lands in a sibling temporary file renamed into place, so a failed run
+/// leaves any previous file intact.
+fn write_vectors<T: Serialize>(out: &Path, vectors: &T, packed: bool) -> Result<()> {
+    let io_error = |err: serde_json::Error| {
+        if err.is_io() {
+            anyhow::Error::new(std::io::Error::from(err))
+        } else {
+            anyhow::Error::new(err)
+        }
+    };
+    let tmp = out.with_extension("json.tmp");
+    let file =
+        fs::File::create(&tmp).with_context(|| format!("failed to create {}", tmp.display()))?;
+    let mut writer = BufWriter::new(file);
+    if packed {
+        let mut output = serde_json::to_value(vectors).context("failed to serialize vectors")?;
+        match output
+            .get_mut("sets")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            Some(sets) => sets.iter_mut().try_for_each(pack_vector_set)?,
+            None => pack_vector_set(&mut output)?,
+        }
+        serde_json::to_writer_pretty(&mut writer, &output)
+    } else {
+        serde_json::to_writer_pretty(&mut writer, vectors)
+    }
+    .map_err(io_error)
+    .with_context(|| format!("failed to write vectors to {}", tmp.display()))?;
+    writer
+        .flush()
+        .with_context(|| format!("failed to write vectors to {}", tmp.display()))?;
+    fs::rename(&tmp, out).with_context(|| format!("failed to write vectors to {}", out.display()))
+}
+
+/// Returns `None` when `--help` was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>> {
+    let mut parsed = Args {
+        out: None,
+        profile: DEFAULT_PROFILE,
+        sample_count: None,
+        budget_seconds: None,
+        schema_version: VECTOR_SCHEMA_VERSION,
+        self_verify: false,
+        differential: false,
+        packed: false,
+        family_seeds: BTreeMap::new(),
+    };
+    let usage = |message: String| anyhow::Error::new(UsageError(message));
 
     while let Some(arg) = args.next() {
+        let mut value = |what: &str| {
+            args.next()
+                .ok_or_else(|| usage(format!("{arg} requires {what}")))
+        };
         match arg.as_str() {
-            "--out" => {
-                let path = args.next().expect("--out requires a path");
-                out = PathBuf::from(path);
+            "--out" => parsed.out = Some(PathBuf::from(value("a path")?)),
+            "--profile" => {
+                let raw = value("default or large")?;
+                parsed.profile = [DEFAULT_PROFILE, LARGE_PROFILE]
+                    .into_iter()
+                    .find(|profile| profile.name == raw)
+                    .ok_or_else(|| {
+                        usage(format!("--profile must be default or large, got {raw}"))
+                    })?;
             }
             "--count" => {
-                let raw = args.next().expect("--count requires a number");
-                sample_count = raw.parse::<usize>().expect("--count must be a usize");
+                let raw = value("a number")?;
+                parsed.sample_count = Some(
+                    raw.parse::<usize>()
+                        .map_err(|_| usage(format!("--count must be a usize, got {raw}")))?,
+                );
             }
-            "--help" | "-h" => {
-                eprintln!("Usage: stwo-vector-gen [--out <path>] [--count <n>]");
-                std::process::exit(0);
+            "--budget-seconds" => {
+                let raw = value("a number")?;
+                parsed.budget_seconds = Some(raw.parse::<f64>().map_err(|_| {
+                    usage(format!(
+                        "--budget-seconds must be a number of seconds, got {raw}"
+                    ))
+                })?);
             }
-            _ => {
-                panic!("unknown argument: {arg}");
+            "--schema" => {
+                let raw = value("a version")?;
+                parsed.schema_version = match raw.parse::<u32>() {
+                    Ok(version)
+                        if (LEGACY_VECTOR_SCHEMA_VERSION..=VECTOR_SCHEMA_VERSION)
+                            .contains(&version) =>
+                    {
+                        version
+                    }
+                    _ => {
+                        return Err(usage(format!(
+                            "--schema must be between {LEGACY_VECTOR_SCHEMA_VERSION} and \
+                             {VECTOR_SCHEMA_VERSION}, got {raw}"
+                        )))
+                    }
+                };
+            }
+            "--self-verify" => parsed.self_verify = true,
+            "--differential" => parsed.differential = true,
+            "--encoding" => {
+                let raw = value("plain or packed")?;
+                parsed.packed = match raw.as_str() {
+                    "plain" => false,
+                    "packed" => true,
+                    _ => {
+                        return Err(usage(format!(
+                            "--encoding must be plain or packed, got {raw}"
+                        )))
+                    }
+                };
+            }
+            "--family-seed" => {
+                let raw = value("<family>=<hex seed>")?;
+                let (family, seed) = parse_family_seed(&raw).map_err(usage)?;
+                parsed.family_seeds.insert(family, seed);
             }
+            "--help" | "-h" => return Ok(None),
+            _ => return Err(usage(format!("unknown argument: {arg}"))),
         }
     }
+    if parsed.differential && !parsed.family_seeds.is_empty() {
+        return Err(usage(
+            "--family-seed cannot be combined with --differential".to_string(),
+        ));
+    }
+    if parsed.differential && parsed.profile.name != DEFAULT_PROFILE.name {
+        return Err(usage(
+            "--differential only supports the default profile".to_string(),
+        ));
+    }
 
-    (out, sample_count)
+    Ok(Some(parsed))
 }
 
-fn generate_vectors(state: &mut u64, sample_count: usize) -> FieldVectors {
-    let mut m31 = Vec::with_capacity(sample_count);
-    let mut cm31 = Vec::with_capacity(sample_count);
-    let mut qm31 = Vec::with_capacity(sample_count);
-    let mut circle_m31 = Vec::with_capacity(sample_count);
-    let mut fft_m31 = Vec::with_capacity(sample_count);
-    let mut blake3 = Vec::with_capacity(BLAKE3_VECTOR_COUNT);
-
-    for _ in 0..sample_count {
-        let a = sample_m31(state, true);
-        let b = sample_m31(state, true);
-        m31.push(M31Vector {
-            a: encode_m31(a),
-            b: encode_m31(b),
-            add: encode_m31(a + b),
-            sub: encode_m31(a - b),
-            mul: encode_m31(a * b),
-            inv_a: encode_m31(a.inverse()),
-            div_ab: encode_m31(a / b),
-        });
+/// Parses a `--family-seed` value such as `m31=0x1234` (the `0x` is optional).
+fn parse_family_seed(raw: &str) -> std::result::Result<(&'static str, u64), String> {
+    let Some((name, hex)) = raw.split_once('=') else {
+        return Err(format!(
+            "--family-seed must be <family>=<hex seed>, got {raw}"
+        ));
+    };
+    let Some(&(family, _, _)) = FAMILY_HELP.iter().find(|(family, _, _)| *family == name) else {
+        return Err(format!(
+            "--family-seed: unknown family {name}; see --list-families"
+        ));
+    };
+    if matches!(
+        FAMILY_SEEDS.iter().find(|(seeded, _)| *seeded == family),
+        Some((_, None))
+    ) {
+        return Err(format!(
+            "--family-seed: {family} is fixed and draws no seed"
+        ));
+    }
+    match u64::from_str_radix(hex.trim_start_matches("0x"), 16) {
+        Ok(0) => Err(format!("--family-seed: {family} seed must be non-zero")),
+        Ok(seed) => Ok((family, seed)),
+        Err(_) => Err(format!(
+            "--family-seed: {family} seed must be hex, got {hex}"
+        )),
     }
+}
 
-    for _ in 0..sample_count {
-        let a = sample_cm31(state, true);
-        let b = sample_cm31(state, true);
-        cm31.push(CM31Vector {
-            a: encode_cm31(a),
-            b: encode_cm31(b),
-            add: encode_cm31(a + b),
-            sub: encode_cm31(a - b),
-            mul: encode_cm31(a * b),
-            inv_a: encode_cm31(a.inverse()),
-            div_ab: encode_cm31(a / b),
-        });
+fn print_help() {
+    println!(
+        "Usage: stwo-vector-gen [--out <path>] [--profile <default|large>] [--count <n>] \
+         [--budget-seconds <n>] \
+         [--schema <{LEGACY_VECTOR_SCHEMA_VERSION}..{VECTOR_SCHEMA_VERSION}>] [--self-verify] \
+         [--differential] [--encoding <plain|packed>] [--family-seed <family>=<hex>]..."
+    );
+    println!("       stwo-vector-gen diff <old.json> <new.json> [--family <name>]... [--summary]");
+    println!("       stwo-vector-gen --list-families | --describe <family>");
+    println!();
+    for profile in [DEFAULT_PROFILE, LARGE_PROFILE] {
+        println!(
+            "Profile {}: writes {}; FRI folds up to 2^{}, VCS columns up to 2^{}, PCS lifting \
+             log size {}",
+            profile.name,
+            profile.default_out,
+            profile.fri_fold_log_sizes.1,
+            profile.vcs_max_column_log_size,
+            profile.pcs_lifting_log_size
+        );
+    }
+    println!();
+    println!(
+        "Exit codes: 0 success, {EXIT_DIFF_CHANGED} diff found changes, {EXIT_USAGE} usage error, \
+         {EXIT_IO} I/O error, {EXIT_GENERATION} generation failure"
+    );
+    println!();
+    println!("Families (default count):");
+    for (family, count, note) in FAMILY_HELP {
+        match (count, note.is_empty()) {
+            (0, _) => println!("  {family:<36} {note}"),
+            (_, true) => println!("  {family:<36} {count}"),
+            (_, false) => println!("  {family:<36} {count} ({note})"),
+        }
     }
+}
 
-    for _ in 0..sample_count {
-        let a = sample_qm31(state, true);
-        let b = sample_qm31(state, true);
-        qm31.push(QM31Vector {
-            a: encode_qm31(a),
-            b: encode_qm31(b),
-            add: encode_qm31(a + b),
-            sub: encode_qm31(a - b),
-            mul: encode_qm31(a * b),
-            inv_a: encode_qm31(a.inverse()),
-            div_ab: encode_qm31(a / b),
-        });
+/// One line per family: default count, the seed stream it draws from and the `FAMILY_HELP` note.
+fn print_family_list() {
+    for (family, count, note) in FAMILY_HELP {
+        let seed = match FAMILY_SEEDS.iter().find(|(name, _)| *name == family) {
+            Some((_, Some(seed))) => format!("dedicated {seed:#x}"),
+            Some((_, None)) => "none (fixed)".to_string(),
+            None => format!("derived {:#x}", derived_family_seed(VECTOR_SEED, family)),
+        };
+        println!("{family:<36} {count:>6}  {seed:<28} {note}");
     }
+}
 
-    for _ in 0..sample_count {
-        let a_scalar = sample_scalar(state);
-        let b_scalar = sample_scalar(state);
-        let a = M31_CIRCLE_GEN.mul(a_scalar as u128);
-        let b = M31_CIRCLE_GEN.mul(b_scalar as u128);
-        let log_order_a = a.log_order();
-        debug_assert!(log_order_a <= M31_CIRCLE_LOG_ORDER);
-        circle_m31.push(CircleM31Vector {
-            a_scalar,
-            b_scalar,
-            log_order_a,
-            a: encode_circle_point(a),
-            b: encode_circle_point(b),
-            add: encode_circle_point(a + b),
-            sub: encode_circle_point(a - b),
-            double_a: encode_circle_point(a.double()),
-            conjugate_a: encode_circle_point(a.conjugate()),
-        });
+fn describe_family(family: &str) -> Result<()> {
+    let Some((_, description, fields)) = FAMILY_DESCRIPTIONS
+        .iter()
+        .find(|(name, _, _)| *name == family)
+    else {
+        return Err(UsageError(format!("unknown family {family}; see --list-families")).into());
+    };
+    println!("{family}: {description}");
+    println!("fields:");
+    for field in *fields {
+        println!("  {field}");
     }
+    Ok(())
+}
 
-    for _ in 0..sample_count {
-        let a = sample_m31(state, false);
-        let b = sample_m31(state, false);
-        let twid = sample_m31(state, true);
-        let itwid = twid.inverse();
+struct DiffArgs {
+    old: PathBuf,
+    new: PathBuf,
+    families: Vec<String>,
+    summary: bool,
+}
 
-        let mut v0 = a;
-        let mut v1 = b;
-        butterfly(&mut v0, &mut v1, twid);
-        let butterfly_out = [encode_m31(v0), encode_m31(v1)];
+/// Per-family comparison of two vector files. Entries are matched as a multiset; unmatched old
+/// and new entries are paired up in order as modifications and the rest count as removed/added.
+#[derive(Debug, Default)]
+struct FamilyDiff {
+    family: String,
+    old_count: usize,
+    new_count: usize,
+    added: usize,
+    removed: usize,
+    /// (new index, first differing field path) per modified entry.
+    modified: Vec<(usize, String)>,
+    /// Same entries in a different order.
+    reordered: bool,
+}
 
-        ibutterfly(&mut v0, &mut v1, itwid);
-        let ibutterfly_out = [encode_m31(v0), encode_m31(v1)];
+impl FamilyDiff {
+    fn changed(&self) -> bool {
+        self.added > 0 || self.removed > 0 || !self.modified.is_empty() || self.reordered
+    }
+}
 
-        fft_m31.push(FftM31Vector {
-            a: encode_m31(a),
-            b: encode_m31(b),
-            twid: encode_m31(twid),
-            butterfly: butterfly_out,
-            ibutterfly: ibutterfly_out,
-        });
+fn parse_diff_args(mut args: impl Iterator<Item = String>) -> Result<DiffArgs> {
+    let usage = |message: String| anyhow::Error::new(UsageError(message));
+    let mut paths = Vec::new();
+    let mut families = Vec::new();
+    let mut summary = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--family" => families.push(
+                args.next()
+                    .ok_or_else(|| usage("--family requires a family name".to_string()))?,
+            ),
+            "--summary" => summary = true,
+            _ if arg.starts_with("--") => {
+                return Err(usage(format!("unknown diff argument: {arg}")))
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
     }
+    let [old, new]: [PathBuf; 2] = paths.try_into().map_err(|paths: Vec<PathBuf>| {
+        usage(format!(
+            "diff takes exactly two vector files (<old.json> <new.json>), got {}",
+            paths.len()
+        ))
+    })?;
+    Ok(DiffArgs {
+        old,
+        new,
+        families,
+        summary,
+    })
+}
 
-    let pcs_quotients = generate_pcs_quotients_vectors(state, PCS_VECTOR_COUNT);
-    let fri_folds = generate_fri_fold_vectors(state, FRI_FOLD_VECTOR_COUNT);
-    let fri_decommit = generate_fri_decommit_vectors(state, FRI_DECOMMIT_VECTOR_COUNT);
-    let proof_extract_oods = generate_proof_extract_oods_vectors(state, PROOF_OODS_VECTOR_COUNT);
-    let proof_sizes = generate_proof_size_vectors(state, PROOF_SIZE_VECTOR_COUNT);
-    let prover_line = generate_prover_line_vectors(state, PROVER_LINE_VECTOR_COUNT);
-    let vcs_verifier = generate_vcs_verifier_vectors(state, VCS_VERIFIER_VECTOR_COUNT);
-    let vcs_prover = generate_vcs_prover_vectors(state, VCS_PROVER_VECTOR_COUNT);
-    let vcs_lifted_verifier =
-        generate_vcs_lifted_verifier_vectors(state, VCS_LIFTED_VERIFIER_VECTOR_COUNT);
-    let vcs_lifted_prover =
-        generate_vcs_lifted_prover_vectors(state, VCS_LIFTED_PROVER_VECTOR_COUNT);
-    let example_state_machine_trace = generate_example_state_machine_trace_vectors(
-        state,
-        EXAMPLE_STATE_MACHINE_TRACE_VECTOR_COUNT,
-    );
-    let example_state_machine_transitions = generate_example_state_machine_transition_vectors(
-        state,
-        EXAMPLE_STATE_MACHINE_TRANSITION_VECTOR_COUNT,
+/// Reads a vector file as untyped JSON, so files from other schema versions and upstream commits
+/// can be compared even where the current structs would reject them.
+fn read_vector_file(path: &Path) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let raw = fs::read_to_string(path)
+        .with_context(|| format!("failed to read vector file {}", path.display()))?;
+    match serde_json::from_str(&raw) {
+        Ok(serde_json::Value::Object(fields)) => Ok(fields),
+        _ => Err(anyhow::Error::new(UsageError(format!(
+            "{} is not a vector file (expected a JSON object)",
+            path.display()
+        )))),
+    }
+}
+
+/// Vector families of a file by name. Object-valued families (`known_answer`) contribute one
+/// family per sub-array, named `family.field`.
+fn vector_families(
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> BTreeMap<String, Vec<serde_json::Value>> {
+    let mut families = BTreeMap::new();
+    for (name, value) in fields {
+        if NON_FAMILY_KEYS.contains(&name.as_str()) {
+            continue;
+        }
+        match value {
+            serde_json::Value::Array(entries) => {
+                families.insert(name.clone(), entries.clone());
+            }
+            serde_json::Value::Object(parts) => {
+                for (part, entries) in parts {
+                    let entries = match entries {
+                        serde_json::Value::Array(entries) => entries.clone(),
+                        other => vec![other.clone()],
+                    };
+                    families.insert(format!("{name}.{part}"), entries);
+                }
+            }
+            other => {
+                families.insert(name.clone(), vec![other.clone()]);
+            }
+        }
+    }
+    families
+}
+
+fn diff_family(family: &str, old: &[serde_json::Value], new: &[serde_json::Value]) -> FamilyDiff {
+    let mut diff = FamilyDiff {
+        family: family.to_string(),
+        old_count: old.len(),
+        new_count: new.len(),
+        ..FamilyDiff::default()
+    };
+    if old == new {
+        return diff;
+    }
+    // `Value` maps are sorted, so the compact rendering is canonical.
+    let mut unmatched_old: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, entry) in old.iter().enumerate().rev() {
+        unmatched_old
+            .entry(entry.to_string())
+            .or_default()
+            .push(index);
+    }
+    let mut new_only = Vec::new();
+    for (index, entry) in new.iter().enumerate() {
+        let matched = unmatched_old
+            .get_mut(&entry.to_string())
+            .and_then(Vec::pop)
+            .is_some();
+        if !matched {
+            new_only.push(index);
+        }
+    }
+    let mut old_only: Vec<usize> = unmatched_old.into_values().flatten().collect();
+    old_only.sort_unstable();
+
+    if old_only.is_empty() && new_only.is_empty() {
+        diff.reordered = true;
+        return diff;
+    }
+    for (&old_index, &new_index) in old_only.iter().zip(&new_only) {
+        let path =
+            first_difference(&old[old_index], &new[new_index], String::new()).unwrap_or_default();
+        diff.modified.push((new_index, path));
+    }
+    diff.removed = old_only.len() - diff.modified.len();
+    diff.added = new_only.len() - diff.modified.len();
+    diff
+}
+
+/// Path of the first field where `old` and `new` differ (`.field[index]...`), or `None` if they
+/// are equal. Fields are visited in sorted order.
+fn first_difference(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    path: String,
+) -> Option<String> {
+    use serde_json::Value;
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            let keys: std::collections::BTreeSet<&String> =
+                old_fields.keys().chain(new_fields.keys()).collect();
+            keys.into_iter().find_map(|key| {
+                let field_path = format!("{path}.{key}");
+                match (old_fields.get(key), new_fields.get(key)) {
+                    (Some(old), Some(new)) => first_difference(old, new, field_path),
+                    _ => Some(field_path),
+                }
+            })
+        }
+        (Value::Array(old_items), Value::Array(new_items)) => old_items
+            .iter()
+            .zip(new_items)
+            .enumerate()
+            .find_map(|(index, (old, new))| first_difference(old, new, format!("{path}[{index}]")))
+            .or_else(|| {
+                (old_items.len() != new_items.len())
+                    .then(|| format!("{path} (length {} -> {})", old_items.len(), new_items.len()))
+            }),
+        _ => (old != new).then(|| {
+            if path.is_empty() {
+                "<entry>".to_string()
+            } else {
+                path
+            }
+        }),
+    }
+}
+
+/// `stwo-vector-gen diff`: compares two vector files family by family. Returns
+/// `EXIT_DIFF_CHANGED` if any compared family differs, so it can gate regeneration PRs. `meta`
+/// differences are reported but do not count as changes.
+fn run_diff(args: DiffArgs) -> Result<ExitCode> {
+    let old_fields = read_vector_file(&args.old)?;
+    let new_fields = read_vector_file(&args.new)?;
+    let old_families = vector_families(&old_fields);
+    let new_families = vector_families(&new_fields);
+
+    let mut names: Vec<&String> = old_families.keys().chain(new_families.keys()).collect();
+    names.sort_unstable();
+    names.dedup();
+    for family in &args.families {
+        if !names
+            .iter()
+            .any(|name| *name == family || name.starts_with(&format!("{family}.")))
+        {
+            bail!(UsageError(format!(
+                "--family {family} is not a family of either vector file"
+            )));
+        }
+    }
+    names.retain(|name| {
+        args.families.is_empty()
+            || args
+                .families
+                .iter()
+                .any(|family| *name == family || name.starts_with(&format!("{family}.")))
+    });
+
+    let empty = Vec::new();
+    let diffs: Vec<FamilyDiff> = names
+        .iter()
+        .map(|name| {
+            diff_family(
+                name,
+                old_families.get(*name).unwrap_or(&empty),
+                new_families.get(*name).unwrap_or(&empty),
+            )
+        })
+        .collect();
+    let changed = diffs.iter().filter(|diff| diff.changed()).count();
+
+    if !args.summary {
+        println!("diff {} -> {}", args.old.display(), args.new.display());
+        if let Some(path) = old_fields
+            .get("meta")
+            .zip(new_fields.get("meta"))
+            .and_then(|(old, new)| first_difference(old, new, "meta".to_string()))
+        {
+            println!("meta differs at {path}");
+        }
+    }
+    for diff in diffs.iter().filter(|diff| diff.changed()) {
+        let line = if diff.reordered {
+            "reordered".to_string()
+        } else {
+            format!("+{} -{} ~{}", diff.added, diff.removed, diff.modified.len())
+        };
+        if args.summary {
+            println!("{}: {line}", diff.family);
+            continue;
+        }
+        println!(
+            "{}: {} -> {} entries, {line}",
+            diff.family, diff.old_count, diff.new_count
+        );
+        for (index, path) in diff.modified.iter().take(DIFF_MODIFIED_SHOWN) {
+            println!("  [{index}] first differs at {path}");
+        }
+        if diff.modified.len() > DIFF_MODIFIED_SHOWN {
+            println!(
+                "  ... and {} more modified",
+                diff.modified.len() - DIFF_MODIFIED_SHOWN
+            );
+        }
+    }
+    println!("{changed} of {} families changed", diffs.len());
+    Ok(if changed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_DIFF_CHANGED)
+    })
+}
+
+/// Rewrites the `PACKED_COLUMN_FIELDS` of a serialized vector set as packed strings, records
+/// the encoding per family in `meta.column_encoding`, and checks that every packed column decodes
+/// back to the original values.
+fn pack_vector_set(set: &mut serde_json::Value) -> Result<()> {
+    let mut encodings = serde_json::Map::new();
+    for (family, fields) in PACKED_COLUMN_FIELDS {
+        let Some(entries) = set
+            .get_mut(family)
+            .and_then(serde_json::Value::as_array_mut)
+        else {
+            continue;
+        };
+        for entry in entries.iter_mut() {
+            for field in fields {
+                let Some(columns) = entry.get_mut(*field) else {
+                    continue;
+                };
+                let plain: Vec<Vec<u32>> = serde_json::from_value(columns.take())
+                    .with_context(|| format!("{family}.{field} is not a list of u32 columns"))?;
+                let mut packed = Vec::with_capacity(plain.len());
+                for column in &plain {
+                    let encoded = encode_packed_column(column);
+                    if decode_packed_column(&encoded).as_ref() != Some(column) {
+                        bail!("{family}.{field}: packed column does not round-trip");
+                    }
+                    packed.push(serde_json::Value::String(encoded));
+                }
+                *columns = serde_json::Value::Array(packed);
+            }
+        }
+        encodings.insert(family.to_string(), PACKED_COLUMN_ENCODING.into());
+    }
+    let meta = set
+        .get_mut("meta")
+        .and_then(serde_json::Value::as_object_mut)
+        .ok_or_else(|| anyhow!("vector set has no meta object"))?;
+    meta.insert(
+        "column_encoding".to_string(),
+        serde_json::Value::Object(encodings),
+    );
+    Ok(())
+}
+
+fn encode_packed_column(column: &[u32]) -> String {
+    let bytes: Vec<u8> = column
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect();
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let word = chunk.iter().enumerate().fold(0u32, |word, (i, byte)| {
+            word | (u32::from(*byte) << (16 - 8 * i))
+        });
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_ALPHABET[(word >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Inverse of `encode_packed_column`; `None` on malformed input.
+fn decode_packed_column(encoded: &str) -> Option<Vec<u32>> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 4 != 0 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    for chunk in encoded.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 {
+            return None;
+        }
+        let mut word = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let digit = BASE64_ALPHABET.iter().position(|&a| a == c)? as u32;
+            word |= digit << (18 - 6 * i);
+        }
+        bytes.extend_from_slice(&word.to_be_bytes()[1..4 - padding]);
+    }
+    if bytes.len() % 4 != 0 {
+        return None;
+    }
+    Some(
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().expect("4-byte chunk")))
+            .collect(),
+    )
+}
+
+/// `family/relation` of every `DIFFERENTIAL_CHECKS` entry that does not hold across `sets`.
+fn failed_differential_checks(sets: &[FieldVectors; 2]) -> Vec<String> {
+    let valid_verifies = |cases: Vec<(&str, &str)>| {
+        cases
+            .iter()
+            .all(|(case, expected)| *case != "valid" || *expected == "ok")
+    };
+    DIFFERENTIAL_CHECKS
+        .iter()
+        .filter(|(family, relation, _)| {
+            let holds = match (*family, *relation) {
+                ("m31", "seed_dependent") => sets[0]
+                    .m31
+                    .iter()
+                    .zip(sets[1].m31.iter())
+                    .any(|(a, b)| (a.a, a.b) != (b.a, b.b)),
+                ("m31", "count_matches_sample_count") => sets
+                    .iter()
+                    .all(|set| set.m31.len() == set.meta.sample_count),
+                ("fri_folds", "fold_line_halves") => sets.iter().all(|set| {
+                    set.fri_folds
+                        .iter()
+                        .all(|v| v.fold_line_values.len() * 2 == v.line_eval.len())
+                }),
+                ("fri_folds", "fold_circle_halves") => sets.iter().all(|set| {
+                    set.fri_folds
+                        .iter()
+                        .all(|v| v.fold_circle_values.len() * 2 == v.circle_eval.len())
+                }),
+                ("vcs_verifier", "valid_case_verifies") => sets.iter().all(|set| {
+                    valid_verifies(
+                        set.vcs_verifier
+                            .iter()
+                            .map(|v| (v.case.as_str(), v.expected.as_str()))
+                            .collect(),
+                    )
+                }),
+                ("fri_decommit", "valid_case_verifies") => sets.iter().all(|set| {
+                    valid_verifies(
+                        set.fri_decommit
+                            .iter()
+                            .map(|v| (v.case.as_str(), v.expected.as_str()))
+                            .collect(),
+                    )
+                }),
+                ("pcs_quotients", "row_quotient_per_query") => sets.iter().all(|set| {
+                    set.pcs_quotients
+                        .iter()
+                        .all(|v| v.row_quotients.len() == v.query_positions.len())
+                }),
+                ("example_state_machine_trace", "column_length") => sets.iter().all(|set| {
+                    set.example_state_machine_trace.iter().all(|v| {
+                        v.columns
+                            .iter()
+                            .all(|column| column.len() == 1usize << v.log_size)
+                    })
+                }),
+                ("channel_mix_root", "root_patterns_match") => sets[0]
+                    .channel_mix_root
+                    .iter()
+                    .map(|v| &v.root_pattern)
+                    .eq(sets[1].channel_mix_root.iter().map(|v| &v.root_pattern)),
+                _ => false,
+            };
+            !holds
+        })
+        .map(|(family, relation, _)| format!("{family}/{relation}"))
+        .collect()
+}
+
+/// Replays recorded vector inputs through the upstream APIs (not this tool's reference helpers)
+/// and returns one `family[index].field` message per output that no longer matches.
+fn self_verify_vectors(vectors: &FieldVectors) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    let mut check = |family: &str, index: usize, field: &str, matches: bool| {
+        if !matches {
+            mismatches.push(format!("{family}[{index}].{field}"));
+        }
+    };
+
+    for (index, v) in vectors.secure_column.iter().enumerate() {
+        let column = v
+            .column
+            .iter()
+            .copied()
+            .map(decode_qm31)
+            .collect::<Vec<_>>();
+        let coordinates = secure_column_coordinates(&column);
+        check(
+            "secure_column",
+            index,
+            "coordinates",
+            coordinates
+                .iter()
+                .zip(&v.coordinates)
+                .all(|(coords, expected)| {
+                    coords
+                        .iter()
+                        .copied()
+                        .map(encode_m31)
+                        .eq(expected.iter().copied())
+                }),
+        );
+        check(
+            "secure_column",
+            index,
+            "reconstructed",
+            (0..column.len())
+                .map(|row| {
+                    encode_qm31(QM31::from_m31_array(std::array::from_fn(|coord| {
+                        coordinates[coord][row]
+                    })))
+                })
+                .eq(v.reconstructed.iter().copied()),
+        );
+        let leaf_hashes = secure_column_leaf_hashes(&coordinates);
+        check(
+            "secure_column",
+            index,
+            "leaf_hashes",
+            leaf_hashes.into_iter().map(encode_hash).collect::<Vec<_>>() == v.leaf_hashes,
+        );
+    }
+
+    for (index, v) in vectors.fri_folds.iter().enumerate() {
+        let line_eval = v
+            .line_eval
+            .iter()
+            .copied()
+            .map(decode_qm31)
+            .collect::<Vec<_>>();
+        let line_domain = LineDomain::new(Coset::half_odds(v.line_log_size));
+        let (_, folded) = fold_line(&line_eval, line_domain, decode_qm31(v.alpha));
+        check(
+            "fri_folds",
+            index,
+            "fold_line_values",
+            folded.into_iter().map(encode_qm31).collect::<Vec<_>>() == v.fold_line_values,
+        );
+
+        let circle_eval = v
+            .circle_eval
+            .iter()
+            .copied()
+            .map(decode_qm31)
+            .collect::<Vec<_>>();
+        let mut folded = vec![QM31::from(0); circle_eval.len() >> 1];
+        fold_circle_into_line(
+            &mut folded,
+            &circle_eval,
+            CanonicCoset::new(v.circle_log_size).circle_domain(),
+            decode_qm31(v.alpha),
+        );
+        check(
+            "fri_folds",
+            index,
+            "fold_circle_values",
+            folded.into_iter().map(encode_qm31).collect::<Vec<_>>() == v.fold_circle_values,
+        );
+    }
+
+    for (family, family_vectors) in [
+        ("pcs_quotients", &vectors.pcs_quotients),
+        (
+            "pcs_quotients_shared_points",
+            &vectors.pcs_quotients_shared_points,
+        ),
+    ] {
+        for (index, v) in family_vectors.iter().enumerate() {
+            let samples = v
+                .samples
+                .iter()
+                .map(|tree| {
+                    tree.iter()
+                        .map(|column| column.iter().map(decode_point_sample).collect())
+                        .collect()
+                })
+                .collect();
+            let queried_values = v
+                .queried_values
+                .iter()
+                .map(|tree| {
+                    tree.iter()
+                        .map(|column| column.iter().copied().map(decode_m31).collect())
+                        .collect()
+                })
+                .collect();
+            let answers = fri_answers(
+                TreeVec(v.column_log_sizes.clone()),
+                TreeVec(samples),
+                decode_qm31(v.random_coeff),
+                &v.query_positions,
+                TreeVec(queried_values),
+                v.lifting_log_size,
+            );
+            check(
+                family,
+                index,
+                "fri_answers",
+                answers.is_ok_and(|answers| {
+                    answers.into_iter().map(encode_qm31).collect::<Vec<_>>() == v.fri_answers
+                }),
+            );
+            let domain = CanonicCoset::new(v.lifting_log_size).circle_domain();
+            check(
+                family,
+                index,
+                "domain_points",
+                v.query_positions
+                    .iter()
+                    .map(|&position| {
+                        encode_circle_point(
+                            domain.at(bit_reverse_index(position, v.lifting_log_size)),
+                        )
+                    })
+                    .eq(v.domain_points.iter().copied()),
+            );
+        }
+    }
+
+    for (index, v) in vectors.pcs_preprocessed_queries.iter().enumerate() {
+        check(
+            "pcs_preprocessed_queries",
+            index,
+            "expected",
+            prepare_preprocessed_query_positions(
+                &v.query_positions,
+                v.max_log_size,
+                v.pp_max_log_size,
+            ) == v.expected,
+        );
+    }
+
+    let lifted_verify = |root: [u8; 32],
+                         column_log_sizes: &[u32],
+                         query_positions: &[usize],
+                         queried_values: &[Vec<u32>],
+                         hash_witness: &[[u8; 32]]| {
+        run_vcs_lifted_verifier(
+            Blake2sHash(root),
+            column_log_sizes.to_vec(),
+            query_positions.to_vec(),
+            queried_values
+                .iter()
+                .map(|column| column.iter().copied().map(decode_m31).collect())
+                .collect(),
+            MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                hash_witness: hash_witness.iter().copied().map(Blake2sHash).collect(),
+            },
+        )
+    };
+    for (index, v) in vectors.vcs_lifted_verifier.iter().enumerate() {
+        let result = lifted_verify(
+            v.root,
+            &v.column_log_sizes,
+            &v.query_positions,
+            &v.queried_values,
+            &v.hash_witness,
+        );
+        check(
+            "vcs_lifted_verifier",
+            index,
+            "expected",
+            result == v.expected,
+        );
+    }
+    for (index, v) in vectors.vcs_lifted_error_variants.iter().enumerate() {
+        for probe in &v.probes {
+            let result = lifted_verify(
+                probe.root,
+                &probe.column_log_sizes,
+                &probe.query_positions,
+                &probe.queried_values,
+                &probe.hash_witness,
+            );
+            check(
+                "vcs_lifted_error_variants",
+                index,
+                "probes.expected",
+                result == probe.expected,
+            );
+        }
+    }
+    for (index, v) in vectors.vcs_lifted_prover.iter().enumerate() {
+        let result = lifted_verify(
+            v.root,
+            &v.column_log_sizes,
+            &v.query_positions,
+            &v.queried_values,
+            &v.hash_witness,
+        );
+        check("vcs_lifted_prover", index, "root", result == "ok");
+    }
+    for (index, v) in vectors.queried_rows_reshape.iter().enumerate() {
+        let result = lifted_verify(
+            v.root,
+            &v.column_log_sizes,
+            &v.query_positions,
+            &v.queried_values,
+            &v.hash_witness,
+        );
+        check("queried_rows_reshape", index, "root", result == "ok");
+    }
+
+    mismatches
+}
+
+fn generate_vectors(
+    seed: u64,
+    family_seed_overrides: &BTreeMap<&'static str, u64>,
+    profile: &GenerationProfile,
+    sample_count: usize,
+    budget_seconds: Option<f64>,
+    schema_version: u32,
+) -> FieldVectors {
+    let mut streams = FamilyStreams::new(seed, family_seed_overrides);
+    let mut timer = GenerationTimer::new(budget_seconds);
+    let family_started = Instant::now();
+    let known_answer = generate_known_answer_vectors();
+    timer.record(
+        "known_answer",
+        known_answer.field_ops.len() + known_answer.merkle.len(),
+        family_started,
+        None,
+    );
+    let mut m31 = Vec::with_capacity(sample_count);
+    let mut cm31 = Vec::with_capacity(sample_count);
+    let mut qm31 = Vec::with_capacity(sample_count);
+    let mut circle_m31 = Vec::with_capacity(sample_count);
+    let mut fft_m31 = Vec::with_capacity(sample_count);
+    let mut blake3 = Vec::with_capacity(BLAKE3_VECTOR_COUNT);
+
+    let family_started = Instant::now();
+    let state = streams.state("m31");
+    for _ in 0..sample_count {
+        let a = sample_m31(state, true);
+        let b = sample_m31(state, true);
+        m31.push(M31Vector {
+            a: encode_m31(a),
+            b: encode_m31(b),
+            add: encode_m31(a + b),
+            sub: encode_m31(a - b),
+            mul: encode_m31(a * b),
+            inv_a: encode_m31(a.inverse()),
+            div_ab: encode_m31(a / b),
+        });
+    }
+    timer.record("m31", m31.len(), family_started, None);
+
+    let family_started = Instant::now();
+    let state = streams.state("cm31");
+    for _ in 0..sample_count {
+        let a = sample_cm31(state, true);
+        let b = sample_cm31(state, true);
+        cm31.push(CM31Vector {
+            a: encode_cm31(a),
+            b: encode_cm31(b),
+            add: encode_cm31(a + b),
+            sub: encode_cm31(a - b),
+            mul: encode_cm31(a * b),
+            inv_a: encode_cm31(a.inverse()),
+            div_ab: encode_cm31(a / b),
+        });
+    }
+    timer.record("cm31", cm31.len(), family_started, None);
+
+    let family_started = Instant::now();
+    let state = streams.state("qm31");
+    for _ in 0..sample_count {
+        let a = sample_qm31(state, true);
+        let b = sample_qm31(state, true);
+        qm31.push(QM31Vector {
+            a: encode_qm31(a),
+            b: encode_qm31(b),
+            add: encode_qm31(a + b),
+            sub: encode_qm31(a - b),
+            mul: encode_qm31(a * b),
+            inv_a: encode_qm31(a.inverse()),
+            div_ab: encode_qm31(a / b),
+        });
+    }
+    timer.record("qm31", qm31.len(), family_started, None);
+
+    let family_started = Instant::now();
+    let state = streams.state("circle_m31");
+    for _ in 0..sample_count {
+        let a_scalar = sample_scalar(state);
+        let b_scalar = sample_scalar(state);
+        let a = M31_CIRCLE_GEN.mul(a_scalar as u128);
+        let b = M31_CIRCLE_GEN.mul(b_scalar as u128);
+        let log_order_a = a.log_order();
+        debug_assert!(log_order_a <= M31_CIRCLE_LOG_ORDER);
+        circle_m31.push(CircleM31Vector {
+            a_scalar,
+            b_scalar,
+            log_order_a,
+            a: encode_circle_point(a),
+            b: encode_circle_point(b),
+            add: encode_circle_point(a + b),
+            sub: encode_circle_point(a - b),
+            double_a: encode_circle_point(a.double()),
+            conjugate_a: encode_circle_point(a.conjugate()),
+        });
+    }
+    timer.record("circle_m31", circle_m31.len(), family_started, None);
+
+    let family_started = Instant::now();
+    let state = streams.state("fft_m31");
+    for _ in 0..sample_count {
+        let a = sample_m31(state, false);
+        let b = sample_m31(state, false);
+        let twid = sample_m31(state, true);
+        let itwid = twid.inverse();
+
+        let mut v0 = a;
+        let mut v1 = b;
+        butterfly(&mut v0, &mut v1, twid);
+        let butterfly_out = [encode_m31(v0), encode_m31(v1)];
+
+        ibutterfly(&mut v0, &mut v1, itwid);
+        let ibutterfly_out = [encode_m31(v0), encode_m31(v1)];
+
+        fft_m31.push(FftM31Vector {
+            a: encode_m31(a),
+            b: encode_m31(b),
+            twid: encode_m31(twid),
+            butterfly: butterfly_out,
+            ibutterfly: ibutterfly_out,
+        });
+    }
+    timer.record("fft_m31", fft_m31.len(), family_started, None);
+
+    let pcs_quotients = timer.run_with_retries("pcs_quotients", |stats| {
+        generate_pcs_quotients_vectors(
+            streams.state("pcs_quotients"),
+            profile.pcs_count,
+            profile.pcs_lifting_log_size,
+            0,
+            PCS_FORCED_QUERY_VECTOR_COUNT,
+            stats,
+        )
+    });
+    let fri_folds = timer.run("fri_folds", || {
+        generate_fri_fold_vectors(
+            streams.state("fri_folds"),
+            profile.fri_fold_count,
+            profile.fri_fold_log_sizes,
+        )
+    });
+    let fri_decommit = timer.run("fri_decommit", || {
+        generate_fri_decommit_vectors(streams.state("fri_decommit"), FRI_DECOMMIT_VECTOR_COUNT)
+    });
+    let mut proof_extract_oods = timer.run("proof_extract_oods", || {
+        generate_proof_extract_oods_vectors(
+            streams.state("proof_extract_oods"),
+            PROOF_OODS_VECTOR_COUNT,
+        )
+    });
+    let mut proof_sizes = timer.run("proof_sizes", || {
+        generate_proof_size_vectors(streams.state("proof_sizes"), PROOF_SIZE_VECTOR_COUNT)
+    });
+    let prover_line = timer.run("prover_line", || {
+        generate_prover_line_vectors(streams.state("prover_line"), PROVER_LINE_VECTOR_COUNT)
+    });
+    let vcs_verifier = timer.run_with_retries("vcs_verifier", |stats| {
+        generate_vcs_verifier_vectors(
+            streams.state("vcs_verifier"),
+            profile.vcs_verifier_count,
+            profile.vcs_max_column_log_size,
+            stats,
+        )
+    });
+    let vcs_prover = timer.run_with_retries("vcs_prover", |stats| {
+        generate_vcs_prover_vectors(
+            streams.state("vcs_prover"),
+            profile.vcs_prover_count,
+            profile.vcs_max_column_log_size,
+            stats,
+        )
+    });
+    let vcs_lifted_verifier = timer.run_with_retries("vcs_lifted_verifier", |stats| {
+        generate_vcs_lifted_verifier_vectors(
+            streams.state("vcs_lifted_verifier"),
+            profile.vcs_lifted_verifier_count,
+            profile.vcs_max_column_log_size,
+            stats,
+        )
+    });
+    let vcs_lifted_prover = timer.run_with_retries("vcs_lifted_prover", |stats| {
+        generate_vcs_lifted_prover_vectors(
+            streams.state("vcs_lifted_prover"),
+            profile.vcs_lifted_prover_count,
+            profile.vcs_max_column_log_size,
+            stats,
+        )
+    });
+    let mut queried_rows_reshape = Vec::new();
+    let example_state_machine_trace = timer.run("example_state_machine_trace", || {
+        generate_example_state_machine_trace_vectors(
+            streams.state("example_state_machine_trace"),
+            EXAMPLE_STATE_MACHINE_TRACE_VECTOR_COUNT,
+        )
+    });
+    let example_state_machine_transitions = timer.run("example_state_machine_transitions", || {
+        generate_example_state_machine_transition_vectors(
+            streams.state("example_state_machine_transitions"),
+            EXAMPLE_STATE_MACHINE_TRANSITION_VECTOR_COUNT,
+        )
+    });
+    let example_state_machine_claimed_sum =
+        timer.run_with_retries("example_state_machine_claimed_sum", |stats| {
+            generate_example_state_machine_claimed_sum_vectors(
+                streams.state("example_state_machine_claimed_sum"),
+                EXAMPLE_STATE_MACHINE_CLAIMED_SUM_VECTOR_COUNT,
+                stats,
+            )
+        });
+    let example_state_machine_lookup_draw = timer.run("example_state_machine_lookup_draw", || {
+        generate_example_state_machine_lookup_draw_vectors(
+            streams.state("example_state_machine_lookup_draw"),
+            EXAMPLE_STATE_MACHINE_LOOKUP_DRAW_VECTOR_COUNT,
+        )
+    });
+    let example_state_machine_statement = timer.run("example_state_machine_statement", || {
+        generate_example_state_machine_statement_vectors(
+            streams.state("example_state_machine_statement"),
+            EXAMPLE_STATE_MACHINE_STATEMENT_VECTOR_COUNT,
+        )
+    });
+    let example_xor_is_first = timer.run("example_xor_is_first", || {
+        generate_example_xor_is_first_vectors(
+            streams.state("example_xor_is_first"),
+            EXAMPLE_XOR_IS_FIRST_VECTOR_COUNT,
+        )
+    });
+    let example_xor_is_step_with_offset = timer.run("example_xor_is_step_with_offset", || {
+        generate_example_xor_is_step_with_offset_vectors(
+            streams.state("example_xor_is_step_with_offset"),
+            EXAMPLE_XOR_IS_STEP_WITH_OFFSET_VECTOR_COUNT,
+        )
+    });
+    let mut example_wide_fibonacci_trace = timer.run("example_wide_fibonacci_trace", || {
+        generate_example_wide_fibonacci_trace_vectors(
+            streams.state("example_wide_fibonacci_trace"),
+            EXAMPLE_WIDE_FIBONACCI_TRACE_VECTOR_COUNT,
+        )
+    });
+    let example_plonk_trace = timer.run("example_plonk_trace", || {
+        generate_example_plonk_trace_vectors(
+            streams.state("example_plonk_trace"),
+            EXAMPLE_PLONK_TRACE_VECTOR_COUNT,
+        )
+    });
+
+    let family_started = Instant::now();
+    let state = streams.state("blake3");
+    for _ in 0..BLAKE3_VECTOR_COUNT {
+        let data_len = next_u64(state) as usize % 96;
+        let mut data = vec![0u8; data_len];
+        fill_bytes(state, &mut data);
+        let hash = Blake3Hasher::hash(&data);
+
+        let mut left_data = vec![0u8; next_u64(state) as usize % 64];
+        fill_bytes(state, &mut left_data);
+        let mut right_data = vec![0u8; next_u64(state) as usize % 64];
+        fill_bytes(state, &mut right_data);
+        let left = Blake3Hasher::hash(&left_data);
+        let right = Blake3Hasher::hash(&right_data);
+        let concat_hash = Blake3Hasher::concat_and_hash(&left, &right);
+
+        blake3.push(Blake3Vector {
+            data,
+            hash: encode_blake3_hash(hash),
+            left: encode_blake3_hash(left),
+            right: encode_blake3_hash(right),
+            concat_hash: encode_blake3_hash(concat_hash),
+        });
+    }
+    timer.record("blake3", blake3.len(), family_started, None);
+
+    let qm31_structure = timer.run("qm31_structure", || {
+        generate_qm31_structure_vectors(
+            streams.state("qm31_structure"),
+            QM31_STRUCTURE_VECTOR_COUNT,
+        )
+    });
+    let fri_layer_count = timer.run("fri_layer_count", generate_fri_layer_count_vectors);
+    let blake2s = timer.run("blake2s", || {
+        generate_blake2s_vectors(streams.state("blake2s"), BLAKE2S_VECTOR_COUNT)
+    });
+    let fri_last_layer = timer.run("fri_last_layer", || {
+        generate_fri_last_layer_vectors(
+            streams.state("fri_last_layer"),
+            FRI_LAST_LAYER_VECTOR_COUNT,
+        )
+    });
+    let channel_mix_root = timer.run("channel_mix_root", || {
+        generate_channel_mix_root_vectors(
+            streams.state("channel_mix_root"),
+            CHANNEL_MIX_ROOT_VECTOR_COUNT,
+        )
+    });
+    let fri_fold_chain = timer.run("fri_fold_chain", || {
+        generate_fri_fold_chain_vectors(
+            streams.state("fri_fold_chain"),
+            FRI_FOLD_CHAIN_VECTOR_COUNT,
+        )
+    });
+    let fri_answers_errors = timer.run_with_retries("fri_answers_errors", |stats| {
+        generate_fri_answers_error_vectors(
+            streams.state("fri_answers_errors"),
+            FRI_ANSWERS_ERROR_VECTOR_COUNT,
+            stats,
+        )
+    });
+    let line_poly_construction = timer.run("line_poly_construction", || {
+        generate_line_poly_construction_vectors(streams.state("line_poly_construction"))
+    });
+    let pcs_quotients_shared_points =
+        timer.run_with_retries("pcs_quotients_shared_points", |stats| {
+            generate_pcs_quotients_vectors(
+                streams.state("pcs_quotients_shared_points"),
+                profile.pcs_shared_point_count,
+                profile.pcs_lifting_log_size,
+                PCS_SHARED_POINT_PERCENT,
+                0,
+                stats,
+            )
+        });
+    let denominator_inverses = timer.run("denominator_inverses", || {
+        generate_denominator_inverses_vectors(streams.state("denominator_inverses"))
+    });
+    let samples_with_randomness = timer.run("samples_with_randomness", || {
+        generate_samples_with_randomness_vectors(
+            streams.state("samples_with_randomness"),
+            SAMPLES_WITH_RANDOMNESS_VECTOR_COUNT,
+        )
+    });
+    // Continue their families' streams after the entries generated above.
+    proof_extract_oods.extend(timer.run("proof_extract_oods_errors", || {
+        generate_proof_extract_oods_error_vectors(streams.state("proof_extract_oods"))
+    }));
+    proof_sizes.extend(timer.run("proof_sizes_edges", || {
+        generate_proof_size_edge_vectors(streams.state("proof_sizes"))
+    }));
+    let example_state_machine_components =
+        timer.run_with_retries("example_state_machine_components", |stats| {
+            generate_example_state_machine_components_vectors(
+                streams.state("example_state_machine_components"),
+                EXAMPLE_STATE_MACHINE_COMPONENTS_VECTOR_COUNT,
+                stats,
+            )
+        });
+    let fri_fold_ordering = timer.run("fri_fold_ordering", || {
+        generate_fri_fold_ordering_vectors(
+            streams.state("fri_fold_ordering"),
+            FRI_FOLD_ORDERING_VECTOR_COUNT,
+        )
+    });
+    example_wide_fibonacci_trace.extend(timer.run("example_wide_fibonacci_trace_seeded", || {
+        generate_example_wide_fibonacci_seeded_trace_vectors(
+            streams.state("example_wide_fibonacci_trace"),
+            EXAMPLE_WIDE_FIBONACCI_TRACE_VECTOR_COUNT,
+        )
+    }));
+    let example_poseidon_trace = timer.run(
+        "example_poseidon_trace",
+        generate_example_poseidon_trace_vectors,
+    );
+    let example_poseidon_rounds = timer.run("example_poseidon_rounds", || {
+        generate_example_poseidon_rounds_vectors(
+            streams.state("example_poseidon_rounds"),
+            EXAMPLE_POSEIDON_ROUNDS_VECTOR_COUNT,
+        )
+    });
+    queried_rows_reshape.extend(timer.run_with_retries("queried_rows_reshape", |stats| {
+        generate_queried_rows_reshape_vectors(
+            streams.state("queried_rows_reshape"),
+            QUERIED_ROWS_RESHAPE_VECTOR_COUNT,
+            stats,
+        )
+    }));
+    let composition_sampled_order = timer.run("composition_sampled_order", || {
+        generate_composition_sampled_order_vectors(
+            streams.state("composition_sampled_order"),
+            COMPOSITION_SAMPLED_ORDER_VECTOR_COUNT,
+        )
+    });
+    let pcs_commit = timer.run("pcs_commit", || {
+        generate_pcs_commit_vectors(streams.state("pcs_commit"), PCS_COMMIT_VECTOR_COUNT)
+    });
+    let oods_draw = timer.run("oods_draw", || {
+        generate_oods_draw_vectors(streams.state("oods_draw"), OODS_DRAW_VECTOR_COUNT)
+    });
+    let accumulation = timer.run("accumulation", || {
+        generate_accumulation_vectors(streams.state("accumulation"), ACCUMULATION_VECTOR_COUNT)
+    });
+    let merkle_avalanche = timer.run("merkle_avalanche", || {
+        generate_merkle_avalanche_vectors(
+            streams.state("merkle_avalanche"),
+            MERKLE_AVALANCHE_VECTOR_COUNT,
+        )
+    });
+    let lookup_draw_v2 = timer.run("lookup_draw_v2", || {
+        generate_lookup_draw_v2_vectors(
+            streams.state("lookup_draw_v2"),
+            LOOKUP_DRAW_V2_VECTOR_COUNT,
+        )
+    });
+    let vcs_lifted_error_variants = timer.run_with_retries("vcs_lifted_error_variants", |stats| {
+        generate_vcs_lifted_error_variant_vectors(streams.state("vcs_lifted_error_variants"), stats)
+    });
+    let circle_scalar_mul = timer.run("circle_scalar_mul", || {
+        generate_circle_scalar_mul_vectors(streams.state("circle_scalar_mul"))
+    });
+    let secure_column = timer.run("secure_column", || {
+        generate_secure_column_vectors(streams.state("secure_column"))
+    });
+    let blowup_sizing = timer.run("blowup_sizing", generate_blowup_sizing_vectors);
+    let config_security = timer.run("config_security", generate_config_security_vectors);
+    let e2e_tree_shapes = timer.run("e2e_tree_shapes", generate_e2e_tree_shapes_vectors);
+    let field_boundaries = timer.run("field_boundaries", generate_field_boundaries_vectors);
+    let fft_edge = timer.run("fft_edge", generate_fft_edge_vectors);
+
+    let fri_layer_decommit = timer.run("fri_layer_decommit", || {
+        generate_fri_layer_decommit_vectors(
+            streams.state("fri_layer_decommit"),
+            FRI_LAYER_DECOMMIT_VECTOR_COUNT,
+        )
+    });
+    let fri_layer_chain_decommit = timer.run("fri_layer_chain_decommit", || {
+        generate_fri_layer_chain_decommit_vectors(
+            streams.state("fri_layer_chain_decommit"),
+            FRI_LAYER_CHAIN_DECOMMIT_VECTOR_COUNT,
+        )
+    });
+    let fri_first_layer_decommit = timer.run("fri_first_layer_decommit", || {
+        generate_fri_first_layer_decommit_vectors(
+            streams.state("fri_first_layer_decommit"),
+            FRI_FIRST_LAYER_DECOMMIT_VECTOR_COUNT,
+        )
+    });
+    let pcs_preprocessed_queries = timer.run("pcs_preprocessed_queries", || {
+        generate_pcs_preprocessed_query_vectors(
+            streams.state("pcs_preprocessed_queries"),
+            PCS_PREPROCESSED_QUERY_VECTOR_COUNT,
+        )
+    });
+
+    let families = (schema_version >= FAMILIES_SCHEMA_VERSION)
+        .then(|| family_metadata(&timer.families, seed, family_seed_overrides));
+    let taxonomy = error_taxonomy();
+    let mut ids = VectorIds::new(schema_version >= VECTOR_ID_SCHEMA_VERSION);
+
+    let vectors = FieldVectors {
+        meta: Meta {
+            upstream_commit: UPSTREAM_COMMIT,
+            profile: profile.name,
+            sample_count,
+            schema_version,
+            seed,
+            seed_strategy: VECTOR_SEED_STRATEGY,
+        },
+        families,
+        error_taxonomy: (schema_version >= ERROR_TAXONOMY_SCHEMA_VERSION).then(|| taxonomy.clone()),
+        generation_stats: timer.families,
+        known_answer,
+        m31: ids.family("m31", m31),
+        cm31: ids.family("cm31", cm31),
+        qm31: ids.family("qm31", qm31),
+        qm31_structure: ids.family("qm31_structure", qm31_structure),
+        secure_column: ids.family("secure_column", secure_column),
+        circle_m31: ids.family("circle_m31", circle_m31),
+        circle_scalar_mul: ids.family("circle_scalar_mul", circle_scalar_mul),
+        fft_m31: ids.family("fft_m31", fft_m31),
+        fft_edge: ids.family("fft_edge", fft_edge),
+        blake3: ids.family("blake3", blake3),
+        blake2s: ids.family("blake2s", blake2s),
+        channel_mix_root: ids.family("channel_mix_root", channel_mix_root),
+        oods_draw: ids.family("oods_draw", oods_draw),
+        accumulation: ids.family("accumulation", accumulation),
+        merkle_avalanche: ids.family("merkle_avalanche", merkle_avalanche),
+        lookup_draw_v2: ids.family("lookup_draw_v2", lookup_draw_v2),
+        field_boundaries: ids.family("field_boundaries", field_boundaries),
+        pcs_quotients: ids.family("pcs_quotients", pcs_quotients),
+        pcs_quotients_shared_points: ids
+            .family("pcs_quotients_shared_points", pcs_quotients_shared_points),
+        denominator_inverses: ids.family("denominator_inverses", denominator_inverses),
+        samples_with_randomness: ids.family("samples_with_randomness", samples_with_randomness),
+        pcs_preprocessed_queries: ids.family("pcs_preprocessed_queries", pcs_preprocessed_queries),
+        fri_answers_errors: ids.family("fri_answers_errors", fri_answers_errors),
+        fri_folds: ids.family("fri_folds", fri_folds),
+        fri_layer_count: ids.family("fri_layer_count", fri_layer_count),
+        fri_fold_chain: ids.family("fri_fold_chain", fri_fold_chain),
+        fri_fold_ordering: ids.family("fri_fold_ordering", fri_fold_ordering),
+        fri_last_layer: ids.family("fri_last_layer", fri_last_layer),
+        line_poly_construction: ids.family("line_poly_construction", line_poly_construction),
+        fri_decommit: ids.family("fri_decommit", fri_decommit),
+        fri_layer_decommit: ids.family("fri_layer_decommit", fri_layer_decommit),
+        fri_layer_chain_decommit: ids.family("fri_layer_chain_decommit", fri_layer_chain_decommit),
+        fri_first_layer_decommit: ids.family("fri_first_layer_decommit", fri_first_layer_decommit),
+        proof_extract_oods: ids.family("proof_extract_oods", proof_extract_oods),
+        composition_sampled_order: ids
+            .family("composition_sampled_order", composition_sampled_order),
+        blowup_sizing: ids.family("blowup_sizing", blowup_sizing),
+        config_security: ids.family("config_security", config_security),
+        proof_sizes: ids.family("proof_sizes", proof_sizes),
+        prover_line: ids.family("prover_line", prover_line),
+        vcs_verifier: ids.family("vcs_verifier", vcs_verifier),
+        vcs_prover: ids.family("vcs_prover", vcs_prover),
+        vcs_lifted_verifier: ids.family("vcs_lifted_verifier", vcs_lifted_verifier),
+        vcs_lifted_error_variants: ids
+            .family("vcs_lifted_error_variants", vcs_lifted_error_variants),
+        vcs_lifted_prover: ids.family("vcs_lifted_prover", vcs_lifted_prover),
+        queried_rows_reshape: ids.family("queried_rows_reshape", queried_rows_reshape),
+        pcs_commit: ids.family("pcs_commit", pcs_commit),
+        e2e_tree_shapes: ids.family("e2e_tree_shapes", e2e_tree_shapes),
+        example_state_machine_trace: ids
+            .family("example_state_machine_trace", example_state_machine_trace),
+        example_state_machine_transitions: ids.family(
+            "example_state_machine_transitions",
+            example_state_machine_transitions,
+        ),
+        example_state_machine_claimed_sum: ids.family(
+            "example_state_machine_claimed_sum",
+            example_state_machine_claimed_sum,
+        ),
+        example_state_machine_lookup_draw: ids.family(
+            "example_state_machine_lookup_draw",
+            example_state_machine_lookup_draw,
+        ),
+        example_state_machine_statement: ids.family(
+            "example_state_machine_statement",
+            example_state_machine_statement,
+        ),
+        example_state_machine_components: ids.family(
+            "example_state_machine_components",
+            example_state_machine_components,
+        ),
+        example_xor_is_first: ids.family("example_xor_is_first", example_xor_is_first),
+        example_xor_is_step_with_offset: ids.family(
+            "example_xor_is_step_with_offset",
+            example_xor_is_step_with_offset,
+        ),
+        example_wide_fibonacci_trace: ids
+            .family("example_wide_fibonacci_trace", example_wide_fibonacci_trace),
+        example_plonk_trace: ids.family("example_plonk_trace", example_plonk_trace),
+        example_poseidon_trace: ids.family("example_poseidon_trace", example_poseidon_trace),
+        example_poseidon_rounds: ids.family("example_poseidon_rounds", example_poseidon_rounds),
+    };
+    assert_errors_in_taxonomy(&vectors, &taxonomy);
+    vectors
+}
+
+/// Every error-like string field, keyed by family. Names come from the `*_ERROR_NAMES` lists that
+/// sit next to the exhaustive name matches, so the taxonomy cannot drift from the generator.
+fn error_taxonomy() -> BTreeMap<&'static str, ErrorTaxonomyEntry> {
+    let entry =
+        |field, source, errors: &[&'static str], outcomes: &[&'static str]| ErrorTaxonomyEntry {
+            field,
+            source,
+            errors: errors.to_vec(),
+            outcomes: outcomes.to_vec(),
+        };
+    let vcs = Some("stwo::core::vcs::verifier::MerkleVerificationError");
+    let vcs_lifted = Some("stwo::core::vcs_lifted::verifier::MerkleVerificationError");
+    let fri_decommit = Some("FriDecommitError");
+    BTreeMap::from([
+        (
+            "vcs_verifier",
+            entry("expected", vcs, &MERKLE_ERROR_NAMES, &["ok", "Panic"]),
+        ),
+        (
+            "vcs_lifted_verifier",
+            entry(
+                "expected",
+                vcs_lifted,
+                &MERKLE_LIFTED_ERROR_NAMES,
+                &["ok", "Panic"],
+            ),
+        ),
+        (
+            "vcs_lifted_error_variants",
+            entry(
+                "probes.expected",
+                vcs_lifted,
+                &MERKLE_LIFTED_ERROR_NAMES,
+                &["ok", "Panic"],
+            ),
+        ),
+        (
+            "fri_decommit",
+            entry("expected", fri_decommit, &FRI_DECOMMIT_ERROR_NAMES, &["ok"]),
+        ),
+        (
+            "fri_layer_decommit",
+            entry("expected", fri_decommit, &FRI_DECOMMIT_ERROR_NAMES, &["ok"]),
+        ),
+        (
+            "fri_last_layer",
+            entry(
+                "expected",
+                Some("stwo::core::fri::FriVerificationError"),
+                &FRI_VERIFICATION_ERROR_NAMES,
+                &["ok"],
+            ),
+        ),
+        (
+            "fri_answers_errors",
+            entry(
+                "expected",
+                Some("stwo::core::verifier::VerificationError"),
+                &VERIFICATION_ERROR_NAMES,
+                &["ok", "Panic"],
+            ),
+        ),
+        (
+            "proof_extract_oods",
+            entry(
+                "expected_error",
+                Some("stwo::core::proof::InvalidOodsSampleStructure"),
+                &OODS_SAMPLE_ERROR_NAMES,
+                &["Panic"],
+            ),
+        ),
+        (
+            "line_poly_construction",
+            entry("expected", None, &[], &["ok", "Panic"]),
+        ),
+        (
+            "denominator_inverses",
+            entry("expected", None, &[], &["ok", "Panic"]),
+        ),
+    ])
+}
+
+/// Panics listing every error-like value a family emitted that its taxonomy entry does not name.
+fn assert_errors_in_taxonomy(
+    vectors: &FieldVectors,
+    taxonomy: &BTreeMap<&'static str, ErrorTaxonomyEntry>,
+) {
+    let mut emitted = Vec::<(&str, &str)>::new();
+    emitted.extend(
+        vectors
+            .vcs_verifier
+            .iter()
+            .map(|v| ("vcs_verifier", v.expected.as_str())),
     );
-    let example_state_machine_claimed_sum = generate_example_state_machine_claimed_sum_vectors(
-        state,
-        EXAMPLE_STATE_MACHINE_CLAIMED_SUM_VECTOR_COUNT,
+    emitted.extend(
+        vectors
+            .vcs_lifted_verifier
+            .iter()
+            .map(|v| ("vcs_lifted_verifier", v.expected.as_str())),
     );
-    let example_state_machine_lookup_draw = generate_example_state_machine_lookup_draw_vectors(
-        state,
-        EXAMPLE_STATE_MACHINE_LOOKUP_DRAW_VECTOR_COUNT,
+    emitted.extend(
+        vectors
+            .vcs_lifted_error_variants
+            .iter()
+            .flat_map(|v| &v.probes)
+            .map(|probe| ("vcs_lifted_error_variants", probe.expected.as_str())),
     );
-    let example_state_machine_statement = generate_example_state_machine_statement_vectors(
-        state,
-        EXAMPLE_STATE_MACHINE_STATEMENT_VECTOR_COUNT,
+    emitted.extend(
+        vectors
+            .fri_decommit
+            .iter()
+            .map(|v| ("fri_decommit", v.expected.as_str())),
     );
-    let example_xor_is_first =
-        generate_example_xor_is_first_vectors(state, EXAMPLE_XOR_IS_FIRST_VECTOR_COUNT);
-    let example_xor_is_step_with_offset = generate_example_xor_is_step_with_offset_vectors(
-        state,
-        EXAMPLE_XOR_IS_STEP_WITH_OFFSET_VECTOR_COUNT,
+    emitted.extend(
+        vectors
+            .fri_layer_decommit
+            .iter()
+            .map(|v| ("fri_layer_decommit", v.expected.as_str())),
     );
-    let example_wide_fibonacci_trace = generate_example_wide_fibonacci_trace_vectors(
-        state,
-        EXAMPLE_WIDE_FIBONACCI_TRACE_VECTOR_COUNT,
+    emitted.extend(
+        vectors
+            .fri_last_layer
+            .iter()
+            .map(|v| ("fri_last_layer", v.expected.as_str())),
+    );
+    emitted.extend(
+        vectors
+            .fri_answers_errors
+            .iter()
+            .map(|v| ("fri_answers_errors", v.expected.as_str())),
+    );
+    emitted.extend(
+        vectors
+            .proof_extract_oods
+            .iter()
+            .filter_map(|v| v.expected_error.as_deref())
+            .map(|error| ("proof_extract_oods", error)),
+    );
+    emitted.extend(
+        vectors
+            .line_poly_construction
+            .iter()
+            .map(|v| ("line_poly_construction", v.expected.as_str())),
+    );
+    emitted.extend(
+        vectors
+            .denominator_inverses
+            .iter()
+            .map(|v| ("denominator_inverses", v.expected.as_str())),
     );
-    let example_plonk_trace =
-        generate_example_plonk_trace_vectors(state, EXAMPLE_PLONK_TRACE_VECTOR_COUNT);
 
-    for _ in 0..BLAKE3_VECTOR_COUNT {
-        let data_len = next_u64(state) as usize % 96;
+    let mut unknown = emitted
+        .into_iter()
+        .filter(|(family, value)| {
+            let entry = taxonomy
+                .get(family)
+                .expect("every checked family has a taxonomy entry");
+            !entry.errors.contains(value) && !entry.outcomes.contains(value)
+        })
+        .map(|(family, value)| format!("{family}: {value}"))
+        .collect::<Vec<_>>();
+    unknown.sort_unstable();
+    unknown.dedup();
+    assert!(
+        unknown.is_empty(),
+        "vector values missing from the error taxonomy: {}",
+        unknown.join(", ")
+    );
+}
+
+/// Folds the generation phases into one entry per output family, each with the seed its stream
+/// started from (see `family_seed`).
+fn family_metadata(
+    stats: &[FamilyGenerationStats],
+    primary_seed: u64,
+    overrides: &BTreeMap<&'static str, u64>,
+) -> BTreeMap<&'static str, FamilyMeta> {
+    let mut families = BTreeMap::<&'static str, FamilyMeta>::new();
+    for phase in stats {
+        let family = FAMILY_EXTENSION_PHASES
+            .iter()
+            .find(|(extension, _)| *extension == phase.family)
+            .map_or(phase.family, |(_, family)| *family);
+        let seed = family_seed(primary_seed, overrides, family);
+        let entry = families.entry(family).or_insert_with(|| FamilyMeta {
+            count: 0,
+            seed,
+            generator: Vec::new(),
+        });
+        entry.count += phase.cases;
+        entry.generator.push(phase.family);
+    }
+    families
+}
+
+/// Seed of `family`'s stream: a `--family-seed` override, else its `FAMILY_SEEDS` entry (`None`
+/// for fixed families), else derived from the primary seed.
+fn family_seed(
+    primary_seed: u64,
+    overrides: &BTreeMap<&'static str, u64>,
+    family: &str,
+) -> Option<u64> {
+    if let Some(seed) = overrides.get(family) {
+        return Some(*seed);
+    }
+    FAMILY_SEEDS
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map_or_else(
+            || Some(derived_family_seed(primary_seed, family)),
+            |(_, seed)| *seed,
+        )
+}
+
+/// The first 8 bytes (little-endian) of Blake2s over the primary seed and the family name, so a
+/// family's stream does not depend on how much any other family draws. Never 0, on which
+/// xorshift would stall.
+fn derived_family_seed(primary_seed: u64, family: &str) -> u64 {
+    let mut data = primary_seed.to_le_bytes().to_vec();
+    data.extend_from_slice(family.as_bytes());
+    let hash = Blake2sHasher::hash(&data);
+    u64::from_le_bytes(hash.0[..8].try_into().expect("8-byte prefix")).max(1)
+}
+
+/// One xorshift state per family, started from `family_seed` on first use. Extension phases ask
+/// for their family's state and so continue its stream.
+struct FamilyStreams<'a> {
+    primary_seed: u64,
+    overrides: &'a BTreeMap<&'static str, u64>,
+    states: BTreeMap<&'static str, u64>,
+}
+
+impl<'a> FamilyStreams<'a> {
+    fn new(primary_seed: u64, overrides: &'a BTreeMap<&'static str, u64>) -> Self {
+        Self {
+            primary_seed,
+            overrides,
+            states: BTreeMap::new(),
+        }
+    }
+
+    fn state(&mut self, family: &'static str) -> &mut u64 {
+        let (primary_seed, overrides) = (self.primary_seed, self.overrides);
+        self.states.entry(family).or_insert_with(|| {
+            family_seed(primary_seed, overrides, family)
+                .unwrap_or_else(|| panic!("fixed family {family} has no seed stream"))
+        })
+    }
+}
+
+/// Hand-computed answers for the core field ops, a butterfly pair and a 4-leaf Merkle tree.
+/// Upstream is evaluated on each entry and generation panics on any disagreement, so a
+/// regression in upstream cannot silently flow into the vectors.
+///
+/// Notation: P = 2^31 - 1, CM31 = M31[i] / (i^2 + 1), QM31 = CM31[u] / (u^2 - (2 + i)); QM31
+/// limbs are listed as [a, b, c, d] for (a + bi) + (c + di)u.
+fn generate_known_answer_vectors() -> KnownAnswerVectors {
+    // 2^30 = (P + 1) / 2, so 2 * 2^30 = P + 1 = 1.
+    const INV2: u32 = 1 << 30;
+    // P = 2 (mod 5), so 2P + 1 = 0 (mod 5) and (2P + 1) / 5 = 858993459 is 1/5.
+    const INV5: u32 = 858_993_459;
+
+    let field_ops = vec![
+        // (P - 1) + 2 = P + 1 = 1.
+        known_answer_field(
+            "m31_add_wraps",
+            "m31_add",
+            vec![vec![P - 1], vec![2]],
+            vec![1],
+        ),
+        // 2^16 * 2^16 = 2^32 = 2 * 2^31 = 2 * 1.
+        known_answer_field(
+            "m31_mul_2_pow_32",
+            "m31_mul",
+            vec![vec![1 << 16], vec![1 << 16]],
+            vec![2],
+        ),
+        // (-1) * (-1) = 1.
+        known_answer_field(
+            "m31_mul_minus_one_squared",
+            "m31_mul",
+            vec![vec![P - 1], vec![P - 1]],
+            vec![1],
+        ),
+        known_answer_field("m31_inverse_two", "m31_inverse", vec![vec![2]], vec![INV2]),
+        known_answer_field("m31_inverse_five", "m31_inverse", vec![vec![5]], vec![INV5]),
+        // (-1 + i) + (2 - i) = 1.
+        known_answer_field(
+            "cm31_add_cancels_imaginary",
+            "cm31_add",
+            vec![vec![P - 1, 1], vec![2, P - 1]],
+            vec![1, 0],
+        ),
+        // (1 + 2i)(3 + 4i) = 3 + 4i + 6i + 8i^2 = -5 + 10i.
+        known_answer_field(
+            "cm31_mul_small",
+            "cm31_mul",
+            vec![vec![1, 2], vec![3, 4]],
+            vec![P - 5, 10],
+        ),
+        // (1 + i)(1 - i) = 2, so 1 / (1 + i) = (1 - i) / 2 = 2^30 - 2^30 i.
+        known_answer_field(
+            "cm31_inverse_one_plus_i",
+            "cm31_inverse",
+            vec![vec![1, 1]],
+            vec![INV2, P - INV2],
+        ),
+        // (1 + u) + (1 - u) = 2.
+        known_answer_field(
+            "qm31_add_cancels_u",
+            "qm31_add",
+            vec![vec![1, 0, 1, 0], vec![1, 0, P - 1, 0]],
+            vec![2, 0, 0, 0],
+        ),
+        // u * u = 2 + i.
+        known_answer_field(
+            "qm31_mul_u_squared",
+            "qm31_mul",
+            vec![vec![0, 0, 1, 0], vec![0, 0, 1, 0]],
+            vec![2, 1, 0, 0],
+        ),
+        // (1 + u)(1 - u) = 1 - u^2 = 1 - (2 + i) = -1 - i.
+        known_answer_field(
+            "qm31_mul_difference_of_squares",
+            "qm31_mul",
+            vec![vec![1, 0, 1, 0], vec![1, 0, P - 1, 0]],
+            vec![P - 1, P - 1, 0, 0],
+        ),
+        // 1 / u = u / u^2 = u / (2 + i) = u (2 - i) / 5 = (2/5 - i/5) u.
+        known_answer_field(
+            "qm31_inverse_u",
+            "qm31_inverse",
+            vec![vec![0, 0, 1, 0]],
+            vec![0, 0, 2 * INV5, P - INV5],
+        ),
+        // butterfly(v0, v1, t) = (v0 + v1 t, v0 - v1 t): (3 + 5 * 2, 3 - 5 * 2) = (13, -7).
+        known_answer_field(
+            "butterfly_small",
+            "m31_butterfly",
+            vec![vec![3], vec![5], vec![2]],
+            vec![13, P - 7],
+        ),
+        // ibutterfly(v0, v1, t') = (v0 + v1, (v0 - v1) t'): (13 - 7, 20 / 2) = (6, 10), i.e. the
+        // butterfly above inverted up to the factor 2 the inverse FFT normalizes away.
+        known_answer_field(
+            "ibutterfly_small",
+            "m31_ibutterfly",
+            vec![vec![13], vec![P - 7], vec![INV2]],
+            vec![6, 10],
+        ),
+    ];
+    for vector in &field_ops {
+        let upstream = evaluate_known_answer_field_op(vector.op, &vector.inputs);
+        assert_eq!(
+            upstream, vector.expected,
+            "known-answer mismatch for {}: upstream disagrees with the hand-computed value",
+            vector.name
+        );
+    }
+
+    // Computed independently with Python's hashlib.blake2s (32-byte digest): leaves are
+    // blake2s(b"leaf" padded to 64 bytes || value as u32 LE), parents are
+    // blake2s(b"node" padded to 64 bytes || left || right).
+    let merkle = vec![KnownAnswerMerkleVector {
+        name: "four_leaves_1_2_3_4",
+        leaves: vec![1, 2, 3, 4],
+        root: hex_to_hash("c54f0d3f69154477b5a6d25f3a9911714eaf7a81731458039723a20c902e8472"),
+    }];
+    for vector in &merkle {
+        let mut layer = vector
+            .leaves
+            .iter()
+            .map(|&leaf| VcsMerkleHasher::hash_node(None, &[M31::from_u32_unchecked(leaf)]))
+            .collect::<Vec<_>>();
+        while layer.len() > 1 {
+            layer = layer
+                .chunks_exact(2)
+                .map(|pair| VcsMerkleHasher::hash_node(Some((pair[0], pair[1])), &[]))
+                .collect();
+        }
+        assert_eq!(
+            encode_hash(layer[0]),
+            vector.root,
+            "known-answer mismatch for {}: upstream Merkle root disagrees with the reference",
+            vector.name
+        );
+    }
+
+    KnownAnswerVectors { field_ops, merkle }
+}
+
+fn known_answer_field(
+    name: &'static str,
+    op: &'static str,
+    inputs: Vec<Vec<u32>>,
+    expected: Vec<u32>,
+) -> KnownAnswerFieldVector {
+    KnownAnswerFieldVector {
+        name,
+        op,
+        inputs,
+        expected,
+    }
+}
+
+fn evaluate_known_answer_field_op(op: &str, inputs: &[Vec<u32>]) -> Vec<u32> {
+    let m31 = |limbs: &[u32]| M31::from_u32_unchecked(limbs[0]);
+    let cm31 = |limbs: &[u32]| CM31::from_u32_unchecked(limbs[0], limbs[1]);
+    let qm31 = |limbs: &[u32]| QM31::from_u32_unchecked(limbs[0], limbs[1], limbs[2], limbs[3]);
+    match op {
+        "m31_add" => vec![encode_m31(m31(&inputs[0]) + m31(&inputs[1]))],
+        "m31_mul" => vec![encode_m31(m31(&inputs[0]) * m31(&inputs[1]))],
+        "m31_inverse" => vec![encode_m31(m31(&inputs[0]).inverse())],
+        "cm31_add" => encode_cm31(cm31(&inputs[0]) + cm31(&inputs[1])).to_vec(),
+        "cm31_mul" => encode_cm31(cm31(&inputs[0]) * cm31(&inputs[1])).to_vec(),
+        "cm31_inverse" => encode_cm31(cm31(&inputs[0]).inverse()).to_vec(),
+        "qm31_add" => encode_qm31(qm31(&inputs[0]) + qm31(&inputs[1])).to_vec(),
+        "qm31_mul" => encode_qm31(qm31(&inputs[0]) * qm31(&inputs[1])).to_vec(),
+        "qm31_inverse" => encode_qm31(qm31(&inputs[0]).inverse()).to_vec(),
+        "m31_butterfly" | "m31_ibutterfly" => {
+            let (mut v0, mut v1) = (m31(&inputs[0]), m31(&inputs[1]));
+            if op == "m31_butterfly" {
+                butterfly(&mut v0, &mut v1, m31(&inputs[2]));
+            } else {
+                ibutterfly(&mut v0, &mut v1, m31(&inputs[2]));
+            }
+            vec![encode_m31(v0), encode_m31(v1)]
+        }
+        other => panic!("unknown known-answer op {other}"),
+    }
+}
+
+fn hex_to_hash(hex: &str) -> [u8; 32] {
+    std::array::from_fn(|i| {
+        u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).expect("known-answer hash is valid hex")
+    })
+}
+
+fn generate_fft_edge_vectors() -> Vec<FftEdgeVector> {
+    FFT_EDGE_CASES
+        .iter()
+        .map(|&(case, [a, b], twid)| {
+            let (a, b, twid) = (M31::from(a), M31::from(b), M31::from(twid));
+            let mut v0 = a;
+            let mut v1 = b;
+            butterfly(&mut v0, &mut v1, twid);
+            let butterfly_out = [v0, v1];
+
+            let itwid = std::panic::catch_unwind(|| twid.inverse()).ok();
+            let ibutterfly_out = itwid.map(|itwid| {
+                ibutterfly(&mut v0, &mut v1, itwid);
+                [encode_m31(v0), encode_m31(v1)]
+            });
+            let undefined = if itwid.is_some() {
+                Vec::new()
+            } else {
+                vec!["itwid".to_string(), "ibutterfly".to_string()]
+            };
+
+            let mut w0 = a;
+            let mut w1 = b;
+            ibutterfly(&mut w0, &mut w1, twid);
+
+            FftEdgeVector {
+                case: case.to_string(),
+                a: encode_m31(a),
+                b: encode_m31(b),
+                twid: encode_m31(twid),
+                butterfly: butterfly_out.map(encode_m31),
+                itwid: itwid.map(encode_m31),
+                ibutterfly: ibutterfly_out,
+                ibutterfly_with_twid: [encode_m31(w0), encode_m31(w1)],
+                undefined,
+            }
+        })
+        .collect()
+}
+
+fn generate_field_boundaries_vectors() -> Vec<FieldBoundaryVector> {
+    let mut out = Vec::with_capacity(FIELD_BOUNDARIES_VECTOR_COUNT);
+    let m31: Vec<M31> = FIELD_BOUNDARY_M31
+        .iter()
+        .map(|&x| M31::from_u32_unchecked(x))
+        .collect();
+    push_field_boundary_pairs(&mut out, "m31", &m31, M31::from_u32_unchecked(0), |x| {
+        vec![encode_m31(x)]
+    });
+    let cm31: Vec<CM31> = FIELD_BOUNDARY_CM31
+        .iter()
+        .map(|&[a, b]| CM31::from_u32_unchecked(a, b))
+        .collect();
+    push_field_boundary_pairs(
+        &mut out,
+        "cm31",
+        &cm31,
+        CM31::from_u32_unchecked(0, 0),
+        |x| encode_cm31(x).to_vec(),
+    );
+    let qm31: Vec<QM31> = FIELD_BOUNDARY_QM31
+        .iter()
+        .map(|&[a, b, c, d]| QM31::from_u32_unchecked(a, b, c, d))
+        .collect();
+    push_field_boundary_pairs(
+        &mut out,
+        "qm31",
+        &qm31,
+        QM31::from_u32_unchecked(0, 0, 0, 0),
+        |x| encode_qm31(x).to_vec(),
+    );
+    assert_eq!(out.len(), FIELD_BOUNDARIES_VECTOR_COUNT);
+    out
+}
+
+/// Every ordered pair of `values`, including `a == b`. Inverses are checked to be involutive and
+/// quotients against multiplication before they are written.
+fn push_field_boundary_pairs<F>(
+    out: &mut Vec<FieldBoundaryVector>,
+    field: &str,
+    values: &[F],
+    zero: F,
+    encode: impl Fn(F) -> Vec<u32>,
+) where
+    F: Copy
+        + PartialEq
+        + std::fmt::Debug
+        + std::ops::Add<Output = F>
+        + std::ops::Sub<Output = F>
+        + std::ops::Mul<Output = F>
+        + std::ops::Div<Output = F>
+        + FieldExpOps,
+{
+    for &a in values {
+        for &b in values {
+            let mut undefined = Vec::new();
+            let inv_a = if a == zero {
+                undefined.push("inv_a".to_string());
+                None
+            } else {
+                let inv = a.inverse();
+                assert_eq!(inv.inverse(), a, "{field} inverse of {a:?}");
+                Some(inv)
+            };
+            let div_ab = if b == zero {
+                undefined.push("div_ab".to_string());
+                None
+            } else {
+                let quotient = a / b;
+                assert_eq!(quotient * b, a, "{field} {a:?} / {b:?}");
+                Some(quotient)
+            };
+            assert_eq!((a + b) - b, a, "{field} {a:?} + {b:?}");
+            out.push(FieldBoundaryVector {
+                field: field.to_string(),
+                a: encode(a),
+                b: encode(b),
+                add: encode(a + b),
+                sub: encode(a - b),
+                mul: encode(a * b),
+                inv_a: inv_a.map(&encode),
+                div_ab: div_ab.map(&encode),
+                undefined,
+            });
+        }
+    }
+}
+
+fn generate_qm31_structure_vectors(state: &mut u64, count: usize) -> Vec<QM31StructureVector> {
+    let mut out = Vec::with_capacity(count + QM31_STRUCTURE_SELF_CONJUGATE_COUNT);
+    for i in 0..(count + QM31_STRUCTURE_SELF_CONJUGATE_COUNT) {
+        let (case, value) = if i < count {
+            ("random", sample_qm31(state, false))
+        } else {
+            // Values in the CM31 subfield are fixed by complex conjugation.
+            let a = sample_cm31(state, false);
+            ("self_conjugate", QM31(a, CM31(M31::from(0), M31::from(0))))
+        };
+        let conjugate = value.complex_conjugate();
+        if case == "self_conjugate" {
+            assert_eq!(
+                conjugate, value,
+                "self-conjugate qm31 case must be conjugation fixed"
+            );
+        }
+
+        let m31_array = value.to_m31_array();
+        let from_m31_array = QM31::from_m31_array(m31_array);
+        assert_eq!(
+            from_m31_array, value,
+            "qm31 m31-array round trip must be exact"
+        );
+
+        let partial_evals: [QM31; 4] = std::array::from_fn(|_| sample_qm31(state, false));
+        let combined = QM31::from_partial_evals(partial_evals);
+
+        out.push(QM31StructureVector {
+            case: case.to_string(),
+            value: encode_qm31(value),
+            complex_conjugate: encode_qm31(conjugate),
+            m31_array: m31_array.map(encode_m31),
+            from_m31_array: encode_qm31(from_m31_array),
+            partial_evals: partial_evals.map(encode_qm31),
+            from_partial_evals: encode_qm31(combined),
+        });
+    }
+    out
+}
+
+fn generate_secure_column_vectors(state: &mut u64) -> Vec<SecureColumnVector> {
+    SECURE_COLUMN_CASES
+        .iter()
+        .map(|&(case, len)| {
+            let column = (0..len)
+                .map(|_| sample_qm31(state, false))
+                .collect::<Vec<_>>();
+            let coordinates = secure_column_coordinates(&column);
+            let reconstructed = (0..len)
+                .map(|row| {
+                    QM31::from_m31_array(std::array::from_fn(|coord| coordinates[coord][row]))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                reconstructed, column,
+                "secure column coordinate round trip must be exact"
+            );
+            let leaf_hashes = secure_column_leaf_hashes(&coordinates);
+            if len.is_power_of_two() && len >= 2 {
+                let committed = build_vcs_lifted_leaves(&coordinates.iter().collect::<Vec<_>>());
+                assert_eq!(
+                    leaf_hashes, committed,
+                    "secure column leaf hashes must match the lifted commitment leaves"
+                );
+            }
+
+            SecureColumnVector {
+                case: case.to_string(),
+                column: column.iter().copied().map(encode_qm31).collect(),
+                coordinates: coordinates.map(|coords| coords.into_iter().map(encode_m31).collect()),
+                reconstructed: reconstructed.into_iter().map(encode_qm31).collect(),
+                leaf_hashes: leaf_hashes.into_iter().map(encode_hash).collect(),
+            }
+        })
+        .collect()
+}
+
+/// The four coordinate columns of `column`, as `compute_fri_layer_decommit_outputs` commits them.
+fn secure_column_coordinates(column: &[QM31]) -> [Vec<M31>; 4] {
+    let mut coordinates: [Vec<M31>; 4] = std::array::from_fn(|_| Vec::with_capacity(column.len()));
+    for value in column {
+        for (coord, value) in value.to_m31_array().into_iter().enumerate() {
+            coordinates[coord].push(value);
+        }
+    }
+    coordinates
+}
+
+/// Lifted-hasher leaf of every row over equal-length coordinate columns, absorbing one column at a
+/// time like `build_vcs_lifted_leaves`; defined for any length, including odd ones.
+fn secure_column_leaf_hashes(coordinates: &[Vec<M31>; 4]) -> Vec<Blake2sHash> {
+    (0..coordinates[0].len())
+        .map(|row| {
+            let mut hasher = LiftedMerkleHasher::default_with_initial_state();
+            for column in coordinates {
+                hasher.update_leaf(&[column[row]]);
+            }
+            hasher.finalize()
+        })
+        .collect()
+}
+
+fn generate_blake2s_vectors(state: &mut u64, count: usize) -> Vec<Blake2sVector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let data_len = next_u64(state) as usize % 97;
         let mut data = vec![0u8; data_len];
         fill_bytes(state, &mut data);
-        let hash = Blake3Hasher::hash(&data);
+        let hash = Blake2sHasher::hash(&data);
+
+        let n_values = next_u64(state) as usize % 9;
+        let node_values = (0..n_values)
+            .map(|_| sample_m31(state, false))
+            .collect::<Vec<_>>();
+        let left = sample_hash(state);
+        let right = sample_hash(state);
+        let hash_node_with_children = VcsMerkleHasher::hash_node(Some((left, right)), &node_values);
+        let hash_node_leaf = VcsMerkleHasher::hash_node(None, &node_values);
+
+        let n_updates = 1 + next_u64(state) as usize % 4;
+        let mut hasher = LiftedMerkleHasher::default_with_initial_state();
+        let mut lifted_leaf_updates = Vec::with_capacity(n_updates);
+        let mut lifted_leaf_digests = Vec::with_capacity(n_updates + 1);
+        lifted_leaf_digests.push(encode_hash(hasher.clone().finalize()));
+        for _ in 0..n_updates {
+            let update_len = next_u64(state) as usize % 9;
+            let update = (0..update_len)
+                .map(|_| sample_m31(state, false))
+                .collect::<Vec<_>>();
+            hasher.update_leaf(&update);
+            lifted_leaf_digests.push(encode_hash(hasher.clone().finalize()));
+            lifted_leaf_updates.push(update.into_iter().map(encode_m31).collect());
+        }
+
+        out.push(Blake2sVector {
+            data,
+            hash: encode_hash(hash),
+            node_values: node_values.into_iter().map(encode_m31).collect(),
+            left: encode_hash(left),
+            right: encode_hash(right),
+            hash_node_with_children: encode_hash(hash_node_with_children),
+            hash_node_leaf: encode_hash(hash_node_leaf),
+            lifted_leaf_updates,
+            lifted_leaf_digests,
+        });
+    }
+    out
+}
+
+/// Mixes commitment roots into a prefixed channel exactly as `CommitmentSchemeVerifier` does
+/// (`MerkleChannel::mix_root`), alongside the digests that plausible-but-wrong conventions produce.
+fn generate_channel_mix_root_vectors(state: &mut u64, count: usize) -> Vec<ChannelMixRootVector> {
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let prefix_mix_u64 = next_u64(state);
+        let n_u32s = next_u64(state) as usize % 5;
+        let prefix_mix_u32s = (0..n_u32s)
+            .map(|_| next_u64(state) as u32)
+            .collect::<Vec<_>>();
+        let mut channel = Blake2sChannel::default();
+        channel.mix_u64(prefix_mix_u64);
+        channel.mix_u32s(&prefix_mix_u32s);
+        let digest_before = channel.digest();
+
+        let (root_pattern, root) = match i % 5 {
+            0 => ("all_zero", Blake2sHash([0u8; 32])),
+            1 => ("all_ones", Blake2sHash([0xffu8; 32])),
+            2 => (
+                "incrementing",
+                Blake2sHash(std::array::from_fn(|j| j as u8)),
+            ),
+            3 => {
+                let mut bytes = [0u8; 32];
+                let bit = next_u64(state) as usize % 256;
+                bytes[bit / 8] = 1 << (bit % 8);
+                ("single_bit", Blake2sHash(bytes))
+            }
+            _ => ("random", sample_hash(state)),
+        };
+
+        let mut mixed = channel.clone();
+        Blake2sMerkleChannel::mix_root(&mut mixed, root);
+        let digest_after = mixed.digest();
+        let draw_secure_felt = mixed.draw_secure_felt();
+
+        let root_words_le = root
+            .0
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().expect("4-byte chunk")))
+            .collect::<Vec<_>>();
+        let root_words_be = root
+            .0
+            .chunks_exact(4)
+            .map(|chunk| u32::from_be_bytes(chunk.try_into().expect("4-byte chunk")))
+            .collect::<Vec<_>>();
+        let mut wrong_variant_digests = BTreeMap::new();
+        let mut u32s_le = channel.clone();
+        u32s_le.mix_u32s(&root_words_le);
+        wrong_variant_digests.insert("mix_u32s_le", encode_hash(u32s_le.digest()));
+        let mut u32s_be = channel.clone();
+        u32s_be.mix_u32s(&root_words_be);
+        wrong_variant_digests.insert("mix_u32s_be", encode_hash(u32s_be.digest()));
+        wrong_variant_digests.insert(
+            "raw_bytes_root_then_digest",
+            encode_hash(Blake2sHasher::concat_and_hash(&root, &digest_before)),
+        );
+        wrong_variant_digests.insert(
+            "raw_bytes_root_only",
+            encode_hash(Blake2sHasher::hash(&root.0)),
+        );
+
+        out.push(ChannelMixRootVector {
+            root_pattern: root_pattern.to_string(),
+            prefix_mix_u64,
+            prefix_mix_u32s,
+            digest_before: encode_hash(digest_before),
+            root: encode_hash(root),
+            digest_after: encode_hash(digest_after),
+            draw_secure_felt: encode_qm31(draw_secure_felt),
+            wrong_variant_digests,
+        });
+    }
+    out
+}
+
+/// Every vector starts with a `mix_u64` so no two share a channel state; the remaining mixes are
+/// drawn from `mix_u32s`, `mix_felts` and `MerkleChannel::mix_root`.
+fn generate_oods_draw_vectors(state: &mut u64, count: usize) -> Vec<OodsDrawVector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut channel = Blake2sChannel::default();
+        let n_mixes = 1 + (next_u64(state) as usize) % OODS_DRAW_MAX_MIXES;
+        let mut mixes = Vec::with_capacity(n_mixes);
+        for i in 0..n_mixes {
+            let mut mix = ChannelMixVector {
+                op: String::new(),
+                value_u64: None,
+                values_u32: None,
+                felts: None,
+                root: None,
+            };
+            let op = if i == 0 { 0 } else { next_u64(state) % 4 };
+            match op {
+                0 => {
+                    let value = next_u64(state);
+                    channel.mix_u64(value);
+                    mix.op = "mix_u64".to_string();
+                    mix.value_u64 = Some(value);
+                }
+                1 => {
+                    let values = (0..next_u64(state) % 5)
+                        .map(|_| next_u64(state) as u32)
+                        .collect::<Vec<_>>();
+                    channel.mix_u32s(&values);
+                    mix.op = "mix_u32s".to_string();
+                    mix.values_u32 = Some(values);
+                }
+                2 => {
+                    let felts = (0..1 + next_u64(state) % 3)
+                        .map(|_| sample_qm31(state, false))
+                        .collect::<Vec<_>>();
+                    channel.mix_felts(&felts);
+                    mix.op = "mix_felts".to_string();
+                    mix.felts = Some(felts.into_iter().map(encode_qm31).collect());
+                }
+                _ => {
+                    let root = sample_hash(state);
+                    Blake2sMerkleChannel::mix_root(&mut channel, root);
+                    mix.op = "mix_root".to_string();
+                    mix.root = Some(encode_hash(root));
+                }
+            }
+            mixes.push(mix);
+        }
+        let digest_before = channel.digest();
+
+        let n_draws = 1 + (next_u64(state) as usize) % OODS_DRAW_MAX_DRAWS;
+        let draws = (0..n_draws)
+            .map(|_| {
+                let t = channel.clone().draw_secure_felt();
+                let point = CirclePoint::<QM31>::get_random_point(&mut channel);
+                OodsDrawStepVector {
+                    t: encode_qm31(t),
+                    point: encode_secure_circle_point(point),
+                    digest_after: encode_hash(channel.digest()),
+                }
+            })
+            .collect();
+
+        out.push(OodsDrawVector {
+            mixes,
+            digest_before: encode_hash(digest_before),
+            draws,
+        });
+    }
+    out
+}
+
+fn generate_accumulation_vectors(state: &mut u64, count: usize) -> Vec<AccumulationVector> {
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let random_coeff = sample_qm31(state, true);
+        let n_evaluations = match i % 3 {
+            0 => 0,
+            1 => 1,
+            _ => 2 + (next_u64(state) as usize) % (ACCUMULATION_MAX_EVALUATIONS - 1),
+        };
+        let evaluations = (0..n_evaluations)
+            .map(|_| sample_qm31(state, false))
+            .collect::<Vec<_>>();
+        let mut point_accumulator = PointEvaluationAccumulator::new(random_coeff);
+        for &evaluation in &evaluations {
+            point_accumulator.accumulate(evaluation);
+        }
+
+        let max_log_size = 2 + (next_u64(state) as u32) % 4;
+        let (requests, calls, total_columns) = match i % 3 {
+            0 => accumulate_domain_columns(state, random_coeff, max_log_size, [(max_log_size, 0)]),
+            1 => accumulate_domain_columns(state, random_coeff, max_log_size, [(max_log_size, 1)]),
+            _ => {
+                let small_log_size = 1 + (next_u64(state) as u32) % (max_log_size - 1);
+                let large_cols = 1 + (next_u64(state) as usize) % 3;
+                let small_cols = 1 + (next_u64(state) as usize) % 3;
+                accumulate_domain_columns(
+                    state,
+                    random_coeff,
+                    max_log_size,
+                    [(max_log_size, large_cols), (small_log_size, small_cols)],
+                )
+            }
+        };
+
+        out.push(AccumulationVector {
+            random_coeff: encode_qm31(random_coeff),
+            evaluations: evaluations.into_iter().map(encode_qm31).collect(),
+            accumulation: encode_qm31(point_accumulator.finalize()),
+            max_log_size,
+            total_columns,
+            requests,
+            calls,
+        });
+    }
+    out
+}
+
+/// Runs one `DomainEvaluationAccumulator::columns` call over `requests` (distinct log sizes) with
+/// up to two spare coefficients left unclaimed, then a random batch of `accumulate` calls.
+fn accumulate_domain_columns<const N: usize>(
+    state: &mut u64,
+    random_coeff: QM31,
+    max_log_size: u32,
+    requests: [(u32, usize); N],
+) -> (
+    Vec<AccumulationColumnVector>,
+    Vec<AccumulationCallVector>,
+    usize,
+) {
+    let total_columns =
+        requests.iter().map(|(_, n_cols)| n_cols).sum::<usize>() + (next_u64(state) as usize) % 3;
+    let mut accumulator =
+        DomainEvaluationAccumulator::<CpuBackend>::new(random_coeff, max_log_size, total_columns);
+    let mut columns = accumulator.columns(requests);
+
+    let targets = (0..N).filter(|&c| requests[c].1 > 0).collect::<Vec<_>>();
+    let n_calls = if targets.is_empty() {
+        0
+    } else {
+        1 + (next_u64(state) as usize) % ACCUMULATION_MAX_CALLS
+    };
+    let mut calls = Vec::with_capacity(n_calls);
+    for _ in 0..n_calls {
+        let column = targets[(next_u64(state) as usize) % targets.len()];
+        let (log_size, n_cols) = requests[column];
+        let index = (next_u64(state) as usize) % (1 << log_size);
+        let coeff_index = (next_u64(state) as usize) % n_cols;
+        let evaluation = sample_qm31(state, false);
+        let coeff = columns[column].random_coeff_powers[coeff_index];
+        columns[column].accumulate(index, evaluation * coeff);
+        calls.push(AccumulationCallVector {
+            column,
+            index,
+            coeff_index,
+            evaluation: encode_qm31(evaluation),
+        });
+    }
+
+    let requests = requests
+        .iter()
+        .zip(&columns)
+        .map(|(&(log_size, n_cols), column)| AccumulationColumnVector {
+            log_size,
+            n_cols,
+            random_coeff_powers: column
+                .random_coeff_powers
+                .iter()
+                .copied()
+                .map(encode_qm31)
+                .collect(),
+            values: (0..1 << log_size)
+                .map(|row| encode_qm31(column.col.at(row)))
+                .collect(),
+        })
+        .collect();
+    (requests, calls, total_columns)
+}
+
+/// Schemes alternate. Each vector flips bit 30 somewhere, a bit in 16..31 of the last column, and
+/// a bit of the final leaf (the last row of a largest column), then a few random bits.
+fn generate_merkle_avalanche_vectors(state: &mut u64, count: usize) -> Vec<MerkleAvalancheVector> {
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let scheme = MERKLE_AVALANCHE_SCHEMES[i % MERKLE_AVALANCHE_SCHEMES.len()];
+        let n_columns = 2 + (next_u64(state) as usize) % 3;
+        let column_log_sizes = (0..n_columns)
+            .map(|_| 1 + (next_u64(state) as u32) % MERKLE_AVALANCHE_MAX_COLUMN_LOG_SIZE)
+            .collect::<Vec<_>>();
+        let mut columns = column_log_sizes
+            .iter()
+            .map(|&log_size| {
+                (0..1usize << log_size)
+                    .map(|_| sample_m31(state, false))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let root = merkle_avalanche_root(scheme, &column_log_sizes, &columns);
 
-        let mut left_data = vec![0u8; next_u64(state) as usize % 64];
-        fill_bytes(state, &mut left_data);
-        let mut right_data = vec![0u8; next_u64(state) as usize % 64];
-        fill_bytes(state, &mut right_data);
-        let left = Blake3Hasher::hash(&left_data);
-        let right = Blake3Hasher::hash(&right_data);
-        let concat_hash = Blake3Hasher::concat_and_hash(&left, &right);
+        let max_log_size = *column_log_sizes.iter().max().expect("at least one column");
+        let largest = column_log_sizes
+            .iter()
+            .position(|&log_size| log_size == max_log_size)
+            .expect("largest column");
+        let random_index = |state: &mut u64, column: usize| {
+            (next_u64(state) as usize) % (1 << column_log_sizes[column])
+        };
+        let mut targets = Vec::with_capacity(3 + MERKLE_AVALANCHE_RANDOM_PERTURBATIONS);
+        let column = (next_u64(state) as usize) % n_columns;
+        targets.push((column, random_index(state, column), 30));
+        let last = n_columns - 1;
+        targets.push((
+            last,
+            random_index(state, last),
+            16 + (next_u64(state) as u32) % 15,
+        ));
+        targets.push((
+            largest,
+            (1 << max_log_size) - 1,
+            (next_u64(state) as u32) % 31,
+        ));
+        for _ in 0..MERKLE_AVALANCHE_RANDOM_PERTURBATIONS {
+            let column = (next_u64(state) as usize) % n_columns;
+            targets.push((
+                column,
+                random_index(state, column),
+                (next_u64(state) as u32) % 31,
+            ));
+        }
 
-        blake3.push(Blake3Vector {
-            data,
-            hash: encode_blake3_hash(hash),
-            left: encode_blake3_hash(left),
-            right: encode_blake3_hash(right),
-            concat_hash: encode_blake3_hash(concat_hash),
+        let perturbations = targets
+            .into_iter()
+            .map(|(column, index, bit)| {
+                let original = columns[column][index];
+                let flipped = original.0 ^ (1 << bit);
+                // All 31 bits set is P itself, which is not a canonical M31; flip the next bit
+                // as well so the value stays in range and still differs from the original.
+                let flipped = if flipped == P {
+                    flipped ^ (1 << ((bit + 1) % 31))
+                } else {
+                    flipped
+                };
+                columns[column][index] = M31::from_u32_unchecked(flipped);
+                let perturbed_root = merkle_avalanche_root(scheme, &column_log_sizes, &columns);
+                columns[column][index] = original;
+                let root_changed = perturbed_root != root;
+                assert!(
+                    root_changed,
+                    "{scheme}: flipping bit {bit} of column {column} row {index} kept the root"
+                );
+                MerklePerturbationVector {
+                    column,
+                    index,
+                    bit,
+                    value: flipped,
+                    root: encode_hash(perturbed_root),
+                    root_changed,
+                }
+            })
+            .collect();
+
+        out.push(MerkleAvalancheVector {
+            scheme: scheme.to_string(),
+            column_log_sizes,
+            columns: columns
+                .into_iter()
+                .map(|column| column.into_iter().map(encode_m31).collect())
+                .collect(),
+            root: encode_hash(root),
+            perturbations,
         });
     }
+    out
+}
 
-    let mut fri_layer_state = FRI_LAYER_DECOMMIT_SEED;
-    let fri_layer_decommit =
-        generate_fri_layer_decommit_vectors(&mut fri_layer_state, FRI_LAYER_DECOMMIT_VECTOR_COUNT);
-    let mut pcs_preprocessed_query_state = PCS_PREPROCESSED_QUERY_SEED;
-    let pcs_preprocessed_queries = generate_pcs_preprocessed_query_vectors(
-        &mut pcs_preprocessed_query_state,
-        PCS_PREPROCESSED_QUERY_VECTOR_COUNT,
-    );
-
-    FieldVectors {
-        meta: Meta {
-            upstream_commit: UPSTREAM_COMMIT,
-            sample_count,
-            schema_version: VECTOR_SCHEMA_VERSION,
-            seed: VECTOR_SEED,
-            seed_strategy: VECTOR_SEED_STRATEGY,
-        },
-        m31,
-        cm31,
-        qm31,
-        circle_m31,
-        fft_m31,
-        blake3,
-        pcs_quotients,
-        pcs_preprocessed_queries,
-        fri_folds,
-        fri_decommit,
-        fri_layer_decommit,
-        proof_extract_oods,
-        proof_sizes,
-        prover_line,
-        vcs_verifier,
-        vcs_prover,
-        vcs_lifted_verifier,
-        vcs_lifted_prover,
-        example_state_machine_trace,
-        example_state_machine_transitions,
-        example_state_machine_claimed_sum,
-        example_state_machine_lookup_draw,
-        example_state_machine_statement,
-        example_xor_is_first,
-        example_xor_is_step_with_offset,
-        example_wide_fibonacci_trace,
-        example_plonk_trace,
+fn merkle_avalanche_root(
+    scheme: &str,
+    column_log_sizes: &[u32],
+    columns: &[Vec<M31>],
+) -> Blake2sHash {
+    if scheme == "vcs_lifted" {
+        return vcs_lifted_layers(column_log_sizes, columns)[0][0];
+    }
+    let mut columns_by_layer = BTreeMap::<u32, Vec<Vec<M31>>>::new();
+    for (&log_size, column) in column_log_sizes.iter().zip(columns) {
+        columns_by_layer
+            .entry(log_size)
+            .or_default()
+            .push(column.clone());
     }
+    let max_log_size = *column_log_sizes.iter().max().expect("at least one column");
+    vcs_layer_hashes(&columns_by_layer, max_log_size)[&0][0]
 }
 
 fn generate_example_state_machine_trace_vectors(
@@ -720,33 +4847,145 @@ fn generate_example_state_machine_trace_vectors(
     let mut out = Vec::with_capacity(count);
     for _ in 0..count {
         let log_size = 2 + ((next_u64(state) as u32) % 9);
-        let n = 1usize << log_size;
         let inc_index = (next_u64(state) as usize) % 2;
 
         let initial_state = [sample_m31(state, false), sample_m31(state, false)];
-        let mut curr_state = initial_state;
-
-        let mut columns = vec![vec![M31::from(0); n], vec![M31::from(0); n]];
-        for i in 0..n {
-            let idx = bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
-            columns[0][idx] = curr_state[0];
-            columns[1][idx] = curr_state[1];
-            curr_state[inc_index] += M31::from(1);
-        }
 
         out.push(ExampleStateMachineTraceVector {
             log_size,
             initial_state: encode_state(initial_state),
             inc_index,
-            columns: columns
-                .into_iter()
-                .map(|column| column.into_iter().map(encode_m31).collect())
-                .collect(),
+            columns: state_machine_trace_columns(log_size, initial_state, inc_index),
+        });
+    }
+    out
+}
+
+/// Trace columns of one state-machine component, in bit-reversed circle-domain order.
+fn state_machine_trace_columns(
+    log_size: u32,
+    initial_state: [M31; 2],
+    inc_index: usize,
+) -> Vec<Vec<u32>> {
+    let n = 1usize << log_size;
+    let mut curr_state = initial_state;
+    let mut columns = vec![vec![M31::from(0); n], vec![M31::from(0); n]];
+    for i in 0..n {
+        let idx = bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
+        columns[0][idx] = curr_state[0];
+        columns[1][idx] = curr_state[1];
+        curr_state[inc_index] += M31::from(1);
+    }
+    columns
+        .into_iter()
+        .map(|column| column.into_iter().map(encode_m31).collect())
+        .collect()
+}
+
+/// Both components of the state machine as committed in the upstream example's trace tree: the
+/// first runs `2^log_n_rows` rows from the initial state, the second `2^(log_n_rows - 1)` rows
+/// from the intermediate state along the other axis. `x_first` is the interop example's order
+/// (inc_index 0 then 1); `y_first` swaps the axes. Each component's claimed sum is computed
+/// row by row and checked against the telescoping form before it is recorded.
+fn generate_example_state_machine_components_vectors(
+    state: &mut u64,
+    count: usize,
+    stats: &mut RetryStats,
+) -> Vec<ExampleStateMachineComponentsVector> {
+    let mut out = Vec::with_capacity(count);
+    while out.len() < count {
+        let (axis_order, first_inc_index) = if out.len() % 2 == 0 {
+            ("x_first", 0)
+        } else {
+            ("y_first", 1)
+        };
+        let log_n_rows = 2 + ((next_u64(state) as u32) % 7);
+        let initial_state = [sample_m31(state, false), sample_m31(state, false)];
+        let z = sample_qm31(state, false);
+        let alpha = sample_qm31(state, false);
+
+        let mut intermediate_state = initial_state;
+        intermediate_state[first_inc_index] += M31::from(1u32 << log_n_rows);
+        let mut final_state = intermediate_state;
+        final_state[1 - first_inc_index] += M31::from(1u32 << (log_n_rows - 1));
+
+        let layout = [
+            (
+                log_n_rows,
+                first_inc_index,
+                initial_state,
+                intermediate_state,
+            ),
+            (
+                log_n_rows - 1,
+                1 - first_inc_index,
+                intermediate_state,
+                final_state,
+            ),
+        ];
+        let mut components = Vec::with_capacity(layout.len());
+        for (log_size, inc_index, start, end) in layout {
+            let Some(claimed_sum) =
+                state_machine_component_claimed_sum(log_size, start, inc_index, z, alpha)
+            else {
+                break;
+            };
+            let telescoping_claim =
+                combine_state(start, z, alpha).inverse() - combine_state(end, z, alpha).inverse();
+            assert_eq!(
+                claimed_sum, telescoping_claim,
+                "state-machine component claimed sum does not telescope"
+            );
+            components.push(ExampleStateMachineComponentTrace {
+                log_size,
+                inc_index,
+                initial_state: encode_state(start),
+                final_state: encode_state(end),
+                columns: state_machine_trace_columns(log_size, start, inc_index),
+                claimed_sum: encode_qm31(claimed_sum),
+            });
+        }
+        if components.len() != layout.len() {
+            stats.rejections += 1;
+            continue;
+        }
+
+        out.push(ExampleStateMachineComponentsVector {
+            axis_order,
+            log_n_rows,
+            initial_state: encode_state(initial_state),
+            intermediate_state: encode_state(intermediate_state),
+            final_state: encode_state(final_state),
+            z: encode_qm31(z),
+            alpha: encode_qm31(alpha),
+            components,
         });
     }
     out
 }
 
+/// Row-by-row logup sum of one component, or `None` if any combined state is zero.
+fn state_machine_component_claimed_sum(
+    log_size: u32,
+    initial_state: [M31; 2],
+    inc_index: usize,
+    z: QM31,
+    alpha: QM31,
+) -> Option<QM31> {
+    let mut curr_state = initial_state;
+    let mut claimed_sum = QM31::from(0);
+    for _ in 0..1usize << log_size {
+        let input = combine_state(curr_state, z, alpha);
+        curr_state[inc_index] += M31::from(1);
+        let output = combine_state(curr_state, z, alpha);
+        if input == QM31::from(0) || output == QM31::from(0) {
+            return None;
+        }
+        claimed_sum += (output - input) / (input * output);
+    }
+    Some(claimed_sum)
+}
+
 fn generate_example_state_machine_transition_vectors(
     state: &mut u64,
     count: usize,
@@ -775,6 +5014,7 @@ fn generate_example_state_machine_transition_vectors(
 fn generate_example_state_machine_claimed_sum_vectors(
     state: &mut u64,
     count: usize,
+    stats: &mut RetryStats,
 ) -> Vec<ExampleStateMachineClaimedSumVector> {
     let mut out = Vec::with_capacity(count);
     while out.len() < count {
@@ -805,6 +5045,7 @@ fn generate_example_state_machine_claimed_sum_vectors(
             claimed_sum += numerator / denominator;
         }
         if degenerate {
+            stats.rejections += 1;
             continue;
         }
 
@@ -813,6 +5054,7 @@ fn generate_example_state_machine_claimed_sum_vectors(
         let initial_combined = combine_state(initial_state, z, alpha);
         let final_combined = combine_state(final_state, z, alpha);
         if initial_combined == QM31::from(0) || final_combined == QM31::from(0) {
+            stats.rejections += 1;
             continue;
         }
 
@@ -859,6 +5101,65 @@ fn generate_example_state_machine_lookup_draw_vectors(
     out
 }
 
+fn generate_lookup_draw_v2_vectors(state: &mut u64, count: usize) -> Vec<LookupDrawV2Vector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mix_u64 = next_u64(state);
+        let n_u32s = 1 + ((next_u64(state) as usize) % 6);
+        let mix_u32s = (0..n_u32s)
+            .map(|_| next_u64(state) as u32)
+            .collect::<Vec<_>>();
+
+        let mut channel = Blake2sChannel::default();
+        channel.mix_u64(mix_u64);
+        channel.mix_u32s(&mix_u32s);
+        let digest_before = encode_hash(channel.digest());
+
+        let n_rounds = 2 + (next_u64(state) as usize) % (LOOKUP_DRAW_V2_MAX_ROUNDS - 1);
+        let mut steps = Vec::new();
+        for round in 0..n_rounds {
+            if next_u64(state) % 2 == 0 {
+                for _ in 0..2 {
+                    let felt = channel.draw_secure_felt();
+                    steps.push(LookupDrawStepVector {
+                        op: "draw_secure_felt".to_string(),
+                        felts: vec![encode_qm31(felt)],
+                        digest_after: encode_hash(channel.digest()),
+                    });
+                }
+            } else {
+                let n_felts = 3 + (next_u64(state) as usize) % (LOOKUP_DRAW_V2_MAX_BATCH - 2);
+                let felts = channel.draw_secure_felts(n_felts);
+                steps.push(LookupDrawStepVector {
+                    op: "draw_secure_felts".to_string(),
+                    felts: felts.into_iter().map(encode_qm31).collect(),
+                    digest_after: encode_hash(channel.digest()),
+                });
+            }
+            if round + 1 < n_rounds {
+                let n_felts = 1 + (next_u64(state) as usize) % LOOKUP_DRAW_V2_MAX_MIXED_FELTS;
+                let felts = (0..n_felts)
+                    .map(|_| sample_qm31(state, false))
+                    .collect::<Vec<_>>();
+                channel.mix_felts(&felts);
+                steps.push(LookupDrawStepVector {
+                    op: "mix_felts".to_string(),
+                    felts: felts.into_iter().map(encode_qm31).collect(),
+                    digest_after: encode_hash(channel.digest()),
+                });
+            }
+        }
+
+        out.push(LookupDrawV2Vector {
+            mix_u64,
+            mix_u32s,
+            digest_before,
+            steps,
+        });
+    }
+    out
+}
+
 fn generate_example_state_machine_statement_vectors(
     state: &mut u64,
     count: usize,
@@ -928,117 +5229,561 @@ fn generate_example_xor_is_step_with_offset_vectors(
         let log_size = 1 + ((next_u64(state) as u32) % 10);
         let n = 1usize << log_size;
         let log_step = (next_u64(state) as u32) % (log_size + 1);
-        let step = 1usize << log_step;
         let offset = (next_u64(state) as usize) % (n.saturating_mul(2).max(1));
+        out.push(xor_is_step_with_offset_vector(log_size, log_step, offset));
+    }
+
+    // Boundary cases, appended after the random ones so their indices stay put: offsets at `n`,
+    // `2n - 1` and past `2n`, every row set (log_step 0), and a single pulse (log_step ==
+    // log_size) at every offset residue.
+    for log_size in [1u32, 3, 4] {
+        let n = 1usize << log_size;
+        for log_step in [1, log_size - 1] {
+            for offset in [n, 2 * n - 1, 5 * n + 3] {
+                out.push(xor_is_step_with_offset_vector(log_size, log_step, offset));
+            }
+        }
+        out.push(xor_is_step_with_offset_vector(log_size, 0, n + 1));
+        for offset in 0..n {
+            out.push(xor_is_step_with_offset_vector(log_size, log_size, offset));
+        }
+    }
+    out
+}
+
+fn xor_is_step_with_offset_vector(
+    log_size: u32,
+    log_step: u32,
+    offset: usize,
+) -> ExampleXorIsStepWithOffsetVector {
+    let n = 1usize << log_size;
+    let step = 1usize << log_step;
+    let mut values = vec![0u32; n];
+    let mut i = offset % step;
+    while i < n {
+        let circle_domain_idx = coset_index_to_circle_domain_index(i, log_size);
+        let bit_rev_idx = bit_reverse_index(circle_domain_idx, log_size);
+        values[bit_rev_idx] = 1;
+        i += step;
+    }
+    ExampleXorIsStepWithOffsetVector {
+        log_size,
+        log_step,
+        offset,
+        values,
+    }
+}
+
+fn generate_example_wide_fibonacci_trace_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<ExampleWideFibonacciTraceVector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let log_n_rows = 2 + ((next_u64(state) as u32) % 9);
+        let sequence_len = 2 + ((next_u64(state) as u32) % 15);
+        let trace = wide_fibonacci_trace(log_n_rows, sequence_len, |row| {
+            (M31::from(1), M31::from(row as u32))
+        });
+
+        out.push(ExampleWideFibonacciTraceVector {
+            log_n_rows,
+            sequence_len,
+            columns: encode_wide_fibonacci_columns(trace),
+            seed_a: None,
+            seed_b: None,
+            constraints_satisfied: None,
+        });
+    }
+    out
+}
+
+/// Wide-Fibonacci traces with random per-row starting pairs. Every other vector has one cell
+/// past the seed columns bumped by one, so the recorded constraint flags include failures (the
+/// bumped cell and the two cells computed from it).
+fn generate_example_wide_fibonacci_seeded_trace_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<ExampleWideFibonacciTraceVector> {
+    let mut out = Vec::with_capacity(count);
+    for vector_index in 0..count {
+        let log_n_rows = 2 + ((next_u64(state) as u32) % 9);
+        let sequence_len = 3 + ((next_u64(state) as u32) % 14);
+        let n = 1usize << log_n_rows;
+        let seeds = (0..n)
+            .map(|_| (sample_m31(state, false), sample_m31(state, false)))
+            .collect::<Vec<_>>();
+        let mut trace = wide_fibonacci_trace(log_n_rows, sequence_len, |row| seeds[row]);
+
+        if vector_index % 2 == 1 {
+            let col = 2 + (next_u64(state) as usize) % (sequence_len as usize - 2);
+            let row = (next_u64(state) as usize) % n;
+            trace[col][row] += M31::from(1);
+        }
+
+        let constraints_satisfied = trace
+            .windows(3)
+            .map(|cols| {
+                (0..n)
+                    .map(|i| cols[2][i] == cols[0][i].square() + cols[1][i].square())
+                    .collect()
+            })
+            .collect();
+
+        out.push(ExampleWideFibonacciTraceVector {
+            log_n_rows,
+            sequence_len,
+            seed_a: Some(trace[0].iter().copied().map(encode_m31).collect()),
+            seed_b: Some(trace[1].iter().copied().map(encode_m31).collect()),
+            columns: encode_wide_fibonacci_columns(trace),
+            constraints_satisfied: Some(constraints_satisfied),
+        });
+    }
+    out
+}
+
+/// Fills `sequence_len` columns in bit-reversed circle-domain order; `seed(row)` gives the first
+/// two values of the row's sequence.
+fn wide_fibonacci_trace(
+    log_n_rows: u32,
+    sequence_len: u32,
+    seed: impl Fn(usize) -> (M31, M31),
+) -> Vec<Vec<M31>> {
+    let n = 1usize << log_n_rows;
+    let mut trace = vec![vec![M31::from(0); n]; sequence_len as usize];
+    for row in 0..n {
+        let bit_rev = bit_reverse_index(
+            coset_index_to_circle_domain_index(row, log_n_rows),
+            log_n_rows,
+        );
+
+        let (mut a, mut b) = seed(row);
+        trace[0][bit_rev] = a;
+        trace[1][bit_rev] = b;
+        for col in trace.iter_mut().skip(2) {
+            let c = a.square() + b.square();
+            col[bit_rev] = c;
+            a = b;
+            b = c;
+        }
+    }
+    trace
+}
+
+fn encode_wide_fibonacci_columns(trace: Vec<Vec<M31>>) -> Vec<Vec<u32>> {
+    trace
+        .into_iter()
+        .map(|column| column.into_iter().map(encode_m31).collect::<Vec<u32>>())
+        .collect()
+}
+
+fn generate_example_poseidon_trace_vectors() -> Vec<ExamplePoseidonTraceVector> {
+    EXAMPLE_POSEIDON_TRACE_LOG_N_ROWS
+        .iter()
+        .map(|&log_n_rows| {
+            let columns = poseidon::gen_trace(log_n_rows);
+            assert_eq!(columns.len(), POSEIDON_COLUMNS);
+            ExamplePoseidonTraceVector {
+                log_n_rows,
+                columns: columns
+                    .into_iter()
+                    .map(|column| column.into_iter().map(encode_m31).collect())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn generate_example_poseidon_rounds_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<ExamplePoseidonRoundsVector> {
+    let encode = |values: &[M31]| values.iter().copied().map(encode_m31).collect::<Vec<_>>();
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let external_round = i % POSEIDON_FULL_ROUNDS;
+        let internal_round = i % POSEIDON_PARTIAL_ROUNDS;
+        let input: [M31; POSEIDON_STATE] = std::array::from_fn(|_| sample_m31(state, false));
+        let external_round_constants: [M31; POSEIDON_STATE] =
+            std::array::from_fn(|state_i| poseidon_external_round_const(external_round, state_i));
+        let internal_round_constant = poseidon_internal_round_const(internal_round);
+
+        let mut after_add_external_round_constants = input;
+        for (value, constant) in after_add_external_round_constants
+            .iter_mut()
+            .zip(external_round_constants)
+        {
+            *value += constant;
+        }
+        let mut after_add_internal_round_constant = input;
+        after_add_internal_round_constant[0] += internal_round_constant;
+        let after_apply_m4 = input
+            .chunks_exact(4)
+            .flat_map(|chunk| poseidon_apply_m4([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect::<Vec<_>>();
+        let mut after_external_round_matrix = input;
+        poseidon_apply_external_round_matrix(&mut after_external_round_matrix);
+        let mut after_internal_round_matrix = input;
+        poseidon_apply_internal_round_matrix(&mut after_internal_round_matrix);
+        let after_pow5 = input.map(poseidon_pow5);
+
+        out.push(ExamplePoseidonRoundsVector {
+            external_round,
+            internal_round,
+            input: encode(&input),
+            external_round_constants: encode(&external_round_constants),
+            internal_round_constant: encode_m31(internal_round_constant),
+            after_add_external_round_constants: encode(&after_add_external_round_constants),
+            after_add_internal_round_constant: encode(&after_add_internal_round_constant),
+            after_apply_m4: encode(&after_apply_m4),
+            after_external_round_matrix: encode(&after_external_round_matrix),
+            after_internal_round_matrix: encode(&after_internal_round_matrix),
+            after_pow5: encode(&after_pow5),
+        });
+    }
+    out
+}
+
+fn generate_example_plonk_trace_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<ExamplePlonkTraceVector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let log_n_rows = 2 + ((next_u64(state) as u32) % 9);
+        let (preprocessed, main) = plonk_trace_columns(log_n_rows);
+        out.push(ExamplePlonkTraceVector {
+            log_n_rows,
+            preprocessed,
+            main,
+        });
+    }
+    out
+}
+
+/// Preprocessed and main trace columns of the plonk example, in row order.
+fn plonk_trace_columns(log_n_rows: u32) -> (Vec<Vec<u32>>, Vec<Vec<u32>>) {
+    let n = 1usize << log_n_rows;
+
+    let mut preprocessed = vec![vec![M31::from(0); n]; 4];
+    let mut main = vec![vec![M31::from(0); n]; 4];
+    let mut fib = vec![M31::from(0); n + 2];
+    fib[0] = M31::from(1);
+    fib[1] = M31::from(1);
+    for i in 2..fib.len() {
+        fib[i] = fib[i - 1] + fib[i - 2];
+    }
+
+    for i in 0..n {
+        preprocessed[0][i] = M31::from(i as u32);
+        preprocessed[1][i] = M31::from((i + 1) as u32);
+        preprocessed[2][i] = M31::from((i + 2) as u32);
+        preprocessed[3][i] = M31::from(1);
+
+        main[0][i] = M31::from(1);
+        main[1][i] = fib[i];
+        main[2][i] = fib[i + 1];
+        main[3][i] = fib[i + 2];
+    }
+    if n >= 2 {
+        main[0][n - 1] = M31::from(0);
+        main[0][n - 2] = M31::from(1);
+    }
+
+    let encode = |columns: Vec<Vec<M31>>| -> Vec<Vec<u32>> {
+        columns
+            .into_iter()
+            .map(|column| column.into_iter().map(encode_m31).collect())
+            .collect()
+    };
+    (encode(preprocessed), encode(main))
+}
+
+/// `random` commits two trees of 1-4 random columns with log sizes 3-5; `state_machine` and
+/// `plonk` commit the example's preprocessed tree and then its main trace tree at log size 3-5.
+fn generate_pcs_commit_vectors(state: &mut u64, count: usize) -> Vec<PcsCommitVector> {
+    (0..count)
+        .map(|index| {
+            let case = PCS_COMMIT_CASES[index % PCS_COMMIT_CASES.len()];
+            let trees = match case {
+                "random" => (0..2)
+                    .map(|_| {
+                        let n_columns = 1 + (next_u64(state) as usize) % 4;
+                        (0..n_columns)
+                            .map(|_| {
+                                let log_size = 3 + (next_u64(state) as u32) % 3;
+                                (0..1usize << log_size)
+                                    .map(|_| encode_m31(sample_m31(state, false)))
+                                    .collect()
+                            })
+                            .collect()
+                    })
+                    .collect(),
+                "state_machine" => {
+                    let log_size = 3 + (next_u64(state) as u32) % 3;
+                    let initial_state = [sample_m31(state, false), sample_m31(state, false)];
+                    let mut is_first = vec![0u32; 1 << log_size];
+                    is_first[0] = 1;
+                    vec![
+                        vec![is_first],
+                        state_machine_trace_columns(log_size, initial_state, 0),
+                    ]
+                }
+                "plonk" => {
+                    let (preprocessed, main) =
+                        plonk_trace_columns(3 + (next_u64(state) as u32) % 3);
+                    vec![preprocessed, main]
+                }
+                other => unreachable!("unknown pcs_commit case {other}"),
+            };
+            pcs_commit_vector(case, trees)
+        })
+        .collect()
+}
+
+fn pcs_commit_vector(case: &str, trees: Vec<Vec<Vec<u32>>>) -> PcsCommitVector {
+    let config = PcsConfig {
+        fri_config: FriConfig::new(0, PCS_COMMIT_LOG_BLOWUP_FACTOR, 3),
+        ..PcsConfig::default()
+    };
+    let max_log_size = trees
+        .iter()
+        .flatten()
+        .map(|column| column.len().ilog2())
+        .max()
+        .expect("pcs_commit trees have columns");
+    let twiddles = CpuBackend::precompute_twiddles(
+        CanonicCoset::new(max_log_size + PCS_COMMIT_LOG_BLOWUP_FACTOR)
+            .circle_domain()
+            .half_coset,
+    );
+    let mut scheme =
+        CommitmentSchemeProver::<CpuBackend, Blake2sMerkleChannel>::new(config, &twiddles);
+    let mut channel = Blake2sChannel::default();
+    for columns in &trees {
+        let mut builder = scheme.tree_builder();
+        builder.extend_evals(
+            columns
+                .iter()
+                .map(|column| {
+                    CpuCircleEvaluation::<M31, BitReversedOrder>::new(
+                        CanonicCoset::new(column.len().ilog2()).circle_domain(),
+                        column.iter().copied().map(decode_m31).collect(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        );
+        builder.commit(&mut channel);
+    }
+
+    let roots = scheme.roots();
+    let trees = trees
+        .into_iter()
+        .enumerate()
+        .map(|(tree_index, columns)| {
+            let column_log_sizes: Vec<u32> =
+                columns.iter().map(|column| column.len().ilog2()).collect();
+            let mut sorted_column_order: Vec<usize> = (0..columns.len()).collect();
+            sorted_column_order.sort_by_key(|&column| column_log_sizes[column]);
+            PcsCommitTreeVector {
+                column_log_sizes,
+                columns,
+                sorted_column_order,
+                n_layers: scheme.trees[tree_index].commitment.layers.len(),
+                root: encode_hash(roots[tree_index]),
+            }
+        })
+        .collect();
+    PcsCommitVector {
+        case: case.to_string(),
+        log_blowup_factor: PCS_COMMIT_LOG_BLOWUP_FACTOR,
+        trees,
+    }
+}
 
-        let mut values = vec![0u32; n];
-        let mut i = offset % step;
-        while i < n {
-            let circle_domain_idx = coset_index_to_circle_domain_index(i, log_size);
-            let bit_rev_idx = bit_reverse_index(circle_domain_idx, log_size);
-            values[bit_rev_idx] = 1;
-            i += step;
-        }
+fn generate_e2e_tree_shapes_vectors() -> Vec<E2eTreeShapesVector> {
+    E2E_TREE_SHAPES_LOG_SIZES
+        .iter()
+        .map(|&log_size| build_e2e_tree_shapes_vector(log_size))
+        .collect()
+}
 
-        out.push(ExampleXorIsStepWithOffsetVector {
-            log_size,
-            log_step,
-            offset,
+/// Proves over the fixed initial state `[log_size, 2 * log_size]` with the lookup elements drawn
+/// after the main commitment, as the state machine example does.
+fn build_e2e_tree_shapes_vector(log_size: u32) -> E2eTreeShapesVector {
+    let config = PcsConfig {
+        pow_bits: 0,
+        fri_config: FriConfig::new(0, 1, 3),
+        ..PcsConfig::default()
+    };
+    let initial_state = [M31::from(log_size), M31::from(2 * log_size)];
+    let n = 1usize << log_size;
+    let eval = |values: Vec<M31>| {
+        CpuCircleEvaluation::<M31, BitReversedOrder>::new(
+            CanonicCoset::new(log_size).circle_domain(),
             values,
-        });
+        )
+    };
+
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    let twiddles = CpuBackend::precompute_twiddles(
+        CanonicCoset::new(log_size + 1 + config.fri_config.log_blowup_factor)
+            .circle_domain()
+            .half_coset,
+    );
+    let mut scheme =
+        CommitmentSchemeProver::<CpuBackend, Blake2sMerkleChannel>::new(config, &twiddles);
+
+    let mut is_first = vec![M31::from(0); n];
+    is_first[0] = M31::from(1);
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(vec![eval(is_first)]);
+    builder.commit(&mut channel);
+
+    let main: Vec<Vec<M31>> = state_machine_trace_columns(log_size, initial_state, 0)
+        .into_iter()
+        .map(|column| column.into_iter().map(decode_m31).collect())
+        .collect();
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(main.iter().cloned().map(eval).collect::<Vec<_>>());
+    builder.commit(&mut channel);
+
+    let z = channel.draw_secure_felt();
+    let alpha = channel.draw_secure_felt();
+    let mut running_sum = vec![QM31::from(0); n];
+    let mut claimed_sum = QM31::from(0);
+    for i in 0..n {
+        let idx = bit_reverse_index(coset_index_to_circle_domain_index(i, log_size), log_size);
+        let combined = combine_state([main[0][idx], main[1][idx]], z, alpha);
+        assert_ne!(
+            combined,
+            QM31::from(0),
+            "e2e_tree_shapes: zero combined state"
+        );
+        claimed_sum += combined.inverse();
+        running_sum[idx] = claimed_sum;
     }
-    out
-}
+    let interaction = (0..SECURE_EXTENSION_DEGREE)
+        .map(|coord| {
+            running_sum
+                .iter()
+                .map(|&value| decode_m31(encode_qm31(value)[coord]))
+                .collect()
+        })
+        .map(eval)
+        .collect::<Vec<_>>();
+    let mut builder = scheme.tree_builder();
+    builder.extend_evals(interaction);
+    builder.commit(&mut channel);
 
-fn generate_example_wide_fibonacci_trace_vectors(
-    state: &mut u64,
-    count: usize,
-) -> Vec<ExampleWideFibonacciTraceVector> {
-    let mut out = Vec::with_capacity(count);
-    for _ in 0..count {
-        let log_n_rows = 2 + ((next_u64(state) as u32) % 9);
-        let sequence_len = 2 + ((next_u64(state) as u32) % 15);
-        let n = 1usize << log_n_rows;
-        let n_cols = sequence_len as usize;
+    let component = TreeShapesComponent {
+        log_size,
+        composition_eval: claimed_sum,
+    };
+    let proof = prove::<CpuBackend, Blake2sMerkleChannel>(&[&component], &mut channel, scheme)
+        .expect("e2e_tree_shapes: prove");
+
+    let mut column_log_sizes = component.trace_log_degree_bounds().0;
+    column_log_sizes.push(vec![
+        component.max_constraint_log_degree_bound()
+            - E2E_COMPOSITION_LOG_SPLIT;
+        2 * SECURE_EXTENSION_DEGREE
+    ]);
+    let commitments = proof.0.commitments.to_vec();
+    let pcs = &proof.0;
+    assert_eq!(commitments.len(), E2E_TREE_NAMES.len());
+    let trees = E2E_TREE_NAMES
+        .iter()
+        .zip(column_log_sizes)
+        .enumerate()
+        .map(|(tree, (name, column_log_sizes))| E2eTreeShapeVector {
+            tree: name.to_string(),
+            n_columns: column_log_sizes.len(),
+            column_log_sizes,
+            commitment: encode_hash(commitments[tree]),
+            sampled_values_shape: pcs.sampled_values[tree].iter().map(Vec::len).collect(),
+            queried_values_shape: pcs.queried_values[tree].iter().map(Vec::len).collect(),
+            hash_witness_len: pcs.decommitments[tree].hash_witness.len(),
+        })
+        .collect::<Vec<_>>();
 
-        let mut trace = vec![vec![M31::from(0); n]; n_cols];
-        for row in 0..n {
-            let bit_rev = bit_reverse_index(
-                coset_index_to_circle_domain_index(row, log_n_rows),
-                log_n_rows,
-            );
+    let mut channel = Blake2sChannel::default();
+    config.mix_into(&mut channel);
+    let mut verifier = CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(config);
+    verifier.commit(commitments[0], &trees[0].column_log_sizes, &mut channel);
+    verifier.commit(commitments[1], &trees[1].column_log_sizes, &mut channel);
+    channel.draw_secure_felt();
+    channel.draw_secure_felt();
+    verifier.commit(commitments[2], &trees[2].column_log_sizes, &mut channel);
+    verify(&[&component], &mut channel, &mut verifier, proof)
+        .expect("e2e_tree_shapes: upstream verify");
+
+    E2eTreeShapesVector {
+        log_size,
+        initial_state: encode_state(initial_state),
+        log_blowup_factor: config.fri_config.log_blowup_factor,
+        n_queries: config.fri_config.n_queries,
+        trees,
+    }
+}
 
-            let mut a = M31::from(1);
-            let mut b = M31::from(row as u32);
-            trace[0][bit_rev] = a;
-            trace[1][bit_rev] = b;
-            for col in trace.iter_mut().skip(2) {
-                let c = a.square() + b.square();
-                col[bit_rev] = c;
-                a = b;
-                b = c;
-            }
-        }
+impl Component for TreeShapesComponent {
+    fn n_constraints(&self) -> usize {
+        1
+    }
 
-        out.push(ExampleWideFibonacciTraceVector {
-            log_n_rows,
-            sequence_len,
-            columns: trace
-                .into_iter()
-                .map(|column| column.into_iter().map(encode_m31).collect::<Vec<u32>>())
-                .collect(),
-        });
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_size + 1
     }
-    out
-}
 
-fn generate_example_plonk_trace_vectors(
-    state: &mut u64,
-    count: usize,
-) -> Vec<ExamplePlonkTraceVector> {
-    let mut out = Vec::with_capacity(count);
-    for _ in 0..count {
-        let log_n_rows = 2 + ((next_u64(state) as u32) % 9);
-        let n = 1usize << log_n_rows;
+    fn trace_log_degree_bounds(&self) -> TreeVec<Vec<u32>> {
+        TreeVec::new(vec![
+            vec![self.log_size],
+            vec![self.log_size; 2],
+            vec![self.log_size; SECURE_EXTENSION_DEGREE],
+        ])
+    }
 
-        let mut preprocessed = vec![vec![M31::from(0); n]; 4];
-        let mut main = vec![vec![M31::from(0); n]; 4];
-        let mut fib = vec![M31::from(0); n + 2];
-        fib[0] = M31::from(1);
-        fib[1] = M31::from(1);
-        for i in 2..fib.len() {
-            fib[i] = fib[i - 1] + fib[i - 2];
-        }
+    fn mask_points(
+        &self,
+        point: CirclePoint<QM31>,
+        _max_log_degree_bound: u32,
+    ) -> TreeVec<Vec<Vec<CirclePoint<QM31>>>> {
+        let prev_point = point - CanonicCoset::new(self.log_size).step().into_ef();
+        TreeVec::new(vec![
+            vec![vec![]],
+            vec![vec![point]; 2],
+            vec![vec![prev_point, point]; SECURE_EXTENSION_DEGREE],
+        ])
+    }
 
-        for i in 0..n {
-            preprocessed[0][i] = M31::from(i as u32);
-            preprocessed[1][i] = M31::from((i + 1) as u32);
-            preprocessed[2][i] = M31::from((i + 2) as u32);
-            preprocessed[3][i] = M31::from(1);
+    fn preprocessed_column_indices(&self) -> Vec<usize> {
+        vec![0]
+    }
 
-            main[0][i] = M31::from(1);
-            main[1][i] = fib[i];
-            main[2][i] = fib[i + 1];
-            main[3][i] = fib[i + 2];
-        }
-        if n >= 2 {
-            main[0][n - 1] = M31::from(0);
-            main[0][n - 2] = M31::from(1);
-        }
+    fn evaluate_constraint_quotients_at_point(
+        &self,
+        _point: CirclePoint<QM31>,
+        _mask: &TreeVec<Vec<Vec<QM31>>>,
+        evaluation_accumulator: &mut PointEvaluationAccumulator,
+        _max_log_degree_bound: u32,
+    ) {
+        evaluation_accumulator.accumulate(self.composition_eval);
+    }
+}
 
-        out.push(ExamplePlonkTraceVector {
-            log_n_rows,
-            preprocessed: preprocessed
-                .into_iter()
-                .map(|column| column.into_iter().map(encode_m31).collect::<Vec<u32>>())
-                .collect(),
-            main: main
-                .into_iter()
-                .map(|column| column.into_iter().map(encode_m31).collect::<Vec<u32>>())
-                .collect(),
-        });
+impl ComponentProver<CpuBackend> for TreeShapesComponent {
+    fn evaluate_constraint_quotients_on_domain(
+        &self,
+        _trace: &Trace<'_, CpuBackend>,
+        evaluation_accumulator: &mut DomainEvaluationAccumulator<CpuBackend>,
+    ) {
+        let [mut col] = evaluation_accumulator.columns([(self.log_size + 1, 1)]);
+        for i in 0..1usize << (self.log_size + 1) {
+            col.accumulate(i, self.composition_eval);
+        }
     }
-    out
 }
 
 fn generate_proof_extract_oods_vectors(
@@ -1067,15 +5812,459 @@ fn generate_proof_extract_oods_vectors(
             left_eval + oods_point.repeated_double(composition_log_size - 2).x * right_eval;
 
         out.push(ProofExtractOodsVector {
+            case: "valid".to_string(),
             composition_log_size,
             oods_point: encode_secure_circle_point(oods_point),
+            has_composition_tree: true,
             composition_values: composition_values.into_iter().map(encode_qm31).collect(),
-            expected: encode_qm31(expected),
+            expected: Some(encode_qm31(expected)),
+            expected_error: None,
+        });
+    }
+    out
+}
+
+/// Malformed composition masks for `StarkProof::extract_composition_oods_eval`, classified by
+/// running upstream: wrong column counts, no sampled-values tree at all, and a conjugate-fixed
+/// (M31-circle) OODS point.
+fn generate_proof_extract_oods_error_vectors(state: &mut u64) -> Vec<ProofExtractOodsVector> {
+    let mut cases = Vec::new();
+    for n_values in [4usize, 7, 9] {
+        let oods_point = sample_secure_point_non_degenerate(state);
+        let values = (0..n_values)
+            .map(|_| sample_qm31(state, false))
+            .collect::<Vec<_>>();
+        cases.push((
+            format!("composition_values_{n_values}"),
+            oods_point,
+            Some(values),
+        ));
+    }
+
+    let oods_point = sample_secure_point_non_degenerate(state);
+    cases.push(("composition_tree_missing".to_string(), oods_point, None));
+
+    let base_point = M31_CIRCLE_GEN.mul(sample_scalar_u128(state));
+    let fixed_point = CirclePoint {
+        x: QM31::from(base_point.x),
+        y: QM31::from(base_point.y),
+    };
+    let values = (0..8)
+        .map(|_| sample_qm31(state, false))
+        .collect::<Vec<_>>();
+    cases.push((
+        "conjugate_fixed_point".to_string(),
+        fixed_point,
+        Some(values),
+    ));
+
+    cases
+        .into_iter()
+        .map(|(case, oods_point, composition_values)| {
+            let composition_log_size = 2 + ((next_u64(state) as u32) % 8);
+            let (expected, expected_error) = run_extract_composition_oods_eval(
+                oods_point,
+                composition_log_size,
+                &composition_values,
+            );
+            ProofExtractOodsVector {
+                case,
+                composition_log_size,
+                oods_point: encode_secure_circle_point(oods_point),
+                has_composition_tree: composition_values.is_some(),
+                composition_values: composition_values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(encode_qm31)
+                    .collect(),
+                expected: expected.map(encode_qm31),
+                expected_error,
+            }
+        })
+        .collect()
+}
+
+/// Builds proof-shaped `sampled_values` (this tool builds without the prover, so no real proof)
+/// and pins which cells the OODS composition check reads, and in which order.
+fn generate_composition_sampled_order_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<CompositionSampledOrderVector> {
+    let mut out = Vec::with_capacity(count);
+    for vector_index in 0..count {
+        let composition_log_size = 2 + ((next_u64(state) as u32) % 8);
+        let oods_point = sample_secure_point_non_degenerate(state);
+
+        // Mask counts per column, tree by tree; the composition tree always has
+        // 2 * SECURE_EXTENSION_DEGREE single-sample columns.
+        let n_preprocessed = next_u64(state) as usize % 3;
+        let n_trace = 1 + (next_u64(state) as usize % 6);
+        let mut tree_masks = vec![
+            vec![1; n_preprocessed],
+            (0..n_trace)
+                .map(|_| 1 + (next_u64(state) as usize % 2))
+                .collect::<Vec<_>>(),
+        ];
+        if vector_index % 2 == 1 {
+            tree_masks.push(vec![1; 4]);
+        }
+        tree_masks.push(vec![1; 2 * SECURE_EXTENSION_DEGREE]);
+        let composition_tree = tree_masks.len() - 1;
+
+        let sampled_values = tree_masks
+            .iter()
+            .map(|masks| {
+                masks
+                    .iter()
+                    .map(|&n_masks| {
+                        (0..n_masks)
+                            .map(|_| sample_qm31(state, false))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut cells = Vec::new();
+        let mut composition_cells = Vec::new();
+        for (tree, columns) in sampled_values.iter().enumerate() {
+            for (column, masks) in columns.iter().enumerate() {
+                for (mask, value) in masks.iter().enumerate() {
+                    let mut composition_coordinate = None;
+                    if tree == composition_tree {
+                        composition_cells.push(cells.len());
+                        let half = if column < SECURE_EXTENSION_DEGREE {
+                            "left"
+                        } else {
+                            "right"
+                        };
+                        composition_coordinate =
+                            Some((half.to_string(), column % SECURE_EXTENSION_DEGREE));
+                    }
+                    cells.push(CompositionSampledCell {
+                        tree,
+                        column,
+                        mask,
+                        value: encode_qm31(*value),
+                        composition_coordinate,
+                    });
+                }
+            }
+        }
+
+        let composition = &sampled_values[composition_tree];
+        let partial_evals = |half: usize| -> [QM31; SECURE_EXTENSION_DEGREE] {
+            std::array::from_fn(|i| composition[half * SECURE_EXTENSION_DEGREE + i][0])
+        };
+        let expected = QM31::from_partial_evals(partial_evals(0))
+            + oods_point.repeated_double(composition_log_size - 2).x
+                * QM31::from_partial_evals(partial_evals(1));
+        let (actual, err) = run_extract_oods_eval_on_sampled_values(
+            oods_point,
+            composition_log_size,
+            sampled_values.clone(),
+        );
+        assert_eq!(err, None, "extract_composition_oods_eval failed");
+        assert_eq!(actual, Some(expected));
+
+        out.push(CompositionSampledOrderVector {
+            composition_log_size,
+            oods_point: encode_secure_circle_point(oods_point),
+            sampled_values: sampled_values
+                .into_iter()
+                .map(|tree| {
+                    tree.into_iter()
+                        .map(|column| column.into_iter().map(encode_qm31).collect())
+                        .collect()
+                })
+                .collect(),
+            cells,
+            composition_cells,
+            composition_oods_eval: encode_qm31(expected),
+        });
+    }
+    out
+}
+
+fn circle_scalar_mul_edge_scalar(case: &str, order: u128) -> u128 {
+    match case {
+        "zero" => 0,
+        "one" => 1,
+        "order_minus_one" => order - 1,
+        "order" => order,
+        "order_plus_one" => order + 1,
+        "max" => u128::MAX,
+        other => unreachable!("unknown circle_scalar_mul case {other}"),
+    }
+}
+
+/// Every scalar is checked to act modulo the group order, and reduced scalars 0, 1 and order - 1
+/// against the identity, the generator and its conjugate.
+fn generate_circle_scalar_mul_vectors(state: &mut u64) -> Vec<CircleScalarMulVector> {
+    let mut out = Vec::with_capacity(CIRCLE_SCALAR_MUL_VECTOR_COUNT);
+    let mut scalars = |order: u128| {
+        let mut scalars: Vec<(&str, u128)> = CIRCLE_SCALAR_MUL_EDGE_CASES
+            .iter()
+            .map(|&case| (case, circle_scalar_mul_edge_scalar(case, order)))
+            .collect();
+        for _ in 0..CIRCLE_SCALAR_MUL_RANDOM_COUNT {
+            scalars.push(("random", sample_scalar_u128(state)));
+        }
+        scalars
+    };
+    let m31_order = 1u128 << M31_CIRCLE_LOG_ORDER;
+    for (case, scalar) in scalars(m31_order) {
+        let point = M31_CIRCLE_GEN.mul(scalar);
+        let reduced = scalar % m31_order;
+        assert_eq!(point, M31_CIRCLE_GEN.mul(reduced), "m31 {case}");
+        match reduced {
+            0 => assert_eq!(point, CirclePoint::zero()),
+            1 => assert_eq!(point, M31_CIRCLE_GEN),
+            r if r == m31_order - 1 => assert_eq!(point, M31_CIRCLE_GEN.conjugate()),
+            _ => {}
+        }
+        out.push(CircleScalarMulVector {
+            generator: "m31".to_string(),
+            case: case.to_string(),
+            scalar: scalar.to_string(),
+            x: vec![encode_m31(point.x)],
+            y: vec![encode_m31(point.y)],
+            log_order: Some(point.log_order()),
+        });
+    }
+    for (case, scalar) in scalars(SECURE_FIELD_CIRCLE_ORDER) {
+        let point = SECURE_FIELD_CIRCLE_GEN.mul(scalar);
+        let reduced = scalar % SECURE_FIELD_CIRCLE_ORDER;
+        assert_eq!(
+            point,
+            SECURE_FIELD_CIRCLE_GEN.mul(reduced),
+            "secure_field {case}"
+        );
+        match reduced {
+            0 => assert_eq!(point, CirclePoint::zero()),
+            1 => assert_eq!(point, SECURE_FIELD_CIRCLE_GEN),
+            r if r == SECURE_FIELD_CIRCLE_ORDER - 1 => {
+                assert_eq!(point, SECURE_FIELD_CIRCLE_GEN.conjugate())
+            }
+            _ => {}
+        }
+        out.push(CircleScalarMulVector {
+            generator: "secure_field".to_string(),
+            case: case.to_string(),
+            scalar: scalar.to_string(),
+            x: encode_qm31(point.x).to_vec(),
+            y: encode_qm31(point.y).to_vec(),
+            log_order: None,
         });
     }
     out
 }
 
+/// Degree increment 1 is the wide-Fibonacci-style degree-2 case, 3 the Poseidon case.
+fn generate_blowup_sizing_vectors() -> Vec<BlowupSizingVector> {
+    let mut out = Vec::new();
+    for trace_log_size in [1u32, 3, 5, 8, 12, 16, 20] {
+        for constraint_log_degree_increment in [1u32, 3] {
+            for log_blowup in 1..=4u32 {
+                let max_constraint_log_degree_bound =
+                    trace_log_size + constraint_log_degree_increment;
+                let composition_poly_log_size = max_constraint_log_degree_bound - 1;
+
+                let config = PcsConfig {
+                    fri_config: FriConfig::new(0, log_blowup, 3),
+                    ..PcsConfig::default()
+                };
+                let mut channel = Blake2sChannel::default();
+                let mut scheme = CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(config);
+                scheme.commit(Blake2sHash([0u8; 32]), &[trace_log_size], &mut channel);
+                scheme.commit(
+                    Blake2sHash([0u8; 32]),
+                    &[composition_poly_log_size; 2 * SECURE_EXTENSION_DEGREE],
+                    &mut channel,
+                );
+                let committed = scheme.column_log_sizes();
+                let trace_commitment_log_size = committed[0][0];
+                let composition_commitment_log_size = committed[1][0];
+                let first_fri_layer_log_size = committed
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .max()
+                    .expect("committed columns");
+
+                out.push(BlowupSizingVector {
+                    trace_log_size,
+                    constraint_log_degree_increment,
+                    log_blowup,
+                    max_constraint_log_degree_bound,
+                    twiddle_log_size: max_constraint_log_degree_bound + log_blowup,
+                    trace_commitment_log_size,
+                    composition_poly_log_size,
+                    composition_commitment_log_size,
+                    first_fri_layer_log_size,
+                });
+            }
+        }
+    }
+    out
+}
+
+fn run_extract_composition_oods_eval(
+    oods_point: CirclePoint<QM31>,
+    composition_log_size: u32,
+    composition_values: &Option<Vec<QM31>>,
+) -> (Option<QM31>, Option<String>) {
+    let sampled_values = composition_values
+        .iter()
+        .map(|values| values.iter().map(|value| vec![*value]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    run_extract_oods_eval_on_sampled_values(oods_point, composition_log_size, sampled_values)
+}
+
+fn run_extract_oods_eval_on_sampled_values(
+    oods_point: CirclePoint<QM31>,
+    composition_log_size: u32,
+    sampled_values: Vec<Vec<Vec<QM31>>>,
+) -> (Option<QM31>, Option<String>) {
+    let proof = StarkProof::<LiftedMerkleHasher>(CommitmentSchemeProof {
+        config: PcsConfig::default(),
+        commitments: TreeVec(vec![]),
+        sampled_values: TreeVec(sampled_values),
+        decommitments: TreeVec(vec![]),
+        queried_values: TreeVec(vec![]),
+        proof_of_work: 0,
+        fri_proof: FriProof {
+            first_layer: FriLayerProof {
+                fri_witness: vec![],
+                decommitment: MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                    hash_witness: vec![],
+                },
+                commitment: Blake2sHash([0u8; 32]),
+            },
+            inner_layers: vec![],
+            last_layer_poly: LinePoly::new(vec![QM31::from(1)]),
+        },
+    });
+    let result = std::panic::catch_unwind(move || {
+        proof.extract_composition_oods_eval(oods_point, composition_log_size)
+    });
+    match result {
+        Ok(Ok(value)) => (Some(value), None),
+        Ok(Err(err)) => (None, Some(debug_variant_name(&format!("{err:?}")))),
+        Err(_) => (None, Some("Panic".to_string())),
+    }
+}
+
+fn generate_config_security_vectors() -> Vec<ConfigSecurityVector> {
+    let mut out = Vec::new();
+    for pow_bits in [0u32, 10, 26] {
+        for log_blowup_factor in [1u32, 2, 4, 8, 16] {
+            for n_queries in [3usize, 20, 70] {
+                out.push(config_security_vector(
+                    "table",
+                    pow_bits,
+                    log_blowup_factor,
+                    0,
+                    n_queries,
+                    None,
+                ));
+            }
+        }
+    }
+    for target_bits in [80u32, 96, 128] {
+        for pow_bits in [0u32, 10, 20] {
+            for log_blowup_factor in 1..=4u32 {
+                let min_queries = (target_bits - pow_bits).div_ceil(log_blowup_factor) as usize;
+                for n_queries in [min_queries - 1, min_queries] {
+                    out.push(config_security_vector(
+                        "target_boundary",
+                        pow_bits,
+                        log_blowup_factor,
+                        0,
+                        n_queries,
+                        Some(target_bits),
+                    ));
+                }
+            }
+        }
+    }
+    let mut grid_index = 0u32;
+    for pow_bits in [0u32, 10, 20, 30] {
+        for log_blowup_factor in 1..=4u32 {
+            for n_queries in [1usize, 2, 8, 20, 35, 70] {
+                out.push(config_security_vector(
+                    "cli_grid",
+                    pow_bits,
+                    log_blowup_factor,
+                    grid_index % 5,
+                    n_queries,
+                    None,
+                ));
+                grid_index += 1;
+            }
+        }
+    }
+    for (pow_bits, log_blowup_factor, log_last_layer_degree_bound, n_queries) in [
+        (0u32, 1u32, 0u32, 0usize),
+        (20, 1, 0, 0),
+        (0, 0, 0, 70),
+        (20, 0, 0, 0),
+        (0, 17, 0, 3),
+        (0, 1, 11, 3),
+    ] {
+        out.push(config_security_vector(
+            "degenerate",
+            pow_bits,
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+            n_queries,
+            None,
+        ));
+    }
+    out
+}
+
+fn config_security_vector(
+    case: &'static str,
+    pow_bits: u32,
+    log_blowup_factor: u32,
+    log_last_layer_degree_bound: u32,
+    n_queries: usize,
+    target_bits: Option<u32>,
+) -> ConfigSecurityVector {
+    let accepted = std::panic::catch_unwind(|| {
+        FriConfig::new(log_last_layer_degree_bound, log_blowup_factor, n_queries)
+    })
+    .is_ok();
+    let config = PcsConfig {
+        pow_bits,
+        fri_config: FriConfig {
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+            n_queries,
+        },
+    };
+    let security_bits = config.security_bits();
+    let classification = if !accepted {
+        "rejected_fri_config"
+    } else if n_queries == 0 {
+        "no_queries"
+    } else {
+        "valid"
+    };
+    ConfigSecurityVector {
+        case,
+        pow_bits,
+        log_blowup_factor,
+        log_last_layer_degree_bound,
+        n_queries,
+        security_bits,
+        classification,
+        target_bits,
+        meets_target: target_bits.map(|target| security_bits >= target),
+    }
+}
+
 fn generate_proof_size_vectors(state: &mut u64, count: usize) -> Vec<ProofSizeVector> {
     let mut out = Vec::with_capacity(count);
     for _ in 0..count {
@@ -1156,84 +6345,210 @@ fn generate_proof_size_vectors(state: &mut u64, count: usize) -> Vec<ProofSizeVe
             .map(|_| sample_qm31(state, false))
             .collect::<Vec<_>>();
 
-        let proof = StarkProof::<LiftedMerkleHasher>(CommitmentSchemeProof {
-            config: PcsConfig::default(),
-            commitments: TreeVec(commitments.clone()),
-            sampled_values: TreeVec(sampled_values.clone()),
-            decommitments: TreeVec(decommitments.clone()),
-            queried_values: TreeVec(queried_values.clone()),
-            proof_of_work: next_u64(state),
-            fri_proof: FriProof {
-                first_layer: FriLayerProof {
-                    fri_witness: first_layer_witness.clone(),
-                    decommitment: first_layer_decommitment.clone(),
-                    commitment: first_layer_commitment,
-                },
-                inner_layers: inner_layers.clone(),
-                last_layer_poly: LinePoly::new(last_layer_poly.clone()),
+        let proof_of_work = next_u64(state);
+        out.push(build_proof_size_vector(ProofSizeInputs {
+            commitments,
+            sampled_values,
+            decommitments,
+            queried_values,
+            proof_of_work,
+            first_layer: FriLayerProof {
+                fri_witness: first_layer_witness,
+                decommitment: first_layer_decommitment,
+                commitment: first_layer_commitment,
             },
-        });
+            inner_layers,
+            last_layer_poly,
+        }));
+    }
+    out
+}
 
-        let breakdown = proof.size_breakdown_estimate();
-        out.push(ProofSizeVector {
-            commitments: commitments.into_iter().map(encode_hash).collect(),
-            sampled_values: sampled_values
-                .into_iter()
-                .map(|tree| {
-                    tree.into_iter()
-                        .map(|col| col.into_iter().map(encode_qm31).collect())
-                        .collect()
-                })
-                .collect(),
-            decommitments: decommitments
-                .into_iter()
-                .map(|decommitment| {
-                    decommitment
-                        .hash_witness
-                        .into_iter()
-                        .map(encode_hash)
-                        .collect()
-                })
-                .collect(),
-            queried_values: queried_values
-                .into_iter()
-                .map(|tree| {
-                    tree.into_iter()
-                        .map(|col| col.into_iter().map(encode_m31).collect())
-                        .collect()
-                })
-                .collect(),
-            proof_of_work: proof.0.proof_of_work,
-            first_layer_witness: first_layer_witness.into_iter().map(encode_qm31).collect(),
-            first_layer_decommitment: first_layer_decommitment
-                .hash_witness
-                .into_iter()
-                .map(encode_hash)
-                .collect(),
-            first_layer_commitment: encode_hash(first_layer_commitment),
-            inner_layers: inner_layers
-                .into_iter()
-                .map(|layer| ProofSizeInnerLayerVector {
-                    fri_witness: layer.fri_witness.into_iter().map(encode_qm31).collect(),
-                    decommitment: layer
-                        .decommitment
-                        .hash_witness
-                        .into_iter()
-                        .map(encode_hash)
-                        .collect(),
-                    commitment: encode_hash(layer.commitment),
-                })
-                .collect(),
-            last_layer_poly: last_layer_poly.into_iter().map(encode_qm31).collect(),
-            expected_breakdown: ProofSizeBreakdownVector {
-                oods_samples: breakdown.oods_samples,
-                queries_values: breakdown.queries_values,
-                fri_samples: breakdown.fri_samples,
-                fri_decommitments: breakdown.fri_decommitments,
-                trace_decommitments: breakdown.trace_decommitments,
-            },
-        });
+struct ProofSizeInputs {
+    commitments: Vec<Blake2sHash>,
+    sampled_values: Vec<Vec<Vec<QM31>>>,
+    decommitments: Vec<MerkleDecommitmentLifted<LiftedMerkleHasher>>,
+    queried_values: Vec<Vec<Vec<M31>>>,
+    proof_of_work: u64,
+    first_layer: FriLayerProof<LiftedMerkleHasher>,
+    inner_layers: Vec<FriLayerProof<LiftedMerkleHasher>>,
+    last_layer_poly: Vec<QM31>,
+}
+
+fn build_proof_size_vector(inputs: ProofSizeInputs) -> ProofSizeVector {
+    let ProofSizeInputs {
+        commitments,
+        sampled_values,
+        decommitments,
+        queried_values,
+        proof_of_work,
+        first_layer,
+        inner_layers,
+        last_layer_poly,
+    } = inputs;
+    let proof = StarkProof::<LiftedMerkleHasher>(CommitmentSchemeProof {
+        config: PcsConfig::default(),
+        commitments: TreeVec(commitments.clone()),
+        sampled_values: TreeVec(sampled_values.clone()),
+        decommitments: TreeVec(decommitments.clone()),
+        queried_values: TreeVec(queried_values.clone()),
+        proof_of_work,
+        fri_proof: FriProof {
+            first_layer: first_layer.clone(),
+            inner_layers: inner_layers.clone(),
+            last_layer_poly: LinePoly::new(last_layer_poly.clone()),
+        },
+    });
+
+    let breakdown = proof.size_breakdown_estimate();
+    ProofSizeVector {
+        commitments: commitments.into_iter().map(encode_hash).collect(),
+        sampled_values: sampled_values
+            .into_iter()
+            .map(|tree| {
+                tree.into_iter()
+                    .map(|col| col.into_iter().map(encode_qm31).collect())
+                    .collect()
+            })
+            .collect(),
+        decommitments: decommitments
+            .into_iter()
+            .map(|decommitment| {
+                decommitment
+                    .hash_witness
+                    .into_iter()
+                    .map(encode_hash)
+                    .collect()
+            })
+            .collect(),
+        queried_values: queried_values
+            .into_iter()
+            .map(|tree| {
+                tree.into_iter()
+                    .map(|col| col.into_iter().map(encode_m31).collect())
+                    .collect()
+            })
+            .collect(),
+        proof_of_work: proof.0.proof_of_work,
+        first_layer_witness: first_layer
+            .fri_witness
+            .into_iter()
+            .map(encode_qm31)
+            .collect(),
+        first_layer_decommitment: first_layer
+            .decommitment
+            .hash_witness
+            .into_iter()
+            .map(encode_hash)
+            .collect(),
+        first_layer_commitment: encode_hash(first_layer.commitment),
+        inner_layers: inner_layers
+            .into_iter()
+            .map(|layer| ProofSizeInnerLayerVector {
+                fri_witness: layer.fri_witness.into_iter().map(encode_qm31).collect(),
+                decommitment: layer
+                    .decommitment
+                    .hash_witness
+                    .into_iter()
+                    .map(encode_hash)
+                    .collect(),
+                commitment: encode_hash(layer.commitment),
+            })
+            .collect(),
+        last_layer_poly: last_layer_poly.into_iter().map(encode_qm31).collect(),
+        expected_breakdown: ProofSizeBreakdownVector {
+            oods_samples: breakdown.oods_samples,
+            queries_values: breakdown.queries_values,
+            fri_samples: breakdown.fri_samples,
+            fri_decommitments: breakdown.fri_decommitments,
+            trace_decommitments: breakdown.trace_decommitments,
+        },
     }
+}
+
+/// Boundary shapes for `size_breakdown_estimate`: a proof with every collection empty (and a
+/// single-coefficient last layer), one whose trees, columns, witnesses and decommitments exist
+/// but are empty, and a large one with hundreds of hashes per decommitment.
+fn generate_proof_size_edge_vectors(state: &mut u64) -> Vec<ProofSizeVector> {
+    let empty_layer = |commitment| FriLayerProof::<LiftedMerkleHasher> {
+        fri_witness: vec![],
+        decommitment: MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+            hash_witness: vec![],
+        },
+        commitment,
+    };
+    let mut out = Vec::with_capacity(3);
+
+    out.push(build_proof_size_vector(ProofSizeInputs {
+        commitments: vec![],
+        sampled_values: vec![],
+        decommitments: vec![],
+        queried_values: vec![],
+        proof_of_work: 0,
+        first_layer: empty_layer(sample_hash(state)),
+        inner_layers: vec![],
+        last_layer_poly: vec![sample_qm31(state, false)],
+    }));
+
+    out.push(build_proof_size_vector(ProofSizeInputs {
+        commitments: vec![sample_hash(state), sample_hash(state)],
+        sampled_values: vec![vec![], vec![vec![]]],
+        decommitments: vec![
+            MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                hash_witness: vec![],
+            };
+            2
+        ],
+        queried_values: vec![vec![], vec![vec![]]],
+        proof_of_work: next_u64(state),
+        first_layer: empty_layer(sample_hash(state)),
+        inner_layers: (0..3).map(|_| empty_layer(sample_hash(state))).collect(),
+        last_layer_poly: vec![sample_qm31(state, false)],
+    }));
+
+    let n_trees = 3;
+    let n_queries = 64;
+    out.push(build_proof_size_vector(ProofSizeInputs {
+        commitments: (0..n_trees).map(|_| sample_hash(state)).collect(),
+        sampled_values: (0..n_trees)
+            .map(|_| {
+                (0..16)
+                    .map(|_| (0..2).map(|_| sample_qm31(state, false)).collect())
+                    .collect()
+            })
+            .collect(),
+        decommitments: (0..n_trees)
+            .map(|_| MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                hash_witness: (0..300).map(|_| sample_hash(state)).collect(),
+            })
+            .collect(),
+        queried_values: (0..n_trees)
+            .map(|_| {
+                (0..16)
+                    .map(|_| (0..n_queries).map(|_| sample_m31(state, false)).collect())
+                    .collect()
+            })
+            .collect(),
+        proof_of_work: next_u64(state),
+        first_layer: FriLayerProof {
+            fri_witness: (0..n_queries).map(|_| sample_qm31(state, false)).collect(),
+            decommitment: MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                hash_witness: (0..400).map(|_| sample_hash(state)).collect(),
+            },
+            commitment: sample_hash(state),
+        },
+        inner_layers: (0..12)
+            .map(|_| FriLayerProof {
+                fri_witness: (0..n_queries).map(|_| sample_qm31(state, false)).collect(),
+                decommitment: MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+                    hash_witness: (0..200).map(|_| sample_hash(state)).collect(),
+                },
+                commitment: sample_hash(state),
+            })
+            .collect(),
+        last_layer_poly: (0..16).map(|_| sample_qm31(state, false)).collect(),
+    }));
+
     out
 }
 
@@ -1284,26 +6599,59 @@ fn line_ifft(values: &mut [QM31], mut domain: LineDomain) {
     }
 }
 
-fn generate_vcs_verifier_vectors(state: &mut u64, count: usize) -> Vec<VcsVerifierVector> {
-    let mut out = Vec::with_capacity(count);
-    while out.len() < count {
-        let mut cases = build_vcs_verifier_cases(state);
+fn generate_vcs_verifier_vectors(
+    state: &mut u64,
+    count: usize,
+    max_log_size: u32,
+    stats: &mut RetryStats,
+) -> Vec<VcsVerifierVector> {
+    let mut out = Vec::with_capacity(count + VCS_SINGLE_ELEMENT_VERIFIER_VECTOR_COUNT);
+    extend_vcs_verifier_vectors(&mut out, state, count, Some(max_log_size), stats);
+    extend_vcs_verifier_vectors(
+        &mut out,
+        state,
+        VCS_SINGLE_ELEMENT_VERIFIER_VECTOR_COUNT,
+        None,
+        stats,
+    );
+    out
+}
+
+/// `max_log_size` bounds the random column log sizes; `None` commits single-element columns.
+fn extend_vcs_verifier_vectors(
+    out: &mut Vec<VcsVerifierVector>,
+    state: &mut u64,
+    count: usize,
+    max_log_size: Option<u32>,
+    stats: &mut RetryStats,
+) {
+    let target = out.len() + count;
+    while out.len() < target {
+        let mut cases = build_vcs_verifier_cases(state, max_log_size);
         if cases.is_empty() {
+            stats.rejections += 1;
             continue;
         }
-        let remaining = count - out.len();
+        let remaining = target - out.len();
         if cases.len() > remaining {
             cases.truncate(remaining);
         }
         out.extend(cases);
     }
-    out
 }
 
-fn generate_vcs_prover_vectors(state: &mut u64, count: usize) -> Vec<VcsProverVector> {
-    let mut out = Vec::with_capacity(count);
-    while out.len() < count {
-        let Some(base) = build_vcs_base_case(state) else {
+fn generate_vcs_prover_vectors(
+    state: &mut u64,
+    count: usize,
+    max_log_size: u32,
+    stats: &mut RetryStats,
+) -> Vec<VcsProverVector> {
+    let mut out = Vec::with_capacity(count + VCS_SINGLE_ELEMENT_PROVER_VECTOR_COUNT);
+    while out.len() < count + VCS_SINGLE_ELEMENT_PROVER_VECTOR_COUNT {
+        // The trailing vectors commit only to single-element (log size 0) columns.
+        let case_max_log_size = (out.len() < count).then_some(max_log_size);
+        let Some(base) = build_vcs_base_case(state, case_max_log_size) else {
+            stats.rejections += 1;
             continue;
         };
         out.push(VcsProverVector {
@@ -1343,11 +6691,17 @@ fn generate_vcs_prover_vectors(state: &mut u64, count: usize) -> Vec<VcsProverVe
 fn generate_vcs_lifted_verifier_vectors(
     state: &mut u64,
     count: usize,
+    max_log_size: u32,
+    stats: &mut RetryStats,
 ) -> Vec<VcsLiftedVerifierVector> {
     let mut out = Vec::with_capacity(count);
+    let mut batch = 0;
     while out.len() < count {
-        let mut cases = build_vcs_lifted_verifier_cases(state);
+        let query_case = VCS_LIFTED_QUERY_CASES[batch % VCS_LIFTED_QUERY_CASES.len()];
+        batch += 1;
+        let mut cases = build_vcs_lifted_verifier_cases(state, query_case, max_log_size);
         if cases.is_empty() {
+            stats.rejections += 1;
             continue;
         }
         let remaining = count - out.len();
@@ -1359,8 +6713,14 @@ fn generate_vcs_lifted_verifier_vectors(
     out
 }
 
-fn build_vcs_lifted_verifier_cases(state: &mut u64) -> Vec<VcsLiftedVerifierVector> {
-    let Some(base) = build_vcs_lifted_base_case(state) else {
+/// Valid and tampered cases around one commitment; the valid case is labelled with `query_case`
+/// unless the queries are random.
+fn build_vcs_lifted_verifier_cases(
+    state: &mut u64,
+    query_case: &str,
+    max_log_size: u32,
+) -> Vec<VcsLiftedVerifierVector> {
+    let Some(base) = build_vcs_lifted_base_case(state, query_case, max_log_size) else {
         return vec![];
     };
 
@@ -1374,36 +6734,29 @@ fn build_vcs_lifted_verifier_cases(state: &mut u64) -> Vec<VcsLiftedVerifierVect
     let mut push_case =
         |case: &str,
          case_root: Blake2sHash,
+         case_column_log_sizes: Vec<u32>,
+         case_query_positions: Vec<usize>,
          case_queried_values: Vec<Vec<M31>>,
          case_decommitment: MerkleDecommitmentLifted<LiftedMerkleHasher>| {
-            let expected = run_vcs_lifted_verifier(
+            out.push(vcs_lifted_verifier_case(
+                case,
                 case_root,
-                column_log_sizes.clone(),
-                query_positions.clone(),
-                case_queried_values.clone(),
-                case_decommitment.clone(),
-            );
-            out.push(VcsLiftedVerifierVector {
-                case: case.to_string(),
-                root: encode_hash(case_root),
-                column_log_sizes: column_log_sizes.clone(),
-                query_positions: query_positions.clone(),
-                queried_values: case_queried_values
-                    .into_iter()
-                    .map(|column| column.into_iter().map(encode_m31).collect())
-                    .collect(),
-                hash_witness: case_decommitment
-                    .hash_witness
-                    .into_iter()
-                    .map(encode_hash)
-                    .collect(),
-                expected,
-            });
+                case_column_log_sizes,
+                case_query_positions,
+                case_queried_values,
+                case_decommitment,
+            ));
         };
 
     push_case(
-        "valid",
+        if query_case == "random" {
+            "valid"
+        } else {
+            query_case
+        },
         root,
+        column_log_sizes.clone(),
+        query_positions.clone(),
         queried_values.clone(),
         base_decommitment.clone(),
     );
@@ -1413,6 +6766,8 @@ fn build_vcs_lifted_verifier_cases(state: &mut u64) -> Vec<VcsLiftedVerifierVect
     push_case(
         "root_mismatch",
         bad_root,
+        column_log_sizes.clone(),
+        query_positions.clone(),
         queried_values.clone(),
         base_decommitment.clone(),
     );
@@ -1420,12 +6775,40 @@ fn build_vcs_lifted_verifier_cases(state: &mut u64) -> Vec<VcsLiftedVerifierVect
     if !base_decommitment.hash_witness.is_empty() {
         let mut short = base_decommitment.clone();
         short.hash_witness.pop();
-        push_case("witness_too_short", root, queried_values.clone(), short);
+        push_case(
+            "witness_too_short",
+            root,
+            column_log_sizes.clone(),
+            query_positions.clone(),
+            queried_values.clone(),
+            short,
+        );
     }
 
     let mut long = base_decommitment.clone();
     long.hash_witness.push(sample_hash(state));
-    push_case("witness_too_long", root, queried_values.clone(), long);
+    push_case(
+        "witness_too_long",
+        root,
+        column_log_sizes.clone(),
+        query_positions.clone(),
+        queried_values.clone(),
+        long,
+    );
+
+    // The lifted decommitment carries no column witness, so only hash content can be tampered.
+    if !base_decommitment.hash_witness.is_empty() {
+        let mut tampered = base_decommitment.clone();
+        flip_random_hash_bit(state, &mut tampered.hash_witness);
+        push_case(
+            "hash_witness_value_tampered",
+            root,
+            column_log_sizes.clone(),
+            query_positions.clone(),
+            queried_values.clone(),
+            tampered,
+        );
+    }
 
     if !queried_values.is_empty() && !queried_values[0].is_empty() {
         let mut bad_values = queried_values.clone();
@@ -1433,21 +6816,197 @@ fn build_vcs_lifted_verifier_cases(state: &mut u64) -> Vec<VcsLiftedVerifierVect
         push_case(
             "queried_values_mismatch",
             root,
+            column_log_sizes.clone(),
+            query_positions.clone(),
             bad_values,
-            base_decommitment,
+            base_decommitment.clone(),
+        );
+    }
+
+    // Columns handed over in reverse of their commitment order, sizes and values kept paired.
+    let mut reordered_log_sizes = column_log_sizes.clone();
+    reordered_log_sizes.reverse();
+    let mut reordered_values = queried_values.clone();
+    reordered_values.reverse();
+    push_case(
+        "columns_reordered",
+        root,
+        reordered_log_sizes,
+        query_positions.clone(),
+        reordered_values,
+        base_decommitment.clone(),
+    );
+
+    // Query positions in descending order, with every column's values permuted to match.
+    if query_positions.len() >= 2 {
+        let mut unsorted_queries = query_positions.clone();
+        unsorted_queries.reverse();
+        let unsorted_values = queried_values
+            .iter()
+            .map(|column| column.iter().rev().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        push_case(
+            "queries_unsorted",
+            root,
+            column_log_sizes.clone(),
+            unsorted_queries,
+            unsorted_values,
+            base_decommitment.clone(),
         );
     }
 
+    // The first query position repeated, with its values repeated in every column.
+    let mut duplicated_queries = query_positions.clone();
+    duplicated_queries.insert(1, query_positions[0]);
+    let duplicated_values = queried_values
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            column.insert(1, column[0]);
+            column
+        })
+        .collect::<Vec<_>>();
+    push_case(
+        "queries_duplicated",
+        root,
+        column_log_sizes,
+        duplicated_queries,
+        duplicated_values,
+        base_decommitment,
+    );
+
+    out
+}
+
+/// Runs the lifted verifier on one layout and records the outcome as `expected`.
+fn vcs_lifted_verifier_case(
+    case: &str,
+    root: Blake2sHash,
+    column_log_sizes: Vec<u32>,
+    query_positions: Vec<usize>,
+    queried_values: Vec<Vec<M31>>,
+    decommitment: MerkleDecommitmentLifted<LiftedMerkleHasher>,
+) -> VcsLiftedVerifierVector {
+    let expected = run_vcs_lifted_verifier(
+        root,
+        column_log_sizes.clone(),
+        query_positions.clone(),
+        queried_values.clone(),
+        decommitment.clone(),
+    );
+    VcsLiftedVerifierVector {
+        case: case.to_string(),
+        root: encode_hash(root),
+        column_log_sizes,
+        query_positions,
+        queried_values: queried_values
+            .into_iter()
+            .map(|column| column.into_iter().map(encode_m31).collect())
+            .collect(),
+        hash_witness: decommitment
+            .hash_witness
+            .into_iter()
+            .map(encode_hash)
+            .collect(),
+        expected,
+    }
+}
+
+/// Probes every classic `MerkleVerificationError` variant against one lifted commitment. The
+/// lifted error enum only has `WitnessTooShort`, `WitnessTooLong` and `RootMismatch` (the match in
+/// `merkle_error_name_lifted` is exhaustive), so the queried-values count probes can only surface
+/// as one of those, `ok` or `Panic`; generation fails if a lifted variant stops being reachable.
+fn generate_vcs_lifted_error_variant_vectors(
+    state: &mut u64,
+    stats: &mut RetryStats,
+) -> Vec<VcsLiftedErrorVariantVector> {
+    // Witness-length probes need a non-empty witness, which all-leaves queries may not have.
+    let base = loop {
+        match build_vcs_lifted_base_case(state, "random", VCS_MAX_COLUMN_LOG_SIZE) {
+            Some(base) if !base.decommitment.hash_witness.is_empty() => break base,
+            _ => stats.rejections += 1,
+        }
+    };
+
+    let probe = |case: &str| {
+        let mut root = base.root;
+        let mut queried_values = base.queried_values.clone();
+        let mut decommitment = base.decommitment.clone();
+        match case {
+            "witness_too_short" => {
+                decommitment.hash_witness.pop();
+            }
+            "witness_too_long" => decommitment.hash_witness.push(Blake2sHash([0u8; 32])),
+            "root_mismatch" => root.0[0] ^= 1,
+            "extra_column" => {
+                let last = queried_values.last().expect("lifted base case has columns");
+                queried_values.push(last.clone());
+            }
+            "missing_column" => {
+                queried_values.pop();
+            }
+            "extra_query_value" => {
+                let first = queried_values[0][0];
+                queried_values[0].push(first);
+            }
+            "missing_query_value" => {
+                queried_values[0].pop();
+            }
+            other => unreachable!("unknown lifted error probe {other}"),
+        }
+        vcs_lifted_verifier_case(
+            case,
+            root,
+            base.column_log_sizes.clone(),
+            base.query_positions.clone(),
+            queried_values,
+            decommitment,
+        )
+    };
+
+    let out: Vec<_> = MERKLE_ERROR_VARIANT_PROBES
+        .iter()
+        .map(|&(variant, cases)| {
+            let probes: Vec<_> = cases.iter().map(|case| probe(case)).collect();
+            let reachable = probes.iter().any(|p| p.expected == variant);
+            VcsLiftedErrorVariantVector {
+                variant: variant.to_string(),
+                status: if reachable {
+                    "reachable"
+                } else {
+                    "unreachable_variant"
+                }
+                .to_string(),
+                probes,
+            }
+        })
+        .collect();
+    for v in &out {
+        if matches!(
+            v.variant.as_str(),
+            "WitnessTooShort" | "WitnessTooLong" | "RootMismatch"
+        ) {
+            assert_eq!(v.status, "reachable", "lifted {} not reachable", v.variant);
+        }
+    }
     out
 }
 
-fn generate_vcs_lifted_prover_vectors(state: &mut u64, count: usize) -> Vec<VcsLiftedProverVector> {
+fn generate_vcs_lifted_prover_vectors(
+    state: &mut u64,
+    count: usize,
+    max_log_size: u32,
+    stats: &mut RetryStats,
+) -> Vec<VcsLiftedProverVector> {
     let mut out = Vec::with_capacity(count);
     while out.len() < count {
-        let Some(base) = build_vcs_lifted_base_case(state) else {
+        let query_case = VCS_LIFTED_QUERY_CASES[out.len() % VCS_LIFTED_QUERY_CASES.len()];
+        let Some(base) = build_vcs_lifted_base_case(state, query_case, max_log_size) else {
+            stats.rejections += 1;
             continue;
         };
         out.push(VcsLiftedProverVector {
+            case: query_case.to_string(),
             root: encode_hash(base.root),
             column_log_sizes: base.column_log_sizes.clone(),
             columns: base
@@ -1467,17 +7026,122 @@ fn generate_vcs_lifted_prover_vectors(state: &mut u64, count: usize) -> Vec<VcsL
                 .into_iter()
                 .map(encode_hash)
                 .collect(),
+            layer_queries: base.layer_queries,
+        });
+    }
+    out
+}
+
+fn generate_queried_rows_reshape_vectors(
+    state: &mut u64,
+    count: usize,
+    stats: &mut RetryStats,
+) -> Vec<QueriedRowsReshapeVector> {
+    let mut out = Vec::with_capacity(count);
+    while out.len() < count {
+        // At least one column below the query domain, so some values are shared by queries.
+        let query_log_size = 3 + (next_u64(state) as u32 % 2);
+        let n_columns = 3 + (next_u64(state) as usize % 3);
+        let column_log_sizes = (0..n_columns)
+            .map(|c| match c {
+                0 => query_log_size,
+                1 => 1,
+                _ => 1 + (next_u64(state) as u32 % query_log_size),
+            })
+            .collect::<Vec<_>>();
+        let columns = column_log_sizes
+            .iter()
+            .enumerate()
+            .map(|(c, &log_size)| {
+                (0..1u32 << log_size)
+                    .map(|row| M31::from_u32_unchecked(((c as u32 + 1) << 16) | row))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // An adjacent pair plus a few random positions.
+        let domain_size = 1usize << query_log_size;
+        let pair = next_u64(state) as usize & (domain_size - 2);
+        let mut query_positions = vec![pair, pair + 1];
+        for _ in 0..1 + (next_u64(state) as usize % 3) {
+            query_positions.push(next_u64(state) as usize & (domain_size - 1));
+        }
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        let Some(case) = commit_vcs_lifted_case(column_log_sizes, columns, query_positions) else {
+            stats.rejections += 1;
+            continue;
+        };
+        let source_rows = case
+            .column_log_sizes
+            .iter()
+            .map(|&log_size| {
+                let shift = query_log_size - log_size;
+                case.query_positions
+                    .iter()
+                    .map(|pos| (pos >> (shift + 1) << 1) + (pos & 1))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for (c, rows) in source_rows.iter().enumerate() {
+            for (q, &row) in rows.iter().enumerate() {
+                assert_eq!(case.queried_values[c][q], case.columns[c][row]);
+            }
+        }
+        let query_rows = (0..case.query_positions.len())
+            .map(|q| {
+                case.queried_values
+                    .iter()
+                    .map(|column| encode_m31(column[q]))
+                    .collect()
+            })
+            .collect();
+
+        out.push(QueriedRowsReshapeVector {
+            root: encode_hash(case.root),
+            column_log_sizes: case.column_log_sizes.clone(),
+            query_log_size,
+            query_positions: case.query_positions.clone(),
+            hash_witness: case
+                .decommitment
+                .hash_witness
+                .iter()
+                .copied()
+                .map(encode_hash)
+                .collect(),
+            queried_values: case
+                .queried_values
+                .iter()
+                .map(|column| column.iter().copied().map(encode_m31).collect())
+                .collect(),
+            source_rows,
+            query_rows,
         });
     }
     out
 }
 
-fn build_vcs_lifted_base_case(state: &mut u64) -> Option<VcsLiftedBaseCase> {
+/// Random columns with queries following `query_case` (one of `VCS_LIFTED_QUERY_CASES`):
+/// `adjacent_queries` takes an aligned quad `4j..4j + 4` (siblings at the leaves and again one
+/// layer up), a sibling pair `(2k, 2k + 1)` outside it and one random position;
+/// `all_leaves_queried` takes every position, leaving an empty hash witness. Column log sizes
+/// run from 1 to `max_log_size` (at least 4); the first `adjacent_queries` column takes one of
+/// the top two.
+fn build_vcs_lifted_base_case(
+    state: &mut u64,
+    query_case: &str,
+    max_log_size: u32,
+) -> Option<VcsLiftedBaseCase> {
     let n_columns = 2 + (next_u64(state) as usize % 4);
     let mut column_log_sizes = Vec::with_capacity(n_columns);
     let mut columns = Vec::with_capacity(n_columns);
-    for _ in 0..n_columns {
-        let log_size = 1 + (next_u64(state) as u32 % 4);
+    for c in 0..n_columns {
+        let log_size = if c == 0 && query_case == "adjacent_queries" {
+            max_log_size - 1 + (next_u64(state) as u32 % 2)
+        } else {
+            1 + (next_u64(state) as u32 % max_log_size)
+        };
         column_log_sizes.push(log_size);
         let col = (0..(1usize << log_size))
             .map(|_| sample_m31(state, false))
@@ -1488,33 +7152,45 @@ fn build_vcs_lifted_base_case(state: &mut u64) -> Option<VcsLiftedBaseCase> {
     let max_log_size = *column_log_sizes.iter().max().expect("at least one column");
     let domain_size = 1usize << max_log_size;
     let mut query_positions = Vec::with_capacity(4);
-    let n_queries = 1 + (next_u64(state) as usize % domain_size.min(4));
-    while query_positions.len() < n_queries {
-        let q = next_u64(state) as usize & (domain_size - 1);
-        if !query_positions.contains(&q) {
-            query_positions.push(q);
+    match query_case {
+        "adjacent_queries" => {
+            let quad = next_u64(state) as usize & (domain_size - 4);
+            let pair =
+                (quad + 4 + 2 * (next_u64(state) as usize % ((domain_size - 4) / 2))) % domain_size;
+            query_positions.extend(quad..quad + 4);
+            query_positions.extend([pair, pair + 1]);
+            query_positions.push(next_u64(state) as usize & (domain_size - 1));
+            query_positions.sort_unstable();
+            query_positions.dedup();
+        }
+        "all_leaves_queried" => query_positions.extend(0..domain_size),
+        _ => {
+            let n_queries = 1 + (next_u64(state) as usize % domain_size.min(4));
+            while query_positions.len() < n_queries {
+                let q = next_u64(state) as usize & (domain_size - 1);
+                if !query_positions.contains(&q) {
+                    query_positions.push(q);
+                }
+            }
+            query_positions.sort_unstable();
         }
     }
-    query_positions.sort_unstable();
-
-    let mut sorted_indices = (0..columns.len()).collect::<Vec<_>>();
-    sorted_indices.sort_by_key(|&i| (column_log_sizes[i], i));
-    let sorted_columns = sorted_indices
-        .iter()
-        .map(|&i| &columns[i])
-        .collect::<Vec<_>>();
 
-    let leaves = build_vcs_lifted_leaves(&sorted_columns);
-    let mut layers = vec![leaves];
-    while layers.last().expect("at least one layer").len() > 1 {
-        let prev = layers.last().expect("previous layer");
-        layers.push(
-            (0..(prev.len() >> 1))
-                .map(|i| LiftedMerkleHasher::hash_children((prev[2 * i], prev[2 * i + 1])))
-                .collect(),
-        );
+    let case = commit_vcs_lifted_case(column_log_sizes, columns, query_positions)?;
+    if query_case == "all_leaves_queried" {
+        assert!(case.decommitment.hash_witness.is_empty());
     }
-    layers.reverse();
+    Some(case)
+}
+
+/// Commits `columns` in a lifted Merkle tree and decommits `query_positions` (sorted, distinct,
+/// in the largest column's domain); `None` if upstream rejects the decommitment.
+fn commit_vcs_lifted_case(
+    column_log_sizes: Vec<u32>,
+    columns: Vec<Vec<M31>>,
+    query_positions: Vec<usize>,
+) -> Option<VcsLiftedBaseCase> {
+    let layers = vcs_lifted_layers(&column_log_sizes, &columns);
     let root = layers
         .first()
         .expect("root layer")
@@ -1536,6 +7212,7 @@ fn build_vcs_lifted_base_case(state: &mut u64) -> Option<VcsLiftedBaseCase> {
         .collect::<Vec<_>>();
 
     let mut hash_witness = Vec::<Blake2sHash>::new();
+    let mut layer_queries = Vec::<Vec<usize>>::with_capacity(layers.len() - 1);
     let mut prev_layer_queries = query_positions.clone();
     prev_layer_queries.dedup();
     for layer_log_size in (0..layers.len() - 1).rev() {
@@ -1556,6 +7233,7 @@ fn build_vcs_lifted_base_case(state: &mut u64) -> Option<VcsLiftedBaseCase> {
             curr_layer_queries.push(first >> 1);
             p += chunk_len;
         }
+        layer_queries.push(curr_layer_queries.clone());
         prev_layer_queries = curr_layer_queries;
     }
 
@@ -1579,9 +7257,33 @@ fn build_vcs_lifted_base_case(state: &mut u64) -> Option<VcsLiftedBaseCase> {
         query_positions,
         queried_values,
         decommitment,
+        layer_queries,
     })
 }
 
+/// Every layer of the lifted Merkle tree over `columns`, root layer first.
+fn vcs_lifted_layers(column_log_sizes: &[u32], columns: &[Vec<M31>]) -> Vec<Vec<Blake2sHash>> {
+    let mut sorted_indices = (0..columns.len()).collect::<Vec<_>>();
+    sorted_indices.sort_by_key(|&i| (column_log_sizes[i], i));
+    let sorted_columns = sorted_indices
+        .iter()
+        .map(|&i| &columns[i])
+        .collect::<Vec<_>>();
+
+    let leaves = build_vcs_lifted_leaves(&sorted_columns);
+    let mut layers = vec![leaves];
+    while layers.last().expect("at least one layer").len() > 1 {
+        let prev = layers.last().expect("previous layer");
+        layers.push(
+            (0..(prev.len() >> 1))
+                .map(|i| LiftedMerkleHasher::hash_children((prev[2 * i], prev[2 * i + 1])))
+                .collect(),
+        );
+    }
+    layers.reverse();
+    layers
+}
+
 fn build_vcs_lifted_leaves(columns: &[&Vec<M31>]) -> Vec<Blake2sHash> {
     let hasher = LiftedMerkleHasher::default_with_initial_state();
     if columns.is_empty() {
@@ -1613,16 +7315,59 @@ fn build_vcs_lifted_leaves(columns: &[&Vec<M31>]) -> Vec<Blake2sHash> {
         prev_layer_log_size = log_size;
         group_start = group_end;
     }
-
-    prev_layer.into_iter().map(|h| h.finalize()).collect()
+
+    prev_layer.into_iter().map(|h| h.finalize()).collect()
+}
+
+/// Node hashes of every layer of the (non-lifted) Merkle tree over `columns_by_layer`, keyed by
+/// layer log size.
+fn vcs_layer_hashes(
+    columns_by_layer: &BTreeMap<u32, Vec<Vec<M31>>>,
+    max_log_size: u32,
+) -> BTreeMap<u32, Vec<Blake2sHash>> {
+    let mut layer_hashes = BTreeMap::<u32, Vec<Blake2sHash>>::new();
+    for layer_log_size in (0..=max_log_size).rev() {
+        let n_nodes = 1usize << layer_log_size;
+        let layer_columns = columns_by_layer
+            .get(&layer_log_size)
+            .cloned()
+            .unwrap_or_default();
+        let prev_layer = if layer_log_size == max_log_size {
+            None
+        } else {
+            Some(
+                layer_hashes
+                    .get(&(layer_log_size + 1))
+                    .expect("previous layer should be available"),
+            )
+        };
+
+        let mut hashes = Vec::with_capacity(n_nodes);
+        for node_index in 0..n_nodes {
+            let children = prev_layer.map(|p| (p[2 * node_index], p[2 * node_index + 1]));
+            let node_values = layer_columns
+                .iter()
+                .map(|column| column[node_index])
+                .collect::<Vec<_>>();
+            hashes.push(VcsMerkleHasher::hash_node(children, &node_values));
+        }
+        layer_hashes.insert(layer_log_size, hashes);
+    }
+    layer_hashes
 }
 
-fn build_vcs_base_case(state: &mut u64) -> Option<VcsBaseCase> {
+/// Samples a random commitment and a valid decommitment for it, with column log sizes up to
+/// `max_log_size`. With `None` every column has log size 0, so the whole tree is a single root
+/// node.
+fn build_vcs_base_case(state: &mut u64, max_log_size: Option<u32>) -> Option<VcsBaseCase> {
     let n_columns = 2 + (next_u64(state) as usize % 4);
     let mut column_log_sizes = Vec::with_capacity(n_columns);
     let mut columns = Vec::with_capacity(n_columns);
     for _ in 0..n_columns {
-        let log_size = 1 + (next_u64(state) as u32 % 4);
+        let log_size = match max_log_size {
+            Some(max_log_size) => next_u64(state) as u32 % (max_log_size + 1),
+            None => 0,
+        };
         column_log_sizes.push(log_size);
         let col = (0..(1usize << log_size))
             .map(|_| sample_m31(state, false))
@@ -1658,34 +7403,7 @@ fn build_vcs_base_case(state: &mut u64) -> Option<VcsBaseCase> {
         queries_per_log_size.insert(log_size, queries);
     }
 
-    let mut layer_hashes = BTreeMap::<u32, Vec<Blake2sHash>>::new();
-    for layer_log_size in (0..=max_log_size).rev() {
-        let n_nodes = 1usize << layer_log_size;
-        let layer_columns = columns_by_layer
-            .get(&layer_log_size)
-            .cloned()
-            .unwrap_or_default();
-        let prev_layer = if layer_log_size == max_log_size {
-            None
-        } else {
-            Some(
-                layer_hashes
-                    .get(&(layer_log_size + 1))
-                    .expect("previous layer should be available"),
-            )
-        };
-
-        let mut hashes = Vec::with_capacity(n_nodes);
-        for node_index in 0..n_nodes {
-            let children = prev_layer.map(|p| (p[2 * node_index], p[2 * node_index + 1]));
-            let node_values = layer_columns
-                .iter()
-                .map(|column| column[node_index])
-                .collect::<Vec<_>>();
-            hashes.push(VcsMerkleHasher::hash_node(children, &node_values));
-        }
-        layer_hashes.insert(layer_log_size, hashes);
-    }
+    let layer_hashes = vcs_layer_hashes(&columns_by_layer, max_log_size);
     let root = layer_hashes
         .get(&0)
         .expect("root layer")
@@ -1777,8 +7495,8 @@ fn build_vcs_base_case(state: &mut u64) -> Option<VcsBaseCase> {
     })
 }
 
-fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
-    let Some(base) = build_vcs_base_case(state) else {
+fn build_vcs_verifier_cases(state: &mut u64, max_log_size: Option<u32>) -> Vec<VcsVerifierVector> {
+    let Some(base) = build_vcs_base_case(state, max_log_size) else {
         return vec![];
     };
 
@@ -1789,15 +7507,16 @@ fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
     let base_decommitment = base.decommitment.clone();
 
     let mut out = Vec::<VcsVerifierVector>::new();
-    let mut push_case =
+    let mut push_case_with_queries =
         |case: &str,
          case_root: Blake2sHash,
+         case_queries: &BTreeMap<u32, Vec<usize>>,
          case_queried_values: Vec<M31>,
          case_decommitment: MerkleDecommitment<VcsMerkleHasher>| {
             let expected = run_vcs_verifier(
                 case_root,
                 column_log_sizes.clone(),
-                queries_per_log_size.clone(),
+                case_queries.clone(),
                 case_queried_values.clone(),
                 case_decommitment.clone(),
             );
@@ -1805,7 +7524,7 @@ fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
                 case: case.to_string(),
                 root: encode_hash(case_root),
                 column_log_sizes: column_log_sizes.clone(),
-                queries_per_log_size: queries_per_log_size
+                queries_per_log_size: case_queries
                     .iter()
                     .map(|(log_size, queries)| VcsLogSizeQueriesVector {
                         log_size: *log_size,
@@ -1827,6 +7546,20 @@ fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
             });
         };
 
+    let mut push_case =
+        |case: &str,
+         case_root: Blake2sHash,
+         case_queried_values: Vec<M31>,
+         case_decommitment: MerkleDecommitment<VcsMerkleHasher>| {
+            push_case_with_queries(
+                case,
+                case_root,
+                &queries_per_log_size,
+                case_queried_values,
+                case_decommitment,
+            )
+        };
+
     push_case(
         "valid",
         root,
@@ -1857,6 +7590,29 @@ fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
     long.hash_witness.push(sample_hash(state));
     push_case("witness_too_long", root, queried_values.clone(), long);
 
+    // Content corruption with every length intact, the case that separates RootMismatch from
+    // the length errors.
+    if !base_decommitment.hash_witness.is_empty() {
+        let mut tampered = base_decommitment.clone();
+        flip_random_hash_bit(state, &mut tampered.hash_witness);
+        push_case(
+            "hash_witness_value_tampered",
+            root,
+            queried_values.clone(),
+            tampered,
+        );
+    }
+    if !base_decommitment.column_witness.is_empty() {
+        let mut tampered = base_decommitment.clone();
+        flip_random_m31_bit(state, &mut tampered.column_witness);
+        push_case(
+            "column_witness_value_tampered",
+            root,
+            queried_values.clone(),
+            tampered,
+        );
+    }
+
     if !queried_values.is_empty() {
         let mut short_values = queried_values.clone();
         short_values.pop();
@@ -1874,6 +7630,39 @@ fn build_vcs_verifier_cases(state: &mut u64) -> Vec<VcsVerifierVector> {
         "queried_values_too_long",
         root,
         long_values,
+        base_decommitment.clone(),
+    );
+
+    // Queries for a log size with no columns, and a column log size with no queries: upstream's
+    // outcome for both is the contract, whatever it is.
+    let column_less_log_size = (0..)
+        .find(|log_size| !column_log_sizes.contains(log_size))
+        .expect("some log size has no columns");
+    let mut extra_log_size_queries = queries_per_log_size.clone();
+    let n_extra_queries = 1 + (next_u64(state) as usize % 2);
+    let mut extra_queries = (0..n_extra_queries)
+        .map(|_| next_u64(state) as usize & ((1usize << column_less_log_size) - 1))
+        .collect::<Vec<_>>();
+    extra_queries.sort_unstable();
+    extra_queries.dedup();
+    extra_log_size_queries.insert(column_less_log_size, extra_queries);
+    push_case_with_queries(
+        "queries_for_log_size_without_columns",
+        root,
+        &extra_log_size_queries,
+        queried_values.clone(),
+        base_decommitment.clone(),
+    );
+
+    let present_log_sizes = queries_per_log_size.keys().copied().collect::<Vec<_>>();
+    let dropped_log_size = present_log_sizes[next_u64(state) as usize % present_log_sizes.len()];
+    let mut missing_log_size_queries = queries_per_log_size.clone();
+    missing_log_size_queries.remove(&dropped_log_size);
+    push_case_with_queries(
+        "column_log_size_without_queries",
+        root,
+        &missing_log_size_queries,
+        queried_values,
         base_decommitment,
     );
 
@@ -1900,13 +7689,27 @@ fn run_vcs_verifier(
     queried_values: Vec<M31>,
     decommitment: MerkleDecommitment<VcsMerkleHasher>,
 ) -> String {
-    let verifier = MerkleVerifier::<VcsMerkleHasher>::new(root, column_log_sizes);
-    match verifier.verify(&queries_per_log_size, queried_values, decommitment) {
-        Ok(()) => "ok".to_string(),
-        Err(err) => merkle_error_name(err).to_string(),
+    // Query maps naming log sizes without columns (or omitting ones with columns) may make
+    // upstream panic rather than return an error; record that as "Panic".
+    let result = std::panic::catch_unwind(move || {
+        let verifier = MerkleVerifier::<VcsMerkleHasher>::new(root, column_log_sizes);
+        verifier.verify(&queries_per_log_size, queried_values, decommitment)
+    });
+    match result {
+        Ok(Ok(())) => "ok".to_string(),
+        Ok(Err(err)) => merkle_error_name(err).to_string(),
+        Err(_) => "Panic".to_string(),
     }
 }
 
+const MERKLE_ERROR_NAMES: [&str; 5] = [
+    "WitnessTooShort",
+    "WitnessTooLong",
+    "TooManyQueriedValues",
+    "TooFewQueriedValues",
+    "RootMismatch",
+];
+
 fn merkle_error_name(err: MerkleVerificationError) -> &'static str {
     match err {
         MerkleVerificationError::WitnessTooShort => "WitnessTooShort",
@@ -1924,13 +7727,21 @@ fn run_vcs_lifted_verifier(
     queried_values: Vec<Vec<M31>>,
     decommitment: MerkleDecommitmentLifted<LiftedMerkleHasher>,
 ) -> String {
-    let verifier = MerkleVerifierLifted::<LiftedMerkleHasher>::new(root, column_log_sizes);
-    match verifier.verify(&query_positions, queried_values, decommitment) {
-        Ok(()) => "ok".to_string(),
-        Err(err) => merkle_error_name_lifted(err).to_string(),
+    // Malformed layouts (reordered columns, duplicated queries) may make upstream panic rather
+    // than return an error; that outcome is part of the contract, so record it as "Panic".
+    let result = std::panic::catch_unwind(move || {
+        let verifier = MerkleVerifierLifted::<LiftedMerkleHasher>::new(root, column_log_sizes);
+        verifier.verify(&query_positions, queried_values, decommitment)
+    });
+    match result {
+        Ok(Ok(())) => "ok".to_string(),
+        Ok(Err(err)) => merkle_error_name_lifted(err).to_string(),
+        Err(_) => "Panic".to_string(),
     }
 }
 
+const MERKLE_LIFTED_ERROR_NAMES: [&str; 3] = ["WitnessTooShort", "WitnessTooLong", "RootMismatch"];
+
 fn merkle_error_name_lifted(err: MerkleVerificationErrorLifted) -> &'static str {
     match err {
         MerkleVerificationErrorLifted::WitnessTooShort => "WitnessTooShort",
@@ -1939,6 +7750,49 @@ fn merkle_error_name_lifted(err: MerkleVerificationErrorLifted) -> &'static str
     }
 }
 
+const FRI_VERIFICATION_ERROR_NAMES: [&str; 7] = [
+    "InvalidNumFriLayers",
+    "FirstLayerEvaluationsInvalid",
+    "FirstLayerCommitmentInvalid",
+    "InnerLayerCommitmentInvalid",
+    "InnerLayerEvaluationsInvalid",
+    "LastLayerDegreeInvalid",
+    "LastLayerEvaluationsInvalid",
+];
+
+fn fri_verification_error_name(err: &FriVerificationError) -> &'static str {
+    match err {
+        FriVerificationError::InvalidNumFriLayers { .. } => "InvalidNumFriLayers",
+        FriVerificationError::FirstLayerEvaluationsInvalid { .. } => "FirstLayerEvaluationsInvalid",
+        FriVerificationError::FirstLayerCommitmentInvalid { .. } => "FirstLayerCommitmentInvalid",
+        FriVerificationError::InnerLayerCommitmentInvalid { .. } => "InnerLayerCommitmentInvalid",
+        FriVerificationError::InnerLayerEvaluationsInvalid { .. } => "InnerLayerEvaluationsInvalid",
+        FriVerificationError::LastLayerDegreeInvalid { .. } => "LastLayerDegreeInvalid",
+        FriVerificationError::LastLayerEvaluationsInvalid { .. } => "LastLayerEvaluationsInvalid",
+    }
+}
+
+const VERIFICATION_ERROR_NAMES: [&str; 5] = [
+    "InvalidStructure",
+    "OodsNotMatching",
+    "Merkle",
+    "ProofOfWork",
+    "Fri",
+];
+
+fn verification_error_name(err: &VerificationError) -> &'static str {
+    match err {
+        VerificationError::InvalidStructure { .. } => "InvalidStructure",
+        VerificationError::OodsNotMatching { .. } => "OodsNotMatching",
+        VerificationError::Merkle { .. } => "Merkle",
+        VerificationError::ProofOfWork { .. } => "ProofOfWork",
+        VerificationError::Fri { .. } => "Fri",
+    }
+}
+
+/// `StarkProof::extract_composition_oods_eval` fails with a single unit struct, recorded by name.
+const OODS_SAMPLE_ERROR_NAMES: [&str; 1] = ["InvalidOodsSampleStructure"];
+
 fn generate_pcs_preprocessed_query_vectors(
     state: &mut u64,
     count: usize,
@@ -1965,30 +7819,68 @@ fn generate_pcs_preprocessed_query_vectors(
         }
         query_positions.sort_unstable();
 
-        out.push(PcsPreprocessedQueryVector {
-            expected: prepare_preprocessed_query_positions(
-                &query_positions,
-                max_log_size,
-                pp_max_log_size,
-            ),
+        out.push(pcs_preprocessed_query_vector(
+            "random",
             query_positions,
             max_log_size,
             pp_max_log_size,
-        });
+        ));
     }
+
+    let edge_cases: [(&str, Vec<usize>, u32, u32); 8] = [
+        ("empty_queries", vec![], 4, 3),
+        ("empty_queries_pp_larger", vec![], 2, 4),
+        ("single_query_at_zero", vec![0], 4, 2),
+        ("all_positions", (0..8).collect(), 3, 2),
+        ("all_positions_pp_larger", (0..4).collect(), 2, 4),
+        ("equal_max_sizes", vec![0, 1, 6, 9, 14, 15], 4, 4),
+        ("max_log_size_zero", vec![0], 0, 0),
+        ("max_log_size_zero_pp_larger", vec![0], 0, 3),
+    ];
+    out.extend(edge_cases.into_iter().map(
+        |(case, query_positions, max_log_size, pp_max_log_size)| {
+            pcs_preprocessed_query_vector(case, query_positions, max_log_size, pp_max_log_size)
+        },
+    ));
     out
 }
 
-fn generate_fri_fold_vectors(state: &mut u64, count: usize) -> Vec<FriFoldVector> {
+fn pcs_preprocessed_query_vector(
+    case: &str,
+    query_positions: Vec<usize>,
+    max_log_size: u32,
+    pp_max_log_size: u32,
+) -> PcsPreprocessedQueryVector {
+    PcsPreprocessedQueryVector {
+        case: case.to_string(),
+        expected: prepare_preprocessed_query_positions(
+            &query_positions,
+            max_log_size,
+            pp_max_log_size,
+        ),
+        query_positions,
+        max_log_size,
+        pp_max_log_size,
+    }
+}
+
+/// Both evaluation log sizes are drawn from the inclusive range `log_sizes`.
+fn generate_fri_fold_vectors(
+    state: &mut u64,
+    count: usize,
+    log_sizes: (u32, u32),
+) -> Vec<FriFoldVector> {
+    let (min_log_size, max_log_size) = log_sizes;
+    let log_size_span = max_log_size - min_log_size + 1;
     let mut out = Vec::with_capacity(count);
     for _ in 0..count {
-        let line_log_size = 2 + ((next_u64(state) as u32) % 5);
+        let line_log_size = min_log_size + ((next_u64(state) as u32) % log_size_span);
         let line_len = 1usize << line_log_size;
         let line_eval = (0..line_len)
             .map(|_| sample_qm31(state, false))
             .collect::<Vec<_>>();
 
-        let circle_log_size = 2 + ((next_u64(state) as u32) % 5);
+        let circle_log_size = min_log_size + ((next_u64(state) as u32) % log_size_span);
         let circle_len = 1usize << circle_log_size;
         let circle_eval = (0..circle_len)
             .map(|_| sample_qm31(state, false))
@@ -2023,6 +7915,355 @@ fn generate_fri_fold_vectors(state: &mut u64, count: usize) -> Vec<FriFoldVector
     out
 }
 
+/// Alternates `fold_line` and `fold_circle_into_line`, each applied to one evaluation vector in
+/// its contract (bit-reversed) order and in natural order.
+fn generate_fri_fold_ordering_vectors(state: &mut u64, count: usize) -> Vec<FriFoldOrderingVector> {
+    let mut out = Vec::with_capacity(count);
+    for vector_index in 0..count {
+        let log_size = 2 + ((next_u64(state) as u32) % 5);
+        let evaluations_bit_reversed = (0..1usize << log_size)
+            .map(|_| sample_qm31(state, false))
+            .collect::<Vec<_>>();
+        let alpha = sample_qm31(state, true);
+        let mut evaluations_natural = evaluations_bit_reversed.clone();
+        bit_reverse(&mut evaluations_natural);
+
+        let (fold, coset) = if vector_index % 2 == 0 {
+            ("fold_line", Coset::half_odds(log_size))
+        } else {
+            (
+                "fold_circle_into_line",
+                CanonicCoset::new(log_size).circle_domain().half_coset,
+            )
+        };
+        let folded_bit_reversed = apply_fri_fold(fold, log_size, &evaluations_bit_reversed, alpha);
+        let mut folded_natural = folded_bit_reversed.clone();
+        bit_reverse(&mut folded_natural);
+        let misordered_fold = apply_fri_fold(fold, log_size, &evaluations_natural, alpha);
+        let output_permutation = (0..folded_natural.len())
+            .map(|i| bit_reverse_index(i, log_size - 1))
+            .collect();
+
+        out.push(FriFoldOrderingVector {
+            fold,
+            input_order: "bit_reversed",
+            log_size,
+            domain_initial_index: coset.initial_index.0,
+            domain_step_size: coset.step_size.0,
+            alpha: encode_qm31(alpha),
+            evaluations_bit_reversed: evaluations_bit_reversed
+                .into_iter()
+                .map(encode_qm31)
+                .collect(),
+            evaluations_natural: evaluations_natural.into_iter().map(encode_qm31).collect(),
+            folded_bit_reversed: folded_bit_reversed.into_iter().map(encode_qm31).collect(),
+            folded_natural: folded_natural.into_iter().map(encode_qm31).collect(),
+            output_permutation,
+            misordered_fold: misordered_fold.into_iter().map(encode_qm31).collect(),
+        });
+    }
+    out
+}
+
+fn apply_fri_fold(fold: &str, log_size: u32, values: &[QM31], alpha: QM31) -> Vec<QM31> {
+    match fold {
+        "fold_line" => fold_line(values, LineDomain::new(Coset::half_odds(log_size)), alpha).1,
+        _ => {
+            let mut folded = vec![QM31::from(0); values.len() >> 1];
+            fold_circle_into_line(
+                &mut folded,
+                values,
+                CanonicCoset::new(log_size).circle_domain(),
+                alpha,
+            );
+            folded
+        }
+    }
+}
+
+/// Folds a circle evaluation into a line and then line-by-line down to the last layer, one alpha
+/// per fold, recording every intermediate layer and its domain so divergences can be bisected.
+fn generate_fri_fold_chain_vectors(state: &mut u64, count: usize) -> Vec<FriFoldChainVector> {
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let circle_log_size = FRI_FOLD_CHAIN_MIN_CIRCLE_LOG_SIZE
+            + (next_u64(state) as u32)
+                % (FRI_FOLD_CHAIN_MAX_CIRCLE_LOG_SIZE - FRI_FOLD_CHAIN_MIN_CIRCLE_LOG_SIZE + 1);
+        let first_line_log_size = circle_log_size - 1;
+        let last_layer_log_size = (next_u64(state) as u32)
+            % (FRI_FOLD_CHAIN_MAX_LAST_LAYER_LOG_SIZE.min(first_line_log_size - 1) + 1);
+        let circle_eval = (0..1usize << circle_log_size)
+            .map(|_| sample_qm31(state, false))
+            .collect::<Vec<_>>();
+        let n_folds = 1 + (first_line_log_size - last_layer_log_size) as usize;
+        let alphas = (0..n_folds)
+            .map(|_| sample_qm31(state, true))
+            .collect::<Vec<_>>();
+
+        let circle_domain = CanonicCoset::new(circle_log_size).circle_domain();
+        let mut values = vec![QM31::from(0); circle_eval.len() >> 1];
+        fold_circle_into_line(&mut values, &circle_eval, circle_domain, alphas[0]);
+        let mut domain = LineDomain::new(circle_domain.half_coset);
+
+        let mut layers = Vec::with_capacity(n_folds);
+        let mut push_layer = |domain: LineDomain, values: &[QM31]| {
+            let coset = domain.coset();
+            layers.push(FriFoldChainLayerVector {
+                domain_log_size: domain.log_size(),
+                domain_initial_index: coset.initial_index.0,
+                domain_step_size: coset.step_size.0,
+                values: values.iter().copied().map(encode_qm31).collect(),
+            });
+        };
+        push_layer(domain, &values);
+        for alpha in &alphas[1..] {
+            let (folded_domain, folded_values) = fold_line(&values, domain, *alpha);
+            domain = folded_domain;
+            values = folded_values;
+            push_layer(domain, &values);
+        }
+        assert_eq!(domain.log_size(), last_layer_log_size);
+
+        let last_layer_coeffs_bit_reversed = interpolate_line_values(values, last_layer_log_size);
+        let mut last_layer_coeffs_ordered = last_layer_coeffs_bit_reversed.clone();
+        bit_reverse(&mut last_layer_coeffs_ordered);
+
+        out.push(FriFoldChainVector {
+            circle_log_size,
+            circle_eval: circle_eval.into_iter().map(encode_qm31).collect(),
+            last_layer_log_size,
+            alphas: alphas.into_iter().map(encode_qm31).collect(),
+            layers,
+            last_layer_coeffs_bit_reversed: last_layer_coeffs_bit_reversed
+                .into_iter()
+                .map(encode_qm31)
+                .collect(),
+            last_layer_coeffs_ordered: last_layer_coeffs_ordered
+                .into_iter()
+                .map(encode_qm31)
+                .collect(),
+        });
+    }
+    out
+}
+
+fn generate_fri_layer_count_vectors() -> Vec<FriLayerCountVector> {
+    let mut out = Vec::new();
+    for column_log_size in FRI_LAYER_COUNT_MIN_COLUMN_LOG_SIZE..=FRI_LAYER_COUNT_MAX_COLUMN_LOG_SIZE
+    {
+        for log_blowup in 1..=FRI_LAYER_COUNT_MAX_LOG_BLOWUP {
+            for log_last_layer in 0..=FRI_LAYER_COUNT_MAX_LOG_LAST_LAYER {
+                out.push(fri_layer_count_row(
+                    column_log_size,
+                    log_blowup,
+                    log_last_layer,
+                ));
+            }
+        }
+    }
+    out
+}
+
+fn fri_layer_count_row(
+    column_log_size: u32,
+    log_blowup: u32,
+    log_last_layer: u32,
+) -> FriLayerCountVector {
+    let config = FriConfig::new(log_last_layer, log_blowup, 1);
+    let first_layer_domain_log_size = column_log_size + config.log_blowup_factor;
+    let last_layer_domain_log_size = config.log_last_layer_degree_bound + config.log_blowup_factor;
+    let last_layer_poly_len = 1usize << config.log_last_layer_degree_bound;
+
+    // The circle-to-line fold leaves a line polynomial of log degree bound
+    // `column_log_size - 1`; every inner layer folds it by one until it reaches the last layer.
+    let valid = column_log_size > config.log_last_layer_degree_bound;
+    if !valid {
+        return FriLayerCountVector {
+            column_log_size,
+            log_blowup,
+            log_last_layer_degree_bound: log_last_layer,
+            valid,
+            first_layer_domain_log_size,
+            n_inner_layers: 0,
+            last_layer_domain_log_size,
+            last_layer_poly_len,
+            source: "formula".to_string(),
+        };
+    }
+    let formula_inner_layers = column_log_size - 1 - config.log_last_layer_degree_bound;
+
+    if first_layer_domain_log_size > FRI_LAYER_COUNT_MAX_SIMULATED_DOMAIN_LOG_SIZE {
+        return FriLayerCountVector {
+            column_log_size,
+            log_blowup,
+            log_last_layer_degree_bound: log_last_layer,
+            valid,
+            first_layer_domain_log_size,
+            n_inner_layers: formula_inner_layers,
+            last_layer_domain_log_size,
+            last_layer_poly_len,
+            source: "formula".to_string(),
+        };
+    }
+
+    let alpha = QM31::from(M31::from(3));
+    let circle_domain = CanonicCoset::new(first_layer_domain_log_size).circle_domain();
+    let circle_eval = vec![QM31::from(M31::from(1)); circle_domain.size()];
+    let mut line_eval = vec![QM31::from(0); circle_eval.len() >> 1];
+    fold_circle_into_line(&mut line_eval, &circle_eval, circle_domain, alpha);
+
+    let mut line_domain = LineDomain::new(Coset::half_odds(first_layer_domain_log_size - 1));
+    let mut n_inner_layers = 0u32;
+    while line_domain.size() > 1usize << last_layer_domain_log_size {
+        let (folded_domain, folded_eval) = fold_line(&line_eval, line_domain, alpha);
+        line_domain = folded_domain;
+        line_eval = folded_eval;
+        n_inner_layers += 1;
+    }
+    assert_eq!(
+        line_eval.len() >> config.log_blowup_factor,
+        last_layer_poly_len,
+        "simulated last layer length disagrees with the config"
+    );
+    assert_eq!(
+        n_inner_layers, formula_inner_layers,
+        "simulated fri inner layer count disagrees with the formula"
+    );
+
+    FriLayerCountVector {
+        column_log_size,
+        log_blowup,
+        log_last_layer_degree_bound: log_last_layer,
+        valid,
+        first_layer_domain_log_size,
+        n_inner_layers,
+        last_layer_domain_log_size,
+        last_layer_poly_len,
+        source: "fold_simulation".to_string(),
+    }
+}
+
+/// Last-layer polynomials checked against `log_last_layer_degree_bound` and evaluated at query
+/// points of the last line domain. Upstream rejects by coefficient count, so a zero-padded tail
+/// beyond the bound fails exactly like a nonzero one.
+fn generate_fri_last_layer_vectors(state: &mut u64, count: usize) -> Vec<FriLastLayerVector> {
+    let mut out = Vec::with_capacity(count);
+    for i in 0..count {
+        let log_last_layer_degree_bound =
+            next_u64(state) as u32 % (FRI_LAST_LAYER_MAX_LOG_DEGREE_BOUND + 1);
+        let log_blowup_factor = 1 + next_u64(state) as u32 % FRI_LAST_LAYER_MAX_LOG_BLOWUP;
+        let last_layer_domain_log_size = log_last_layer_degree_bound + log_blowup_factor;
+        let bound_len = 1usize << log_last_layer_degree_bound;
+
+        let (case, coeffs) = match i % 4 {
+            0 => (
+                "trailing_nonzero",
+                (0..2 * bound_len)
+                    .map(|j| sample_qm31(state, j >= bound_len))
+                    .collect::<Vec<_>>(),
+            ),
+            1 => (
+                "trailing_zero_padded",
+                (0..2 * bound_len)
+                    .map(|j| {
+                        if j < bound_len {
+                            sample_qm31(state, false)
+                        } else {
+                            QM31::from(0)
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            _ => {
+                let log_len = next_u64(state) as u32 % (log_last_layer_degree_bound + 1);
+                (
+                    "valid",
+                    (0..1usize << log_len)
+                        .map(|_| sample_qm31(state, false))
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+        let expected = if coeffs.len() > bound_len {
+            fri_verification_error_name(&FriVerificationError::LastLayerDegreeInvalid).to_string()
+        } else {
+            "ok".to_string()
+        };
+
+        let domain = LineDomain::new(Coset::half_odds(last_layer_domain_log_size));
+        let n_queries =
+            1 + next_u64(state) as usize % FRI_LAST_LAYER_MAX_QUERIES.min(domain.size());
+        let mut query_positions = Vec::with_capacity(n_queries);
+        while query_positions.len() < n_queries {
+            let q = next_u64(state) as usize & (domain.size() - 1);
+            if !query_positions.contains(&q) {
+                query_positions.push(q);
+            }
+        }
+        query_positions.sort_unstable();
+
+        let poly = LinePoly::new(coeffs.clone());
+        let query_x = query_positions
+            .iter()
+            .map(|&q| domain.at(bit_reverse_index(q, last_layer_domain_log_size)))
+            .collect::<Vec<_>>();
+        let evaluations = query_x
+            .iter()
+            .map(|&x| encode_qm31(poly.eval_at_point(x.into())))
+            .collect();
+
+        out.push(FriLastLayerVector {
+            case: case.to_string(),
+            log_blowup_factor,
+            log_last_layer_degree_bound,
+            last_layer_domain_log_size,
+            coeffs: coeffs.into_iter().map(encode_qm31).collect(),
+            query_positions,
+            query_x: query_x.into_iter().map(encode_m31).collect(),
+            evaluations,
+            expected,
+        });
+    }
+    out
+}
+
+/// `LinePoly::new` on wire-sized coefficient vectors: non-power-of-two counts (including zero)
+/// must be rejected before construction, since upstream asserts rather than returning an error.
+fn generate_line_poly_construction_vectors(state: &mut u64) -> Vec<LinePolyConstructionVector> {
+    let mut out = Vec::with_capacity(
+        LINE_POLY_CONSTRUCTION_LENGTHS.len() * LINE_POLY_CONSTRUCTION_SAMPLES_PER_LENGTH,
+    );
+    for &coeff_count in &LINE_POLY_CONSTRUCTION_LENGTHS {
+        for _ in 0..LINE_POLY_CONSTRUCTION_SAMPLES_PER_LENGTH {
+            let coeffs = (0..coeff_count)
+                .map(|_| sample_qm31(state, false))
+                .collect::<Vec<_>>();
+            let eval_point = sample_qm31(state, false);
+            let constructed = {
+                let coeffs = coeffs.clone();
+                std::panic::catch_unwind(move || LinePoly::new(coeffs))
+            };
+            let (expected, log_size, evaluation) = match constructed {
+                Ok(poly) => (
+                    "ok".to_string(),
+                    Some(poly.len().ilog2()),
+                    Some(encode_qm31(poly.eval_at_point(eval_point))),
+                ),
+                Err(_) => ("Panic".to_string(), None, None),
+            };
+            out.push(LinePolyConstructionVector {
+                coeff_count,
+                coeffs: coeffs.into_iter().map(encode_qm31).collect(),
+                expected,
+                log_size,
+                eval_point: encode_qm31(eval_point),
+                evaluation,
+            });
+        }
+    }
+    out
+}
+
 fn generate_fri_decommit_vectors(state: &mut u64, count: usize) -> Vec<FriDecommitVector> {
     let mut out = Vec::with_capacity(count);
     while out.len() < count {
@@ -2070,7 +8311,7 @@ fn build_fri_decommit_cases(state: &mut u64) -> Vec<FriDecommitVector> {
             match compute_fri_decommit_outputs(&column, &case_queries, case_fold_step) {
                 Ok(outputs) => ("ok".to_string(), outputs),
                 Err(err) => (
-                    err.to_string(),
+                    fri_decommit_error_name(err).to_string(),
                     FriDecommitOutputs {
                         decommitment_positions: Vec::new(),
                         witness_evals: Vec::new(),
@@ -2109,6 +8350,22 @@ fn build_fri_decommit_cases(state: &mut u64) -> Vec<FriDecommitVector> {
     out
 }
 
+/// Mirror of the Zig prover's `FriDecommitError`; upstream decommits without these checks.
+#[derive(Debug, Clone, Copy)]
+enum FriDecommitError {
+    QueryOutOfRange,
+    FoldStepTooLarge,
+}
+
+const FRI_DECOMMIT_ERROR_NAMES: [&str; 2] = ["QueryOutOfRange", "FoldStepTooLarge"];
+
+fn fri_decommit_error_name(err: FriDecommitError) -> &'static str {
+    match err {
+        FriDecommitError::QueryOutOfRange => "QueryOutOfRange",
+        FriDecommitError::FoldStepTooLarge => "FoldStepTooLarge",
+    }
+}
+
 struct FriDecommitOutputs {
     decommitment_positions: Vec<usize>,
     witness_evals: Vec<QM31>,
@@ -2120,9 +8377,9 @@ fn compute_fri_decommit_outputs(
     column: &[QM31],
     query_positions: &[usize],
     fold_step: u32,
-) -> Result<FriDecommitOutputs, &'static str> {
+) -> Result<FriDecommitOutputs, FriDecommitError> {
     if fold_step >= usize::BITS {
-        return Err("FoldStepTooLarge");
+        return Err(FriDecommitError::FoldStepTooLarge);
     }
 
     let mut decommitment_positions = Vec::<usize>::new();
@@ -2148,7 +8405,7 @@ fn compute_fri_decommit_outputs(
 
         for position in subset_start..subset_start + subset_len {
             if position >= column.len() {
-                return Err("QueryOutOfRange");
+                return Err(FriDecommitError::QueryOutOfRange);
             }
             decommitment_positions.push(position);
             let eval = column[position];
@@ -2225,7 +8482,7 @@ fn build_fri_layer_decommit_cases(state: &mut u64) -> Vec<FriLayerDecommitVector
             match compute_fri_layer_decommit_outputs(&column, &case_queries, case_fold_step) {
                 Ok(outputs) => ("ok".to_string(), outputs),
                 Err(err) => (
-                    err.to_string(),
+                    fri_decommit_error_name(err).to_string(),
                     FriLayerDecommitOutputs {
                         commitment: base_commitment,
                         decommitment_positions: Vec::new(),
@@ -2256,16 +8513,186 @@ fn build_fri_layer_decommit_cases(state: &mut u64) -> Vec<FriLayerDecommitVector
         });
     };
 
-    push_case("valid", fold_step, query_positions.clone());
+    push_case("valid", fold_step, query_positions.clone());
+
+    let mut out_of_range_queries = query_positions.clone();
+    out_of_range_queries.push(line_len + 1 + (next_u64(state) as usize % 4));
+    out_of_range_queries.sort_unstable();
+    push_case("query_out_of_range", fold_step, out_of_range_queries);
+
+    push_case("fold_step_too_large", usize::BITS, query_positions);
+
+    out
+}
+
+fn generate_fri_layer_chain_decommit_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<FriLayerChainDecommitVector> {
+    (0..count)
+        .map(|_| build_fri_layer_chain_decommit_vector(state))
+        .collect()
+}
+
+/// 2-3 layers starting at log size 5-8: a circle-to-line step, then line steps of 1 or 2.
+fn build_fri_layer_chain_decommit_vector(state: &mut u64) -> FriLayerChainDecommitVector {
+    let n_layers = 2 + (next_u64(state) as usize % 2);
+    let mut log_size = 5 + (next_u64(state) as u32 % 4);
+    let domain_size = 1usize << log_size;
+    let n_queries = 2 + (next_u64(state) as usize % 5);
+    let mut query_positions = (0..n_queries)
+        .map(|_| next_u64(state) as usize & (domain_size - 1))
+        .collect::<Vec<_>>();
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
+    let mut queries = Queries {
+        positions: query_positions.clone(),
+        log_domain_size: log_size,
+    };
+    let mut layers = Vec::with_capacity(n_layers);
+    for layer in 0..n_layers {
+        let fold_step = if layer == 0 {
+            CIRCLE_TO_LINE_FOLD_STEP
+        } else {
+            1 + (next_u64(state) as u32 % 2)
+        };
+        let column = (0..1usize << log_size)
+            .map(|_| sample_qm31(state, false))
+            .collect::<Vec<_>>();
+        let outputs = compute_fri_layer_decommit_outputs(&column, &queries.positions, fold_step)
+            .expect("chain queries lie in the layer domain");
+        layers.push(FriLayerChainStepVector {
+            log_size,
+            fold_step,
+            column: column.into_iter().map(encode_qm31).collect(),
+            query_positions: queries.positions.clone(),
+            commitment: encode_hash(outputs.commitment),
+            decommitment_positions: outputs.decommitment_positions,
+            fri_witness: outputs.fri_witness.into_iter().map(encode_qm31).collect(),
+            hash_witness: outputs.hash_witness.into_iter().map(encode_hash).collect(),
+        });
+        queries = queries.fold(fold_step);
+        log_size -= fold_step;
+    }
+
+    FriLayerChainDecommitVector {
+        query_positions,
+        layers,
+    }
+}
+
+fn generate_fri_first_layer_decommit_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<FriFirstLayerDecommitVector> {
+    (0..count)
+        .map(|_| build_fri_first_layer_decommit_vector(state))
+        .collect()
+}
+
+/// 2-3 columns with distinct log sizes in `1..=max`, where the largest is 4-7.
+fn build_fri_first_layer_decommit_vector(state: &mut u64) -> FriFirstLayerDecommitVector {
+    let max_log_size = 4 + (next_u64(state) as u32 % 4);
+    let n_columns = 2 + (next_u64(state) as usize % 2);
+    let mut log_sizes = vec![max_log_size];
+    while log_sizes.len() < n_columns {
+        let log_size = 1 + (next_u64(state) as u32 % max_log_size);
+        if !log_sizes.contains(&log_size) {
+            log_sizes.push(log_size);
+        }
+    }
+    log_sizes.sort_unstable();
+
+    let columns = log_sizes
+        .iter()
+        .map(|&log_size| {
+            (0..1usize << log_size)
+                .map(|_| sample_qm31(state, false))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
 
-    let mut out_of_range_queries = query_positions.clone();
-    out_of_range_queries.push(line_len + 1 + (next_u64(state) as usize % 4));
-    out_of_range_queries.sort_unstable();
-    push_case("query_out_of_range", fold_step, out_of_range_queries);
+    let n_queries = 2 + (next_u64(state) as usize % 5);
+    let mut query_positions = (0..n_queries)
+        .map(|_| next_u64(state) as usize & ((1usize << max_log_size) - 1))
+        .collect::<Vec<_>>();
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
+    let mut column_query_positions = Vec::with_capacity(columns.len());
+    let mut fri_witness = Vec::new();
+    let mut decommitment_positions = Vec::new();
+    let mut coordinate_columns = Vec::with_capacity(4 * columns.len());
+    for (&log_size, column) in log_sizes.iter().zip(&columns) {
+        let shift = max_log_size - log_size + 1;
+        let mut positions = query_positions
+            .iter()
+            .map(|&q| ((q >> shift) << 1) + (q & 1))
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+        positions.dedup();
+
+        let outputs = compute_fri_decommit_outputs(column, &positions, CIRCLE_TO_LINE_FOLD_STEP)
+            .expect("lifted queries lie in the column domain");
+        fri_witness.extend(outputs.witness_evals);
+        if log_size == max_log_size {
+            decommitment_positions = outputs.decommitment_positions;
+        }
+        column_query_positions.push(positions);
 
-    push_case("fold_step_too_large", usize::BITS, query_positions);
+        let mut coordinates = vec![Vec::with_capacity(column.len()); 4];
+        for value in column {
+            for (coordinate, m31) in coordinates.iter_mut().zip(value.to_m31_array()) {
+                coordinate.push(m31);
+            }
+        }
+        coordinate_columns.extend(coordinates);
+    }
 
-    out
+    let sorted_columns = coordinate_columns.iter().collect::<Vec<_>>();
+    let (commitment, hash_witness) =
+        lifted_merkle_decommit(&sorted_columns, &decommitment_positions);
+    let queried_values = coordinate_columns
+        .iter()
+        .map(|column| {
+            let shift = max_log_size - column.len().ilog2() + 1;
+            decommitment_positions
+                .iter()
+                .map(|&q| column[((q >> shift) << 1) + (q & 1)])
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let outcome = run_vcs_lifted_verifier(
+        commitment,
+        coordinate_columns
+            .iter()
+            .map(|column| column.len().ilog2())
+            .collect(),
+        decommitment_positions.clone(),
+        queried_values,
+        MerkleDecommitmentLifted::<LiftedMerkleHasher> {
+            hash_witness: hash_witness.clone(),
+        },
+    );
+    assert_eq!(
+        outcome, "ok",
+        "fri_first_layer_decommit: upstream lifted verifier rejects the combined decommitment"
+    );
+
+    FriFirstLayerDecommitVector {
+        log_sizes,
+        columns: columns
+            .into_iter()
+            .map(|column| column.into_iter().map(encode_qm31).collect())
+            .collect(),
+        query_positions,
+        column_query_positions,
+        commitment: encode_hash(commitment),
+        decommitment_positions,
+        fri_witness: fri_witness.into_iter().map(encode_qm31).collect(),
+        hash_witness: hash_witness.into_iter().map(encode_hash).collect(),
+    }
 }
 
 struct FriLayerDecommitOutputs {
@@ -2281,7 +8708,7 @@ fn compute_fri_layer_decommit_outputs(
     column: &[QM31],
     query_positions: &[usize],
     fold_step: u32,
-) -> Result<FriLayerDecommitOutputs, &'static str> {
+) -> Result<FriLayerDecommitOutputs, FriDecommitError> {
     let helper = compute_fri_decommit_outputs(column, query_positions, fold_step)?;
 
     let mut base_columns = vec![Vec::with_capacity(column.len()); 4];
@@ -2292,7 +8719,26 @@ fn compute_fri_layer_decommit_outputs(
         }
     }
     let sorted_columns = base_columns.iter().collect::<Vec<_>>();
-    let leaves = build_vcs_lifted_leaves(&sorted_columns);
+    let (commitment, hash_witness) =
+        lifted_merkle_decommit(&sorted_columns, &helper.decommitment_positions);
+
+    Ok(FriLayerDecommitOutputs {
+        commitment,
+        decommitment_positions: helper.decommitment_positions,
+        fri_witness: helper.witness_evals,
+        hash_witness,
+        value_map_positions: helper.value_map_positions,
+        value_map_values: helper.value_map_values,
+    })
+}
+
+/// Root and hash witness of the lifted Merkle tree over `sorted_columns` (ascending length),
+/// decommitted at the sorted max-size `decommitment_positions`.
+fn lifted_merkle_decommit(
+    sorted_columns: &[&Vec<M31>],
+    decommitment_positions: &[usize],
+) -> (Blake2sHash, Vec<Blake2sHash>) {
+    let leaves = build_vcs_lifted_leaves(sorted_columns);
     let mut layers = vec![leaves];
     while layers.last().expect("at least one layer").len() > 1 {
         let prev = layers.last().expect("previous layer");
@@ -2311,7 +8757,7 @@ fn compute_fri_layer_decommit_outputs(
         .expect("root hash");
 
     let mut hash_witness = Vec::<Blake2sHash>::new();
-    let mut prev_layer_queries = helper.decommitment_positions.clone();
+    let mut prev_layer_queries = decommitment_positions.to_vec();
     prev_layer_queries.dedup();
     for layer_log_size in (0..layers.len() - 1).rev() {
         let prev_layer_hashes = layers
@@ -2333,28 +8779,39 @@ fn compute_fri_layer_decommit_outputs(
         }
         prev_layer_queries = curr_layer_queries;
     }
+    (commitment, hash_witness)
+}
 
-    Ok(FriLayerDecommitOutputs {
-        commitment,
-        decommitment_positions: helper.decommitment_positions,
-        fri_witness: helper.witness_evals,
-        hash_witness,
-        value_map_positions: helper.value_map_positions,
-        value_map_values: helper.value_map_values,
-    })
+#[derive(Clone)]
+struct FriAnswersInputs {
+    column_log_sizes: Vec<Vec<u32>>,
+    samples: Vec<Vec<Vec<PointSample>>>,
+    random_coeff: QM31,
+    query_positions: Vec<usize>,
+    queried_values: Vec<Vec<Vec<M31>>>,
 }
 
-fn generate_pcs_quotients_vectors(state: &mut u64, count: usize) -> Vec<PcsQuotientsVector> {
+fn generate_fri_answers_error_vectors(
+    state: &mut u64,
+    count: usize,
+    stats: &mut RetryStats,
+) -> Vec<FriAnswersErrorVector> {
     let mut out = Vec::with_capacity(count);
     while out.len() < count {
-        if let Some(v) = try_generate_pcs_quotients_vector(state) {
-            out.push(v);
+        let mut cases = build_fri_answers_error_cases(state);
+        if cases.is_empty() {
+            stats.rejections += 1;
+            continue;
         }
+        cases.truncate(count - out.len());
+        out.extend(cases);
     }
     out
 }
 
-fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVector> {
+/// Mismatched `fri_answers` inputs derived from a base case that upstream accepts, each tagged
+/// with the upstream error variant (or "Panic" where upstream asserts instead of erroring).
+fn build_fri_answers_error_cases(state: &mut u64) -> Vec<FriAnswersErrorVector> {
     let n_trees = 2usize;
     let cols_per_tree = 2usize;
     let domain_size = 1usize << PCS_LIFTING_LOG_SIZE;
@@ -2366,6 +8823,462 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
             query_positions.push(q);
         }
     }
+    let column_log_sizes = (0..n_trees)
+        .map(|_| {
+            (0..cols_per_tree)
+                .map(|_| 4 + ((next_u64(state) as u32) % (PCS_LIFTING_LOG_SIZE - 3)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let samples = (0..n_trees)
+        .map(|_| {
+            (0..cols_per_tree)
+                .map(|_| {
+                    vec![PointSample {
+                        point: sample_secure_point_non_degenerate(state),
+                        value: sample_qm31(state, false),
+                    }]
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let queried_values = (0..n_trees)
+        .map(|_| {
+            (0..cols_per_tree)
+                .map(|_| {
+                    (0..query_positions.len())
+                        .map(|_| sample_m31(state, false))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let base = FriAnswersInputs {
+        column_log_sizes,
+        samples,
+        random_coeff: sample_qm31(state, true),
+        query_positions,
+        queried_values,
+    };
+    if run_fri_answers(base.clone()) != "ok" {
+        return vec![];
+    }
+
+    let mut out = Vec::new();
+    let mut push_case = |case: &str, inputs: FriAnswersInputs| {
+        let expected = run_fri_answers(inputs.clone());
+        out.push(FriAnswersErrorVector {
+            case: case.to_string(),
+            lifting_log_size: PCS_LIFTING_LOG_SIZE,
+            column_log_sizes: inputs.column_log_sizes,
+            samples: inputs
+                .samples
+                .iter()
+                .map(|tree| {
+                    tree.iter()
+                        .map(|col| col.iter().map(encode_point_sample).collect())
+                        .collect()
+                })
+                .collect(),
+            random_coeff: encode_qm31(inputs.random_coeff),
+            query_positions: inputs.query_positions,
+            queried_values: inputs
+                .queried_values
+                .iter()
+                .map(|tree| {
+                    tree.iter()
+                        .map(|col| col.iter().copied().map(encode_m31).collect())
+                        .collect()
+                })
+                .collect(),
+            expected,
+        });
+    };
+
+    push_case("valid", base.clone());
+
+    let mut extra_column = base.clone();
+    extra_column.column_log_sizes[0].push(extra_column.column_log_sizes[0][0]);
+    push_case("column_count_exceeds_queried_values", extra_column);
+
+    let mut missing_queried_column = base.clone();
+    missing_queried_column.queried_values[0].pop();
+    push_case("queried_values_column_missing", missing_queried_column);
+
+    let mut extra_query = base.clone();
+    let extra_position = (0..domain_size)
+        .find(|q| !extra_query.query_positions.contains(q))
+        .expect("lifting domain larger than query count");
+    extra_query.query_positions.push(extra_position);
+    push_case("query_positions_longer_than_column", extra_query);
+
+    let mut short_column = base.clone();
+    short_column.queried_values[1][0].pop();
+    push_case("queried_column_too_short", short_column);
+
+    let mut empty_samples = base;
+    empty_samples
+        .samples
+        .iter_mut()
+        .flatten()
+        .for_each(|col| col.clear());
+    push_case("empty_sample_batches", empty_samples);
+
+    out
+}
+
+fn run_fri_answers(inputs: FriAnswersInputs) -> String {
+    // Upstream asserts on some malformed shapes instead of returning an error; record those as
+    // "Panic" so the Zig classification can still be compared.
+    let result = std::panic::catch_unwind(move || {
+        fri_answers(
+            TreeVec(inputs.column_log_sizes),
+            TreeVec(inputs.samples),
+            inputs.random_coeff,
+            &inputs.query_positions,
+            TreeVec(inputs.queried_values),
+            PCS_LIFTING_LOG_SIZE,
+        )
+    });
+    match result {
+        Ok(Ok(_)) => "ok".to_string(),
+        Ok(Err(err)) => verification_error_name(&err).to_string(),
+        Err(_) => "Panic".to_string(),
+    }
+}
+
+/// Extracts the enum variant name from a `Debug` rendering such as `InvalidStructure("...")`.
+fn debug_variant_name(debug: &str) -> String {
+    debug
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// A query position every vector must contain, so the bit-reversed mapping onto the lifting
+/// domain hits index 0 and the conjugated second half-coset regardless of the random draw.
+#[derive(Debug, Clone, Copy)]
+enum PcsForcedQuery {
+    PositionZero,
+    ConjugateHalf,
+}
+
+/// The first `forced_query_count` vectors include query position 0 and the next
+/// `forced_query_count` a position whose domain index lies in the conjugate half.
+fn generate_pcs_quotients_vectors(
+    state: &mut u64,
+    count: usize,
+    lifting_log_size: u32,
+    shared_point_percent: u64,
+    forced_query_count: usize,
+    stats: &mut RetryStats,
+) -> Vec<PcsQuotientsVector> {
+    let mut out = Vec::with_capacity(count);
+    while out.len() < count {
+        let forced_query = if out.len() < forced_query_count {
+            Some(PcsForcedQuery::PositionZero)
+        } else if out.len() < 2 * forced_query_count {
+            Some(PcsForcedQuery::ConjugateHalf)
+        } else {
+            None
+        };
+        match try_generate_pcs_quotients_vector(
+            state,
+            lifting_log_size,
+            shared_point_percent,
+            forced_query,
+        ) {
+            Some(v) => out.push(v),
+            None => stats.rejections += 1,
+        }
+    }
+    out
+}
+
+/// With a nonzero `shared_point_percent`, roughly that share of columns take a common OODS point
+/// as their first sample (as in real proofs), and the vector is only kept if `ColumnSampleBatch`
+/// merges them into a single batch (asserted). A zero percentage leaves the sampled stream
+/// untouched.
+/// `build_samples_with_randomness_and_periodicity` outputs annotated with where each entry came
+/// from: the random coefficient's exponent and the input (tree, column, sample) it expands, with
+/// `periodic` set for the extra sample shifted by the column's period on the lifting domain.
+/// Column log sizes sit 0..=3 below the lifting size, rotating per column so every vector mixes
+/// replication factors; provenance is recovered by matching upstream output, not assumed.
+fn generate_samples_with_randomness_vectors(
+    state: &mut u64,
+    count: usize,
+) -> Vec<SamplesWithRandomnessVector> {
+    let n_trees = 2usize;
+    let lifting_generator = CanonicCoset::new(PCS_LIFTING_LOG_SIZE).step();
+
+    let mut out = Vec::with_capacity(count);
+    for vector_idx in 0..count {
+        let column_log_sizes = (0..n_trees)
+            .map(|tree| {
+                (0..SAMPLES_WITH_RANDOMNESS_COLS_PER_TREE)
+                    .map(|col| PCS_LIFTING_LOG_SIZE - ((vector_idx + tree + col) % 4) as u32)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let samples = (0..n_trees)
+            .map(|_| {
+                (0..SAMPLES_WITH_RANDOMNESS_COLS_PER_TREE)
+                    .map(|_| {
+                        let n_samples = match next_u64(state) % 4 {
+                            0 => 0,
+                            1 => 1,
+                            _ => 2,
+                        };
+                        (0..n_samples)
+                            .map(|_| PointSample {
+                                point: sample_secure_point_non_degenerate(state),
+                                value: sample_qm31(state, false),
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let random_coeff = sample_qm31(state, true);
+
+        let size_iters = column_log_sizes
+            .iter()
+            .cloned()
+            .map(|v| v.into_iter())
+            .collect::<Vec<_>>();
+        let expanded = build_samples_with_randomness_and_periodicity(
+            &TreeVec(samples.clone()),
+            size_iters,
+            PCS_LIFTING_LOG_SIZE,
+            random_coeff,
+        );
+
+        let n_entries = expanded
+            .iter()
+            .flatten()
+            .map(|col| col.len())
+            .sum::<usize>();
+        let mut powers = Vec::with_capacity(n_entries);
+        let mut pow = QM31::from(1);
+        for _ in 0..n_entries {
+            powers.push(pow);
+            pow *= random_coeff;
+        }
+
+        let samples_with_randomness = expanded
+            .iter()
+            .enumerate()
+            .map(|(tree, tree_entries)| {
+                tree_entries
+                    .iter()
+                    .enumerate()
+                    .map(|(column, col_entries)| {
+                        let inputs = &samples[tree][column];
+                        let period_generator =
+                            lifting_generator.repeated_double(column_log_sizes[tree][column]);
+                        let period_shift = CirclePoint {
+                            x: QM31::from(period_generator.x),
+                            y: QM31::from(period_generator.y),
+                        };
+                        col_entries
+                            .iter()
+                            .map(|(sample, coeff)| {
+                                let (sample_index, periodic) = inputs
+                                    .iter()
+                                    .position(|input| {
+                                        input.point == sample.point && input.value == sample.value
+                                    })
+                                    .map(|idx| (idx, false))
+                                    .or_else(|| {
+                                        inputs
+                                            .iter()
+                                            .position(|input| {
+                                                input.point + period_shift == sample.point
+                                                    && input.value == sample.value
+                                            })
+                                            .map(|idx| (idx, true))
+                                    })
+                                    .expect("expanded sample matches no input sample");
+                                let power_index = powers
+                                    .iter()
+                                    .position(|p| p == coeff)
+                                    .expect("random coefficient is not a power of alpha");
+                                SampleProvenanceVector {
+                                    sample: encode_point_sample(sample),
+                                    random_coeff: encode_qm31(*coeff),
+                                    power_index,
+                                    tree,
+                                    column,
+                                    sample_index,
+                                    periodic,
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        out.push(SamplesWithRandomnessVector {
+            lifting_log_size: PCS_LIFTING_LOG_SIZE,
+            column_log_sizes,
+            samples: samples
+                .iter()
+                .map(|tree| {
+                    tree.iter()
+                        .map(|col| col.iter().map(encode_point_sample).collect())
+                        .collect()
+                })
+                .collect(),
+            random_coeff: encode_qm31(random_coeff),
+            samples_with_randomness,
+        });
+    }
+    out
+}
+
+/// Standalone `denominator_inverses` vectors. Near-degenerate sample points come from the circle
+/// parametrization `t -> ((1 - t^2) / (1 + t^2), 2t / (1 + t^2))` with `t = t0 + u * k` for a
+/// CM31 `t0` and a small integer `k`: the `u` components of such a point vanish with `k`, and at
+/// `k = 0` the point lies on the CM31 circle, where the denominator is exactly zero.
+fn generate_denominator_inverses_vectors(state: &mut u64) -> Vec<DenominatorInversesVector> {
+    let mut out = Vec::with_capacity(
+        DENOMINATOR_INVERSES_RANDOM_COUNT + DENOMINATOR_INVERSES_NEAR_DEGENERATE_COUNT + 1,
+    );
+    for _ in 0..DENOMINATOR_INVERSES_RANDOM_COUNT {
+        let n_points = 1 + next_u64(state) as usize % DENOMINATOR_INVERSES_MAX_POINTS;
+        let points = (0..n_points)
+            .map(|_| (sample_secure_point_non_degenerate(state), None))
+            .collect();
+        out.push(build_denominator_inverses_vector(state, "random", points));
+    }
+    for _ in 0..DENOMINATOR_INVERSES_NEAR_DEGENERATE_COUNT {
+        let n_points = 1 + next_u64(state) as usize % DENOMINATOR_INVERSES_MAX_POINTS;
+        let points = (0..n_points)
+            .map(|_| {
+                let k = 1 + next_u64(state) % DENOMINATOR_INVERSES_MAX_PERTURBATION;
+                (
+                    sample_perturbed_cm31_circle_point(state, k as u32),
+                    Some(k as u32),
+                )
+            })
+            .collect();
+        out.push(build_denominator_inverses_vector(
+            state,
+            "near_degenerate",
+            points,
+        ));
+    }
+    let points = vec![
+        (sample_secure_point_non_degenerate(state), None),
+        (sample_perturbed_cm31_circle_point(state, 0), Some(0)),
+    ];
+    out.push(build_denominator_inverses_vector(
+        state,
+        "degenerate",
+        points,
+    ));
+    out
+}
+
+fn build_denominator_inverses_vector(
+    state: &mut u64,
+    case: &str,
+    points: Vec<(CirclePoint<QM31>, Option<u32>)>,
+) -> DenominatorInversesVector {
+    let domain_index = next_u64(state) as usize & ((1usize << PCS_LIFTING_LOG_SIZE) - 1);
+    let domain_point = CanonicCoset::new(PCS_LIFTING_LOG_SIZE)
+        .circle_domain()
+        .at(domain_index);
+    let sample_points = points.iter().map(|(point, _)| *point).collect::<Vec<_>>();
+    let denominators = sample_points
+        .iter()
+        .map(|p| encode_cm31((p.x.0 - domain_point.x) * p.y.1 - (p.y.0 - domain_point.y) * p.x.1))
+        .collect();
+
+    // Upstream batch-inverts without a zero check; record a panic rather than assume zeros.
+    let inverses_result = {
+        let sample_points = sample_points.clone();
+        std::panic::catch_unwind(move || denominator_inverses(&sample_points, domain_point))
+    };
+    let (expected, inverses) = match inverses_result {
+        Ok(inverses) => (
+            "ok".to_string(),
+            inverses.into_iter().map(encode_cm31).collect(),
+        ),
+        Err(_) => ("Panic".to_string(), vec![]),
+    };
+
+    DenominatorInversesVector {
+        case: case.to_string(),
+        sample_points: sample_points
+            .into_iter()
+            .map(encode_secure_circle_point)
+            .collect(),
+        u_perturbations: points.into_iter().map(|(_, k)| k).collect(),
+        domain_log_size: PCS_LIFTING_LOG_SIZE,
+        domain_index,
+        domain_point: encode_circle_point(domain_point),
+        denominators,
+        expected,
+        inverses,
+    }
+}
+
+fn sample_perturbed_cm31_circle_point(state: &mut u64, k: u32) -> CirclePoint<QM31> {
+    loop {
+        let t = QM31(sample_cm31(state, false), CM31(M31::from(k), M31::from(0)));
+        let denom = QM31::from(1) + t * t;
+        if denom == QM31::from(0) {
+            continue;
+        }
+        let denom_inv = denom.inverse();
+        let point = CirclePoint {
+            x: (QM31::from(1) - t * t) * denom_inv,
+            y: (t + t) * denom_inv,
+        };
+        debug_assert!(point.x * point.x + point.y * point.y == QM31::from(1));
+        return point;
+    }
+}
+
+fn try_generate_pcs_quotients_vector(
+    state: &mut u64,
+    lifting_log_size: u32,
+    shared_point_percent: u64,
+    forced_query: Option<PcsForcedQuery>,
+) -> Option<PcsQuotientsVector> {
+    let n_trees = 2usize;
+    let cols_per_tree = 2usize;
+    let domain_size = 1usize << lifting_log_size;
+
+    let mut query_positions = Vec::with_capacity(PCS_QUERY_COUNT);
+    match forced_query {
+        Some(PcsForcedQuery::PositionZero) => query_positions.push(0),
+        Some(PcsForcedQuery::ConjugateHalf) => {
+            // Bit reversal moves the low bit to the top, so it selects the half-coset.
+            let q = (next_u64(state) as usize) & (domain_size - 1);
+            let q = if bit_reverse_index(q, lifting_log_size) < domain_size / 2 {
+                q ^ 1
+            } else {
+                q
+            };
+            assert!(bit_reverse_index(q, lifting_log_size) >= domain_size / 2);
+            query_positions.push(q);
+        }
+        None => {}
+    }
+    while query_positions.len() < PCS_QUERY_COUNT {
+        let q = (next_u64(state) as usize) & (domain_size - 1);
+        if !query_positions.contains(&q) {
+            query_positions.push(q);
+        }
+    }
+
+    let shared_point =
+        (shared_point_percent > 0).then(|| sample_secure_point_non_degenerate(state));
+    let mut shared_point_columns = 0usize;
 
     let mut column_log_sizes: Vec<Vec<u32>> = Vec::with_capacity(n_trees);
     let mut samples_raw: Vec<Vec<Vec<PointSample>>> = Vec::with_capacity(n_trees);
@@ -2377,14 +9290,23 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
         let mut tree_queries = Vec::with_capacity(cols_per_tree);
 
         for _ in 0..cols_per_tree {
-            let log_size = 4 + ((next_u64(state) as u32) % (PCS_LIFTING_LOG_SIZE - 3));
+            let log_size = 4 + ((next_u64(state) as u32) % (lifting_log_size - 3));
             tree_sizes.push(log_size);
 
             let n_samples = if (next_u64(state) & 1) == 0 { 1 } else { 2 };
             let mut col_samples = Vec::with_capacity(n_samples);
-            for _ in 0..n_samples {
+            for sample_idx in 0..n_samples {
+                let point = match shared_point {
+                    Some(point)
+                        if sample_idx == 0 && next_u64(state) % 100 < shared_point_percent =>
+                    {
+                        shared_point_columns += 1;
+                        point
+                    }
+                    _ => sample_secure_point_non_degenerate(state),
+                };
                 col_samples.push(PointSample {
-                    point: sample_secure_point_non_degenerate(state),
+                    point,
                     value: sample_qm31(state, false),
                 });
             }
@@ -2421,18 +9343,32 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
     let samples_with_randomness = build_samples_with_randomness_and_periodicity(
         &TreeVec(samples_raw.clone()),
         size_iters,
-        PCS_LIFTING_LOG_SIZE,
+        lifting_log_size,
         random_coeff,
     );
 
     let flattened_samples_with_randomness =
         samples_with_randomness.iter().flatten().collect::<Vec<_>>();
     let sample_batches = ColumnSampleBatch::new_vec(&flattened_samples_with_randomness);
+    if let Some(shared_point) = shared_point {
+        if shared_point_columns < 2 {
+            return None;
+        }
+        let shared_batches = sample_batches
+            .iter()
+            .filter(|batch| batch.point == shared_point)
+            .collect::<Vec<_>>();
+        assert!(
+            shared_batches.len() == 1
+                && shared_batches[0].cols_vals_randpows.len() == shared_point_columns,
+            "columns sampled at a shared point were not merged into one batch"
+        );
+    }
 
     let sample_points = sample_batches.iter().map(|b| b.point).collect::<Vec<_>>();
-    let lifting_domain = CanonicCoset::new(PCS_LIFTING_LOG_SIZE).circle_domain();
+    let lifting_domain = CanonicCoset::new(lifting_log_size).circle_domain();
     for &position in &query_positions {
-        let domain_point = lifting_domain.at(bit_reverse_index(position, PCS_LIFTING_LOG_SIZE));
+        let domain_point = lifting_domain.at(bit_reverse_index(position, lifting_log_size));
         for sample_point in &sample_points {
             let prx = sample_point.x.0;
             let pry = sample_point.y.0;
@@ -2457,13 +9393,15 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
         Vec::with_capacity(query_positions.len());
     let mut partial_numerators_out: Vec<Vec<[u32; 4]>> = Vec::with_capacity(query_positions.len());
     let mut row_quotients_out: Vec<[u32; 4]> = Vec::with_capacity(query_positions.len());
+    let mut domain_points_out: Vec<[u32; 2]> = Vec::with_capacity(query_positions.len());
 
     for (row_idx, &position) in query_positions.iter().enumerate() {
         let queried_values_at_row = queried_values_flat
             .iter()
             .map(|column| column[row_idx])
             .collect::<Vec<_>>();
-        let domain_point = lifting_domain.at(bit_reverse_index(position, PCS_LIFTING_LOG_SIZE));
+        let domain_point = lifting_domain.at(bit_reverse_index(position, lifting_log_size));
+        domain_points_out.push(encode_circle_point(domain_point));
 
         let den_inv = denominator_inverses(&sample_points, domain_point);
         denominator_inverses_out.push(den_inv.into_iter().map(encode_cm31).collect());
@@ -2495,7 +9433,7 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
         random_coeff,
         &query_positions,
         TreeVec(queried_values_raw.clone()),
-        PCS_LIFTING_LOG_SIZE,
+        lifting_log_size,
     )
     .ok()?;
 
@@ -2560,11 +9498,12 @@ fn try_generate_pcs_quotients_vector(state: &mut u64) -> Option<PcsQuotientsVect
         .collect();
 
     Some(PcsQuotientsVector {
-        lifting_log_size: PCS_LIFTING_LOG_SIZE,
+        lifting_log_size: lifting_log_size,
         column_log_sizes,
         samples: samples_encoded,
         random_coeff: encode_qm31(random_coeff),
         query_positions,
+        domain_points: domain_points_out,
         queried_values: queried_encoded,
         samples_with_randomness: samples_with_randomness_encoded,
         sample_batches: sample_batches_encoded,
@@ -2583,8 +9522,14 @@ fn encode_point_sample(sample: &PointSample) -> PointSampleVector {
     }
 }
 
-fn encode_m31(x: M31) -> u32 {
-    x.0
+fn decode_point_sample(sample: &PointSampleVector) -> PointSample {
+    PointSample {
+        point: CirclePoint {
+            x: decode_qm31(sample.point[0]),
+            y: decode_qm31(sample.point[1]),
+        },
+        value: decode_qm31(sample.value),
+    }
 }
 
 fn encode_state(state: [M31; 2]) -> [u32; 2] {
@@ -2595,32 +9540,6 @@ fn combine_state(state: [M31; 2], z: QM31, alpha: QM31) -> QM31 {
     QM31::from(state[0]) + alpha * QM31::from(state[1]) - z
 }
 
-fn encode_hash(x: Blake2sHash) -> [u8; 32] {
-    x.0
-}
-
-fn encode_blake3_hash(x: Blake3Hash) -> [u8; 32] {
-    x.as_ref()
-        .try_into()
-        .expect("blake3 hash should be 32 bytes")
-}
-
-fn encode_cm31(x: CM31) -> [u32; 2] {
-    [x.0 .0, x.1 .0]
-}
-
-fn encode_qm31(x: QM31) -> [u32; 4] {
-    [x.0 .0 .0, x.0 .1 .0, x.1 .0 .0, x.1 .1 .0]
-}
-
-fn encode_circle_point(p: CirclePoint<M31>) -> [u32; 2] {
-    [p.x.0, p.y.0]
-}
-
-fn encode_secure_circle_point(p: CirclePoint<QM31>) -> [[u32; 4]; 2] {
-    [encode_qm31(p.x), encode_qm31(p.y)]
-}
-
 fn sample_scalar(state: &mut u64) -> u64 {
     next_u64(state) & ((1u64 << M31_CIRCLE_LOG_ORDER) - 1)
 }
@@ -2629,54 +9548,18 @@ fn sample_scalar_u128(state: &mut u64) -> u128 {
     ((next_u64(state) as u128) << 64) | (next_u64(state) as u128)
 }
 
-fn sample_hash(state: &mut u64) -> Blake2sHash {
-    let mut bytes = [0u8; 32];
-    fill_bytes(state, &mut bytes);
-    Blake2sHash(bytes)
-}
-
-fn fill_bytes(state: &mut u64, bytes: &mut [u8]) {
-    for chunk in bytes.chunks_mut(8) {
-        let block = next_u64(state).to_le_bytes();
-        let n = chunk.len();
-        chunk.copy_from_slice(&block[..n]);
-    }
-}
-
-fn sample_m31(state: &mut u64, non_zero: bool) -> M31 {
-    loop {
-        let candidate = (next_u64(state) as u32) & 0x7fff_ffff;
-        if candidate == P {
-            continue;
-        }
-        if non_zero && candidate == 0 {
-            continue;
-        }
-        return M31::from_u32_unchecked(candidate);
-    }
-}
-
-fn sample_cm31(state: &mut u64, non_zero: bool) -> CM31 {
-    loop {
-        let out = CM31(sample_m31(state, false), sample_m31(state, false));
-        if non_zero && out.0 .0 == 0 && out.1 .0 == 0 {
-            continue;
-        }
-        return out;
-    }
+/// Flips one random bit of one random (non-empty) witness entry, keeping the length.
+fn flip_random_hash_bit(state: &mut u64, hashes: &mut [Blake2sHash]) {
+    let index = next_u64(state) as usize % hashes.len();
+    let bit = next_u64(state) as usize % 256;
+    hashes[index].0[bit / 8] ^= 1 << (bit % 8);
 }
 
-fn sample_qm31(state: &mut u64, non_zero: bool) -> QM31 {
-    loop {
-        let out = QM31(
-            CM31(sample_m31(state, false), sample_m31(state, false)),
-            CM31(sample_m31(state, false), sample_m31(state, false)),
-        );
-        if non_zero && encode_qm31(out) == [0, 0, 0, 0] {
-            continue;
-        }
-        return out;
-    }
+/// Flips one random bit of one random value; a result of `P` reduces to 0, still a change.
+fn flip_random_m31_bit(state: &mut u64, values: &mut [M31]) {
+    let index = next_u64(state) as usize % values.len();
+    let bit = next_u64(state) as u32 % 31;
+    values[index] = M31::from(values[index].0 ^ (1 << bit));
 }
 
 fn sample_secure_point_non_degenerate(state: &mut u64) -> CirclePoint<QM31> {
@@ -2687,13 +9570,3 @@ fn sample_secure_point_non_degenerate(state: &mut u64) -> CirclePoint<QM31> {
         }
     }
 }
-
-fn next_u64(state: &mut u64) -> u64 {
-    // Xorshift64* (deterministic, non-cryptographic).
-    let mut x = *state;
-    x ^= x >> 12;
-    x ^= x << 25;
-    x ^= x >> 27;
-    *state = x;
-    x.wrapping_mul(0x2545_f491_4f6c_dd1d)
-}
@@ -0,0 +1,91 @@
+//! Keeps `--list-families` and `--describe` in step with the generator: every listed family must
+//! be described, and the described field names must be exactly the keys of the entries a
+//! generated file holds.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Top-level keys that describe the run rather than hold a family.
+const NON_FAMILY_KEYS: [&str; 4] = ["meta", "families", "error_taxonomy", "generation_stats"];
+
+fn vector_gen(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-vector-gen"))
+        .args(args)
+        .output()
+        .expect("stwo-vector-gen runs");
+    assert!(
+        output.status.success(),
+        "stwo-vector-gen {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("utf-8 output")
+}
+
+/// Description line and field names printed by `--describe <family>`.
+fn describe(family: &str) -> (String, Vec<String>) {
+    let output = vector_gen(&["--describe", family]);
+    let mut lines = output.lines();
+    let description = lines
+        .next()
+        .and_then(|line| line.strip_prefix(&format!("{family}: ")))
+        .unwrap_or_else(|| panic!("--describe {family} prints no description"))
+        .to_string();
+    assert_eq!(lines.next(), Some("fields:"));
+    let fields = lines.map(|line| line.trim().to_string()).collect();
+    (description, fields)
+}
+
+#[test]
+fn every_listed_family_is_described() {
+    let listing = vector_gen(&["--list-families"]);
+    let families = listing
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .collect::<Vec<_>>();
+    assert!(!families.is_empty());
+    for family in families {
+        let (description, fields) = describe(family);
+        assert!(!description.is_empty(), "{family} has an empty description");
+        assert!(!fields.is_empty(), "{family} lists no fields");
+    }
+}
+
+#[test]
+fn described_fields_match_generated_vectors() {
+    let dir = std::env::temp_dir().join(format!("stwo-vector-gen-describe-{}", std::process::id()));
+    let out: PathBuf = dir.join("fields.json");
+    vector_gen(&["--count", "2", "--out", out.to_str().expect("utf-8 path")]);
+    let vectors: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&out).expect("generated file")).expect("json");
+    fs::remove_dir_all(&dir).ok();
+
+    let mut mismatches = Vec::new();
+    for (family, value) in vectors.as_object().expect("top-level object") {
+        if NON_FAMILY_KEYS.contains(&family.as_str()) {
+            continue;
+        }
+        let entry = match value {
+            serde_json::Value::Array(entries) => match entries.first() {
+                Some(entry) => entry,
+                None => continue,
+            },
+            other => other,
+        };
+        let mut generated = entry
+            .as_object()
+            .unwrap_or_else(|| panic!("{family} entries are not objects"))
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        let (_, mut described) = describe(family);
+        generated.sort();
+        described.sort();
+        if generated != described {
+            mismatches.push(format!(
+                "{family}: described {described:?}, generated {generated:?}"
+            ));
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
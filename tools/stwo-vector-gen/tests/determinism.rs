@@ -0,0 +1,61 @@
+//! Two runs with the same seeds and `--count` must write the same file byte for byte. The only
+//! field allowed to differ is `generation_stats[].seconds`, which is wall-clock time.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn generate(out: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-vector-gen"))
+        .args(["--count", "3", "--family-seed", "pcs_quotients=0x5eed"])
+        .args(["--out", out.to_str().expect("utf-8 path")])
+        .output()
+        .expect("stwo-vector-gen runs");
+    assert!(
+        output.status.success(),
+        "stwo-vector-gen failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    fs::read_to_string(out).expect("generated file")
+}
+
+/// Replaces the value of every pretty-printed `"seconds"` line, keeping the rest of the bytes.
+fn mask_seconds(text: &str) -> String {
+    let mut masked = 0;
+    let out = text
+        .split_inclusive('\n')
+        .map(|line| {
+            let indent = line.len() - line.trim_start().len();
+            match line.trim_start().strip_prefix("\"seconds\": ") {
+                Some(rest) => {
+                    masked += 1;
+                    let tail = rest.trim_start_matches(|c: char| {
+                        c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '-' | '+')
+                    });
+                    format!("{}\"seconds\": _{tail}", &line[..indent])
+                }
+                None => line.to_string(),
+            }
+        })
+        .collect();
+    assert!(masked > 0, "no generation_stats seconds in the output");
+    out
+}
+
+#[test]
+fn same_seed_and_count_give_identical_files() {
+    let dir = std::env::temp_dir().join(format!(
+        "stwo-vector-gen-determinism-{}",
+        std::process::id()
+    ));
+    let first = generate(&dir.join("first.json"));
+    let second = generate(&dir.join("second.json"));
+    fs::remove_dir_all(&dir).ok();
+
+    let (first, second) = (mask_seconds(&first), mask_seconds(&second));
+    assert_eq!(first.len(), second.len());
+    if let Some(line) = first.lines().zip(second.lines()).position(|(a, b)| a != b) {
+        panic!("outputs differ at line {}", line + 1);
+    }
+    assert!(first == second);
+}
@@ -0,0 +1,58 @@
+//! `--family-seed` must regenerate exactly one family: every other family, and the seeds the
+//! `families` map records for them, stay byte-for-byte the same.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn generate(out: &Path, extra: &[&str]) -> serde_json::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-vector-gen"))
+        .args(["--count", "2", "--out", out.to_str().expect("utf-8 path")])
+        .args(extra)
+        .output()
+        .expect("stwo-vector-gen runs");
+    assert!(
+        output.status.success(),
+        "stwo-vector-gen {extra:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_str(&fs::read_to_string(out).expect("generated file")).expect("json")
+}
+
+#[test]
+fn family_seed_override_only_changes_that_family() {
+    let dir = std::env::temp_dir().join(format!("stwo-vector-gen-seeds-{}", std::process::id()));
+    let base = generate(&dir.join("base.json"), &[]);
+    let overridden = generate(
+        &dir.join("overridden.json"),
+        &["--family-seed", "pcs_quotients=0x5eed"],
+    );
+    fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(overridden["families"]["pcs_quotients"]["seed"], 0x5eed);
+    assert_ne!(base["pcs_quotients"], overridden["pcs_quotients"]);
+    for (family, value) in base.as_object().expect("top-level object") {
+        match family.as_str() {
+            "pcs_quotients" | "generation_stats" => {}
+            "families" => {
+                let mut base_families = value.clone();
+                let mut overridden_families = overridden["families"].clone();
+                base_families["pcs_quotients"]["seed"].take();
+                overridden_families["pcs_quotients"]["seed"].take();
+                assert_eq!(base_families, overridden_families);
+            }
+            _ => assert_eq!(value, &overridden[family], "{family} changed"),
+        }
+    }
+}
+
+#[test]
+fn family_seed_rejects_fixed_and_unknown_families() {
+    for value in ["known_answer=0x1", "no_such_family=0x1", "m31=0", "m31=xyz"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_stwo-vector-gen"))
+            .args(["--family-seed", value])
+            .output()
+            .expect("stwo-vector-gen runs");
+        assert_eq!(output.status.code(), Some(2), "--family-seed {value}");
+    }
+}
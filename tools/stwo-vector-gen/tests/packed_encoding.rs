@@ -0,0 +1,86 @@
+//! `--encoding packed` rewrites the trace column fields as standard base64 of little-endian u32s
+//! and marks each packed family in `meta.column_encoding`; decoding the packed file gives back
+//! exactly the columns the plain file holds.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use base64::Engine;
+
+/// Families and column fields `--encoding packed` rewrites.
+const PACKED_COLUMN_FIELDS: [(&str, &[&str]); 4] = [
+    ("example_state_machine_trace", &["columns"]),
+    ("example_wide_fibonacci_trace", &["columns"]),
+    ("example_plonk_trace", &["preprocessed", "main"]),
+    ("example_poseidon_trace", &["columns"]),
+];
+
+fn generate(out: &Path, encoding: &str) -> serde_json::Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_stwo-vector-gen"))
+        .args(["--count", "2", "--encoding", encoding, "--out"])
+        .arg(out)
+        .output()
+        .expect("stwo-vector-gen runs");
+    assert!(
+        output.status.success(),
+        "stwo-vector-gen --encoding {encoding} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_str(&fs::read_to_string(out).expect("generated file")).expect("json")
+}
+
+fn decode_column(encoded: &serde_json::Value) -> Vec<u32> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded.as_str().expect("packed column is a string"))
+        .expect("standard base64");
+    assert_eq!(bytes.len() % 4, 0);
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().expect("4-byte chunk")))
+        .collect()
+}
+
+#[test]
+fn packed_columns_round_trip_to_the_plain_columns() {
+    let dir = std::env::temp_dir().join(format!("stwo-vector-gen-packed-{}", std::process::id()));
+    let plain = generate(&dir.join("plain.json"), "plain");
+    let mut packed = generate(&dir.join("packed.json"), "packed");
+    fs::remove_dir_all(&dir).ok();
+
+    assert!(plain["meta"].get("column_encoding").is_none());
+    for (family, fields) in PACKED_COLUMN_FIELDS {
+        assert_eq!(
+            packed["meta"]["column_encoding"][family], "base64_u32_le",
+            "{family}"
+        );
+        let entries = packed[family].as_array_mut().expect("family entries");
+        assert!(!entries.is_empty(), "{family}");
+        for (index, entry) in entries.iter_mut().enumerate() {
+            for field in fields {
+                let columns = entry[*field].as_array().expect("packed columns");
+                let decoded = columns.iter().map(decode_column).collect::<Vec<_>>();
+                let expected: Vec<Vec<u32>> =
+                    serde_json::from_value(plain[family][index][*field].clone())
+                        .expect("plain columns");
+                assert_eq!(decoded, expected, "{family}[{index}].{field}");
+                entry[*field] = serde_json::to_value(decoded).expect("json");
+            }
+        }
+    }
+
+    // With the columns decoded, the two files hold the same vectors.
+    for (key, value) in plain.as_object().expect("top-level object") {
+        match key.as_str() {
+            "generation_stats" => {}
+            "meta" => {
+                let mut meta = packed["meta"].clone();
+                meta.as_object_mut()
+                    .expect("meta object")
+                    .remove("column_encoding");
+                assert_eq!(value, &meta);
+            }
+            _ => assert_eq!(value, &packed[key], "{key}"),
+        }
+    }
+}